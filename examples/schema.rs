@@ -3,8 +3,13 @@ use std::fs::create_dir_all;
 
 use cosmwasm_schema::{export_schema, remove_schemas, schema_for};
 use funding_trading_bridge_smart_contract::store::contract_state::ContractStateV1;
+use funding_trading_bridge_smart_contract::store::runtime_tunables::RuntimeTunables;
 use funding_trading_bridge_smart_contract::types::msg::{
-    ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg,
+    ComplianceQuery, ComplianceResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg,
+};
+use funding_trading_bridge_smart_contract::types::responses::{
+    AccountReadinessResponse, BootstrapResponse, ContractBalancesResponse, DashboardResponse,
+    PreviewExecuteResponse,
 };
 
 fn main() {
@@ -19,4 +24,13 @@ fn main() {
     export_schema(&schema_for!(MigrateMsg), &out_dir);
     // Query results
     export_schema(&schema_for!(ContractStateV1), &out_dir);
+    export_schema(&schema_for!(AccountReadinessResponse), &out_dir);
+    export_schema(&schema_for!(BootstrapResponse), &out_dir);
+    export_schema(&schema_for!(ContractBalancesResponse), &out_dir);
+    export_schema(&schema_for!(RuntimeTunables), &out_dir);
+    export_schema(&schema_for!(DashboardResponse), &out_dir);
+    export_schema(&schema_for!(PreviewExecuteResponse), &out_dir);
+    // Compliance contract interface
+    export_schema(&schema_for!(ComplianceQuery), &out_dir);
+    export_schema(&schema_for!(ComplianceResponse), &out_dir);
 }
@@ -0,0 +1,267 @@
+use crate::store::contract_state::ContractStateV1;
+use crate::types::msg::{ExecuteMsg, QueryMsg};
+use crate::types::quote::TradeDirection;
+use crate::types::responses::TradeSimulationResponse;
+use cosmwasm_std::{
+    to_json_binary, Addr, Coin, CustomQuery, QuerierWrapper, StdResult, Uint128, WasmMsg,
+};
+use result_extensions::ResultExtensions;
+use serde::de::DeserializeOwned;
+
+/// A typed handle to a deployed instance of this contract, allowing other contracts to build its
+/// messages and run its queries without hand-writing their json.  Consume this crate with the
+/// `library` feature enabled to use this module without exporting the contract's entry points.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BridgeContract(pub Addr);
+impl BridgeContract {
+    /// The bech32 address of the contract instance.
+    pub fn addr(&self) -> &Addr {
+        &self.0
+    }
+
+    /// Builds a message that invokes the given execution route on the contract.
+    ///
+    /// # Parameters
+    /// * `msg` The execution route to invoke.
+    /// * `funds` The coins to send to the contract alongside the message.
+    pub fn execute_msg(&self, msg: &ExecuteMsg, funds: Vec<Coin>) -> StdResult<WasmMsg> {
+        WasmMsg::Execute {
+            contract_addr: self.0.to_string(),
+            msg: to_json_binary(msg)?,
+            funds,
+        }
+        .to_ok()
+    }
+
+    /// Builds a message that converts the sender's deposit denom to trading denom via the
+    /// [fund_trading](crate::execute::fund_trading::fund_trading) route.
+    ///
+    /// # Parameters
+    /// * `trade_amount` The amount of deposit denom to trade.
+    pub fn fund_trading_msg(&self, trade_amount: Uint128) -> StdResult<WasmMsg> {
+        self.execute_msg(
+            &ExecuteMsg::FundTrading {
+                trade_amount,
+                dry_run: false,
+                quote: None,
+                referrer: None,
+                allow_partial: false,
+                allow_remainder: false,
+            },
+            vec![],
+        )
+    }
+
+    /// Builds a message that converts the sender's trading denom to deposit denom via the
+    /// [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading) route.
+    ///
+    /// # Parameters
+    /// * `trade_amount` The amount of trading denom to trade.
+    pub fn withdraw_trading_msg(&self, trade_amount: Uint128) -> StdResult<WasmMsg> {
+        self.execute_msg(
+            &ExecuteMsg::WithdrawTrading {
+                trade_amount,
+                dry_run: false,
+                quote: None,
+                referrer: None,
+                allow_partial: false,
+                allow_remainder: false,
+            },
+            vec![],
+        )
+    }
+
+    /// Runs the given query against the contract, deserializing its response to the requested
+    /// type.
+    ///
+    /// # Parameters
+    /// * `querier` The querier used to reach the contract.
+    /// * `msg` The query route to invoke.
+    pub fn query<T: DeserializeOwned, C: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<C>,
+        msg: &QueryMsg,
+    ) -> StdResult<T> {
+        querier.query_wasm_smart(self.0.to_string(), msg)
+    }
+
+    /// Fetches the contract's current configuration via the [query_contract_state](crate::query::query_contract_state::query_contract_state)
+    /// route.
+    ///
+    /// # Parameters
+    /// * `querier` The querier used to reach the contract.
+    pub fn query_state<C: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<C>,
+    ) -> StdResult<ContractStateV1> {
+        self.query(querier, &QueryMsg::QueryContractState {})
+    }
+
+    /// Simulates a [fund_trading](crate::execute::fund_trading::fund_trading) trade for the given
+    /// sender via the [simulate_trade](crate::query::query_simulate_trade::query_simulate_trade)
+    /// route.
+    ///
+    /// # Parameters
+    /// * `querier` The querier used to reach the contract.
+    /// * `sender` The bech32 address of the account that would request the trade.
+    /// * `amount` The amount of deposit denom that the trade would request.
+    pub fn simulate_fund<C: CustomQuery, S: Into<String>>(
+        &self,
+        querier: &QuerierWrapper<C>,
+        sender: S,
+        amount: Uint128,
+    ) -> StdResult<TradeSimulationResponse> {
+        self.simulate_trade(querier, sender, TradeDirection::Fund, amount)
+    }
+
+    /// Simulates a [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading) trade for
+    /// the given sender via the [simulate_trade](crate::query::query_simulate_trade::query_simulate_trade)
+    /// route.
+    ///
+    /// # Parameters
+    /// * `querier` The querier used to reach the contract.
+    /// * `sender` The bech32 address of the account that would request the trade.
+    /// * `amount` The amount of trading denom that the trade would request.
+    pub fn simulate_withdraw<C: CustomQuery, S: Into<String>>(
+        &self,
+        querier: &QuerierWrapper<C>,
+        sender: S,
+        amount: Uint128,
+    ) -> StdResult<TradeSimulationResponse> {
+        self.simulate_trade(querier, sender, TradeDirection::Withdraw, amount)
+    }
+
+    fn simulate_trade<C: CustomQuery, S: Into<String>>(
+        &self,
+        querier: &QuerierWrapper<C>,
+        sender: S,
+        direction: TradeDirection,
+        amount: Uint128,
+    ) -> StdResult<TradeSimulationResponse> {
+        self.query(
+            querier,
+            &QueryMsg::SimulateTrade {
+                sender: sender.into(),
+                direction,
+                amount,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::BridgeContract;
+    use crate::store::contract_state::ContractStateV1;
+    use crate::types::denom::Denom;
+    use crate::types::msg::{ExecuteMsg, QueryMsg};
+    use crate::types::quote::TradeDirection;
+    use crate::types::responses::TradeSimulationResponse;
+    use cosmwasm_std::testing::MockQuerier;
+    use cosmwasm_std::{
+        from_json, to_json_binary, Addr, ContractResult, Empty, QuerierWrapper, SystemError,
+        SystemResult, Uint128, WasmMsg, WasmQuery,
+    };
+
+    fn mock_bridge_querier() -> MockQuerier {
+        let mut querier = MockQuerier::new(&[]);
+        querier.update_wasm(|query| match query {
+            WasmQuery::Smart { contract_addr, msg } if contract_addr == "bridge" => {
+                let response = match from_json::<QueryMsg>(msg) {
+                    Ok(QueryMsg::QueryContractState {}) => to_json_binary(&ContractStateV1::new(
+                        Addr::unchecked("admin"),
+                        "bridge",
+                        &Denom::new("deposit", 2),
+                        &Denom::new("trading", 6),
+                        &[],
+                        &[],
+                    )),
+                    Ok(QueryMsg::SimulateTrade {
+                        sender,
+                        direction,
+                        amount,
+                    }) => to_json_binary(&TradeSimulationResponse {
+                        sender: Addr::unchecked(sender),
+                        direction,
+                        requested_amount: amount,
+                        would_succeed: false,
+                        collected_amount: None,
+                        output_amount: None,
+                        remainder: None,
+                        remainder_requires_acknowledgment: None,
+                        requires_approval: None,
+                        msg_fee: None,
+                        fee_discount: None,
+                        failures: vec!["failure".to_string()],
+                    }),
+                    other => panic!("unexpected query sent to the bridge: {other:?}"),
+                };
+                SystemResult::Ok(ContractResult::Ok(
+                    response.expect("the mocked response should serialize"),
+                ))
+            }
+            _ => SystemResult::Err(SystemError::UnsupportedRequest {
+                kind: "only the bridge contract can be queried".to_string(),
+            }),
+        });
+        querier
+    }
+
+    #[test]
+    fn trade_messages_should_target_the_bridge_route() {
+        let bridge = BridgeContract(Addr::unchecked("bridge"));
+        let msg = bridge
+            .fund_trading_msg(Uint128::new(100))
+            .expect("the fund trading msg should be built");
+        match msg {
+            WasmMsg::Execute {
+                contract_addr,
+                msg,
+                funds,
+            } => {
+                assert_eq!("bridge", contract_addr, "the bridge should be targeted");
+                assert!(funds.is_empty(), "no funds should be sent with a trade");
+                assert_eq!(
+                    ExecuteMsg::FundTrading {
+                        trade_amount: Uint128::new(100),
+                        dry_run: false,
+                        quote: None,
+                        referrer: None,
+                        allow_partial: false,
+                        allow_remainder: false,
+                    },
+                    from_json::<ExecuteMsg>(&msg).expect("the execute msg should deserialize"),
+                    "the fund trading route should be invoked",
+                );
+            }
+            msg => panic!("unexpected wasm msg built: {msg:?}"),
+        };
+    }
+
+    #[test]
+    fn queries_should_deserialize_the_bridge_responses() {
+        let bridge = BridgeContract(Addr::unchecked("bridge"));
+        let querier = mock_bridge_querier();
+        let querier = QuerierWrapper::<Empty>::new(&querier);
+        let state = bridge
+            .query_state(&querier)
+            .expect("the contract state should be queried");
+        assert_eq!(
+            "bridge", state.contract_name,
+            "the contract state response should be deserialized",
+        );
+        let simulation = bridge
+            .simulate_withdraw(&querier, "sender", Uint128::new(5))
+            .expect("the withdraw should be simulated");
+        assert_eq!(
+            TradeDirection::Withdraw,
+            simulation.direction,
+            "the withdraw direction should be simulated",
+        );
+        assert_eq!(
+            vec!["failure".to_string()],
+            simulation.failures,
+            "the simulation response should be deserialized",
+        );
+    }
+}
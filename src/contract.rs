@@ -1,15 +1,91 @@
+use crate::execute::accept_admin_role::accept_admin_role;
+use crate::execute::admin_add_denylisted_account::admin_add_denylisted_account;
+use crate::execute::admin_add_fee_exemption::admin_add_fee_exemption;
+use crate::execute::admin_add_whitelisted_account::admin_add_whitelisted_account;
+use crate::execute::admin_batch_force_withdraw::admin_batch_force_withdraw;
+use crate::execute::admin_bulk_update_attributes::admin_bulk_update_attributes;
+use crate::execute::admin_cancel_pending_admin::admin_cancel_pending_admin;
+use crate::execute::admin_disable_minting_permanently::admin_disable_minting_permanently;
+use crate::execute::admin_emergency_withdraw::admin_emergency_withdraw;
+use crate::execute::admin_pause::admin_pause;
+use crate::execute::admin_propose_new_admin::admin_propose_new_admin;
+use crate::execute::admin_publish_trading_denom_metadata::admin_publish_trading_denom_metadata;
+use crate::execute::admin_refresh_marker_cache::admin_refresh_marker_cache;
+use crate::execute::admin_remove_denylisted_account::admin_remove_denylisted_account;
+use crate::execute::admin_remove_fee_exemption::admin_remove_fee_exemption;
+use crate::execute::admin_remove_whitelisted_account::admin_remove_whitelisted_account;
+use crate::execute::admin_resume::admin_resume;
+use crate::execute::admin_run_diagnostics::admin_run_diagnostics;
+use crate::execute::admin_set_custody_address::admin_set_custody_address;
+use crate::execute::admin_set_tunables::admin_set_tunables;
 use crate::execute::admin_update_admin::admin_update_admin;
+use crate::execute::admin_update_all_required_attributes::admin_update_all_required_attributes;
+use crate::execute::admin_update_compliance_contract::admin_update_compliance_contract;
+use crate::execute::admin_update_contract_name::admin_update_contract_name;
+use crate::execute::admin_update_daily_cap::admin_update_daily_cap;
 use crate::execute::admin_update_deposit_required_attributes::admin_update_deposit_required_attributes;
+use crate::execute::admin_update_fee_discounts::admin_update_fee_discounts;
+use crate::execute::admin_update_fund_enabled::admin_update_fund_enabled;
+use crate::execute::admin_update_fund_fee::admin_update_fund_fee;
+use crate::execute::admin_update_large_trade_threshold::admin_update_large_trade_threshold;
+use crate::execute::admin_update_max_trade_amounts::admin_update_max_trade_amounts;
+use crate::execute::admin_update_min_trade_amounts::admin_update_min_trade_amounts;
+use crate::execute::admin_update_msg_fee::admin_update_msg_fee;
+use crate::execute::admin_update_per_account_daily_limit::admin_update_per_account_daily_limit;
+use crate::execute::admin_update_required_scope_spec::admin_update_required_scope_spec;
+use crate::execute::admin_update_schedule::admin_update_schedule;
+use crate::execute::admin_update_skip_balance_precheck::admin_update_skip_balance_precheck;
+use crate::execute::admin_update_trading_marker::admin_update_trading_marker;
+use crate::execute::admin_update_withdraw_enabled::admin_update_withdraw_enabled;
+use crate::execute::admin_update_withdraw_fee::admin_update_withdraw_fee;
 use crate::execute::admin_update_withdraw_required_attributes::admin_update_withdraw_required_attributes;
+use crate::execute::approve_pending_trade::approve_pending_trade;
 use crate::execute::fund_trading::fund_trading;
+use crate::execute::gov_update_authority::gov_update_authority;
+use crate::execute::rebalance::rebalance;
+use crate::execute::reject_pending_trade::reject_pending_trade;
 use crate::execute::withdraw_trading::withdraw_trading;
 use crate::instantiate::instantiate_contract::instantiate_contract;
 use crate::migrate::migrate_contract::migrate_contract;
+use crate::migrate::migrate_contract_with_cleanup::migrate_contract_with_cleanup;
+use crate::query::query_account_daily_remaining::query_account_daily_remaining;
+use crate::query::query_account_readiness::query_account_readiness;
+use crate::query::query_account_stats::query_account_stats;
+use crate::query::query_admin::query_admin;
+use crate::query::query_all_referral_stats::query_all_referral_stats;
+use crate::query::query_bootstrap::query_bootstrap;
+use crate::query::query_bridge_stats::query_bridge_stats;
+use crate::query::query_contract_balances::query_contract_balances;
 use crate::query::query_contract_state::query_contract_state;
+use crate::query::query_custody_address::query_custody_address;
+use crate::query::query_daily_capacity::query_daily_capacity;
+use crate::query::query_dashboard::query_dashboard;
+use crate::query::query_denylist::query_denylist;
+use crate::query::query_denylist_status::query_denylist_status;
+use crate::query::query_fee_exemptions::query_fee_exemptions;
+use crate::query::query_format_amount::query_format_amount;
+use crate::query::query_migration_info::query_migration_info;
+use crate::query::query_parse_amount::query_parse_amount;
+use crate::query::query_pending_admin::query_pending_admin;
+use crate::query::query_pending_trades::query_pending_trades;
+use crate::query::query_preview_execute::query_preview_execute;
+use crate::query::query_quote::query_quote;
+use crate::query::query_receipt::query_receipt;
+use crate::query::query_referral_stats::query_referral_stats;
+use crate::query::query_required_grants::query_required_grants;
+use crate::query::query_runtime_tunables::query_runtime_tunables;
+use crate::query::query_simulate_fund_trading::query_simulate_fund_trading;
+use crate::query::query_simulate_trade::query_simulate_trade;
+use crate::query::query_simulate_withdraw_trading::query_simulate_withdraw_trading;
+use crate::query::query_suggest_amounts::query_suggest_amounts;
+use crate::query::query_trade_history::query_trade_history;
+use crate::query::query_whitelisted_accounts::query_whitelisted_accounts;
+use crate::reply::trade_step_reply::handle_trade_step_reply;
 use crate::types::error::ContractError;
 use crate::types::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg};
+use crate::types::trade_options::TradeOptions;
 use crate::util::self_validating::SelfValidating;
-use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response};
 
 /// The entry point used when an account instantiates a stored code wasm payload of this contract on
 /// the Provenance Blockchain.
@@ -24,7 +100,7 @@ use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Respons
 /// of the instantiation message, as well as the funds provided as an amount during the transaction.
 /// * `msg` A custom instantiation message defined by this contract for creating the initial
 /// configuration used by the contract.
-#[entry_point]
+#[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     deps: DepsMut,
     env: Env,
@@ -49,7 +125,7 @@ pub fn instantiate(
 /// * `msg` A custom execution message enum defined by this contract to allow multiple different
 /// processes to be defined for the singular execution route entry point allowed by the
 /// cosmwasm framework.
-#[entry_point]
+#[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
     deps: DepsMut,
     env: Env,
@@ -58,21 +134,180 @@ pub fn execute(
 ) -> Result<Response, ContractError> {
     msg.self_validate()?;
     match msg {
-        ExecuteMsg::AdminUpdateAdmin { new_admin_address } => {
-            admin_update_admin(deps, env, info, new_admin_address)
+        ExecuteMsg::AdminAddDenylistedAccount { account, reason } => {
+            admin_add_denylisted_account(deps, env, info, account, reason)
         }
-        ExecuteMsg::AdminUpdateDepositRequiredAttributes { attributes } => {
+        ExecuteMsg::AdminAddFeeExemption { account } => {
+            admin_add_fee_exemption(deps, env, info, account)
+        }
+        ExecuteMsg::AdminAddWhitelistedAccount { account } => {
+            admin_add_whitelisted_account(deps, env, info, account)
+        }
+        ExecuteMsg::AdminBatchForceWithdraw {
+            accounts,
+            max_per_account,
+        } => admin_batch_force_withdraw(deps, env, info, accounts, max_per_account),
+        ExecuteMsg::AdminBulkUpdateAttributes {
+            updates,
+            chunk_token,
+        } => admin_bulk_update_attributes(deps, env, info, updates, chunk_token),
+        ExecuteMsg::AdminCancelPendingAdmin {} => admin_cancel_pending_admin(deps, env, info),
+        ExecuteMsg::AdminDisableMintingPermanently { .. } => {
+            admin_disable_minting_permanently(deps, env, info)
+        }
+        ExecuteMsg::AdminEmergencyWithdraw {
+            denom,
+            amount,
+            recipient,
+        } => admin_emergency_withdraw(deps, env, info, denom, amount, recipient),
+        ExecuteMsg::AdminPause {} => admin_pause(deps, env, info),
+        ExecuteMsg::AdminProposeNewAdmin { new_admin_address } => {
+            admin_propose_new_admin(deps, env, info, new_admin_address)
+        }
+        ExecuteMsg::AdminPublishTradingDenomMetadata { metadata } => {
+            admin_publish_trading_denom_metadata(deps, env, info, metadata)
+        }
+        ExecuteMsg::AdminRefreshMarkerCache {} => admin_refresh_marker_cache(deps, env, info),
+        ExecuteMsg::AdminRemoveDenylistedAccount { account } => {
+            admin_remove_denylisted_account(deps, env, info, account)
+        }
+        ExecuteMsg::AdminRemoveFeeExemption { account } => {
+            admin_remove_fee_exemption(deps, env, info, account)
+        }
+        ExecuteMsg::AdminRemoveWhitelistedAccount { account } => {
+            admin_remove_whitelisted_account(deps, env, info, account)
+        }
+        ExecuteMsg::AdminResume {} => admin_resume(deps, env, info),
+        ExecuteMsg::AdminRunDiagnostics {} => admin_run_diagnostics(deps, env, info),
+        ExecuteMsg::AdminSetCustodyAddress { address } => {
+            admin_set_custody_address(deps, env, info, address)
+        }
+        ExecuteMsg::AdminSetTunables { tunables } => admin_set_tunables(deps, env, info, tunables),
+        ExecuteMsg::AdminUpdateAdmin {
+            new_admin_address,
+            admin_is_group,
+        } => admin_update_admin(deps, env, info, new_admin_address, admin_is_group),
+        ExecuteMsg::AdminUpdateContractName { name } => {
+            admin_update_contract_name(deps, env, info, name)
+        }
+        ExecuteMsg::AdminUpdateComplianceContract {
+            compliance_contract,
+        } => admin_update_compliance_contract(deps, env, info, compliance_contract),
+        ExecuteMsg::AdminUpdateAllRequiredAttributes {
+            deposit_attributes,
+            withdraw_attributes,
+            ..
+        } => admin_update_all_required_attributes(
+            deps,
+            env,
+            info,
+            deposit_attributes,
+            withdraw_attributes,
+        ),
+        ExecuteMsg::AdminUpdateDepositRequiredAttributes { attributes, .. } => {
             admin_update_deposit_required_attributes(deps, env, info, attributes)
         }
-        ExecuteMsg::AdminUpdateWithdrawRequiredAttributes { attributes } => {
+        ExecuteMsg::AdminUpdateWithdrawRequiredAttributes { attributes, .. } => {
             admin_update_withdraw_required_attributes(deps, env, info, attributes)
         }
-        ExecuteMsg::FundTrading { trade_amount } => {
-            fund_trading(deps, env, info, trade_amount.u128())
+        ExecuteMsg::AdminUpdateSkipBalancePrecheck {
+            skip_balance_precheck,
+        } => admin_update_skip_balance_precheck(deps, env, info, skip_balance_precheck),
+        ExecuteMsg::AdminUpdateFundEnabled { fund_enabled } => {
+            admin_update_fund_enabled(deps, env, info, fund_enabled)
+        }
+        ExecuteMsg::AdminUpdateWithdrawEnabled { withdraw_enabled } => {
+            admin_update_withdraw_enabled(deps, env, info, withdraw_enabled)
+        }
+        ExecuteMsg::AdminUpdateLargeTradeThreshold {
+            large_trade_threshold,
+        } => admin_update_large_trade_threshold(deps, env, info, large_trade_threshold),
+        ExecuteMsg::AdminUpdateDailyCap { daily_cap } => {
+            admin_update_daily_cap(deps, env, info, daily_cap)
+        }
+        ExecuteMsg::AdminUpdatePerAccountDailyLimit {
+            per_account_daily_limit,
+        } => admin_update_per_account_daily_limit(deps, env, info, per_account_daily_limit),
+        ExecuteMsg::AdminUpdateMinTradeAmounts {
+            min_fund_amount,
+            min_withdraw_amount,
+        } => admin_update_min_trade_amounts(deps, env, info, min_fund_amount, min_withdraw_amount),
+        ExecuteMsg::AdminUpdateMaxTradeAmounts {
+            max_fund_amount,
+            max_withdraw_amount,
+        } => admin_update_max_trade_amounts(deps, env, info, max_fund_amount, max_withdraw_amount),
+        ExecuteMsg::AdminUpdateMsgFee { msg_fee } => admin_update_msg_fee(deps, env, info, msg_fee),
+        ExecuteMsg::AdminUpdateFeeDiscounts { fee_discounts } => {
+            admin_update_fee_discounts(deps, env, info, fee_discounts)
+        }
+        ExecuteMsg::AdminUpdateFundFee {
+            fund_fee_bps,
+            fee_collector,
+        } => admin_update_fund_fee(deps, env, info, fund_fee_bps, fee_collector),
+        ExecuteMsg::AdminUpdateWithdrawFee { withdraw_fee_bps } => {
+            admin_update_withdraw_fee(deps, env, info, withdraw_fee_bps)
         }
-        ExecuteMsg::WithdrawTrading { trade_amount } => {
-            withdraw_trading(deps, env, info, trade_amount.u128())
+        ExecuteMsg::AdminUpdateRequiredScopeSpec {
+            required_scope_spec,
+        } => admin_update_required_scope_spec(deps, env, info, required_scope_spec),
+        ExecuteMsg::AdminUpdateSchedule {
+            trading_start,
+            sunset_at,
+        } => admin_update_schedule(deps, env, info, trading_start, sunset_at),
+        ExecuteMsg::AdminUpdateTradingMarker { denom, force } => {
+            admin_update_trading_marker(deps, env, info, denom, force)
         }
+        ExecuteMsg::AcceptAdminRole {} => accept_admin_role(deps, env, info),
+        ExecuteMsg::ApprovePendingTrade { id } => approve_pending_trade(deps, env, info, id),
+        ExecuteMsg::RejectPendingTrade { id, reason } => {
+            reject_pending_trade(deps, env, info, id, reason)
+        }
+        ExecuteMsg::GovUpdateAuthority { gov_authority } => {
+            gov_update_authority(deps, env, info, gov_authority)
+        }
+        ExecuteMsg::FundTrading {
+            trade_amount,
+            dry_run,
+            quote,
+            referrer,
+            allow_partial,
+            allow_remainder,
+        } => fund_trading(
+            deps,
+            env,
+            info,
+            trade_amount,
+            TradeOptions {
+                dry_run,
+                quote,
+                referrer,
+                allow_partial,
+                allow_remainder,
+            },
+        ),
+        ExecuteMsg::WithdrawTrading {
+            trade_amount,
+            dry_run,
+            quote,
+            referrer,
+            allow_partial,
+            allow_remainder,
+        } => withdraw_trading(
+            deps,
+            env,
+            info,
+            trade_amount,
+            TradeOptions {
+                dry_run,
+                quote,
+                referrer,
+                allow_partial,
+                allow_remainder,
+            },
+        ),
+        ExecuteMsg::Rebalance {
+            target_trading_balance,
+        } => rebalance(deps, env, info, target_trading_balance),
     }
 }
 
@@ -83,16 +318,75 @@ pub fn execute(
 ///
 /// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
 /// resources like contract internal storage and a querier to retrieve blockchain objects.
-/// * `_env` An environment object provided by the cosmwasm framework.  Describes the contract's
-/// details, as well as blockchain information at the time of the transaction.  Unused by this
-/// function, but required by cosmwasm for successfully defined query entrypoint.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
 /// * `msg` A custom query message enum defined by this contract to allow multiple different results
 /// to be determined for this route.
-#[entry_point]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, ContractError> {
     msg.self_validate()?;
     match msg {
         QueryMsg::QueryContractState {} => query_contract_state(deps),
+        QueryMsg::QueryRuntimeTunables {} => query_runtime_tunables(deps),
+        QueryMsg::QueryAdmin {} => query_admin(deps),
+        QueryMsg::QueryPendingAdmin {} => query_pending_admin(deps),
+        QueryMsg::QueryCustodyAddress {} => query_custody_address(deps),
+        QueryMsg::QueryMigrationInfo {} => query_migration_info(deps),
+        QueryMsg::QueryRequiredGrants {} => query_required_grants(deps, env),
+        QueryMsg::QueryAccountReadiness { account } => query_account_readiness(deps, account),
+        QueryMsg::QueryDenylistStatus { account } => query_denylist_status(deps, account),
+        QueryMsg::QueryContractBalances {} => query_contract_balances(deps, env),
+        QueryMsg::QueryBridgeStats {} => query_bridge_stats(deps),
+        QueryMsg::QueryDailyCapacity {} => query_daily_capacity(deps, env),
+        QueryMsg::QueryDashboard {} => query_dashboard(deps, env),
+        QueryMsg::QueryBootstrap {} => query_bootstrap(deps, env),
+        QueryMsg::QueryQuote { direction, amount } => query_quote(deps, env, direction, amount),
+        QueryMsg::QueryPendingTrades { start_after, limit } => {
+            query_pending_trades(deps, env, start_after, limit)
+        }
+        QueryMsg::QueryReferralStats { referrer } => query_referral_stats(deps, referrer),
+        QueryMsg::QueryAccountStats { account } => query_account_stats(deps, account),
+        QueryMsg::QueryAccountDailyRemaining { account } => {
+            query_account_daily_remaining(deps, env, account)
+        }
+        QueryMsg::QueryAllReferralStats { start_after, limit } => {
+            query_all_referral_stats(deps, start_after, limit)
+        }
+        QueryMsg::QueryFeeExemptions { start_after, limit } => {
+            query_fee_exemptions(deps, start_after, limit)
+        }
+        QueryMsg::QueryDenylist { start_after, limit } => query_denylist(deps, start_after, limit),
+        QueryMsg::QueryWhitelistedAccounts { start_after, limit } => {
+            query_whitelisted_accounts(deps, start_after, limit)
+        }
+        QueryMsg::QueryReceipt { receipt } => query_receipt(deps, receipt),
+        QueryMsg::QueryTradeHistory {
+            start_after,
+            limit,
+            descending,
+        } => query_trade_history(deps, start_after, limit, descending),
+        QueryMsg::PreviewExecute { msg, sender } => query_preview_execute(deps, env, msg, sender),
+        QueryMsg::QuerySuggestAmounts { direction, amount } => {
+            query_suggest_amounts(deps, direction, amount)
+        }
+        QueryMsg::QueryFormatAmount { denom_side, amount } => {
+            query_format_amount(deps, denom_side, amount)
+        }
+        QueryMsg::QueryParseAmount {
+            denom_side,
+            display,
+        } => query_parse_amount(deps, denom_side, display),
+        QueryMsg::SimulateFundTrading { trade_amount } => {
+            query_simulate_fund_trading(deps, trade_amount)
+        }
+        QueryMsg::SimulateWithdrawTrading { trade_amount } => {
+            query_simulate_withdraw_trading(deps, trade_amount)
+        }
+        QueryMsg::SimulateTrade {
+            sender,
+            direction,
+            amount,
+        } => query_simulate_trade(deps, env, sender, direction, amount),
     }
 }
 
@@ -103,15 +397,36 @@ pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, ContractErr
 ///
 /// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
 /// resources like contract internal storage and a querier to retrieve blockchain objects.
-/// * `_env` An environment object provided by the cosmwasm framework.  Describes the contract's
-/// details, as well as blockchain information at the time of the transaction.  Unused by this
-/// function, but required by cosmwasm for successfully defined migration entrypoint.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
 /// * msg` A custom migrate message enum defined by this contract to allow multiple different
 /// results of invoking the migrate endpoint.
-#[entry_point]
-pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
     msg.self_validate()?;
     match msg {
-        MigrateMsg::ContractUpgrade {} => migrate_contract(deps),
+        MigrateMsg::ContractUpgrade {
+            instantiated_at_height,
+            instantiated_at_time,
+        } => migrate_contract(deps, env, instantiated_at_height, instantiated_at_time),
+        MigrateMsg::ContractUpgradeWithCleanup { purge_keys } => {
+            migrate_contract_with_cleanup(deps, env, purge_keys)
+        }
     }
 }
+
+/// The entry point used when a submessage emitted by the contract requests a reply.  Each message
+/// emitted by a trade replies when it fails, allowing the failure to be reported as an error that
+/// names the step of the trade that was rejected.
+///
+/// # Parameters
+///
+/// * `_deps` A dependencies object provided by the cosmwasm framework.  Unused, because replies
+/// only translate failures.
+/// * `_env` An environment object provided by the cosmwasm framework.  Unused, because replies only
+/// translate failures.
+/// * `msg` The reply produced by the submessage, containing its id and result.
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    handle_trade_step_reply(msg)
+}
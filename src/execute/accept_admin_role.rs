@@ -0,0 +1,194 @@
+use crate::store::audit_log::{append_audit_entry, AuditEntry};
+use crate::store::contract_state::{get_contract_state_v1, set_contract_state_v1};
+use crate::store::pending_admin::{get_pending_admin, set_pending_admin};
+use crate::types::error::ContractError;
+use crate::util::response_utils::base_response;
+use crate::util::validation_utils::check_funds_are_empty;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execute functionality.  This function will only accept the request if
+/// the sender is the [pending admin](crate::store::pending_admin) proposed by the
+/// [admin_propose_new_admin](crate::execute::admin_propose_new_admin::admin_propose_new_admin)
+/// route.  The function replaces the contract admin with the sender and discards the proposal.
+/// Because the sender signs the request, the new admin is guaranteed to be an address that can act
+/// as the admin.  The accepted admin is not recorded as a [group](crate::store::contract_state::ContractStateV1#admin_is_group).
+/// The change is recorded in the [audit log](crate::store::audit_log).
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+pub fn accept_admin_role(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    let mut contract_state = get_contract_state_v1(deps.storage)?;
+    if get_pending_admin(deps.storage)?.as_ref() != Some(&info.sender) {
+        return ContractError::NotAuthorizedError {
+            message: "only the pending admin may accept the admin role".to_string(),
+        }
+        .to_err();
+    }
+    let previous_admin = contract_state.admin;
+    contract_state.admin = info.sender;
+    contract_state.admin_is_group = false;
+    set_contract_state_v1(deps.storage, &contract_state)?;
+    set_pending_admin(deps.storage, None)?;
+    let audit_entry = append_audit_entry(
+        deps.storage,
+        AuditEntry {
+            id: 0,
+            action: "accept_admin_role".to_string(),
+            actor: contract_state.admin.to_owned(),
+            via_group_policy: false,
+            via_gov_authority: false,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            details: vec![format!("accepted the admin role from [{previous_admin}]")],
+        },
+    )?;
+    base_response("accept_admin_role", &env, &contract_state)
+        .add_attribute("previous_admin", previous_admin.as_str())
+        .add_attribute("new_admin", contract_state.admin.as_str())
+        .add_attribute("audit_entry_id", audit_entry.id.to_string())
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::accept_admin_role::accept_admin_role;
+    use crate::store::contract_state::{get_contract_state_v1, CONTRACT_TYPE, CONTRACT_VERSION};
+    use crate::store::pending_admin::{get_pending_admin, set_pending_admin};
+    use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_constants::{DEFAULT_ADMIN, DEFAULT_CONTRACT_NAME};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::error::ContractError;
+    use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coins, Addr};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn provided_funds_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = accept_admin_role(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("pending-admin"), &coins(10, "nhash")),
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(&error, ContractError::InvalidFundsError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn missing_contract_state_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = accept_admin_role(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("pending-admin"), &[]),
+        )
+        .expect_err("an error should occur when the contract state is missing");
+        assert!(
+            matches!(&error, ContractError::StorageError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn missing_proposal_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let error = accept_admin_role(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+        )
+        .expect_err("an error should occur when no proposal is pending");
+        assert!(
+            matches!(&error, ContractError::NotAuthorizedError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn sender_other_than_the_pending_admin_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        set_pending_admin(&mut deps.storage, Some(&Addr::unchecked("pending-admin")))
+            .expect("the pending admin should be stored");
+        for sender in [DEFAULT_ADMIN, "someone-else"] {
+            let error = accept_admin_role(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&Addr::unchecked(sender), &[]),
+            )
+            .expect_err("an error should occur when the sender is not the pending admin");
+            assert_eq!(
+                "not authorized: only the pending admin may accept the admin role",
+                error.to_string(),
+                "unexpected error message encountered",
+            );
+        }
+        assert_eq!(
+            DEFAULT_ADMIN,
+            get_contract_state_v1(deps.as_ref().storage)
+                .expect("contract state should load")
+                .admin
+                .as_str(),
+            "the admin should not change after a rejected request",
+        );
+    }
+
+    #[test]
+    fn successful_input_should_derive_a_response() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let pending_admin = Addr::unchecked("pending-admin");
+        set_pending_admin(&mut deps.storage, Some(&pending_admin))
+            .expect("the pending admin should be stored");
+        let response =
+            accept_admin_role(deps.as_mut(), mock_env(), message_info(&pending_admin, &[]))
+                .expect("the pending admin should be able to accept the admin role");
+        assert!(
+            response.messages.is_empty(),
+            "no messages should be emitted in the response",
+        );
+        assert_eq!(
+            8,
+            response.attributes.len(),
+            "eight attributes should be emitted in the response",
+        );
+        response.assert_attribute("action", "accept_admin_role");
+        response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+        response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
+        response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
+        response.assert_attribute("previous_admin", DEFAULT_ADMIN);
+        response.assert_attribute("new_admin", "pending-admin");
+        response.assert_attribute("audit_entry_id", "1");
+        assert_eq!(
+            pending_admin,
+            get_contract_state_v1(deps.as_ref().storage)
+                .expect("contract state should load after the update")
+                .admin,
+            "the pending admin should become the admin",
+        );
+        assert!(
+            get_pending_admin(deps.as_ref().storage)
+                .expect("the pending admin should load")
+                .is_none(),
+            "the proposal should be discarded once accepted",
+        );
+        accept_admin_role(deps.as_mut(), mock_env(), message_info(&pending_admin, &[]))
+            .expect_err("the admin role cannot be accepted twice");
+    }
+}
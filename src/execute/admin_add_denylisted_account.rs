@@ -0,0 +1,230 @@
+use crate::store::audit_log::{append_audit_entry, AuditEntry};
+use crate::store::contract_state::{get_contract_state_v1, AdminSender};
+use crate::store::denylist::add_denylisted_account;
+use crate::types::error::ContractError;
+use crate::util::response_utils::admin_response;
+use crate::util::validation_utils::check_funds_are_empty;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execute functionality.  This function will only accept the request if
+/// the sender is the registered contract admin in the [contract state](crate::store::contract_state::ContractStateV1).
+/// The function adds an account to the [denylist](crate::store::denylist), after which its trades
+/// are rejected regardless of the attributes that it holds, reporting the provided reason.  Adding
+/// an account that is already denylisted is rejected.
+/// The change is recorded in the [audit log](crate::store::audit_log).
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `account` The bech32 address of the account to block.
+/// * `reason` The reason that the account is blocked, if any.
+pub fn admin_add_denylisted_account(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    account: String,
+    reason: Option<String>,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    let contract_state = get_contract_state_v1(deps.storage)?;
+    let admin_sender = contract_state.check_admin(&info.sender, "add a denylisted account")?;
+    let account = deps.api.addr_validate(&account)?;
+    add_denylisted_account(deps.storage, &account, reason.to_owned())?;
+    let audit_entry = append_audit_entry(
+        deps.storage,
+        AuditEntry {
+            id: 0,
+            action: "admin_add_denylisted_account".to_string(),
+            actor: info.sender,
+            via_group_policy: admin_sender == AdminSender::GroupPolicy,
+            via_gov_authority: admin_sender == AdminSender::GovAuthority,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            details: vec![format!(
+                "added [{account}] to the denylist with reason [{}]",
+                reason.as_deref().unwrap_or("none")
+            )],
+        },
+    )?;
+    admin_response(
+        "admin_add_denylisted_account",
+        &env,
+        &contract_state,
+        admin_sender,
+    )
+    .add_attribute("added_denylisted_account", account)
+    .add_attribute(
+        "denylist_reason",
+        reason.unwrap_or_else(|| "none".to_string()),
+    )
+    .add_attribute("audit_entry_id", audit_entry.id.to_string())
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::admin_add_denylisted_account::admin_add_denylisted_account;
+    use crate::store::contract_state::{CONTRACT_TYPE, CONTRACT_VERSION};
+    use crate::store::denylist::{get_denylist_entry, DenylistEntry};
+    use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_constants::{DEFAULT_ADMIN, DEFAULT_CONTRACT_NAME};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::error::ContractError;
+    use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coins, Addr};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn provided_funds_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let account = deps.api.addr_make("flagged-account");
+        let error = admin_add_denylisted_account(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &coins(10, "nhash")),
+            account.to_string(),
+            None,
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(&error, ContractError::InvalidFundsError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn missing_contract_state_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let account = deps.api.addr_make("flagged-account");
+        let error = admin_add_denylisted_account(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            account.to_string(),
+            None,
+        )
+        .expect_err("an error should occur when the contract state is missing");
+        assert!(
+            matches!(&error, ContractError::StorageError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn non_admin_sender_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let account = deps.api.addr_make("flagged-account");
+        let error = admin_add_denylisted_account(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("not-the-admin"), &[]),
+            account.to_string(),
+            None,
+        )
+        .expect_err("an error should occur when the sender is not the admin");
+        assert!(
+            matches!(&error, ContractError::NotAuthorizedError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+        assert_eq!(
+            None,
+            get_denylist_entry(&deps.storage, &account)
+                .expect("fetching a denylist entry should succeed"),
+            "the account should not be denylisted",
+        );
+    }
+
+    #[test]
+    fn invalid_account_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let error = admin_add_denylisted_account(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            "not-a-bech32-address".to_string(),
+            None,
+        )
+        .expect_err("an error should occur when the account is not a valid address");
+        assert!(
+            matches!(&error, ContractError::Std(_)),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn successful_input_should_derive_a_response() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let account = deps.api.addr_make("flagged-account");
+        let response = admin_add_denylisted_account(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            account.to_string(),
+            Some("flagged by compliance".to_string()),
+        )
+        .expect("proper input on an instantiated contract should derive a successful response");
+        assert!(
+            response.messages.is_empty(),
+            "no messages should be emitted in the response",
+        );
+        assert_eq!(
+            8,
+            response.attributes.len(),
+            "eight attributes should be emitted in the response",
+        );
+        response.assert_attribute("action", "admin_add_denylisted_account");
+        response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+        response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
+        response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
+        response.assert_attribute("added_denylisted_account", account.as_str());
+        response.assert_attribute("denylist_reason", "flagged by compliance");
+        response.assert_attribute("audit_entry_id", "1");
+        assert_eq!(
+            Some(DenylistEntry {
+                account: account.to_owned(),
+                reason: Some("flagged by compliance".to_string()),
+            }),
+            get_denylist_entry(&deps.storage, &account)
+                .expect("fetching a denylist entry should succeed"),
+            "the account should be denylisted with its reason",
+        );
+        let error = admin_add_denylisted_account(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            account.to_string(),
+            None,
+        )
+        .expect_err("denylisting a duplicate account should fail");
+        assert_eq!(
+            format!("validation failed: account [{account}] is already denylisted"),
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+    }
+
+    #[test]
+    fn missing_reason_should_be_reported_as_none() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let account = deps.api.addr_make("flagged-account");
+        let response = admin_add_denylisted_account(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            account.to_string(),
+            None,
+        )
+        .expect("an account should be denylisted without a reason");
+        response.assert_attribute("denylist_reason", "none");
+    }
+}
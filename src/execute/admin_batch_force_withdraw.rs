@@ -0,0 +1,435 @@
+use crate::execute::withdraw_trading::build_withdraw_messages;
+use crate::store::audit_log::{append_audit_entry, AuditEntry};
+use crate::store::contract_state::{get_contract_state_v1, AdminSender};
+use crate::types::error::ContractError;
+use crate::types::quote::TradeDirection;
+use crate::types::trade_plan::TradePlan;
+use crate::util::conversion_utils::convert_denom;
+use crate::util::marker_cache_utils::get_trading_marker_address;
+use crate::util::provenance_utils::get_account_balance;
+use crate::util::response_utils::admin_response;
+use crate::util::trade_record_utils::record_executed_trade;
+use crate::util::validation_utils::check_funds_are_empty;
+use cosmwasm_std::{Addr, Attribute, DepsMut, Env, MessageInfo, Response, StdResult, Uint128};
+use result_extensions::ResultExtensions;
+
+/// The largest amount of messages that a single batch will emit.  Each withdrawn account emits
+/// three messages, so processing stops before the account that would exceed this value.
+pub const MAX_BATCH_FORCE_WITHDRAW_MESSAGES: usize = 120;
+const MESSAGES_PER_WITHDRAW: usize = 3;
+
+/// Invoked via the contract's execute functionality.  This function will only accept the request if
+/// the sender is the registered contract admin in the [contract state](crate::store::contract_state::ContractStateV1).
+/// The function returns deposit denom to each of the given accounts in order by collecting and
+/// burning its trading denom balance, optionally capped per account, with the same messages as
+/// [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading).  The accounts' required
+/// attributes are not checked.  Accounts without a balance that converts to deposit denom are
+/// skipped.  Processing stops before the account that would push the batch past
+/// [MAX_BATCH_FORCE_WITHDRAW_MESSAGES], and the response names the first unprocessed account so that
+/// the remainder can be submitted in a later batch.  The batch is rejected with an
+/// [InsolventError](ContractError::InsolventError) if the contract does not hold enough deposit
+/// denom to release to every processed account.  Each withdrawal is recorded in the trade, account and
+/// bridge statistics and issued a [receipt](crate::store::trade_receipts::TradeReceipt), and each
+/// batch is recorded in the [audit log](crate::store::audit_log).
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `accounts` The bech32 addresses of the accounts to withdraw, in processing order.
+/// * `max_per_account` If provided, the largest amount of trading denom withdrawn from any single
+/// account.
+pub fn admin_batch_force_withdraw(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    accounts: Vec<String>,
+    max_per_account: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    let contract_state = get_contract_state_v1(deps.storage)?;
+    let admin_sender = contract_state.check_admin(&info.sender, "force withdraws")?;
+    let accounts = accounts
+        .iter()
+        .map(|account| deps.api.addr_validate(account))
+        .collect::<StdResult<Vec<Addr>>>()?;
+    let trading_marker_address = get_trading_marker_address(&deps.as_ref(), &contract_state)?;
+    let mut messages = vec![];
+    let mut account_attributes: Vec<Attribute> = vec![];
+    let mut details = vec![];
+    let mut total_released = Uint128::zero();
+    let mut processed_count = 0;
+    let mut withdrawn_count = 0;
+    for (index, account) in accounts.iter().enumerate() {
+        if messages.len() + MESSAGES_PER_WITHDRAW > MAX_BATCH_FORCE_WITHDRAW_MESSAGES {
+            break;
+        }
+        let balance =
+            get_account_balance(&deps.as_ref(), account, &contract_state.trading_marker.name)?;
+        let requested_amount = max_per_account.map_or(balance, |max| balance.min(max));
+        let conversion = convert_denom(
+            requested_amount,
+            &contract_state.trading_marker,
+            &contract_state.deposit_marker,
+        )?;
+        account_attributes.push(Attribute::new(format!("account_{index}"), account));
+        let outcome = if balance.is_zero() {
+            "skipped_zero_balance"
+        } else if conversion.target_amount.is_zero() {
+            "skipped_unconvertible_balance"
+        } else {
+            let collected_amount = requested_amount.checked_sub(conversion.remainder)?;
+            messages.extend(build_withdraw_messages(
+                &env,
+                &contract_state,
+                account,
+                &trading_marker_address,
+                collected_amount,
+                conversion.target_amount,
+                None,
+            ));
+            total_released = total_released.checked_add(conversion.target_amount)?;
+            let trade_receipt = record_executed_trade(
+                deps.storage,
+                &env,
+                &contract_state,
+                TradeDirection::Withdraw,
+                account,
+                None,
+                &TradePlan {
+                    conversion: conversion.to_owned(),
+                    collected_amount,
+                    deposit_amount: conversion.target_amount,
+                    messages: vec![],
+                    msg_fee: None,
+                    fee_discount: None,
+                    trade_fee: None,
+                    whitelisted: false,
+                    referrer: None,
+                    requires_approval: false,
+                },
+            )?;
+            details.push(format!(
+                "withdrew [{collected_amount}{}] from [{account}] for [{}{}]",
+                contract_state.trading_marker.name,
+                conversion.target_amount,
+                contract_state.deposit_marker.name,
+            ));
+            account_attributes.push(Attribute::new(
+                format!("account_{index}_collected_amount"),
+                collected_amount.to_string(),
+            ));
+            account_attributes.push(Attribute::new(
+                format!("account_{index}_released_amount"),
+                conversion.target_amount.to_string(),
+            ));
+            account_attributes.push(Attribute::new(
+                format!("account_{index}_receipt"),
+                trade_receipt.receipt.to_string(),
+            ));
+            withdrawn_count += 1;
+            "withdrawn"
+        };
+        account_attributes.push(Attribute::new(format!("account_{index}_outcome"), outcome));
+        processed_count += 1;
+    }
+    let available_reserves = get_account_balance(
+        &deps.as_ref(),
+        env.contract.address.as_str(),
+        &contract_state.deposit_marker.name,
+    )?;
+    if available_reserves < total_released {
+        return ContractError::InsolventError {
+            required: total_released,
+            available: available_reserves,
+            denom: contract_state.deposit_marker.name.to_owned(),
+        }
+        .to_err();
+    }
+    details.insert(
+        0,
+        format!(
+            "force withdrew [{withdrawn_count}] of [{processed_count}] processed accounts, releasing [{total_released}{}]",
+            contract_state.deposit_marker.name,
+        ),
+    );
+    let audit_entry = append_audit_entry(
+        deps.storage,
+        AuditEntry {
+            id: 0,
+            action: "admin_batch_force_withdraw".to_string(),
+            actor: info.sender,
+            via_group_policy: admin_sender == AdminSender::GroupPolicy,
+            via_gov_authority: admin_sender == AdminSender::GovAuthority,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            details,
+        },
+    )?;
+    let response = admin_response(
+        "admin_batch_force_withdraw",
+        &env,
+        &contract_state,
+        admin_sender,
+    )
+    .add_attribute("processed_account_count", processed_count.to_string())
+    .add_attribute(
+        "unprocessed_account_count",
+        (accounts.len() - processed_count).to_string(),
+    )
+    .add_attribute("total_released_amount", total_released.to_string())
+    .add_attribute("audit_entry_id", audit_entry.id.to_string());
+    match accounts.get(processed_count) {
+        Some(account) => response.add_attribute("resume_from_account", account),
+        None => response,
+    }
+    .add_attributes(account_attributes)
+    .add_submessages(messages)
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::admin_batch_force_withdraw::{
+        admin_batch_force_withdraw, MAX_BATCH_FORCE_WITHDRAW_MESSAGES,
+    };
+    use crate::store::account_stats::get_account_stats;
+    use crate::store::audit_log::get_audit_entries;
+    use crate::store::bridge_stats::get_bridge_stats;
+    use crate::store::contract_state::{CONTRACT_TYPE, CONTRACT_VERSION};
+    use crate::store::trade_receipts::get_trade_receipt;
+    use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_constants::{
+        DEFAULT_ADMIN, DEFAULT_CONTRACT_NAME, DEFAULT_DEPOSIT_DENOM_NAME,
+        DEFAULT_TRADING_DENOM_NAME,
+    };
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::test::test_markers::{mock_marker_response, test_marker_account};
+    use crate::types::error::ContractError;
+    use crate::types::msg::MAX_BATCH_FORCE_WITHDRAW_ACCOUNTS;
+    use crate::types::quote::TradeDirection;
+    use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coin, coins, Addr, Uint128};
+    use provwasm_mocks::{
+        mock_provenance_dependencies, mock_provenance_dependencies_with_custom_querier,
+        MockProvenanceQuerier,
+    };
+    use provwasm_std::types::cosmos::bank::v1beta1::{QueryBalanceRequest, QueryBalanceResponse};
+    use provwasm_std::types::cosmos::base::v1beta1::Coin;
+
+    // The mock querier answers every balance query with the same coin, so each account's trading
+    // balance doubles as the contract's reserves
+    fn get_batch_querier<S: Into<String>>(balance: S) -> MockProvenanceQuerier {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(
+            &mut querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    amount: balance.into(),
+                    denom: DEFAULT_TRADING_DENOM_NAME.to_string(),
+                }),
+            },
+        );
+        mock_marker_response(
+            &mut querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        querier
+    }
+
+    #[test]
+    fn provided_funds_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_batch_force_withdraw(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &coins(10, "nhash")),
+            vec!["account".to_string()],
+            None,
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(&error, ContractError::InvalidFundsError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn non_admin_sender_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let error = admin_batch_force_withdraw(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            vec!["account".to_string()],
+            None,
+        )
+        .expect_err("an error should occur when the sender is not the admin");
+        assert!(
+            matches!(&error, ContractError::NotAuthorizedError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn successful_batch_should_withdraw_each_account() {
+        let mut deps =
+            mock_provenance_dependencies_with_custom_querier(get_batch_querier("4005000"));
+        test_instantiate(&mut deps);
+        let first = deps.api.addr_make("first");
+        let second = deps.api.addr_make("second");
+        let response = admin_batch_force_withdraw(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            vec![first.to_string(), second.to_string()],
+            Some(Uint128::new(1005000)),
+        )
+        .expect("a valid batch should succeed");
+        assert_eq!(
+            6,
+            response.messages.len(),
+            "three messages should be emitted for each withdrawn account",
+        );
+        assert_eq!(
+            19,
+            response.attributes.len(),
+            "nineteen attributes should be emitted in the response",
+        );
+        response.assert_attribute("action", "admin_batch_force_withdraw");
+        response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+        response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
+        response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
+        response.assert_attribute("processed_account_count", "2");
+        response.assert_attribute("unprocessed_account_count", "0");
+        response.assert_attribute("total_released_amount", "200");
+        response.assert_attribute("audit_entry_id", "1");
+        response.assert_attribute("account_0", first.as_str());
+        response.assert_attribute("account_0_collected_amount", "1000000");
+        response.assert_attribute("account_0_released_amount", "100");
+        response.assert_attribute("account_0_receipt", "1");
+        response.assert_attribute("account_0_outcome", "withdrawn");
+        response.assert_attribute("account_1", second.as_str());
+        response.assert_attribute("account_1_receipt", "2");
+        response.assert_attribute("account_1_outcome", "withdrawn");
+        let trade_receipt = get_trade_receipt(&deps.storage, 2)
+            .expect("the second withdraw should be issued a receipt");
+        assert_eq!(
+            second, trade_receipt.account,
+            "the receipt should identify the withdrawn account",
+        );
+        assert_eq!(
+            TradeDirection::Withdraw,
+            trade_receipt.direction,
+            "the receipt should record a withdraw",
+        );
+        assert_eq!(
+            coin(1000000, DEFAULT_TRADING_DENOM_NAME),
+            trade_receipt.input,
+            "the receipt should record the collected trading denom",
+        );
+        assert_eq!(
+            coin(100, DEFAULT_DEPOSIT_DENOM_NAME),
+            trade_receipt.output,
+            "the receipt should record the released deposit denom",
+        );
+        assert_eq!(
+            Uint128::new(5000),
+            trade_receipt.remainder,
+            "the receipt should record the unconverted remainder",
+        );
+        let account_stats =
+            get_account_stats(&deps.storage, &first).expect("the account stats should load");
+        assert_eq!(
+            1, account_stats.withdraw_count,
+            "the withdraw should be counted"
+        );
+        assert_eq!(
+            Uint128::new(100),
+            account_stats.total_withdrawn,
+            "the released deposit denom should be credited to the account",
+        );
+        let bridge_stats = get_bridge_stats(&deps.storage).expect("the bridge stats should load");
+        assert_eq!(
+            2, bridge_stats.withdraw_count,
+            "both withdraws should be counted"
+        );
+        assert_eq!(
+            Uint128::new(2000000),
+            bridge_stats.total_trading_burned,
+            "the collected trading denom should be recorded as burned",
+        );
+        assert_eq!(
+            Uint128::new(200),
+            bridge_stats.total_deposit_released,
+            "the released deposit denom should be recorded",
+        );
+        assert_eq!(
+            3,
+            get_audit_entries(&deps.storage)
+                .expect("the audit log should be fetched")
+                .first()
+                .expect("the batch should be recorded in the audit log")
+                .details
+                .len(),
+            "the audit entry should summarize the batch and describe each withdraw",
+        );
+    }
+
+    #[test]
+    fn accounts_without_a_convertible_balance_should_be_skipped() {
+        let mut deps = mock_provenance_dependencies_with_custom_querier(get_batch_querier("9999"));
+        test_instantiate(&mut deps);
+        let account = deps.api.addr_make("account");
+        let response = admin_batch_force_withdraw(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            vec![account.to_string()],
+            None,
+        )
+        .expect("a batch of skipped accounts should succeed");
+        assert!(
+            response.messages.is_empty(),
+            "no messages should be emitted for skipped accounts",
+        );
+        response.assert_attribute("processed_account_count", "1");
+        response.assert_attribute("total_released_amount", "0");
+        response.assert_attribute("account_0_outcome", "skipped_unconvertible_balance");
+    }
+
+    #[test]
+    fn batch_exceeding_the_message_cap_should_report_where_to_resume() {
+        let mut deps =
+            mock_provenance_dependencies_with_custom_querier(get_batch_querier("1000000000"));
+        test_instantiate(&mut deps);
+        let accounts = (0..MAX_BATCH_FORCE_WITHDRAW_ACCOUNTS)
+            .map(|index| deps.api.addr_make(&format!("account{index}")).to_string())
+            .collect::<Vec<_>>();
+        let processed_count = MAX_BATCH_FORCE_WITHDRAW_MESSAGES / 3;
+        let response = admin_batch_force_withdraw(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            accounts.to_owned(),
+            Some(Uint128::new(10000)),
+        )
+        .expect("a batch exceeding the message cap should partially succeed");
+        assert_eq!(
+            MAX_BATCH_FORCE_WITHDRAW_MESSAGES,
+            response.messages.len(),
+            "the batch should emit up to the message cap",
+        );
+        response.assert_attribute("processed_account_count", processed_count.to_string());
+        response.assert_attribute(
+            "unprocessed_account_count",
+            (MAX_BATCH_FORCE_WITHDRAW_ACCOUNTS - processed_count).to_string(),
+        );
+        response.assert_attribute("resume_from_account", &accounts[processed_count]);
+    }
+}
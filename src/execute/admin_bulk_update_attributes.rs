@@ -0,0 +1,450 @@
+use crate::store::audit_log::{append_audit_entry, AuditEntry};
+use crate::store::bulk_attribute_update::{
+    get_staged_attribute_update, remove_staged_attribute_update, set_staged_attribute_update,
+    StagedAttributeUpdate,
+};
+use crate::store::contract_state::{get_contract_state_v1, set_contract_state_v1, AdminSender};
+use crate::types::attribute_update::AttributeUpdate;
+use crate::types::error::ContractError;
+use crate::types::quote::TradeDirection;
+use crate::util::response_utils::admin_response;
+use crate::util::validation_utils::check_funds_are_empty;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execute functionality.  This function will only accept the request if
+/// the sender is the registered contract admin in the [contract state](crate::store::contract_state::ContractStateV1).
+/// The function replaces the required attributes of the trade routes across multiple transactions.
+/// A chunk without a `chunk_token` starts a new operation, abandoning any operation already in
+/// progress, and the response's `chunk_token` attribute identifies the operation.  Chunks that
+/// supply the token add their updates to the operation, and a chunk that supplies the token
+/// without any updates completes it.  Updates are only [staged](StagedAttributeUpdate) until the
+/// operation completes, at which point every updated route's list is replaced at once and the
+/// change is recorded in the [audit log](crate::store::audit_log) as a single entry.
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `updates` The attribute updates contained in this chunk.
+/// * `chunk_token` The token of the operation to which this chunk belongs, or `None` to start a new
+/// operation.
+pub fn admin_bulk_update_attributes(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    updates: Vec<AttributeUpdate>,
+    chunk_token: Option<String>,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    let mut contract_state = get_contract_state_v1(deps.storage)?;
+    let admin_sender = contract_state.check_admin(&info.sender, "update attributes")?;
+    let response = admin_response(
+        "admin_bulk_update_attributes",
+        &env,
+        &contract_state,
+        admin_sender,
+    );
+    let existing_update = get_staged_attribute_update(deps.storage)?;
+    let Some(chunk_token) = chunk_token else {
+        let mut staged_update = StagedAttributeUpdate {
+            chunk_token: format!(
+                "{}-{}",
+                env.block.height,
+                env.transaction.as_ref().map_or(0, |tx| tx.index),
+            ),
+            started_at_height: env.block.height,
+            chunk_count: 1,
+            deposit_attributes: None,
+            withdraw_attributes: None,
+        };
+        stage_updates(&mut staged_update, updates)?;
+        set_staged_attribute_update(deps.storage, &staged_update)?;
+        let response = staged_response(response, &staged_update);
+        return match existing_update {
+            Some(existing_update) => {
+                response.add_attribute("abandoned_chunk_token", existing_update.chunk_token)
+            }
+            None => response,
+        }
+        .to_ok();
+    };
+    let mut staged_update = match existing_update {
+        Some(staged_update) if staged_update.chunk_token == chunk_token => staged_update,
+        _ => {
+            return ContractError::NotFoundError {
+                message: format!(
+                    "no bulk attribute update is in progress with chunk token [{chunk_token}]"
+                ),
+            }
+            .to_err();
+        }
+    };
+    if !updates.is_empty() {
+        stage_updates(&mut staged_update, updates)?;
+        staged_update.chunk_count = staged_update.chunk_count.saturating_add(1);
+        set_staged_attribute_update(deps.storage, &staged_update)?;
+        return staged_response(response, &staged_update).to_ok();
+    }
+    let mut details = vec![];
+    if let Some(attributes) = staged_update.deposit_attributes {
+        details.push(format!(
+            "changed required deposit attributes from [{}] to [{}]",
+            contract_state.required_deposit_attributes.join(","),
+            attributes.join(","),
+        ));
+        contract_state.required_deposit_attributes = attributes;
+    }
+    if let Some(attributes) = staged_update.withdraw_attributes {
+        details.push(format!(
+            "changed required withdraw attributes from [{}] to [{}]",
+            contract_state.required_withdraw_attributes.join(","),
+            attributes.join(","),
+        ));
+        contract_state.required_withdraw_attributes = attributes;
+    }
+    set_contract_state_v1(deps.storage, &contract_state)?;
+    remove_staged_attribute_update(deps.storage);
+    let audit_entry = append_audit_entry(
+        deps.storage,
+        AuditEntry {
+            id: 0,
+            action: "admin_bulk_update_attributes".to_string(),
+            actor: info.sender,
+            via_group_policy: admin_sender == AdminSender::GroupPolicy,
+            via_gov_authority: admin_sender == AdminSender::GovAuthority,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            details,
+        },
+    )?;
+    response
+        .add_attribute("chunk_token", chunk_token)
+        .add_attribute("bulk_update_status", "applied")
+        .add_attribute("chunk_count", staged_update.chunk_count.to_string())
+        .add_attribute(
+            "new_deposit_attributes",
+            format!("[{}]", contract_state.required_deposit_attributes.join(",")),
+        )
+        .add_attribute(
+            "new_withdraw_attributes",
+            format!(
+                "[{}]",
+                contract_state.required_withdraw_attributes.join(",")
+            ),
+        )
+        .add_attribute("audit_entry_id", audit_entry.id.to_string())
+        .to_ok()
+}
+
+fn stage_updates(
+    staged_update: &mut StagedAttributeUpdate,
+    updates: Vec<AttributeUpdate>,
+) -> Result<(), ContractError> {
+    for update in updates {
+        let staged_attributes = match update.route {
+            TradeDirection::Fund => &mut staged_update.deposit_attributes,
+            TradeDirection::Withdraw => &mut staged_update.withdraw_attributes,
+        }
+        .get_or_insert_with(Vec::new);
+        for attribute in update.attributes {
+            if staged_attributes.contains(&attribute) {
+                return ContractError::ValidationError {
+                    message: format!(
+                        "attribute [{attribute}] is staged more than once for the [{}] route",
+                        update.route.name(),
+                    ),
+                }
+                .to_err();
+            }
+            staged_attributes.push(attribute);
+        }
+    }
+    ().to_ok()
+}
+
+fn staged_response(response: Response, staged_update: &StagedAttributeUpdate) -> Response {
+    response
+        .add_attribute("chunk_token", &staged_update.chunk_token)
+        .add_attribute("bulk_update_status", "staged")
+        .add_attribute("chunk_count", staged_update.chunk_count.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::admin_bulk_update_attributes::admin_bulk_update_attributes;
+    use crate::store::audit_log::get_audit_entries;
+    use crate::store::bulk_attribute_update::get_staged_attribute_update;
+    use crate::store::contract_state::{get_contract_state_v1, CONTRACT_TYPE, CONTRACT_VERSION};
+    use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_constants::{
+        DEFAULT_ADMIN, DEFAULT_CONTRACT_NAME, DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE,
+        DEFAULT_REQUIRED_WITHDRAW_ATTRIBUTE,
+    };
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::attribute_update::AttributeUpdate;
+    use crate::types::error::ContractError;
+    use crate::types::quote::TradeDirection;
+    use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coins, Addr, Response};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    fn update(route: TradeDirection, attributes: &[&str]) -> AttributeUpdate {
+        AttributeUpdate {
+            pair_id: None,
+            route,
+            attributes: attributes.iter().map(|attr| attr.to_string()).collect(),
+        }
+    }
+
+    fn chunk_token(response: &Response) -> String {
+        response
+            .attributes
+            .iter()
+            .find(|attr| attr.key == "chunk_token")
+            .expect("the response should include the chunk token")
+            .value
+            .to_owned()
+    }
+
+    #[test]
+    fn provided_funds_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_bulk_update_attributes(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &coins(10, "nhash")),
+            vec![update(TradeDirection::Fund, &["attr.pb"])],
+            None,
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(&error, ContractError::InvalidFundsError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn missing_contract_state_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_bulk_update_attributes(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            vec![update(TradeDirection::Fund, &["attr.pb"])],
+            None,
+        )
+        .expect_err("an error should occur when the contract state is missing");
+        assert!(
+            matches!(&error, ContractError::StorageError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn non_admin_sender_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let error = admin_bulk_update_attributes(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("not-the-admin"), &[]),
+            vec![update(TradeDirection::Fund, &["attr.pb"])],
+            None,
+        )
+        .expect_err("an error should occur when the sender is not the admin");
+        assert!(
+            matches!(&error, ContractError::NotAuthorizedError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn unknown_chunk_token_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let error = admin_bulk_update_attributes(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            vec![],
+            Some("unknown".to_string()),
+        )
+        .expect_err("an error should occur when no operation has the chunk token");
+        assert!(
+            matches!(&error, ContractError::NotFoundError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn duplicate_staged_attribute_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let response = admin_bulk_update_attributes(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            vec![update(TradeDirection::Withdraw, &["attr.pb"])],
+            None,
+        )
+        .expect("the first chunk should be staged");
+        let error = admin_bulk_update_attributes(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            vec![update(TradeDirection::Withdraw, &["attr.pb"])],
+            Some(chunk_token(&response)),
+        )
+        .expect_err("an error should occur when an attribute is staged twice for a route");
+        assert_eq!(
+            "validation failed: attribute [attr.pb] is staged more than once for the [withdraw] route",
+            error.to_string(),
+            "unexpected error encountered",
+        );
+    }
+
+    #[test]
+    fn chunks_should_only_be_applied_when_the_operation_completes() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let response = admin_bulk_update_attributes(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            vec![update(TradeDirection::Fund, &["first.kyc.pb"])],
+            None,
+        )
+        .expect("the first chunk should be staged");
+        response.assert_attribute("bulk_update_status", "staged");
+        response.assert_attribute("chunk_count", "1");
+        let token = chunk_token(&response);
+        let response = admin_bulk_update_attributes(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            vec![update(TradeDirection::Fund, &["second.kyc.pb"])],
+            Some(token.to_owned()),
+        )
+        .expect("the second chunk should be staged");
+        response.assert_attribute("bulk_update_status", "staged");
+        response.assert_attribute("chunk_count", "2");
+        let contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        assert_eq!(
+            vec![DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE.to_string()],
+            contract_state.required_deposit_attributes,
+            "trades should observe the previous attributes while chunks are staged",
+        );
+        let response = admin_bulk_update_attributes(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            vec![],
+            Some(token.to_owned()),
+        )
+        .expect("the operation should complete");
+        assert!(
+            response.messages.is_empty(),
+            "no messages should be emitted in the response",
+        );
+        assert_eq!(
+            11,
+            response.attributes.len(),
+            "eleven attributes should be emitted in the response",
+        );
+        response.assert_attribute("action", "admin_bulk_update_attributes");
+        response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+        response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
+        response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
+        response.assert_attribute("chunk_token", &token);
+        response.assert_attribute("bulk_update_status", "applied");
+        response.assert_attribute("chunk_count", "2");
+        response.assert_attribute("new_deposit_attributes", "[first.kyc.pb,second.kyc.pb]");
+        response.assert_attribute(
+            "new_withdraw_attributes",
+            format!("[{DEFAULT_REQUIRED_WITHDRAW_ATTRIBUTE}]"),
+        );
+        response.assert_attribute("audit_entry_id", "1");
+        let contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        assert_eq!(
+            vec!["first.kyc.pb".to_string(), "second.kyc.pb".to_string()],
+            contract_state.required_deposit_attributes,
+            "every staged chunk should be applied",
+        );
+        assert_eq!(
+            vec![DEFAULT_REQUIRED_WITHDRAW_ATTRIBUTE.to_string()],
+            contract_state.required_withdraw_attributes,
+            "routes without updates should be unchanged",
+        );
+        assert!(
+            get_staged_attribute_update(&deps.storage)
+                .expect("the staged update should load")
+                .is_none(),
+            "the staged update should be removed once applied",
+        );
+        let audit_entries = get_audit_entries(&deps.storage).expect("the audit log should load");
+        assert_eq!(
+            1,
+            audit_entries.len(),
+            "the operation should be audited as a single change",
+        );
+        assert_eq!(
+            vec![format!(
+                "changed required deposit attributes from [{DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE}] to [first.kyc.pb,second.kyc.pb]"
+            )],
+            audit_entries[0].details,
+            "the audit entry should describe each updated route",
+        );
+        admin_bulk_update_attributes(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            vec![],
+            Some(token),
+        )
+        .expect_err("a completed operation should not be completed again");
+    }
+
+    #[test]
+    fn new_operation_should_abandon_the_operation_in_progress() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let mut env = mock_env();
+        let response = admin_bulk_update_attributes(
+            deps.as_mut(),
+            env.to_owned(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            vec![update(TradeDirection::Fund, &["first.kyc.pb"])],
+            None,
+        )
+        .expect("the first operation should be staged");
+        let abandoned_token = chunk_token(&response);
+        env.block.height += 1;
+        let response = admin_bulk_update_attributes(
+            deps.as_mut(),
+            env,
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            vec![update(TradeDirection::Withdraw, &["second.kyc.pb"])],
+            None,
+        )
+        .expect("the second operation should be staged");
+        response.assert_attribute("abandoned_chunk_token", &abandoned_token);
+        let staged_update = get_staged_attribute_update(&deps.storage)
+            .expect("the staged update should load")
+            .expect("the second operation should be staged");
+        assert_ne!(
+            abandoned_token, staged_update.chunk_token,
+            "the second operation should receive a new token",
+        );
+        assert!(
+            staged_update.deposit_attributes.is_none(),
+            "the abandoned operation's updates should be discarded",
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use crate::store::audit_log::{append_audit_entry, AuditEntry};
+use crate::store::contract_state::{get_contract_state_v1, AdminSender};
+use crate::store::pending_admin::{get_pending_admin, set_pending_admin};
+use crate::types::error::ContractError;
+use crate::util::response_utils::admin_response;
+use crate::util::validation_utils::check_funds_are_empty;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execute functionality.  This function will only accept the request if
+/// the sender is the registered contract admin in the [contract state](crate::store::contract_state::ContractStateV1).
+/// The function discards the [pending admin](crate::store::pending_admin) proposed by the
+/// [admin_propose_new_admin](crate::execute::admin_propose_new_admin::admin_propose_new_admin)
+/// route, after which the proposed address can no longer accept the admin role.  An error is
+/// returned if no proposal is pending.
+/// The change is recorded in the [audit log](crate::store::audit_log).
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+pub fn admin_cancel_pending_admin(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    let contract_state = get_contract_state_v1(deps.storage)?;
+    let admin_sender = contract_state.check_admin(&info.sender, "cancel the pending admin")?;
+    let Some(pending_admin) = get_pending_admin(deps.storage)? else {
+        return ContractError::ValidationError {
+            message: "no admin proposal is pending".to_string(),
+        }
+        .to_err();
+    };
+    set_pending_admin(deps.storage, None)?;
+    let audit_entry = append_audit_entry(
+        deps.storage,
+        AuditEntry {
+            id: 0,
+            action: "admin_cancel_pending_admin".to_string(),
+            actor: info.sender,
+            via_group_policy: admin_sender == AdminSender::GroupPolicy,
+            via_gov_authority: admin_sender == AdminSender::GovAuthority,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            details: vec![format!(
+                "cancelled the proposal of [{pending_admin}] as admin"
+            )],
+        },
+    )?;
+    admin_response(
+        "admin_cancel_pending_admin",
+        &env,
+        &contract_state,
+        admin_sender,
+    )
+    .add_attribute("cancelled_pending_admin", pending_admin.as_str())
+    .add_attribute("audit_entry_id", audit_entry.id.to_string())
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::admin_cancel_pending_admin::admin_cancel_pending_admin;
+    use crate::store::contract_state::{CONTRACT_TYPE, CONTRACT_VERSION};
+    use crate::store::pending_admin::{get_pending_admin, set_pending_admin};
+    use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_constants::{DEFAULT_ADMIN, DEFAULT_CONTRACT_NAME};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::error::ContractError;
+    use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coins, Addr};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn provided_funds_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_cancel_pending_admin(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &coins(10, "nhash")),
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(&error, ContractError::InvalidFundsError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn missing_contract_state_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_cancel_pending_admin(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+        )
+        .expect_err("an error should occur when the contract state is missing");
+        assert!(
+            matches!(&error, ContractError::StorageError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn non_admin_sender_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let pending_admin = Addr::unchecked("pending-admin");
+        set_pending_admin(&mut deps.storage, Some(&pending_admin))
+            .expect("the pending admin should be stored");
+        let error = admin_cancel_pending_admin(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&pending_admin, &[]),
+        )
+        .expect_err("an error should occur when the sender is not the admin");
+        assert!(
+            matches!(&error, ContractError::NotAuthorizedError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn missing_proposal_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let error = admin_cancel_pending_admin(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+        )
+        .expect_err("an error should occur when no proposal is pending");
+        assert_eq!(
+            "validation failed: no admin proposal is pending",
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+    }
+
+    #[test]
+    fn successful_input_should_derive_a_response() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        set_pending_admin(&mut deps.storage, Some(&Addr::unchecked("pending-admin")))
+            .expect("the pending admin should be stored");
+        let response = admin_cancel_pending_admin(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+        )
+        .expect("the admin should be able to cancel a pending proposal");
+        assert!(
+            response.messages.is_empty(),
+            "no messages should be emitted in the response",
+        );
+        assert_eq!(
+            7,
+            response.attributes.len(),
+            "seven attributes should be emitted in the response",
+        );
+        response.assert_attribute("action", "admin_cancel_pending_admin");
+        response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+        response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
+        response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
+        response.assert_attribute("cancelled_pending_admin", "pending-admin");
+        response.assert_attribute("audit_entry_id", "1");
+        assert!(
+            get_pending_admin(deps.as_ref().storage)
+                .expect("the pending admin should load")
+                .is_none(),
+            "the proposal should be discarded",
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use crate::store::audit_log::{append_audit_entry, AuditEntry};
+use crate::store::contract_state::{get_contract_state_v1, set_contract_state_v1, AdminSender};
+use crate::types::error::ContractError;
+use crate::util::response_utils::admin_response;
+use crate::util::validation_utils::check_funds_are_empty;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execute functionality.  This function will only accept the request if
+/// the sender is the registered contract admin in the [contract state](crate::store::contract_state::ContractStateV1).
+/// The function sets the contract state's [minting_disabled](crate::store::contract_state::ContractStateV1#minting_disabled)
+/// flag, after which the [fund_trading](crate::execute::fund_trading::fund_trading) route rejects
+/// every trade while the [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading)
+/// route remains available.  No route can clear the flag once it is set.  The change is recorded
+/// in the [audit log](crate::store::audit_log).
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+pub fn admin_disable_minting_permanently(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    let mut contract_state = get_contract_state_v1(deps.storage)?;
+    let admin_sender = contract_state.check_admin(&info.sender, "disable minting")?;
+    if contract_state.minting_disabled {
+        return ContractError::ValidationError {
+            message: "minting is already permanently disabled".to_string(),
+        }
+        .to_err();
+    }
+    contract_state.minting_disabled = true;
+    set_contract_state_v1(deps.storage, &contract_state)?;
+    let audit_entry = append_audit_entry(
+        deps.storage,
+        AuditEntry {
+            id: 0,
+            action: "admin_disable_minting_permanently".to_string(),
+            actor: info.sender,
+            via_group_policy: admin_sender == AdminSender::GroupPolicy,
+            via_gov_authority: admin_sender == AdminSender::GovAuthority,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            details: vec!["permanently disabled minting".to_string()],
+        },
+    )?;
+    admin_response(
+        "admin_disable_minting_permanently",
+        &env,
+        &contract_state,
+        admin_sender,
+    )
+    .add_attribute("minting_disabled", "true")
+    .add_attribute("audit_entry_id", audit_entry.id.to_string())
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::admin_disable_minting_permanently::admin_disable_minting_permanently;
+    use crate::store::audit_log::get_audit_entries;
+    use crate::store::contract_state::{get_contract_state_v1, CONTRACT_TYPE, CONTRACT_VERSION};
+    use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_constants::{DEFAULT_ADMIN, DEFAULT_CONTRACT_NAME};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::error::ContractError;
+    use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coins, Addr};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn provided_funds_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_disable_minting_permanently(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &coins(10, "nhash")),
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(&error, ContractError::InvalidFundsError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn missing_contract_state_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_disable_minting_permanently(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+        )
+        .expect_err("an error should occur when the contract state is missing");
+        assert!(
+            matches!(&error, ContractError::StorageError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn non_admin_sender_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let error = admin_disable_minting_permanently(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("not-admin"), &[]),
+        )
+        .expect_err("an error should occur when the sender is not the admin");
+        assert!(
+            matches!(&error, ContractError::NotAuthorizedError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+        assert!(
+            !get_contract_state_v1(deps.as_ref().storage)
+                .expect("contract state should load")
+                .minting_disabled,
+            "minting should remain enabled after a rejected request",
+        );
+    }
+
+    #[test]
+    fn successful_input_should_disable_minting() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let response = admin_disable_minting_permanently(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+        )
+        .expect("minting should be disabled by the admin");
+        assert!(
+            response.messages.is_empty(),
+            "no messages should be emitted",
+        );
+        assert_eq!(
+            7,
+            response.attributes.len(),
+            "the correct number of attributes should be emitted",
+        );
+        response.assert_attribute("action", "admin_disable_minting_permanently");
+        response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+        response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
+        response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
+        response.assert_attribute("minting_disabled", "true");
+        response.assert_attribute("audit_entry_id", "1");
+        assert!(
+            get_contract_state_v1(deps.as_ref().storage)
+                .expect("contract state should load")
+                .minting_disabled,
+            "minting should be disabled in the contract state",
+        );
+        let entries = get_audit_entries(&deps.storage).expect("audit entries should load");
+        assert_eq!(1, entries.len(), "a single audit entry should be recorded");
+        assert_eq!(
+            vec!["permanently disabled minting".to_string()],
+            entries[0].details,
+            "the audit entry should describe the change",
+        );
+        let error = admin_disable_minting_permanently(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+        )
+        .expect_err("disabling minting a second time should fail");
+        assert_eq!(
+            "validation failed: minting is already permanently disabled",
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+    }
+}
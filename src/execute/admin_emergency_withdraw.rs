@@ -0,0 +1,459 @@
+use crate::store::audit_log::{append_audit_entry, AuditEntry};
+use crate::store::contract_state::{get_contract_state_v1, AdminSender};
+use crate::types::error::ContractError;
+use crate::util::provenance_utils::{get_account_balance, get_marker_address_for_denom};
+use crate::util::response_utils::admin_response;
+use crate::util::validation_utils::check_funds_are_empty;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
+use provwasm_std::types::cosmos::base::v1beta1::Coin;
+use provwasm_std::types::provenance::marker::v1::MsgTransferRequest;
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execute functionality.  This function will only accept the request if
+/// the sender is the registered contract admin in the [contract state](crate::store::contract_state::ContractStateV1).
+/// The function transfers escrowed deposit denom from the contract to a recipient outside of the
+/// normal trading routes, so that funds can be recovered in an emergency.  The recipient cannot be
+/// the contract itself or the account of either configured marker.  The contract must hold the
+/// full amount before the transfer is emitted, and every withdrawal is recorded in the
+/// [audit log](crate::store::audit_log).
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `denom` The denom to transfer.  Must be the name of the contract state's
+/// [deposit_marker](crate::store::contract_state::ContractStateV1#deposit_marker).
+/// * `amount` The amount of the denom to transfer to the recipient.
+/// * `recipient` The bech32 address that will receive the funds.
+pub fn admin_emergency_withdraw(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: String,
+    amount: Uint128,
+    recipient: String,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    let contract_state = get_contract_state_v1(deps.storage)?;
+    let admin_sender = contract_state.check_admin(&info.sender, "perform an emergency withdraw")?;
+    let recipient = deps.api.addr_validate(&recipient)?;
+    if amount.is_zero() {
+        return ContractError::ValidationError {
+            message: "emergency withdraw amount must be greater than zero".to_string(),
+        }
+        .to_err();
+    }
+    if denom != contract_state.deposit_marker.name {
+        return ContractError::ValidationError {
+            message: format!(
+                "emergency withdraw denom [{denom}] must be the deposit denom [{}]",
+                contract_state.deposit_marker.name,
+            ),
+        }
+        .to_err();
+    }
+    if recipient == env.contract.address {
+        return ContractError::InvalidAccountError {
+            message: "the emergency withdraw recipient cannot be the contract's own address"
+                .to_string(),
+        }
+        .to_err();
+    }
+    for marker in [
+        &contract_state.deposit_marker,
+        &contract_state.trading_marker,
+    ] {
+        // Funds sent to a marker account would be escrowed by the marker rather than recovered
+        if get_marker_address_for_denom(&deps.as_ref(), &marker.name)? == recipient.as_str() {
+            return ContractError::InvalidAccountError {
+                message: format!(
+                    "the emergency withdraw recipient cannot be the account of marker [{}]",
+                    marker.name,
+                ),
+            }
+            .to_err();
+        }
+    }
+    let available = get_account_balance(&deps.as_ref(), env.contract.address.as_str(), &denom)?;
+    if available < amount {
+        return ContractError::InsolventError {
+            required: amount,
+            available,
+            denom,
+        }
+        .to_err();
+    }
+    let transfer_msg = MsgTransferRequest {
+        administrator: env.contract.address.to_string(),
+        amount: Some(Coin {
+            denom: denom.to_owned(),
+            amount: amount.to_string(),
+        }),
+        from_address: env.contract.address.to_string(),
+        to_address: recipient.to_string(),
+    };
+    let audit_entry = append_audit_entry(
+        deps.storage,
+        AuditEntry {
+            id: 0,
+            action: "admin_emergency_withdraw".to_string(),
+            actor: info.sender,
+            via_group_policy: admin_sender == AdminSender::GroupPolicy,
+            via_gov_authority: admin_sender == AdminSender::GovAuthority,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            details: vec![format!(
+                "transferred [{amount}{denom}] from the contract to [{recipient}]"
+            )],
+        },
+    )?;
+    admin_response(
+        "admin_emergency_withdraw",
+        &env,
+        &contract_state,
+        admin_sender,
+    )
+    .add_message(transfer_msg)
+    .add_attribute("denom", denom)
+    .add_attribute("amount", amount.to_string())
+    .add_attribute("recipient", recipient)
+    .add_attribute("audit_entry_id", audit_entry.id.to_string())
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::admin_emergency_withdraw::admin_emergency_withdraw;
+    use crate::store::audit_log::get_audit_entries;
+    use crate::store::contract_state::{CONTRACT_TYPE, CONTRACT_VERSION};
+    use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_constants::{
+        DEFAULT_ADMIN, DEFAULT_CONTRACT_NAME, DEFAULT_DEPOSIT_DENOM_NAME,
+        DEFAULT_TRADING_DENOM_NAME,
+    };
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::test::test_markers::{mock_marker_response, test_marker_account};
+    use crate::types::error::ContractError;
+    use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coins, Addr, AnyMsg, CosmosMsg, Uint128};
+    use provwasm_mocks::{mock_provenance_dependencies, MockProvenanceQuerier};
+    use provwasm_std::types::cosmos::bank::v1beta1::{QueryBalanceRequest, QueryBalanceResponse};
+    use provwasm_std::types::cosmos::base::v1beta1::Coin;
+    use provwasm_std::types::provenance::marker::v1::MsgTransferRequest;
+
+    fn mock_contract_balance(querier: &mut MockProvenanceQuerier, amount: u128) {
+        QueryBalanceRequest::mock_response(
+            querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+                    amount: amount.to_string(),
+                }),
+            },
+        );
+    }
+
+    #[test]
+    fn provided_funds_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let recipient = deps.api.addr_make("recipient");
+        let error = admin_emergency_withdraw(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &coins(10, "nhash")),
+            DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+            Uint128::new(100),
+            recipient.to_string(),
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(&error, ContractError::InvalidFundsError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn missing_contract_state_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let recipient = deps.api.addr_make("recipient");
+        let error = admin_emergency_withdraw(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+            Uint128::new(100),
+            recipient.to_string(),
+        )
+        .expect_err("an error should occur when the contract state is missing");
+        assert!(
+            matches!(&error, ContractError::StorageError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn non_admin_sender_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let recipient = deps.api.addr_make("recipient");
+        let error = admin_emergency_withdraw(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("not-the-admin"), &[]),
+            DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+            Uint128::new(100),
+            recipient.to_string(),
+        )
+        .expect_err("an error should occur when the sender is not the admin");
+        assert!(
+            matches!(&error, ContractError::NotAuthorizedError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn invalid_recipient_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let error = admin_emergency_withdraw(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+            Uint128::new(100),
+            "not-an-address".to_string(),
+        )
+        .expect_err("an error should occur when the recipient is invalid");
+        assert!(
+            matches!(&error, ContractError::Std(_)),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn zero_amount_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let recipient = deps.api.addr_make("recipient");
+        let error = admin_emergency_withdraw(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+            Uint128::zero(),
+            recipient.to_string(),
+        )
+        .expect_err("an error should occur when the amount is zero");
+        assert_eq!(
+            "validation failed: emergency withdraw amount must be greater than zero",
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+    }
+
+    #[test]
+    fn non_deposit_denom_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let recipient = deps.api.addr_make("recipient");
+        let error = admin_emergency_withdraw(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            DEFAULT_TRADING_DENOM_NAME.to_string(),
+            Uint128::new(100),
+            recipient.to_string(),
+        )
+        .expect_err("an error should occur when the denom is not the deposit denom");
+        assert_eq!(
+            format!(
+                "validation failed: emergency withdraw denom [{DEFAULT_TRADING_DENOM_NAME}] must be the deposit denom [{DEFAULT_DEPOSIT_DENOM_NAME}]"
+            ),
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+    }
+
+    #[test]
+    fn contract_address_recipient_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let mut env = mock_env();
+        env.contract.address = deps.api.addr_make("bridge-contract");
+        let contract_address = env.contract.address.to_string();
+        let error = admin_emergency_withdraw(
+            deps.as_mut(),
+            env,
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+            Uint128::new(100),
+            contract_address,
+        )
+        .expect_err("an error should occur when the recipient is the contract's own address");
+        assert!(
+            matches!(&error, ContractError::InvalidAccountError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn insufficient_contract_balance_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        mock_contract_balance(&mut deps.querier, 99);
+        mock_marker_response(
+            &mut deps.querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        let recipient = deps.api.addr_make("recipient");
+        let error = admin_emergency_withdraw(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+            Uint128::new(100),
+            recipient.to_string(),
+        )
+        .expect_err("an error should occur when the contract does not hold the amount");
+        match error {
+            ContractError::InsolventError {
+                required,
+                available,
+                denom,
+            } => {
+                assert_eq!(
+                    100,
+                    required.u128(),
+                    "the required amount should be reported"
+                );
+                assert_eq!(
+                    99,
+                    available.u128(),
+                    "the available amount should be reported"
+                );
+                assert_eq!(
+                    DEFAULT_DEPOSIT_DENOM_NAME, denom,
+                    "the deposit denom should be reported",
+                );
+            }
+            _ => panic!("unexpected error encountered: {error:?}"),
+        }
+        assert!(
+            get_audit_entries(&deps.storage)
+                .expect("audit entries should load")
+                .is_empty(),
+            "a rejected withdraw should not be recorded in the audit log",
+        );
+    }
+
+    #[test]
+    fn marker_address_recipient_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let marker = test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0");
+        let marker_address = marker
+            .base_account
+            .as_ref()
+            .expect("the test marker should have a base account")
+            .address
+            .to_owned();
+        mock_marker_response(&mut deps.querier, marker);
+        let error = admin_emergency_withdraw(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+            Uint128::new(100),
+            marker_address,
+        )
+        .expect_err("an error should occur when the recipient is a marker's account");
+        assert_eq!(
+            format!(
+                "invalid account: the emergency withdraw recipient cannot be the account of marker [{DEFAULT_DEPOSIT_DENOM_NAME}]"
+            ),
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+    }
+
+    #[test]
+    fn successful_input_should_derive_a_response() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        mock_contract_balance(&mut deps.querier, 100);
+        mock_marker_response(
+            &mut deps.querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        let recipient = deps.api.addr_make("recipient");
+        let response = admin_emergency_withdraw(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+            Uint128::new(100),
+            recipient.to_string(),
+        )
+        .expect("proper input on an instantiated contract should derive a successful response");
+        assert_eq!(
+            1,
+            response.messages.len(),
+            "a single transfer message should be emitted in the response",
+        );
+        match &response.messages[0].msg {
+            CosmosMsg::Any(AnyMsg { type_url, value })
+                if type_url == "/provenance.marker.v1.MsgTransferRequest" =>
+            {
+                let req = MsgTransferRequest::try_from(value.to_owned())
+                    .expect("the transfer request msg should properly deserialize");
+                assert_eq!(
+                    MOCK_CONTRACT_ADDR, req.administrator,
+                    "the administrator should be the contract",
+                );
+                assert_eq!(
+                    MOCK_CONTRACT_ADDR, req.from_address,
+                    "the funds should be sent from the contract",
+                );
+                assert_eq!(
+                    recipient.as_str(),
+                    req.to_address,
+                    "the funds should be sent to the recipient",
+                );
+                let amount = req
+                    .amount
+                    .expect("the transfer request should contain a coin amount");
+                assert_eq!("100", amount.amount, "the full amount should be sent");
+                assert_eq!(
+                    DEFAULT_DEPOSIT_DENOM_NAME, amount.denom,
+                    "the deposit denom should be sent",
+                );
+            }
+            msg => panic!("unexpected message emitted: {msg:?}"),
+        }
+        assert_eq!(
+            9,
+            response.attributes.len(),
+            "nine attributes should be emitted in the response",
+        );
+        response.assert_attribute("action", "admin_emergency_withdraw");
+        response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+        response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
+        response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
+        response.assert_attribute("denom", DEFAULT_DEPOSIT_DENOM_NAME);
+        response.assert_attribute("amount", "100");
+        response.assert_attribute("recipient", recipient.as_str());
+        response.assert_attribute("audit_entry_id", "1");
+        let audit_entries = get_audit_entries(&deps.storage).expect("audit entries should load");
+        assert_eq!(
+            vec![format!(
+                "transferred [100{DEFAULT_DEPOSIT_DENOM_NAME}] from the contract to [{recipient}]"
+            )],
+            audit_entries[0].details,
+            "the audit entry should describe the withdraw",
+        );
+    }
+}
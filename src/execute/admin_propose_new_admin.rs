@@ -0,0 +1,214 @@
+use crate::store::audit_log::{append_audit_entry, AuditEntry};
+use crate::store::contract_state::{get_contract_state_v1, AdminSender};
+use crate::store::pending_admin::{get_pending_admin, set_pending_admin};
+use crate::types::error::ContractError;
+use crate::util::response_utils::admin_response;
+use crate::util::validation_utils::check_funds_are_empty;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execute functionality.  This function will only accept the request if
+/// the sender is the registered contract admin in the [contract state](crate::store::contract_state::ContractStateV1).
+/// The function stores the provided address as the [pending admin](crate::store::pending_admin),
+/// replacing any earlier proposal.  The admin does not change until the proposed address invokes
+/// the [accept_admin_role](crate::execute::accept_admin_role::accept_admin_role) route, so a
+/// mistyped address can be corrected or [cancelled](crate::execute::admin_cancel_pending_admin::admin_cancel_pending_admin)
+/// without losing admin control.
+/// The change is recorded in the [audit log](crate::store::audit_log).
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `new_admin_address` The bech32 Provenance Blockchain address that may accept the admin role.
+pub fn admin_propose_new_admin(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    new_admin_address: String,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    let contract_state = get_contract_state_v1(deps.storage)?;
+    let admin_sender = contract_state.check_admin(&info.sender, "propose a new admin")?;
+    let new_admin_addr = deps.api.addr_validate(new_admin_address.as_str())?;
+    if new_admin_addr == contract_state.admin {
+        return ContractError::ValidationError {
+            message: format!("[{new_admin_addr}] is already the contract admin"),
+        }
+        .to_err();
+    }
+    let previous_pending_admin = get_pending_admin(deps.storage)?;
+    set_pending_admin(deps.storage, Some(&new_admin_addr))?;
+    let audit_entry = append_audit_entry(
+        deps.storage,
+        AuditEntry {
+            id: 0,
+            action: "admin_propose_new_admin".to_string(),
+            actor: info.sender,
+            via_group_policy: admin_sender == AdminSender::GroupPolicy,
+            via_gov_authority: admin_sender == AdminSender::GovAuthority,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            details: vec![format!("proposed [{new_admin_addr}] as admin")],
+        },
+    )?;
+    admin_response(
+        "admin_propose_new_admin",
+        &env,
+        &contract_state,
+        admin_sender,
+    )
+    .add_attribute(
+        "previous_pending_admin",
+        previous_pending_admin.map_or_else(|| "none".to_string(), |addr| addr.to_string()),
+    )
+    .add_attribute("pending_admin", new_admin_addr.as_str())
+    .add_attribute("audit_entry_id", audit_entry.id.to_string())
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::admin_propose_new_admin::admin_propose_new_admin;
+    use crate::store::contract_state::{get_contract_state_v1, CONTRACT_TYPE, CONTRACT_VERSION};
+    use crate::store::pending_admin::get_pending_admin;
+    use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_constants::{DEFAULT_ADMIN, DEFAULT_CONTRACT_NAME};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::error::ContractError;
+    use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coins, Addr};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn provided_funds_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_propose_new_admin(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &coins(10, "nhash")),
+            "test".to_string(),
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(&error, ContractError::InvalidFundsError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn missing_contract_state_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_propose_new_admin(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            "test".to_string(),
+        )
+        .expect_err("an error should occur when the contract state is missing");
+        assert!(
+            matches!(&error, ContractError::StorageError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn non_admin_sender_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let new_admin = deps.api.addr_make("new-admin");
+        let error = admin_propose_new_admin(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&new_admin, &[]),
+            new_admin.to_string(),
+        )
+        .expect_err("an error should occur when the sender is not the admin");
+        assert!(
+            matches!(&error, ContractError::NotAuthorizedError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+        assert!(
+            get_pending_admin(deps.as_ref().storage)
+                .expect("the pending admin should load")
+                .is_none(),
+            "no admin should be proposed after a rejected request",
+        );
+    }
+
+    #[test]
+    fn invalid_address_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        admin_propose_new_admin(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            "not a bech32 address".to_string(),
+        )
+        .expect_err("an error should occur when the proposed address is invalid");
+        assert!(
+            get_pending_admin(deps.as_ref().storage)
+                .expect("the pending admin should load")
+                .is_none(),
+            "an invalid address should not be proposed",
+        );
+    }
+
+    #[test]
+    fn successful_input_should_derive_a_response() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let first_proposal = deps.api.addr_make("first-proposal");
+        let new_admin = deps.api.addr_make("new-admin");
+        let response = admin_propose_new_admin(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            first_proposal.to_string(),
+        )
+        .expect("the admin should be able to propose a new admin");
+        assert!(
+            response.messages.is_empty(),
+            "no messages should be emitted in the response",
+        );
+        assert_eq!(
+            8,
+            response.attributes.len(),
+            "eight attributes should be emitted in the response",
+        );
+        response.assert_attribute("action", "admin_propose_new_admin");
+        response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+        response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
+        response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
+        response.assert_attribute("previous_pending_admin", "none");
+        response.assert_attribute("pending_admin", first_proposal.as_str());
+        response.assert_attribute("audit_entry_id", "1");
+        let response = admin_propose_new_admin(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            new_admin.to_string(),
+        )
+        .expect("the admin should be able to replace a proposal");
+        response.assert_attribute("previous_pending_admin", first_proposal.as_str());
+        response.assert_attribute("pending_admin", new_admin.as_str());
+        assert_eq!(
+            Some(new_admin),
+            get_pending_admin(deps.as_ref().storage).expect("the pending admin should load"),
+            "the latest proposal should replace the earlier one",
+        );
+        assert_eq!(
+            DEFAULT_ADMIN,
+            get_contract_state_v1(deps.as_ref().storage)
+                .expect("contract state should load")
+                .admin
+                .as_str(),
+            "the admin should not change until the proposal is accepted",
+        );
+    }
+}
@@ -0,0 +1,227 @@
+use crate::store::audit_log::{append_audit_entry, AuditEntry};
+use crate::store::contract_state::{get_contract_state_v1, AdminSender};
+use crate::types::denom::DenomMetadataInput;
+use crate::types::error::ContractError;
+use crate::util::provenance_utils::msg_set_denom_metadata;
+use crate::util::response_utils::admin_response;
+use crate::util::validation_utils::check_funds_are_empty;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execute functionality.  This function will only accept the request if
+/// the sender is the registered contract admin in the [contract state](crate::store::contract_state::ContractStateV1).
+/// The function emits a marker module message that publishes bank module metadata for the trading
+/// marker's denom, replacing any metadata published before, which allows metadata published at
+/// instantiation to be corrected.  The metadata's exponent must equal the trading marker's
+/// precision.  The change is recorded in the [audit log](crate::store::audit_log).
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `metadata` The display values to publish for the trading marker's denom.
+pub fn admin_publish_trading_denom_metadata(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    metadata: DenomMetadataInput,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    let contract_state = get_contract_state_v1(deps.storage)?;
+    let admin_sender =
+        contract_state.check_admin(&info.sender, "publish trading denom metadata")?;
+    metadata.validate_for_marker(&contract_state.trading_marker)?;
+    let audit_entry = append_audit_entry(
+        deps.storage,
+        AuditEntry {
+            id: 0,
+            action: "admin_publish_trading_denom_metadata".to_string(),
+            actor: info.sender,
+            via_group_policy: admin_sender == AdminSender::GroupPolicy,
+            via_gov_authority: admin_sender == AdminSender::GovAuthority,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            details: vec![format!(
+                "published metadata for [{}] with display [{}] and exponent [{}]",
+                contract_state.trading_marker.name, metadata.display, metadata.exponent,
+            )],
+        },
+    )?;
+    admin_response(
+        "admin_publish_trading_denom_metadata",
+        &env,
+        &contract_state,
+        admin_sender,
+    )
+    .add_message(msg_set_denom_metadata(
+        &contract_state.trading_marker,
+        &metadata,
+        env.contract.address.to_owned(),
+    ))
+    .add_attribute("trading_denom_metadata_display", &metadata.display)
+    .add_attribute("audit_entry_id", audit_entry.id.to_string())
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::admin_publish_trading_denom_metadata::admin_publish_trading_denom_metadata;
+    use crate::store::audit_log::get_audit_entries;
+    use crate::store::contract_state::{get_contract_state_v1, CONTRACT_TYPE, CONTRACT_VERSION};
+    use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_constants::{
+        DEFAULT_ADMIN, DEFAULT_CONTRACT_NAME, DEFAULT_TRADING_DENOM_NAME,
+        DEFAULT_TRADING_DENOM_PRECISION,
+    };
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::denom::DenomMetadataInput;
+    use crate::types::error::ContractError;
+    use crate::util::provenance_utils::msg_set_denom_metadata;
+    use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coins, Addr, AnyMsg, CosmosMsg};
+    use provwasm_mocks::mock_provenance_dependencies;
+    use provwasm_std::types::provenance::marker::v1::MsgSetDenomMetadataRequest;
+
+    fn test_metadata() -> DenomMetadataInput {
+        DenomMetadataInput {
+            name: "Trading".to_string(),
+            display: "ttrading".to_string(),
+            symbol: "TRD".to_string(),
+            description: "The trading denom".to_string(),
+            exponent: DEFAULT_TRADING_DENOM_PRECISION as u32,
+        }
+    }
+
+    #[test]
+    fn provided_funds_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_publish_trading_denom_metadata(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &coins(10, "nhash")),
+            test_metadata(),
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(&error, ContractError::InvalidFundsError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn missing_contract_state_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_publish_trading_denom_metadata(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            test_metadata(),
+        )
+        .expect_err("an error should occur when the contract state is missing");
+        assert!(
+            matches!(&error, ContractError::StorageError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn non_admin_sender_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let error = admin_publish_trading_denom_metadata(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("not-admin"), &[]),
+            test_metadata(),
+        )
+        .expect_err("an error should occur when the sender is not the admin");
+        assert!(
+            matches!(&error, ContractError::NotAuthorizedError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn mismatched_exponent_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let error = admin_publish_trading_denom_metadata(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            DenomMetadataInput {
+                exponent: 2,
+                ..test_metadata()
+            },
+        )
+        .expect_err("an error should occur when the exponent differs from the precision");
+        assert_eq!(
+            format!(
+                "validation failed: denom metadata exponent [2] must equal the trading marker precision [{DEFAULT_TRADING_DENOM_PRECISION}]"
+            ),
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+    }
+
+    #[test]
+    fn successful_input_should_publish_the_metadata() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let response = admin_publish_trading_denom_metadata(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            test_metadata(),
+        )
+        .expect("the admin should be able to publish the metadata");
+        assert_eq!(
+            1,
+            response.messages.len(),
+            "a single message should be emitted",
+        );
+        let contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        match &response.messages.first().unwrap().msg {
+            CosmosMsg::Any(AnyMsg { type_url: _, value }) => {
+                let metadata_msg = MsgSetDenomMetadataRequest::try_from(value.to_owned())
+                    .expect("expected the metadata msg binary to deserialize correctly");
+                assert_eq!(
+                    msg_set_denom_metadata(
+                        &contract_state.trading_marker,
+                        &test_metadata(),
+                        MOCK_CONTRACT_ADDR,
+                    ),
+                    metadata_msg,
+                    "expected the correct metadata msg to be deserialized",
+                );
+                assert_eq!(
+                    DEFAULT_TRADING_DENOM_NAME,
+                    metadata_msg
+                        .metadata
+                        .expect("the metadata should be set")
+                        .base,
+                    "the trading denom should be the base unit",
+                );
+            }
+            msg => panic!("unexpected msg format for denom metadata: {msg:?}"),
+        }
+        assert_eq!(
+            7,
+            response.attributes.len(),
+            "the correct number of attributes should be emitted",
+        );
+        response.assert_attribute("action", "admin_publish_trading_denom_metadata");
+        response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+        response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
+        response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
+        response.assert_attribute("trading_denom_metadata_display", "ttrading");
+        response.assert_attribute("audit_entry_id", "1");
+        let entries = get_audit_entries(&deps.storage).expect("audit entries should load");
+        assert_eq!(1, entries.len(), "a single audit entry should be recorded");
+    }
+}
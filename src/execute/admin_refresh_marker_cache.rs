@@ -0,0 +1,188 @@
+use crate::store::audit_log::{append_audit_entry, AuditEntry};
+use crate::store::contract_state::{get_contract_state_v1, AdminSender};
+use crate::types::error::ContractError;
+use crate::util::marker_cache_utils::refresh_marker_address_cache;
+use crate::util::response_utils::admin_response;
+use crate::util::validation_utils::check_funds_are_empty;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execute functionality.  This function will only accept the request if
+/// the sender is the registered contract admin in the [contract state](crate::store::contract_state::ContractStateV1).
+/// The function re-queries the trading marker and overwrites the [marker address cache](crate::store::marker_address_cache::MarkerAddressCache)
+/// with its current address, allowing the admin to immediately repair the cache after the marker is
+/// re-created at a new address rather than waiting for a withdraw to verify it.
+/// The change is recorded in the [audit log](crate::store::audit_log).
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+pub fn admin_refresh_marker_cache(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    let contract_state = get_contract_state_v1(deps.storage)?;
+    let admin_sender = contract_state.check_admin(&info.sender, "refresh the marker cache")?;
+    let (previous_address, current_address) =
+        refresh_marker_address_cache(deps.branch(), &contract_state)?;
+    let cache_repaired = previous_address
+        .as_ref()
+        .is_some_and(|previous_address| previous_address != current_address);
+    let audit_entry = append_audit_entry(
+        deps.storage,
+        AuditEntry {
+            id: 0,
+            action: "admin_refresh_marker_cache".to_string(),
+            actor: info.sender,
+            via_group_policy: admin_sender == AdminSender::GroupPolicy,
+            via_gov_authority: admin_sender == AdminSender::GovAuthority,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            details: vec![format!(
+                "changed cached trading marker address from [{}] to [{current_address}]",
+                previous_address
+                    .as_ref()
+                    .map_or_else(|| "none".to_string(), |address| address.to_string())
+            )],
+        },
+    )?;
+    admin_response(
+        "admin_refresh_marker_cache",
+        &env,
+        &contract_state,
+        admin_sender,
+    )
+    .add_attribute(
+        "previous_trading_marker_address",
+        previous_address.map_or_else(|| "none".to_string(), |address| address.to_string()),
+    )
+    .add_attribute("new_trading_marker_address", current_address)
+    .add_attribute("cache_repaired", cache_repaired.to_string())
+    .add_attribute("audit_entry_id", audit_entry.id.to_string())
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::admin_refresh_marker_cache::admin_refresh_marker_cache;
+    use crate::store::contract_state::{CONTRACT_TYPE, CONTRACT_VERSION};
+    use crate::store::marker_address_cache::{
+        get_marker_address_cache, set_marker_address_cache, MarkerAddressCache,
+    };
+    use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_constants::{
+        DEFAULT_ADMIN, DEFAULT_CONTRACT_NAME, DEFAULT_TRADING_DENOM_NAME,
+    };
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::test::test_markers::{mock_marker_response, test_marker_account};
+    use crate::types::error::ContractError;
+    use cosmwasm_std::testing::{message_info, mock_env, MockApi, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coins, Addr};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn provided_funds_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_refresh_marker_cache(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &coins(10, "nhash")),
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(&error, ContractError::InvalidFundsError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn missing_contract_state_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_refresh_marker_cache(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+        )
+        .expect_err("an error should occur when the contract state is missing");
+        assert!(
+            matches!(&error, ContractError::StorageError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn non_admin_sender_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let error = admin_refresh_marker_cache(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("not-the-admin"), &[]),
+        )
+        .expect_err("an error should occur when the sender is not the admin");
+        assert!(
+            matches!(&error, ContractError::NotAuthorizedError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn successful_input_should_derive_a_response() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        mock_marker_response(
+            &mut deps.querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        let stale_address = deps.api.addr_make("stale-marker");
+        set_marker_address_cache(
+            &mut deps.storage,
+            &MarkerAddressCache {
+                trading_marker_address: stale_address.to_owned(),
+                trades_since_verification: 3,
+            },
+        )
+        .expect("setting the cache should succeed");
+        let response = admin_refresh_marker_cache(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+        )
+        .expect("proper input on an instantiated contract should derive a successful response");
+        assert!(
+            response.messages.is_empty(),
+            "no messages should be emitted in the response",
+        );
+        assert_eq!(
+            9,
+            response.attributes.len(),
+            "nine attributes should be emitted in the response",
+        );
+        response.assert_attribute("action", "admin_refresh_marker_cache");
+        response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+        response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
+        response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
+        response.assert_attribute("previous_trading_marker_address", stale_address.as_str());
+        response.assert_attribute(
+            "new_trading_marker_address",
+            MockApi::default().addr_make("marker").as_str(),
+        );
+        response.assert_attribute("cache_repaired", "true");
+        response.assert_attribute("audit_entry_id", "1");
+        assert_eq!(
+            Some(MarkerAddressCache {
+                trading_marker_address: MockApi::default().addr_make("marker"),
+                trades_since_verification: 0,
+            }),
+            get_marker_address_cache(&deps.storage).expect("the cache should load"),
+            "the cache should be repaired and its count reset",
+        );
+    }
+}
@@ -0,0 +1,215 @@
+use crate::store::audit_log::{append_audit_entry, AuditEntry};
+use crate::store::contract_state::{get_contract_state_v1, AdminSender};
+use crate::types::error::ContractError;
+use crate::util::diagnostics_utils::run_diagnostics;
+use crate::util::response_utils::admin_response;
+use crate::util::validation_utils::check_funds_are_empty;
+use cosmwasm_std::{DepsMut, Env, Event, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execute functionality.  This function will only accept the request if
+/// the sender is the registered contract admin in the [contract state](crate::store::contract_state::ContractStateV1).
+/// The function verifies the contract's external dependencies, like its marker grants and name
+/// binding, and emits the findings as events.  The findings are also appended to the
+/// [audit log](crate::store::audit_log) to provide an on-chain record that the verification was
+/// performed.  Failing checks do not cause an error, because the findings are the intended result.
+/// No configuration is modified.
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+pub fn admin_run_diagnostics(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    let contract_state = get_contract_state_v1(deps.storage)?;
+    let admin_sender = contract_state.check_admin(&info.sender, "run diagnostics")?;
+    let checks = run_diagnostics(&deps.as_ref(), &env, &contract_state);
+    let failed_count = checks.iter().filter(|check| !check.passed).count();
+    let audit_entry = append_audit_entry(
+        deps.storage,
+        AuditEntry {
+            id: 0,
+            action: "admin_run_diagnostics".to_string(),
+            actor: info.sender,
+            via_group_policy: admin_sender == AdminSender::GroupPolicy,
+            via_gov_authority: admin_sender == AdminSender::GovAuthority,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            details: checks
+                .iter()
+                .map(|check| {
+                    format!(
+                        "{}: {} - {}",
+                        check.name,
+                        if check.passed { "passed" } else { "failed" },
+                        check.details,
+                    )
+                })
+                .collect(),
+        },
+    )?;
+    admin_response("admin_run_diagnostics", &env, &contract_state, admin_sender)
+        .add_events(checks.iter().map(|check| {
+            Event::new("diagnostic_check")
+                .add_attribute("name", &check.name)
+                .add_attribute("passed", check.passed.to_string())
+                .add_attribute("details", &check.details)
+        }))
+        .add_attribute("checks_passed", (checks.len() - failed_count).to_string())
+        .add_attribute("checks_failed", failed_count.to_string())
+        .add_attribute("audit_entry_id", audit_entry.id.to_string())
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::admin_run_diagnostics::admin_run_diagnostics;
+    use crate::store::audit_log::get_audit_entries;
+    use crate::store::contract_state::{get_contract_state_v1, CONTRACT_TYPE, CONTRACT_VERSION};
+    use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_constants::{DEFAULT_ADMIN, DEFAULT_CONTRACT_NAME};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::test::test_markers::mock_missing_marker_response;
+    use crate::types::error::ContractError;
+    use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coins, Addr};
+    use provwasm_mocks::{
+        mock_provenance_dependencies, mock_provenance_dependencies_with_custom_querier,
+        MockProvenanceQuerier,
+    };
+
+    #[test]
+    fn provided_funds_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_run_diagnostics(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &coins(10, "nhash")),
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(&error, ContractError::InvalidFundsError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn missing_contract_state_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_run_diagnostics(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+        )
+        .expect_err("an error should occur when the contract state is missing");
+        assert!(
+            matches!(&error, ContractError::StorageError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn non_admin_sender_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let error = admin_run_diagnostics(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("not-the-admin"), &[]),
+        )
+        .expect_err("an error should occur when the sender is not the admin");
+        assert!(
+            matches!(&error, ContractError::NotAuthorizedError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn failing_checks_should_be_recorded_without_an_error() {
+        let mut deps =
+            mock_provenance_dependencies_with_custom_querier(MockProvenanceQuerier::new(&[]));
+        test_instantiate(&mut deps);
+        // The markers are removed after instantiation and no other queries are mocked, so every
+        // check will fail
+        mock_missing_marker_response(&mut deps.querier);
+        let state_before =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        let env = mock_env();
+        let response = admin_run_diagnostics(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+        )
+        .expect("failing checks should not cause the route to fail");
+        assert!(
+            response.messages.is_empty(),
+            "no messages should be emitted in the response",
+        );
+        assert_eq!(
+            5,
+            response.events.len(),
+            "an event should be emitted for each check",
+        );
+        assert!(
+            response
+                .events
+                .iter()
+                .all(|event| event.ty == "diagnostic_check"
+                    && event
+                        .attributes
+                        .iter()
+                        .any(|attr| attr.key == "passed" && attr.value == "false")),
+            "each check should be emitted as a failing diagnostic event",
+        );
+        assert_eq!(
+            8,
+            response.attributes.len(),
+            "eight attributes should be emitted in the response",
+        );
+        response.assert_attribute("action", "admin_run_diagnostics");
+        response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+        response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
+        response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
+        response.assert_attribute("checks_passed", "0");
+        response.assert_attribute("checks_failed", "5");
+        response.assert_attribute("audit_entry_id", "1");
+        let entries = get_audit_entries(&deps.storage).expect("the audit log should load");
+        assert_eq!(1, entries.len(), "a single audit entry should be recorded");
+        let entry = &entries[0];
+        assert_eq!(
+            "admin_run_diagnostics", entry.action,
+            "the audit entry should record the action",
+        );
+        assert_eq!(
+            DEFAULT_ADMIN,
+            entry.actor.as_str(),
+            "the audit entry should record the sender",
+        );
+        assert_eq!(
+            env.block.time, entry.timestamp,
+            "the audit entry should record the block time",
+        );
+        assert_eq!(
+            env.block.height, entry.block_height,
+            "the audit entry should record the block height",
+        );
+        assert_eq!(
+            5,
+            entry.details.len(),
+            "each check's findings should be recorded in the audit entry",
+        );
+        assert_eq!(
+            state_before,
+            get_contract_state_v1(&deps.storage).expect("contract state should load"),
+            "running diagnostics should not modify the contract configuration",
+        );
+    }
+}
@@ -0,0 +1,282 @@
+use crate::store::audit_log::{append_audit_entry, AuditEntry};
+use crate::store::contract_state::{get_contract_state_v1, set_contract_state_v1, AdminSender};
+use crate::types::error::ContractError;
+use crate::util::provenance_utils::get_marker_address_for_denom;
+use crate::util::response_utils::admin_response;
+use crate::util::validation_utils::check_funds_are_empty;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execute functionality.  This function will only accept the request if
+/// the sender is the registered contract admin in the [contract state](crate::store::contract_state::ContractStateV1).
+/// The function sets the custody account that holds the contract's reserves when they are moved out
+/// of the contract.  Funds sent to the contract's own address or to a marker account cannot be
+/// recovered by this contract, so those addresses are refused.  Every change is recorded in the
+/// [audit log](crate::store::audit_log).
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `address` The bech32 address that will be set in the contract state's
+/// [custody_address](crate::store::contract_state::ContractStateV1#custody_address) property upon
+/// successful execution.
+pub fn admin_set_custody_address(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    address: String,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    let mut contract_state = get_contract_state_v1(deps.storage)?;
+    let admin_sender = contract_state.check_admin(&info.sender, "set the custody address")?;
+    let custody_address = deps.api.addr_validate(&address)?;
+    if custody_address == env.contract.address {
+        return ContractError::InvalidAccountError {
+            message: "the custody address cannot be the contract's own address".to_string(),
+        }
+        .to_err();
+    }
+    for marker in [
+        &contract_state.deposit_marker,
+        &contract_state.trading_marker,
+    ] {
+        // The marker module is queried rather than the marker address cache, because a stale cache
+        // would allow a re-created marker's new address to be accepted
+        if get_marker_address_for_denom(&deps.as_ref(), &marker.name)? == custody_address.as_str() {
+            return ContractError::InvalidAccountError {
+                message: format!(
+                    "the custody address cannot be the account of marker [{}]",
+                    marker.name,
+                ),
+            }
+            .to_err();
+        }
+    }
+    let previous_value = contract_state
+        .custody_address
+        .replace(custody_address.to_owned())
+        .map_or_else(|| "none".to_string(), |address| address.to_string());
+    set_contract_state_v1(deps.storage, &contract_state)?;
+    let audit_entry = append_audit_entry(
+        deps.storage,
+        AuditEntry {
+            id: 0,
+            action: "admin_set_custody_address".to_string(),
+            actor: info.sender,
+            via_group_policy: admin_sender == AdminSender::GroupPolicy,
+            via_gov_authority: admin_sender == AdminSender::GovAuthority,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            details: vec![format!(
+                "changed custody address from [{previous_value}] to [{custody_address}]"
+            )],
+        },
+    )?;
+    admin_response(
+        "admin_set_custody_address",
+        &env,
+        &contract_state,
+        admin_sender,
+    )
+    .add_attribute("previous_custody_address", previous_value)
+    .add_attribute("new_custody_address", custody_address)
+    .add_attribute("audit_entry_id", audit_entry.id.to_string())
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::admin_set_custody_address::admin_set_custody_address;
+    use crate::store::audit_log::get_audit_entries;
+    use crate::store::contract_state::{get_contract_state_v1, CONTRACT_TYPE, CONTRACT_VERSION};
+    use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_constants::{
+        DEFAULT_ADMIN, DEFAULT_CONTRACT_NAME, DEFAULT_DEPOSIT_DENOM_NAME,
+        DEFAULT_TRADING_DENOM_NAME,
+    };
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::test::test_markers::{mock_marker_response, test_marker_account};
+    use crate::types::error::ContractError;
+    use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coins, Addr};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn provided_funds_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let custody = deps.api.addr_make("custody");
+        let error = admin_set_custody_address(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &coins(10, "nhash")),
+            custody.to_string(),
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(&error, ContractError::InvalidFundsError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn missing_contract_state_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let custody = deps.api.addr_make("custody");
+        let error = admin_set_custody_address(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            custody.to_string(),
+        )
+        .expect_err("an error should occur when the contract state is missing");
+        assert!(
+            matches!(&error, ContractError::StorageError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn non_admin_sender_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let custody = deps.api.addr_make("custody");
+        let error = admin_set_custody_address(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("not-the-admin"), &[]),
+            custody.to_string(),
+        )
+        .expect_err("an error should occur when the sender is not the admin");
+        assert!(
+            matches!(&error, ContractError::NotAuthorizedError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn invalid_address_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let error = admin_set_custody_address(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            "not-an-address".to_string(),
+        )
+        .expect_err("an error should occur when the address is invalid");
+        assert!(
+            matches!(&error, ContractError::Std(_)),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn contract_address_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let mut env = mock_env();
+        env.contract.address = deps.api.addr_make("bridge-contract");
+        let contract_address = env.contract.address.to_string();
+        let error = admin_set_custody_address(
+            deps.as_mut(),
+            env,
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            contract_address,
+        )
+        .expect_err("an error should occur when the address is the contract's own address");
+        assert_eq!(
+            "invalid account: the custody address cannot be the contract's own address",
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+    }
+
+    #[test]
+    fn marker_address_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let marker = test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0");
+        let marker_address = marker
+            .base_account
+            .as_ref()
+            .expect("the test marker should have a base account")
+            .address
+            .to_owned();
+        mock_marker_response(&mut deps.querier, marker);
+        let error = admin_set_custody_address(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            marker_address,
+        )
+        .expect_err("an error should occur when the address is a marker's account");
+        assert_eq!(
+            format!(
+                "invalid account: the custody address cannot be the account of marker [{DEFAULT_DEPOSIT_DENOM_NAME}]"
+            ),
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+        assert!(
+            get_contract_state_v1(&deps.storage)
+                .expect("contract state should load")
+                .custody_address
+                .is_none(),
+            "the rejected address should not be stored",
+        );
+    }
+
+    #[test]
+    fn successful_input_should_derive_a_response() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        mock_marker_response(
+            &mut deps.querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        let custody = deps.api.addr_make("custody");
+        let response = admin_set_custody_address(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            custody.to_string(),
+        )
+        .expect("proper input on an instantiated contract should derive a successful response");
+        assert!(
+            response.messages.is_empty(),
+            "no messages should be emitted in the response",
+        );
+        assert_eq!(
+            8,
+            response.attributes.len(),
+            "eight attributes should be emitted in the response",
+        );
+        response.assert_attribute("action", "admin_set_custody_address");
+        response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+        response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
+        response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
+        response.assert_attribute("previous_custody_address", "none");
+        response.assert_attribute("new_custody_address", custody.as_str());
+        response.assert_attribute("audit_entry_id", "1");
+        assert_eq!(
+            Some(custody.to_owned()),
+            get_contract_state_v1(&deps.storage)
+                .expect("contract state should load")
+                .custody_address,
+            "the custody address should be persisted",
+        );
+        let audit_entries = get_audit_entries(&deps.storage).expect("audit entries should load");
+        assert_eq!(
+            vec![format!(
+                "changed custody address from [none] to [{custody}]"
+            )],
+            audit_entries[0].details,
+            "the audit entry should describe the change",
+        );
+    }
+}
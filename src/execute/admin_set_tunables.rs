@@ -0,0 +1,264 @@
+use crate::store::audit_log::{append_audit_entry, AuditEntry};
+use crate::store::contract_state::{get_contract_state_v1, AdminSender};
+use crate::store::runtime_tunables::{get_runtime_tunables, set_runtime_tunables, RuntimeTunables};
+use crate::types::error::ContractError;
+use crate::util::response_utils::admin_response;
+use crate::util::validation_utils::check_funds_are_empty;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execute functionality.  This function will only accept the request if
+/// the sender is the registered contract admin in the [contract state](crate::store::contract_state::ContractStateV1).
+/// The function replaces the stored [runtime tunables](RuntimeTunables) with the provided values.
+/// The change is recorded in the [audit log](crate::store::audit_log).
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `tunables` The new values that will be stored upon successful execution.
+pub fn admin_set_tunables(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    tunables: RuntimeTunables,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    let contract_state = get_contract_state_v1(deps.storage)?;
+    let admin_sender = contract_state.check_admin(&info.sender, "update the runtime tunables")?;
+    let previous_tunables = get_runtime_tunables(deps.storage)?;
+    set_runtime_tunables(deps.storage, &tunables)?;
+    let audit_entry = append_audit_entry(
+        deps.storage,
+        AuditEntry {
+            id: 0,
+            action: "admin_set_tunables".to_string(),
+            actor: info.sender,
+            via_group_policy: admin_sender == AdminSender::GroupPolicy,
+            via_gov_authority: admin_sender == AdminSender::GovAuthority,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            details: vec![
+                format!(
+                    "changed attribute_page_size from [{}] to [{}]",
+                    previous_tunables.attribute_page_size, tunables.attribute_page_size
+                ),
+                format!(
+                    "changed attribute_max_pages from [{}] to [{}]",
+                    previous_tunables.attribute_max_pages, tunables.attribute_max_pages
+                ),
+                format!(
+                    "changed quote_validity_blocks from [{}] to [{}]",
+                    previous_tunables.quote_validity_blocks, tunables.quote_validity_blocks
+                ),
+                format!(
+                    "changed pending_trade_expiration_blocks from [{}] to [{}]",
+                    previous_tunables.pending_trade_expiration_blocks,
+                    tunables.pending_trade_expiration_blocks
+                ),
+                format!(
+                    "changed verify_marker_cache_every_n_trades from [{}] to [{}]",
+                    verify_interval_attribute(previous_tunables.verify_marker_cache_every_n_trades),
+                    verify_interval_attribute(tunables.verify_marker_cache_every_n_trades)
+                ),
+                format!(
+                    "changed event_verbosity from [{}] to [{}]",
+                    previous_tunables.event_verbosity.name(),
+                    tunables.event_verbosity.name()
+                ),
+                format!(
+                    "changed verify_marker_access_on_trade from [{}] to [{}]",
+                    previous_tunables.verify_marker_access_on_trade,
+                    tunables.verify_marker_access_on_trade
+                ),
+            ],
+        },
+    )?;
+    admin_response("admin_set_tunables", &env, &contract_state, admin_sender)
+        .add_attribute(
+            "previous_attribute_page_size",
+            previous_tunables.attribute_page_size.to_string(),
+        )
+        .add_attribute(
+            "new_attribute_page_size",
+            tunables.attribute_page_size.to_string(),
+        )
+        .add_attribute(
+            "previous_attribute_max_pages",
+            previous_tunables.attribute_max_pages.to_string(),
+        )
+        .add_attribute(
+            "new_attribute_max_pages",
+            tunables.attribute_max_pages.to_string(),
+        )
+        .add_attribute(
+            "previous_quote_validity_blocks",
+            previous_tunables.quote_validity_blocks.to_string(),
+        )
+        .add_attribute(
+            "new_quote_validity_blocks",
+            tunables.quote_validity_blocks.to_string(),
+        )
+        .add_attribute(
+            "previous_pending_trade_expiration_blocks",
+            previous_tunables
+                .pending_trade_expiration_blocks
+                .to_string(),
+        )
+        .add_attribute(
+            "new_pending_trade_expiration_blocks",
+            tunables.pending_trade_expiration_blocks.to_string(),
+        )
+        .add_attribute(
+            "previous_verify_marker_cache_every_n_trades",
+            verify_interval_attribute(previous_tunables.verify_marker_cache_every_n_trades),
+        )
+        .add_attribute(
+            "new_verify_marker_cache_every_n_trades",
+            verify_interval_attribute(tunables.verify_marker_cache_every_n_trades),
+        )
+        .add_attribute(
+            "previous_event_verbosity",
+            previous_tunables.event_verbosity.name(),
+        )
+        .add_attribute("new_event_verbosity", tunables.event_verbosity.name())
+        .add_attribute(
+            "previous_verify_marker_access_on_trade",
+            previous_tunables.verify_marker_access_on_trade.to_string(),
+        )
+        .add_attribute(
+            "new_verify_marker_access_on_trade",
+            tunables.verify_marker_access_on_trade.to_string(),
+        )
+        .add_attribute("audit_entry_id", audit_entry.id.to_string())
+        .to_ok()
+}
+
+fn verify_interval_attribute(interval: Option<u64>) -> String {
+    interval.map_or_else(|| "none".to_string(), |interval| interval.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::admin_set_tunables::admin_set_tunables;
+    use crate::store::contract_state::{CONTRACT_TYPE, CONTRACT_VERSION};
+    use crate::store::runtime_tunables::{get_runtime_tunables, EventVerbosity, RuntimeTunables};
+    use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_constants::{DEFAULT_ADMIN, DEFAULT_CONTRACT_NAME};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::error::ContractError;
+    use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coins, Addr};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    fn new_tunables() -> RuntimeTunables {
+        RuntimeTunables {
+            attribute_page_size: 100,
+            attribute_max_pages: 2,
+            quote_validity_blocks: 50,
+            pending_trade_expiration_blocks: 1000,
+            verify_marker_cache_every_n_trades: Some(20),
+            event_verbosity: EventVerbosity::Minimal,
+            verify_marker_access_on_trade: true,
+        }
+    }
+
+    #[test]
+    fn provided_funds_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_set_tunables(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &coins(10, "nhash")),
+            new_tunables(),
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(&error, ContractError::InvalidFundsError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn missing_contract_state_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_set_tunables(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            new_tunables(),
+        )
+        .expect_err("an error should occur when the contract state is missing");
+        assert!(
+            matches!(&error, ContractError::StorageError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn non_admin_sender_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let error = admin_set_tunables(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("not-the-admin"), &[]),
+            new_tunables(),
+        )
+        .expect_err("an error should occur when the sender is not the admin");
+        assert!(
+            matches!(&error, ContractError::NotAuthorizedError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn successful_input_should_derive_a_response() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let response = admin_set_tunables(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            new_tunables(),
+        )
+        .expect("proper input on an instantiated contract should derive a successful response");
+        assert!(
+            response.messages.is_empty(),
+            "no messages should be emitted in the response",
+        );
+        assert_eq!(
+            20,
+            response.attributes.len(),
+            "twenty attributes should be emitted in the response",
+        );
+        response.assert_attribute("action", "admin_set_tunables");
+        response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+        response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
+        response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
+        response.assert_attribute("previous_attribute_page_size", "25");
+        response.assert_attribute("new_attribute_page_size", "100");
+        response.assert_attribute("previous_attribute_max_pages", "10");
+        response.assert_attribute("new_attribute_max_pages", "2");
+        response.assert_attribute("previous_quote_validity_blocks", "100");
+        response.assert_attribute("new_quote_validity_blocks", "50");
+        response.assert_attribute("previous_pending_trade_expiration_blocks", "14400");
+        response.assert_attribute("new_pending_trade_expiration_blocks", "1000");
+        response.assert_attribute("previous_verify_marker_cache_every_n_trades", "none");
+        response.assert_attribute("new_verify_marker_cache_every_n_trades", "20");
+        response.assert_attribute("previous_event_verbosity", "standard");
+        response.assert_attribute("new_event_verbosity", "minimal");
+        response.assert_attribute("previous_verify_marker_access_on_trade", "false");
+        response.assert_attribute("new_verify_marker_access_on_trade", "true");
+        response.assert_attribute("audit_entry_id", "1");
+        assert_eq!(
+            new_tunables(),
+            get_runtime_tunables(&deps.storage).expect("runtime tunables should load"),
+            "the new tunables should be persisted",
+        );
+    }
+}
@@ -1,5 +1,8 @@
-use crate::store::contract_state::{get_contract_state_v1, set_contract_state_v1, CONTRACT_TYPE};
+use crate::store::audit_log::{append_audit_entry, AuditEntry};
+use crate::store::contract_state::{get_contract_state_v1, set_contract_state_v1, AdminSender};
+use crate::store::pending_admin::set_pending_admin;
 use crate::types::error::ContractError;
+use crate::util::response_utils::admin_response;
 use crate::util::validation_utils::check_funds_are_empty;
 use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
 use result_extensions::ResultExtensions;
@@ -7,7 +10,11 @@ use result_extensions::ResultExtensions;
 /// Invoked via the contract's execute functionality.  This function will only accept the request if
 /// the sender is the registered contract admin in the [contract state](crate::store::contract_state::ContractStateV1).
 /// The function swaps the current value in the contract state for the newly-provided value,
-/// effectively removing the previous admin and setting a new one.
+/// effectively removing the previous admin and setting a new one.  When the current admin is a
+/// group, this request must arrive through the group's policy address like any other admin route.
+/// Any [pending admin](crate::store::pending_admin) proposal is discarded, because it was made on
+/// behalf of the replaced admin.
+/// The change is recorded in the [audit log](crate::store::audit_log).
 ///
 /// # Parameters
 /// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
@@ -18,42 +25,72 @@ use result_extensions::ResultExtensions;
 /// of the instantiation message, as well as the funds provided as an amount during the transaction.
 /// * `new_admin_address` The bech32 Provenance Blockchain address that will become the new admin
 /// upon successful invocation of this function.
+/// * `admin_is_group` Whether or not the new admin is the policy address of a group or DAO, stored
+/// in the contract state's [admin_is_group](crate::store::contract_state::ContractStateV1#admin_is_group)
+/// property.
 pub fn admin_update_admin(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
     new_admin_address: String,
+    admin_is_group: bool,
 ) -> Result<Response, ContractError> {
     check_funds_are_empty(&info)?;
     let mut contract_state = get_contract_state_v1(deps.storage)?;
-    if info.sender != contract_state.admin {
-        return ContractError::NotAuthorizedError {
-            message: "only the contract admin may change the admin".to_string(),
-        }
-        .to_err();
-    }
+    let admin_sender = contract_state.check_admin(&info.sender, "change the admin")?;
     let previous_admin_addr = contract_state.admin.to_owned();
+    let previous_admin_is_group = contract_state.admin_is_group;
     let new_admin_addr = deps.api.addr_validate(new_admin_address.as_str())?;
     contract_state.admin = new_admin_addr;
+    contract_state.admin_is_group = admin_is_group;
     set_contract_state_v1(deps.storage, &contract_state)?;
-    Response::new()
-        .add_attribute("action", "admin_update_admin")
-        .add_attribute("contract_address", env.contract.address.as_str())
-        .add_attribute("contract_type", CONTRACT_TYPE)
-        .add_attribute("contract_name", &contract_state.contract_name)
+    set_pending_admin(deps.storage, None)?;
+    let audit_entry = append_audit_entry(
+        deps.storage,
+        AuditEntry {
+            id: 0,
+            action: "admin_update_admin".to_string(),
+            actor: info.sender,
+            via_group_policy: admin_sender == AdminSender::GroupPolicy,
+            via_gov_authority: admin_sender == AdminSender::GovAuthority,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            details: vec![
+                format!(
+                    "changed admin from [{previous_admin_addr}] to [{}]",
+                    contract_state.admin
+                ),
+                format!(
+                    "changed admin_is_group from [{previous_admin_is_group}] to [{admin_is_group}]"
+                ),
+            ],
+        },
+    )?;
+    admin_response("admin_update_admin", &env, &contract_state, admin_sender)
         .add_attribute("previous_admin", previous_admin_addr.as_str())
         .add_attribute("new_admin", new_admin_address)
+        .add_attribute(
+            "previous_admin_is_group",
+            previous_admin_is_group.to_string(),
+        )
+        .add_attribute("new_admin_is_group", admin_is_group.to_string())
+        .add_attribute("audit_entry_id", audit_entry.id.to_string())
         .to_ok()
 }
 
 #[cfg(test)]
 mod tests {
     use crate::execute::admin_update_admin::admin_update_admin;
-    use crate::store::contract_state::CONTRACT_TYPE;
+    use crate::store::audit_log::get_audit_entries;
+    use crate::store::contract_state::{
+        get_contract_state_v1, set_contract_state_v1, CONTRACT_TYPE, CONTRACT_VERSION,
+    };
+    use crate::store::pending_admin::{get_pending_admin, set_pending_admin};
     use crate::test::attribute_extractor::AttributeExtractor;
     use crate::test::test_constants::{DEFAULT_ADMIN, DEFAULT_CONTRACT_NAME};
-    use crate::test::test_instantiate::test_instantiate;
+    use crate::test::test_instantiate::{test_instantiate, test_instantiate_with_msg};
     use crate::types::error::ContractError;
+    use crate::types::msg::InstantiateMsg;
     use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
     use cosmwasm_std::{coins, Addr};
     use provwasm_mocks::mock_provenance_dependencies;
@@ -66,6 +103,7 @@ mod tests {
             mock_env(),
             message_info(&Addr::unchecked(DEFAULT_ADMIN), &coins(10, "nhash")),
             "test".to_string(),
+            false,
         )
         .expect_err("an error should occur when funds are provided");
         assert!(
@@ -82,6 +120,7 @@ mod tests {
             mock_env(),
             message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
             "test".to_string(),
+            false,
         )
         .expect_err("an error should occur when the contract state is missing");
         assert!(
@@ -94,13 +133,16 @@ mod tests {
     fn successful_input_should_derive_a_response() {
         let mut deps = mock_provenance_dependencies();
         deps.api = deps.api.with_prefix("tp");
-        test_instantiate(deps.as_mut());
+        test_instantiate(&mut deps);
         let new_admin = "tp1adaaddt7r2agqfje9f8ysu8d5v85kqrv3qdeyn".to_string();
+        set_pending_admin(&mut deps.storage, Some(&Addr::unchecked("proposed-admin")))
+            .expect("the pending admin should be stored");
         let response = admin_update_admin(
             deps.as_mut(),
             mock_env(),
             message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
             new_admin.to_owned(),
+            true,
         )
         .expect("proper input on an instantiated contract should derive a successful response");
         assert!(
@@ -108,15 +150,112 @@ mod tests {
             "no messages should be emitted in the response"
         );
         assert_eq!(
-            6,
+            10,
             response.attributes.len(),
-            "six attributes should be emitted in the response"
+            "ten attributes should be emitted in the response"
         );
         response.assert_attribute("action", "admin_update_admin");
         response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
         response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
         response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
         response.assert_attribute("previous_admin", DEFAULT_ADMIN);
-        response.assert_attribute("new_admin", new_admin);
+        response.assert_attribute("new_admin", &new_admin);
+        response.assert_attribute("previous_admin_is_group", "false");
+        response.assert_attribute("new_admin_is_group", "true");
+        response.assert_attribute("audit_entry_id", "1");
+        let contract_state = get_contract_state_v1(deps.as_ref().storage)
+            .expect("contract state should load after the update");
+        assert_eq!(
+            new_admin.as_str(),
+            contract_state.admin.as_str(),
+            "the new admin should be persisted to the contract state",
+        );
+        assert!(
+            contract_state.admin_is_group,
+            "the group flag should be persisted to the contract state",
+        );
+        assert!(
+            get_pending_admin(deps.as_ref().storage)
+                .expect("the pending admin should load after the update")
+                .is_none(),
+            "the pending admin proposal should be discarded",
+        );
+    }
+
+    #[test]
+    fn group_member_sender_should_be_directed_to_the_group_policy() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let mut contract_state = get_contract_state_v1(deps.as_ref().storage)
+            .expect("contract state should load after instantiation");
+        contract_state.admin_is_group = true;
+        set_contract_state_v1(deps.as_mut().storage, &contract_state)
+            .expect("contract state should be stored");
+        let error = admin_update_admin(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("group-member"), &[]),
+            "test".to_string(),
+            false,
+        )
+        .expect_err("an error should occur when a group member executes the route directly");
+        match error {
+            ContractError::NotAuthorizedError { message } => assert!(
+                message.contains(&format!("group policy [{DEFAULT_ADMIN}]")),
+                "the error should name the group policy address, but got: {message}",
+            ),
+            e => panic!("unexpected error encountered: {e:?}"),
+        };
+    }
+
+    #[test]
+    fn gov_authority_sender_should_be_able_to_replace_the_admin() {
+        let mut deps = mock_provenance_dependencies();
+        let gov_authority = deps.api.addr_make("gov");
+        let new_admin = deps.api.addr_make("new-admin");
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                gov_authority: Some(gov_authority.to_string()),
+                ..InstantiateMsg::default()
+            },
+        );
+        let response = admin_update_admin(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&gov_authority, &[]),
+            new_admin.to_string(),
+            false,
+        )
+        .expect("the gov authority should be able to replace a lost admin");
+        response.assert_attribute("gov_authority_override", "true");
+        response.assert_attribute("previous_admin", DEFAULT_ADMIN);
+        response.assert_attribute("new_admin", new_admin.as_str());
+        let contract_state = get_contract_state_v1(deps.as_ref().storage)
+            .expect("contract state should load after the update");
+        assert_eq!(
+            new_admin, contract_state.admin,
+            "the new admin should be persisted to the contract state",
+        );
+        assert_eq!(
+            Some(gov_authority.to_owned()),
+            contract_state.gov_authority,
+            "replacing the admin should not affect the gov authority",
+        );
+        let audit_entries = get_audit_entries(&deps.storage).expect("audit entries should load");
+        assert_eq!(
+            1,
+            audit_entries.len(),
+            "a single audit entry should be recorded",
+        );
+        assert_eq!(
+            gov_authority, audit_entries[0].actor,
+            "the gov authority should be recorded as the actor",
+        );
+        assert!(
+            audit_entries[0].via_gov_authority,
+            "the audit entry should record the gov authority override",
+        );
     }
 }
@@ -0,0 +1,232 @@
+use crate::store::audit_log::{append_audit_entry, AuditEntry};
+use crate::store::contract_state::{get_contract_state_v1, set_contract_state_v1, AdminSender};
+use crate::types::error::ContractError;
+use crate::util::response_utils::admin_response;
+use crate::util::validation_utils::check_funds_are_empty;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execute functionality.  This function will only accept the request if
+/// the sender is the registered contract admin in the [contract state](crate::store::contract_state::ContractStateV1).
+/// The function replaces the attribute names required by both the [fund_trading](crate::execute::fund_trading::fund_trading)
+/// and [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading) execution routes in
+/// a single state save, so that both routes always enforce the same rotation of attributes.  Every
+/// rotation is recorded in the [audit log](crate::store::audit_log) as a single entry.
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `deposit_attributes` The new attributes that will be set in the contract state's
+/// [required_deposit_attributes](crate::store::contract_state::ContractStateV1#required_deposit_attributes)
+/// property upon successful execution.
+/// * `withdraw_attributes` The new attributes that will be set in the contract state's
+/// [required_withdraw_attributes](crate::store::contract_state::ContractStateV1#required_withdraw_attributes)
+/// property upon successful execution.
+pub fn admin_update_all_required_attributes(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    deposit_attributes: Vec<String>,
+    withdraw_attributes: Vec<String>,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    let mut contract_state = get_contract_state_v1(deps.storage)?;
+    let admin_sender = contract_state.check_admin(&info.sender, "update attributes")?;
+    let previous_deposit_attributes = contract_state.required_deposit_attributes.clone();
+    let previous_withdraw_attributes = contract_state.required_withdraw_attributes.clone();
+    contract_state.required_deposit_attributes = deposit_attributes;
+    contract_state.required_withdraw_attributes = withdraw_attributes;
+    set_contract_state_v1(deps.storage, &contract_state)?;
+    let audit_entry = append_audit_entry(
+        deps.storage,
+        AuditEntry {
+            id: 0,
+            action: "admin_update_all_required_attributes".to_string(),
+            actor: info.sender,
+            via_group_policy: admin_sender == AdminSender::GroupPolicy,
+            via_gov_authority: admin_sender == AdminSender::GovAuthority,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            details: vec![
+                format!(
+                    "changed required deposit attributes from [{}] to [{}]",
+                    previous_deposit_attributes.join(","),
+                    contract_state.required_deposit_attributes.join(","),
+                ),
+                format!(
+                    "changed required withdraw attributes from [{}] to [{}]",
+                    previous_withdraw_attributes.join(","),
+                    contract_state.required_withdraw_attributes.join(","),
+                ),
+            ],
+        },
+    )?;
+    admin_response(
+        "admin_update_all_required_attributes",
+        &env,
+        &contract_state,
+        admin_sender,
+    )
+    .add_attribute(
+        "previous_deposit_attributes",
+        format!("[{}]", previous_deposit_attributes.join(",")),
+    )
+    .add_attribute(
+        "new_deposit_attributes",
+        format!("[{}]", contract_state.required_deposit_attributes.join(",")),
+    )
+    .add_attribute(
+        "previous_withdraw_attributes",
+        format!("[{}]", previous_withdraw_attributes.join(",")),
+    )
+    .add_attribute(
+        "new_withdraw_attributes",
+        format!(
+            "[{}]",
+            contract_state.required_withdraw_attributes.join(",")
+        ),
+    )
+    .add_attribute("audit_entry_id", audit_entry.id.to_string())
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::admin_update_all_required_attributes::admin_update_all_required_attributes;
+    use crate::store::audit_log::get_audit_entries;
+    use crate::store::contract_state::{get_contract_state_v1, CONTRACT_TYPE, CONTRACT_VERSION};
+    use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_constants::{
+        DEFAULT_ADMIN, DEFAULT_CONTRACT_NAME, DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE,
+        DEFAULT_REQUIRED_WITHDRAW_ATTRIBUTE,
+    };
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::error::ContractError;
+    use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coins, Addr};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn provided_funds_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_update_all_required_attributes(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &coins(10, "nhash")),
+            vec!["deposit.pb".to_string()],
+            vec!["withdraw.pb".to_string()],
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(&error, ContractError::InvalidFundsError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn missing_contract_state_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_update_all_required_attributes(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            vec!["deposit.pb".to_string()],
+            vec!["withdraw.pb".to_string()],
+        )
+        .expect_err("an error should occur when the contract state is missing");
+        assert!(
+            matches!(&error, ContractError::StorageError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn non_admin_sender_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let error = admin_update_all_required_attributes(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("not-the-admin"), &[]),
+            vec!["deposit.pb".to_string()],
+            vec!["withdraw.pb".to_string()],
+        )
+        .expect_err("an error should occur when the sender is not the admin");
+        assert!(
+            matches!(&error, ContractError::NotAuthorizedError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn successful_input_should_rotate_both_lists_together() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let response = admin_update_all_required_attributes(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            vec!["kyc.deposit.pb".to_string(), "aml.pb".to_string()],
+            vec![],
+        )
+        .expect("proper input on an instantiated contract should derive a successful response");
+        assert!(
+            response.messages.is_empty(),
+            "no messages should be emitted in the response",
+        );
+        assert_eq!(
+            10,
+            response.attributes.len(),
+            "ten attributes should be emitted in the response",
+        );
+        response.assert_attribute("action", "admin_update_all_required_attributes");
+        response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+        response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
+        response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
+        response.assert_attribute(
+            "previous_deposit_attributes",
+            format!("[{DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE}]"),
+        );
+        response.assert_attribute("new_deposit_attributes", "[kyc.deposit.pb,aml.pb]");
+        response.assert_attribute(
+            "previous_withdraw_attributes",
+            format!("[{DEFAULT_REQUIRED_WITHDRAW_ATTRIBUTE}]"),
+        );
+        response.assert_attribute("new_withdraw_attributes", "[]");
+        response.assert_attribute("audit_entry_id", "1");
+        let contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        assert_eq!(
+            vec!["kyc.deposit.pb".to_string(), "aml.pb".to_string()],
+            contract_state.required_deposit_attributes,
+            "the new deposit attributes should be persisted",
+        );
+        assert!(
+            contract_state.required_withdraw_attributes.is_empty(),
+            "the new withdraw attributes should be persisted",
+        );
+        let audit_entries = get_audit_entries(&deps.storage).expect("audit entries should load");
+        assert_eq!(
+            1,
+            audit_entries.len(),
+            "the rotation should be recorded as a single audit entry",
+        );
+        assert_eq!(
+            vec![
+                format!(
+                    "changed required deposit attributes from [{DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE}] to [kyc.deposit.pb,aml.pb]"
+                ),
+                format!(
+                    "changed required withdraw attributes from [{DEFAULT_REQUIRED_WITHDRAW_ATTRIBUTE}] to []"
+                ),
+            ],
+            audit_entries[0].details,
+            "the audit entry should describe both lists",
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use crate::store::audit_log::{append_audit_entry, AuditEntry};
+use crate::store::contract_state::{get_contract_state_v1, set_contract_state_v1, AdminSender};
+use crate::types::error::ContractError;
+use crate::util::response_utils::admin_response;
+use crate::util::validation_utils::check_funds_are_empty;
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execute functionality.  This function will only accept the request if
+/// the sender is the registered contract admin in the [contract state](crate::store::contract_state::ContractStateV1).
+/// The function sets the compliance contract that must approve every trade accepted by the
+/// [fund_trading](crate::execute::fund_trading::fund_trading) and [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading)
+/// execution routes.  Trades that are already pending are checked against the new value when they
+/// are approved.  The change is recorded in the [audit log](crate::store::audit_log).
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `compliance_contract` The bech32 address that will be set in the contract state's
+/// [compliance_contract](crate::store::contract_state::ContractStateV1#compliance_contract)
+/// property upon successful execution.  `None` removes the compliance check.
+pub fn admin_update_compliance_contract(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    compliance_contract: Option<String>,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    let mut contract_state = get_contract_state_v1(deps.storage)?;
+    let admin_sender =
+        contract_state.check_admin(&info.sender, "update the compliance contract")?;
+    let previous_value = contract_state.compliance_contract;
+    contract_state.compliance_contract = compliance_contract
+        .as_ref()
+        .map(|compliance_contract| deps.api.addr_validate(compliance_contract))
+        .transpose()?;
+    set_contract_state_v1(deps.storage, &contract_state)?;
+    let audit_entry = append_audit_entry(
+        deps.storage,
+        AuditEntry {
+            id: 0,
+            action: "admin_update_compliance_contract".to_string(),
+            actor: info.sender,
+            via_group_policy: admin_sender == AdminSender::GroupPolicy,
+            via_gov_authority: admin_sender == AdminSender::GovAuthority,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            details: vec![format!(
+                "changed compliance_contract from [{}] to [{}]",
+                compliance_contract_attribute(&previous_value),
+                compliance_contract_attribute(&contract_state.compliance_contract)
+            )],
+        },
+    )?;
+    admin_response(
+        "admin_update_compliance_contract",
+        &env,
+        &contract_state,
+        admin_sender,
+    )
+    .add_attribute(
+        "previous_compliance_contract",
+        compliance_contract_attribute(&previous_value),
+    )
+    .add_attribute(
+        "new_compliance_contract",
+        compliance_contract_attribute(&contract_state.compliance_contract),
+    )
+    .add_attribute("audit_entry_id", audit_entry.id.to_string())
+    .to_ok()
+}
+
+fn compliance_contract_attribute(compliance_contract: &Option<Addr>) -> String {
+    compliance_contract.as_ref().map_or_else(
+        || "none".to_string(),
+        |compliance_contract| compliance_contract.to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::admin_update_compliance_contract::admin_update_compliance_contract;
+    use crate::store::contract_state::{get_contract_state_v1, CONTRACT_TYPE, CONTRACT_VERSION};
+    use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_constants::{DEFAULT_ADMIN, DEFAULT_CONTRACT_NAME};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::error::ContractError;
+    use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coins, Addr};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn provided_funds_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let compliance_contract = deps.api.addr_make("compliance");
+        let error = admin_update_compliance_contract(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &coins(10, "nhash")),
+            Some(compliance_contract.to_string()),
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(&error, ContractError::InvalidFundsError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn missing_contract_state_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let compliance_contract = deps.api.addr_make("compliance");
+        let error = admin_update_compliance_contract(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Some(compliance_contract.to_string()),
+        )
+        .expect_err("an error should occur when the contract state is missing");
+        assert!(
+            matches!(&error, ContractError::StorageError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn non_admin_sender_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let compliance_contract = deps.api.addr_make("compliance");
+        let error = admin_update_compliance_contract(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("not-the-admin"), &[]),
+            Some(compliance_contract.to_string()),
+        )
+        .expect_err("an error should occur when the sender is not the admin");
+        assert!(
+            matches!(&error, ContractError::NotAuthorizedError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn invalid_address_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let error = admin_update_compliance_contract(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Some("not-a-bech32-address".to_string()),
+        )
+        .expect_err("an error should occur when the compliance contract is not a valid address");
+        assert!(
+            matches!(&error, ContractError::Std(_)),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn successful_input_should_derive_a_response() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let compliance_contract = deps.api.addr_make("compliance");
+        let response = admin_update_compliance_contract(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Some(compliance_contract.to_string()),
+        )
+        .expect("proper input on an instantiated contract should derive a successful response");
+        assert!(
+            response.messages.is_empty(),
+            "no messages should be emitted in the response",
+        );
+        assert_eq!(
+            8,
+            response.attributes.len(),
+            "eight attributes should be emitted in the response",
+        );
+        response.assert_attribute("action", "admin_update_compliance_contract");
+        response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+        response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
+        response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
+        response.assert_attribute("previous_compliance_contract", "none");
+        response.assert_attribute("new_compliance_contract", compliance_contract.as_str());
+        response.assert_attribute("audit_entry_id", "1");
+        assert_eq!(
+            Some(compliance_contract.to_owned()),
+            get_contract_state_v1(deps.as_ref().storage)
+                .expect("contract state should load after the update")
+                .compliance_contract,
+            "the new value should be persisted to the contract state",
+        );
+        let response = admin_update_compliance_contract(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            None,
+        )
+        .expect("removing the compliance check should derive a successful response");
+        response.assert_attribute("previous_compliance_contract", compliance_contract.as_str());
+        response.assert_attribute("new_compliance_contract", "none");
+        assert!(
+            get_contract_state_v1(deps.as_ref().storage)
+                .expect("contract state should load after the update")
+                .compliance_contract
+                .is_none(),
+            "the compliance check should be removed from the contract state",
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use crate::store::audit_log::{append_audit_entry, AuditEntry};
+use crate::store::contract_state::{get_contract_state_v1, set_contract_state_v1, AdminSender};
+use crate::types::error::ContractError;
+use crate::util::response_utils::admin_response;
+use crate::util::validation_utils::check_funds_are_empty;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execute functionality.  This function will only accept the request if
+/// the sender is the registered contract admin in the [contract state](crate::store::contract_state::ContractStateV1).
+/// The function sets the largest amount of deposit denom that the [fund_trading](crate::execute::fund_trading::fund_trading)
+/// execution route accepts during a single UTC day.  Volume already accepted during the current
+/// day counts toward the new cap.
+/// The change is recorded in the [audit log](crate::store::audit_log).
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `daily_cap` The new value that will be set in the contract state's
+/// [daily_cap](crate::store::contract_state::ContractStateV1#daily_cap)
+/// property upon successful execution.  `None` removes the cap.
+pub fn admin_update_daily_cap(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    daily_cap: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    let mut contract_state = get_contract_state_v1(deps.storage)?;
+    let admin_sender = contract_state.check_admin(&info.sender, "update the daily cap")?;
+    let previous_value = contract_state.daily_cap;
+    contract_state.daily_cap = daily_cap;
+    set_contract_state_v1(deps.storage, &contract_state)?;
+    let audit_entry = append_audit_entry(
+        deps.storage,
+        AuditEntry {
+            id: 0,
+            action: "admin_update_daily_cap".to_string(),
+            actor: info.sender,
+            via_group_policy: admin_sender == AdminSender::GroupPolicy,
+            via_gov_authority: admin_sender == AdminSender::GovAuthority,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            details: vec![format!(
+                "changed daily_cap from [{}] to [{}]",
+                cap_attribute(previous_value),
+                cap_attribute(daily_cap)
+            )],
+        },
+    )?;
+    admin_response(
+        "admin_update_daily_cap",
+        &env,
+        &contract_state,
+        admin_sender,
+    )
+    .add_attribute("previous_daily_cap", cap_attribute(previous_value))
+    .add_attribute("new_daily_cap", cap_attribute(daily_cap))
+    .add_attribute("audit_entry_id", audit_entry.id.to_string())
+    .to_ok()
+}
+
+fn cap_attribute(cap: Option<Uint128>) -> String {
+    cap.map_or_else(|| "none".to_string(), |cap| cap.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::admin_update_daily_cap::admin_update_daily_cap;
+    use crate::store::contract_state::{get_contract_state_v1, CONTRACT_TYPE, CONTRACT_VERSION};
+    use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_constants::{DEFAULT_ADMIN, DEFAULT_CONTRACT_NAME};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::error::ContractError;
+    use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coins, Addr, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn provided_funds_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_update_daily_cap(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &coins(10, "nhash")),
+            Some(Uint128::new(1000)),
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(&error, ContractError::InvalidFundsError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn missing_contract_state_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_update_daily_cap(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Some(Uint128::new(1000)),
+        )
+        .expect_err("an error should occur when the contract state is missing");
+        assert!(
+            matches!(&error, ContractError::StorageError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn non_admin_sender_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let error = admin_update_daily_cap(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("not-the-admin"), &[]),
+            Some(Uint128::new(1000)),
+        )
+        .expect_err("an error should occur when the sender is not the admin");
+        assert!(
+            matches!(&error, ContractError::NotAuthorizedError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn successful_input_should_derive_a_response() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let response = admin_update_daily_cap(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Some(Uint128::new(1000)),
+        )
+        .expect("proper input on an instantiated contract should derive a successful response");
+        assert!(
+            response.messages.is_empty(),
+            "no messages should be emitted in the response",
+        );
+        assert_eq!(
+            8,
+            response.attributes.len(),
+            "eight attributes should be emitted in the response",
+        );
+        response.assert_attribute("action", "admin_update_daily_cap");
+        response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+        response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
+        response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
+        response.assert_attribute("previous_daily_cap", "none");
+        response.assert_attribute("new_daily_cap", "1000");
+        response.assert_attribute("audit_entry_id", "1");
+        assert_eq!(
+            Some(Uint128::new(1000)),
+            get_contract_state_v1(deps.as_ref().storage)
+                .expect("contract state should load after the update")
+                .daily_cap,
+            "the new value should be persisted to the contract state",
+        );
+        let response = admin_update_daily_cap(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            None,
+        )
+        .expect("removing the cap should derive a successful response");
+        response.assert_attribute("previous_daily_cap", "1000");
+        response.assert_attribute("new_daily_cap", "none");
+        assert!(
+            get_contract_state_v1(deps.as_ref().storage)
+                .expect("contract state should load after the update")
+                .daily_cap
+                .is_none(),
+            "the cap should be removed from the contract state",
+        );
+    }
+}
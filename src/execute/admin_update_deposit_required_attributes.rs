@@ -1,5 +1,7 @@
-use crate::store::contract_state::{get_contract_state_v1, set_contract_state_v1, CONTRACT_TYPE};
+use crate::store::audit_log::{append_audit_entry, AuditEntry};
+use crate::store::contract_state::{get_contract_state_v1, set_contract_state_v1, AdminSender};
 use crate::types::error::ContractError;
+use crate::util::response_utils::admin_response;
 use crate::util::validation_utils::check_funds_are_empty;
 use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
 use result_extensions::ResultExtensions;
@@ -8,7 +10,9 @@ use result_extensions::ResultExtensions;
 /// the sender is the registered contract admin in the [contract state](crate::store::contract_state::ContractStateV1).
 /// The function sets a new collection of attribute names required when an account deposits their
 /// deposit denom into the contract via the [fund_trading](crate::execute::fund_trading::fund_trading)
-/// execution route.
+/// execution route.  Message validation only accepts an empty collection when the clear is explicitly
+/// confirmed, so an empty collection is recorded in the [audit log](crate::store::audit_log) as an
+/// intentional clear.
 ///
 /// # Parameters
 /// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
@@ -28,41 +32,59 @@ pub fn admin_update_deposit_required_attributes(
 ) -> Result<Response, ContractError> {
     check_funds_are_empty(&info)?;
     let mut contract_state = get_contract_state_v1(deps.storage)?;
-    if info.sender != contract_state.admin {
-        return ContractError::NotAuthorizedError {
-            message: "only the contract admin may update attributes".to_string(),
-        }
-        .to_err();
-    }
+    let admin_sender = contract_state.check_admin(&info.sender, "update attributes")?;
     let previous_attributes = contract_state.required_deposit_attributes.clone();
     contract_state.required_deposit_attributes = attributes;
     set_contract_state_v1(deps.storage, &contract_state)?;
-    Response::new()
-        .add_attribute("action", "admin_update_deposit_required_attributes")
-        .add_attribute("contract_address", env.contract.address.as_str())
-        .add_attribute("contract_type", CONTRACT_TYPE)
-        .add_attribute("contract_name", &contract_state.contract_name)
-        .add_attribute(
-            "previous_attributes",
-            format!("[{}]", previous_attributes.join(",").as_str()),
-        )
-        .add_attribute(
-            "new_attributes",
-            format!(
-                "[{}]",
-                contract_state
-                    .required_deposit_attributes
-                    .join(",")
-                    .as_str()
-            ),
-        )
-        .to_ok()
+    let response = admin_response(
+        "admin_update_deposit_required_attributes",
+        &env,
+        &contract_state,
+        admin_sender,
+    )
+    .add_attribute(
+        "previous_attributes",
+        format!("[{}]", previous_attributes.join(",").as_str()),
+    )
+    .add_attribute(
+        "new_attributes",
+        format!(
+            "[{}]",
+            contract_state
+                .required_deposit_attributes
+                .join(",")
+                .as_str()
+        ),
+    );
+    if contract_state.required_deposit_attributes.is_empty() {
+        let audit_entry = append_audit_entry(
+            deps.storage,
+            AuditEntry {
+                id: 0,
+                action: "admin_update_deposit_required_attributes".to_string(),
+                actor: info.sender,
+                via_group_policy: admin_sender == AdminSender::GroupPolicy,
+                via_gov_authority: admin_sender == AdminSender::GovAuthority,
+                timestamp: env.block.time,
+                block_height: env.block.height,
+                details: vec![format!(
+                    "intentionally cleared required deposit attributes [{}]",
+                    previous_attributes.join(","),
+                )],
+            },
+        )?;
+        response.add_attribute("audit_entry_id", audit_entry.id.to_string())
+    } else {
+        response
+    }
+    .to_ok()
 }
 
 #[cfg(test)]
 mod tests {
     use crate::execute::admin_update_deposit_required_attributes::admin_update_deposit_required_attributes;
-    use crate::store::contract_state::CONTRACT_TYPE;
+    use crate::store::audit_log::get_audit_entries;
+    use crate::store::contract_state::{CONTRACT_TYPE, CONTRACT_VERSION};
     use crate::test::attribute_extractor::AttributeExtractor;
     use crate::test::test_constants::{DEFAULT_ADMIN, DEFAULT_CONTRACT_NAME};
     use crate::test::test_instantiate::test_instantiate_with_msg;
@@ -148,9 +170,10 @@ mod tests {
         expected_new_attributes_attr_value: S3,
     ) {
         let test_name = test_name.into();
+        let clears_attributes = new_attributes.is_empty();
         let mut deps = mock_provenance_dependencies();
         test_instantiate_with_msg(
-            deps.as_mut(),
+            &mut deps,
             InstantiateMsg {
                 required_deposit_attributes: previous_attributes.to_vec(),
                 ..InstantiateMsg::default()
@@ -174,9 +197,9 @@ mod tests {
             test_name,
         );
         assert_eq!(
-            6,
+            if clears_attributes { 8 } else { 7 },
             response.attributes.len(),
-            "{}: six attributes should be emitted in the response",
+            "{}: the correct number of attributes should be emitted in the response",
             test_name,
         );
         response.assert_attribute_with_message_prefix(
@@ -190,6 +213,11 @@ mod tests {
             &test_name,
         );
         response.assert_attribute_with_message_prefix("contract_type", CONTRACT_TYPE, &test_name);
+        response.assert_attribute_with_message_prefix(
+            "contract_version",
+            CONTRACT_VERSION,
+            &test_name,
+        );
         response.assert_attribute_with_message_prefix(
             "contract_name",
             DEFAULT_CONTRACT_NAME,
@@ -205,5 +233,30 @@ mod tests {
             expected_new_attributes_attr_value.into(),
             &test_name,
         );
+        let audit_entries = get_audit_entries(&deps.storage).expect("audit entries should load");
+        if clears_attributes {
+            response.assert_attribute_with_message_prefix("audit_entry_id", "1", &test_name);
+            assert_eq!(
+                1,
+                audit_entries.len(),
+                "{}: clearing the attributes should be recorded in the audit log",
+                test_name,
+            );
+            assert_eq!(
+                vec![format!(
+                    "intentionally cleared required deposit attributes [{}]",
+                    previous_attributes.join(","),
+                )],
+                audit_entries[0].details,
+                "{}: the audit entry should describe the cleared attributes",
+                test_name,
+            );
+        } else {
+            assert!(
+                audit_entries.is_empty(),
+                "{}: an update that does not clear the attributes should not be audited",
+                test_name,
+            );
+        }
     }
 }
@@ -0,0 +1,203 @@
+use crate::store::audit_log::{append_audit_entry, AuditEntry};
+use crate::store::contract_state::{get_contract_state_v1, set_contract_state_v1, AdminSender};
+use crate::types::error::ContractError;
+use crate::types::msg_fee::FeeDiscount;
+use crate::util::response_utils::admin_response;
+use crate::util::validation_utils::check_funds_are_empty;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execute functionality.  This function will only accept the request if
+/// the sender is the registered contract admin in the [contract state](crate::store::contract_state::ContractStateV1).
+/// The function replaces the discounts of the [msg fee](crate::store::contract_state::ContractStateV1#msg_fee)
+/// granted to senders of the [fund_trading](crate::execute::fund_trading::fund_trading) and
+/// [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading) routes that hold a
+/// designated attribute.  Trades that are already pending were assessed when they were submitted,
+/// and are unaffected.  Every change is recorded in the [audit log](crate::store::audit_log).
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `fee_discounts` The new value that will be set in the contract state's
+/// [fee_discounts](crate::store::contract_state::ContractStateV1#fee_discounts) property upon
+/// successful execution.
+pub fn admin_update_fee_discounts(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    fee_discounts: Vec<FeeDiscount>,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    let mut contract_state = get_contract_state_v1(deps.storage)?;
+    let admin_sender = contract_state.check_admin(&info.sender, "update the fee discounts")?;
+    let previous_value = fee_discounts_attribute(&contract_state.fee_discounts);
+    contract_state.fee_discounts = fee_discounts;
+    set_contract_state_v1(deps.storage, &contract_state)?;
+    let new_value = fee_discounts_attribute(&contract_state.fee_discounts);
+    let audit_entry = append_audit_entry(
+        deps.storage,
+        AuditEntry {
+            id: 0,
+            action: "admin_update_fee_discounts".to_string(),
+            actor: info.sender,
+            via_group_policy: admin_sender == AdminSender::GroupPolicy,
+            via_gov_authority: admin_sender == AdminSender::GovAuthority,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            details: vec![format!(
+                "changed fee discounts from {previous_value} to {new_value}"
+            )],
+        },
+    )?;
+    admin_response(
+        "admin_update_fee_discounts",
+        &env,
+        &contract_state,
+        admin_sender,
+    )
+    .add_attribute("previous_fee_discounts", previous_value)
+    .add_attribute("new_fee_discounts", new_value)
+    .add_attribute("audit_entry_id", audit_entry.id.to_string())
+    .to_ok()
+}
+
+fn fee_discounts_attribute(fee_discounts: &[FeeDiscount]) -> String {
+    format!(
+        "[{}]",
+        fee_discounts
+            .iter()
+            .map(|discount| format!("{}:{}", discount.attribute, discount.discount_bps_off))
+            .collect::<Vec<String>>()
+            .join(","),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::admin_update_fee_discounts::admin_update_fee_discounts;
+    use crate::store::audit_log::get_audit_entries;
+    use crate::store::contract_state::{get_contract_state_v1, CONTRACT_TYPE, CONTRACT_VERSION};
+    use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_constants::{DEFAULT_ADMIN, DEFAULT_CONTRACT_NAME};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::error::ContractError;
+    use crate::types::msg_fee::FeeDiscount;
+    use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coins, Addr};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    fn test_discounts() -> Vec<FeeDiscount> {
+        vec![
+            FeeDiscount {
+                attribute: "loyalty.gold.pb".to_string(),
+                discount_bps_off: 5000,
+            },
+            FeeDiscount {
+                attribute: "loyalty.platinum.pb".to_string(),
+                discount_bps_off: 10000,
+            },
+        ]
+    }
+
+    #[test]
+    fn provided_funds_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_update_fee_discounts(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &coins(10, "nhash")),
+            test_discounts(),
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(&error, ContractError::InvalidFundsError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn missing_contract_state_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_update_fee_discounts(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            test_discounts(),
+        )
+        .expect_err("an error should occur when the contract state is missing");
+        assert!(
+            matches!(&error, ContractError::StorageError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn non_admin_sender_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let error = admin_update_fee_discounts(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("not-the-admin"), &[]),
+            test_discounts(),
+        )
+        .expect_err("an error should occur when the sender is not the admin");
+        assert!(
+            matches!(&error, ContractError::NotAuthorizedError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn successful_input_should_derive_a_response() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let response = admin_update_fee_discounts(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            test_discounts(),
+        )
+        .expect("proper input on an instantiated contract should derive a successful response");
+        assert!(
+            response.messages.is_empty(),
+            "no messages should be emitted in the response",
+        );
+        assert_eq!(
+            8,
+            response.attributes.len(),
+            "eight attributes should be emitted in the response",
+        );
+        response.assert_attribute("action", "admin_update_fee_discounts");
+        response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+        response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
+        response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
+        response.assert_attribute("previous_fee_discounts", "[]");
+        response.assert_attribute(
+            "new_fee_discounts",
+            "[loyalty.gold.pb:5000,loyalty.platinum.pb:10000]",
+        );
+        response.assert_attribute("audit_entry_id", "1");
+        assert_eq!(
+            test_discounts(),
+            get_contract_state_v1(&deps.storage)
+                .expect("contract state should load")
+                .fee_discounts,
+            "the fee discounts should be persisted",
+        );
+        let audit_entries = get_audit_entries(&deps.storage).expect("audit entries should load");
+        assert_eq!(
+            vec![
+                "changed fee discounts from [] to [loyalty.gold.pb:5000,loyalty.platinum.pb:10000]"
+                    .to_string()
+            ],
+            audit_entries[0].details,
+            "the audit entry should describe the change",
+        );
+    }
+}
@@ -0,0 +1,305 @@
+use crate::store::audit_log::{append_audit_entry, AuditEntry};
+use crate::store::contract_state::{get_contract_state_v1, set_contract_state_v1, AdminSender};
+use crate::types::error::ContractError;
+use crate::util::response_utils::admin_response;
+use crate::util::validation_utils::check_funds_are_empty;
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execute functionality.  This function will only accept the request if
+/// the sender is the registered contract admin in the [contract state](crate::store::contract_state::ContractStateV1).
+/// The function sets the portion of the trading denom minted by the [fund_trading](crate::execute::fund_trading::fund_trading)
+/// route that is withheld from the sender, and the account that receives it.  The account also
+/// receives the [withdraw fee](crate::store::contract_state::ContractStateV1#withdraw_fee_bps),
+/// so it cannot be removed while a withdraw fee is set.  Trades that are already pending withhold
+/// the fee configured when they are approved.
+/// The change is recorded in the [audit log](crate::store::audit_log).
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `fund_fee_bps` The new value that will be set in the contract state's
+/// [fund_fee_bps](crate::store::contract_state::ContractStateV1#fund_fee_bps) property upon
+/// successful execution.  `None` removes the fee.
+/// * `fee_collector` The bech32 address that will be set in the contract state's
+/// [fee_collector](crate::store::contract_state::ContractStateV1#fee_collector) property upon
+/// successful execution.
+pub fn admin_update_fund_fee(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    fund_fee_bps: Option<u16>,
+    fee_collector: Option<String>,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    let mut contract_state = get_contract_state_v1(deps.storage)?;
+    let admin_sender = contract_state.check_admin(&info.sender, "update the fund fee")?;
+    let fee_collector = fee_collector
+        .as_ref()
+        .map(|fee_collector| deps.api.addr_validate(fee_collector))
+        .transpose()?;
+    if fee_collector.is_none()
+        && contract_state
+            .withdraw_fee_bps
+            .is_some_and(|withdraw_fee_bps| withdraw_fee_bps > 0)
+    {
+        return ContractError::ValidationError {
+            message: "the fee collector cannot be removed while a withdraw fee is set".to_string(),
+        }
+        .to_err();
+    }
+    let previous_fund_fee_bps = contract_state.fund_fee_bps;
+    let previous_fee_collector = contract_state.fee_collector;
+    contract_state.fund_fee_bps = fund_fee_bps;
+    contract_state.fee_collector = fee_collector;
+    set_contract_state_v1(deps.storage, &contract_state)?;
+    let audit_entry = append_audit_entry(
+        deps.storage,
+        AuditEntry {
+            id: 0,
+            action: "admin_update_fund_fee".to_string(),
+            actor: info.sender,
+            via_group_policy: admin_sender == AdminSender::GroupPolicy,
+            via_gov_authority: admin_sender == AdminSender::GovAuthority,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            details: vec![
+                format!(
+                    "changed fund_fee_bps from [{}] to [{}]",
+                    fund_fee_bps_attribute(previous_fund_fee_bps),
+                    fund_fee_bps_attribute(contract_state.fund_fee_bps)
+                ),
+                format!(
+                    "changed fee_collector from [{}] to [{}]",
+                    fee_collector_attribute(&previous_fee_collector),
+                    fee_collector_attribute(&contract_state.fee_collector)
+                ),
+            ],
+        },
+    )?;
+    admin_response("admin_update_fund_fee", &env, &contract_state, admin_sender)
+        .add_attribute(
+            "previous_fund_fee_bps",
+            fund_fee_bps_attribute(previous_fund_fee_bps),
+        )
+        .add_attribute(
+            "new_fund_fee_bps",
+            fund_fee_bps_attribute(contract_state.fund_fee_bps),
+        )
+        .add_attribute(
+            "previous_fee_collector",
+            fee_collector_attribute(&previous_fee_collector),
+        )
+        .add_attribute(
+            "new_fee_collector",
+            fee_collector_attribute(&contract_state.fee_collector),
+        )
+        .add_attribute("audit_entry_id", audit_entry.id.to_string())
+        .to_ok()
+}
+
+fn fund_fee_bps_attribute(fund_fee_bps: Option<u16>) -> String {
+    fund_fee_bps.map_or_else(
+        || "none".to_string(),
+        |fund_fee_bps| fund_fee_bps.to_string(),
+    )
+}
+
+fn fee_collector_attribute(fee_collector: &Option<Addr>) -> String {
+    fee_collector.as_ref().map_or_else(
+        || "none".to_string(),
+        |fee_collector| fee_collector.to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::admin_update_fund_fee::admin_update_fund_fee;
+    use crate::store::contract_state::{get_contract_state_v1, CONTRACT_TYPE, CONTRACT_VERSION};
+    use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_constants::{DEFAULT_ADMIN, DEFAULT_CONTRACT_NAME};
+    use crate::test::test_instantiate::{test_instantiate, test_instantiate_with_msg};
+    use crate::types::error::ContractError;
+    use crate::types::msg::InstantiateMsg;
+    use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coins, Addr};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn provided_funds_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let collector = deps.api.addr_make("collector");
+        let error = admin_update_fund_fee(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &coins(10, "nhash")),
+            Some(25),
+            Some(collector.to_string()),
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(&error, ContractError::InvalidFundsError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn missing_contract_state_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let collector = deps.api.addr_make("collector");
+        let error = admin_update_fund_fee(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Some(25),
+            Some(collector.to_string()),
+        )
+        .expect_err("an error should occur when the contract state is missing");
+        assert!(
+            matches!(&error, ContractError::StorageError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn non_admin_sender_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let collector = deps.api.addr_make("collector");
+        let error = admin_update_fund_fee(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("not-the-admin"), &[]),
+            Some(25),
+            Some(collector.to_string()),
+        )
+        .expect_err("an error should occur when the sender is not the admin");
+        assert!(
+            matches!(&error, ContractError::NotAuthorizedError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn invalid_collector_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let error = admin_update_fund_fee(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Some(25),
+            Some("not-a-bech32-address".to_string()),
+        )
+        .expect_err("an error should occur when the fee collector is not a valid address");
+        assert!(
+            matches!(&error, ContractError::Std(_)),
+            "unexpected error encountered: {error:?}",
+        );
+        assert!(
+            get_contract_state_v1(deps.as_ref().storage)
+                .expect("contract state should load")
+                .fund_fee_bps
+                .is_none(),
+            "the rejected fee should not be persisted",
+        );
+    }
+
+    #[test]
+    fn removing_the_collector_of_a_withdraw_fee_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let collector = deps.api.addr_make("collector");
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                withdraw_fee_bps: Some(30),
+                fee_collector: Some(collector.to_string()),
+                ..InstantiateMsg::default()
+            },
+        );
+        let error = admin_update_fund_fee(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            None,
+            None,
+        )
+        .expect_err("an error should occur when the withdraw fee would lose its collector");
+        assert_eq!(
+            "validation failed: the fee collector cannot be removed while a withdraw fee is set",
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+        assert_eq!(
+            Some(collector),
+            get_contract_state_v1(deps.as_ref().storage)
+                .expect("contract state should load")
+                .fee_collector,
+            "the fee collector should be unchanged",
+        );
+    }
+
+    #[test]
+    fn successful_input_should_derive_a_response() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let collector = deps.api.addr_make("collector");
+        let response = admin_update_fund_fee(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Some(25),
+            Some(collector.to_string()),
+        )
+        .expect("proper input on an instantiated contract should derive a successful response");
+        assert!(
+            response.messages.is_empty(),
+            "no messages should be emitted in the response",
+        );
+        assert_eq!(
+            10,
+            response.attributes.len(),
+            "ten attributes should be emitted in the response",
+        );
+        response.assert_attribute("action", "admin_update_fund_fee");
+        response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+        response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
+        response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
+        response.assert_attribute("previous_fund_fee_bps", "none");
+        response.assert_attribute("new_fund_fee_bps", "25");
+        response.assert_attribute("previous_fee_collector", "none");
+        response.assert_attribute("new_fee_collector", collector.as_str());
+        response.assert_attribute("audit_entry_id", "1");
+        let contract_state = get_contract_state_v1(deps.as_ref().storage)
+            .expect("contract state should load after the update");
+        assert_eq!(
+            (Some(25), Some(collector.to_owned())),
+            (contract_state.fund_fee_bps, contract_state.fee_collector),
+            "the new values should be persisted to the contract state",
+        );
+        let response = admin_update_fund_fee(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            None,
+            None,
+        )
+        .expect("removing the fee should derive a successful response");
+        response.assert_attribute("previous_fund_fee_bps", "25");
+        response.assert_attribute("new_fund_fee_bps", "none");
+        response.assert_attribute("previous_fee_collector", collector.as_str());
+        response.assert_attribute("new_fee_collector", "none");
+        let contract_state = get_contract_state_v1(deps.as_ref().storage)
+            .expect("contract state should load after the update");
+        assert_eq!(
+            (None, None),
+            (contract_state.fund_fee_bps, contract_state.fee_collector),
+            "the fee should be removed from the contract state",
+        );
+    }
+}
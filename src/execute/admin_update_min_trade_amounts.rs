@@ -0,0 +1,262 @@
+use crate::store::audit_log::{append_audit_entry, AuditEntry};
+use crate::store::contract_state::{get_contract_state_v1, set_contract_state_v1, AdminSender};
+use crate::types::error::ContractError;
+use crate::types::quote::TradeDirection;
+use crate::util::response_utils::admin_response;
+use crate::util::validation_utils::{check_funds_are_empty, check_trade_amount_range};
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execute functionality.  This function will only accept the request if
+/// the sender is the registered contract admin in the [contract state](crate::store::contract_state::ContractStateV1).
+/// The function sets the smallest amounts that the [fund_trading](crate::execute::fund_trading::fund_trading)
+/// and [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading) execution routes
+/// accept.  Trades that are already pending are unaffected.
+/// The change is recorded in the [audit log](crate::store::audit_log).
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `min_fund_amount` The new value that will be set in the contract state's
+/// [min_fund_amount](crate::store::contract_state::ContractStateV1#min_fund_amount) property upon
+/// successful execution.  `None` removes the minimum.
+/// * `min_withdraw_amount` The new value that will be set in the contract state's
+/// [min_withdraw_amount](crate::store::contract_state::ContractStateV1#min_withdraw_amount)
+/// property upon successful execution.  `None` removes the minimum.
+pub fn admin_update_min_trade_amounts(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    min_fund_amount: Option<Uint128>,
+    min_withdraw_amount: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    let mut contract_state = get_contract_state_v1(deps.storage)?;
+    let admin_sender =
+        contract_state.check_admin(&info.sender, "update the minimum trade amounts")?;
+    check_trade_amount_range(
+        TradeDirection::Fund,
+        min_fund_amount,
+        contract_state.max_fund_amount,
+    )?;
+    check_trade_amount_range(
+        TradeDirection::Withdraw,
+        min_withdraw_amount,
+        contract_state.max_withdraw_amount,
+    )?;
+    let previous_min_fund_amount = contract_state.min_fund_amount;
+    let previous_min_withdraw_amount = contract_state.min_withdraw_amount;
+    contract_state.min_fund_amount = min_fund_amount;
+    contract_state.min_withdraw_amount = min_withdraw_amount;
+    set_contract_state_v1(deps.storage, &contract_state)?;
+    let audit_entry = append_audit_entry(
+        deps.storage,
+        AuditEntry {
+            id: 0,
+            action: "admin_update_min_trade_amounts".to_string(),
+            actor: info.sender,
+            via_group_policy: admin_sender == AdminSender::GroupPolicy,
+            via_gov_authority: admin_sender == AdminSender::GovAuthority,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            details: vec![
+                format!(
+                    "changed min_fund_amount from [{}] to [{}]",
+                    minimum_attribute(previous_min_fund_amount),
+                    minimum_attribute(min_fund_amount)
+                ),
+                format!(
+                    "changed min_withdraw_amount from [{}] to [{}]",
+                    minimum_attribute(previous_min_withdraw_amount),
+                    minimum_attribute(min_withdraw_amount)
+                ),
+            ],
+        },
+    )?;
+    admin_response(
+        "admin_update_min_trade_amounts",
+        &env,
+        &contract_state,
+        admin_sender,
+    )
+    .add_attribute(
+        "previous_min_fund_amount",
+        minimum_attribute(previous_min_fund_amount),
+    )
+    .add_attribute("new_min_fund_amount", minimum_attribute(min_fund_amount))
+    .add_attribute(
+        "previous_min_withdraw_amount",
+        minimum_attribute(previous_min_withdraw_amount),
+    )
+    .add_attribute(
+        "new_min_withdraw_amount",
+        minimum_attribute(min_withdraw_amount),
+    )
+    .add_attribute("audit_entry_id", audit_entry.id.to_string())
+    .to_ok()
+}
+
+fn minimum_attribute(minimum: Option<Uint128>) -> String {
+    minimum.map_or_else(|| "none".to_string(), |minimum| minimum.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::admin_update_max_trade_amounts::admin_update_max_trade_amounts;
+    use crate::execute::admin_update_min_trade_amounts::admin_update_min_trade_amounts;
+    use crate::store::contract_state::{get_contract_state_v1, CONTRACT_TYPE, CONTRACT_VERSION};
+    use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_constants::{DEFAULT_ADMIN, DEFAULT_CONTRACT_NAME};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::error::ContractError;
+    use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coins, Addr, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn provided_funds_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_update_min_trade_amounts(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &coins(10, "nhash")),
+            Some(Uint128::new(100)),
+            None,
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(&error, ContractError::InvalidFundsError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn missing_contract_state_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_update_min_trade_amounts(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Some(Uint128::new(100)),
+            None,
+        )
+        .expect_err("an error should occur when the contract state is missing");
+        assert!(
+            matches!(&error, ContractError::StorageError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn non_admin_sender_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let error = admin_update_min_trade_amounts(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("not-the-admin"), &[]),
+            Some(Uint128::new(100)),
+            None,
+        )
+        .expect_err("an error should occur when the sender is not the admin");
+        assert!(
+            matches!(&error, ContractError::NotAuthorizedError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn minimum_above_the_stored_maximum_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        admin_update_max_trade_amounts(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Some(Uint128::new(100)),
+            None,
+        )
+        .expect("setting a fund maximum should succeed");
+        let error = admin_update_min_trade_amounts(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Some(Uint128::new(101)),
+            None,
+        )
+        .expect_err("a minimum above the stored maximum should be rejected");
+        assert_eq!(
+            "validation failed: min_fund_amount [101] cannot exceed max_fund_amount [100]",
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+    }
+
+    #[test]
+    fn successful_input_should_derive_a_response() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let response = admin_update_min_trade_amounts(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Some(Uint128::new(100)),
+            Some(Uint128::new(1_000_000)),
+        )
+        .expect("proper input on an instantiated contract should derive a successful response");
+        assert!(
+            response.messages.is_empty(),
+            "no messages should be emitted in the response",
+        );
+        assert_eq!(
+            10,
+            response.attributes.len(),
+            "ten attributes should be emitted in the response",
+        );
+        response.assert_attribute("action", "admin_update_min_trade_amounts");
+        response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+        response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
+        response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
+        response.assert_attribute("previous_min_fund_amount", "none");
+        response.assert_attribute("new_min_fund_amount", "100");
+        response.assert_attribute("previous_min_withdraw_amount", "none");
+        response.assert_attribute("new_min_withdraw_amount", "1000000");
+        response.assert_attribute("audit_entry_id", "1");
+        let contract_state = get_contract_state_v1(deps.as_ref().storage)
+            .expect("contract state should load after the update");
+        assert_eq!(
+            Some(Uint128::new(100)),
+            contract_state.min_fund_amount,
+            "the new fund minimum should be persisted to the contract state",
+        );
+        assert_eq!(
+            Some(Uint128::new(1_000_000)),
+            contract_state.min_withdraw_amount,
+            "the new withdraw minimum should be persisted to the contract state",
+        );
+        let response = admin_update_min_trade_amounts(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            None,
+            None,
+        )
+        .expect("removing the minimums should derive a successful response");
+        response.assert_attribute("previous_min_fund_amount", "100");
+        response.assert_attribute("new_min_fund_amount", "none");
+        response.assert_attribute("previous_min_withdraw_amount", "1000000");
+        response.assert_attribute("new_min_withdraw_amount", "none");
+        let contract_state = get_contract_state_v1(deps.as_ref().storage)
+            .expect("contract state should load after the update");
+        assert!(
+            contract_state.min_fund_amount.is_none()
+                && contract_state.min_withdraw_amount.is_none(),
+            "the minimums should be removed from the contract state",
+        );
+    }
+}
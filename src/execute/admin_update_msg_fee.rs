@@ -0,0 +1,222 @@
+use crate::store::audit_log::{append_audit_entry, AuditEntry};
+use crate::store::contract_state::{get_contract_state_v1, set_contract_state_v1, AdminSender};
+use crate::types::error::ContractError;
+use crate::types::msg_fee::MsgFee;
+use crate::util::response_utils::admin_response;
+use crate::util::validation_utils::check_funds_are_empty;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execute functionality.  This function will only accept the request if
+/// the sender is the registered contract admin in the [contract state](crate::store::contract_state::ContractStateV1).
+/// The function sets the Provenance msg fee assessed on every trade accepted by the
+/// [fund_trading](crate::execute::fund_trading::fund_trading) and [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading)
+/// execution routes.  Trades that are already pending were assessed when they were submitted, and
+/// are unaffected.  The change is recorded in the [audit log](crate::store::audit_log).
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `msg_fee` The new value that will be set in the contract state's
+/// [msg_fee](crate::store::contract_state::ContractStateV1#msg_fee) property upon successful
+/// execution.  `None` removes the fee.
+pub fn admin_update_msg_fee(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg_fee: Option<MsgFee>,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    let mut contract_state = get_contract_state_v1(deps.storage)?;
+    let admin_sender = contract_state.check_admin(&info.sender, "update the msg fee")?;
+    if let Some(msg_fee) = &msg_fee {
+        deps.api.addr_validate(&msg_fee.recipient)?;
+    }
+    let previous_value = contract_state.msg_fee;
+    contract_state.msg_fee = msg_fee;
+    set_contract_state_v1(deps.storage, &contract_state)?;
+    let audit_entry = append_audit_entry(
+        deps.storage,
+        AuditEntry {
+            id: 0,
+            action: "admin_update_msg_fee".to_string(),
+            actor: info.sender,
+            via_group_policy: admin_sender == AdminSender::GroupPolicy,
+            via_gov_authority: admin_sender == AdminSender::GovAuthority,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            details: vec![format!(
+                "changed msg_fee from [{}] to [{}]",
+                msg_fee_attribute(&previous_value),
+                msg_fee_attribute(&contract_state.msg_fee)
+            )],
+        },
+    )?;
+    admin_response("admin_update_msg_fee", &env, &contract_state, admin_sender)
+        .add_attribute("previous_msg_fee", msg_fee_attribute(&previous_value))
+        .add_attribute("new_msg_fee", msg_fee_attribute(&contract_state.msg_fee))
+        .add_attribute("audit_entry_id", audit_entry.id.to_string())
+        .to_ok()
+}
+
+fn msg_fee_attribute(msg_fee: &Option<MsgFee>) -> String {
+    msg_fee.as_ref().map_or_else(
+        || "none".to_string(),
+        |msg_fee| format!("{} to {}", msg_fee.amount, msg_fee.recipient),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::admin_update_msg_fee::admin_update_msg_fee;
+    use crate::store::contract_state::{get_contract_state_v1, CONTRACT_TYPE, CONTRACT_VERSION};
+    use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_constants::{DEFAULT_ADMIN, DEFAULT_CONTRACT_NAME};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::error::ContractError;
+    use crate::types::msg_fee::MsgFee;
+    use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coin, coins, Addr};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    fn test_msg_fee(recipient: &Addr) -> MsgFee {
+        MsgFee {
+            amount: coin(100, "nhash"),
+            recipient: recipient.to_string(),
+        }
+    }
+
+    #[test]
+    fn provided_funds_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let recipient = deps.api.addr_make("recipient");
+        let error = admin_update_msg_fee(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &coins(10, "nhash")),
+            Some(test_msg_fee(&recipient)),
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(&error, ContractError::InvalidFundsError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn missing_contract_state_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let recipient = deps.api.addr_make("recipient");
+        let error = admin_update_msg_fee(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Some(test_msg_fee(&recipient)),
+        )
+        .expect_err("an error should occur when the contract state is missing");
+        assert!(
+            matches!(&error, ContractError::StorageError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn non_admin_sender_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let recipient = deps.api.addr_make("recipient");
+        let error = admin_update_msg_fee(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("not-the-admin"), &[]),
+            Some(test_msg_fee(&recipient)),
+        )
+        .expect_err("an error should occur when the sender is not the admin");
+        assert!(
+            matches!(&error, ContractError::NotAuthorizedError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn invalid_recipient_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let error = admin_update_msg_fee(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Some(test_msg_fee(&Addr::unchecked("not-a-bech32-address"))),
+        )
+        .expect_err("an error should occur when the fee recipient is not a valid address");
+        assert!(
+            matches!(&error, ContractError::Std(_)),
+            "unexpected error encountered: {error:?}",
+        );
+        assert!(
+            get_contract_state_v1(deps.as_ref().storage)
+                .expect("contract state should load")
+                .msg_fee
+                .is_none(),
+            "the rejected fee should not be persisted",
+        );
+    }
+
+    #[test]
+    fn successful_input_should_derive_a_response() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let recipient = deps.api.addr_make("recipient");
+        let response = admin_update_msg_fee(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Some(test_msg_fee(&recipient)),
+        )
+        .expect("proper input on an instantiated contract should derive a successful response");
+        assert!(
+            response.messages.is_empty(),
+            "no messages should be emitted in the response",
+        );
+        assert_eq!(
+            8,
+            response.attributes.len(),
+            "eight attributes should be emitted in the response",
+        );
+        response.assert_attribute("action", "admin_update_msg_fee");
+        response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+        response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
+        response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
+        response.assert_attribute("previous_msg_fee", "none");
+        response.assert_attribute("new_msg_fee", format!("100nhash to {recipient}"));
+        response.assert_attribute("audit_entry_id", "1");
+        assert_eq!(
+            Some(test_msg_fee(&recipient)),
+            get_contract_state_v1(deps.as_ref().storage)
+                .expect("contract state should load after the update")
+                .msg_fee,
+            "the new value should be persisted to the contract state",
+        );
+        let response = admin_update_msg_fee(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            None,
+        )
+        .expect("removing the fee should derive a successful response");
+        response.assert_attribute("previous_msg_fee", format!("100nhash to {recipient}"));
+        response.assert_attribute("new_msg_fee", "none");
+        assert!(
+            get_contract_state_v1(deps.as_ref().storage)
+                .expect("contract state should load after the update")
+                .msg_fee
+                .is_none(),
+            "the fee should be removed from the contract state",
+        );
+    }
+}
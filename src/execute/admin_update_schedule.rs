@@ -0,0 +1,289 @@
+use crate::store::audit_log::{append_audit_entry, AuditEntry};
+use crate::store::contract_state::{get_contract_state_v1, set_contract_state_v1, AdminSender};
+use crate::types::error::ContractError;
+use crate::types::schedule::ScheduledPoint;
+use crate::util::response_utils::admin_response;
+use crate::util::validation_utils::check_funds_are_empty;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execute functionality.  This function will only accept the request if
+/// the sender is the registered contract admin in the [contract state](crate::store::contract_state::ContractStateV1).
+/// The function replaces the trading schedule enforced by the [fund_trading](crate::execute::fund_trading::fund_trading)
+/// and [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading) execution routes.  A
+/// start that has already been reached can no longer be changed, and a new sunset cannot be
+/// scheduled at a point that has already been reached.  Every change is recorded in the
+/// [audit log](crate::store::audit_log).
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `trading_start` The value that will be set in the contract state's [trading_start](crate::store::contract_state::ContractStateV1#trading_start)
+/// property upon successful execution.  `None` removes the start.
+/// * `sunset_at` The value that will be set in the contract state's [sunset_at](crate::store::contract_state::ContractStateV1#sunset_at)
+/// property upon successful execution.  `None` removes the sunset.
+pub fn admin_update_schedule(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    trading_start: Option<ScheduledPoint>,
+    sunset_at: Option<ScheduledPoint>,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    let mut contract_state = get_contract_state_v1(deps.storage)?;
+    let admin_sender = contract_state.check_admin(&info.sender, "update the trading schedule")?;
+    if let Some(current_start) = &contract_state.trading_start {
+        if current_start.has_passed(&env.block) && trading_start.as_ref() != Some(current_start) {
+            return ContractError::ValidationError {
+                message: format!(
+                    "trading began at [{current_start}], so its start can no longer be changed"
+                ),
+            }
+            .to_err();
+        }
+    }
+    if let Some(new_sunset) = &sunset_at {
+        if contract_state.sunset_at.as_ref() != Some(new_sunset)
+            && new_sunset.has_passed(&env.block)
+        {
+            return ContractError::ValidationError {
+                message: format!("sunset_at [{new_sunset}] has already passed"),
+            }
+            .to_err();
+        }
+    }
+    let previous_start = schedule_attribute(&contract_state.trading_start);
+    let previous_sunset = schedule_attribute(&contract_state.sunset_at);
+    contract_state.trading_start = trading_start;
+    contract_state.sunset_at = sunset_at;
+    set_contract_state_v1(deps.storage, &contract_state)?;
+    let new_start = schedule_attribute(&contract_state.trading_start);
+    let new_sunset = schedule_attribute(&contract_state.sunset_at);
+    let audit_entry = append_audit_entry(
+        deps.storage,
+        AuditEntry {
+            id: 0,
+            action: "admin_update_schedule".to_string(),
+            actor: info.sender,
+            via_group_policy: admin_sender == AdminSender::GroupPolicy,
+            via_gov_authority: admin_sender == AdminSender::GovAuthority,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            details: vec![
+                format!("changed trading start from [{previous_start}] to [{new_start}]"),
+                format!("changed sunset from [{previous_sunset}] to [{new_sunset}]"),
+            ],
+        },
+    )?;
+    admin_response("admin_update_schedule", &env, &contract_state, admin_sender)
+        .add_attribute("previous_trading_start", previous_start)
+        .add_attribute("new_trading_start", new_start)
+        .add_attribute("previous_sunset_at", previous_sunset)
+        .add_attribute("new_sunset_at", new_sunset)
+        .add_attribute("audit_entry_id", audit_entry.id.to_string())
+        .to_ok()
+}
+
+fn schedule_attribute(point: &Option<ScheduledPoint>) -> String {
+    point
+        .as_ref()
+        .map_or_else(|| "none".to_string(), |point| point.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::admin_update_schedule::admin_update_schedule;
+    use crate::store::audit_log::get_audit_entries;
+    use crate::store::contract_state::{
+        get_contract_state_v1, set_contract_state_v1, CONTRACT_TYPE, CONTRACT_VERSION,
+    };
+    use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_constants::{DEFAULT_ADMIN, DEFAULT_CONTRACT_NAME};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::error::ContractError;
+    use crate::types::schedule::ScheduledPoint;
+    use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coins, Addr};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn provided_funds_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_update_schedule(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &coins(10, "nhash")),
+            None,
+            None,
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(&error, ContractError::InvalidFundsError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn missing_contract_state_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_update_schedule(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            None,
+            None,
+        )
+        .expect_err("an error should occur when the contract state is missing");
+        assert!(
+            matches!(&error, ContractError::StorageError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn non_admin_sender_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let error = admin_update_schedule(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("not-the-admin"), &[]),
+            None,
+            None,
+        )
+        .expect_err("an error should occur when the sender is not the admin");
+        assert!(
+            matches!(&error, ContractError::NotAuthorizedError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn start_that_has_passed_should_not_be_changed() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let env = mock_env();
+        let started_at = ScheduledPoint::Height(env.block.height - 10);
+        let mut contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        contract_state.trading_start = Some(started_at.to_owned());
+        set_contract_state_v1(&mut deps.storage, &contract_state)
+            .expect("contract state should be stored");
+        for trading_start in [None, Some(ScheduledPoint::Height(env.block.height + 10))] {
+            let error = admin_update_schedule(
+                deps.as_mut(),
+                env.to_owned(),
+                message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+                trading_start,
+                None,
+            )
+            .expect_err("an error should occur when a passed start is changed");
+            assert_eq!(
+                format!(
+                    "validation failed: trading began at [{started_at}], so its start can no longer be changed"
+                ),
+                error.to_string(),
+                "unexpected error message encountered",
+            );
+        }
+        admin_update_schedule(
+            deps.as_mut(),
+            env.to_owned(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Some(started_at),
+            Some(ScheduledPoint::Height(env.block.height + 10)),
+        )
+        .expect("a passed start should be accepted when it is unchanged");
+    }
+
+    #[test]
+    fn sunset_in_the_past_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let env = mock_env();
+        let error = admin_update_schedule(
+            deps.as_mut(),
+            env.to_owned(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            None,
+            Some(ScheduledPoint::Time(env.block.time)),
+        )
+        .expect_err("an error should occur when the sunset has already passed");
+        assert_eq!(
+            format!(
+                "validation failed: sunset_at [time {}] has already passed",
+                env.block.time
+            ),
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+        assert!(
+            get_contract_state_v1(&deps.storage)
+                .expect("contract state should load")
+                .sunset_at
+                .is_none(),
+            "the rejected sunset should not be stored",
+        );
+    }
+
+    #[test]
+    fn successful_input_should_derive_a_response() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let env = mock_env();
+        let trading_start = ScheduledPoint::Height(env.block.height + 10);
+        let sunset_at = ScheduledPoint::Height(env.block.height + 100);
+        let response = admin_update_schedule(
+            deps.as_mut(),
+            env.to_owned(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Some(trading_start.to_owned()),
+            Some(sunset_at.to_owned()),
+        )
+        .expect("proper input on an instantiated contract should derive a successful response");
+        assert!(
+            response.messages.is_empty(),
+            "no messages should be emitted in the response",
+        );
+        assert_eq!(
+            10,
+            response.attributes.len(),
+            "ten attributes should be emitted in the response",
+        );
+        response.assert_attribute("action", "admin_update_schedule");
+        response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+        response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
+        response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
+        response.assert_attribute("previous_trading_start", "none");
+        response.assert_attribute("new_trading_start", trading_start.to_string());
+        response.assert_attribute("previous_sunset_at", "none");
+        response.assert_attribute("new_sunset_at", sunset_at.to_string());
+        response.assert_attribute("audit_entry_id", "1");
+        let contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        assert_eq!(
+            Some(trading_start.to_owned()),
+            contract_state.trading_start,
+            "the trading start should be persisted",
+        );
+        assert_eq!(
+            Some(sunset_at.to_owned()),
+            contract_state.sunset_at,
+            "the sunset should be persisted",
+        );
+        let audit_entries = get_audit_entries(&deps.storage).expect("audit entries should load");
+        assert_eq!(
+            vec![
+                format!("changed trading start from [none] to [{trading_start}]"),
+                format!("changed sunset from [none] to [{sunset_at}]"),
+            ],
+            audit_entries[0].details,
+            "the audit entry should describe both changes",
+        );
+    }
+}
@@ -0,0 +1,438 @@
+use crate::store::audit_log::{append_audit_entry, AuditEntry};
+use crate::store::contract_state::{get_contract_state_v1, set_contract_state_v1, AdminSender};
+use crate::types::denom::Denom;
+use crate::types::error::ContractError;
+use crate::util::marker_cache_utils::refresh_marker_address_cache;
+use crate::util::provenance_utils::{
+    check_contract_marker_access, check_marker_is_restricted_and_active, get_denom_supply,
+    get_marker_account, TRADING_MARKER_REQUIRED_ACCESS,
+};
+use crate::util::response_utils::admin_response;
+use crate::util::validation_utils::check_funds_are_empty;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execute functionality.  This function will only accept the request if
+/// the sender is the registered contract admin in the [contract state](crate::store::contract_state::ContractStateV1).
+/// The function replaces the contract's [trading_marker](crate::store::contract_state::ContractStateV1#trading_marker),
+/// allowing the trading denom to be rotated during a token migration.  The new marker must exist
+/// on-chain, and must be restricted and active, or a [ValidationError](ContractError::ValidationError)
+/// naming its actual type or status is returned.  The contract must also hold the [trading marker permissions](crate::util::provenance_utils::TRADING_MARKER_REQUIRED_ACCESS)
+/// on the new marker, or a [NotAuthorizedError](ContractError::NotAuthorizedError) naming the
+/// missing permissions is returned.  The [marker address cache](crate::store::marker_address_cache::MarkerAddressCache)
+/// is immediately repopulated with its address.
+///
+/// Trading denom that is still in circulation remains denominated in the previous marker, and can
+/// no longer be withdrawn once the marker is replaced.  The request is therefore rejected while the
+/// previous marker's on-chain supply is nonzero, unless `force` is set, in which case the amount
+/// still in circulation is recorded in the audit log.  Pending
+/// trades are re-planned against the new marker if they are approved after the update.
+/// The change is recorded in the [audit log](crate::store::audit_log).
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `denom` The new value that will be set in the contract state's
+/// [trading_marker](crate::store::contract_state::ContractStateV1#trading_marker) property upon
+/// successful execution.
+/// * `force` Whether or not to replace the marker while trading denom is still in circulation.
+pub fn admin_update_trading_marker(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: Denom,
+    force: bool,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    let mut contract_state = get_contract_state_v1(deps.storage)?;
+    let admin_sender = contract_state.check_admin(&info.sender, "update the trading marker")?;
+    if denom.name == contract_state.deposit_marker.name {
+        return ContractError::ValidationError {
+            message: format!(
+                "the trading marker cannot use the deposit marker's denom [{}]",
+                denom.name,
+            ),
+        }
+        .to_err();
+    }
+    let outstanding_amount = get_denom_supply(&deps.as_ref(), &contract_state.trading_marker.name)?;
+    if !outstanding_amount.is_zero() && !force {
+        return ContractError::ValidationError {
+            message: format!(
+                "[{outstanding_amount}{}] of trading denom is still in circulation; set force to replace the trading marker anyway",
+                contract_state.trading_marker.name,
+            ),
+        }
+        .to_err();
+    }
+    let marker = get_marker_account(&deps.as_ref(), &denom.name)?;
+    check_marker_is_restricted_and_active(&marker)?;
+    check_contract_marker_access(
+        &marker,
+        &env.contract.address,
+        &TRADING_MARKER_REQUIRED_ACCESS,
+    )?;
+    let previous_marker = contract_state.trading_marker.to_owned();
+    contract_state.trading_marker = denom;
+    // Resolving the new marker's address verifies that the marker exists on-chain before the
+    // contract state is stored
+    refresh_marker_address_cache(deps.branch(), &contract_state)?;
+    set_contract_state_v1(deps.storage, &contract_state)?;
+    let mut details = vec![format!(
+        "changed trading_marker from [{}] with precision [{}] to [{}] with precision [{}]",
+        previous_marker.name,
+        previous_marker.precision,
+        contract_state.trading_marker.name,
+        contract_state.trading_marker.precision
+    )];
+    // A nonzero supply only passes the circulation check when the replacement is forced
+    if !outstanding_amount.is_zero() {
+        details.push(format!(
+            "replaced the trading marker with [{outstanding_amount}{}] still in circulation",
+            previous_marker.name
+        ));
+    }
+    let audit_entry = append_audit_entry(
+        deps.storage,
+        AuditEntry {
+            id: 0,
+            action: "admin_update_trading_marker".to_string(),
+            actor: info.sender,
+            via_group_policy: admin_sender == AdminSender::GroupPolicy,
+            via_gov_authority: admin_sender == AdminSender::GovAuthority,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            details,
+        },
+    )?;
+    admin_response(
+        "admin_update_trading_marker",
+        &env,
+        &contract_state,
+        admin_sender,
+    )
+    .add_attribute("previous_trading_marker_name", &previous_marker.name)
+    .add_attribute(
+        "previous_trading_marker_precision",
+        previous_marker.precision.to_string(),
+    )
+    .add_attribute(
+        "new_trading_marker_name",
+        &contract_state.trading_marker.name,
+    )
+    .add_attribute(
+        "new_trading_marker_precision",
+        contract_state.trading_marker.precision.to_string(),
+    )
+    .add_attribute("outstanding_trading_amount", outstanding_amount.to_string())
+    .add_attribute("audit_entry_id", audit_entry.id.to_string())
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::admin_update_trading_marker::admin_update_trading_marker;
+    use crate::store::audit_log::get_audit_entries;
+    use crate::store::bridge_stats::record_bridge_trade;
+    use crate::store::contract_state::{get_contract_state_v1, CONTRACT_TYPE, CONTRACT_VERSION};
+    use crate::store::marker_address_cache::get_marker_address_cache;
+    use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_constants::{
+        DEFAULT_ADMIN, DEFAULT_CONTRACT_NAME, DEFAULT_DEPOSIT_DENOM_NAME,
+        DEFAULT_TRADING_DENOM_NAME,
+    };
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::test::test_markers::{
+        mock_marker_response, mock_missing_marker_response, test_marker_account,
+    };
+    use crate::types::denom::Denom;
+    use crate::types::error::ContractError;
+    use cosmwasm_std::testing::{message_info, mock_env, MockApi, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coins, Addr};
+    use provwasm_mocks::{mock_provenance_dependencies, MockProvenanceQuerier};
+    use provwasm_std::types::cosmos::bank::v1beta1::{QuerySupplyOfRequest, QuerySupplyOfResponse};
+    use provwasm_std::types::cosmos::base::v1beta1::Coin;
+    use provwasm_std::types::provenance::marker::v1::{Access, MarkerStatus, MarkerType};
+
+    fn mock_trading_supply<S: Into<String>>(querier: &mut MockProvenanceQuerier, amount: S) {
+        QuerySupplyOfRequest::mock_response(
+            querier,
+            QuerySupplyOfResponse {
+                amount: Some(Coin {
+                    denom: DEFAULT_TRADING_DENOM_NAME.to_string(),
+                    amount: amount.into(),
+                }),
+            },
+        );
+    }
+
+    #[test]
+    fn provided_funds_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_update_trading_marker(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &coins(10, "nhash")),
+            Denom::new("newtrading", 6),
+            false,
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(&error, ContractError::InvalidFundsError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn missing_contract_state_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_update_trading_marker(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Denom::new("newtrading", 6),
+            false,
+        )
+        .expect_err("an error should occur when the contract state is missing");
+        assert!(
+            matches!(&error, ContractError::StorageError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn non_admin_sender_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let error = admin_update_trading_marker(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("not-the-admin"), &[]),
+            Denom::new("newtrading", 6),
+            false,
+        )
+        .expect_err("an error should occur when the sender is not the admin");
+        assert!(
+            matches!(&error, ContractError::NotAuthorizedError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn deposit_marker_denom_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let error = admin_update_trading_marker(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Denom::new(DEFAULT_DEPOSIT_DENOM_NAME, 6),
+            false,
+        )
+        .expect_err("an error should occur when the deposit denom is reused");
+        assert!(
+            matches!(&error, ContractError::ValidationError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn missing_marker_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        mock_trading_supply(&mut deps.querier, "0");
+        mock_missing_marker_response(&mut deps.querier);
+        admin_update_trading_marker(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Denom::new("newtrading", 6),
+            false,
+        )
+        .expect_err("an error should occur when the marker cannot be found");
+        assert_eq!(
+            DEFAULT_TRADING_DENOM_NAME,
+            get_contract_state_v1(&deps.storage)
+                .expect("contract state should load")
+                .trading_marker
+                .name,
+            "the trading marker should be unchanged",
+        );
+    }
+
+    #[test]
+    fn marker_that_is_not_restricted_and_active_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        mock_trading_supply(&mut deps.querier, "0");
+        let mut marker = test_marker_account("newtrading", "0");
+        marker.marker_type = MarkerType::Coin as i32;
+        mock_marker_response(&mut deps.querier, marker);
+        let error = admin_update_trading_marker(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Denom::new("newtrading", 6),
+            false,
+        )
+        .expect_err("an error should occur when the marker is a coin marker");
+        assert_eq!(
+            "validation failed: marker [newtrading] has type [MARKER_TYPE_COIN], but must be [MARKER_TYPE_RESTRICTED]",
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+        let mut marker = test_marker_account("newtrading", "0");
+        marker.status = MarkerStatus::Proposed as i32;
+        mock_marker_response(&mut deps.querier, marker);
+        let error = admin_update_trading_marker(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Denom::new("newtrading", 6),
+            false,
+        )
+        .expect_err("an error should occur when the marker is not active");
+        assert_eq!(
+            "validation failed: marker [newtrading] has status [MARKER_STATUS_PROPOSED], but must be [MARKER_STATUS_ACTIVE]",
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+        assert_eq!(
+            DEFAULT_TRADING_DENOM_NAME,
+            get_contract_state_v1(&deps.storage)
+                .expect("contract state should load")
+                .trading_marker
+                .name,
+            "the trading marker should be unchanged",
+        );
+    }
+
+    #[test]
+    fn outstanding_trading_denom_should_require_force() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        mock_marker_response(&mut deps.querier, test_marker_account("newtrading", "0"));
+        mock_trading_supply(&mut deps.querier, "600000");
+        let error = admin_update_trading_marker(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Denom::new("newtrading", 6),
+            false,
+        )
+        .expect_err("an error should occur while trading denom is in circulation");
+        assert_eq!(
+            format!("validation failed: [600000{DEFAULT_TRADING_DENOM_NAME}] of trading denom is still in circulation; set force to replace the trading marker anyway"),
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+        let response = admin_update_trading_marker(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Denom::new("newtrading", 6),
+            true,
+        )
+        .expect("a forced update should succeed despite the outstanding amount");
+        response.assert_attribute("outstanding_trading_amount", "600000");
+        response.assert_attribute("audit_entry_id", "1");
+        assert_eq!(
+            format!("replaced the trading marker with [600000{DEFAULT_TRADING_DENOM_NAME}] still in circulation"),
+            get_audit_entries(&deps.storage).expect("audit entries should load")[0].details[1],
+            "the audit entry should record the forced replacement",
+        );
+    }
+
+    #[test]
+    fn marker_without_the_contract_permissions_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        mock_trading_supply(&mut deps.querier, "0");
+        let mut marker = test_marker_account("newtrading", "0");
+        marker.access_control[0]
+            .permissions
+            .retain(|permission| *permission != Access::Burn as i32);
+        mock_marker_response(&mut deps.querier, marker);
+        let error = admin_update_trading_marker(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Denom::new("newtrading", 6),
+            false,
+        )
+        .expect_err("an error should occur when the contract lacks a marker permission");
+        assert_eq!(
+            format!("not authorized: contract [{MOCK_CONTRACT_ADDR}] is missing permissions [ACCESS_BURN] on marker [newtrading]"),
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+        assert_eq!(
+            DEFAULT_TRADING_DENOM_NAME,
+            get_contract_state_v1(&deps.storage)
+                .expect("contract state should load")
+                .trading_marker
+                .name,
+            "the trading marker should be unchanged",
+        );
+    }
+
+    #[test]
+    fn successful_input_should_derive_a_response() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        mock_trading_supply(&mut deps.querier, "0");
+        mock_marker_response(&mut deps.querier, test_marker_account("newtrading", "0"));
+        let response = admin_update_trading_marker(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Denom::new("newtrading", 8),
+            false,
+        )
+        .expect("proper input on an instantiated contract should derive a successful response");
+        assert!(
+            response.messages.is_empty(),
+            "no messages should be emitted in the response",
+        );
+        assert_eq!(
+            11,
+            response.attributes.len(),
+            "eleven attributes should be emitted in the response",
+        );
+        response.assert_attribute("action", "admin_update_trading_marker");
+        response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+        response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
+        response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
+        response.assert_attribute("previous_trading_marker_name", DEFAULT_TRADING_DENOM_NAME);
+        response.assert_attribute("previous_trading_marker_precision", "6");
+        response.assert_attribute("new_trading_marker_name", "newtrading");
+        response.assert_attribute("new_trading_marker_precision", "8");
+        response.assert_attribute("outstanding_trading_amount", "0");
+        response.assert_attribute("audit_entry_id", "1");
+        assert_eq!(
+            vec![format!("changed trading_marker from [{DEFAULT_TRADING_DENOM_NAME}] with precision [6] to [newtrading] with precision [8]")],
+            get_audit_entries(&deps.storage).expect("audit entries should load")[0].details,
+            "no circulation detail should be recorded when nothing is in circulation",
+        );
+        assert_eq!(
+            Denom::new("newtrading", 8),
+            get_contract_state_v1(&deps.storage)
+                .expect("contract state should load after the update")
+                .trading_marker,
+            "the new marker should be persisted to the contract state",
+        );
+        assert_eq!(
+            MockApi::default().addr_make("marker"),
+            get_marker_address_cache(&deps.storage)
+                .expect("the marker address cache should load")
+                .expect("the marker address cache should be populated")
+                .trading_marker_address,
+            "the new marker's address should be cached",
+        );
+    }
+}
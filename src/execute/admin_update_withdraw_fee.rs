@@ -0,0 +1,236 @@
+use crate::store::audit_log::{append_audit_entry, AuditEntry};
+use crate::store::contract_state::{get_contract_state_v1, set_contract_state_v1, AdminSender};
+use crate::types::error::ContractError;
+use crate::util::response_utils::admin_response;
+use crate::util::validation_utils::check_funds_are_empty;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execute functionality.  This function will only accept the request if
+/// the sender is the registered contract admin in the [contract state](crate::store::contract_state::ContractStateV1).
+/// The function sets the portion of the deposit denom released by the [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading)
+/// route that is withheld from the sender.  The fee is paid to the [fee_collector](crate::store::contract_state::ContractStateV1#fee_collector),
+/// which is managed by the [admin_update_fund_fee](crate::execute::admin_update_fund_fee::admin_update_fund_fee)
+/// route and must already be configured before a fee above zero is accepted.  Trades that are
+/// already pending withhold the fee configured when they are approved.
+/// The change is recorded in the [audit log](crate::store::audit_log).
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `withdraw_fee_bps` The new value that will be set in the contract state's
+/// [withdraw_fee_bps](crate::store::contract_state::ContractStateV1#withdraw_fee_bps) property
+/// upon successful execution.  `None` removes the fee.
+pub fn admin_update_withdraw_fee(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    withdraw_fee_bps: Option<u16>,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    let mut contract_state = get_contract_state_v1(deps.storage)?;
+    let admin_sender = contract_state.check_admin(&info.sender, "update the withdraw fee")?;
+    if withdraw_fee_bps.is_some_and(|withdraw_fee_bps| withdraw_fee_bps > 0)
+        && contract_state.fee_collector.is_none()
+    {
+        return ContractError::ValidationError {
+            message: "a fee collector must be configured before a withdraw fee is set".to_string(),
+        }
+        .to_err();
+    }
+    let previous_withdraw_fee_bps = contract_state.withdraw_fee_bps;
+    contract_state.withdraw_fee_bps = withdraw_fee_bps;
+    set_contract_state_v1(deps.storage, &contract_state)?;
+    let audit_entry = append_audit_entry(
+        deps.storage,
+        AuditEntry {
+            id: 0,
+            action: "admin_update_withdraw_fee".to_string(),
+            actor: info.sender,
+            via_group_policy: admin_sender == AdminSender::GroupPolicy,
+            via_gov_authority: admin_sender == AdminSender::GovAuthority,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            details: vec![format!(
+                "changed withdraw_fee_bps from [{}] to [{}]",
+                withdraw_fee_bps_attribute(previous_withdraw_fee_bps),
+                withdraw_fee_bps_attribute(contract_state.withdraw_fee_bps)
+            )],
+        },
+    )?;
+    admin_response(
+        "admin_update_withdraw_fee",
+        &env,
+        &contract_state,
+        admin_sender,
+    )
+    .add_attribute(
+        "previous_withdraw_fee_bps",
+        withdraw_fee_bps_attribute(previous_withdraw_fee_bps),
+    )
+    .add_attribute(
+        "new_withdraw_fee_bps",
+        withdraw_fee_bps_attribute(contract_state.withdraw_fee_bps),
+    )
+    .add_attribute("audit_entry_id", audit_entry.id.to_string())
+    .to_ok()
+}
+
+fn withdraw_fee_bps_attribute(withdraw_fee_bps: Option<u16>) -> String {
+    withdraw_fee_bps.map_or_else(
+        || "none".to_string(),
+        |withdraw_fee_bps| withdraw_fee_bps.to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::admin_update_withdraw_fee::admin_update_withdraw_fee;
+    use crate::store::contract_state::{get_contract_state_v1, CONTRACT_TYPE, CONTRACT_VERSION};
+    use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_constants::{DEFAULT_ADMIN, DEFAULT_CONTRACT_NAME};
+    use crate::test::test_instantiate::{test_instantiate, test_instantiate_with_msg};
+    use crate::types::error::ContractError;
+    use crate::types::msg::InstantiateMsg;
+    use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coins, Addr};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn provided_funds_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_update_withdraw_fee(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &coins(10, "nhash")),
+            Some(30),
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(&error, ContractError::InvalidFundsError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn missing_contract_state_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = admin_update_withdraw_fee(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Some(30),
+        )
+        .expect_err("an error should occur when the contract state is missing");
+        assert!(
+            matches!(&error, ContractError::StorageError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn non_admin_sender_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let error = admin_update_withdraw_fee(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("not-the-admin"), &[]),
+            Some(30),
+        )
+        .expect_err("an error should occur when the sender is not the admin");
+        assert!(
+            matches!(&error, ContractError::NotAuthorizedError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn missing_fee_collector_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let error = admin_update_withdraw_fee(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Some(30),
+        )
+        .expect_err("an error should occur when no fee collector is configured");
+        assert_eq!(
+            "validation failed: a fee collector must be configured before a withdraw fee is set",
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+        admin_update_withdraw_fee(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Some(0),
+        )
+        .expect("a zero fee should not require a fee collector");
+    }
+
+    #[test]
+    fn successful_input_should_derive_a_response() {
+        let mut deps = mock_provenance_dependencies();
+        let collector = deps.api.addr_make("collector");
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                fee_collector: Some(collector.to_string()),
+                ..InstantiateMsg::default()
+            },
+        );
+        let response = admin_update_withdraw_fee(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Some(30),
+        )
+        .expect("proper input on an instantiated contract should derive a successful response");
+        assert!(
+            response.messages.is_empty(),
+            "no messages should be emitted in the response",
+        );
+        assert_eq!(
+            8,
+            response.attributes.len(),
+            "eight attributes should be emitted in the response",
+        );
+        response.assert_attribute("action", "admin_update_withdraw_fee");
+        response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+        response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
+        response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
+        response.assert_attribute("previous_withdraw_fee_bps", "none");
+        response.assert_attribute("new_withdraw_fee_bps", "30");
+        response.assert_attribute("audit_entry_id", "1");
+        assert_eq!(
+            Some(30),
+            get_contract_state_v1(deps.as_ref().storage)
+                .expect("contract state should load after the update")
+                .withdraw_fee_bps,
+            "the new value should be persisted to the contract state",
+        );
+        let response = admin_update_withdraw_fee(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            None,
+        )
+        .expect("removing the fee should derive a successful response");
+        response.assert_attribute("previous_withdraw_fee_bps", "30");
+        response.assert_attribute("new_withdraw_fee_bps", "none");
+        assert_eq!(
+            None,
+            get_contract_state_v1(deps.as_ref().storage)
+                .expect("contract state should load after the update")
+                .withdraw_fee_bps,
+            "the fee should be removed from the contract state",
+        );
+    }
+}
@@ -0,0 +1,505 @@
+use crate::execute::fund_trading::plan_fund_trading;
+use crate::execute::withdraw_trading::plan_withdraw_trading;
+use crate::store::audit_log::{append_audit_entry, AuditEntry};
+use crate::store::contract_state::{get_contract_state_v1, AdminSender};
+use crate::store::pending_trades::{get_pending_trade, remove_pending_trade};
+use crate::types::error::ContractError;
+use crate::types::quote::TradeDirection;
+use crate::types::trade_options::TradeOptions;
+use crate::util::response_utils::{add_trade_receipt, admin_response};
+use crate::util::trade_record_utils::record_planned_trade;
+use crate::util::validation_utils::check_funds_are_empty;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execute functionality.  This function will only accept the request if
+/// the sender is the registered contract admin in the [contract state](crate::store::contract_state::ContractStateV1).
+/// The function executes a [pending trade](crate::store::pending_trades::PendingTrade) on behalf of
+/// the account that requested it.  Every check performed by the trade's execution route is run again
+/// against the account's current balances and attributes, including the minimum and maximum trade
+/// amounts and the acknowledgement of any unconvertible remainder, and the trade is rejected if any
+/// of them fail or if the trade has expired.  A fund trade is also rejected when the
+/// [daily cap](crate::store::contract_state::ContractStateV1#daily_cap) has no room for it on the
+/// day of approval, and any trade is rejected when it would exceed its account's
+/// [per-account daily limit](crate::store::contract_state::ContractStateV1#per_account_daily_limit)
+/// on that day.  Upon success, the pending trade is removed, the approval is
+/// recorded in the [audit log](crate::store::audit_log), the trade's referrer is credited, the trade
+/// is issued a [receipt](crate::store::trade_receipts::TradeReceipt), and the trade's messages are
+/// emitted.
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `id` The identifier of the pending trade to approve.
+pub fn approve_pending_trade(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    let contract_state = get_contract_state_v1(deps.storage)?;
+    let admin_sender = contract_state.check_admin(&info.sender, "approve pending trades")?;
+    let pending_trade = get_pending_trade(deps.storage, id)?;
+    if pending_trade.is_expired(env.block.height) {
+        return ContractError::ValidationError {
+            message: format!(
+                "pending trade [{id}] expired at height [{}] and can only be rejected",
+                pending_trade.expires_at_height,
+            ),
+        }
+        .to_err();
+    }
+    // Any quote was verified when the trade was submitted, and the approval intentionally executes
+    // against the contract's current configuration
+    let options = TradeOptions {
+        allow_remainder: pending_trade.allow_remainder,
+        ..TradeOptions::default()
+    };
+    let (plan, input_marker) = match pending_trade.direction {
+        TradeDirection::Fund => (
+            plan_fund_trading(
+                &deps.as_ref(),
+                &env,
+                &contract_state,
+                &pending_trade.sender,
+                pending_trade.trade_amount,
+                &options,
+            )?,
+            &contract_state.deposit_marker,
+        ),
+        TradeDirection::Withdraw => (
+            plan_withdraw_trading(
+                &deps.as_ref(),
+                &env,
+                &contract_state,
+                &pending_trade.sender,
+                pending_trade.trade_amount,
+                &options,
+            )?,
+            &contract_state.trading_marker,
+        ),
+    };
+    plan.check_remainder_acknowledged(&input_marker.name, options.allow_remainder)?;
+    remove_pending_trade(deps.storage, id);
+    let trade_receipt = record_planned_trade(
+        deps.storage,
+        &env,
+        &contract_state,
+        pending_trade.direction,
+        &pending_trade.sender,
+        pending_trade.referrer.as_ref(),
+        &plan,
+    )?;
+    let audit_entry = append_audit_entry(
+        deps.storage,
+        AuditEntry {
+            id: 0,
+            action: "approve_pending_trade".to_string(),
+            actor: info.sender,
+            via_group_policy: admin_sender == AdminSender::GroupPolicy,
+            via_gov_authority: admin_sender == AdminSender::GovAuthority,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            details: vec![format!(
+                "approved pending {} trade [{id}] of [{}] for [{}]",
+                pending_trade.direction.name(),
+                pending_trade.trade_amount,
+                pending_trade.sender,
+            )],
+        },
+    )?;
+    let response = admin_response("approve_pending_trade", &env, &contract_state, admin_sender)
+        .add_attribute("pending_trade_id", id.to_string())
+        .add_attribute("direction", pending_trade.direction.name())
+        .add_attribute("trade_sender", pending_trade.sender.as_str())
+        .add_attribute("requested_amount", pending_trade.trade_amount.to_string())
+        .add_attribute("actual_amount", plan.collected_amount.to_string())
+        .add_attribute("received_amount", plan.output_amount().to_string())
+        .add_attribute("audit_entry_id", audit_entry.id.to_string());
+    let response = match pending_trade.referrer {
+        Some(referrer) => response.add_attribute("referrer", referrer),
+        None => response,
+    };
+    let response = if plan.whitelisted {
+        response.add_attribute("whitelisted", "true")
+    } else {
+        response
+    };
+    let response = match &plan.trade_fee {
+        Some(trade_fee) => response
+            .add_attribute("fee_amount", trade_fee.amount.to_string())
+            .add_attribute("fee_collector", &trade_fee.collector),
+        None => response,
+    };
+    add_trade_receipt(response.add_submessages(plan.messages), &trade_receipt)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::approve_pending_trade::approve_pending_trade;
+    use crate::store::audit_log::get_audit_entries;
+    use crate::store::contract_state::{
+        get_contract_state_v1, set_contract_state_v1, CONTRACT_TYPE, CONTRACT_VERSION,
+    };
+    use crate::store::pending_trades::{get_pending_trade, insert_pending_trade, PendingTrade};
+    use crate::store::referral_stats::{get_referral_stats, ReferralStats};
+    use crate::store::trade_receipts::{get_trade_receipt, TradeReceipt};
+    use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_constants::{
+        DEFAULT_ADMIN, DEFAULT_CONTRACT_NAME, DEFAULT_DEPOSIT_DENOM_NAME,
+        DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE, DEFAULT_TRADING_DENOM_NAME,
+    };
+    use crate::test::test_instantiate::{test_instantiate, test_instantiate_with_msg};
+    use crate::test::test_markers::{
+        mock_marker_params_response, mock_marker_response, test_marker_account,
+    };
+    use crate::types::denom::Denom;
+    use crate::types::error::ContractError;
+    use crate::types::msg::InstantiateMsg;
+    use crate::types::quote::TradeDirection;
+    use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coin, coins, Addr, Storage, Uint128};
+    use provwasm_mocks::{
+        mock_provenance_dependencies, mock_provenance_dependencies_with_custom_querier,
+        MockProvenanceQuerier,
+    };
+    use provwasm_std::types::cosmos::bank::v1beta1::{QueryBalanceRequest, QueryBalanceResponse};
+    use provwasm_std::types::cosmos::base::v1beta1::Coin;
+    use provwasm_std::types::provenance::attribute::v1::{
+        Attribute, AttributeType, QueryAttributesRequest, QueryAttributesResponse,
+    };
+
+    fn store_pending_fund_trade(storage: &mut dyn Storage, expires_at_height: u64) {
+        insert_pending_trade(
+            storage,
+            PendingTrade {
+                id: 0,
+                direction: TradeDirection::Fund,
+                sender: Addr::unchecked("sender"),
+                trade_amount: Uint128::new(100),
+                submitted_at_height: 1,
+                expires_at_height,
+                referrer: None,
+                allow_remainder: false,
+            },
+        )
+        .expect("the pending trade should be stored");
+    }
+
+    fn get_fund_querier<S: Into<String>>(sender_balance: S) -> MockProvenanceQuerier {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(
+            &mut querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    amount: sender_balance.into(),
+                    denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+                }),
+            },
+        );
+        QueryAttributesRequest::mock_response(
+            &mut querier,
+            QueryAttributesResponse {
+                account: "sender".to_string(),
+                attributes: vec![Attribute {
+                    name: DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE.to_string(),
+                    value: vec![],
+                    attribute_type: AttributeType::String as i32,
+                    address: "addr".to_string(),
+                    expiration_date: None,
+                }],
+                pagination: None,
+            },
+        );
+        mock_marker_response(
+            &mut querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        mock_marker_params_response(&mut querier, "");
+        querier
+    }
+
+    #[test]
+    fn provided_funds_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = approve_pending_trade(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &coins(10, "nhash")),
+            1,
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(&error, ContractError::InvalidFundsError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn non_admin_sender_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        store_pending_fund_trade(&mut deps.storage, mock_env().block.height);
+        let error = approve_pending_trade(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            1,
+        )
+        .expect_err("an error should occur when the sender is not the admin");
+        assert!(
+            matches!(&error, ContractError::NotAuthorizedError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn missing_pending_trade_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let error = approve_pending_trade(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            1,
+        )
+        .expect_err("an error should occur when the pending trade does not exist");
+        assert!(
+            matches!(&error, ContractError::NotFoundError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn expired_pending_trade_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies_with_custom_querier(get_fund_querier("100"));
+        test_instantiate(&mut deps);
+        let env = mock_env();
+        store_pending_fund_trade(&mut deps.storage, env.block.height - 1);
+        let error = approve_pending_trade(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            1,
+        )
+        .expect_err("an error should occur when the pending trade has expired");
+        assert_eq!(
+            format!(
+                "validation failed: pending trade [1] expired at height [{}] and can only be rejected",
+                env.block.height - 1,
+            ),
+            error.to_string(),
+            "unexpected error emitted for an expired pending trade",
+        );
+        get_pending_trade(&deps.storage, 1)
+            .expect("an expired pending trade should remain until it is rejected");
+    }
+
+    #[test]
+    fn failed_checks_should_cause_an_error_and_keep_the_pending_trade() {
+        let mut deps = mock_provenance_dependencies_with_custom_querier(get_fund_querier("99"));
+        test_instantiate(&mut deps);
+        store_pending_fund_trade(&mut deps.storage, mock_env().block.height);
+        let error = approve_pending_trade(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            1,
+        )
+        .expect_err("an error should occur when the sender no longer holds enough funds");
+        assert!(
+            matches!(&error, ContractError::PreconditionsFailed { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+        get_pending_trade(&deps.storage, 1)
+            .expect("the pending trade should remain after a failed approval");
+    }
+
+    #[test]
+    fn trade_amount_below_the_current_minimum_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies_with_custom_querier(get_fund_querier("100"));
+        test_instantiate(&mut deps);
+        let mut contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        contract_state.min_fund_amount = Some(Uint128::new(101));
+        set_contract_state_v1(&mut deps.storage, &contract_state)
+            .expect("contract state should be stored");
+        store_pending_fund_trade(&mut deps.storage, mock_env().block.height);
+        let error = approve_pending_trade(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            1,
+        )
+        .expect_err("an error should occur when the trade is below the current minimum");
+        match error {
+            ContractError::PreconditionsFailed { failures } => assert!(
+                failures.contains(&format!("validation failed: requested [100{DEFAULT_DEPOSIT_DENOM_NAME}], but the minimum fund amount is [101{DEFAULT_DEPOSIT_DENOM_NAME}]")),
+                "the minimum should be reported, but got: {failures:?}",
+            ),
+            e => panic!("unexpected error encountered: {e:?}"),
+        };
+        get_pending_trade(&deps.storage, 1)
+            .expect("the pending trade should remain after a failed approval");
+    }
+
+    #[test]
+    fn unacknowledged_remainder_should_prevent_approval() {
+        for (allow_remainder, should_succeed) in [(false, false), (true, true)] {
+            let mut deps =
+                mock_provenance_dependencies_with_custom_querier(get_fund_querier("105"));
+            // A trading marker with less precision than the deposit marker leaves a remainder of
+            // [5] when funding [105]
+            test_instantiate_with_msg(
+                &mut deps,
+                InstantiateMsg {
+                    deposit_marker: Denom::new(DEFAULT_DEPOSIT_DENOM_NAME, 2),
+                    trading_marker: Denom::new(DEFAULT_TRADING_DENOM_NAME, 1),
+                    ..InstantiateMsg::default()
+                },
+            );
+            insert_pending_trade(
+                &mut deps.storage,
+                PendingTrade {
+                    id: 0,
+                    direction: TradeDirection::Fund,
+                    sender: Addr::unchecked("sender"),
+                    trade_amount: Uint128::new(105),
+                    submitted_at_height: 1,
+                    expires_at_height: mock_env().block.height,
+                    referrer: None,
+                    allow_remainder,
+                },
+            )
+            .expect("the pending trade should be stored");
+            let result = approve_pending_trade(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+                1,
+            );
+            if should_succeed {
+                let response = result.expect("an acknowledged remainder should allow approval");
+                response.assert_attribute("actual_amount", "100");
+            } else {
+                let error = result.expect_err("an unacknowledged remainder should fail");
+                assert!(
+                    matches!(&error, ContractError::RemainderNotAcknowledgedError { .. }),
+                    "unexpected error encountered: {error:?}",
+                );
+                get_pending_trade(&deps.storage, 1)
+                    .expect("the pending trade should remain after a failed approval");
+            }
+        }
+    }
+
+    #[test]
+    fn successful_approval_should_execute_the_trade() {
+        let mut deps = mock_provenance_dependencies_with_custom_querier(get_fund_querier("100"));
+        test_instantiate(&mut deps);
+        store_pending_fund_trade(&mut deps.storage, mock_env().block.height);
+        let response = approve_pending_trade(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            1,
+        )
+        .expect("approving a valid pending trade should succeed");
+        assert_eq!(
+            3,
+            response.messages.len(),
+            "the approved trade should emit all three fund trading messages",
+        );
+        assert_eq!(
+            13,
+            response.attributes.len(),
+            "thirteen attributes should be emitted in the response",
+        );
+        response.assert_attribute("action", "approve_pending_trade");
+        response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+        response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
+        response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
+        response.assert_attribute("pending_trade_id", "1");
+        response.assert_attribute("direction", "fund");
+        response.assert_attribute("trade_sender", "sender");
+        response.assert_attribute("requested_amount", "100");
+        response.assert_attribute("actual_amount", "100");
+        response.assert_attribute("received_amount", "1000000");
+        response.assert_attribute("audit_entry_id", "1");
+        response.assert_attribute("receipt", "1");
+        assert_eq!(
+            TradeReceipt {
+                receipt: 1,
+                account: Addr::unchecked("sender"),
+                direction: TradeDirection::Fund,
+                input: coin(100, DEFAULT_DEPOSIT_DENOM_NAME),
+                output: coin(1000000, DEFAULT_TRADING_DENOM_NAME),
+                remainder: Uint128::zero(),
+                block_height: mock_env().block.height,
+                block_time: mock_env().block.time,
+            },
+            get_trade_receipt(&deps.storage, 1)
+                .expect("the approved trade should be issued a receipt"),
+            "the receipt should record the approved trade",
+        );
+        let error =
+            get_pending_trade(&deps.storage, 1).expect_err("the approved trade should be removed");
+        assert!(
+            matches!(error, ContractError::NotFoundError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+        let audit_entries =
+            get_audit_entries(&deps.storage).expect("the audit log should be fetched");
+        assert_eq!(
+            vec!["approved pending fund trade [1] of [100] for [sender]".to_string()],
+            audit_entries
+                .first()
+                .expect("the approval should be recorded in the audit log")
+                .details,
+            "the audit entry should describe the approved trade",
+        );
+    }
+
+    #[test]
+    fn approving_a_referred_trade_should_credit_the_referrer() {
+        let mut deps = mock_provenance_dependencies_with_custom_querier(get_fund_querier("100"));
+        test_instantiate(&mut deps);
+        let referrer = Addr::unchecked("referrer");
+        insert_pending_trade(
+            &mut deps.storage,
+            PendingTrade {
+                id: 0,
+                direction: TradeDirection::Fund,
+                sender: Addr::unchecked("sender"),
+                trade_amount: Uint128::new(100),
+                submitted_at_height: 1,
+                expires_at_height: mock_env().block.height,
+                referrer: Some(referrer.to_owned()),
+                allow_remainder: false,
+            },
+        )
+        .expect("the pending trade should be stored");
+        let response = approve_pending_trade(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            1,
+        )
+        .expect("approving a referred pending trade should succeed");
+        response.assert_attribute("referrer", "referrer");
+        assert_eq!(
+            ReferralStats {
+                trade_count: 1,
+                volume: Uint128::new(100),
+            },
+            get_referral_stats(&deps.storage, &referrer).expect("the stats should be fetched"),
+            "the referrer should be credited when the trade is approved",
+        );
+    }
+}
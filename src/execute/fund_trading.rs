@@ -1,11 +1,34 @@
-use crate::store::contract_state::{get_contract_state_v1, CONTRACT_TYPE};
+use crate::store::account_daily_usage::check_account_daily_limit;
+use crate::store::attribute_whitelist::is_whitelisted_account;
+use crate::store::contract_state::{get_contract_state_v1, ContractStateV1};
+use crate::store::daily_volume::check_daily_capacity;
+use crate::store::denylist::check_account_not_denylisted;
+use crate::store::fee_exemptions::is_fee_exempt;
+use crate::store::runtime_tunables::{get_runtime_tunables, RuntimeTunables};
 use crate::types::error::ContractError;
+use crate::types::quote::TradeDirection;
+use crate::types::trade_options::TradeOptions;
+use crate::types::trade_plan::TradePlan;
+use crate::types::trade_step::TradeStep;
+use crate::util::compliance_utils::check_trade_compliance;
 use crate::util::conversion_utils::convert_denom;
+use crate::util::fee_utils::check_sender_attributes;
+use crate::util::partial_fill_utils::resolve_fill_amount;
+use crate::util::pending_trade_utils::submit_pending_trade;
 use crate::util::provenance_utils::{
-    check_account_has_all_attributes, check_account_has_enough_denom,
+    check_account_has_enough_denom, check_account_owns_scope_with_spec,
+    check_contract_access_to_markers, check_marker_can_mint, get_marker_account,
 };
-use crate::util::validation_utils::check_funds_are_empty;
-use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use crate::util::quote_utils::check_quote;
+use crate::util::response_utils::{
+    add_dry_run_attributes, add_msg_fee_attributes, add_trade_attributes, add_trade_receipt,
+    trade_execution_messages, trade_response, TradeEvent,
+};
+use crate::util::trade_record_utils::record_planned_trade;
+use crate::util::validation_utils::{
+    check_funds_are_empty, check_preconditions, validate_referrer,
+};
+use cosmwasm_std::{coin, Addr, Attribute, Deps, DepsMut, Env, MessageInfo, Response, Uint128};
 use provwasm_std::types::cosmos::base::v1beta1::Coin;
 use provwasm_std::types::provenance::marker::v1::{
     MsgMintRequest, MsgTransferRequest, MsgWithdrawRequest,
@@ -15,7 +38,28 @@ use result_extensions::ResultExtensions;
 /// Invoked via the contract's execute functionality.  The function will attempt to pull [trade_amount](fund_trading#trade_amount)
 /// of the deposit marker's denom from the sender's account with a marker transfer, discern how much
 /// of the trading denom to which the submitted amount is equivalent, and then mint and withdraw
-/// that equivalent amount into the sender's account.
+/// that equivalent amount into the sender's account.  The trade is rejected with a single
+/// [PreconditionsFailed](ContractError::PreconditionsFailed) error listing every violated
+/// requirement, including a paused contract or route, a closed trading window, disabled minting,
+/// a trade amount outside the configured minimum and maximum, deposit denom collections that
+/// would exceed the contract's [daily cap](ContractStateV1#daily_cap) for the current UTC day or
+/// the sender's [per-account daily limit](ContractStateV1#per_account_daily_limit), and any
+/// missing attributes, scope, or balance.
+/// Trades valued at or above the contract's [large trade threshold](ContractStateV1#large_trade_threshold)
+/// emit no trade messages after passing their checks, and are instead stored as pending trades that
+/// the contract admin must approve via [approve_pending_trade](crate::execute::approve_pending_trade::approve_pending_trade).
+/// Every accepted trade, including one held for approval, also assesses the contract's
+/// [msg fee](ContractStateV1#msg_fee) when one is configured, reduced by the largest
+/// [fee discount](ContractStateV1#fee_discounts) whose attribute the sender holds.  Senders on
+/// the [fee exemption](crate::store::fee_exemptions) list are assessed no fees at all, but must
+/// still pass every other check.  Senders on the
+/// [attribute whitelist](crate::store::attribute_whitelist) skip both the required attribute and
+/// required scope checks, but must still hold the traded amount, and are flagged with a
+/// `whitelisted` attribute.  Senders on the [denylist](crate::store::denylist) are rejected with a
+/// [NotAuthorizedError](ContractError::NotAuthorizedError) before any other account check.  When
+/// the [verify_marker_access_on_trade](RuntimeTunables#verify_marker_access_on_trade) tunable is
+/// enabled, the trade is then rejected with a [NotAuthorizedError](ContractError::NotAuthorizedError)
+/// if the contract lacks a required permission on either marker.
 ///
 /// # Parameters
 /// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
@@ -26,26 +70,231 @@ use result_extensions::ResultExtensions;
 /// of the instantiation message, as well as the funds provided as an amount during the transaction.
 /// * `trade_amount` The amount of the deposit marker to pull from the sender's account in exchange
 /// for trading denom.
+/// * `options` The optional behaviors requested for the trade.  See [TradeOptions] for details.
 pub fn fund_trading(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    trade_amount: u128,
+    trade_amount: Uint128,
+    options: TradeOptions,
 ) -> Result<Response, ContractError> {
     check_funds_are_empty(&info)?;
     let contract_state = get_contract_state_v1(deps.storage)?;
-    check_account_has_all_attributes(
-        &deps,
-        &info.sender,
-        &contract_state.required_deposit_attributes,
+    let tunables = get_runtime_tunables(deps.storage)?;
+    let response = trade_response(
+        "fund_trading",
+        &env,
+        &contract_state,
+        tunables.event_verbosity,
+    );
+    execute_fund_trading(
+        deps,
+        &env,
+        &contract_state,
+        &tunables,
+        info.sender,
+        trade_amount,
+        options,
+        response,
+    )
+}
+
+/// Performs every check and calculation of [fund_trading] for the given sender, adding the
+/// trade's attributes and messages to the input response.  This allows other routes to execute the
+/// same trade without duplicating its behavior.
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `contract_state` The current contract state, which dictates the denoms and requirements used
+/// in the trade.
+/// * `tunables` The current runtime tunables, which dictate the attributes emitted for the trade.
+/// * `sender` The account trading its deposit denom for trading denom.
+/// * `trade_amount` The amount of the deposit marker to pull from the sender's account in exchange
+/// for trading denom.
+/// * `options` The optional behaviors requested for the trade.  See [TradeOptions] for details.
+/// * `response` The response to which the trade's attributes and messages are added.
+pub fn execute_fund_trading(
+    deps: DepsMut,
+    env: &Env,
+    contract_state: &ContractStateV1,
+    tunables: &RuntimeTunables,
+    sender: Addr,
+    trade_amount: Uint128,
+    options: TradeOptions,
+    response: Response,
+) -> Result<Response, ContractError> {
+    let plan = plan_fund_trading(
+        &deps.as_ref(),
+        env,
+        contract_state,
+        &sender,
+        trade_amount,
+        &options,
+    )?;
+    let fill_amount = plan.conversion.source_amount;
+    plan.check_remainder_acknowledged(
+        &contract_state.deposit_marker.name,
+        options.allow_remainder,
     )?;
+    let mut route_attributes = vec![
+        Attribute::new("deposit_input_denom", &contract_state.deposit_marker.name),
+        Attribute::new("deposit_requested_amount", trade_amount.to_string()),
+        Attribute::new("deposit_actual_amount", plan.collected_amount.to_string()),
+        Attribute::new("received_denom", &contract_state.trading_marker.name),
+        Attribute::new("received_amount", plan.output_amount().to_string()),
+    ];
+    if fill_amount < trade_amount {
+        route_attributes.push(Attribute::new("partial_fill", "true"));
+        route_attributes.push(Attribute::new("filled_amount", fill_amount.to_string()));
+    }
+    if let Some(referrer) = &plan.referrer {
+        route_attributes.push(Attribute::new("referrer", referrer));
+    }
+    if plan.whitelisted {
+        route_attributes.push(Attribute::new("whitelisted", "true"));
+    }
+    if let Some(trade_fee) = &plan.trade_fee {
+        route_attributes.push(Attribute::new("fee_amount", trade_fee.amount.to_string()));
+        route_attributes.push(Attribute::new("fee_collector", &trade_fee.collector));
+    }
+    let input = coin(
+        plan.collected_amount.u128(),
+        &contract_state.deposit_marker.name,
+    );
+    let output = coin(
+        plan.output_amount().u128(),
+        &contract_state.trading_marker.name,
+    );
+    let response = add_trade_attributes(
+        response,
+        env,
+        contract_state,
+        tunables.event_verbosity,
+        TradeEvent {
+            sender: sender.to_owned(),
+            input,
+            output,
+            remainder: plan.conversion.remainder,
+            requires_approval: plan.requires_approval,
+            route_attributes,
+        },
+    );
+    if options.dry_run {
+        add_dry_run_attributes(
+            response,
+            tunables.event_verbosity,
+            plan.conversion.remainder,
+            plan.requires_approval,
+        )
+        .to_ok()
+    } else if plan.requires_approval {
+        submit_pending_trade(
+            deps.storage,
+            env,
+            TradeDirection::Fund,
+            sender,
+            fill_amount,
+            plan.referrer.to_owned(),
+            options.allow_remainder,
+            add_msg_fee_attributes(
+                response.add_submessages(trade_execution_messages(env, &plan, "fund_trading")),
+                plan.msg_fee.as_ref(),
+                plan.fee_discount.as_ref(),
+                tunables.event_verbosity,
+            ),
+        )
+    } else {
+        let trade_receipt = record_planned_trade(
+            deps.storage,
+            env,
+            contract_state,
+            TradeDirection::Fund,
+            &sender,
+            plan.referrer.as_ref(),
+            &plan,
+        )?;
+        add_trade_receipt(
+            add_msg_fee_attributes(
+                response.add_submessages(trade_execution_messages(env, &plan, "fund_trading")),
+                plan.msg_fee.as_ref(),
+                plan.fee_discount.as_ref(),
+                tunables.event_verbosity,
+            ),
+            &trade_receipt,
+        )
+    }
+}
+
+/// Runs every check performed by [fund_trading] and constructs the messages that it would emit,
+/// without modifying any contract state.  This allows the same logic to back both the execution
+/// route and any query that previews its results.
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `contract_state` The current contract state, which dictates the denoms and requirements used
+/// in the trade.
+/// * `sender` The account that would be trading its deposit denom for trading denom.
+/// * `requested_amount` The amount of the deposit marker to pull from the sender's account in
+/// exchange for trading denom, before any partial fill reduces it.
+/// * `options` The optional behaviors requested for the trade.  Only the `quote`, `referrer` and
+/// `allow_partial` options affect the plan.
+pub fn plan_fund_trading(
+    deps: &Deps,
+    env: &Env,
+    contract_state: &ContractStateV1,
+    sender: &Addr,
+    requested_amount: Uint128,
+    options: &TradeOptions,
+) -> Result<TradePlan, ContractError> {
+    // A denylisted sender is refused before anything else is evaluated, so that the contract's
+    // other requirements are never reported to it
+    check_account_not_denylisted(deps.storage, sender)?;
+    let referrer = validate_referrer(deps, sender, options.referrer.to_owned())?;
+    let tunables = get_runtime_tunables(deps.storage)?;
+    // Every route that trades, including approvals and rebalances, verifies the contract's marker
+    // permissions here when the tunable requires it
+    if tunables.verify_marker_access_on_trade {
+        check_contract_access_to_markers(
+            deps,
+            &env.contract.address,
+            &contract_state.deposit_marker,
+            &contract_state.trading_marker,
+        )?;
+    }
+    let trade_amount = if options.allow_partial {
+        resolve_fill_amount(
+            deps,
+            contract_state,
+            TradeDirection::Fund,
+            sender,
+            requested_amount,
+        )?
+    } else {
+        requested_amount
+    };
+    if let Some(quote) = &options.quote {
+        check_quote(
+            env,
+            contract_state,
+            &tunables,
+            TradeDirection::Fund,
+            trade_amount,
+            quote,
+        )?;
+    }
     let conversion = convert_denom(
         trade_amount,
         &contract_state.deposit_marker,
         &contract_state.trading_marker,
     )?;
-    if conversion.target_amount == 0 {
-        return ContractError::InvalidFundsError {
+    let conversion_check = if conversion.target_amount.is_zero() {
+        ContractError::InvalidFundsError {
             message: format!(
                 "sent [{}{}], but that is not enough to convert to at least one [{}]",
                 trade_amount,
@@ -53,15 +302,92 @@ pub fn fund_trading(
                 &contract_state.trading_marker.name,
             ),
         }
-        .to_err();
-    }
+        .to_err()
+    } else {
+        Ok(())
+    };
     // Transfer the necessary amount from the sender (total amount requested - remainder that cannot be converted)
-    let transferred_amount = trade_amount - conversion.remainder;
-    check_account_has_enough_denom(
-        &deps.as_ref(),
-        info.sender.as_str(),
-        &contract_state.deposit_marker.name,
-        transferred_amount,
+    let transferred_amount = trade_amount.checked_sub(conversion.remainder)?;
+    // Whitelisted senders may trade without holding the required attributes, but
+    // their attributes are still searched for any fee discount
+    let whitelisted = is_whitelisted_account(deps.storage, sender)?;
+    let required_attributes: &[String] = if whitelisted {
+        &[]
+    } else {
+        contract_state.required_deposit_attributes.as_slice()
+    };
+    // Every precondition is evaluated before failing, allowing the sender to resolve all problems
+    // at once
+    let attribute_check =
+        check_sender_attributes(deps, contract_state, sender, required_attributes, &tunables);
+    let mut preconditions = vec![
+        contract_state.check_not_paused(),
+        contract_state.check_fund_enabled(),
+        contract_state.check_trading_schedule(&env.block),
+        contract_state.check_minting_enabled(),
+        // The limits apply to the requested amount, even when a partial fill reduces it
+        contract_state.check_min_trade_amount(TradeDirection::Fund, requested_amount),
+        contract_state.check_max_trade_amount(TradeDirection::Fund, requested_amount),
+        conversion_check,
+        check_daily_capacity(
+            deps.storage,
+            &env.block.time,
+            contract_state.daily_cap,
+            transferred_amount,
+            &contract_state.deposit_marker.name,
+        )
+        .map(|_| ()),
+        check_account_daily_limit(
+            deps.storage,
+            sender,
+            &env.block.time,
+            contract_state.per_account_daily_limit,
+            transferred_amount,
+            &contract_state.deposit_marker.name,
+        )
+        .map(|_| ()),
+        attribute_check.requirement,
+    ];
+    // The required scope is waived for whitelisted senders, like the required attributes
+    if let Some(scope_spec) = contract_state
+        .required_scope_spec
+        .as_ref()
+        .filter(|_| !whitelisted)
+    {
+        preconditions.push(check_account_owns_scope_with_spec(
+            deps,
+            sender.as_str(),
+            scope_spec,
+        ));
+    }
+    if let Some(compliance_contract) = &contract_state.compliance_contract {
+        preconditions.push(check_trade_compliance(
+            deps,
+            compliance_contract,
+            sender,
+            TradeDirection::Fund,
+            transferred_amount,
+            &contract_state.deposit_marker.name,
+        ));
+    }
+    if !contract_state.skip_balance_precheck {
+        preconditions.push(check_account_has_enough_denom(
+            deps,
+            sender.as_str(),
+            &contract_state.deposit_marker.name,
+            &contract_state.deposit_denom_aliases,
+            transferred_amount,
+        ));
+    }
+    check_preconditions(preconditions)?;
+    // Fee-exempt senders are assessed neither the msg fee nor any fee withheld from the output
+    let fee_exempt = is_fee_exempt(deps.storage, sender)?;
+    // Verify that the trading marker can mint the converted amount before emitting any messages,
+    // because a failed mint within the marker module does not describe the violated constraint
+    check_marker_can_mint(
+        deps,
+        &get_marker_account(deps, &contract_state.trading_marker.name)?,
+        conversion.target_amount,
     )?;
     let transfer_msg = MsgTransferRequest {
         administrator: env.contract.address.to_string(),
@@ -69,7 +395,7 @@ pub fn fund_trading(
             denom: contract_state.deposit_marker.name.to_owned(),
             amount: transferred_amount.to_string(),
         }),
-        from_address: info.sender.to_string(),
+        from_address: sender.to_string(),
         to_address: env.contract.address.to_string(),
     };
     // Mint the amount of coin to which the conversion equates
@@ -81,44 +407,105 @@ pub fn fund_trading(
         administrator: env.contract.address.to_string(),
         amount: Some(minted_coin.to_owned()),
     };
-    // Withdraw the newly-minted coin to the sender, effectively making the trade
+    // The fund fee is rounded down and its rate is always below the full amount, so the sender
+    // always receives at least one unit of the minted coin
+    let fund_fee = contract_state
+        .fund_fee(conversion.target_amount)
+        .filter(|_| !fee_exempt);
+    let fee_amount = fund_fee
+        .as_ref()
+        .map(|fund_fee| fund_fee.amount)
+        .unwrap_or_default();
+    // Withdraw the newly-minted coin to the sender, less any fund fee, effectively making the trade
     let withdraw_msg = MsgWithdrawRequest {
         denom: contract_state.trading_marker.name.to_owned(),
         administrator: env.contract.address.to_string(),
-        to_address: info.sender.to_string(),
-        amount: vec![minted_coin.to_owned()],
+        to_address: sender.to_string(),
+        amount: vec![Coin {
+            denom: minted_coin.denom.to_owned(),
+            amount: conversion
+                .target_amount
+                .checked_sub(fee_amount)?
+                .to_string(),
+        }],
     };
-    Response::new()
-        .add_message(transfer_msg)
-        .add_message(mint_msg)
-        .add_message(withdraw_msg)
-        .add_attribute("action", "fund_trading")
-        .add_attribute("contract_address", env.contract.address.to_string())
-        .add_attribute("contract_type", CONTRACT_TYPE)
-        .add_attribute("contract_name", &contract_state.contract_name)
-        .add_attribute("deposit_input_denom", &contract_state.deposit_marker.name)
-        .add_attribute("deposit_requested_amount", trade_amount.to_string())
-        .add_attribute("deposit_actual_amount", transferred_amount.to_string())
-        .add_attribute("received_denom", minted_coin.denom)
-        .add_attribute("received_amount", minted_coin.amount)
-        .to_ok()
+    let mut messages = vec![
+        TradeStep::Collect.sub_msg(transfer_msg),
+        TradeStep::Mint.sub_msg(mint_msg),
+        TradeStep::Withdraw.sub_msg(withdraw_msg),
+    ];
+    if let Some(fund_fee) = fund_fee
+        .as_ref()
+        .filter(|fund_fee| !fund_fee.amount.is_zero())
+    {
+        messages.push(TradeStep::Withdraw.sub_msg(MsgWithdrawRequest {
+            denom: contract_state.trading_marker.name.to_owned(),
+            administrator: env.contract.address.to_string(),
+            to_address: fund_fee.collector.to_string(),
+            amount: vec![Coin {
+                denom: minted_coin.denom,
+                amount: fund_fee.amount.to_string(),
+            }],
+        }));
+    }
+    let requires_approval = contract_state.requires_trade_approval(transferred_amount);
+    TradePlan {
+        conversion,
+        collected_amount: transferred_amount,
+        deposit_amount: transferred_amount,
+        messages,
+        msg_fee: attribute_check.msg_fee.filter(|_| !fee_exempt),
+        fee_discount: attribute_check.fee_discount.filter(|_| !fee_exempt),
+        trade_fee: fund_fee,
+        whitelisted,
+        referrer,
+        requires_approval,
+    }
+    .to_ok()
 }
 
 #[cfg(test)]
 mod tests {
     use crate::execute::fund_trading::fund_trading;
-    use crate::store::contract_state::CONTRACT_TYPE;
+    use crate::store::attribute_whitelist::{add_whitelisted_account, remove_whitelisted_account};
+    use crate::store::contract_state::{
+        get_contract_state_v1, set_contract_state_v1, CONTRACT_TYPE, CONTRACT_VERSION,
+    };
+    use crate::store::denylist::add_denylisted_account;
+    use crate::store::fee_exemptions::{add_fee_exemption, remove_fee_exemption};
+    use crate::store::pending_trades::get_pending_trade;
+    use crate::store::referral_stats::{get_referral_stats, ReferralStats};
+    use crate::store::runtime_tunables::{set_runtime_tunables, EventVerbosity, RuntimeTunables};
+    use crate::store::trade_receipts::get_trade_receipt;
+    use crate::store::trade_stats::{
+        day_of, get_daily_trade_totals, get_trade_stats, record_trade, TradeStats, TradeTotals,
+    };
     use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_compliance::mock_compliance_response;
     use crate::test::test_constants::{
         DEFAULT_CONTRACT_NAME, DEFAULT_DEPOSIT_DENOM_NAME, DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE,
         DEFAULT_TRADING_DENOM_NAME,
     };
     use crate::test::test_instantiate::{test_instantiate, test_instantiate_with_msg};
+    use crate::test::test_markers::{
+        mock_marker_params_response, mock_marker_response, test_marker_account,
+    };
+    use crate::test::test_metadata::{mock_scope_ownership_response, TEST_SCOPE_SPEC};
     use crate::types::denom::Denom;
     use crate::types::error::ContractError;
-    use crate::types::msg::InstantiateMsg;
+    use crate::types::msg::{ComplianceResponse, InstantiateMsg};
+    use crate::types::msg_fee::{FeeDiscount, MsgFee};
+    use crate::types::quote::{QuoteRef, TradeDirection};
+    use crate::types::responses::TradeReceiptData;
+    use crate::types::schedule::ScheduledPoint;
+    use crate::types::trade_options::TradeOptions;
+    use crate::types::trade_step::TradeStep;
+    use crate::util::quote_utils::derive_trade_quote;
     use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
-    use cosmwasm_std::{coins, Addr, AnyMsg, CosmosMsg};
+    use cosmwasm_std::{
+        coin, coins, from_json, Addr, AnyMsg, CosmosMsg, DepsMut, Order, ReplyOn, Response,
+        Storage, Uint128,
+    };
     use provwasm_mocks::{
         mock_provenance_dependencies, mock_provenance_dependencies_with_custom_querier,
         MockProvenanceQuerier,
@@ -129,8 +516,9 @@ mod tests {
         Attribute, AttributeType, QueryAttributesRequest, QueryAttributesResponse,
     };
     use provwasm_std::types::provenance::marker::v1::{
-        MsgMintRequest, MsgTransferRequest, MsgWithdrawRequest,
+        Access, AccessGrant, MsgMintRequest, MsgTransferRequest, MsgWithdrawRequest,
     };
+    use provwasm_std::types::provenance::msgfees::v1::MsgAssessCustomMsgFeeRequest;
 
     #[test]
     fn provided_funds_should_cause_an_error() {
@@ -139,7 +527,8 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             message_info(&Addr::unchecked("some-sender"), &coins(10, "nhash")),
-            10,
+            Uint128::new(10),
+            TradeOptions::default(),
         )
         .expect_err("an error should be emitted when coin is provided");
         assert!(
@@ -155,7 +544,8 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             message_info(&Addr::unchecked("some-sender"), &[]),
-            10,
+            Uint128::new(10),
+            TradeOptions::default(),
         )
         .expect_err("an error should be emitted when no contract state exists");
         assert!(
@@ -191,12 +581,62 @@ mod tests {
             },
         );
         let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
-        test_instantiate(deps.as_mut());
-        let error = fund_trading(deps.as_mut(), mock_env(), message_info(&Addr::unchecked("some-sender"), &[]), 10)
+        test_instantiate(&mut deps);
+        let error = fund_trading(deps.as_mut(), mock_env(), message_info(&Addr::unchecked("some-sender"), &[]), Uint128::new(10), TradeOptions::default())
             .expect_err("an error should occur when the sender tries to trade more funds than are available to them");
-        assert!(
-            matches!(error, ContractError::InvalidAccountError { .. }),
-            "unexpected error type encountered when the sender tries to trade too much: {error:?}",
+        assert_eq!(
+            "preconditions failed: invalid account: required [10], but account only holds [9]",
+            error.to_string(),
+            "unexpected error encountered when the sender tries to trade too much",
+        );
+    }
+
+    #[test]
+    fn sender_holding_required_amount_only_through_aliases_should_cause_an_error() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(
+            &mut querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    amount: "9".to_string(),
+                    denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+                }),
+            },
+        );
+        QueryAttributesRequest::mock_response(
+            &mut querier,
+            QueryAttributesResponse {
+                account: "sender".to_string(),
+                attributes: vec![Attribute {
+                    name: DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE.to_string(),
+                    value: vec![],
+                    attribute_type: AttributeType::String as i32,
+                    address: "addr".to_string(),
+                    expiration_date: None,
+                }],
+                pagination: None,
+            },
+        );
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                deposit_denom_aliases: vec!["ibc/ABCDEF".to_string()],
+                ..InstantiateMsg::default()
+            },
+        );
+        let error = fund_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("some-sender"), &[]),
+            Uint128::new(10),
+            TradeOptions::default(),
+        )
+        .expect_err("an error should occur when only the deposit denom aliases cover the trade");
+        assert_eq!(
+            format!("preconditions failed: invalid account: required [10], but account only holds [9] of [{DEFAULT_DEPOSIT_DENOM_NAME}], the only denom that can be transferred; aliases [ibc/ABCDEF] bring its total to [18]"),
+            error.to_string(),
+            "unexpected error encountered when only the deposit denom aliases cover the trade",
         );
     }
 
@@ -221,16 +661,129 @@ mod tests {
             },
         );
         let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
-        test_instantiate(deps.as_mut());
+        test_instantiate(&mut deps);
         let error = fund_trading(
             deps.as_mut(),
             mock_env(),
             message_info(&Addr::unchecked("some-sender"), &[]),
-            10,
+            Uint128::new(10),
+            TradeOptions::default(),
         )
         .expect_err("an error should occur when the sender does not have a required attribute");
-        assert!(
-            matches!(error, ContractError::InvalidAccountError { .. },),
+        assert_eq!(
+            "preconditions failed: invalid account: account does not have all required attributes",
+            error.to_string(),
+            "unexpected error when account is missing required attributes",
+        );
+    }
+
+    #[test]
+    fn sender_missing_required_attribute_and_amount_should_report_both_failures() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(
+            &mut querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    amount: "9".to_string(),
+                    denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+                }),
+            },
+        );
+        QueryAttributesRequest::mock_response(
+            &mut querier,
+            QueryAttributesResponse {
+                account: "some-sender".to_string(),
+                attributes: vec![],
+                pagination: None,
+            },
+        );
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        test_instantiate(&mut deps);
+        let error = fund_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("some-sender"), &[]),
+            Uint128::new(10),
+            TradeOptions::default(),
+        )
+        .expect_err("an error should occur when the sender fails multiple preconditions");
+        match error {
+            ContractError::PreconditionsFailed { failures } => assert_eq!(
+                vec![
+                    "invalid account: account does not have all required attributes".to_string(),
+                    "invalid account: required [10], but account only holds [9]".to_string(),
+                ],
+                failures,
+                "every failed precondition should be reported",
+            ),
+            e => panic!("unexpected error emitted when multiple preconditions fail: {e:?}"),
+        };
+    }
+
+    #[test]
+    fn whitelisted_sender_should_skip_required_attributes_but_not_the_balance_check() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(
+            &mut querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    amount: "100".to_string(),
+                    denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+                }),
+            },
+        );
+        QueryAttributesRequest::mock_response(
+            &mut querier,
+            QueryAttributesResponse {
+                account: "sender".to_string(),
+                attributes: vec![],
+                pagination: None,
+            },
+        );
+        mock_marker_response(
+            &mut querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        mock_marker_params_response(&mut querier, "");
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        test_instantiate(&mut deps);
+        add_whitelisted_account(&mut deps.storage, &Addr::unchecked("sender"))
+            .expect("whitelisting the sender should succeed");
+        let error = fund_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(101),
+            TradeOptions::default(),
+        )
+        .expect_err("a whitelisted sender should still need to hold the traded amount");
+        assert_eq!(
+            "preconditions failed: invalid account: required [101], but account only holds [100]",
+            error.to_string(),
+            "only the balance check should fail for a whitelisted sender",
+        );
+        let response = fund_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(100),
+            TradeOptions::default(),
+        )
+        .expect("a whitelisted sender without the required attributes should be able to trade");
+        response.assert_attribute("whitelisted", "true");
+        remove_whitelisted_account(&mut deps.storage, &Addr::unchecked("sender"))
+            .expect("removing the sender from the whitelist should succeed");
+        let error = fund_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(100),
+            TradeOptions::default(),
+        )
+        .expect_err("a sender removed from the whitelist should need the required attributes");
+        assert_eq!(
+            "preconditions failed: invalid account: account does not have all required attributes",
+            error.to_string(),
             "unexpected error when account is missing required attributes",
         );
     }
@@ -265,7 +818,7 @@ mod tests {
         // Setup trading marker to have a smaller precision than deposit, which will cause a single
         // digit conversion to fail
         test_instantiate_with_msg(
-            deps.as_mut(),
+            &mut deps,
             InstantiateMsg {
                 deposit_marker: Denom::new("denom1", 2),
                 trading_marker: Denom::new("denom2", 1),
@@ -276,21 +829,20 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             message_info(&Addr::unchecked("sender"), &[]),
-            9,
+            Uint128::new(9),
+            TradeOptions::default(),
         )
         .expect_err("a conversion that does not produce any trading denom should fail");
-        let _expected_err =
-            "sent [9denom1], but that is not enough to convert to at least one [denom2]"
-                .to_string();
-        assert!(
-            matches!(
-                error,
-                ContractError::InvalidFundsError {
-                    message: _expected_err,
-                },
+        match error {
+            ContractError::PreconditionsFailed { failures } => assert!(
+                failures.contains(
+                    &"invalid funds: sent [9denom1], but that is not enough to convert to at least one [denom2]"
+                        .to_string()
+                ),
+                "the failed conversion should be reported, but got: {failures:?}",
             ),
-            "unexpected error occurred when invalid conversion occurs",
-        );
+            e => panic!("unexpected error occurred when invalid conversion occurs: {e:?}"),
+        };
     }
 
     #[test]
@@ -319,6 +871,11 @@ mod tests {
                 pagination: None,
             },
         );
+        mock_marker_response(
+            &mut querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        mock_marker_params_response(&mut querier, "");
         let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
         // Setup the trading marker to have a smaller precision than the deposit, requiring some
         // remainder to be returned.  Ex:
@@ -326,18 +883,37 @@ mod tests {
         // of 1, which will convert to 10 (aka 1.0).  The 3 will be dropped and be a remaining value
         // for the sender
         test_instantiate_with_msg(
-            deps.as_mut(),
+            &mut deps,
             InstantiateMsg {
                 deposit_marker: Denom::new(DEFAULT_DEPOSIT_DENOM_NAME, 2),
                 trading_marker: Denom::new(DEFAULT_TRADING_DENOM_NAME, 1),
                 ..InstantiateMsg::default()
             },
         );
+        let error = fund_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(103),
+            TradeOptions::default(),
+        )
+        .expect_err("an error should occur when the remainder is not acknowledged");
+        assert_eq!(
+            format!(
+                "unacknowledged remainder: the trade would leave [3{DEFAULT_DEPOSIT_DENOM_NAME}] unconverted; trade [100{DEFAULT_DEPOSIT_DENOM_NAME}] instead, or set allow_remainder to accept the remainder"
+            ),
+            error.to_string(),
+            "the error should name the remainder and the largest clean amount",
+        );
         let response = fund_trading(
             deps.as_mut(),
             mock_env(),
             message_info(&Addr::unchecked("sender"), &[]),
-            103,
+            Uint128::new(103),
+            TradeOptions {
+                allow_remainder: true,
+                ..TradeOptions::default()
+            },
         )
         .expect("proper circumstances should derive a successful result");
         assert_eq!(
@@ -345,6 +921,19 @@ mod tests {
             response.messages.len(),
             "expected the response to include three messages",
         );
+        assert_eq!(
+            vec![
+                (ReplyOn::Error, TradeStep::Collect.reply_id()),
+                (ReplyOn::Error, TradeStep::Mint.reply_id()),
+                (ReplyOn::Error, TradeStep::Withdraw.reply_id()),
+            ],
+            response
+                .messages
+                .iter()
+                .map(|msg| (msg.reply_on.to_owned(), msg.id))
+                .collect::<Vec<_>>(),
+            "each message should reply on error with the id of the step that it performs",
+        );
         response.messages.iter().for_each(|msg| match &msg.msg {
             CosmosMsg::Any(AnyMsg { type_url, value }) => match type_url.as_str() {
                 "/provenance.marker.v1.MsgTransferRequest" => {
@@ -437,29 +1026,65 @@ mod tests {
             msg => panic!("unexpected message emitted: {msg:?}"),
         });
         assert_eq!(
-            9,
+            11,
             response.attributes.len(),
-            "expected nine attributes to be emitted",
+            "expected eleven attributes to be emitted",
         );
         response.assert_attribute("action", "fund_trading");
         response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
         response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
         response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
         response.assert_attribute("deposit_input_denom", DEFAULT_DEPOSIT_DENOM_NAME);
         response.assert_attribute("deposit_requested_amount", "103");
         response.assert_attribute("deposit_actual_amount", "100");
         response.assert_attribute("received_denom", DEFAULT_TRADING_DENOM_NAME);
         response.assert_attribute("received_amount", "10");
+        response.assert_attribute("receipt", "1");
+        assert_eq!(
+            TradeReceiptData { receipt: 1 },
+            from_json::<TradeReceiptData>(
+                response.data.expect("the response should include data"),
+            )
+            .expect("the response data should deserialize"),
+            "the response data should identify the trade's receipt",
+        );
+        let trade_receipt =
+            get_trade_receipt(&deps.storage, 1).expect("the trade's receipt should load");
+        assert_eq!(
+            "sender",
+            trade_receipt.account.as_str(),
+            "the sender should be recorded",
+        );
+        assert_eq!(
+            TradeDirection::Fund,
+            trade_receipt.direction,
+            "the direction should be recorded",
+        );
+        assert_eq!(
+            coin(100, DEFAULT_DEPOSIT_DENOM_NAME),
+            trade_receipt.input,
+            "the collected deposit denom should be recorded as the input",
+        );
+        assert_eq!(
+            coin(10, DEFAULT_TRADING_DENOM_NAME),
+            trade_receipt.output,
+            "the received trading denom should be recorded as the output",
+        );
+        assert_eq!(
+            Uint128::new(3),
+            trade_receipt.remainder,
+            "the remainder should be recorded",
+        );
     }
 
-    #[test]
-    fn request_that_does_not_need_full_amount_expected_succeeds() {
+    fn trade_with_event_verbosity(verbosity: EventVerbosity) -> Response {
         let mut querier = MockProvenanceQuerier::new(&[]);
         QueryBalanceRequest::mock_response(
             &mut querier,
             QueryBalanceResponse {
                 balance: Some(Coin {
-                    amount: "200".to_string(),
+                    amount: "103".to_string(),
                     denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
                 }),
             },
@@ -478,25 +1103,1584 @@ mod tests {
                 pagination: None,
             },
         );
+        mock_marker_response(
+            &mut querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        mock_marker_params_response(&mut querier, "");
         let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
-        // Setup the trading marker to have a smaller precision than the deposit, requiring some
-        // remainder to be returned.  Ex:
-        // Sender wants to send 250, which equates to 2.50.  They don't actually have 250, but they
-        // do have 200, which is allowed.  This should be allowed to proceed.
         test_instantiate_with_msg(
-            deps.as_mut(),
+            &mut deps,
             InstantiateMsg {
-                deposit_marker: Denom::new(DEFAULT_DEPOSIT_DENOM_NAME, 3),
+                deposit_marker: Denom::new(DEFAULT_DEPOSIT_DENOM_NAME, 2),
                 trading_marker: Denom::new(DEFAULT_TRADING_DENOM_NAME, 1),
                 ..InstantiateMsg::default()
             },
         );
+        set_runtime_tunables(
+            &mut deps.storage,
+            &RuntimeTunables {
+                event_verbosity: verbosity,
+                ..RuntimeTunables::default()
+            },
+        )
+        .expect("setting the tunables should succeed");
         fund_trading(
             deps.as_mut(),
             mock_env(),
             message_info(&Addr::unchecked("sender"), &[]),
-            250,
+            Uint128::new(103),
+            TradeOptions {
+                allow_remainder: true,
+                ..TradeOptions::default()
+            },
         )
-        .expect("proper circumstances should derive a successful result");
+        .expect("proper circumstances should derive a successful result")
+    }
+
+    #[test]
+    fn event_verbosity_should_determine_the_emitted_attributes() {
+        let pair = format!("{DEFAULT_DEPOSIT_DENOM_NAME}/{DEFAULT_TRADING_DENOM_NAME}");
+        let standard_keys = vec![
+            "action",
+            "contract_address",
+            "contract_type",
+            "contract_version",
+            "contract_name",
+            "deposit_input_denom",
+            "deposit_requested_amount",
+            "deposit_actual_amount",
+            "received_denom",
+            "received_amount",
+        ];
+        let response = trade_with_event_verbosity(EventVerbosity::Minimal);
+        assert_eq!(
+            vec!["action", "pair", "sender", "input", "output", "receipt"],
+            response
+                .attributes
+                .iter()
+                .map(|attr| attr.key.as_str())
+                .collect::<Vec<_>>(),
+            "the minimal level should only emit the trade's essentials",
+        );
+        assert_eq!(
+            3,
+            response.messages.len(),
+            "the verbosity should not change the emitted messages",
+        );
+        response.assert_attribute("action", "fund_trading");
+        response.assert_attribute("pair", &pair);
+        response.assert_attribute("sender", "sender");
+        response.assert_attribute("input", format!("100{DEFAULT_DEPOSIT_DENOM_NAME}"));
+        response.assert_attribute("output", format!("10{DEFAULT_TRADING_DENOM_NAME}"));
+        let response = trade_with_event_verbosity(EventVerbosity::Standard);
+        assert_eq!(
+            [standard_keys.to_owned(), vec!["receipt"]].concat(),
+            response
+                .attributes
+                .iter()
+                .map(|attr| attr.key.as_str())
+                .collect::<Vec<_>>(),
+            "the standard level should emit the route's attributes",
+        );
+        let response = trade_with_event_verbosity(EventVerbosity::Verbose);
+        assert_eq!(
+            [
+                standard_keys,
+                vec![
+                    "sender",
+                    "pair",
+                    "remainder",
+                    "requires_approval",
+                    "block_height",
+                    "receipt",
+                ],
+            ]
+            .concat(),
+            response
+                .attributes
+                .iter()
+                .map(|attr| attr.key.as_str())
+                .collect::<Vec<_>>(),
+            "the verbose level should emit every attribute",
+        );
+        response.assert_attribute("sender", "sender");
+        response.assert_attribute("pair", &pair);
+        response.assert_attribute("remainder", "3");
+        response.assert_attribute("requires_approval", "false");
+        response.assert_attribute("block_height", mock_env().block.height.to_string());
+    }
+
+    #[test]
+    fn request_that_does_not_need_full_amount_expected_succeeds() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(
+            &mut querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    amount: "200".to_string(),
+                    denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+                }),
+            },
+        );
+        QueryAttributesRequest::mock_response(
+            &mut querier,
+            QueryAttributesResponse {
+                account: "sender".to_string(),
+                attributes: vec![Attribute {
+                    name: DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE.to_string(),
+                    value: vec![],
+                    attribute_type: AttributeType::String as i32,
+                    address: "addr".to_string(),
+                    expiration_date: None,
+                }],
+                pagination: None,
+            },
+        );
+        mock_marker_response(
+            &mut querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        mock_marker_params_response(&mut querier, "");
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        // Setup the trading marker to have a smaller precision than the deposit, requiring some
+        // remainder to be returned.  Ex:
+        // Sender wants to send 250, which equates to 2.50.  They don't actually have 250, but they
+        // do have 200, which is allowed.  This should be allowed to proceed.
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                deposit_marker: Denom::new(DEFAULT_DEPOSIT_DENOM_NAME, 3),
+                trading_marker: Denom::new(DEFAULT_TRADING_DENOM_NAME, 1),
+                ..InstantiateMsg::default()
+            },
+        );
+        fund_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(250),
+            TradeOptions {
+                allow_remainder: true,
+                ..TradeOptions::default()
+            },
+        )
+        .expect("proper circumstances should derive a successful result");
+    }
+
+    #[test]
+    fn skipped_balance_precheck_should_not_query_sender_balance() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        // No balance response is mocked, so any balance query made by the route would fail
+        QueryAttributesRequest::mock_response(
+            &mut querier,
+            QueryAttributesResponse {
+                account: "sender".to_string(),
+                attributes: vec![Attribute {
+                    name: DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE.to_string(),
+                    value: vec![],
+                    attribute_type: AttributeType::String as i32,
+                    address: "addr".to_string(),
+                    expiration_date: None,
+                }],
+                pagination: None,
+            },
+        );
+        mock_marker_response(
+            &mut querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        mock_marker_params_response(&mut querier, "");
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                skip_balance_precheck: true,
+                ..InstantiateMsg::default()
+            },
+        );
+        let response = fund_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(100),
+            TradeOptions::default(),
+        )
+        .expect("the trade should succeed without a balance query when the precheck is skipped");
+        assert_eq!(
+            3,
+            response.messages.len(),
+            "expected the response to include all three messages",
+        );
+    }
+
+    #[test]
+    fn mint_exceeding_marker_max_supply_should_cause_an_error() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(
+            &mut querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    amount: "100".to_string(),
+                    denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+                }),
+            },
+        );
+        QueryAttributesRequest::mock_response(
+            &mut querier,
+            QueryAttributesResponse {
+                account: "sender".to_string(),
+                attributes: vec![Attribute {
+                    name: DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE.to_string(),
+                    value: vec![],
+                    attribute_type: AttributeType::String as i32,
+                    address: "addr".to_string(),
+                    expiration_date: None,
+                }],
+                pagination: None,
+            },
+        );
+        mock_marker_response(
+            &mut querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "500000"),
+        );
+        mock_marker_params_response(&mut querier, "1000000");
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        test_instantiate(&mut deps);
+        // 100 deposit equates to 1000000 trading, which would raise the supply above its max
+        let error = fund_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(100),
+            TradeOptions::default(),
+        )
+        .expect_err("an error should occur when the mint would exceed the marker's max supply");
+        assert_eq!(
+            format!(
+                "validation failed: minting [1000000] would raise the supply of marker [{DEFAULT_TRADING_DENOM_NAME}] to [1500000], exceeding the max supply of [1000000] by [500000]",
+            ),
+            error.to_string(),
+            "unexpected error emitted when the mint would exceed the marker's max supply",
+        );
+    }
+
+    #[test]
+    fn quoted_trade_should_require_a_matching_quote() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(
+            &mut querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    amount: "101".to_string(),
+                    denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+                }),
+            },
+        );
+        QueryAttributesRequest::mock_response(
+            &mut querier,
+            QueryAttributesResponse {
+                account: "sender".to_string(),
+                attributes: vec![Attribute {
+                    name: DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE.to_string(),
+                    value: vec![],
+                    attribute_type: AttributeType::String as i32,
+                    address: "addr".to_string(),
+                    expiration_date: None,
+                }],
+                pagination: None,
+            },
+        );
+        mock_marker_response(
+            &mut querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        mock_marker_params_response(&mut querier, "");
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        test_instantiate(&mut deps);
+        let env = mock_env();
+        let quote = derive_trade_quote(
+            &env,
+            &get_contract_state_v1(&deps.storage).expect("contract state should load"),
+            &RuntimeTunables::default(),
+            TradeDirection::Fund,
+            Uint128::new(100),
+            env.block.height,
+        )
+        .expect("the quote should be derived");
+        let quote_ref = QuoteRef {
+            hash: quote.hash,
+            quoted_at_height: quote.quoted_at_height,
+        };
+        let error = fund_trading(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(101),
+            TradeOptions {
+                quote: Some(quote_ref.clone()),
+                ..TradeOptions::default()
+            },
+        )
+        .expect_err("an error should occur when the trade does not match its quote");
+        assert!(
+            matches!(error, ContractError::InvalidQuoteError { .. }),
+            "unexpected error emitted when the trade does not match its quote: {error:?}",
+        );
+        let response = fund_trading(
+            deps.as_mut(),
+            env,
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(100),
+            TradeOptions {
+                quote: Some(quote_ref),
+                ..TradeOptions::default()
+            },
+        )
+        .expect("a trade matching its quote should succeed");
+        assert_eq!(
+            3,
+            response.messages.len(),
+            "a trade matching its quote should emit all of its messages",
+        );
+    }
+
+    #[test]
+    fn dry_run_should_emit_attributes_without_messages() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(
+            &mut querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    amount: "103".to_string(),
+                    denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+                }),
+            },
+        );
+        QueryAttributesRequest::mock_response(
+            &mut querier,
+            QueryAttributesResponse {
+                account: "sender".to_string(),
+                attributes: vec![Attribute {
+                    name: DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE.to_string(),
+                    value: vec![],
+                    attribute_type: AttributeType::String as i32,
+                    address: "addr".to_string(),
+                    expiration_date: None,
+                }],
+                pagination: None,
+            },
+        );
+        mock_marker_response(
+            &mut querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        mock_marker_params_response(&mut querier, "");
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                deposit_marker: Denom::new(DEFAULT_DEPOSIT_DENOM_NAME, 2),
+                trading_marker: Denom::new(DEFAULT_TRADING_DENOM_NAME, 1),
+                ..InstantiateMsg::default()
+            },
+        );
+        let storage_before = deps
+            .storage
+            .range(None, None, Order::Ascending)
+            .collect::<Vec<_>>();
+        let response = fund_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(103),
+            TradeOptions {
+                dry_run: true,
+                allow_remainder: true,
+                ..TradeOptions::default()
+            },
+        )
+        .expect("a dry run with proper circumstances should derive a successful result");
+        assert!(
+            response.messages.is_empty(),
+            "a dry run should not emit any messages",
+        );
+        assert_eq!(
+            13,
+            response.attributes.len(),
+            "expected all trade attributes to be emitted, as well as the dry run attributes",
+        );
+        response.assert_attribute("dry_run", "true");
+        response.assert_attribute("requires_approval", "false");
+        response.assert_attribute("remainder", "3");
+        response.assert_attribute("action", "fund_trading");
+        response.assert_attribute("deposit_requested_amount", "103");
+        response.assert_attribute("deposit_actual_amount", "100");
+        response.assert_attribute("received_amount", "10");
+        assert_eq!(
+            storage_before,
+            deps.storage
+                .range(None, None, Order::Ascending)
+                .collect::<Vec<_>>(),
+            "a dry run should not modify contract storage",
+        );
+    }
+
+    #[test]
+    fn trade_at_large_trade_threshold_should_be_held_for_approval() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(
+            &mut querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    amount: "100".to_string(),
+                    denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+                }),
+            },
+        );
+        QueryAttributesRequest::mock_response(
+            &mut querier,
+            QueryAttributesResponse {
+                account: "sender".to_string(),
+                attributes: vec![Attribute {
+                    name: DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE.to_string(),
+                    value: vec![],
+                    attribute_type: AttributeType::String as i32,
+                    address: "addr".to_string(),
+                    expiration_date: None,
+                }],
+                pagination: None,
+            },
+        );
+        mock_marker_response(
+            &mut querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        mock_marker_params_response(&mut querier, "");
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                large_trade_threshold: Some(Uint128::new(100)),
+                ..InstantiateMsg::default()
+            },
+        );
+        let response = fund_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(99),
+            TradeOptions::default(),
+        )
+        .expect("a trade below the threshold should succeed");
+        assert_eq!(
+            3,
+            response.messages.len(),
+            "a trade below the threshold should execute immediately",
+        );
+        let response = fund_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(100),
+            TradeOptions::default(),
+        )
+        .expect("a trade at the threshold should be accepted for approval");
+        assert!(
+            response.messages.is_empty(),
+            "a trade at the threshold should not emit any messages",
+        );
+        response.assert_attribute("action", "fund_trading");
+        response.assert_attribute("deposit_actual_amount", "100");
+        response.assert_attribute("pending_trade_id", "1");
+        assert_eq!(
+            "trade_pending_approval",
+            response
+                .events
+                .first()
+                .expect("the pending approval event should be emitted")
+                .ty,
+            "the pending approval event should be emitted",
+        );
+        let pending_trade =
+            get_pending_trade(&deps.storage, 1).expect("the pending trade should be stored");
+        assert_eq!(
+            TradeDirection::Fund,
+            pending_trade.direction,
+            "the pending trade should record its direction",
+        );
+        assert_eq!(
+            "sender",
+            pending_trade.sender.as_str(),
+            "the pending trade should record its sender",
+        );
+        assert_eq!(
+            Uint128::new(100),
+            pending_trade.trade_amount,
+            "the pending trade should record its requested amount",
+        );
+        assert_eq!(
+            TradeStats {
+                totals: TradeTotals {
+                    fund_count: 1,
+                    fund_volume: Uint128::new(99),
+                    ..TradeTotals::default()
+                },
+                account_count: 1,
+            },
+            get_trade_stats(&deps.storage).expect("the trade stats should load"),
+            "only the executed trade should be recorded in the trade stats",
+        );
+    }
+
+    #[test]
+    fn configured_msg_fee_should_be_assessed_on_accepted_trades() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(
+            &mut querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    amount: "100".to_string(),
+                    denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+                }),
+            },
+        );
+        QueryAttributesRequest::mock_response(
+            &mut querier,
+            QueryAttributesResponse {
+                account: "sender".to_string(),
+                attributes: vec![Attribute {
+                    name: DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE.to_string(),
+                    value: vec![],
+                    attribute_type: AttributeType::String as i32,
+                    address: "addr".to_string(),
+                    expiration_date: None,
+                }],
+                pagination: None,
+            },
+        );
+        mock_marker_response(
+            &mut querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        mock_marker_params_response(&mut querier, "");
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                large_trade_threshold: Some(Uint128::new(100)),
+                ..InstantiateMsg::default()
+            },
+        );
+        let response = fund_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(99),
+            TradeOptions::default(),
+        )
+        .expect("a trade without a configured fee should succeed");
+        assert_eq!(
+            3,
+            response.messages.len(),
+            "only the trade's messages should be emitted when no fee is configured",
+        );
+        assert!(
+            response.messages.iter().all(|msg| !matches!(
+                &msg.msg,
+                CosmosMsg::Any(AnyMsg { type_url, .. }) if type_url == "/provenance.msgfees.v1.MsgAssessCustomMsgFeeRequest",
+            )),
+            "no fee should be assessed when no fee is configured",
+        );
+        assert!(
+            response.attributes.iter().all(|attr| attr.key != "msg_fee"),
+            "no fee attribute should be emitted when no fee is configured",
+        );
+        let mut contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        contract_state.msg_fee = Some(MsgFee {
+            amount: coin(250, "nhash"),
+            recipient: "fee-recipient".to_string(),
+        });
+        set_contract_state_v1(&mut deps.storage, &contract_state)
+            .expect("contract state should be updated");
+        let assert_fee_msg = |msg: &CosmosMsg| match msg {
+            CosmosMsg::Any(AnyMsg { type_url, value }) => {
+                assert_eq!(
+                    "/provenance.msgfees.v1.MsgAssessCustomMsgFeeRequest", type_url,
+                    "the fee should be assessed after the trade's messages",
+                );
+                let req = MsgAssessCustomMsgFeeRequest::try_from(value.to_owned())
+                    .expect("the value should properly deserialize to an assess fee request");
+                assert_eq!(
+                    MsgAssessCustomMsgFeeRequest {
+                        name: "fund_trading".to_string(),
+                        amount: Some(Coin {
+                            denom: "nhash".to_string(),
+                            amount: "250".to_string(),
+                        }),
+                        recipient: "fee-recipient".to_string(),
+                        from: MOCK_CONTRACT_ADDR.to_string(),
+                        recipient_basis_points: "10000".to_string(),
+                    },
+                    req,
+                    "the configured fee should be assessed by the contract",
+                );
+            }
+            msg => panic!("unexpected message emitted: {msg:?}"),
+        };
+        let response = fund_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(99),
+            TradeOptions::default(),
+        )
+        .expect("a trade with a configured fee should succeed");
+        assert_eq!(
+            4,
+            response.messages.len(),
+            "the fee should be emitted alongside the trade's messages",
+        );
+        assert_fee_msg(&response.messages[3].msg);
+        response.assert_attribute("msg_fee", "250nhash");
+        let response = fund_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(100),
+            TradeOptions::default(),
+        )
+        .expect("a trade at the threshold should be accepted for approval");
+        assert_eq!(
+            1,
+            response.messages.len(),
+            "only the fee should be emitted when the trade is held for approval",
+        );
+        assert_fee_msg(&response.messages[0].msg);
+        response.assert_attribute("pending_trade_id", "1");
+        let response = fund_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(99),
+            TradeOptions {
+                dry_run: true,
+                ..TradeOptions::default()
+            },
+        )
+        .expect("a dry run with a configured fee should succeed");
+        assert!(
+            response.messages.is_empty(),
+            "a dry run should not assess the fee",
+        );
+    }
+
+    #[test]
+    fn held_fee_discount_should_reduce_the_assessed_fee() {
+        for (held_attribute, expected_fee) in [
+            ("loyalty.gold.pb", Some("125nhash")),
+            ("loyalty.platinum.pb", None),
+        ] {
+            let mut querier = MockProvenanceQuerier::new(&[]);
+            QueryBalanceRequest::mock_response(
+                &mut querier,
+                QueryBalanceResponse {
+                    balance: Some(Coin {
+                        amount: "100".to_string(),
+                        denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+                    }),
+                },
+            );
+            QueryAttributesRequest::mock_response(
+                &mut querier,
+                QueryAttributesResponse {
+                    account: "sender".to_string(),
+                    attributes: [DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE, held_attribute]
+                        .iter()
+                        .map(|name| Attribute {
+                            name: name.to_string(),
+                            value: vec![],
+                            attribute_type: AttributeType::String as i32,
+                            address: "addr".to_string(),
+                            expiration_date: None,
+                        })
+                        .collect(),
+                    pagination: None,
+                },
+            );
+            mock_marker_response(
+                &mut querier,
+                test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+            );
+            mock_marker_params_response(&mut querier, "");
+            let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+            test_instantiate(&mut deps);
+            let mut contract_state =
+                get_contract_state_v1(&deps.storage).expect("contract state should load");
+            contract_state.msg_fee = Some(MsgFee {
+                amount: coin(250, "nhash"),
+                recipient: "fee-recipient".to_string(),
+            });
+            contract_state.fee_discounts = vec![
+                FeeDiscount {
+                    attribute: "loyalty.gold.pb".to_string(),
+                    discount_bps_off: 5000,
+                },
+                FeeDiscount {
+                    attribute: "loyalty.platinum.pb".to_string(),
+                    discount_bps_off: 10000,
+                },
+            ];
+            set_contract_state_v1(&mut deps.storage, &contract_state)
+                .expect("contract state should be updated");
+            let response = fund_trading(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&Addr::unchecked("sender"), &[]),
+                Uint128::new(100),
+                TradeOptions::default(),
+            )
+            .expect("a trade by a sender holding a discount should succeed");
+            response.assert_attribute("fee_discount_applied", held_attribute);
+            if let Some(expected_fee) = expected_fee {
+                assert_eq!(
+                    4,
+                    response.messages.len(),
+                    "the discounted fee should be emitted alongside the trade's messages",
+                );
+                response.assert_attribute("msg_fee", expected_fee);
+                response.assert_attribute("fee_discount_bps_off", "5000");
+            } else {
+                assert_eq!(
+                    3,
+                    response.messages.len(),
+                    "no fee should be assessed when the discount waives it",
+                );
+                assert!(
+                    response.attributes.iter().all(|attr| attr.key != "msg_fee"),
+                    "no fee attribute should be emitted when the discount waives the fee",
+                );
+                response.assert_attribute("fee_discount_bps_off", "10000");
+            }
+        }
+    }
+
+    #[test]
+    fn configured_fund_fee_should_be_withdrawn_to_the_collector() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(
+            &mut querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    amount: "100".to_string(),
+                    denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+                }),
+            },
+        );
+        QueryAttributesRequest::mock_response(
+            &mut querier,
+            QueryAttributesResponse {
+                account: "sender".to_string(),
+                attributes: vec![Attribute {
+                    name: DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE.to_string(),
+                    value: vec![],
+                    attribute_type: AttributeType::String as i32,
+                    address: "addr".to_string(),
+                    expiration_date: None,
+                }],
+                pagination: None,
+            },
+        );
+        mock_marker_response(
+            &mut querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        mock_marker_params_response(&mut querier, "");
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        test_instantiate(&mut deps);
+        let trade = |deps: DepsMut| {
+            fund_trading(
+                deps,
+                mock_env(),
+                message_info(&Addr::unchecked("sender"), &[]),
+                Uint128::new(99),
+                TradeOptions::default(),
+            )
+            .expect("the trade should succeed")
+        };
+        let withdrawals = |response: &Response| {
+            response
+                .messages
+                .iter()
+                .filter_map(|msg| match &msg.msg {
+                    CosmosMsg::Any(AnyMsg { type_url, value })
+                        if type_url == "/provenance.marker.v1.MsgWithdrawRequest" =>
+                    {
+                        let req = MsgWithdrawRequest::try_from(value.to_owned())
+                            .expect("the value should properly deserialize to a withdraw request");
+                        Some((req.to_address, req.amount[0].amount.to_owned()))
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<(String, String)>>()
+        };
+        let unset_fee_response = trade(deps.as_mut());
+        assert!(
+            unset_fee_response
+                .attributes
+                .iter()
+                .all(|attr| attr.key != "fee_amount" && attr.key != "fee_collector"),
+            "no fee attributes should be emitted when no fund fee is configured",
+        );
+        let mut contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        contract_state.fund_fee_bps = Some(0);
+        contract_state.fee_collector = Some(Addr::unchecked("collector"));
+        set_contract_state_v1(&mut deps.storage, &contract_state)
+            .expect("contract state should be updated");
+        assert_eq!(
+            unset_fee_response.messages,
+            trade(deps.as_mut()).messages,
+            "a zero fund fee should emit exactly the messages of an unset fee",
+        );
+        contract_state.fund_fee_bps = Some(25);
+        set_contract_state_v1(&mut deps.storage, &contract_state)
+            .expect("contract state should be updated");
+        let response = trade(deps.as_mut());
+        assert_eq!(
+            4,
+            response.messages.len(),
+            "the fee withdrawal should be emitted after the trade's messages",
+        );
+        assert_eq!(
+            vec![
+                ("sender".to_string(), "987525".to_string()),
+                ("collector".to_string(), "2475".to_string()),
+            ],
+            withdrawals(&response),
+            "the minted coin should be split between the sender and the collector",
+        );
+        response.assert_attribute("received_amount", "987525");
+        response.assert_attribute("fee_amount", "2475");
+        response.assert_attribute("fee_collector", "collector");
+        add_fee_exemption(&mut deps.storage, &Addr::unchecked("sender"))
+            .expect("adding an exemption should succeed");
+        assert_eq!(
+            unset_fee_response.messages,
+            trade(deps.as_mut()).messages,
+            "a fee-exempt sender should receive the full minted amount",
+        );
+        remove_fee_exemption(&mut deps.storage, &Addr::unchecked("sender"))
+            .expect("removing an exemption should succeed");
+        // With matching precisions, 99 deposit mints 99 trading, and the fee rounds down to zero
+        contract_state.trading_marker = Denom::new(DEFAULT_TRADING_DENOM_NAME, 2);
+        set_contract_state_v1(&mut deps.storage, &contract_state)
+            .expect("contract state should be updated");
+        let response = trade(deps.as_mut());
+        assert_eq!(
+            vec![("sender".to_string(), "99".to_string())],
+            withdrawals(&response),
+            "a fee that rounds down to zero should not be withdrawn",
+        );
+        response.assert_attribute("received_amount", "99");
+        response.assert_attribute("fee_amount", "0");
+    }
+
+    #[test]
+    fn required_scope_spec_should_gate_fund_trading() {
+        for (owned_scope_spec, should_succeed) in [
+            ("scopespec1qjpreurq8n7ql8aumrg2f6qfm3j0ns5rmt", false),
+            (TEST_SCOPE_SPEC, true),
+        ] {
+            let mut querier = MockProvenanceQuerier::new(&[]);
+            QueryBalanceRequest::mock_response(
+                &mut querier,
+                QueryBalanceResponse {
+                    balance: Some(Coin {
+                        amount: "100".to_string(),
+                        denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+                    }),
+                },
+            );
+            QueryAttributesRequest::mock_response(
+                &mut querier,
+                QueryAttributesResponse {
+                    account: "sender".to_string(),
+                    attributes: vec![Attribute {
+                        name: DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE.to_string(),
+                        value: vec![],
+                        attribute_type: AttributeType::String as i32,
+                        address: "addr".to_string(),
+                        expiration_date: None,
+                    }],
+                    pagination: None,
+                },
+            );
+            mock_marker_response(
+                &mut querier,
+                test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+            );
+            mock_marker_params_response(&mut querier, "");
+            mock_scope_ownership_response(&mut querier, &["scope-uuid"], owned_scope_spec);
+            let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+            test_instantiate_with_msg(
+                &mut deps,
+                InstantiateMsg {
+                    required_scope_spec: Some(TEST_SCOPE_SPEC.to_string()),
+                    ..InstantiateMsg::default()
+                },
+            );
+            let result = fund_trading(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&Addr::unchecked("sender"), &[]),
+                Uint128::new(100),
+                TradeOptions::default(),
+            );
+            if should_succeed {
+                let response = result.expect("a sender owning a scope with the spec should trade");
+                assert_eq!(
+                    3,
+                    response.messages.len(),
+                    "the trade's messages should be emitted",
+                );
+            } else {
+                match result.expect_err("a sender without a scope with the spec should fail") {
+                    ContractError::PreconditionsFailed { failures } => assert_eq!(
+                        vec![format!(
+                            "invalid account: account does not own a scope with specification [{TEST_SCOPE_SPEC}]"
+                        )],
+                        failures,
+                        "the missing scope should be the only failure, naming the required spec",
+                    ),
+                    e => panic!("unexpected error emitted by a missing scope: {e:?}"),
+                };
+            }
+        }
+    }
+
+    #[test]
+    fn only_whitelisted_senders_should_skip_the_required_scope_spec() {
+        for whitelisted in [true, false] {
+            let mut querier = MockProvenanceQuerier::new(&[]);
+            QueryBalanceRequest::mock_response(
+                &mut querier,
+                QueryBalanceResponse {
+                    balance: Some(Coin {
+                        amount: "100".to_string(),
+                        denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+                    }),
+                },
+            );
+            QueryAttributesRequest::mock_response(
+                &mut querier,
+                QueryAttributesResponse {
+                    account: "sender".to_string(),
+                    attributes: vec![Attribute {
+                        name: DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE.to_string(),
+                        value: vec![],
+                        attribute_type: AttributeType::String as i32,
+                        address: "addr".to_string(),
+                        expiration_date: None,
+                    }],
+                    pagination: None,
+                },
+            );
+            mock_marker_response(
+                &mut querier,
+                test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+            );
+            mock_marker_params_response(&mut querier, "");
+            // The sender owns no scope at all, so the trade only succeeds if the check is skipped
+            mock_scope_ownership_response(&mut querier, &[], TEST_SCOPE_SPEC);
+            let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+            test_instantiate_with_msg(
+                &mut deps,
+                InstantiateMsg {
+                    required_scope_spec: Some(TEST_SCOPE_SPEC.to_string()),
+                    ..InstantiateMsg::default()
+                },
+            );
+            if whitelisted {
+                add_whitelisted_account(&mut deps.storage, &Addr::unchecked("sender"))
+                    .expect("whitelisting the sender should succeed");
+            } else {
+                // Fee exemption only changes the fees assessed, and does not waive the scope
+                add_fee_exemption(&mut deps.storage, &Addr::unchecked("sender"))
+                    .expect("exempting the sender should succeed");
+            }
+            let result = fund_trading(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&Addr::unchecked("sender"), &[]),
+                Uint128::new(100),
+                TradeOptions::default(),
+            );
+            if whitelisted {
+                let response = result
+                    .expect("a whitelisted sender without a scope with the spec should trade");
+                assert_eq!(
+                    3,
+                    response.messages.len(),
+                    "the trade's messages should be emitted",
+                );
+            } else {
+                match result.expect_err("a fee-exempt sender without a scope with the spec should fail") {
+                    ContractError::PreconditionsFailed { failures } => assert_eq!(
+                        vec![format!(
+                            "invalid account: account does not own a scope with specification [{TEST_SCOPE_SPEC}]"
+                        )],
+                        failures,
+                        "the missing scope should still be checked for a fee-exempt sender",
+                    ),
+                    e => panic!("unexpected error emitted by a missing scope: {e:?}"),
+                };
+            }
+        }
+    }
+
+    #[test]
+    fn compliance_contract_should_veto_fund_trading() {
+        for (approved, should_succeed) in [(false, false), (true, true)] {
+            let mut querier = MockProvenanceQuerier::new(&[]);
+            QueryBalanceRequest::mock_response(
+                &mut querier,
+                QueryBalanceResponse {
+                    balance: Some(Coin {
+                        amount: "100".to_string(),
+                        denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+                    }),
+                },
+            );
+            QueryAttributesRequest::mock_response(
+                &mut querier,
+                QueryAttributesResponse {
+                    account: "sender".to_string(),
+                    attributes: vec![Attribute {
+                        name: DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE.to_string(),
+                        value: vec![],
+                        attribute_type: AttributeType::String as i32,
+                        address: "addr".to_string(),
+                        expiration_date: None,
+                    }],
+                    pagination: None,
+                },
+            );
+            mock_marker_response(
+                &mut querier,
+                test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+            );
+            mock_marker_params_response(&mut querier, "");
+            let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+            let compliance_contract = deps.api.addr_make("compliance");
+            mock_compliance_response(
+                &mut deps.querier,
+                &compliance_contract,
+                ComplianceResponse {
+                    approved,
+                    reason: Some("sender is under review".to_string()),
+                },
+            );
+            test_instantiate_with_msg(
+                &mut deps,
+                InstantiateMsg {
+                    compliance_contract: Some(compliance_contract.to_string()),
+                    ..InstantiateMsg::default()
+                },
+            );
+            let result = fund_trading(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&Addr::unchecked("sender"), &[]),
+                Uint128::new(100),
+                TradeOptions::default(),
+            );
+            if should_succeed {
+                let response = result.expect("a trade approved by compliance should succeed");
+                assert_eq!(
+                    3,
+                    response.messages.len(),
+                    "the trade's messages should be emitted",
+                );
+            } else {
+                match result.expect_err("a trade rejected by compliance should fail") {
+                    ContractError::PreconditionsFailed { failures } => assert_eq!(
+                        vec![format!(
+                            "compliance check failed: compliance contract [{compliance_contract}] rejected the trade: sender is under review"
+                        )],
+                        failures,
+                        "the compliance rejection should be the only failure, including its reason",
+                    ),
+                    e => panic!("unexpected error emitted by a compliance rejection: {e:?}"),
+                };
+            }
+        }
+    }
+
+    #[test]
+    fn trading_schedule_should_gate_fund_trading() {
+        let env = mock_env();
+        for (trading_start, sunset_at) in [
+            (Some(ScheduledPoint::Height(env.block.height + 1)), None),
+            (None, Some(ScheduledPoint::Time(env.block.time))),
+        ] {
+            let mut deps = mock_provenance_dependencies();
+            test_instantiate(&mut deps);
+            let mut contract_state =
+                get_contract_state_v1(&deps.storage).expect("contract state should load");
+            contract_state.trading_start = trading_start;
+            contract_state.sunset_at = sunset_at;
+            set_contract_state_v1(&mut deps.storage, &contract_state)
+                .expect("contract state should be stored");
+            let error = fund_trading(
+                deps.as_mut(),
+                env.to_owned(),
+                message_info(&Addr::unchecked("sender"), &[]),
+                Uint128::new(100),
+                TradeOptions::default(),
+            )
+            .expect_err("a trade outside of the trading schedule should fail");
+            match error {
+                ContractError::PreconditionsFailed { failures } => assert!(
+                    failures
+                        .iter()
+                        .any(|failure| failure.starts_with("trading is closed: ")),
+                    "the closed trading window should be reported, but got: {failures:?}",
+                ),
+                e => panic!("unexpected error encountered: {e:?}"),
+            };
+        }
+    }
+
+    #[test]
+    fn disabled_route_should_reject_fund_trading() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let mut contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        contract_state.fund_enabled = false;
+        set_contract_state_v1(&mut deps.storage, &contract_state)
+            .expect("contract state should be stored");
+        let error = fund_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(100),
+            TradeOptions::default(),
+        )
+        .expect_err("a trade through a disabled route should fail");
+        match error {
+            ContractError::PreconditionsFailed { failures } => assert!(
+                failures.contains(
+                    &"route disabled: fund_trading is disabled by the contract admin".to_string()
+                ),
+                "the disabled route should be reported, but got: {failures:?}",
+            ),
+            e => panic!("unexpected error encountered: {e:?}"),
+        };
+    }
+
+    #[test]
+    fn amount_below_the_minimum_should_reject_fund_trading() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(
+            &mut querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    amount: "50".to_string(),
+                    denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+                }),
+            },
+        );
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        test_instantiate(&mut deps);
+        let mut contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        contract_state.min_fund_amount = Some(Uint128::new(100));
+        set_contract_state_v1(&mut deps.storage, &contract_state)
+            .expect("contract state should be stored");
+        let error = fund_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(99),
+            TradeOptions {
+                allow_partial: true,
+                ..TradeOptions::default()
+            },
+        )
+        .expect_err("a trade below the minimum should fail");
+        match error {
+            ContractError::PreconditionsFailed { failures } => assert!(
+                failures.contains(&format!("validation failed: requested [99{DEFAULT_DEPOSIT_DENOM_NAME}], but the minimum fund amount is [100{DEFAULT_DEPOSIT_DENOM_NAME}]")),
+                "the requested amount, not the partial fill, should be compared to the minimum, but got: {failures:?}",
+            ),
+            e => panic!("unexpected error encountered: {e:?}"),
+        };
+    }
+
+    #[test]
+    fn amount_beyond_the_daily_cap_should_reject_fund_trading() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let mut contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        contract_state.daily_cap = Some(Uint128::new(1000));
+        set_contract_state_v1(&mut deps.storage, &contract_state)
+            .expect("contract state should be stored");
+        let env = mock_env();
+        record_trade(
+            &mut deps.storage,
+            &env.block.time,
+            TradeDirection::Fund,
+            &Addr::unchecked("earlier-sender"),
+            Uint128::new(950),
+        )
+        .expect("recording an earlier trade should succeed");
+        let error = fund_trading(
+            deps.as_mut(),
+            env.to_owned(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(51),
+            TradeOptions::default(),
+        )
+        .expect_err("a trade beyond the daily cap should fail");
+        match error {
+            ContractError::PreconditionsFailed { failures } => assert!(
+                failures.contains(&format!("daily cap exceeded: requested [51{DEFAULT_DEPOSIT_DENOM_NAME}], but only [50{DEFAULT_DEPOSIT_DENOM_NAME}] of the daily cap of [1000{DEFAULT_DEPOSIT_DENOM_NAME}] remains")),
+                "the exceeded daily cap should be reported, but got: {failures:?}",
+            ),
+            e => panic!("unexpected error encountered: {e:?}"),
+        };
+        assert_eq!(
+            Uint128::new(950),
+            get_daily_trade_totals(&deps.storage, day_of(&env.block.time))
+                .expect("daily trade totals should load")
+                .fund_volume,
+            "a rejected trade should not be added to the daily volume",
+        );
+    }
+
+    #[test]
+    fn denylisted_sender_should_be_rejected_with_the_stored_reason() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        add_denylisted_account(
+            &mut deps.storage,
+            &Addr::unchecked("sender"),
+            Some("flagged by compliance".to_string()),
+        )
+        .expect("denylisting the sender should succeed");
+        let error = fund_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(100),
+            TradeOptions::default(),
+        )
+        .expect_err("a trade by a denylisted sender should fail");
+        assert_eq!(
+            "not authorized: account [sender] is denylisted: flagged by compliance",
+            error.to_string(),
+            "the stored reason should be reported",
+        );
+    }
+
+    #[test]
+    fn paused_contract_should_reject_fund_trading() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let mut contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        contract_state.paused = true;
+        set_contract_state_v1(&mut deps.storage, &contract_state)
+            .expect("contract state should be stored");
+        let error = fund_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(100),
+            TradeOptions::default(),
+        )
+        .expect_err("a trade while the contract is paused should fail");
+        match error {
+            ContractError::PreconditionsFailed { failures } => assert!(
+                failures
+                    .iter()
+                    .any(|failure| failure.starts_with("contract is paused: ")),
+                "the paused contract should be reported, but got: {failures:?}",
+            ),
+            e => panic!("unexpected error encountered: {e:?}"),
+        };
+    }
+
+    #[test]
+    fn disabled_minting_should_reject_fund_trading() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let mut contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        contract_state.minting_disabled = true;
+        set_contract_state_v1(&mut deps.storage, &contract_state)
+            .expect("contract state should be stored");
+        let error = fund_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(100),
+            TradeOptions::default(),
+        )
+        .expect_err("a trade after minting is disabled should fail");
+        match error {
+            ContractError::PreconditionsFailed { failures } => assert!(
+                failures
+                    .iter()
+                    .any(|failure| failure.starts_with("minting is permanently disabled: ")),
+                "the disabled minting should be reported, but got: {failures:?}",
+            ),
+            e => panic!("unexpected error encountered: {e:?}"),
+        };
+    }
+
+    #[test]
+    fn referred_trade_should_credit_the_referrer() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryAttributesRequest::mock_response(
+            &mut querier,
+            QueryAttributesResponse {
+                account: "sender".to_string(),
+                attributes: vec![Attribute {
+                    name: DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE.to_string(),
+                    value: vec![],
+                    attribute_type: AttributeType::String as i32,
+                    address: "addr".to_string(),
+                    expiration_date: None,
+                }],
+                pagination: None,
+            },
+        );
+        mock_marker_response(
+            &mut querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        mock_marker_params_response(&mut querier, "");
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                skip_balance_precheck: true,
+                ..InstantiateMsg::default()
+            },
+        );
+        let sender = deps.api.addr_make("sender");
+        let referrer = deps.api.addr_make("referrer");
+        let error = fund_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&sender, &[]),
+            Uint128::new(100),
+            TradeOptions {
+                referrer: Some(sender.to_string()),
+                ..TradeOptions::default()
+            },
+        )
+        .expect_err("a self-referral should be rejected");
+        assert_eq!(
+            "validation failed: an account cannot refer its own trades",
+            error.to_string(),
+            "unexpected error encountered for a self-referral",
+        );
+        fund_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&sender, &[]),
+            Uint128::new(100),
+            TradeOptions {
+                dry_run: true,
+                referrer: Some(referrer.to_string()),
+                ..TradeOptions::default()
+            },
+        )
+        .expect("a referred dry run should succeed");
+        assert_eq!(
+            ReferralStats::default(),
+            get_referral_stats(&deps.storage, &referrer).expect("the stats should be fetched"),
+            "a dry run should not credit the referrer",
+        );
+        let response = fund_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&sender, &[]),
+            Uint128::new(100),
+            TradeOptions {
+                referrer: Some(referrer.to_string()),
+                ..TradeOptions::default()
+            },
+        )
+        .expect("a referred trade should succeed");
+        assert_eq!(
+            3,
+            response.messages.len(),
+            "a referred trade should execute normally",
+        );
+        response.assert_attribute("referrer", referrer.as_str());
+        assert_eq!(
+            ReferralStats {
+                trade_count: 1,
+                volume: Uint128::new(100),
+            },
+            get_referral_stats(&deps.storage, &referrer).expect("the stats should be fetched"),
+            "the referrer should be credited with the collected deposit amount",
+        );
+    }
+
+    #[test]
+    fn partial_fill_should_trade_the_largest_convertible_balance() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(
+            &mut querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    amount: "79".to_string(),
+                    denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+                }),
+            },
+        );
+        QueryAttributesRequest::mock_response(
+            &mut querier,
+            QueryAttributesResponse {
+                account: "sender".to_string(),
+                attributes: vec![Attribute {
+                    name: DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE.to_string(),
+                    value: vec![],
+                    attribute_type: AttributeType::String as i32,
+                    address: "addr".to_string(),
+                    expiration_date: None,
+                }],
+                pagination: None,
+            },
+        );
+        mock_marker_response(
+            &mut querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        mock_marker_params_response(&mut querier, "");
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        // Each unit of trading denom requires ten units of deposit denom, so the sender's balance
+        // of 79 can cleanly convert 70
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                deposit_marker: Denom::new(DEFAULT_DEPOSIT_DENOM_NAME, 2),
+                trading_marker: Denom::new(DEFAULT_TRADING_DENOM_NAME, 1),
+                ..InstantiateMsg::default()
+            },
+        );
+        let error = fund_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(200),
+            TradeOptions::default(),
+        )
+        .expect_err("an error should occur when partial fills are not allowed");
+        assert!(
+            matches!(error, ContractError::PreconditionsFailed { .. }),
+            "unexpected error emitted when partial fills are not allowed: {error:?}",
+        );
+        let response = fund_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(200),
+            TradeOptions {
+                allow_partial: true,
+                ..TradeOptions::default()
+            },
+        )
+        .expect("the trade should be partially filled");
+        assert_eq!(
+            3,
+            response.messages.len(),
+            "the partial fill should emit all three messages",
+        );
+        response.assert_attribute("partial_fill", "true");
+        response.assert_attribute("deposit_requested_amount", "200");
+        response.assert_attribute("filled_amount", "70");
+        response.assert_attribute("deposit_actual_amount", "70");
+        response.assert_attribute("received_amount", "7");
+    }
+
+    #[test]
+    fn partial_fill_converting_to_nothing_should_cause_an_error() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(
+            &mut querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    amount: "9".to_string(),
+                    denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+                }),
+            },
+        );
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                deposit_marker: Denom::new(DEFAULT_DEPOSIT_DENOM_NAME, 2),
+                trading_marker: Denom::new(DEFAULT_TRADING_DENOM_NAME, 1),
+                ..InstantiateMsg::default()
+            },
+        );
+        let error = fund_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(200),
+            TradeOptions {
+                allow_partial: true,
+                ..TradeOptions::default()
+            },
+        )
+        .expect_err("an error should occur when no portion of the balance can be converted");
+        assert_eq!(
+            format!("invalid funds: sent [9{DEFAULT_DEPOSIT_DENOM_NAME}], but that is not enough to convert to at least one [{DEFAULT_TRADING_DENOM_NAME}]"),
+            error.to_string(),
+            "the usual conversion error should be emitted",
+        );
+    }
+    #[test]
+    fn missing_marker_access_should_cause_an_error_when_verified_on_trade() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        set_runtime_tunables(
+            &mut deps.storage,
+            &RuntimeTunables {
+                verify_marker_access_on_trade: true,
+                ..RuntimeTunables::default()
+            },
+        )
+        .expect("setting the tunables should succeed");
+        // Instantiation verified the grants, so they are revoked afterward
+        let mut marker = test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0");
+        marker.access_control = vec![AccessGrant {
+            address: MOCK_CONTRACT_ADDR.to_string(),
+            permissions: vec![Access::Transfer as i32],
+        }];
+        mock_marker_response(&mut deps.querier, marker);
+        let error = fund_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(100),
+            TradeOptions::default(),
+        )
+        .expect_err("an error should occur when the contract lacks marker permissions");
+        assert_eq!(
+            format!("not authorized: contract [{MOCK_CONTRACT_ADDR}] is missing permissions [ACCESS_MINT, ACCESS_BURN, ACCESS_WITHDRAW] on marker [{DEFAULT_TRADING_DENOM_NAME}]"),
+            error.to_string(),
+            "unexpected error message encountered",
+        );
     }
 }
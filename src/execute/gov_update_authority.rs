@@ -0,0 +1,261 @@
+use crate::store::audit_log::{append_audit_entry, AuditEntry};
+use crate::store::contract_state::{get_contract_state_v1, set_contract_state_v1, AdminSender};
+use crate::types::error::ContractError;
+use crate::util::response_utils::admin_response;
+use crate::util::validation_utils::check_funds_are_empty;
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execute functionality.  This function will only accept the request if
+/// the sender is the current [gov_authority](crate::store::contract_state::ContractStateV1#gov_authority).
+/// The contract admin cannot invoke it, ensuring that the admin is unable to remove the governance
+/// override that exists to recover from a lost admin key.  A contract instantiated without an
+/// authority can never gain one.  The change is recorded in the [audit log](crate::store::audit_log).
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `gov_authority` The bech32 address of the new authority, or `None` to remove the authority
+/// permanently.
+pub fn gov_update_authority(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    gov_authority: Option<String>,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    let mut contract_state = get_contract_state_v1(deps.storage)?;
+    if contract_state.gov_authority.as_ref() != Some(&info.sender) {
+        return ContractError::NotAuthorizedError {
+            message: "only the current gov authority may update the gov authority".to_string(),
+        }
+        .to_err();
+    }
+    let previous_value = contract_state.gov_authority;
+    contract_state.gov_authority = gov_authority
+        .as_ref()
+        .map(|gov_authority| deps.api.addr_validate(gov_authority))
+        .transpose()?;
+    set_contract_state_v1(deps.storage, &contract_state)?;
+    let audit_entry = append_audit_entry(
+        deps.storage,
+        AuditEntry {
+            id: 0,
+            action: "gov_update_authority".to_string(),
+            actor: info.sender,
+            via_group_policy: false,
+            via_gov_authority: true,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            details: vec![format!(
+                "changed the gov authority from [{}] to [{}]",
+                gov_authority_attribute(&previous_value),
+                gov_authority_attribute(&contract_state.gov_authority),
+            )],
+        },
+    )?;
+    admin_response(
+        "gov_update_authority",
+        &env,
+        &contract_state,
+        AdminSender::GovAuthority,
+    )
+    .add_attribute(
+        "previous_gov_authority",
+        gov_authority_attribute(&previous_value),
+    )
+    .add_attribute(
+        "new_gov_authority",
+        gov_authority_attribute(&contract_state.gov_authority),
+    )
+    .add_attribute("audit_entry_id", audit_entry.id.to_string())
+    .to_ok()
+}
+
+fn gov_authority_attribute(gov_authority: &Option<Addr>) -> String {
+    gov_authority.as_ref().map_or_else(
+        || "none".to_string(),
+        |gov_authority| gov_authority.to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::gov_update_authority::gov_update_authority;
+    use crate::store::audit_log::get_audit_entries;
+    use crate::store::contract_state::{get_contract_state_v1, CONTRACT_TYPE, CONTRACT_VERSION};
+    use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_constants::{DEFAULT_ADMIN, DEFAULT_CONTRACT_NAME};
+    use crate::test::test_instantiate::{test_instantiate, test_instantiate_with_msg};
+    use crate::types::error::ContractError;
+    use crate::types::msg::InstantiateMsg;
+    use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coins, Addr};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn provided_funds_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let gov_authority = deps.api.addr_make("gov");
+        let error = gov_update_authority(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&gov_authority, &coins(10, "nhash")),
+            None,
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(&error, ContractError::InvalidFundsError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn missing_contract_state_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let gov_authority = deps.api.addr_make("gov");
+        let error = gov_update_authority(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&gov_authority, &[]),
+            None,
+        )
+        .expect_err("an error should occur when the contract state is missing");
+        assert!(
+            matches!(&error, ContractError::StorageError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn admin_sender_should_not_be_able_to_change_the_authority() {
+        let mut deps = mock_provenance_dependencies();
+        let gov_authority = deps.api.addr_make("gov");
+        let new_authority = deps.api.addr_make("new-gov");
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                gov_authority: Some(gov_authority.to_string()),
+                ..InstantiateMsg::default()
+            },
+        );
+        let error = gov_update_authority(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            Some(new_authority.to_string()),
+        )
+        .expect_err("an error should occur when the admin attempts to change the authority");
+        assert!(
+            matches!(&error, ContractError::NotAuthorizedError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+        assert_eq!(
+            Some(gov_authority),
+            get_contract_state_v1(deps.as_ref().storage)
+                .expect("contract state should load")
+                .gov_authority,
+            "the gov authority should be unchanged",
+        );
+    }
+
+    #[test]
+    fn missing_authority_should_prevent_any_sender_from_setting_one() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let gov_authority = deps.api.addr_make("gov");
+        for sender in [Addr::unchecked(DEFAULT_ADMIN), gov_authority.to_owned()] {
+            let error = gov_update_authority(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&sender, &[]),
+                Some(gov_authority.to_string()),
+            )
+            .expect_err("an authority should not be settable after instantiation");
+            assert!(
+                matches!(&error, ContractError::NotAuthorizedError { .. }),
+                "unexpected error encountered: {error:?}",
+            );
+        }
+    }
+
+    #[test]
+    fn successful_input_should_derive_a_response() {
+        let mut deps = mock_provenance_dependencies();
+        let gov_authority = deps.api.addr_make("gov");
+        let new_authority = deps.api.addr_make("new-gov");
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                gov_authority: Some(gov_authority.to_string()),
+                ..InstantiateMsg::default()
+            },
+        );
+        let response = gov_update_authority(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&gov_authority, &[]),
+            Some(new_authority.to_string()),
+        )
+        .expect("the gov authority should be able to replace itself");
+        assert!(
+            response.messages.is_empty(),
+            "no messages should be emitted in the response",
+        );
+        assert_eq!(
+            9,
+            response.attributes.len(),
+            "nine attributes should be emitted in the response",
+        );
+        response.assert_attribute("action", "gov_update_authority");
+        response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+        response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
+        response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
+        response.assert_attribute("gov_authority_override", "true");
+        response.assert_attribute("previous_gov_authority", gov_authority.as_str());
+        response.assert_attribute("new_gov_authority", new_authority.as_str());
+        response.assert_attribute("audit_entry_id", "1");
+        assert_eq!(
+            Some(new_authority.to_owned()),
+            get_contract_state_v1(deps.as_ref().storage)
+                .expect("contract state should load after the update")
+                .gov_authority,
+            "the new authority should be persisted to the contract state",
+        );
+        let entry = get_audit_entries(deps.as_ref().storage)
+            .expect("the audit log should load")
+            .first()
+            .cloned()
+            .expect("the change should be recorded in the audit log");
+        assert!(
+            entry.via_gov_authority,
+            "the audit entry should be flagged as a gov authority action",
+        );
+        gov_update_authority(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&gov_authority, &[]),
+            None,
+        )
+        .expect_err("the previous authority should no longer be accepted");
+        gov_update_authority(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&new_authority, &[]),
+            None,
+        )
+        .expect("the new authority should be able to remove itself");
+        assert!(
+            get_contract_state_v1(deps.as_ref().storage)
+                .expect("contract state should load after the update")
+                .gov_authority
+                .is_none(),
+            "the authority should be removed from the contract state",
+        );
+    }
+}
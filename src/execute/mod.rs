@@ -1,18 +1,133 @@
 //! Contains all execution routes used by the [contract file](crate::contract).
 
+/// This execution route allows the address proposed by [admin_propose_new_admin] to become the
+/// contract admin.
+pub mod accept_admin_role;
+/// This execution route allows the contract admin to block an account from trading.
+pub mod admin_add_denylisted_account;
+/// This execution route allows the contract admin to exempt an account from the fees assessed on
+/// its trades.
+pub mod admin_add_fee_exemption;
+/// This execution route allows the contract admin to let an account trade without holding the
+/// required attributes.
+pub mod admin_add_whitelisted_account;
+/// This execution route allows the contract admin to return deposit denom to many accounts at once
+/// by withdrawing their trading marker balances.
+pub mod admin_batch_force_withdraw;
+/// This execution route allows the contract admin to replace the required attributes of the trade
+/// routes across multiple transactions.
+pub mod admin_bulk_update_attributes;
+/// This execution route allows the contract admin to discard a proposed admin before it is
+/// accepted.
+pub mod admin_cancel_pending_admin;
+/// This execution route allows the contract admin to permanently stop the issuance of trading
+/// denom.
+pub mod admin_disable_minting_permanently;
+/// This execution route allows the contract admin to transfer escrowed deposit denom out of the
+/// contract in an emergency.
+pub mod admin_emergency_withdraw;
+/// This execution route allows the contract admin to temporarily halt all trades.
+pub mod admin_pause;
+/// This execution route allows the contract admin to propose a new admin, which takes effect once
+/// the proposed address accepts it with [accept_admin_role].
+pub mod admin_propose_new_admin;
+/// This execution route allows the contract admin to publish the bank denom metadata of the
+/// trading marker.
+pub mod admin_publish_trading_denom_metadata;
+/// This execution route allows the contract admin to repair the cached trading marker address.
+pub mod admin_refresh_marker_cache;
+/// This execution route allows the contract admin to allow a blocked account to trade again.
+pub mod admin_remove_denylisted_account;
+/// This execution route allows the contract admin to remove an account's exemption from the fees
+/// assessed on its trades.
+pub mod admin_remove_fee_exemption;
+/// This execution route allows the contract admin to require an account to hold the required
+/// attributes again.
+pub mod admin_remove_whitelisted_account;
+/// This execution route allows the contract admin to resume trades halted by a pause.
+pub mod admin_resume;
+/// This execution route allows the contract admin to verify the contract's external dependencies
+/// and record the findings in the audit log.
+pub mod admin_run_diagnostics;
+/// This execution route allows the contract admin to set the custody address that holds the
+/// contract's reserves.
+pub mod admin_set_custody_address;
+/// This execution route allows the contract admin to adjust the contract's runtime tunables.
+pub mod admin_set_tunables;
 /// This execution route allows the contract admin to choose a new admin.
 pub mod admin_update_admin;
 /// This execution route allows the contract admin to choose new attributes required when invoking
+/// both [fund_trading] and [withdraw_trading] at once.
+pub mod admin_update_all_required_attributes;
+/// This execution route allows the contract admin to choose the compliance contract that must
+/// approve every trade.
+pub mod admin_update_compliance_contract;
+/// This execution route allows the contract admin to rename the contract instance.
+pub mod admin_update_contract_name;
+/// This execution route allows the contract admin to choose the deposit volume that fund trades may
+/// accept during a single day.
+pub mod admin_update_daily_cap;
+/// This execution route allows the contract admin to choose new attributes required when invoking
 /// [fund_trading].
 pub mod admin_update_deposit_required_attributes;
+/// This execution route allows the contract admin to choose the discounts of the msg fee granted to
+/// senders holding designated attributes.
+pub mod admin_update_fee_discounts;
+/// This execution route allows the contract admin to choose whether or not [fund_trading] accepts
+/// trades.
+pub mod admin_update_fund_enabled;
+/// This execution route allows the contract admin to choose the fee withheld from the trading denom
+/// minted by [fund_trading], and the account that receives it.
+pub mod admin_update_fund_fee;
+/// This execution route allows the contract admin to choose the trade value at or above which trades
+/// must be approved before they execute.
+pub mod admin_update_large_trade_threshold;
+/// This execution route allows the contract admin to choose the largest amounts accepted by the
+/// trade routes.
+pub mod admin_update_max_trade_amounts;
+/// This execution route allows the contract admin to choose the smallest amounts accepted by the
+/// trade routes.
+pub mod admin_update_min_trade_amounts;
+/// This execution route allows the contract admin to choose the Provenance msg fee assessed on every
+/// accepted trade.
+pub mod admin_update_msg_fee;
+/// This execution route allows the contract admin to choose the value that each account may trade
+/// during a single day.
+pub mod admin_update_per_account_daily_limit;
+/// This execution route allows the contract admin to choose the scope specification from which
+/// senders of [fund_trading] must own a scope.
+pub mod admin_update_required_scope_spec;
+/// This execution route allows the contract admin to choose when the trade routes open and close.
+pub mod admin_update_schedule;
+/// This execution route allows the contract admin to choose whether or not the trade routes verify
+/// sender balances before emitting marker transfers.
+pub mod admin_update_skip_balance_precheck;
+/// This execution route allows the contract admin to replace the trading marker during a token
+/// migration.
+pub mod admin_update_trading_marker;
+/// This execution route allows the contract admin to choose whether or not [withdraw_trading]
+/// accepts trades.
+pub mod admin_update_withdraw_enabled;
+/// This execution route allows the contract admin to choose the fee withheld from the deposit denom
+/// released by [withdraw_trading].
+pub mod admin_update_withdraw_fee;
 /// This execution route allows the contract admin to choose new attributes required when invoking
 /// [withdraw_trading].
 pub mod admin_update_withdraw_required_attributes;
+/// This execution route allows the contract admin to execute a trade that is awaiting approval.
+pub mod approve_pending_trade;
 /// This execution route converts the [deposit marker](crate::types::msg::InstantiateMsg#deposit_marker)
 /// denom to the [trading marker](crate::types::msg::InstantiateMsg#trading_marker) denom by transferring
 /// the deposit marker denom from the sender to the contract, and then minting and withdrawing new
 /// trading marker denom to the sender's account.
 pub mod fund_trading;
+/// This execution route allows the chain governance authority to replace or remove itself.
+pub mod gov_update_authority;
+/// This execution route moves the sender's trading marker balance toward a target by performing a
+/// single [fund_trading] or [withdraw_trading] trade.
+pub mod rebalance;
+/// This execution route allows the contract admin to discard a trade that is awaiting approval.
+pub mod reject_pending_trade;
 /// This execution route converts the [trading marker](crate::types::msg::InstantiateMsg#trading_marker)
 /// denom to the [deposit marker](crate::types::msg::InstantiateMsg#deposit_marker) denom by transferring
 /// the trading marker denom from the sender to the trading marker itself, burning the received values,
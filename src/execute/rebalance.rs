@@ -0,0 +1,350 @@
+use crate::execute::fund_trading::execute_fund_trading;
+use crate::execute::withdraw_trading::execute_withdraw_trading;
+use crate::store::contract_state::{get_contract_state_v1, ContractStateV1};
+use crate::store::runtime_tunables::{get_runtime_tunables, EventVerbosity};
+use crate::types::error::ContractError;
+use crate::types::quote::TradeDirection;
+use crate::types::trade_options::TradeOptions;
+use crate::util::conversion_utils::convert_denom;
+use crate::util::provenance_utils::get_account_balance;
+use crate::util::response_utils::trade_response;
+use crate::util::validation_utils::check_funds_are_empty;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response, Uint128};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execute functionality.  The function compares the sender's current
+/// balance of the trading marker's denom to the requested target, and then performs the single
+/// [fund](crate::execute::fund_trading::fund_trading) or [withdraw](crate::execute::withdraw_trading::withdraw_trading)
+/// trade that moves the balance as close to the target as the denoms' precisions allow, without
+/// overshooting it.  The trade is subject to every check of its route, and its response follows the
+/// configured [event verbosity](crate::store::runtime_tunables::RuntimeTunables#event_verbosity).
+/// If the balance is already within one convertible unit of the target, no trade is performed and
+/// the response only contains attributes describing the outcome.
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `target_trading_balance` The amount of the trading marker's denom that the sender wishes to
+/// hold after the rebalance.
+pub fn rebalance(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    target_trading_balance: Uint128,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    let contract_state = get_contract_state_v1(deps.storage)?;
+    let trading_balance = get_account_balance(
+        &deps.as_ref(),
+        &info.sender,
+        &contract_state.trading_marker.name,
+    )?;
+    let tunables = get_runtime_tunables(deps.storage)?;
+    let rebalance_trade =
+        find_rebalance_trade(&contract_state, trading_balance, target_trading_balance)?;
+    let response = trade_response("rebalance", &env, &contract_state, tunables.event_verbosity);
+    // Minimal responses only describe the trade itself, but a rebalance that performs no trade is
+    // always explained
+    let response =
+        if rebalance_trade.is_none() || tunables.event_verbosity != EventVerbosity::Minimal {
+            response
+                .add_attribute("previous_trading_balance", trading_balance.to_string())
+                .add_attribute("target_trading_balance", target_trading_balance.to_string())
+                .add_attribute(
+                    "rebalance_direction",
+                    rebalance_trade
+                        .map(|(direction, _)| direction.name())
+                        .unwrap_or("none"),
+                )
+        } else {
+            response
+        };
+    // Requesting a target balance accepts that the excess below one convertible unit stays with
+    // the sender, so any remainder is acknowledged on the sender's behalf
+    let options = TradeOptions {
+        allow_remainder: true,
+        ..TradeOptions::default()
+    };
+    match rebalance_trade {
+        Some((TradeDirection::Fund, trade_amount)) => execute_fund_trading(
+            deps,
+            &env,
+            &contract_state,
+            &tunables,
+            info.sender,
+            trade_amount,
+            options,
+            response,
+        ),
+        Some((TradeDirection::Withdraw, trade_amount)) => execute_withdraw_trading(
+            deps,
+            &env,
+            &contract_state,
+            &tunables,
+            info.sender,
+            trade_amount,
+            options,
+            response,
+        ),
+        None => response
+            .add_attribute(
+                "rebalance_result",
+                "trading balance is already within one convertible unit of the target",
+            )
+            .to_ok(),
+    }
+}
+
+/// Determines the direction and input amount of the trade that moves the trading balance as close
+/// to the target as possible without overshooting it, or `None` if no such trade exists.
+fn find_rebalance_trade(
+    contract_state: &ContractStateV1,
+    trading_balance: Uint128,
+    target_trading_balance: Uint128,
+) -> Result<Option<(TradeDirection, Uint128)>, ContractError> {
+    if trading_balance < target_trading_balance {
+        // Only whole units of deposit denom can be funded, so the missing trading denom is converted
+        // downward to find the largest fund that does not exceed the target
+        let deposit_amount = convert_denom(
+            target_trading_balance.checked_sub(trading_balance)?,
+            &contract_state.trading_marker,
+            &contract_state.deposit_marker,
+        )?
+        .target_amount;
+        if deposit_amount.is_zero() {
+            None
+        } else {
+            Some((TradeDirection::Fund, deposit_amount))
+        }
+    } else {
+        // The withdraw route leaves any unconvertible remainder of the excess with the sender
+        let excess = trading_balance.checked_sub(target_trading_balance)?;
+        let conversion = convert_denom(
+            excess,
+            &contract_state.trading_marker,
+            &contract_state.deposit_marker,
+        )?;
+        if conversion.target_amount.is_zero() {
+            None
+        } else {
+            Some((TradeDirection::Withdraw, excess))
+        }
+    }
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::rebalance::rebalance;
+    use crate::store::contract_state::{CONTRACT_TYPE, CONTRACT_VERSION};
+    use crate::store::runtime_tunables::{set_runtime_tunables, EventVerbosity, RuntimeTunables};
+    use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_constants::{
+        DEFAULT_CONTRACT_NAME, DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE,
+        DEFAULT_REQUIRED_WITHDRAW_ATTRIBUTE, DEFAULT_TRADING_DENOM_NAME,
+    };
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::test::test_markers::{
+        mock_marker_params_response, mock_marker_response, test_marker_account,
+    };
+    use crate::types::error::ContractError;
+    use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coins, Addr, Uint128};
+    use provwasm_mocks::{
+        mock_provenance_dependencies, mock_provenance_dependencies_with_custom_querier,
+        MockProvenanceQuerier,
+    };
+    use provwasm_std::types::cosmos::bank::v1beta1::{QueryBalanceRequest, QueryBalanceResponse};
+    use provwasm_std::types::cosmos::base::v1beta1::Coin;
+    use provwasm_std::types::provenance::attribute::v1::{
+        Attribute, AttributeType, QueryAttributesRequest, QueryAttributesResponse,
+    };
+
+    // The mock querier answers every balance query with the same coin, so the sender's trading
+    // balance doubles as its deposit balance and the contract's reserves
+    fn get_rebalance_querier<S: Into<String>>(balance: S) -> MockProvenanceQuerier {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(
+            &mut querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    amount: balance.into(),
+                    denom: DEFAULT_TRADING_DENOM_NAME.to_string(),
+                }),
+            },
+        );
+        QueryAttributesRequest::mock_response(
+            &mut querier,
+            QueryAttributesResponse {
+                account: "sender".to_string(),
+                attributes: [
+                    DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE,
+                    DEFAULT_REQUIRED_WITHDRAW_ATTRIBUTE,
+                ]
+                .into_iter()
+                .map(|name| Attribute {
+                    name: name.to_string(),
+                    value: vec![],
+                    attribute_type: AttributeType::String as i32,
+                    address: "addr".to_string(),
+                    expiration_date: None,
+                })
+                .collect(),
+                pagination: None,
+            },
+        );
+        mock_marker_response(
+            &mut querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        mock_marker_params_response(&mut querier, "");
+        querier
+    }
+
+    #[test]
+    fn provided_funds_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = rebalance(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &coins(10, "nhash")),
+            Uint128::new(100),
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(&error, ContractError::InvalidFundsError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn balance_within_one_unit_of_the_target_should_not_trade() {
+        let mut deps =
+            mock_provenance_dependencies_with_custom_querier(get_rebalance_querier("1000000"));
+        test_instantiate(&mut deps);
+        // One unit of deposit denom is worth 10000 units of trading denom, so neither a fund nor a
+        // withdraw can move the balance closer to these targets
+        for target in [1000000, 1009999, 990001] {
+            let response = rebalance(
+                deps.as_mut(),
+                mock_env(),
+                message_info(&Addr::unchecked("sender"), &[]),
+                Uint128::new(target),
+            )
+            .expect("a rebalance that is already at its target should succeed");
+            assert!(
+                response.messages.is_empty(),
+                "no messages should be emitted when the target is already met",
+            );
+            assert_eq!(
+                9,
+                response.attributes.len(),
+                "nine attributes should be emitted in the response",
+            );
+            response.assert_attribute("action", "rebalance");
+            response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+            response.assert_attribute("contract_type", CONTRACT_TYPE);
+            response.assert_attribute("contract_version", CONTRACT_VERSION);
+            response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
+            response.assert_attribute("previous_trading_balance", "1000000");
+            response.assert_attribute("target_trading_balance", target.to_string());
+            response.assert_attribute("rebalance_direction", "none");
+            response.assert_attribute(
+                "rebalance_result",
+                "trading balance is already within one convertible unit of the target",
+            );
+        }
+    }
+
+    #[test]
+    fn balance_below_the_target_should_fund() {
+        let mut deps =
+            mock_provenance_dependencies_with_custom_querier(get_rebalance_querier("1000000"));
+        test_instantiate(&mut deps);
+        let response = rebalance(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(3005000),
+        )
+        .expect("a rebalance below the target should succeed");
+        assert_eq!(
+            3,
+            response.messages.len(),
+            "the fund trading messages should be emitted",
+        );
+        response.assert_attribute("action", "rebalance");
+        response.assert_attribute("rebalance_direction", "fund");
+        response.assert_attribute("deposit_requested_amount", "200");
+        response.assert_attribute("received_amount", "2000000");
+    }
+
+    #[test]
+    fn balance_above_the_target_should_withdraw() {
+        let mut deps =
+            mock_provenance_dependencies_with_custom_querier(get_rebalance_querier("5000000"));
+        test_instantiate(&mut deps);
+        let response = rebalance(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(995000),
+        )
+        .expect("a rebalance above the target should succeed");
+        assert_eq!(
+            3,
+            response.messages.len(),
+            "the withdraw trading messages should be emitted",
+        );
+        response.assert_attribute("action", "rebalance");
+        response.assert_attribute("rebalance_direction", "withdraw");
+        response.assert_attribute("withdraw_input_amount", "4005000");
+        response.assert_attribute("withdraw_actual_amount", "4000000");
+        response.assert_attribute("received_amount", "400");
+    }
+
+    #[test]
+    fn minimal_event_verbosity_should_omit_the_rebalance_attributes_of_a_trade() {
+        let mut deps =
+            mock_provenance_dependencies_with_custom_querier(get_rebalance_querier("1000000"));
+        test_instantiate(&mut deps);
+        set_runtime_tunables(
+            &mut deps.storage,
+            &RuntimeTunables {
+                event_verbosity: EventVerbosity::Minimal,
+                ..RuntimeTunables::default()
+            },
+        )
+        .expect("setting tunables should succeed");
+        let response = rebalance(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(3005000),
+        )
+        .expect("a rebalance below the target should succeed");
+        assert_eq!(
+            vec!["action", "pair", "sender", "input", "output", "receipt"],
+            response
+                .attributes
+                .iter()
+                .map(|attr| attr.key.as_str())
+                .collect::<Vec<_>>(),
+            "the minimal level should only emit the trade's essentials",
+        );
+        response.assert_attribute("action", "rebalance");
+        let response = rebalance(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(1000000),
+        )
+        .expect("a rebalance at the target should succeed");
+        response.assert_attribute("rebalance_direction", "none");
+        response.assert_attribute("previous_trading_balance", "1000000");
+    }
+}
@@ -0,0 +1,279 @@
+use crate::store::audit_log::{append_audit_entry, AuditEntry};
+use crate::store::contract_state::{get_contract_state_v1, AdminSender};
+use crate::store::pending_trades::{get_pending_trade, remove_pending_trade};
+use crate::types::error::ContractError;
+use crate::util::response_utils::admin_response;
+use crate::util::validation_utils::check_funds_are_empty;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use result_extensions::ResultExtensions;
+
+/// Invoked via the contract's execute functionality.  This function will only accept the request if
+/// the sender is the registered contract admin in the [contract state](crate::store::contract_state::ContractStateV1).
+/// The function removes a [pending trade](crate::store::pending_trades::PendingTrade) without
+/// executing it, and records the rejection and its reason in the [audit log](crate::store::audit_log).
+/// Expired pending trades can be rejected to remove them from the contract's storage.
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `info` A message information object provided by the cosmwasm framework.  Describes the sender
+/// of the instantiation message, as well as the funds provided as an amount during the transaction.
+/// * `id` The identifier of the pending trade to reject.
+/// * `reason` A free-form explanation of the rejection.
+pub fn reject_pending_trade(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id: u64,
+    reason: String,
+) -> Result<Response, ContractError> {
+    check_funds_are_empty(&info)?;
+    let contract_state = get_contract_state_v1(deps.storage)?;
+    let admin_sender = contract_state.check_admin(&info.sender, "reject pending trades")?;
+    let pending_trade = get_pending_trade(deps.storage, id)?;
+    remove_pending_trade(deps.storage, id);
+    let audit_entry = append_audit_entry(
+        deps.storage,
+        AuditEntry {
+            id: 0,
+            action: "reject_pending_trade".to_string(),
+            actor: info.sender,
+            via_group_policy: admin_sender == AdminSender::GroupPolicy,
+            via_gov_authority: admin_sender == AdminSender::GovAuthority,
+            timestamp: env.block.time,
+            block_height: env.block.height,
+            details: vec![format!(
+                "rejected pending {} trade [{id}] of [{}] for [{}]: {reason}",
+                pending_trade.direction.name(),
+                pending_trade.trade_amount,
+                pending_trade.sender,
+            )],
+        },
+    )?;
+    admin_response("reject_pending_trade", &env, &contract_state, admin_sender)
+        .add_attribute("pending_trade_id", id.to_string())
+        .add_attribute("direction", pending_trade.direction.name())
+        .add_attribute("trade_sender", pending_trade.sender.as_str())
+        .add_attribute("requested_amount", pending_trade.trade_amount.to_string())
+        .add_attribute(
+            "expired",
+            pending_trade.is_expired(env.block.height).to_string(),
+        )
+        .add_attribute("reason", reason)
+        .add_attribute("audit_entry_id", audit_entry.id.to_string())
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::execute::reject_pending_trade::reject_pending_trade;
+    use crate::store::audit_log::get_audit_entries;
+    use crate::store::contract_state::{
+        get_contract_state_v1, set_contract_state_v1, CONTRACT_TYPE, CONTRACT_VERSION,
+    };
+    use crate::store::pending_trades::{get_pending_trade, insert_pending_trade, PendingTrade};
+    use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_constants::{DEFAULT_ADMIN, DEFAULT_CONTRACT_NAME};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::error::ContractError;
+    use crate::types::quote::TradeDirection;
+    use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coins, Addr, Storage, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    fn store_pending_withdraw_trade(storage: &mut dyn Storage, expires_at_height: u64) {
+        insert_pending_trade(
+            storage,
+            PendingTrade {
+                id: 0,
+                direction: TradeDirection::Withdraw,
+                sender: Addr::unchecked("sender"),
+                trade_amount: Uint128::new(5000000),
+                submitted_at_height: 1,
+                expires_at_height,
+                referrer: None,
+                allow_remainder: false,
+            },
+        )
+        .expect("the pending trade should be stored");
+    }
+
+    #[test]
+    fn provided_funds_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        let error = reject_pending_trade(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &coins(10, "nhash")),
+            1,
+            "some reason".to_string(),
+        )
+        .expect_err("an error should occur when funds are provided");
+        assert!(
+            matches!(&error, ContractError::InvalidFundsError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn non_admin_sender_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        store_pending_withdraw_trade(&mut deps.storage, mock_env().block.height);
+        let error = reject_pending_trade(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            1,
+            "some reason".to_string(),
+        )
+        .expect_err("an error should occur when the sender is not the admin");
+        assert!(
+            matches!(&error, ContractError::NotAuthorizedError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn missing_pending_trade_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let error = reject_pending_trade(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            1,
+            "some reason".to_string(),
+        )
+        .expect_err("an error should occur when the pending trade does not exist");
+        assert!(
+            matches!(&error, ContractError::NotFoundError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn successful_rejection_should_remove_the_trade() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        store_pending_withdraw_trade(&mut deps.storage, mock_env().block.height - 1);
+        let response = reject_pending_trade(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            1,
+            "sender exceeded their daily limit".to_string(),
+        )
+        .expect("rejecting a pending trade should succeed, even after it has expired");
+        assert!(
+            response.messages.is_empty(),
+            "no messages should be emitted in the response",
+        );
+        assert_eq!(
+            12,
+            response.attributes.len(),
+            "twelve attributes should be emitted in the response",
+        );
+        response.assert_attribute("action", "reject_pending_trade");
+        response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+        response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
+        response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
+        response.assert_attribute("pending_trade_id", "1");
+        response.assert_attribute("direction", "withdraw");
+        response.assert_attribute("trade_sender", "sender");
+        response.assert_attribute("requested_amount", "5000000");
+        response.assert_attribute("expired", "true");
+        response.assert_attribute("reason", "sender exceeded their daily limit");
+        response.assert_attribute("audit_entry_id", "1");
+        let error =
+            get_pending_trade(&deps.storage, 1).expect_err("the rejected trade should be removed");
+        assert!(
+            matches!(error, ContractError::NotFoundError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+        assert_eq!(
+            vec![
+                "rejected pending withdraw trade [1] of [5000000] for [sender]: sender exceeded their daily limit"
+                    .to_string()
+            ],
+            get_audit_entries(&deps.storage)
+                .expect("the audit log should be fetched")
+                .first()
+                .expect("the rejection should be recorded in the audit log")
+                .details,
+            "the audit entry should describe the rejected trade",
+        );
+    }
+
+    #[test]
+    fn group_admin_rejection_should_be_recorded_as_a_group_action() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let mut contract_state = get_contract_state_v1(&deps.storage)
+            .expect("contract state should load after instantiation");
+        contract_state.admin_is_group = true;
+        set_contract_state_v1(&mut deps.storage, &contract_state)
+            .expect("contract state should be stored");
+        store_pending_withdraw_trade(&mut deps.storage, mock_env().block.height);
+        reject_pending_trade(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
+            1,
+            "proposal passed".to_string(),
+        )
+        .expect("the group policy should be able to reject a pending trade");
+        let entry = get_audit_entries(&deps.storage)
+            .expect("the audit log should be fetched")
+            .first()
+            .cloned()
+            .expect("the rejection should be recorded in the audit log");
+        assert_eq!(
+            DEFAULT_ADMIN,
+            entry.actor.as_str(),
+            "the group policy should be recorded as the actor",
+        );
+        assert!(
+            entry.via_group_policy,
+            "the entry should record that the action arrived through the group policy",
+        );
+    }
+
+    #[test]
+    fn gov_authority_rejection_should_be_recorded_as_a_gov_action() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let gov_authority = deps.api.addr_make("gov");
+        let mut contract_state = get_contract_state_v1(&deps.storage)
+            .expect("contract state should load after instantiation");
+        contract_state.gov_authority = Some(gov_authority.to_owned());
+        set_contract_state_v1(&mut deps.storage, &contract_state)
+            .expect("contract state should be stored");
+        store_pending_withdraw_trade(&mut deps.storage, mock_env().block.height);
+        let response = reject_pending_trade(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&gov_authority, &[]),
+            1,
+            "governance intervention".to_string(),
+        )
+        .expect("the gov authority should be able to reject a pending trade");
+        response.assert_attribute("gov_authority_override", "true");
+        let entry = get_audit_entries(&deps.storage)
+            .expect("the audit log should be fetched")
+            .first()
+            .cloned()
+            .expect("the rejection should be recorded in the audit log");
+        assert_eq!(
+            gov_authority, entry.actor,
+            "the gov authority should be recorded as the actor",
+        );
+        assert!(
+            entry.via_gov_authority && !entry.via_group_policy,
+            "the entry should record that the action was taken by the gov authority",
+        );
+    }
+}
@@ -1,11 +1,35 @@
-use crate::store::contract_state::{get_contract_state_v1, CONTRACT_TYPE};
+use crate::store::account_daily_usage::check_account_daily_limit;
+use crate::store::attribute_whitelist::is_whitelisted_account;
+use crate::store::contract_state::{get_contract_state_v1, ContractStateV1};
+use crate::store::denylist::check_account_not_denylisted;
+use crate::store::fee_exemptions::is_fee_exempt;
+use crate::store::runtime_tunables::{get_runtime_tunables, RuntimeTunables};
 use crate::types::error::ContractError;
+use crate::types::quote::TradeDirection;
+use crate::types::trade_options::TradeOptions;
+use crate::types::trade_plan::{TradeFee, TradePlan};
+use crate::types::trade_step::TradeStep;
+use crate::util::compliance_utils::check_trade_compliance;
 use crate::util::conversion_utils::convert_denom;
+use crate::util::fee_utils::check_sender_attributes;
+use crate::util::marker_cache_utils::{get_trading_marker_address, maintain_marker_address_cache};
+use crate::util::partial_fill_utils::resolve_fill_amount;
+use crate::util::pending_trade_utils::submit_pending_trade;
 use crate::util::provenance_utils::{
-    check_account_has_all_attributes, check_account_has_enough_denom, get_marker_address_for_denom,
+    check_account_has_enough_denom, check_contract_access_to_markers, get_account_balance,
+};
+use crate::util::quote_utils::check_quote;
+use crate::util::response_utils::{
+    add_dry_run_attributes, add_msg_fee_attributes, add_trade_attributes, add_trade_receipt,
+    trade_execution_messages, trade_response, TradeEvent,
+};
+use crate::util::trade_record_utils::record_planned_trade;
+use crate::util::validation_utils::{
+    check_funds_are_empty, check_preconditions, validate_referrer,
+};
+use cosmwasm_std::{
+    coin, Addr, Attribute, Deps, DepsMut, Env, MessageInfo, Response, SubMsg, Uint128,
 };
-use crate::util::validation_utils::check_funds_are_empty;
-use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
 use provwasm_std::types::cosmos::base::v1beta1::Coin;
 use provwasm_std::types::provenance::marker::v1::{MsgBurnRequest, MsgTransferRequest};
 use result_extensions::ResultExtensions;
@@ -13,7 +37,31 @@ use result_extensions::ResultExtensions;
 /// Invoked via the contract's execute functionality.  The function will attempt to pull [trade_amount](withdraw_trading#trade_amount)
 /// of the trading marker's denom from the sender's account with a marker transfer, discern how much
 /// of the deposit denom to which the submitted amount is equivalent, transfer that amount to the
-/// sender, and then burn the exchanged trading marker denom.
+/// sender, and then burn the exchanged trading marker denom.  The trade is rejected with a single
+/// [PreconditionsFailed](ContractError::PreconditionsFailed) error listing every violated
+/// requirement, including a paused contract or route, a closed trading window, a trade amount
+/// outside the configured minimum and maximum, deposit denom releases that would exceed the
+/// sender's [per-account daily limit](ContractStateV1#per_account_daily_limit), and any missing
+/// attributes or balance.  Once those pass, the trade is rejected with an
+/// [InsolventError](ContractError::InsolventError) if the contract does not hold enough deposit
+/// denom to release to the sender.
+/// Trades valued at or above the contract's [large trade threshold](ContractStateV1#large_trade_threshold)
+/// emit no trade messages after passing their checks, and are instead stored as pending trades that
+/// the contract admin must approve via [approve_pending_trade](crate::execute::approve_pending_trade::approve_pending_trade).
+/// Every accepted trade, including one held for approval, also assesses the contract's
+/// [msg fee](ContractStateV1#msg_fee) when one is configured, reduced by the largest
+/// [fee discount](ContractStateV1#fee_discounts) whose attribute the sender holds.  Senders on
+/// the [fee exemption](crate::store::fee_exemptions) list are assessed no fees at all.  Senders on
+/// the [attribute whitelist](crate::store::attribute_whitelist) skip the required attribute check,
+/// but must still hold the traded amount, and are flagged with a `whitelisted` attribute.  Senders
+/// on the [denylist](crate::store::denylist) are rejected with a [NotAuthorizedError](ContractError::NotAuthorizedError)
+/// before any other account check.  The trading
+/// marker's address is read from the [marker address cache](crate::store::marker_address_cache::MarkerAddressCache), which
+/// the first trade populates, and is only re-queried as often as the [verify_marker_cache_every_n_trades](crate::store::runtime_tunables::RuntimeTunables#verify_marker_cache_every_n_trades)
+/// tunable dictates.  A `marker_cache_warning` attribute is emitted when that check repairs the cache.
+/// When the [verify_marker_access_on_trade](crate::store::runtime_tunables::RuntimeTunables#verify_marker_access_on_trade)
+/// tunable is enabled, the trade is then rejected with a [NotAuthorizedError](ContractError::NotAuthorizedError)
+/// if the contract lacks a required permission on either marker.
 ///
 /// # Parameters
 /// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
@@ -24,26 +72,253 @@ use result_extensions::ResultExtensions;
 /// of the instantiation message, as well as the funds provided as an amount during the transaction.
 /// * `trade_amount` The amount of the trading marker to pull from the sender's account in exchange
 /// for deposit denom.
+/// * `options` The optional behaviors requested for the trade.  See [TradeOptions] for details.
 pub fn withdraw_trading(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    trade_amount: u128,
+    trade_amount: Uint128,
+    options: TradeOptions,
 ) -> Result<Response, ContractError> {
     check_funds_are_empty(&info)?;
     let contract_state = get_contract_state_v1(deps.storage)?;
-    check_account_has_all_attributes(
-        &deps,
-        &info.sender,
-        &contract_state.required_withdraw_attributes,
+    let tunables = get_runtime_tunables(deps.storage)?;
+    let response = trade_response(
+        "withdraw_trading",
+        &env,
+        &contract_state,
+        tunables.event_verbosity,
+    );
+    execute_withdraw_trading(
+        deps,
+        &env,
+        &contract_state,
+        &tunables,
+        info.sender,
+        trade_amount,
+        options,
+        response,
+    )
+}
+
+/// Performs every check and calculation of [withdraw_trading] for the given sender, adding the
+/// trade's attributes and messages to the input response.  This allows other routes to execute the
+/// same trade without duplicating its behavior.
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `contract_state` The current contract state, which dictates the denoms and requirements used
+/// in the trade.
+/// * `tunables` The current runtime tunables, which dictate the attributes emitted for the trade.
+/// * `sender` The account trading its trading denom for deposit denom.
+/// * `trade_amount` The amount of the trading marker to pull from the sender's account in exchange
+/// for deposit denom.
+/// * `options` The optional behaviors requested for the trade.  See [TradeOptions] for details.
+/// * `response` The response to which the trade's attributes and messages are added.
+pub fn execute_withdraw_trading(
+    mut deps: DepsMut,
+    env: &Env,
+    contract_state: &ContractStateV1,
+    tunables: &RuntimeTunables,
+    sender: Addr,
+    trade_amount: Uint128,
+    options: TradeOptions,
+    response: Response,
+) -> Result<Response, ContractError> {
+    let mut plan = plan_withdraw_trading(
+        &deps.as_ref(),
+        env,
+        contract_state,
+        &sender,
+        trade_amount,
+        &options,
+    )?;
+    let fill_amount = plan.conversion.source_amount;
+    plan.check_remainder_acknowledged(
+        &contract_state.trading_marker.name,
+        options.allow_remainder,
     )?;
+    // Dry runs leave contract storage untouched, so only real trades populate or verify the cache
+    let marker_cache_warning = if options.dry_run {
+        None
+    } else {
+        maintain_marker_address_cache(deps.branch(), contract_state, tunables)?
+    };
+    if marker_cache_warning.is_some() {
+        // The plan's messages were built with the stale cached address, so they are rebuilt with
+        // the repaired one
+        plan.messages = build_withdraw_messages(
+            env,
+            contract_state,
+            &sender,
+            &get_trading_marker_address(&deps.as_ref(), contract_state)?,
+            plan.collected_amount,
+            plan.conversion.target_amount,
+            plan.trade_fee.as_ref(),
+        );
+    }
+    let mut route_attributes = vec![
+        Attribute::new("withdraw_input_denom", &contract_state.trading_marker.name),
+        Attribute::new("withdraw_input_amount", trade_amount.to_string()),
+        Attribute::new("withdraw_actual_amount", plan.collected_amount.to_string()),
+        Attribute::new("received_denom", &contract_state.deposit_marker.name),
+        Attribute::new("received_amount", plan.output_amount().to_string()),
+    ];
+    if fill_amount < trade_amount {
+        route_attributes.push(Attribute::new("partial_fill", "true"));
+        route_attributes.push(Attribute::new("filled_amount", fill_amount.to_string()));
+    }
+    if let Some(referrer) = &plan.referrer {
+        route_attributes.push(Attribute::new("referrer", referrer));
+    }
+    if plan.whitelisted {
+        route_attributes.push(Attribute::new("whitelisted", "true"));
+    }
+    if let Some(trade_fee) = &plan.trade_fee {
+        route_attributes.push(Attribute::new("fee_amount", trade_fee.amount.to_string()));
+        route_attributes.push(Attribute::new("fee_collector", &trade_fee.collector));
+    }
+    if let Some(warning) = marker_cache_warning {
+        route_attributes.push(Attribute::new("marker_cache_warning", warning));
+    }
+    let input = coin(
+        plan.collected_amount.u128(),
+        &contract_state.trading_marker.name,
+    );
+    let output = coin(
+        plan.output_amount().u128(),
+        &contract_state.deposit_marker.name,
+    );
+    let response = add_trade_attributes(
+        response,
+        env,
+        contract_state,
+        tunables.event_verbosity,
+        TradeEvent {
+            sender: sender.to_owned(),
+            input,
+            output,
+            remainder: plan.conversion.remainder,
+            requires_approval: plan.requires_approval,
+            route_attributes,
+        },
+    );
+    if options.dry_run {
+        add_dry_run_attributes(
+            response,
+            tunables.event_verbosity,
+            plan.conversion.remainder,
+            plan.requires_approval,
+        )
+        .to_ok()
+    } else if plan.requires_approval {
+        submit_pending_trade(
+            deps.storage,
+            env,
+            TradeDirection::Withdraw,
+            sender,
+            fill_amount,
+            plan.referrer.to_owned(),
+            options.allow_remainder,
+            add_msg_fee_attributes(
+                response.add_submessages(trade_execution_messages(env, &plan, "withdraw_trading")),
+                plan.msg_fee.as_ref(),
+                plan.fee_discount.as_ref(),
+                tunables.event_verbosity,
+            ),
+        )
+    } else {
+        let trade_receipt = record_planned_trade(
+            deps.storage,
+            env,
+            contract_state,
+            TradeDirection::Withdraw,
+            &sender,
+            plan.referrer.as_ref(),
+            &plan,
+        )?;
+        add_trade_receipt(
+            add_msg_fee_attributes(
+                response.add_submessages(trade_execution_messages(env, &plan, "withdraw_trading")),
+                plan.msg_fee.as_ref(),
+                plan.fee_discount.as_ref(),
+                tunables.event_verbosity,
+            ),
+            &trade_receipt,
+        )
+    }
+}
+
+/// Runs every check performed by [withdraw_trading] and constructs the messages that it would
+/// emit, without modifying any contract state.  This allows the same logic to back both the
+/// execution route and any query that previews its results.
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `contract_state` The current contract state, which dictates the denoms and requirements used
+/// in the trade.
+/// * `sender` The account that would be trading its trading denom for deposit denom.
+/// * `requested_amount` The amount of the trading marker to pull from the sender's account in
+/// exchange for deposit denom, before any partial fill reduces it.
+/// * `options` The optional behaviors requested for the trade.  Only the `quote`, `referrer` and
+/// `allow_partial` options affect the plan.
+pub fn plan_withdraw_trading(
+    deps: &Deps,
+    env: &Env,
+    contract_state: &ContractStateV1,
+    sender: &Addr,
+    requested_amount: Uint128,
+    options: &TradeOptions,
+) -> Result<TradePlan, ContractError> {
+    // A denylisted sender is refused before anything else is evaluated, so that the contract's
+    // other requirements are never reported to it
+    check_account_not_denylisted(deps.storage, sender)?;
+    let referrer = validate_referrer(deps, sender, options.referrer.to_owned())?;
+    let tunables = get_runtime_tunables(deps.storage)?;
+    // Every route that trades, including approvals and rebalances, verifies the contract's marker
+    // permissions here when the tunable requires it
+    if tunables.verify_marker_access_on_trade {
+        check_contract_access_to_markers(
+            deps,
+            &env.contract.address,
+            &contract_state.deposit_marker,
+            &contract_state.trading_marker,
+        )?;
+    }
+    let trade_amount = if options.allow_partial {
+        resolve_fill_amount(
+            deps,
+            contract_state,
+            TradeDirection::Withdraw,
+            sender,
+            requested_amount,
+        )?
+    } else {
+        requested_amount
+    };
+    if let Some(quote) = &options.quote {
+        check_quote(
+            env,
+            contract_state,
+            &tunables,
+            TradeDirection::Withdraw,
+            trade_amount,
+            quote,
+        )?;
+    }
     let conversion = convert_denom(
         trade_amount,
         &contract_state.trading_marker,
         &contract_state.deposit_marker,
     )?;
-    if conversion.target_amount == 0 {
-        return ContractError::InvalidFundsError {
+    let conversion_check = if conversion.target_amount.is_zero() {
+        ContractError::InvalidFundsError {
             message: format!(
                 "sent [{}{}], but that is not enough to convert to at least one [{}]",
                 trade_amount,
@@ -51,16 +326,135 @@ pub fn withdraw_trading(
                 &contract_state.deposit_marker.name,
             ),
         }
+        .to_err()
+    } else {
+        Ok(())
+    };
+    let collected_amount = trade_amount.checked_sub(conversion.remainder)?;
+    // Whitelisted senders may trade without holding the required attributes, but
+    // their attributes are still searched for any fee discount
+    let whitelisted = is_whitelisted_account(deps.storage, sender)?;
+    let required_attributes: &[String] = if whitelisted {
+        &[]
+    } else {
+        contract_state.required_withdraw_attributes.as_slice()
+    };
+    // Every precondition is evaluated before failing, allowing the sender to resolve all problems
+    // at once
+    let attribute_check =
+        check_sender_attributes(deps, contract_state, sender, required_attributes, &tunables);
+    let mut preconditions = vec![
+        contract_state.check_not_paused(),
+        contract_state.check_withdraw_enabled(),
+        contract_state.check_trading_schedule(&env.block),
+        // The limits apply to the requested amount, even when a partial fill reduces it
+        contract_state.check_min_trade_amount(TradeDirection::Withdraw, requested_amount),
+        contract_state.check_max_trade_amount(TradeDirection::Withdraw, requested_amount),
+        conversion_check,
+        check_account_daily_limit(
+            deps.storage,
+            sender,
+            &env.block.time,
+            contract_state.per_account_daily_limit,
+            conversion.target_amount,
+            &contract_state.deposit_marker.name,
+        )
+        .map(|_| ()),
+        attribute_check.requirement,
+    ];
+    if let Some(compliance_contract) = &contract_state.compliance_contract {
+        preconditions.push(check_trade_compliance(
+            deps,
+            compliance_contract,
+            sender,
+            TradeDirection::Withdraw,
+            collected_amount,
+            &contract_state.trading_marker.name,
+        ));
+    }
+    if !contract_state.skip_balance_precheck {
+        preconditions.push(check_account_has_enough_denom(
+            deps,
+            sender.as_str(),
+            &contract_state.trading_marker.name,
+            &[],
+            collected_amount,
+        ));
+    }
+    check_preconditions(preconditions)?;
+    // Fee-exempt senders are assessed neither the msg fee nor any fee withheld from the output
+    let fee_exempt = is_fee_exempt(deps.storage, sender)?;
+    // Verify that the contract holds enough deposit denom to release before emitting any messages,
+    // because a failed transfer within the marker module does not reference this contract
+    let available_reserves = get_account_balance(
+        deps,
+        env.contract.address.as_str(),
+        &contract_state.deposit_marker.name,
+    )?;
+    if available_reserves < conversion.target_amount {
+        return ContractError::InsolventError {
+            required: conversion.target_amount,
+            available: available_reserves,
+            denom: contract_state.deposit_marker.name.to_owned(),
+        }
         .to_err();
     }
-    let collected_amount = trade_amount - conversion.remainder;
-    check_account_has_enough_denom(
-        &deps.as_ref(),
-        info.sender.as_str(),
-        &contract_state.trading_marker.name,
+    // The withdraw fee is only considered once the trade is known to release at least one unit.
+    // It is rounded down and its rate is always below the full amount, so the sender always
+    // receives at least one unit of the released coin
+    let withdraw_fee = contract_state
+        .withdraw_fee(conversion.target_amount)
+        .filter(|_| !fee_exempt);
+    let messages = build_withdraw_messages(
+        env,
+        contract_state,
+        sender,
+        &get_trading_marker_address(deps, contract_state)?,
         collected_amount,
-    )?;
-    // Collect the amount to be traded to the contract from the sender and give it directly to the
+        conversion.target_amount,
+        withdraw_fee.as_ref(),
+    );
+    let requires_approval = contract_state.requires_trade_approval(conversion.target_amount);
+    TradePlan {
+        deposit_amount: conversion.target_amount,
+        conversion,
+        collected_amount,
+        messages,
+        msg_fee: attribute_check.msg_fee.filter(|_| !fee_exempt),
+        fee_discount: attribute_check.fee_discount.filter(|_| !fee_exempt),
+        trade_fee: withdraw_fee,
+        whitelisted,
+        referrer,
+        requires_approval,
+    }
+    .to_ok()
+}
+
+/// Constructs the messages that exchange an account's trading denom for deposit denom, in the order
+/// that they should be executed.  Each message is wrapped in a submessage that replies on error
+/// with the [step](TradeStep) that it performs.  No checks are performed.
+///
+/// # Parameters
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `contract_state` The current contract state, which dictates the denoms used in the trade.
+/// * `account` The account whose trading denom is collected, and that receives the deposit denom.
+/// * `trading_marker_address` The bech32 address of the trading marker, which receives the collected
+/// trading denom before it is burned.
+/// * `collected_amount` The amount of trading denom to collect from the account and burn.
+/// * `released_amount` The total amount of deposit denom released by the contract.
+/// * `withdraw_fee` The portion of the released amount that is transferred to the fee collector
+/// instead of the account, if any.
+pub fn build_withdraw_messages(
+    env: &Env,
+    contract_state: &ContractStateV1,
+    account: &Addr,
+    trading_marker_address: &str,
+    collected_amount: Uint128,
+    released_amount: Uint128,
+    withdraw_fee: Option<&TradeFee>,
+) -> Vec<SubMsg> {
+    // Collect the amount to be traded to the contract from the account and give it directly to the
     // marker in order to stage it for burning
     let collect_funds_msg = MsgTransferRequest {
         administrator: env.contract.address.to_string(),
@@ -68,21 +462,21 @@ pub fn withdraw_trading(
             denom: contract_state.trading_marker.name.to_owned(),
             amount: collected_amount.to_string(),
         }),
-        from_address: info.sender.to_string(),
-        to_address: get_marker_address_for_denom(
-            &deps.as_ref(),
-            &contract_state.trading_marker.name,
-        )?,
+        from_address: account.to_string(),
+        to_address: trading_marker_address.to_string(),
     };
-    // Release the total converted amount of funds back to the user
+    let fee_amount = withdraw_fee
+        .map(|withdraw_fee| withdraw_fee.amount)
+        .unwrap_or_default();
+    // Release the total converted amount of funds back to the account, less any withdraw fee
     let release_funds_msg = MsgTransferRequest {
         administrator: env.contract.address.to_string(),
         amount: Some(Coin {
             denom: contract_state.deposit_marker.name.to_owned(),
-            amount: conversion.target_amount.to_string(),
+            amount: released_amount.saturating_sub(fee_amount).to_string(),
         }),
         from_address: env.contract.address.to_string(),
-        to_address: info.sender.to_string(),
+        to_address: account.to_string(),
     };
     // Burn all coins that were received except those that could not be converted, these will be
     // refunded
@@ -93,42 +487,66 @@ pub fn withdraw_trading(
             denom: contract_state.trading_marker.name.to_owned(),
         }),
     };
-    Response::new()
-        .add_message(collect_funds_msg)
-        .add_message(release_funds_msg)
-        .add_message(burn_msg)
-        .add_attribute("action", "withdraw_trading")
-        .add_attribute("contract_address", env.contract.address.to_string())
-        .add_attribute("contract_type", CONTRACT_TYPE)
-        .add_attribute("contract_name", &contract_state.contract_name)
-        .add_attribute("withdraw_input_denom", &contract_state.trading_marker.name)
-        .add_attribute("withdraw_input_amount", trade_amount.to_string())
-        .add_attribute("withdraw_actual_amount", collected_amount.to_string())
-        .add_attribute("received_denom", &contract_state.deposit_marker.name)
-        .add_attribute("received_amount", conversion.target_amount.to_string())
-        .to_ok()
+    let mut messages = vec![
+        TradeStep::Collect.sub_msg(collect_funds_msg),
+        TradeStep::Release.sub_msg(release_funds_msg),
+    ];
+    // Transfer the withheld fee from the contract to the collector in its own message
+    if let Some(withdraw_fee) = withdraw_fee.filter(|withdraw_fee| !withdraw_fee.amount.is_zero()) {
+        messages.push(TradeStep::Release.sub_msg(MsgTransferRequest {
+            administrator: env.contract.address.to_string(),
+            amount: Some(Coin {
+                denom: contract_state.deposit_marker.name.to_owned(),
+                amount: withdraw_fee.amount.to_string(),
+            }),
+            from_address: env.contract.address.to_string(),
+            to_address: withdraw_fee.collector.to_string(),
+        }));
+    }
+    messages.push(TradeStep::Burn.sub_msg(burn_msg));
+    messages
 }
 
 #[cfg(test)]
 mod tests {
     use crate::execute::withdraw_trading::withdraw_trading;
-    use crate::store::contract_state::CONTRACT_TYPE;
+    use crate::store::account_daily_usage::{get_account_daily_usage, record_account_daily_usage};
+    use crate::store::attribute_whitelist::{add_whitelisted_account, remove_whitelisted_account};
+    use crate::store::contract_state::{
+        get_contract_state_v1, set_contract_state_v1, CONTRACT_TYPE, CONTRACT_VERSION,
+    };
+    use crate::store::denylist::add_denylisted_account;
+    use crate::store::fee_exemptions::add_fee_exemption;
+    use crate::store::marker_address_cache::{
+        get_marker_address_cache, set_marker_address_cache, MarkerAddressCache,
+    };
+    use crate::store::runtime_tunables::{set_runtime_tunables, RuntimeTunables};
+    use crate::store::trade_receipts::get_trade_receipt;
     use crate::test::attribute_extractor::AttributeExtractor;
     use crate::test::test_constants::{
         DEFAULT_CONTRACT_NAME, DEFAULT_DEPOSIT_DENOM_NAME, DEFAULT_REQUIRED_WITHDRAW_ATTRIBUTE,
         DEFAULT_TRADING_DENOM_NAME,
     };
     use crate::test::test_instantiate::{test_instantiate, test_instantiate_with_msg};
+    use crate::test::test_markers::{
+        mock_marker_response, mock_missing_marker_response, test_marker_account,
+    };
     use crate::types::denom::Denom;
     use crate::types::error::ContractError;
     use crate::types::msg::InstantiateMsg;
-    use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
-    use cosmwasm_std::{coins, Addr, AnyMsg, CosmosMsg};
+    use crate::types::msg_fee::MsgFee;
+    use crate::types::quote::{QuoteRef, TradeDirection};
+    use crate::types::trade_options::TradeOptions;
+    use crate::types::trade_step::TradeStep;
+    use crate::util::quote_utils::derive_trade_quote;
+    use cosmwasm_std::testing::{message_info, mock_env, MockApi, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{
+        coin, coins, Addr, AnyMsg, CosmosMsg, DepsMut, Order, ReplyOn, Response, Storage, Uint128,
+    };
     use provwasm_mocks::{
         mock_provenance_dependencies, mock_provenance_dependencies_with_custom_querier,
         MockProvenanceQuerier,
     };
-    use provwasm_std::shim::Any;
     use provwasm_std::types::cosmos::auth::v1beta1::BaseAccount;
     use provwasm_std::types::cosmos::bank::v1beta1::{QueryBalanceRequest, QueryBalanceResponse};
     use provwasm_std::types::cosmos::base::v1beta1::Coin;
@@ -136,9 +554,9 @@ mod tests {
         Attribute, AttributeType, QueryAttributesRequest, QueryAttributesResponse,
     };
     use provwasm_std::types::provenance::marker::v1::{
-        MarkerAccount, MarkerStatus, MarkerType, MsgBurnRequest, MsgTransferRequest,
-        QueryMarkerRequest, QueryMarkerResponse,
+        Access, AccessGrant, MarkerAccount, MsgBurnRequest, MsgTransferRequest,
     };
+    use provwasm_std::types::provenance::msgfees::v1::MsgAssessCustomMsgFeeRequest;
 
     #[test]
     fn provided_funds_should_cause_an_error() {
@@ -147,7 +565,8 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             message_info(&Addr::unchecked("sender"), &coins(10, "somecoin")),
-            10,
+            Uint128::new(10),
+            TradeOptions::default(),
         )
         .expect_err("an error should be emitted when coin is provided");
         assert!(
@@ -156,6 +575,160 @@ mod tests {
         );
     }
 
+    #[test]
+    fn disabled_route_should_reject_withdraw_trading() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let mut contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        contract_state.withdraw_enabled = false;
+        set_contract_state_v1(&mut deps.storage, &contract_state)
+            .expect("contract state should be stored");
+        let error = withdraw_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(10),
+            TradeOptions::default(),
+        )
+        .expect_err("a trade through a disabled route should fail");
+        match error {
+            ContractError::PreconditionsFailed { failures } => assert!(
+                failures.contains(
+                    &"route disabled: withdraw_trading is disabled by the contract admin"
+                        .to_string()
+                ),
+                "the disabled route should be reported, but got: {failures:?}",
+            ),
+            e => panic!("unexpected error encountered: {e:?}"),
+        };
+    }
+
+    #[test]
+    fn amount_below_the_minimum_should_reject_withdraw_trading() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let mut contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        contract_state.min_withdraw_amount = Some(Uint128::new(10_000));
+        set_contract_state_v1(&mut deps.storage, &contract_state)
+            .expect("contract state should be stored");
+        let error = withdraw_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(9_999),
+            TradeOptions::default(),
+        )
+        .expect_err("a trade below the minimum should fail");
+        match error {
+            ContractError::PreconditionsFailed { failures } => assert!(
+                failures.contains(&format!("validation failed: requested [9999{DEFAULT_TRADING_DENOM_NAME}], but the minimum withdraw amount is [10000{DEFAULT_TRADING_DENOM_NAME}]")),
+                "the minimum should be reported, but got: {failures:?}",
+            ),
+            e => panic!("unexpected error encountered: {e:?}"),
+        };
+    }
+
+    #[test]
+    fn amount_beyond_the_account_daily_limit_should_reject_withdraw_trading() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let mut contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        contract_state.per_account_daily_limit = Some(Uint128::new(100));
+        set_contract_state_v1(&mut deps.storage, &contract_state)
+            .expect("contract state should be stored");
+        let env = mock_env();
+        let sender = Addr::unchecked("sender");
+        record_account_daily_usage(
+            &mut deps.storage,
+            &sender,
+            &env.block.time,
+            contract_state.per_account_daily_limit,
+            Uint128::new(95),
+            DEFAULT_DEPOSIT_DENOM_NAME,
+        )
+        .expect("recording earlier usage should succeed");
+        let error = withdraw_trading(
+            deps.as_mut(),
+            env.to_owned(),
+            message_info(&sender, &[]),
+            Uint128::new(60_000),
+            TradeOptions::default(),
+        )
+        .expect_err("a trade beyond the daily limit should fail");
+        match error {
+            ContractError::PreconditionsFailed { failures } => assert!(
+                failures
+                    .iter()
+                    .any(|failure| failure.starts_with("account daily limit exceeded: ")),
+                "the exceeded daily limit should be reported, but got: {failures:?}",
+            ),
+            e => panic!("unexpected error encountered: {e:?}"),
+        };
+        assert_eq!(
+            Uint128::new(95),
+            get_account_daily_usage(&deps.storage, &sender, &env.block.time)
+                .expect("daily usage should load")
+                .amount,
+            "a rejected trade should not be added to the daily usage",
+        );
+    }
+
+    #[test]
+    fn denylisted_sender_should_be_rejected_with_the_stored_reason() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        add_denylisted_account(
+            &mut deps.storage,
+            &Addr::unchecked("sender"),
+            Some("flagged by compliance".to_string()),
+        )
+        .expect("denylisting the sender should succeed");
+        let error = withdraw_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(100),
+            TradeOptions::default(),
+        )
+        .expect_err("a trade by a denylisted sender should fail");
+        assert_eq!(
+            "not authorized: account [sender] is denylisted: flagged by compliance",
+            error.to_string(),
+            "the stored reason should be reported",
+        );
+    }
+
+    #[test]
+    fn paused_contract_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let mut contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        contract_state.paused = true;
+        set_contract_state_v1(&mut deps.storage, &contract_state)
+            .expect("contract state should be stored");
+        let error = withdraw_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(10),
+            TradeOptions::default(),
+        )
+        .expect_err("an error should be emitted when the contract is paused");
+        match error {
+            ContractError::PreconditionsFailed { failures } => assert!(
+                failures
+                    .iter()
+                    .any(|failure| failure.starts_with("contract is paused: ")),
+                "the paused contract should be reported, but got: {failures:?}",
+            ),
+            e => panic!("unexpected error encountered: {e:?}"),
+        };
+    }
+
     #[test]
     fn missing_contract_state_should_cause_an_error() {
         let mut deps = mock_provenance_dependencies();
@@ -163,7 +736,8 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             message_info(&Addr::unchecked("sender"), &[]),
-            10,
+            Uint128::new(10),
+            TradeOptions::default(),
         )
         .expect_err("an error should be emitted when no contract state exists");
         assert!(
@@ -172,6 +746,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn expired_quote_should_cause_an_error() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let mut env = mock_env();
+        let quoted_at_height = env.block.height;
+        let quote = derive_trade_quote(
+            &env,
+            &get_contract_state_v1(&deps.storage).expect("contract state should load"),
+            &RuntimeTunables::default(),
+            TradeDirection::Withdraw,
+            Uint128::new(10000),
+            quoted_at_height,
+        )
+        .expect("the quote should be derived");
+        env.block.height = quote.valid_through_height + 1;
+        let error = withdraw_trading(
+            deps.as_mut(),
+            env,
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(10000),
+            TradeOptions {
+                quote: Some(QuoteRef {
+                    hash: quote.hash,
+                    quoted_at_height,
+                }),
+                ..TradeOptions::default()
+            },
+        )
+        .expect_err("an error should occur when the quote has expired");
+        assert!(
+            matches!(error, ContractError::InvalidQuoteError { .. }),
+            "unexpected error emitted when the quote has expired: {error:?}",
+        );
+    }
+
     #[test]
     fn sender_missing_required_amount_should_cause_an_error() {
         let mut querier = MockProvenanceQuerier::new(&[]);
@@ -199,12 +809,13 @@ mod tests {
             },
         );
         let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
-        test_instantiate(deps.as_mut());
-        let error = withdraw_trading(deps.as_mut(), mock_env(), message_info(&Addr::unchecked("sender"), &[]), 10000)
+        test_instantiate(&mut deps);
+        let error = withdraw_trading(deps.as_mut(), mock_env(), message_info(&Addr::unchecked("sender"), &[]), Uint128::new(10000), TradeOptions::default())
             .expect_err("an error should occur when the sender tries to trade more funds than are available to them");
-        assert!(
-            matches!(error, ContractError::InvalidAccountError { .. }),
-            "unexpected error type encountered when the sender tries to trade too much: {error:?}",
+        assert_eq!(
+            "preconditions failed: invalid account: required [10000], but account only holds [10]",
+            error.to_string(),
+            "unexpected error encountered when the sender tries to trade too much",
         );
     }
 
@@ -215,7 +826,7 @@ mod tests {
             &mut querier,
             QueryBalanceResponse {
                 balance: Some(Coin {
-                    amount: "10".to_string(),
+                    amount: "10000".to_string(),
                     denom: DEFAULT_TRADING_DENOM_NAME.to_string(),
                 }),
             },
@@ -229,20 +840,65 @@ mod tests {
             },
         );
         let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
-        test_instantiate(deps.as_mut());
+        test_instantiate(&mut deps);
         let error = withdraw_trading(
             deps.as_mut(),
             mock_env(),
             message_info(&Addr::unchecked("sender"), &[]),
-            10,
+            Uint128::new(10000),
+            TradeOptions::default(),
         )
         .expect_err("an error should occur when the sender does not have a required attribute");
-        assert!(
-            matches!(error, ContractError::InvalidAccountError { .. }),
+        assert_eq!(
+            "preconditions failed: invalid account: account does not have all required attributes",
+            error.to_string(),
             "unexpected error when account is missing required attribute",
         );
     }
 
+    #[test]
+    fn sender_missing_required_attribute_and_amount_should_report_both_failures() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(
+            &mut querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    amount: "10".to_string(),
+                    denom: DEFAULT_TRADING_DENOM_NAME.to_string(),
+                }),
+            },
+        );
+        QueryAttributesRequest::mock_response(
+            &mut querier,
+            QueryAttributesResponse {
+                account: "sender".to_string(),
+                attributes: vec![],
+                pagination: None,
+            },
+        );
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        test_instantiate(&mut deps);
+        let error = withdraw_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(10000),
+            TradeOptions::default(),
+        )
+        .expect_err("an error should occur when the sender fails multiple preconditions");
+        match error {
+            ContractError::PreconditionsFailed { failures } => assert_eq!(
+                vec![
+                    "invalid account: account does not have all required attributes".to_string(),
+                    "invalid account: required [10000], but account only holds [10]".to_string(),
+                ],
+                failures,
+                "every failed precondition should be reported",
+            ),
+            e => panic!("unexpected error emitted when multiple preconditions fail: {e:?}"),
+        };
+    }
+
     #[test]
     fn conversion_producing_no_output_denom_should_cause_an_error() {
         let mut querier = MockProvenanceQuerier::new(&[]);
@@ -274,7 +930,7 @@ mod tests {
         // digit conversion to fail with the input value 7:
         // Input 7 == 0.07, but trading marker can only hold values with one decimal place.
         test_instantiate_with_msg(
-            deps.as_mut(),
+            &mut deps,
             InstantiateMsg {
                 deposit_marker: Denom::new("denom1", 1),
                 trading_marker: Denom::new("denom2", 2),
@@ -285,21 +941,20 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             message_info(&Addr::unchecked("sender"), &[]),
-            7,
+            Uint128::new(7),
+            TradeOptions::default(),
         )
         .expect_err("a conversion that does not produce any deposit denom should fail");
-        let _expected_err =
-            "sent [7denom2], but that is not enough to convert to at least one [denom1]"
-                .to_string();
-        assert!(
-            matches!(
-                error,
-                ContractError::InvalidFundsError {
-                    message: _expected_err,
-                },
+        match error {
+            ContractError::PreconditionsFailed { failures } => assert!(
+                failures.contains(
+                    &"invalid funds: sent [7denom2], but that is not enough to convert to at least one [denom1]"
+                        .to_string()
+                ),
+                "the failed conversion should be reported, but got: {failures:?}",
             ),
-            "unexpected error when invalid conversion occurs",
-        );
+            e => panic!("unexpected error when invalid conversion occurs: {e:?}"),
+        };
     }
 
     #[test]
@@ -328,21 +983,32 @@ mod tests {
                 pagination: None,
             },
         );
-        QueryMarkerRequest::mock_response(&mut querier, QueryMarkerResponse { marker: None });
         let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
         test_instantiate_with_msg(
-            deps.as_mut(),
+            &mut deps,
             InstantiateMsg {
                 deposit_marker: Denom::new("denom1", 2),
                 trading_marker: Denom::new("denom2", 1),
                 ..InstantiateMsg::default()
             },
         );
+        // The marker existed at instantiation, but has since been removed, which the withdraw
+        // discovers when it verifies the cached marker address
+        set_runtime_tunables(
+            &mut deps.storage,
+            &RuntimeTunables {
+                verify_marker_cache_every_n_trades: Some(1),
+                ..RuntimeTunables::default()
+            },
+        )
+        .expect("setting the tunables should succeed");
+        mock_missing_marker_response(&mut deps.querier);
         let error = withdraw_trading(
             deps.as_mut(),
             mock_env(),
             message_info(&Addr::unchecked("sender"), &[]),
-            1,
+            Uint128::new(1),
+            TradeOptions::default(),
         )
         .expect_err("a missing trading marker should cause a failure");
         let _expected_err = "unable to query marker by name [denom2]".to_string();
@@ -383,31 +1049,16 @@ mod tests {
                 pagination: None,
             },
         );
-        QueryMarkerRequest::mock_response(
+        mock_marker_response(
             &mut querier,
-            QueryMarkerResponse {
-                marker: Some(Any {
-                    type_url: "/provenance.marker.v1.MarkerAccount".to_string(),
-                    value: MarkerAccount {
-                        base_account: Some(BaseAccount {
-                            address: "trading-marker-addr".to_string(),
-                            pub_key: None,
-                            account_number: 32,
-                            sequence: 37,
-                        }),
-                        manager: "some-manager".to_string(),
-                        access_control: vec![],
-                        status: MarkerStatus::Active as i32,
-                        denom: DEFAULT_TRADING_DENOM_NAME.to_string(),
-                        supply: "10".to_string(),
-                        marker_type: MarkerType::Restricted as i32,
-                        supply_fixed: false,
-                        allow_governance_control: false,
-                        allow_forced_transfer: false,
-                        required_attributes: vec![],
-                    }
-                    .to_proto_bytes(),
+            MarkerAccount {
+                base_account: Some(BaseAccount {
+                    address: MockApi::default().addr_make("trading-marker").to_string(),
+                    pub_key: None,
+                    account_number: 32,
+                    sequence: 37,
                 }),
+                ..test_marker_account(DEFAULT_TRADING_DENOM_NAME, "10")
             },
         );
         let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
@@ -417,18 +1068,41 @@ mod tests {
         // However, the deposit marker has a precision of 2, which will convert to 4.32.  The 1 will
         // be dropped and be a remaining value for the sender.
         test_instantiate_with_msg(
-            deps.as_mut(),
+            &mut deps,
             InstantiateMsg {
                 deposit_marker: Denom::new(DEFAULT_DEPOSIT_DENOM_NAME, 2),
                 trading_marker: Denom::new(DEFAULT_TRADING_DENOM_NAME, 3),
                 ..InstantiateMsg::default()
             },
         );
+        let error = withdraw_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(4321),
+            TradeOptions::default(),
+        )
+        .expect_err("an error should occur when the remainder is not acknowledged");
+        assert!(
+            matches!(
+                &error,
+                ContractError::RemainderNotAcknowledgedError {
+                    remainder,
+                    clean_amount,
+                    denom,
+                } if remainder.u128() == 1 && clean_amount.u128() == 4320 && denom == DEFAULT_TRADING_DENOM_NAME,
+            ),
+            "unexpected error encountered: {error:?}",
+        );
         let response = withdraw_trading(
             deps.as_mut(),
             mock_env(),
             message_info(&Addr::unchecked("sender"), &[]),
-            4321,
+            Uint128::new(4321),
+            TradeOptions {
+                allow_remainder: true,
+                ..TradeOptions::default()
+            },
         )
         .expect("proper circumstances should derive a successful result");
         assert_eq!(
@@ -436,6 +1110,19 @@ mod tests {
             response.messages.len(),
             "expected the response to include three messages",
         );
+        assert_eq!(
+            vec![
+                (ReplyOn::Error, TradeStep::Collect.reply_id()),
+                (ReplyOn::Error, TradeStep::Release.reply_id()),
+                (ReplyOn::Error, TradeStep::Burn.reply_id()),
+            ],
+            response
+                .messages
+                .iter()
+                .map(|msg| (msg.reply_on.to_owned(), msg.id))
+                .collect::<Vec<_>>(),
+            "each message should reply on error with the id of the step that it performs",
+        );
         response.messages.iter().for_each(|msg| match &msg.msg {
             CosmosMsg::Any(AnyMsg { type_url, value }) => match type_url.as_str() {
                 "/provenance.marker.v1.MsgTransferRequest" => {
@@ -460,7 +1147,8 @@ mod tests {
                                 "the fund collection should take the trading denom as input",
                             );
                             assert_eq!(
-                                "trading-marker-addr", req.to_address,
+                                MockApi::default().addr_make("trading-marker").as_str(),
+                                req.to_address,
                                 "the fund collection should send funds back to the trading marker",
                             );
                         }
@@ -504,19 +1192,43 @@ mod tests {
             msg => panic!("unexpected message emitted: {msg:?}"),
         });
         assert_eq!(
-            9,
+            11,
             response.attributes.len(),
-            "the response should emit nine attributes",
+            "the response should emit eleven attributes",
         );
         response.assert_attribute("action", "withdraw_trading");
         response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
         response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
         response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
         response.assert_attribute("withdraw_input_denom", DEFAULT_TRADING_DENOM_NAME);
         response.assert_attribute("withdraw_input_amount", "4321");
         response.assert_attribute("withdraw_actual_amount", "4320");
         response.assert_attribute("received_denom", DEFAULT_DEPOSIT_DENOM_NAME);
         response.assert_attribute("received_amount", "432");
+        response.assert_attribute("receipt", "1");
+        let trade_receipt =
+            get_trade_receipt(&deps.storage, 1).expect("the trade's receipt should load");
+        assert_eq!(
+            TradeDirection::Withdraw,
+            trade_receipt.direction,
+            "the direction should be recorded",
+        );
+        assert_eq!(
+            coin(4320, DEFAULT_TRADING_DENOM_NAME),
+            trade_receipt.input,
+            "the collected trading denom should be recorded as the input",
+        );
+        assert_eq!(
+            coin(432, DEFAULT_DEPOSIT_DENOM_NAME),
+            trade_receipt.output,
+            "the received deposit denom should be recorded as the output",
+        );
+        assert_eq!(
+            Uint128::new(1),
+            trade_receipt.remainder,
+            "the remainder should be recorded",
+        );
     }
 
     #[test]
@@ -545,31 +1257,16 @@ mod tests {
                 pagination: None,
             },
         );
-        QueryMarkerRequest::mock_response(
+        mock_marker_response(
             &mut querier,
-            QueryMarkerResponse {
-                marker: Some(Any {
-                    type_url: "/provenance.marker.v1.MarkerAccount".to_string(),
-                    value: MarkerAccount {
-                        base_account: Some(BaseAccount {
-                            address: "trading-marker-addr".to_string(),
-                            pub_key: None,
-                            account_number: 32,
-                            sequence: 37,
-                        }),
-                        manager: "some-manager".to_string(),
-                        access_control: vec![],
-                        status: MarkerStatus::Active as i32,
-                        denom: DEFAULT_TRADING_DENOM_NAME.to_string(),
-                        supply: "10".to_string(),
-                        marker_type: MarkerType::Restricted as i32,
-                        supply_fixed: false,
-                        allow_governance_control: false,
-                        allow_forced_transfer: false,
-                        required_attributes: vec![],
-                    }
-                    .to_proto_bytes(),
+            MarkerAccount {
+                base_account: Some(BaseAccount {
+                    address: MockApi::default().addr_make("trading-marker").to_string(),
+                    pub_key: None,
+                    account_number: 32,
+                    sequence: 37,
                 }),
+                ..test_marker_account(DEFAULT_TRADING_DENOM_NAME, "10")
             },
         );
         let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
@@ -578,7 +1275,7 @@ mod tests {
         // Sender wants to send 250, which equates to 2.50.  They don't actually have 250, but they
         // do have 200, which is allowed.  This should be allowed to proceed.
         test_instantiate_with_msg(
-            deps.as_mut(),
+            &mut deps,
             InstantiateMsg {
                 deposit_marker: Denom::new(DEFAULT_DEPOSIT_DENOM_NAME, 1),
                 trading_marker: Denom::new(DEFAULT_TRADING_DENOM_NAME, 3),
@@ -589,8 +1286,700 @@ mod tests {
             deps.as_mut(),
             mock_env(),
             message_info(&Addr::unchecked("sender"), &[]),
-            250,
+            Uint128::new(250),
+            TradeOptions {
+                allow_remainder: true,
+                ..TradeOptions::default()
+            },
         )
         .expect("proper circumstances should derive a successful result");
     }
+
+    #[test]
+    fn skipped_balance_precheck_should_not_query_sender_balance() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        // The mocked balance covers the contract's reserve check, but is far lower than the amount
+        // traded, so the trade would fail if the sender's balance were checked
+        QueryBalanceRequest::mock_response(
+            &mut querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    amount: "1".to_string(),
+                    denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+                }),
+            },
+        );
+        QueryAttributesRequest::mock_response(
+            &mut querier,
+            QueryAttributesResponse {
+                account: "sender".to_string(),
+                attributes: vec![Attribute {
+                    name: DEFAULT_REQUIRED_WITHDRAW_ATTRIBUTE.to_string(),
+                    value: vec![],
+                    attribute_type: AttributeType::Json as i32,
+                    address: "addr".to_string(),
+                    expiration_date: None,
+                }],
+                pagination: None,
+            },
+        );
+        mock_marker_response(
+            &mut querier,
+            MarkerAccount {
+                base_account: Some(BaseAccount {
+                    address: MockApi::default().addr_make("trading-marker").to_string(),
+                    pub_key: None,
+                    account_number: 32,
+                    sequence: 37,
+                }),
+                ..test_marker_account(DEFAULT_TRADING_DENOM_NAME, "10")
+            },
+        );
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                skip_balance_precheck: true,
+                ..InstantiateMsg::default()
+            },
+        );
+        let response = withdraw_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(10000),
+            TradeOptions::default(),
+        )
+        .expect(
+            "the trade should succeed without a sender balance check when the precheck is skipped",
+        );
+        assert_eq!(
+            3,
+            response.messages.len(),
+            "expected the response to include all three messages",
+        );
+    }
+
+    #[test]
+    fn exactly_sufficient_contract_reserves_should_produce_a_result() {
+        let mut deps =
+            mock_provenance_dependencies_with_custom_querier(get_reserve_check_querier("2"));
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                skip_balance_precheck: true,
+                ..InstantiateMsg::default()
+            },
+        );
+        let response = withdraw_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(20000),
+            TradeOptions::default(),
+        )
+        .expect("the trade should succeed when the contract holds exactly the amount to release");
+        response.assert_attribute("received_amount", "2");
+    }
+
+    #[test]
+    fn configured_withdraw_fee_should_be_transferred_to_the_collector() {
+        let mut deps =
+            mock_provenance_dependencies_with_custom_querier(get_reserve_check_querier("1000"));
+        let collector = deps.api.addr_make("collector");
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                skip_balance_precheck: true,
+                withdraw_fee_bps: Some(30),
+                fee_collector: Some(collector.to_string()),
+                ..InstantiateMsg::default()
+            },
+        );
+        let trade = |deps: DepsMut, amount: u128| {
+            withdraw_trading(
+                deps,
+                mock_env(),
+                message_info(&Addr::unchecked("sender"), &[]),
+                Uint128::new(amount),
+                TradeOptions::default(),
+            )
+        };
+        let releases = |response: &Response| {
+            response
+                .messages
+                .iter()
+                .filter_map(|msg| match &msg.msg {
+                    CosmosMsg::Any(AnyMsg { type_url, value })
+                        if type_url == "/provenance.marker.v1.MsgTransferRequest" =>
+                    {
+                        let req = MsgTransferRequest::try_from(value.to_owned())
+                            .expect("the transfer request msg should properly deserialize");
+                        (req.from_address == MOCK_CONTRACT_ADDR).then(|| {
+                            let amount = req.amount.expect("the transfer should have an amount");
+                            (req.to_address, amount.amount)
+                        })
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<(String, String)>>()
+        };
+        let burned_amount = |response: &Response| {
+            response
+                .messages
+                .iter()
+                .find_map(|msg| match &msg.msg {
+                    CosmosMsg::Any(AnyMsg { type_url, value })
+                        if type_url == "/provenance.marker.v1.MsgBurnRequest" =>
+                    {
+                        MsgBurnRequest::try_from(value.to_owned())
+                            .expect("the burn request msg should properly deserialize")
+                            .amount
+                            .map(|amount| amount.amount)
+                    }
+                    _ => None,
+                })
+                .expect("the response should burn the collected trading denom")
+        };
+        let response = trade(deps.as_mut(), 10_000_000).expect("the trade should succeed");
+        assert_eq!(
+            4,
+            response.messages.len(),
+            "the fee transfer should be emitted before the burn",
+        );
+        assert_eq!(
+            vec![
+                ("sender".to_string(), "997".to_string()),
+                (collector.to_string(), "3".to_string()),
+            ],
+            releases(&response),
+            "the released deposit denom should be split between the sender and the collector",
+        );
+        assert_eq!(
+            "10000000",
+            burned_amount(&response),
+            "the full collected amount of trading denom should be burned",
+        );
+        response.assert_attribute("received_amount", "997");
+        response.assert_attribute("fee_amount", "3");
+        response.assert_attribute("fee_collector", collector.as_str());
+        // 100 deposit at 30 bps withholds 0.3, which rounds down to zero
+        let response = trade(deps.as_mut(), 1_000_000).expect("the trade should succeed");
+        assert_eq!(
+            vec![("sender".to_string(), "100".to_string())],
+            releases(&response),
+            "a fee that rounds down to zero should not be transferred",
+        );
+        assert_eq!(
+            "1000000",
+            burned_amount(&response),
+            "the full collected amount of trading denom should be burned",
+        );
+        response.assert_attribute("fee_amount", "0");
+        let error = trade(deps.as_mut(), 9_999)
+            .expect_err("an amount that converts to nothing should fail before the fee applies");
+        match error {
+            ContractError::PreconditionsFailed { failures } => assert!(
+                failures.contains(&format!("invalid funds: sent [9999{DEFAULT_TRADING_DENOM_NAME}], but that is not enough to convert to at least one [{DEFAULT_DEPOSIT_DENOM_NAME}]")),
+                "the failed conversion should be reported, but got: {failures:?}",
+            ),
+            e => panic!("unexpected error encountered: {e:?}"),
+        };
+    }
+
+    #[test]
+    fn configured_msg_fee_should_be_assessed_on_withdraw_trading() {
+        let mut deps =
+            mock_provenance_dependencies_with_custom_querier(get_reserve_check_querier("100"));
+        let recipient = deps.api.addr_make("fee-recipient");
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                skip_balance_precheck: true,
+                msg_fee: Some(MsgFee {
+                    amount: coin(250, "nhash"),
+                    recipient: recipient.to_string(),
+                }),
+                ..InstantiateMsg::default()
+            },
+        );
+        let response = withdraw_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(20000),
+            TradeOptions::default(),
+        )
+        .expect("a trade with a configured fee should succeed");
+        assert_eq!(
+            4,
+            response.messages.len(),
+            "the fee should be emitted alongside the trade's messages",
+        );
+        match &response.messages[3].msg {
+            CosmosMsg::Any(AnyMsg { type_url, value }) => {
+                assert_eq!(
+                    "/provenance.msgfees.v1.MsgAssessCustomMsgFeeRequest", type_url,
+                    "the fee should be assessed after the trade's messages",
+                );
+                assert_eq!(
+                    MsgAssessCustomMsgFeeRequest {
+                        name: "withdraw_trading".to_string(),
+                        amount: Some(Coin {
+                            denom: "nhash".to_string(),
+                            amount: "250".to_string(),
+                        }),
+                        recipient: recipient.to_string(),
+                        from: MOCK_CONTRACT_ADDR.to_string(),
+                        recipient_basis_points: "10000".to_string(),
+                    },
+                    MsgAssessCustomMsgFeeRequest::try_from(value.to_owned())
+                        .expect("the value should properly deserialize to an assess fee request"),
+                    "the configured fee should be assessed by the contract",
+                );
+            }
+            msg => panic!("unexpected message emitted: {msg:?}"),
+        }
+        response.assert_attribute("msg_fee", "250nhash");
+    }
+
+    #[test]
+    fn fee_exempt_sender_should_not_be_assessed_fees() {
+        let mut deps =
+            mock_provenance_dependencies_with_custom_querier(get_reserve_check_querier("1000"));
+        let collector = deps.api.addr_make("collector");
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                skip_balance_precheck: true,
+                msg_fee: Some(MsgFee {
+                    amount: coin(250, "nhash"),
+                    recipient: collector.to_string(),
+                }),
+                withdraw_fee_bps: Some(30),
+                fee_collector: Some(collector.to_string()),
+                ..InstantiateMsg::default()
+            },
+        );
+        add_fee_exemption(&mut deps.storage, &Addr::unchecked("sender"))
+            .expect("adding an exemption should succeed");
+        let response = withdraw_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(10_000_000),
+            TradeOptions::default(),
+        )
+        .expect("the trade should succeed");
+        assert_eq!(
+            3,
+            response.messages.len(),
+            "only the trade's messages should be emitted for a fee-exempt sender",
+        );
+        response.assert_attribute("received_amount", "1000");
+        assert!(
+            response.attributes.iter().all(
+                |attr| !["fee_amount", "fee_collector", "msg_fee"].contains(&attr.key.as_str())
+            ),
+            "no fee attributes should be emitted for a fee-exempt sender",
+        );
+    }
+
+    #[test]
+    fn whitelisted_sender_should_skip_required_attributes_but_not_the_balance_check() {
+        let mut deps =
+            mock_provenance_dependencies_with_custom_querier(get_reserve_check_querier("10000"));
+        // The sender holds the default withdraw attribute, but not this one
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                required_withdraw_attributes: vec!["unheld.attribute.pb".to_string()],
+                ..InstantiateMsg::default()
+            },
+        );
+        add_whitelisted_account(&mut deps.storage, &Addr::unchecked("sender"))
+            .expect("whitelisting the sender should succeed");
+        let error = withdraw_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(20000),
+            TradeOptions::default(),
+        )
+        .expect_err("a whitelisted sender should still need to hold the traded amount");
+        assert_eq!(
+            "preconditions failed: invalid account: required [20000], but account only holds [10000]",
+            error.to_string(),
+            "only the balance check should fail for a whitelisted sender",
+        );
+        let response = withdraw_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(10000),
+            TradeOptions::default(),
+        )
+        .expect("a whitelisted sender without the required attributes should be able to trade");
+        response.assert_attribute("whitelisted", "true");
+        remove_whitelisted_account(&mut deps.storage, &Addr::unchecked("sender"))
+            .expect("removing the sender from the whitelist should succeed");
+        let error = withdraw_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(10000),
+            TradeOptions::default(),
+        )
+        .expect_err("a sender removed from the whitelist should need the required attributes");
+        assert_eq!(
+            "preconditions failed: invalid account: account does not have all required attributes",
+            error.to_string(),
+            "unexpected error when account is missing required attribute",
+        );
+    }
+
+    #[test]
+    fn insufficient_contract_reserves_should_cause_an_error() {
+        let mut deps =
+            mock_provenance_dependencies_with_custom_querier(get_reserve_check_querier("1"));
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                skip_balance_precheck: true,
+                ..InstantiateMsg::default()
+            },
+        );
+        let error = withdraw_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(20000),
+            TradeOptions::default(),
+        )
+        .expect_err(
+            "the trade should fail when the contract is one short of the amount to release",
+        );
+        match error {
+            ContractError::InsolventError {
+                required,
+                available,
+                denom,
+            } => {
+                assert_eq!(
+                    Uint128::new(2),
+                    required,
+                    "the required amount should be the release amount"
+                );
+                assert_eq!(
+                    Uint128::new(1),
+                    available,
+                    "the available amount should be the contract balance"
+                );
+                assert_eq!(
+                    DEFAULT_DEPOSIT_DENOM_NAME, denom,
+                    "the denom should be the deposit denom",
+                );
+            }
+            e => panic!("unexpected error emitted when reserves are insufficient: {e:?}"),
+        };
+    }
+
+    #[test]
+    fn dry_run_should_emit_attributes_without_messages() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(
+            &mut querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    amount: "4321".to_string(),
+                    denom: DEFAULT_TRADING_DENOM_NAME.to_string(),
+                }),
+            },
+        );
+        QueryAttributesRequest::mock_response(
+            &mut querier,
+            QueryAttributesResponse {
+                account: "sender".to_string(),
+                attributes: vec![Attribute {
+                    name: DEFAULT_REQUIRED_WITHDRAW_ATTRIBUTE.to_string(),
+                    value: vec![],
+                    attribute_type: AttributeType::Json as i32,
+                    address: "addr".to_string(),
+                    expiration_date: None,
+                }],
+                pagination: None,
+            },
+        );
+        mock_marker_response(
+            &mut querier,
+            MarkerAccount {
+                base_account: Some(BaseAccount {
+                    address: MockApi::default().addr_make("trading-marker").to_string(),
+                    pub_key: None,
+                    account_number: 32,
+                    sequence: 37,
+                }),
+                ..test_marker_account(DEFAULT_TRADING_DENOM_NAME, "10")
+            },
+        );
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                deposit_marker: Denom::new(DEFAULT_DEPOSIT_DENOM_NAME, 2),
+                trading_marker: Denom::new(DEFAULT_TRADING_DENOM_NAME, 3),
+                // The trade releases 432 deposit denom, so it is valued at exactly the threshold
+                large_trade_threshold: Some(Uint128::new(432)),
+                ..InstantiateMsg::default()
+            },
+        );
+        let storage_before = deps
+            .storage
+            .range(None, None, Order::Ascending)
+            .collect::<Vec<_>>();
+        let response = withdraw_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(4321),
+            TradeOptions {
+                dry_run: true,
+                allow_remainder: true,
+                ..TradeOptions::default()
+            },
+        )
+        .expect("a dry run with proper circumstances should derive a successful result");
+        assert!(
+            response.messages.is_empty(),
+            "a dry run should not emit any messages",
+        );
+        assert_eq!(
+            13,
+            response.attributes.len(),
+            "expected all trade attributes to be emitted, as well as the dry run attributes",
+        );
+        response.assert_attribute("dry_run", "true");
+        response.assert_attribute("requires_approval", "true");
+        response.assert_attribute("remainder", "1");
+        response.assert_attribute("action", "withdraw_trading");
+        response.assert_attribute("withdraw_input_amount", "4321");
+        response.assert_attribute("withdraw_actual_amount", "4320");
+        response.assert_attribute("received_amount", "432");
+        assert_eq!(
+            storage_before,
+            deps.storage
+                .range(None, None, Order::Ascending)
+                .collect::<Vec<_>>(),
+            "a dry run should not modify contract storage",
+        );
+    }
+
+    #[test]
+    fn cached_marker_address_should_be_used_without_querying_the_marker() {
+        let mut deps =
+            mock_provenance_dependencies_with_custom_querier(get_reserve_check_querier("2"));
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                skip_balance_precheck: true,
+                ..InstantiateMsg::default()
+            },
+        );
+        let marker_address = MockApi::default().addr_make("trading-marker");
+        withdraw_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(20000),
+            TradeOptions::default(),
+        )
+        .expect("the first trade should succeed");
+        assert_eq!(
+            Some(MarkerAddressCache {
+                trading_marker_address: marker_address.to_owned(),
+                trades_since_verification: 0,
+            }),
+            get_marker_address_cache(&deps.storage).expect("the cache should load"),
+            "the marker address queried at instantiation should be cached",
+        );
+        // The marker now responds with a different address, so any trade that queries it would
+        // direct the collected trading denom elsewhere
+        mock_marker_response(
+            &mut deps.querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        let response = withdraw_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(20000),
+            TradeOptions::default(),
+        )
+        .expect("a trade using the cached address should succeed");
+        assert_eq!(
+            marker_address.as_str(),
+            get_collection_destination(&response),
+            "the cached address should be used instead of querying the marker",
+        );
+        assert!(
+            response
+                .attributes
+                .iter()
+                .all(|attribute| attribute.key != "marker_cache_warning"),
+            "no warning should be emitted when the cache is not verified",
+        );
+    }
+
+    #[test]
+    fn due_marker_cache_verification_should_repair_the_cache_with_a_warning() {
+        let mut deps =
+            mock_provenance_dependencies_with_custom_querier(get_reserve_check_querier("2"));
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                skip_balance_precheck: true,
+                ..InstantiateMsg::default()
+            },
+        );
+        set_runtime_tunables(
+            &mut deps.storage,
+            &RuntimeTunables {
+                verify_marker_cache_every_n_trades: Some(2),
+                ..RuntimeTunables::default()
+            },
+        )
+        .expect("setting the tunables should succeed");
+        let stale_address = MockApi::default().addr_make("stale-marker");
+        set_marker_address_cache(
+            &mut deps.storage,
+            &MarkerAddressCache {
+                trading_marker_address: stale_address.to_owned(),
+                trades_since_verification: 0,
+            },
+        )
+        .expect("setting the cache should succeed");
+        let response = withdraw_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(20000),
+            TradeOptions::default(),
+        )
+        .expect("a trade before verification is due should succeed");
+        assert_eq!(
+            stale_address.as_str(),
+            get_collection_destination(&response),
+            "the cached address should be used before verification is due",
+        );
+        let response = withdraw_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(20000),
+            TradeOptions::default(),
+        )
+        .expect("a trade that verifies the cache should succeed");
+        let marker_address = MockApi::default().addr_make("trading-marker");
+        assert_eq!(
+            marker_address.as_str(),
+            get_collection_destination(&response),
+            "the repaired address should be used by the verifying trade",
+        );
+        response.assert_attribute(
+            "marker_cache_warning",
+            format!(
+                "cached trading marker address [{stale_address}] did not match the marker's address [{marker_address}] and was repaired",
+            ),
+        );
+    }
+
+    fn get_collection_destination(response: &Response) -> String {
+        response
+            .messages
+            .iter()
+            .find_map(|msg| match &msg.msg {
+                CosmosMsg::Any(AnyMsg { type_url, value })
+                    if type_url == "/provenance.marker.v1.MsgTransferRequest" =>
+                {
+                    let req = MsgTransferRequest::try_from(value.to_owned())
+                        .expect("the transfer request msg should properly deserialize");
+                    (req.from_address == "sender").then_some(req.to_address)
+                }
+                _ => None,
+            })
+            .expect("the response should collect trading denom from the sender")
+    }
+
+    fn get_reserve_check_querier<S: Into<String>>(contract_balance: S) -> MockProvenanceQuerier {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(
+            &mut querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    amount: contract_balance.into(),
+                    denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+                }),
+            },
+        );
+        QueryAttributesRequest::mock_response(
+            &mut querier,
+            QueryAttributesResponse {
+                account: "sender".to_string(),
+                attributes: vec![Attribute {
+                    name: DEFAULT_REQUIRED_WITHDRAW_ATTRIBUTE.to_string(),
+                    value: vec![],
+                    attribute_type: AttributeType::Json as i32,
+                    address: "addr".to_string(),
+                    expiration_date: None,
+                }],
+                pagination: None,
+            },
+        );
+        mock_marker_response(
+            &mut querier,
+            MarkerAccount {
+                base_account: Some(BaseAccount {
+                    address: MockApi::default().addr_make("trading-marker").to_string(),
+                    pub_key: None,
+                    account_number: 32,
+                    sequence: 37,
+                }),
+                ..test_marker_account(DEFAULT_TRADING_DENOM_NAME, "10")
+            },
+        );
+        querier
+    }
+    #[test]
+    fn missing_marker_access_should_cause_an_error_when_verified_on_trade() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        set_runtime_tunables(
+            &mut deps.storage,
+            &RuntimeTunables {
+                verify_marker_access_on_trade: true,
+                ..RuntimeTunables::default()
+            },
+        )
+        .expect("setting the tunables should succeed");
+        // Instantiation verified the grants, so they are revoked afterward
+        let mut marker = test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0");
+        marker.access_control = vec![AccessGrant {
+            address: MOCK_CONTRACT_ADDR.to_string(),
+            permissions: vec![Access::Transfer as i32],
+        }];
+        mock_marker_response(&mut deps.querier, marker);
+        let error = withdraw_trading(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("sender"), &[]),
+            Uint128::new(100),
+            TradeOptions::default(),
+        )
+        .expect_err("an error should occur when the contract lacks marker permissions");
+        assert_eq!(
+            format!("not authorized: contract [{MOCK_CONTRACT_ADDR}] is missing permissions [ACCESS_MINT, ACCESS_BURN, ACCESS_WITHDRAW] on marker [{DEFAULT_TRADING_DENOM_NAME}]"),
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+    }
 }
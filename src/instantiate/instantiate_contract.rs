@@ -1,15 +1,32 @@
-use crate::store::contract_state::{set_contract_state_v1, ContractStateV1};
+use crate::store::contract_state::{
+    set_contract_state_v1, ContractStateV1, CONTRACT_TYPE, CONTRACT_VERSION,
+};
+use crate::store::marker_address_cache::{set_marker_address_cache, MarkerAddressCache};
+use crate::store::runtime_tunables::{set_runtime_tunables, RuntimeTunables};
 use crate::types::error::ContractError;
 use crate::types::msg::InstantiateMsg;
-use crate::util::provenance_utils::msg_bind_name;
+use crate::util::provenance_utils::{
+    check_contract_marker_access, check_ibc_denom_trace, check_marker_is_restricted_and_active,
+    get_marker_account, get_marker_address_for_denom, msg_bind_name, msg_set_denom_metadata,
+    DEPOSIT_MARKER_REQUIRED_ACCESS, TRADING_MARKER_REQUIRED_ACCESS,
+};
+use crate::util::response_utils::base_response;
 use crate::util::validation_utils::check_funds_are_empty;
-use cosmwasm_std::{DepsMut, Env, MessageInfo, Response};
+use cosmwasm_std::{Addr, DepsMut, Env, MessageInfo, Response};
+use cw2::set_contract_version;
 use result_extensions::ResultExtensions;
 
 /// The core functionality that runs when the contract is first instantiated.  This creates the
 /// singleton instance of the [ContractStateV1] used to denote the various configurations for the
-/// contract, as well as optionally binding the contract's name if it does not need to be bound
-/// after creation due to namespace restrictions.
+/// contract, the standard cw2 contract version and the default [RuntimeTunables], as well as optionally binding the contract's name if it does not need to be bound
+/// after creation due to namespace restrictions, and optionally publishing the trading marker's denom metadata.
+/// Both markers must already exist on chain, and a [NotFoundError](ContractError::NotFoundError)
+/// is returned otherwise.  Both markers must be restricted and active, because the trade routes
+/// move denom with marker transfers, and a [ValidationError](ContractError::ValidationError)
+/// naming the marker's actual type or status is returned otherwise.  The contract must also
+/// already hold every permission that its trades require on both markers, and a
+/// [NotAuthorizedError](ContractError::NotAuthorizedError) naming the missing permissions is
+/// returned otherwise.  The trading marker's address is stored as the [MarkerAddressCache].
 ///
 /// # Parameters
 /// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
@@ -27,7 +44,7 @@ pub fn instantiate_contract(
     msg: InstantiateMsg,
 ) -> Result<Response, ContractError> {
     check_funds_are_empty(&info)?;
-    let contract_state = ContractStateV1::new(
+    let mut contract_state = ContractStateV1::new(
         info.sender,
         &msg.contract_name,
         &msg.deposit_marker,
@@ -35,10 +52,79 @@ pub fn instantiate_contract(
         &msg.required_deposit_attributes,
         &msg.required_withdraw_attributes,
     );
+    contract_state.admin_is_group = msg.admin_is_group;
+    contract_state.gov_authority = msg
+        .gov_authority
+        .as_ref()
+        .map(|gov_authority| deps.api.addr_validate(gov_authority))
+        .transpose()?;
+    contract_state.deposit_denom_aliases = msg.deposit_denom_aliases;
+    contract_state.skip_balance_precheck = msg.skip_balance_precheck;
+    contract_state.large_trade_threshold = msg.large_trade_threshold;
+    contract_state.min_fund_amount = msg.min_fund_amount;
+    contract_state.min_withdraw_amount = msg.min_withdraw_amount;
+    contract_state.max_fund_amount = msg.max_fund_amount;
+    contract_state.max_withdraw_amount = msg.max_withdraw_amount;
+    contract_state.daily_cap = msg.daily_cap;
+    contract_state.per_account_daily_limit = msg.per_account_daily_limit;
+    if let Some(expected_trace) = &msg.expected_ibc_trace {
+        check_ibc_denom_trace(&deps.as_ref(), &msg.deposit_marker.name, expected_trace)?;
+    }
+    contract_state.expected_ibc_trace = msg.expected_ibc_trace;
+    if let Some(msg_fee) = &msg.msg_fee {
+        deps.api.addr_validate(&msg_fee.recipient)?;
+    }
+    contract_state.msg_fee = msg.msg_fee;
+    contract_state.fund_fee_bps = msg.fund_fee_bps;
+    contract_state.withdraw_fee_bps = msg.withdraw_fee_bps;
+    contract_state.fee_collector = msg
+        .fee_collector
+        .as_ref()
+        .map(|fee_collector| deps.api.addr_validate(fee_collector))
+        .transpose()?;
+    contract_state.required_scope_spec = msg.required_scope_spec;
+    contract_state.compliance_contract = msg
+        .compliance_contract
+        .as_ref()
+        .map(|compliance_contract| deps.api.addr_validate(compliance_contract))
+        .transpose()?;
+    // Verify that both markers exist now, rather than leaving the first trades to discover that the
+    // contract was configured against a missing denom
+    get_marker_address_for_denom(&deps.as_ref(), &contract_state.deposit_marker.name)?;
+    // The queried address has already been validated, so it can be stored without re-validation
+    let trading_marker_address = Addr::unchecked(get_marker_address_for_denom(
+        &deps.as_ref(),
+        &contract_state.trading_marker.name,
+    )?);
+    // A marker of the wrong type or status, or a missing grant, would otherwise only surface as a
+    // marker module error on the first trade
+    for (denom, required_permissions) in [
+        (
+            &contract_state.deposit_marker,
+            &DEPOSIT_MARKER_REQUIRED_ACCESS[..],
+        ),
+        (
+            &contract_state.trading_marker,
+            &TRADING_MARKER_REQUIRED_ACCESS[..],
+        ),
+    ] {
+        let marker = get_marker_account(&deps.as_ref(), &denom.name)?;
+        check_marker_is_restricted_and_active(&marker)?;
+        check_contract_marker_access(&marker, &env.contract.address, required_permissions)?;
+    }
+    contract_state.instantiated_at_height = Some(env.block.height);
+    contract_state.instantiated_at_time = Some(env.block.time);
     set_contract_state_v1(deps.storage, &contract_state)?;
-    let mut response = Response::new()
-        .add_attribute("action", "instantiate")
-        .add_attribute("contract_name", &msg.contract_name)
+    set_contract_version(deps.storage, CONTRACT_TYPE, CONTRACT_VERSION)?;
+    set_runtime_tunables(deps.storage, &RuntimeTunables::default())?;
+    set_marker_address_cache(
+        deps.storage,
+        &MarkerAddressCache {
+            trading_marker_address,
+            trades_since_verification: 0,
+        },
+    )?;
+    let mut response = base_response("instantiate", &env, &contract_state)
         .add_attribute("deposit_marker_name", &msg.deposit_marker.name)
         .add_attribute("trading_marker_name", &msg.trading_marker.name);
     if let Some(name) = msg.name_to_bind {
@@ -46,19 +132,45 @@ pub fn instantiate_contract(
             .add_message(msg_bind_name(&name, env.contract.address, true)?)
             .add_attribute("contract_bound_with_name", name)
     }
+    if let Some(metadata) = &msg.publish_trading_denom_metadata {
+        response = response
+            .add_message(msg_set_denom_metadata(
+                &contract_state.trading_marker,
+                metadata,
+                env.contract.address,
+            ))
+            .add_attribute("trading_denom_metadata_display", &metadata.display)
+    }
     response.to_ok()
 }
 
 #[cfg(test)]
 mod tests {
     use crate::instantiate::instantiate_contract::instantiate_contract;
+    use crate::store::contract_state::{get_contract_state_v1, CONTRACT_TYPE, CONTRACT_VERSION};
+    use crate::store::marker_address_cache::{get_marker_address_cache, MarkerAddressCache};
+    use crate::store::runtime_tunables::{get_runtime_tunables, RuntimeTunables};
     use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_constants::{DEFAULT_DEPOSIT_DENOM_NAME, DEFAULT_TRADING_DENOM_NAME};
+    use crate::test::test_ibc::{mock_denom_trace_response, TEST_IBC_DENOM};
+    use crate::test::test_markers::{
+        mock_marker_response, mock_missing_marker_response, test_marker_account,
+    };
+    use crate::types::denom::{Denom, DenomMetadataInput, IbcDenomTrace};
     use crate::types::error::ContractError;
     use crate::types::msg::InstantiateMsg;
-    use crate::util::provenance_utils::msg_bind_name;
-    use cosmwasm_std::testing::{message_info, mock_env, MOCK_CONTRACT_ADDR};
-    use cosmwasm_std::{coins, Addr, AnyMsg, CosmosMsg};
-    use provwasm_mocks::mock_provenance_dependencies;
+    use crate::types::msg_fee::MsgFee;
+    use crate::util::provenance_utils::{msg_bind_name, msg_set_denom_metadata};
+    use cosmwasm_std::testing::{message_info, mock_env, MockApi, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{coin, coins, Addr, AnyMsg, CosmosMsg, Storage, Uint128};
+    use cw2::get_contract_version;
+    use provwasm_mocks::{
+        mock_provenance_dependencies, mock_provenance_dependencies_with_custom_querier,
+        MockProvenanceQuerier,
+    };
+    use provwasm_std::types::provenance::marker::v1::{
+        Access, AccessGrant, MarkerStatus, MarkerType, MsgSetDenomMetadataRequest,
+    };
     use provwasm_std::types::provenance::name::v1::MsgBindNameRequest;
 
     #[test]
@@ -86,6 +198,10 @@ mod tests {
     #[test]
     fn test_successful_instantiate_without_name_bind() {
         let mut deps = mock_provenance_dependencies();
+        mock_marker_response(
+            &mut deps.querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
         let instantiate_msg = InstantiateMsg {
             name_to_bind: None,
             ..InstantiateMsg::default()
@@ -102,19 +218,36 @@ mod tests {
             "no messages should be emitted when a name isn't bound",
         );
         assert_eq!(
-            4,
+            7,
             response.attributes.len(),
-            "expected four attributes to be emitted when no name is bound",
+            "expected seven attributes to be emitted when no name is bound",
         );
         response.assert_attribute("action", "instantiate");
+        response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+        response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
         response.assert_attribute("contract_name", instantiate_msg.contract_name);
         response.assert_attribute("deposit_marker_name", instantiate_msg.deposit_marker.name);
         response.assert_attribute("trading_marker_name", instantiate_msg.trading_marker.name);
+        let cw2_version =
+            get_contract_version(&deps.storage).expect("the cw2 contract version should be stored");
+        assert_eq!(
+            CONTRACT_TYPE, cw2_version.contract,
+            "the cw2 contract name should be the contract type",
+        );
+        assert_eq!(
+            CONTRACT_VERSION, cw2_version.version,
+            "the cw2 version should be the contract version",
+        );
     }
 
     #[test]
     fn test_successful_instantiate_with_name_bind() {
         let mut deps = mock_provenance_dependencies();
+        mock_marker_response(
+            &mut deps.querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
         let mut instantiate_msg = InstantiateMsg {
             name_to_bind: Some("name".to_string()),
             ..InstantiateMsg::default()
@@ -147,14 +280,497 @@ mod tests {
             msg => panic!("unexpected msg format for bind name: {msg:?}"),
         }
         assert_eq!(
-            5,
+            8,
             response.attributes.len(),
-            "expected five attributes to be emitted when a name is bound",
+            "expected eight attributes to be emitted when a name is bound",
         );
         response.assert_attribute("action", "instantiate");
+        response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+        response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
         response.assert_attribute("contract_name", instantiate_msg.contract_name);
         response.assert_attribute("deposit_marker_name", instantiate_msg.deposit_marker.name);
         response.assert_attribute("trading_marker_name", instantiate_msg.trading_marker.name);
         response.assert_attribute("contract_bound_with_name", "name");
     }
+
+    #[test]
+    fn test_successful_instantiate_with_denom_metadata() {
+        let mut deps = mock_provenance_dependencies();
+        mock_marker_response(
+            &mut deps.querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        let metadata = DenomMetadataInput {
+            name: "Trading".to_string(),
+            display: "ttrading".to_string(),
+            symbol: "TRD".to_string(),
+            description: "The trading denom".to_string(),
+            exponent: 6,
+        };
+        let instantiate_msg = InstantiateMsg {
+            name_to_bind: None,
+            publish_trading_denom_metadata: Some(metadata.to_owned()),
+            ..InstantiateMsg::default()
+        };
+        let response = instantiate_contract(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("test-sender"), &[]),
+            instantiate_msg.clone(),
+        )
+        .expect("proper params should cause a successful instantiation");
+        assert_eq!(
+            1,
+            response.messages.len(),
+            "expected a single message to be emitted when metadata is published",
+        );
+        match &response.messages.first().unwrap().msg {
+            CosmosMsg::Any(AnyMsg { type_url: _, value }) => {
+                assert_eq!(
+                    msg_set_denom_metadata(
+                        &instantiate_msg.trading_marker,
+                        &metadata,
+                        MOCK_CONTRACT_ADDR,
+                    ),
+                    MsgSetDenomMetadataRequest::try_from(value.to_owned())
+                        .expect("expected the metadata msg binary to deserialize correctly"),
+                    "expected the correct metadata msg to be deserialized",
+                );
+            }
+            msg => panic!("unexpected msg format for denom metadata: {msg:?}"),
+        }
+        assert_eq!(
+            8,
+            response.attributes.len(),
+            "expected eight attributes to be emitted when metadata is published",
+        );
+        response.assert_attribute("trading_denom_metadata_display", "ttrading");
+    }
+
+    #[test]
+    fn test_instantiate_stores_admin_is_group() {
+        let mut deps = mock_provenance_dependencies();
+        mock_marker_response(
+            &mut deps.querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        instantiate_contract(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("group-policy"), &[]),
+            InstantiateMsg {
+                admin_is_group: true,
+                ..InstantiateMsg::default()
+            },
+        )
+        .expect("proper params should cause a successful instantiation");
+        let contract_state = get_contract_state_v1(deps.as_ref().storage)
+            .expect("contract state should load after instantiation");
+        assert_eq!(
+            "group-policy",
+            contract_state.admin.as_str(),
+            "the instantiating group policy should be the admin",
+        );
+        assert!(
+            contract_state.admin_is_group,
+            "the admin is group value should be stored from the instantiate msg",
+        );
+    }
+
+    #[test]
+    fn test_instantiate_stores_gov_authority() {
+        let mut deps = mock_provenance_dependencies();
+        mock_marker_response(
+            &mut deps.querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        let gov_authority = deps.api.addr_make("gov");
+        instantiate_contract(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("test-sender"), &[]),
+            InstantiateMsg {
+                gov_authority: Some(gov_authority.to_string()),
+                ..InstantiateMsg::default()
+            },
+        )
+        .expect("proper params should cause a successful instantiation");
+        assert_eq!(
+            Some(gov_authority),
+            get_contract_state_v1(deps.as_ref().storage)
+                .expect("contract state should load after instantiation")
+                .gov_authority,
+            "the gov authority should be stored from the instantiate msg",
+        );
+        let mut deps = mock_provenance_dependencies();
+        mock_marker_response(
+            &mut deps.querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        let error = instantiate_contract(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("test-sender"), &[]),
+            InstantiateMsg {
+                gov_authority: Some("not-a-bech32-address".to_string()),
+                ..InstantiateMsg::default()
+            },
+        )
+        .expect_err("an invalid gov authority address should cause an error");
+        assert!(
+            matches!(error, ContractError::Std(_)),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn test_instantiate_stores_skip_balance_precheck() {
+        let mut deps = mock_provenance_dependencies();
+        mock_marker_response(
+            &mut deps.querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        instantiate_contract(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("test-sender"), &[]),
+            InstantiateMsg {
+                skip_balance_precheck: true,
+                ..InstantiateMsg::default()
+            },
+        )
+        .expect("proper params should cause a successful instantiation");
+        assert!(
+            get_contract_state_v1(deps.as_ref().storage)
+                .expect("contract state should load after instantiation")
+                .skip_balance_precheck,
+            "the skip balance precheck value should be stored from the instantiate msg",
+        );
+    }
+
+    #[test]
+    fn test_instantiate_stores_deposit_denom_aliases() {
+        let mut deps = mock_provenance_dependencies();
+        mock_marker_response(
+            &mut deps.querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        instantiate_contract(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("test-sender"), &[]),
+            InstantiateMsg {
+                deposit_denom_aliases: vec!["ibc/ABCDEF".to_string()],
+                ..InstantiateMsg::default()
+            },
+        )
+        .expect("proper params should cause a successful instantiation");
+        assert_eq!(
+            vec!["ibc/ABCDEF".to_string()],
+            get_contract_state_v1(deps.as_ref().storage)
+                .expect("contract state should load after instantiation")
+                .deposit_denom_aliases,
+            "the deposit denom aliases should be stored from the instantiate msg",
+        );
+    }
+
+    #[test]
+    fn test_instantiate_stores_large_trade_threshold() {
+        let mut deps = mock_provenance_dependencies();
+        mock_marker_response(
+            &mut deps.querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        instantiate_contract(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("test-sender"), &[]),
+            InstantiateMsg {
+                large_trade_threshold: Some(Uint128::new(1000)),
+                ..InstantiateMsg::default()
+            },
+        )
+        .expect("proper params should cause a successful instantiation");
+        assert_eq!(
+            Some(Uint128::new(1000)),
+            get_contract_state_v1(deps.as_ref().storage)
+                .expect("contract state should load after instantiation")
+                .large_trade_threshold,
+            "the large trade threshold should be stored from the instantiate msg",
+        );
+    }
+
+    #[test]
+    fn test_instantiate_stores_a_valid_msg_fee() {
+        let mut deps = mock_provenance_dependencies();
+        mock_marker_response(
+            &mut deps.querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        let msg_with_fee = |recipient: &str| InstantiateMsg {
+            msg_fee: Some(MsgFee {
+                amount: coin(100, "nhash"),
+                recipient: recipient.to_string(),
+            }),
+            ..InstantiateMsg::default()
+        };
+        let error = instantiate_contract(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("test-sender"), &[]),
+            msg_with_fee("not-a-bech32-address"),
+        )
+        .expect_err("an invalid fee recipient should cause an error");
+        assert!(
+            matches!(error, ContractError::Std(_)),
+            "unexpected error emitted by an invalid fee recipient: {error:?}",
+        );
+        let recipient = deps.api.addr_make("fee-recipient");
+        instantiate_contract(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("test-sender"), &[]),
+            msg_with_fee(recipient.as_str()),
+        )
+        .expect("proper params should cause a successful instantiation");
+        assert_eq!(
+            msg_with_fee(recipient.as_str()).msg_fee,
+            get_contract_state_v1(deps.as_ref().storage)
+                .expect("contract state should load after instantiation")
+                .msg_fee,
+            "the msg fee should be stored from the instantiate msg",
+        );
+    }
+
+    #[test]
+    fn test_instantiate_stores_default_runtime_tunables() {
+        let mut deps = mock_provenance_dependencies();
+        mock_marker_response(
+            &mut deps.querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        instantiate_contract(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("test-sender"), &[]),
+            InstantiateMsg::default(),
+        )
+        .expect("proper params should cause a successful instantiation");
+        assert!(
+            deps.storage.get(b"runtime_tunables").is_some(),
+            "the runtime tunables should be written to storage on instantiation",
+        );
+        assert_eq!(
+            RuntimeTunables::default(),
+            get_runtime_tunables(&deps.storage).expect("runtime tunables should load"),
+            "the default runtime tunables should be stored",
+        );
+    }
+
+    #[test]
+    fn test_instantiate_stores_instantiation_block_details() {
+        let mut deps = mock_provenance_dependencies();
+        mock_marker_response(
+            &mut deps.querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        let env = mock_env();
+        instantiate_contract(
+            deps.as_mut(),
+            env.clone(),
+            message_info(&Addr::unchecked("test-sender"), &[]),
+            InstantiateMsg::default(),
+        )
+        .expect("proper params should cause a successful instantiation");
+        let contract_state = get_contract_state_v1(deps.as_ref().storage)
+            .expect("contract state should load after instantiation");
+        assert_eq!(
+            Some(env.block.height),
+            contract_state.instantiated_at_height,
+            "the instantiation block height should be stored",
+        );
+        assert_eq!(
+            Some(env.block.time),
+            contract_state.instantiated_at_time,
+            "the instantiation block time should be stored",
+        );
+    }
+
+    #[test]
+    fn test_instantiate_verifies_the_expected_ibc_trace() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        mock_denom_trace_response(&mut querier, "transfer/channel-0", "uusdc");
+        mock_marker_response(
+            &mut querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        let msg_with_trace = |base_denom: &str| InstantiateMsg {
+            deposit_marker: Denom::new(TEST_IBC_DENOM, 6),
+            expected_ibc_trace: Some(IbcDenomTrace {
+                base_denom: base_denom.to_string(),
+                path: "transfer/channel-0".to_string(),
+            }),
+            ..InstantiateMsg::default()
+        };
+        let error = instantiate_contract(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("test-sender"), &[]),
+            msg_with_trace("uatom"),
+        )
+        .expect_err("an error should occur when the deposit denom traces elsewhere");
+        assert!(
+            matches!(error, ContractError::ValidationError { .. }),
+            "unexpected error emitted when the ibc trace differs: {error:?}",
+        );
+        assert!(
+            get_contract_state_v1(deps.as_ref().storage).is_err(),
+            "no contract state should be stored when the ibc trace differs",
+        );
+        instantiate_contract(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("test-sender"), &[]),
+            msg_with_trace("uusdc"),
+        )
+        .expect("a matching ibc trace should allow instantiation");
+        assert_eq!(
+            msg_with_trace("uusdc").expected_ibc_trace,
+            get_contract_state_v1(deps.as_ref().storage)
+                .expect("contract state should load after instantiation")
+                .expected_ibc_trace,
+            "the expected ibc trace should be stored",
+        );
+    }
+
+    #[test]
+    fn test_instantiate_caches_the_trading_marker_address() {
+        let mut deps = mock_provenance_dependencies();
+        mock_marker_response(
+            &mut deps.querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        instantiate_contract(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("test-sender"), &[]),
+            InstantiateMsg::default(),
+        )
+        .expect("proper params should cause a successful instantiation");
+        assert_eq!(
+            Some(MarkerAddressCache {
+                trading_marker_address: MockApi::default().addr_make("marker"),
+                trades_since_verification: 0,
+            }),
+            get_marker_address_cache(&deps.storage).expect("the cache should load"),
+            "the trading marker's address should be cached",
+        );
+    }
+
+    #[test]
+    fn test_instantiate_rejects_a_missing_marker() {
+        let mut deps = mock_provenance_dependencies();
+        mock_missing_marker_response(&mut deps.querier);
+        let error = instantiate_contract(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("test-sender"), &[]),
+            InstantiateMsg::default(),
+        )
+        .expect_err("an error should occur when a marker does not exist");
+        assert_eq!(
+            format!("not found: unable to query marker by name [{DEFAULT_DEPOSIT_DENOM_NAME}]"),
+            error.to_string(),
+            "unexpected error emitted when a marker does not exist",
+        );
+        assert!(
+            get_contract_state_v1(deps.as_ref().storage).is_err(),
+            "no contract state should be stored when a marker does not exist",
+        );
+        let mut deps = mock_provenance_dependencies();
+        let error = instantiate_contract(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("test-sender"), &[]),
+            InstantiateMsg::default(),
+        )
+        .expect_err("an error should occur when the marker module rejects the query");
+        assert!(
+            matches!(error, ContractError::NotFoundError { .. }),
+            "unexpected error emitted when the marker query fails: {error:?}",
+        );
+        assert!(
+            get_marker_address_cache(&deps.storage)
+                .expect("the cache should load")
+                .is_none(),
+            "no marker address should be cached when a marker does not exist",
+        );
+    }
+    #[test]
+    fn test_instantiate_rejects_missing_marker_permissions() {
+        let mut deps = mock_provenance_dependencies();
+        let mut marker = test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0");
+        marker.access_control = vec![AccessGrant {
+            address: MOCK_CONTRACT_ADDR.to_string(),
+            permissions: vec![Access::Transfer as i32],
+        }];
+        mock_marker_response(&mut deps.querier, marker);
+        let error = instantiate_contract(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("test-sender"), &[]),
+            InstantiateMsg::default(),
+        )
+        .expect_err("an error should occur when the contract lacks marker permissions");
+        assert_eq!(
+            format!("not authorized: contract [{MOCK_CONTRACT_ADDR}] is missing permissions [ACCESS_MINT, ACCESS_BURN, ACCESS_WITHDRAW] on marker [{DEFAULT_TRADING_DENOM_NAME}]"),
+            error.to_string(),
+            "unexpected error emitted when the contract lacks marker permissions",
+        );
+        assert!(
+            get_contract_state_v1(deps.as_ref().storage).is_err(),
+            "no contract state should be stored when the contract lacks marker permissions",
+        );
+    }
+    #[test]
+    fn test_instantiate_rejects_markers_that_are_not_restricted_and_active() {
+        let mut deps = mock_provenance_dependencies();
+        let mut marker = test_marker_account(DEFAULT_DEPOSIT_DENOM_NAME, "0");
+        marker.marker_type = MarkerType::Coin as i32;
+        mock_marker_response(&mut deps.querier, marker);
+        let error = instantiate_contract(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("test-sender"), &[]),
+            InstantiateMsg::default(),
+        )
+        .expect_err("an error should occur when a marker is a coin marker");
+        assert_eq!(
+            format!("validation failed: marker [{DEFAULT_DEPOSIT_DENOM_NAME}] has type [MARKER_TYPE_COIN], but must be [MARKER_TYPE_RESTRICTED]"),
+            error.to_string(),
+            "unexpected error emitted when a marker is a coin marker",
+        );
+        let mut deps = mock_provenance_dependencies();
+        let mut marker = test_marker_account(DEFAULT_DEPOSIT_DENOM_NAME, "0");
+        marker.status = MarkerStatus::Proposed as i32;
+        mock_marker_response(&mut deps.querier, marker);
+        let error = instantiate_contract(
+            deps.as_mut(),
+            mock_env(),
+            message_info(&Addr::unchecked("test-sender"), &[]),
+            InstantiateMsg::default(),
+        )
+        .expect_err("an error should occur when a marker is not active");
+        assert_eq!(
+            format!("validation failed: marker [{DEFAULT_DEPOSIT_DENOM_NAME}] has status [MARKER_STATUS_PROPOSED], but must be [MARKER_STATUS_ACTIVE]"),
+            error.to_string(),
+            "unexpected error emitted when a marker is not active",
+        );
+        assert!(
+            get_contract_state_v1(deps.as_ref().storage).is_err(),
+            "no contract state should be stored when a marker cannot back trades",
+        );
+    }
 }
@@ -8,16 +8,27 @@
 //! It allows one-to-one trades for the two markers, accounting for differences in artificially
 //! described precisions for the coin counts in the marker denoms.
 
+/// A typed client that other contracts can use to build messages for, and query, a deployed
+/// instance of this contract.
+pub mod client;
 /// The entrypoint for all external commands sent to the compiled wasm.
+#[cfg(feature = "contract")]
 pub mod contract;
 /// All code and functions pertaining to the execute entrypoint.
+#[cfg(feature = "contract")]
 pub mod execute;
 /// All code and functions pertaining to the instantiate entrypoint.
+#[cfg(feature = "contract")]
 pub mod instantiate;
 /// All code and functions pertaining to the migrate entrypoint.
+#[cfg(feature = "contract")]
 pub mod migrate;
 /// All code and functions pertaining to the query entrypoint.
+#[cfg(feature = "contract")]
 pub mod query;
+/// All code and functions pertaining to the reply entrypoint.
+#[cfg(feature = "contract")]
+pub mod reply;
 /// All code and functions pertaining to interacting with mutable contract data storage.
 pub mod store;
 /// All globally-defined structs used by functions throughout the contract.
@@ -25,5 +36,5 @@ pub mod types;
 /// Utility functions and traits adopted by various aspects of the contract.
 pub mod util;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "contract"))]
 pub mod test;
@@ -2,31 +2,71 @@ use crate::store::contract_state::{
     get_contract_state_v1, set_contract_state_v1, ContractStateV1, CONTRACT_TYPE, CONTRACT_VERSION,
 };
 use crate::types::error::ContractError;
-use cosmwasm_std::{to_json_binary, DepsMut, Response};
+use crate::util::response_utils::base_response;
+use cosmwasm_std::{to_json_binary, DepsMut, Env, Response, Storage, Timestamp};
+use cw2::{set_contract_version, CONTRACT};
 use result_extensions::ResultExtensions;
 use semver::Version;
 
 /// The main entrypoint function for running a code migration.  Auxiliary code run when a stored
 /// instance of this contract on chain is migrated over the existing instance.  Verifies that the
 /// new code instance is a newer version than the current version, and then modifies the contract
-/// state to reflect the new version information contained in the stored file.
+/// state and the standard cw2 contract version to reflect the new version information contained in
+/// the stored file.  Contracts instantiated before the cw2 contract version was recorded have it
+/// written for the first time.
 ///
 /// # Parameters
 /// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
 /// resources like contract internal storage and a querier to retrieve blockchain objects.
-pub fn migrate_contract(deps: DepsMut) -> Result<Response, ContractError> {
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `instantiated_at_height` If provided, backfills the contract state's [instantiated_at_height](ContractStateV1#instantiated_at_height).
+/// Rejected if the contract state already records a value.
+/// * `instantiated_at_time` If provided, backfills the contract state's [instantiated_at_time](ContractStateV1#instantiated_at_time).
+/// Rejected if the contract state already records a value.
+pub fn migrate_contract(
+    deps: DepsMut,
+    env: Env,
+    instantiated_at_height: Option<u64>,
+    instantiated_at_time: Option<Timestamp>,
+) -> Result<Response, ContractError> {
     let mut contract_state = get_contract_state_v1(deps.storage)?;
-    validate_migration(&contract_state)?;
+    validate_migration(deps.storage, &contract_state)?;
     contract_state.contract_version = CONTRACT_VERSION.to_string();
+    if let Some(height) = instantiated_at_height {
+        if contract_state.instantiated_at_height.is_some() {
+            return ContractError::MigrationError {
+                message:
+                    "the instantiation block height is already recorded and cannot be backfilled"
+                        .to_string(),
+            }
+            .to_err();
+        }
+        contract_state.instantiated_at_height = Some(height);
+    }
+    if let Some(time) = instantiated_at_time {
+        if contract_state.instantiated_at_time.is_some() {
+            return ContractError::MigrationError {
+                message:
+                    "the instantiation block time is already recorded and cannot be backfilled"
+                        .to_string(),
+            }
+            .to_err();
+        }
+        contract_state.instantiated_at_time = Some(time);
+    }
     set_contract_state_v1(deps.storage, &contract_state)?;
-    Response::new()
-        .add_attribute("action", "migrate")
+    set_contract_version(deps.storage, CONTRACT_TYPE, CONTRACT_VERSION)?;
+    base_response("migrate", &env, &contract_state)
         .add_attribute("new_version", CONTRACT_VERSION)
         .set_data(to_json_binary(&contract_state)?)
         .to_ok()
 }
 
-fn validate_migration(contract_state: &ContractStateV1) -> Result<(), ContractError> {
+fn validate_migration(
+    storage: &dyn Storage,
+    contract_state: &ContractStateV1,
+) -> Result<(), ContractError> {
     if CONTRACT_TYPE != contract_state.contract_type {
         return ContractError::MigrationError {
             message: format!(
@@ -36,6 +76,24 @@ fn validate_migration(contract_state: &ContractStateV1) -> Result<(), ContractEr
         }
         .to_err();
     }
+    // The cw2 contract version is absent for contracts instantiated before it was recorded, but
+    // must otherwise agree with the contract state
+    if let Some(cw2_version) = CONTRACT.may_load(storage)? {
+        if cw2_version.contract != contract_state.contract_type
+            || cw2_version.version != contract_state.contract_version
+        {
+            return ContractError::MigrationError {
+                message: format!(
+                    "stored cw2 contract version [{} {}] does not match stored contract state version [{} {}]",
+                    cw2_version.contract,
+                    cw2_version.version,
+                    contract_state.contract_type,
+                    contract_state.contract_version,
+                ),
+            }
+            .to_err();
+        }
+    }
     let existing_contract_version = contract_state.contract_version.parse::<Version>()?;
     let new_contract_version = CONTRACT_VERSION.parse::<Version>()?;
     if existing_contract_version >= new_contract_version {
@@ -56,19 +114,20 @@ mod tests {
         get_contract_state_v1, set_contract_state_v1, CONTRACT_TYPE, CONTRACT_VERSION,
     };
     use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_constants::DEFAULT_CONTRACT_NAME;
     use crate::test::test_instantiate::test_instantiate;
+    use crate::test::test_versions::set_stored_contract_version;
     use crate::types::error::ContractError;
+    use cosmwasm_std::testing::{mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{Storage, Timestamp};
+    use cw2::{get_contract_version, set_contract_version};
     use provwasm_mocks::mock_provenance_dependencies;
 
     #[test]
     fn test_successful_migration() {
         let mut deps = mock_provenance_dependencies();
-        test_instantiate(deps.as_mut());
-        let mut contract_state = get_contract_state_v1(deps.as_ref().storage)
-            .expect("contract state should load after instantiation");
-        contract_state.contract_version = "0.0.1".to_string();
-        set_contract_state_v1(deps.as_mut().storage, &contract_state)
-            .expect("contract state should save successfully");
+        test_instantiate(&mut deps);
+        set_stored_contract_version(deps.as_mut().storage, "0.0.1");
         assert_eq!(
             "0.0.1",
             get_contract_state_v1(deps.as_ref().storage)
@@ -76,18 +135,22 @@ mod tests {
                 .contract_version,
             "sanity check: contract version should be successfully updated",
         );
-        let response = migrate_contract(deps.as_mut())
+        let response = migrate_contract(deps.as_mut(), mock_env(), None, None)
             .expect("contract migration should succeed when versions are appropriately set");
         assert!(
             response.messages.is_empty(),
             "migrations should never produce messages",
         );
         assert_eq!(
-            2,
+            6,
             response.attributes.len(),
             "the correct number of attributes should be emitted",
         );
         response.assert_attribute("action", "migrate");
+        response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+        response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
+        response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
         response.assert_attribute("new_version", CONTRACT_VERSION);
         let contract_state = get_contract_state_v1(deps.as_ref().storage)
             .expect("contract state should load after a migration");
@@ -95,18 +158,74 @@ mod tests {
             CONTRACT_VERSION, contract_state.contract_version,
             "the contract state should have its contract version altered by the migration",
         );
+        let cw2_version = get_contract_version(deps.as_ref().storage)
+            .expect("the cw2 contract version should load after a migration");
+        assert_eq!(
+            CONTRACT_VERSION, cw2_version.version,
+            "the cw2 contract version should be bumped by the migration",
+        );
+        assert_eq!(
+            CONTRACT_TYPE, cw2_version.contract,
+            "the cw2 contract name should remain the contract type",
+        );
+    }
+
+    #[test]
+    fn test_migration_records_a_missing_cw2_version() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        set_stored_contract_version(deps.as_mut().storage, "0.0.1");
+        // Simulate a contract instantiated before the cw2 contract version was recorded
+        deps.storage.remove(b"contract_info");
+        migrate_contract(deps.as_mut(), mock_env(), None, None)
+            .expect("a migration should succeed when no cw2 contract version is stored");
+        assert_eq!(
+            CONTRACT_VERSION,
+            get_contract_version(deps.as_ref().storage)
+                .expect("the cw2 contract version should be recorded by the migration")
+                .version,
+            "the cw2 contract version should be recorded by the migration",
+        );
+    }
+
+    #[test]
+    fn test_migration_rejects_a_mismatched_cw2_version() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        set_stored_contract_version(deps.as_mut().storage, "0.0.1");
+        set_contract_version(deps.as_mut().storage, CONTRACT_TYPE, "0.0.2")
+            .expect("the cw2 contract version should save successfully");
+        let err = migrate_contract(deps.as_mut(), mock_env(), None, None)
+            .expect_err("an error should occur when the cw2 version disagrees with the state");
+        match err {
+            ContractError::MigrationError { message } => {
+                assert_eq!(
+                    format!("stored cw2 contract version [{CONTRACT_TYPE} 0.0.2] does not match stored contract state version [{CONTRACT_TYPE} 0.0.1]"),
+                    message,
+                    "unexpected error message when mismatched versions are encountered",
+                );
+            }
+            e => panic!("unexpected error emitted: {:?}", e),
+        };
+        assert_eq!(
+            "0.0.1",
+            get_contract_state_v1(deps.as_ref().storage)
+                .expect("contract state should load after a failed migration")
+                .contract_version,
+            "the contract state should not be migrated when the versions disagree",
+        );
     }
 
     #[test]
     fn test_invalid_migration_scenarios() {
         let mut deps = mock_provenance_dependencies();
-        test_instantiate(deps.as_mut());
+        test_instantiate(&mut deps);
         let mut contract_state = get_contract_state_v1(deps.as_ref().storage)
             .expect("expected contract state to load after instantiation");
         contract_state.contract_type = "unexpected contract type".to_string();
         set_contract_state_v1(deps.as_mut().storage, &contract_state)
             .expect("expected contract state to be stored correctly");
-        let err = migrate_contract(deps.as_mut())
+        let err = migrate_contract(deps.as_mut(), mock_env(), None, None)
             .expect_err("an error should occur when migrating from a different contract type");
         match err {
             ContractError::MigrationError { message } => {
@@ -119,10 +238,10 @@ mod tests {
             e => panic!("unexpected error emitted: {:?}", e),
         };
         contract_state.contract_type = CONTRACT_TYPE.to_string();
-        contract_state.contract_version = "999.999.999".to_string();
         set_contract_state_v1(deps.as_mut().storage, &contract_state)
             .expect("expected contract state to be stored successfully after a modification");
-        let err = migrate_contract(deps.as_mut()).expect_err(
+        set_stored_contract_version(deps.as_mut().storage, "999.999.999");
+        let err = migrate_contract(deps.as_mut(), mock_env(), None, None).expect_err(
             "an error should be produced if the contract is downgraded to a lower version",
         );
         match err {
@@ -136,4 +255,61 @@ mod tests {
             e => panic!("unexpected error emitted: {:?}", e),
         };
     }
+
+    #[test]
+    fn test_migration_backfills_missing_instantiation_details() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        set_stored_contract_version(deps.as_mut().storage, "0.0.1");
+        let mut contract_state = get_contract_state_v1(deps.as_ref().storage)
+            .expect("contract state should load after instantiation");
+        // Simulate a contract instantiated before the instantiation details were recorded
+        contract_state.instantiated_at_height = None;
+        contract_state.instantiated_at_time = None;
+        set_contract_state_v1(deps.as_mut().storage, &contract_state)
+            .expect("contract state should save successfully");
+        migrate_contract(
+            deps.as_mut(),
+            mock_env(),
+            Some(1234),
+            Some(Timestamp::from_seconds(5678)),
+        )
+        .expect("a backfill should succeed when no instantiation details are recorded");
+        let contract_state = get_contract_state_v1(deps.as_ref().storage)
+            .expect("contract state should load after a migration");
+        assert_eq!(
+            Some(1234),
+            contract_state.instantiated_at_height,
+            "the instantiation block height should be backfilled",
+        );
+        assert_eq!(
+            Some(Timestamp::from_seconds(5678)),
+            contract_state.instantiated_at_time,
+            "the instantiation block time should be backfilled",
+        );
+    }
+
+    #[test]
+    fn test_migration_rejects_backfill_of_recorded_instantiation_details() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        set_stored_contract_version(deps.as_mut().storage, "0.0.1");
+        let err = migrate_contract(deps.as_mut(), mock_env(), Some(1234), None)
+            .expect_err("an error should occur when backfilling a recorded block height");
+        assert!(
+            matches!(err, ContractError::MigrationError { .. }),
+            "unexpected error emitted: {err:?}",
+        );
+        let err = migrate_contract(
+            deps.as_mut(),
+            mock_env(),
+            None,
+            Some(Timestamp::from_seconds(5678)),
+        )
+        .expect_err("an error should occur when backfilling a recorded block time");
+        assert!(
+            matches!(err, ContractError::MigrationError { .. }),
+            "unexpected error emitted: {err:?}",
+        );
+    }
 }
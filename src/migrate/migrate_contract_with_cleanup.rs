@@ -0,0 +1,145 @@
+use crate::migrate::migrate_contract::migrate_contract;
+use crate::store::deprecated_storage::{is_deprecated_storage_key, purge_deprecated_storage_key};
+use crate::types::error::ContractError;
+use cosmwasm_std::{DepsMut, Env, Response};
+use result_extensions::ResultExtensions;
+
+/// Runs the standard [migration](crate::migrate::migrate_contract::migrate_contract), and then
+/// removes the given raw storage keys.  Every key must be included in the allowlist of
+/// [deprecated storage keys](crate::store::deprecated_storage::DEPRECATED_STORAGE_KEYS), or the
+/// migration is rejected before any storage is modified.  A `purge_<key>` attribute is emitted for
+/// each key, with the value `removed` if a value was stored and removed, or `absent` if nothing
+/// was stored at the key.
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `purge_keys` The raw storage keys of the deprecated items to remove.
+pub fn migrate_contract_with_cleanup(
+    mut deps: DepsMut,
+    env: Env,
+    purge_keys: Vec<String>,
+) -> Result<Response, ContractError> {
+    if let Some(key) = purge_keys
+        .iter()
+        .find(|key| !is_deprecated_storage_key(key))
+    {
+        return ContractError::MigrationError {
+            message: format!(
+                "storage key [{key}] is not a known deprecated key and cannot be purged"
+            ),
+        }
+        .to_err();
+    }
+    let mut response = migrate_contract(deps.branch(), env, None, None)?;
+    for key in purge_keys {
+        let result = if purge_deprecated_storage_key(deps.storage, &key)? {
+            "removed"
+        } else {
+            "absent"
+        };
+        response = response.add_attribute(format!("purge_{key}"), result);
+    }
+    response.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::migrate::migrate_contract_with_cleanup::migrate_contract_with_cleanup;
+    use crate::store::contract_state::{get_contract_state_v1, CONTRACT_VERSION};
+    use crate::store::deprecated_storage::TEST_DEPRECATED_STORAGE_KEY;
+    use crate::store::runtime_tunables::get_runtime_tunables;
+    use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::test::test_versions::set_stored_contract_version;
+    use crate::types::error::ContractError;
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::Storage;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_cleanup_removes_deprecated_keys_and_preserves_live_state() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        set_stored_contract_version(deps.as_mut().storage, "0.0.1");
+        deps.storage
+            .set(TEST_DEPRECATED_STORAGE_KEY.as_bytes(), b"stale item");
+        let tunables_before = get_runtime_tunables(deps.as_ref().storage)
+            .expect("runtime tunables should load before the migration");
+        let response = migrate_contract_with_cleanup(
+            deps.as_mut(),
+            mock_env(),
+            vec![TEST_DEPRECATED_STORAGE_KEY.to_string()],
+        )
+        .expect("a cleanup of deprecated keys should succeed");
+        assert_eq!(
+            7,
+            response.attributes.len(),
+            "the migration attributes and one attribute per key should be emitted",
+        );
+        response.assert_attribute("action", "migrate");
+        response.assert_attribute("new_version", CONTRACT_VERSION);
+        response.assert_attribute(&format!("purge_{TEST_DEPRECATED_STORAGE_KEY}"), "removed");
+        assert!(
+            deps.storage
+                .get(TEST_DEPRECATED_STORAGE_KEY.as_bytes())
+                .is_none(),
+            "the deprecated item should be removed",
+        );
+        assert_eq!(
+            CONTRACT_VERSION,
+            get_contract_state_v1(deps.as_ref().storage)
+                .expect("the live contract state should load after the migration")
+                .contract_version,
+            "the live contract state should be migrated",
+        );
+        assert_eq!(
+            tunables_before,
+            get_runtime_tunables(deps.as_ref().storage)
+                .expect("runtime tunables should load after the migration"),
+            "the live runtime tunables should be untouched",
+        );
+    }
+
+    #[test]
+    fn test_cleanup_rejects_keys_that_are_not_deprecated() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        set_stored_contract_version(deps.as_mut().storage, "0.0.1");
+        deps.storage
+            .set(TEST_DEPRECATED_STORAGE_KEY.as_bytes(), b"stale item");
+        let error = migrate_contract_with_cleanup(
+            deps.as_mut(),
+            mock_env(),
+            vec![
+                TEST_DEPRECATED_STORAGE_KEY.to_string(),
+                "contract_state_v1".to_string(),
+            ],
+        )
+        .expect_err("a cleanup including a live key should fail");
+        assert_eq!(
+            "migration error occurred: storage key [contract_state_v1] is not a known deprecated key and cannot be purged",
+            error.to_string(),
+            "the error should name the rejected key",
+        );
+        assert!(
+            matches!(error, ContractError::MigrationError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+        assert!(
+            deps.storage
+                .get(TEST_DEPRECATED_STORAGE_KEY.as_bytes())
+                .is_some(),
+            "no key should be purged when any key is rejected",
+        );
+        assert_eq!(
+            "0.0.1",
+            get_contract_state_v1(deps.as_ref().storage)
+                .expect("the live contract state should still load")
+                .contract_version,
+            "the contract should not be migrated when any key is rejected",
+        );
+    }
+}
@@ -3,3 +3,5 @@
 
 /// The main entrypoint function for running a code migration.
 pub mod migrate_contract;
+/// The entrypoint function for running a code migration that also removes deprecated storage keys.
+pub mod migrate_contract_with_cleanup;
@@ -1,4 +1,73 @@
 //! Contains the functionality used in the [contract file](crate::contract) to perform a query.
 
+/// A query that fetches the value that a single account may still trade today.
+pub mod query_account_daily_remaining;
+/// A query that summarizes whether or not an account is able to use the trade routes.
+pub mod query_account_readiness;
+/// A query that fetches the trade statistics of a single account.
+pub mod query_account_stats;
+/// A query that fetches the contract admin and whether or not it is a group policy.
+pub mod query_admin;
+/// A query that fetches the trade statistics of every credited referrer.
+pub mod query_all_referral_stats;
+/// A query that fetches everything a client needs to render the bridge in a single response.
+pub mod query_bootstrap;
+/// A query that fetches the lifetime counters of every executed trade.
+pub mod query_bridge_stats;
+/// A query that fetches the contract's current balances of its deposit and trading denoms.
+pub mod query_contract_balances;
 /// A query that fetches the stored values in the [contract state](crate::store::contract_state::ContractStateV1).
 pub mod query_contract_state;
+/// A query that fetches the custody address that holds the contract's reserves.
+pub mod query_custody_address;
+/// A query that fetches the deposit volume accepted today and the capacity remaining under the
+/// daily cap.
+pub mod query_daily_capacity;
+/// A query that fetches a combined view of the contract's configuration and activity.
+pub mod query_dashboard;
+/// A query that fetches the accounts blocked from trading.
+pub mod query_denylist;
+/// A query that fetches whether or not a single account is blocked from trading.
+pub mod query_denylist_status;
+/// A query that fetches the accounts exempt from trade fees.
+pub mod query_fee_exemptions;
+/// A query that renders an amount of either of the bridge's denoms in its decimal display form.
+pub mod query_format_amount;
+/// A query that fetches the constraints that new code must satisfy to migrate the contract.
+pub mod query_migration_info;
+/// A query that converts an amount of either of the bridge's denoms from its decimal display form
+/// into base units.
+pub mod query_parse_amount;
+/// A query that fetches the address proposed to become the contract admin.
+pub mod query_pending_admin;
+/// A query that fetches the trades awaiting admin approval.
+pub mod query_pending_trades;
+/// A query that simulates a trade execution route and returns the messages and amounts it would produce.
+pub mod query_preview_execute;
+/// A query that produces a quote that can lock a trade execution to its conversion.
+pub mod query_quote;
+/// A query that fetches the record of an executed trade by its receipt number.
+pub mod query_receipt;
+/// A query that fetches the trade statistics credited to a single referrer.
+pub mod query_referral_stats;
+/// A query that lists the marker access grants that the contract lacks, with the messages that add them.
+pub mod query_required_grants;
+/// A query that fetches the helper behaviors that the contract admin can adjust without a code
+/// release.
+pub mod query_runtime_tunables;
+/// A query that converts an amount of deposit denom to trading denom as a fund trade would,
+/// without involving an account.
+pub mod query_simulate_fund_trading;
+/// A query that walks a trade's full execution pipeline for a sender and reports its outcome or
+/// every failure.
+pub mod query_simulate_trade;
+/// A query that converts an amount of trading denom to deposit denom as a withdraw trade would,
+/// without involving an account.
+pub mod query_simulate_withdraw_trading;
+/// A query that suggests the trade amounts nearest to an entered amount that convert without a
+/// remainder.
+pub mod query_suggest_amounts;
+/// A query that fetches a page of the history of executed trades.
+pub mod query_trade_history;
+/// A query that fetches the accounts that may trade without holding the required attributes.
+pub mod query_whitelisted_accounts;
@@ -0,0 +1,138 @@
+use crate::store::account_daily_usage::get_account_daily_usage;
+use crate::store::contract_state::get_contract_state_v1;
+use crate::types::error::ContractError;
+use crate::types::responses::AccountDailyRemainingResponse;
+use cosmwasm_std::{to_json_binary, Binary, Deps, Env};
+use result_extensions::ResultExtensions;
+
+/// Fetches the value that an account has traded during the UTC day containing the current block
+/// time, the allowance remaining under the contract's [per-account daily limit](crate::store::contract_state::ContractStateV1#per_account_daily_limit),
+/// and the time at which that allowance resets.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `account` The bech32 address of the account to inspect.
+pub fn query_account_daily_remaining(
+    deps: Deps,
+    env: Env,
+    account: String,
+) -> Result<Binary, ContractError> {
+    let account = deps.api.addr_validate(&account)?;
+    let contract_state = get_contract_state_v1(deps.storage)?;
+    let usage = get_account_daily_usage(deps.storage, &account, &env.block.time)?;
+    to_json_binary(&AccountDailyRemainingResponse {
+        account,
+        per_account_daily_limit: contract_state.per_account_daily_limit,
+        used: usage.amount,
+        remaining: contract_state
+            .per_account_daily_limit
+            .map(|daily_limit| daily_limit.saturating_sub(usage.amount)),
+        resets_at: usage.resets_at(),
+    })?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_account_daily_remaining::query_account_daily_remaining;
+    use crate::store::account_daily_usage::record_account_daily_usage;
+    use crate::store::trade_stats::{day_of, SECONDS_PER_DAY};
+    use crate::test::test_instantiate::{test_instantiate, test_instantiate_with_msg};
+    use crate::types::msg::InstantiateMsg;
+    use crate::types::responses::AccountDailyRemainingResponse;
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_json, Timestamp, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_query_invalid_account() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        query_account_daily_remaining(deps.as_ref(), mock_env(), "not-an-address".to_string())
+            .expect_err("an invalid account address should be rejected");
+    }
+
+    #[test]
+    fn test_query_without_a_daily_limit() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let account = deps.api.addr_make("account");
+        let env = mock_env();
+        let response = from_json::<AccountDailyRemainingResponse>(
+            &query_account_daily_remaining(deps.as_ref(), env.to_owned(), account.to_string())
+                .expect("the query should succeed"),
+        )
+        .expect("the response binary should properly deserialize");
+        assert_eq!(
+            AccountDailyRemainingResponse {
+                account,
+                per_account_daily_limit: None,
+                used: Uint128::zero(),
+                remaining: None,
+                resets_at: Timestamp::from_seconds((day_of(&env.block.time) + 1) * SECONDS_PER_DAY),
+            },
+            response,
+            "no remaining allowance should be reported when no limit is configured",
+        );
+    }
+
+    #[test]
+    fn test_query_remaining_allowance() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                per_account_daily_limit: Some(Uint128::new(500)),
+                ..InstantiateMsg::default()
+            },
+        );
+        let account = deps.api.addr_make("account");
+        let mut env = mock_env();
+        record_account_daily_usage(
+            &mut deps.storage,
+            &account,
+            &env.block.time,
+            Some(Uint128::new(500)),
+            Uint128::new(200),
+            "deposit",
+        )
+        .expect("recording usage should succeed");
+        let response = from_json::<AccountDailyRemainingResponse>(
+            &query_account_daily_remaining(deps.as_ref(), env.to_owned(), account.to_string())
+                .expect("the query should succeed"),
+        )
+        .expect("the response binary should properly deserialize");
+        let resets_at = Timestamp::from_seconds((day_of(&env.block.time) + 1) * SECONDS_PER_DAY);
+        assert_eq!(
+            AccountDailyRemainingResponse {
+                account: account.to_owned(),
+                per_account_daily_limit: Some(Uint128::new(500)),
+                used: Uint128::new(200),
+                remaining: Some(Uint128::new(300)),
+                resets_at,
+            },
+            response,
+            "the remaining allowance should deduct the day's usage from the limit",
+        );
+        env.block.time = resets_at;
+        let response = from_json::<AccountDailyRemainingResponse>(
+            &query_account_daily_remaining(deps.as_ref(), env, account.to_string())
+                .expect("the query should succeed"),
+        )
+        .expect("the response binary should properly deserialize");
+        assert_eq!(
+            (Uint128::zero(), Some(Uint128::new(500))),
+            (response.used, response.remaining),
+            "the full allowance should be available once the day rolls over",
+        );
+        assert_eq!(
+            resets_at.plus_seconds(SECONDS_PER_DAY),
+            response.resets_at,
+            "the next reset should fall at the end of the new day",
+        );
+    }
+}
@@ -0,0 +1,343 @@
+use crate::store::attribute_whitelist::is_whitelisted_account;
+use crate::store::contract_state::get_contract_state_v1;
+use crate::store::runtime_tunables::{get_runtime_tunables, RuntimeTunables};
+use crate::types::denom::Denom;
+use crate::types::error::ContractError;
+use crate::types::responses::AccountReadinessResponse;
+use crate::util::conversion_utils::convert_denom;
+use crate::util::provenance_utils::{
+    check_account_has_all_attributes, check_account_owns_scope_with_spec, get_account_balance,
+};
+use cosmwasm_std::{coin, to_json_binary, Binary, Coin, Deps, Uint128};
+use result_extensions::ResultExtensions;
+
+/// Fetches a summary of whether or not the target account is able to use the trade routes, and how
+/// much it is able to trade.  Sections that cannot be determined are omitted from the response
+/// rather than causing the query to fail.  Accounts on the [attribute whitelist](crate::store::attribute_whitelist)
+/// always meet the required attributes and own the required scope.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `account` The bech32 address of the account to inspect.
+pub fn query_account_readiness(deps: Deps, account: String) -> Result<Binary, ContractError> {
+    let account = deps.api.addr_validate(&account)?;
+    let contract_state = get_contract_state_v1(deps.storage)?;
+    let tunables = get_runtime_tunables(deps.storage)?;
+    let whitelisted = is_whitelisted_account(deps.storage, &account)?;
+    let deposit_balance = get_account_balance(&deps, &account, &contract_state.deposit_marker.name)
+        .ok()
+        .map(|amount| coin(amount.u128(), &contract_state.deposit_marker.name));
+    let trading_balance = get_account_balance(&deps, &account, &contract_state.trading_marker.name)
+        .ok()
+        .map(|amount| coin(amount.u128(), &contract_state.trading_marker.name));
+    to_json_binary(&AccountReadinessResponse {
+        account: account.to_string(),
+        meets_deposit_attributes: meets_attributes(
+            &deps,
+            account.as_str(),
+            whitelisted,
+            &contract_state.required_deposit_attributes,
+            &tunables,
+        ),
+        meets_withdraw_attributes: meets_attributes(
+            &deps,
+            account.as_str(),
+            whitelisted,
+            &contract_state.required_withdraw_attributes,
+            &tunables,
+        ),
+        owns_required_scope: owns_required_scope(
+            &deps,
+            account.as_str(),
+            whitelisted,
+            &contract_state.required_scope_spec,
+        ),
+        max_fund_amount: max_convertible_amount(
+            &deposit_balance,
+            &contract_state.deposit_marker,
+            &contract_state.trading_marker,
+        ),
+        max_withdraw_amount: max_convertible_amount(
+            &trading_balance,
+            &contract_state.trading_marker,
+            &contract_state.deposit_marker,
+        ),
+        deposit_balance,
+        trading_balance,
+    })?
+    .to_ok()
+}
+
+fn meets_attributes(
+    deps: &Deps,
+    account: &str,
+    whitelisted: bool,
+    attributes: &[String],
+    tunables: &RuntimeTunables,
+) -> Option<bool> {
+    if whitelisted {
+        return Some(true);
+    }
+    match check_account_has_all_attributes(deps, account, attributes, tunables) {
+        Ok(_) => Some(true),
+        Err(ContractError::InvalidAccountError { .. }) => Some(false),
+        // Any other error indicates that the attributes could not be fetched
+        Err(_) => None,
+    }
+}
+
+fn owns_required_scope(
+    deps: &Deps,
+    account: &str,
+    whitelisted: bool,
+    scope_spec: &Option<String>,
+) -> Option<bool> {
+    let Some(scope_spec) = scope_spec.as_ref().filter(|_| !whitelisted) else {
+        return Some(true);
+    };
+    match check_account_owns_scope_with_spec(deps, account, scope_spec) {
+        Ok(_) => Some(true),
+        Err(ContractError::InvalidAccountError { .. }) => Some(false),
+        // Any other error indicates that the scopes could not be fetched
+        Err(_) => None,
+    }
+}
+
+fn max_convertible_amount(
+    balance: &Option<Coin>,
+    source_denom: &Denom,
+    target_denom: &Denom,
+) -> Option<Uint128> {
+    let balance = balance.as_ref()?.amount;
+    let conversion = convert_denom(balance, source_denom, target_denom).ok()?;
+    if conversion.target_amount.is_zero() {
+        Some(Uint128::zero())
+    } else {
+        balance.checked_sub(conversion.remainder).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_account_readiness::query_account_readiness;
+    use crate::store::attribute_whitelist::add_whitelisted_account;
+    use crate::store::fee_exemptions::add_fee_exemption;
+    use crate::test::test_constants::{
+        DEFAULT_DEPOSIT_DENOM_NAME, DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE, DEFAULT_TRADING_DENOM_NAME,
+    };
+    use crate::test::test_instantiate::{test_instantiate, test_instantiate_with_msg};
+    use crate::test::test_metadata::{mock_scope_ownership_response, TEST_SCOPE_SPEC};
+    use crate::types::msg::InstantiateMsg;
+    use crate::types::responses::AccountReadinessResponse;
+    use cosmwasm_std::{coin, from_json, Uint128};
+    use provwasm_mocks::{
+        mock_provenance_dependencies, mock_provenance_dependencies_with_custom_querier,
+        MockProvenanceQuerier,
+    };
+    use provwasm_std::types::cosmos::bank::v1beta1::{QueryBalanceRequest, QueryBalanceResponse};
+    use provwasm_std::types::cosmos::base::v1beta1::Coin;
+    use provwasm_std::types::provenance::attribute::v1::{
+        Attribute, AttributeType, QueryAttributesRequest, QueryAttributesResponse,
+    };
+
+    #[test]
+    fn test_query_with_no_storage() {
+        let deps = mock_provenance_dependencies();
+        let account = deps.api.addr_make("account");
+        query_account_readiness(deps.as_ref(), account.to_string())
+            .expect_err("an error should occur when no contract state has been initialized");
+    }
+
+    #[test]
+    fn test_query_with_ready_account() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(
+            &mut querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    amount: "103".to_string(),
+                    denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+                }),
+            },
+        );
+        QueryAttributesRequest::mock_response(
+            &mut querier,
+            QueryAttributesResponse {
+                account: "account".to_string(),
+                attributes: vec![Attribute {
+                    name: DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE.to_string(),
+                    value: vec![],
+                    attribute_type: AttributeType::String as i32,
+                    address: "addr".to_string(),
+                    expiration_date: None,
+                }],
+                pagination: None,
+            },
+        );
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        test_instantiate(&mut deps);
+        let account = deps.api.addr_make("account");
+        let readiness = from_json::<AccountReadinessResponse>(
+            &query_account_readiness(deps.as_ref(), account.to_string())
+                .expect("the readiness query should succeed"),
+        )
+        .expect("the readiness binary should properly deserialize");
+        assert_eq!(
+            account.as_str(),
+            readiness.account,
+            "the queried account should be reflected in the response",
+        );
+        // The mock querier returns the same balance response regardless of the requested denom
+        assert_eq!(
+            Some(coin(103, DEFAULT_DEPOSIT_DENOM_NAME)),
+            readiness.deposit_balance,
+            "the deposit balance should be reported",
+        );
+        assert_eq!(
+            Some(coin(103, DEFAULT_TRADING_DENOM_NAME)),
+            readiness.trading_balance,
+            "the trading balance should be reported",
+        );
+        assert_eq!(
+            Some(true),
+            readiness.meets_deposit_attributes,
+            "the account holds the required deposit attribute",
+        );
+        assert_eq!(
+            Some(false),
+            readiness.meets_withdraw_attributes,
+            "the account does not hold the required withdraw attribute",
+        );
+        assert_eq!(
+            Some(true),
+            readiness.owns_required_scope,
+            "no scope should be needed when no scope specification is required",
+        );
+        assert_eq!(
+            Some(Uint128::new(103)),
+            readiness.max_fund_amount,
+            "the full deposit balance converts cleanly to the more precise trading denom",
+        );
+        assert_eq!(
+            Some(Uint128::zero()),
+            readiness.max_withdraw_amount,
+            "the trading balance is too small to convert to any deposit denom",
+        );
+    }
+
+    #[test]
+    fn test_query_reports_required_scope_ownership() {
+        for (owned_scope_spec, expected_ownership) in [
+            (TEST_SCOPE_SPEC, Some(true)),
+            ("scopespec1qjpreurq8n7ql8aumrg2f6qfm3j0ns5rmt", Some(false)),
+        ] {
+            let mut querier = MockProvenanceQuerier::new(&[]);
+            mock_scope_ownership_response(&mut querier, &["scope-uuid"], owned_scope_spec);
+            let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+            test_instantiate_with_msg(
+                &mut deps,
+                InstantiateMsg {
+                    required_scope_spec: Some(TEST_SCOPE_SPEC.to_string()),
+                    ..InstantiateMsg::default()
+                },
+            );
+            let account = deps.api.addr_make("account");
+            let readiness = from_json::<AccountReadinessResponse>(
+                &query_account_readiness(deps.as_ref(), account.to_string())
+                    .expect("the readiness query should succeed"),
+            )
+            .expect("the readiness binary should properly deserialize");
+            assert_eq!(
+                expected_ownership, readiness.owns_required_scope,
+                "the ownership of a scope with the required specification should be reported",
+            );
+        }
+    }
+
+    #[test]
+    fn test_query_reports_only_whitelisted_accounts_as_exempt_from_the_required_scope() {
+        for whitelisted in [true, false] {
+            let mut querier = MockProvenanceQuerier::new(&[]);
+            mock_scope_ownership_response(&mut querier, &[], TEST_SCOPE_SPEC);
+            let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+            test_instantiate_with_msg(
+                &mut deps,
+                InstantiateMsg {
+                    required_scope_spec: Some(TEST_SCOPE_SPEC.to_string()),
+                    ..InstantiateMsg::default()
+                },
+            );
+            let account = deps.api.addr_make("account");
+            if whitelisted {
+                add_whitelisted_account(&mut deps.storage, &account)
+                    .expect("whitelisting the account should succeed");
+            } else {
+                add_fee_exemption(&mut deps.storage, &account)
+                    .expect("exempting the account should succeed");
+            }
+            let readiness = from_json::<AccountReadinessResponse>(
+                &query_account_readiness(deps.as_ref(), account.to_string())
+                    .expect("the readiness query should succeed"),
+            )
+            .expect("the readiness binary should properly deserialize");
+            assert_eq!(
+                Some(whitelisted),
+                readiness.owns_required_scope,
+                "only a whitelisted account should be exempt from the required scope specification",
+            );
+        }
+    }
+
+    #[test]
+    fn test_query_reports_whitelisted_accounts_as_meeting_attributes() {
+        let mut deps =
+            mock_provenance_dependencies_with_custom_querier(MockProvenanceQuerier::new(&[]));
+        test_instantiate(&mut deps);
+        let account = deps.api.addr_make("account");
+        add_whitelisted_account(&mut deps.storage, &account)
+            .expect("whitelisting the account should succeed");
+        let readiness = from_json::<AccountReadinessResponse>(
+            &query_account_readiness(deps.as_ref(), account.to_string())
+                .expect("the readiness query should succeed"),
+        )
+        .expect("the readiness binary should properly deserialize");
+        assert_eq!(
+            (Some(true), Some(true)),
+            (
+                readiness.meets_deposit_attributes,
+                readiness.meets_withdraw_attributes,
+            ),
+            "a whitelisted account should meet the required attributes without an attribute lookup",
+        );
+    }
+
+    #[test]
+    fn test_query_omits_sections_that_cannot_be_determined() {
+        let mut deps =
+            mock_provenance_dependencies_with_custom_querier(MockProvenanceQuerier::new(&[]));
+        test_instantiate(&mut deps);
+        let account = deps.api.addr_make("account");
+        let readiness = from_json::<AccountReadinessResponse>(
+            &query_account_readiness(deps.as_ref(), account.to_string())
+                .expect("the readiness query should succeed when no lookups succeed"),
+        )
+        .expect("the readiness binary should properly deserialize");
+        assert_eq!(
+            AccountReadinessResponse {
+                account: account.to_string(),
+                deposit_balance: None,
+                trading_balance: None,
+                meets_deposit_attributes: None,
+                meets_withdraw_attributes: None,
+                // No lookup is needed because no scope specification is required
+                owns_required_scope: Some(true),
+                max_fund_amount: None,
+                max_withdraw_amount: None,
+            },
+            readiness,
+            "every section should be omitted when its lookup fails",
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use crate::store::account_stats::get_account_stats;
+use crate::types::error::ContractError;
+use crate::types::responses::AccountStatsResponse;
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+/// Fetches the [trade stats](crate::store::account_stats::AccountStats) of an account.  Accounts
+/// that have never traded receive empty stats.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `account` The bech32 address of the account to inspect.
+pub fn query_account_stats(deps: Deps, account: String) -> Result<Binary, ContractError> {
+    let account = deps.api.addr_validate(&account)?;
+    let stats = get_account_stats(deps.storage, &account)?;
+    to_json_binary(&AccountStatsResponse { account, stats })?.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_account_stats::query_account_stats;
+    use crate::store::account_stats::{record_account_trade, AccountStats};
+    use crate::types::quote::TradeDirection;
+    use crate::types::responses::AccountStatsResponse;
+    use cosmwasm_std::{from_json, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_query_invalid_account() {
+        let deps = mock_provenance_dependencies();
+        query_account_stats(deps.as_ref(), "not-an-address".to_string())
+            .expect_err("an invalid account address should be rejected");
+    }
+
+    #[test]
+    fn test_query_account_stats() {
+        let mut deps = mock_provenance_dependencies();
+        let account = deps.api.addr_make("account");
+        let response = from_json::<AccountStatsResponse>(
+            &query_account_stats(deps.as_ref(), account.to_string())
+                .expect("the query should succeed for an account without stats"),
+        )
+        .expect("the response binary should properly deserialize");
+        assert_eq!(
+            AccountStats::default(),
+            response.stats,
+            "an account that never traded should have empty stats",
+        );
+        record_account_trade(
+            &mut deps.storage,
+            &account,
+            TradeDirection::Withdraw,
+            Uint128::new(250),
+            42,
+        )
+        .expect("recording a trade should succeed");
+        let response = from_json::<AccountStatsResponse>(
+            &query_account_stats(deps.as_ref(), account.to_string())
+                .expect("the query should succeed"),
+        )
+        .expect("the response binary should properly deserialize");
+        assert_eq!(
+            AccountStatsResponse {
+                account,
+                stats: AccountStats {
+                    withdraw_count: 1,
+                    total_withdrawn: Uint128::new(250),
+                    last_trade_height: 42,
+                    ..AccountStats::default()
+                },
+            },
+            response,
+            "the recorded stats should be returned",
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use crate::store::contract_state::get_contract_state_v1;
+use crate::types::error::ContractError;
+use crate::types::responses::AdminResponse;
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+/// Fetches the contract admin from the [contract state](crate::store::contract_state::ContractStateV1),
+/// along with whether or not it is the policy address of a group and the governance authority that
+/// may act in its place.  Clients can use this to decide
+/// whether an admin action should be sent directly or submitted as a group proposal.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+pub fn query_admin(deps: Deps) -> Result<Binary, ContractError> {
+    let contract_state = get_contract_state_v1(deps.storage)?;
+    to_json_binary(&AdminResponse {
+        admin: contract_state.admin,
+        admin_is_group: contract_state.admin_is_group,
+        gov_authority: contract_state.gov_authority,
+    })?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_admin::query_admin;
+    use crate::store::contract_state::{get_contract_state_v1, set_contract_state_v1};
+    use crate::test::test_constants::DEFAULT_ADMIN;
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::responses::AdminResponse;
+    use cosmwasm_std::from_json;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_query_with_no_storage() {
+        let deps = mock_provenance_dependencies();
+        query_admin(deps.as_ref())
+            .expect_err("an error should occur when no contract state has been initialized");
+    }
+
+    #[test]
+    fn test_query_reports_the_group_flag() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let response = from_json::<AdminResponse>(
+            &query_admin(deps.as_ref()).expect("the admin query should succeed"),
+        )
+        .expect("the admin response should deserialize");
+        assert_eq!(
+            DEFAULT_ADMIN,
+            response.admin.as_str(),
+            "the instantiating account should be the admin",
+        );
+        assert!(
+            !response.admin_is_group,
+            "the admin should not be a group by default",
+        );
+        assert!(
+            response.gov_authority.is_none(),
+            "no gov authority should be reported by default",
+        );
+        let mut contract_state = get_contract_state_v1(&deps.storage)
+            .expect("contract state should load after instantiation");
+        contract_state.admin_is_group = true;
+        set_contract_state_v1(&mut deps.storage, &contract_state)
+            .expect("contract state should be stored");
+        let response = from_json::<AdminResponse>(
+            &query_admin(deps.as_ref()).expect("the admin query should succeed"),
+        )
+        .expect("the admin response should deserialize");
+        assert!(
+            response.admin_is_group,
+            "the group flag should be reported once set",
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use crate::store::referral_stats::get_all_referral_stats;
+use crate::types::error::ContractError;
+use crate::types::responses::{ReferralStatsListResponse, ReferralStatsResponse};
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+/// The amount of referrers returned when the query does not specify a limit.
+pub const DEFAULT_REFERRAL_STATS_LIMIT: u32 = 10;
+/// The largest amount of referrers returned by a single query.
+pub const MAX_REFERRAL_STATS_LIMIT: u32 = 30;
+
+/// Fetches a page of the [referral stats](crate::store::referral_stats::ReferralStats) of every
+/// credited referrer, ordered by address.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `start_after` If provided, the bech32 address after which referrers are returned.
+/// * `limit` The maximum amount of referrers to return.  Defaults to
+/// [DEFAULT_REFERRAL_STATS_LIMIT] and is capped at [MAX_REFERRAL_STATS_LIMIT].
+pub fn query_all_referral_stats(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Binary, ContractError> {
+    let start_after = start_after
+        .map(|start_after| deps.api.addr_validate(&start_after))
+        .transpose()?;
+    let limit = limit
+        .unwrap_or(DEFAULT_REFERRAL_STATS_LIMIT)
+        .min(MAX_REFERRAL_STATS_LIMIT);
+    let referral_stats =
+        get_all_referral_stats(deps.storage, start_after.as_ref(), limit as usize)?
+            .into_iter()
+            .map(|(referrer, stats)| ReferralStatsResponse { referrer, stats })
+            .collect();
+    to_json_binary(&ReferralStatsListResponse { referral_stats })?.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_all_referral_stats::{
+        query_all_referral_stats, MAX_REFERRAL_STATS_LIMIT,
+    };
+    use crate::store::referral_stats::record_referral;
+    use crate::types::responses::ReferralStatsListResponse;
+    use cosmwasm_std::{from_json, Addr, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_query_pages_referrers_in_order() {
+        let mut deps = mock_provenance_dependencies();
+        let mut referrers = ["first", "second", "third"]
+            .into_iter()
+            .map(|name| deps.api.addr_make(name))
+            .collect::<Vec<_>>();
+        referrers.sort();
+        for referrer in &referrers {
+            record_referral(&mut deps.storage, referrer, Uint128::new(100))
+                .expect("recording a referral should succeed");
+        }
+        let page = |start_after: Option<&Addr>, limit| {
+            from_json::<ReferralStatsListResponse>(
+                &query_all_referral_stats(
+                    deps.as_ref(),
+                    start_after.map(|addr| addr.to_string()),
+                    limit,
+                )
+                .expect("the query should succeed"),
+            )
+            .expect("the response binary should properly deserialize")
+            .referral_stats
+            .into_iter()
+            .map(|stats| stats.referrer)
+            .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            referrers,
+            page(None, None),
+            "every referrer should be returned in order",
+        );
+        assert_eq!(
+            referrers[1..2].to_vec(),
+            page(Some(&referrers[0]), Some(1)),
+            "only the requested page should be returned",
+        );
+    }
+
+    #[test]
+    fn test_query_caps_the_limit() {
+        let mut deps = mock_provenance_dependencies();
+        for index in 0..MAX_REFERRAL_STATS_LIMIT + 5 {
+            record_referral(
+                &mut deps.storage,
+                &deps.api.addr_make(&format!("referrer{index}")),
+                Uint128::new(1),
+            )
+            .expect("recording a referral should succeed");
+        }
+        let response = from_json::<ReferralStatsListResponse>(
+            &query_all_referral_stats(deps.as_ref(), None, Some(u32::MAX))
+                .expect("the query should succeed"),
+        )
+        .expect("the response binary should properly deserialize");
+        assert_eq!(
+            MAX_REFERRAL_STATS_LIMIT as usize,
+            response.referral_stats.len(),
+            "the limit should be capped",
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use crate::query::query_contract_balances::get_contract_balances;
+use crate::store::contract_state::{get_contract_state_v1, CONTRACT_VERSION};
+use crate::store::runtime_tunables::get_runtime_tunables;
+use crate::types::error::ContractError;
+use crate::types::responses::{BootstrapResponse, TradingPairResponse};
+use crate::util::diagnostics_utils::run_diagnostics;
+use cosmwasm_std::{to_json_binary, Binary, Deps, Env};
+use result_extensions::ResultExtensions;
+
+/// Fetches every section of the [BootstrapResponse] in a single query, reusing the same lookups as
+/// the individual query routes and the [diagnostics](crate::util::diagnostics_utils::run_diagnostics)
+/// that back the contract's operational status.  The query fails if the contract state cannot be
+/// loaded, but omits the contract balances when they cannot be loaded.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+pub fn query_bootstrap(deps: Deps, env: Env) -> Result<Binary, ContractError> {
+    let contract_state = get_contract_state_v1(deps.storage)?;
+    let status_checks = run_diagnostics(&deps, &env, &contract_state);
+    to_json_binary(&BootstrapResponse {
+        contract_version: CONTRACT_VERSION.to_string(),
+        trading_pairs: vec![TradingPairResponse {
+            deposit_marker: contract_state.deposit_marker.to_owned(),
+            trading_marker: contract_state.trading_marker.to_owned(),
+        }],
+        contract_balances: get_contract_balances(&deps, &env).ok(),
+        operational: status_checks.iter().all(|check| check.passed),
+        trading_open: contract_state.check_trading_schedule(&env.block).is_ok(),
+        minting_disabled: contract_state.minting_disabled,
+        tunables: get_runtime_tunables(deps.storage)?,
+        status_checks,
+        contract_state,
+    })?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_bootstrap::query_bootstrap;
+    use crate::store::contract_state::{get_contract_state_v1, CONTRACT_VERSION};
+    use crate::store::runtime_tunables::RuntimeTunables;
+    use crate::test::test_constants::{DEFAULT_DEPOSIT_DENOM_NAME, DEFAULT_TRADING_DENOM_NAME};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::test::test_markers::mock_missing_marker_response;
+    use crate::types::error::ContractError;
+    use crate::types::responses::{BootstrapResponse, TradingPairResponse};
+    use crate::util::diagnostics_utils::run_diagnostics;
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{coin, from_json};
+    use provwasm_mocks::{
+        mock_provenance_dependencies, mock_provenance_dependencies_with_custom_querier,
+        MockProvenanceQuerier,
+    };
+    use provwasm_std::types::cosmos::bank::v1beta1::{
+        QueryBalanceRequest, QueryBalanceResponse, QuerySupplyOfRequest, QuerySupplyOfResponse,
+    };
+
+    #[test]
+    fn test_query_with_no_storage() {
+        let deps = mock_provenance_dependencies();
+        let error = query_bootstrap(deps.as_ref(), mock_env())
+            .expect_err("an error should occur when no contract state exists");
+        assert!(
+            matches!(error, ContractError::StorageError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn test_query_with_stored_state() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(&mut querier, QueryBalanceResponse { balance: None });
+        QuerySupplyOfRequest::mock_response(&mut querier, QuerySupplyOfResponse { amount: None });
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        test_instantiate(&mut deps);
+        mock_missing_marker_response(&mut deps.querier);
+        let bootstrap = from_json::<BootstrapResponse>(
+            &query_bootstrap(deps.as_ref(), mock_env()).expect("the bootstrap should load"),
+        )
+        .expect("the bootstrap binary should properly deserialize");
+        let contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        assert_eq!(
+            CONTRACT_VERSION, bootstrap.contract_version,
+            "the running contract version should be included",
+        );
+        assert_eq!(
+            contract_state, bootstrap.contract_state,
+            "the contract state section should equate to the contract state query",
+        );
+        assert_eq!(
+            RuntimeTunables::default(),
+            bootstrap.tunables,
+            "the tunables section should include the stored tunables",
+        );
+        assert_eq!(
+            vec![TradingPairResponse {
+                deposit_marker: contract_state.deposit_marker.to_owned(),
+                trading_marker: contract_state.trading_marker.to_owned(),
+            }],
+            bootstrap.trading_pairs,
+            "the contract's single trading pair should be included",
+        );
+        let balances = bootstrap
+            .contract_balances
+            .expect("the contract balances section should be populated");
+        assert_eq!(
+            coin(0, DEFAULT_DEPOSIT_DENOM_NAME),
+            balances.deposit_balance,
+            "the deposit balance should be included",
+        );
+        assert_eq!(
+            coin(0, DEFAULT_TRADING_DENOM_NAME),
+            balances.trading_balance,
+            "the trading balance should be included",
+        );
+        let status_checks = run_diagnostics(&deps.as_ref(), &mock_env(), &contract_state);
+        assert_eq!(
+            status_checks, bootstrap.status_checks,
+            "the status checks should equate to the contract's diagnostics",
+        );
+        assert!(
+            !bootstrap.operational,
+            "the contract should not be operational when its markers cannot be found",
+        );
+        assert!(
+            bootstrap.trading_open,
+            "trading should be open without a schedule",
+        );
+        assert!(
+            !bootstrap.minting_disabled,
+            "minting should not be disabled by default",
+        );
+    }
+}
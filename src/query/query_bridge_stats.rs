@@ -0,0 +1,63 @@
+use crate::store::bridge_stats::get_bridge_stats;
+use crate::types::error::ContractError;
+use crate::types::responses::BridgeStatsResponse;
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+/// Fetches the lifetime [bridge stats](crate::store::bridge_stats::BridgeStats) of every executed
+/// trade.  Empty stats are returned before any trade has executed.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+pub fn query_bridge_stats(deps: Deps) -> Result<Binary, ContractError> {
+    let bridge_stats = get_bridge_stats(deps.storage)?;
+    to_json_binary(&BridgeStatsResponse { bridge_stats })?.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_bridge_stats::query_bridge_stats;
+    use crate::store::bridge_stats::{record_bridge_trade, BridgeStats};
+    use crate::types::quote::TradeDirection;
+    use crate::types::responses::BridgeStatsResponse;
+    use cosmwasm_std::{from_json, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_query_bridge_stats() {
+        let mut deps = mock_provenance_dependencies();
+        let response = from_json::<BridgeStatsResponse>(
+            &query_bridge_stats(deps.as_ref())
+                .expect("the query should succeed before any trade executes"),
+        )
+        .expect("the response binary should properly deserialize");
+        assert_eq!(
+            BridgeStats::default(),
+            response.bridge_stats,
+            "empty stats should be returned before any trade executes",
+        );
+        record_bridge_trade(
+            &mut deps.storage,
+            TradeDirection::Fund,
+            Uint128::new(100),
+            Uint128::new(1_000_000),
+        )
+        .expect("recording a trade should succeed");
+        let response = from_json::<BridgeStatsResponse>(
+            &query_bridge_stats(deps.as_ref()).expect("the query should succeed"),
+        )
+        .expect("the response binary should properly deserialize");
+        assert_eq!(
+            BridgeStats {
+                total_deposit_accepted: Uint128::new(100),
+                total_trading_minted: Uint128::new(1_000_000),
+                fund_count: 1,
+                ..BridgeStats::default()
+            },
+            response.bridge_stats,
+            "the recorded stats should be returned",
+        );
+    }
+}
@@ -0,0 +1,150 @@
+use crate::store::contract_state::get_contract_state_v1;
+use crate::types::error::ContractError;
+use crate::types::responses::ContractBalancesResponse;
+use crate::util::provenance_utils::{get_account_balance, get_denom_supply};
+use cosmwasm_std::{coin, to_json_binary, Binary, Deps, Env};
+use result_extensions::ResultExtensions;
+
+/// Fetches the contract address's current balances of the deposit and trading denoms, along with
+/// the total supply of the trading denom.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+pub fn query_contract_balances(deps: Deps, env: Env) -> Result<Binary, ContractError> {
+    to_json_binary(&get_contract_balances(&deps, &env)?)?.to_ok()
+}
+
+/// Derives the [ContractBalancesResponse] used by the [query_contract_balances] route.  Denoms
+/// that the contract does not hold, or that have no supply, are reported with a zero amount.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+pub fn get_contract_balances(
+    deps: &Deps,
+    env: &Env,
+) -> Result<ContractBalancesResponse, ContractError> {
+    let contract_state = get_contract_state_v1(deps.storage)?;
+    let contract_address = env.contract.address.as_str();
+    let deposit_denom = &contract_state.deposit_marker.name;
+    let trading_denom = &contract_state.trading_marker.name;
+    ContractBalancesResponse {
+        deposit_balance: coin(
+            get_account_balance(deps, contract_address, deposit_denom)?.u128(),
+            deposit_denom,
+        ),
+        trading_balance: coin(
+            get_account_balance(deps, contract_address, trading_denom)?.u128(),
+            trading_denom,
+        ),
+        trading_supply: coin(get_denom_supply(deps, trading_denom)?.u128(), trading_denom),
+    }
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_contract_balances::query_contract_balances;
+    use crate::test::test_constants::{DEFAULT_DEPOSIT_DENOM_NAME, DEFAULT_TRADING_DENOM_NAME};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::responses::ContractBalancesResponse;
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{coin, from_json};
+    use provwasm_mocks::{
+        mock_provenance_dependencies, mock_provenance_dependencies_with_custom_querier,
+        MockProvenanceQuerier,
+    };
+    use provwasm_std::types::cosmos::bank::v1beta1::{
+        QueryBalanceRequest, QueryBalanceResponse, QuerySupplyOfRequest, QuerySupplyOfResponse,
+    };
+    use provwasm_std::types::cosmos::base::v1beta1::Coin;
+
+    #[test]
+    fn test_query_with_no_storage() {
+        let deps = mock_provenance_dependencies();
+        query_contract_balances(deps.as_ref(), mock_env())
+            .expect_err("an error should occur when no contract state has been initialized");
+    }
+
+    #[test]
+    fn test_query_with_held_balances() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(
+            &mut querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    amount: "500".to_string(),
+                    denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+                }),
+            },
+        );
+        QuerySupplyOfRequest::mock_response(
+            &mut querier,
+            QuerySupplyOfResponse {
+                amount: Some(Coin {
+                    amount: "5000000".to_string(),
+                    denom: DEFAULT_TRADING_DENOM_NAME.to_string(),
+                }),
+            },
+        );
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        test_instantiate(&mut deps);
+        let balances = from_json::<ContractBalancesResponse>(
+            &query_contract_balances(deps.as_ref(), mock_env())
+                .expect("the balances should be fetched"),
+        )
+        .expect("the balances binary should properly deserialize");
+        assert_eq!(
+            coin(500, DEFAULT_DEPOSIT_DENOM_NAME),
+            balances.deposit_balance,
+            "the deposit balance should be reported",
+        );
+        // The mock querier returns the same balance response regardless of the requested denom
+        assert_eq!(
+            coin(500, DEFAULT_TRADING_DENOM_NAME),
+            balances.trading_balance,
+            "the trading balance should be reported",
+        );
+        assert_eq!(
+            coin(5000000, DEFAULT_TRADING_DENOM_NAME),
+            balances.trading_supply,
+            "the trading supply should be reported",
+        );
+    }
+
+    #[test]
+    fn test_query_with_missing_balances() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(&mut querier, QueryBalanceResponse { balance: None });
+        QuerySupplyOfRequest::mock_response(&mut querier, QuerySupplyOfResponse { amount: None });
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        test_instantiate(&mut deps);
+        let balances = from_json::<ContractBalancesResponse>(
+            &query_contract_balances(deps.as_ref(), mock_env())
+                .expect("missing balances should not cause an error"),
+        )
+        .expect("the balances binary should properly deserialize");
+        assert_eq!(
+            coin(0, DEFAULT_DEPOSIT_DENOM_NAME),
+            balances.deposit_balance,
+            "a missing deposit balance should be reported as zero",
+        );
+        assert_eq!(
+            coin(0, DEFAULT_TRADING_DENOM_NAME),
+            balances.trading_balance,
+            "a missing trading balance should be reported as zero",
+        );
+        assert_eq!(
+            coin(0, DEFAULT_TRADING_DENOM_NAME),
+            balances.trading_supply,
+            "a missing trading supply should be reported as zero",
+        );
+    }
+}
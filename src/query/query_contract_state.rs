@@ -31,7 +31,7 @@ mod tests {
     #[test]
     fn test_query_with_stored_state() {
         let mut deps = mock_provenance_dependencies();
-        test_instantiate(deps.as_mut());
+        test_instantiate(&mut deps);
         let expected_state = get_contract_state_v1(&deps.storage)
             .expect("contract state should load after instantiation");
         let state_from_query = query_contract_state(deps.as_ref())
@@ -0,0 +1,68 @@
+use crate::store::contract_state::get_contract_state_v1;
+use crate::types::error::ContractError;
+use crate::types::responses::CustodyAddressResponse;
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+/// Fetches the custody address from the [contract state](crate::store::contract_state::ContractStateV1),
+/// allowing clients to confirm where the contract's reserves would be sent before requesting an
+/// action that moves them.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+pub fn query_custody_address(deps: Deps) -> Result<Binary, ContractError> {
+    to_json_binary(&CustodyAddressResponse {
+        custody_address: get_contract_state_v1(deps.storage)?.custody_address,
+    })?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_custody_address::query_custody_address;
+    use crate::store::contract_state::{get_contract_state_v1, set_contract_state_v1};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::responses::CustodyAddressResponse;
+    use cosmwasm_std::from_json;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_query_with_no_storage() {
+        let deps = mock_provenance_dependencies();
+        query_custody_address(deps.as_ref())
+            .expect_err("an error should occur when no contract state has been initialized");
+    }
+
+    #[test]
+    fn test_query_reports_the_custody_address() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let response = from_json::<CustodyAddressResponse>(
+            &query_custody_address(deps.as_ref())
+                .expect("the custody address query should succeed"),
+        )
+        .expect("the custody address response should deserialize");
+        assert!(
+            response.custody_address.is_none(),
+            "no custody address should be reported by default",
+        );
+        let custody = deps.api.addr_make("custody");
+        let mut contract_state = get_contract_state_v1(&deps.storage)
+            .expect("contract state should load after instantiation");
+        contract_state.custody_address = Some(custody.to_owned());
+        set_contract_state_v1(&mut deps.storage, &contract_state)
+            .expect("contract state should be stored");
+        let response = from_json::<CustodyAddressResponse>(
+            &query_custody_address(deps.as_ref())
+                .expect("the custody address query should succeed"),
+        )
+        .expect("the custody address response should deserialize");
+        assert_eq!(
+            Some(custody),
+            response.custody_address,
+            "the stored custody address should be reported",
+        );
+    }
+}
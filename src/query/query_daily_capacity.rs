@@ -0,0 +1,115 @@
+use crate::store::contract_state::get_contract_state_v1;
+use crate::store::trade_stats::{day_of, get_daily_trade_totals};
+use crate::types::error::ContractError;
+use crate::types::responses::DailyCapacityResponse;
+use cosmwasm_std::{to_json_binary, Binary, Deps, Env};
+use result_extensions::ResultExtensions;
+
+/// Fetches the deposit denom accepted by fund trades during the UTC day containing the current
+/// block time, and the capacity remaining under the contract's [daily cap](crate::store::contract_state::ContractStateV1#daily_cap).
+/// The volume is reported as zero once the day rolls over, even before any trade executes.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+pub fn query_daily_capacity(deps: Deps, env: Env) -> Result<Binary, ContractError> {
+    let contract_state = get_contract_state_v1(deps.storage)?;
+    let day = day_of(&env.block.time);
+    let volume = get_daily_trade_totals(deps.storage, day)?.fund_volume;
+    to_json_binary(&DailyCapacityResponse {
+        day,
+        daily_cap: contract_state.daily_cap,
+        volume,
+        remaining: contract_state
+            .daily_cap
+            .map(|daily_cap| daily_cap.saturating_sub(volume)),
+    })?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_daily_capacity::query_daily_capacity;
+    use crate::store::trade_stats::{day_of, record_trade, SECONDS_PER_DAY};
+    use crate::test::test_instantiate::{test_instantiate, test_instantiate_with_msg};
+    use crate::types::msg::InstantiateMsg;
+    use crate::types::quote::TradeDirection;
+    use crate::types::responses::DailyCapacityResponse;
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_json, Addr, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_query_without_a_daily_cap() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let env = mock_env();
+        let response = from_json::<DailyCapacityResponse>(
+            &query_daily_capacity(deps.as_ref(), env.to_owned()).expect("the query should succeed"),
+        )
+        .expect("the response binary should properly deserialize");
+        assert_eq!(
+            DailyCapacityResponse {
+                day: day_of(&env.block.time),
+                daily_cap: None,
+                volume: Uint128::zero(),
+                remaining: None,
+            },
+            response,
+            "no remaining capacity should be reported when no cap is configured",
+        );
+    }
+
+    #[test]
+    fn test_query_remaining_capacity_across_the_rollover() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                daily_cap: Some(Uint128::new(1000)),
+                ..InstantiateMsg::default()
+            },
+        );
+        let mut env = mock_env();
+        record_trade(
+            &mut deps.storage,
+            &env.block.time,
+            TradeDirection::Fund,
+            &Addr::unchecked("account"),
+            Uint128::new(400),
+        )
+        .expect("recording the trade should succeed");
+        let response = from_json::<DailyCapacityResponse>(
+            &query_daily_capacity(deps.as_ref(), env.to_owned()).expect("the query should succeed"),
+        )
+        .expect("the response binary should properly deserialize");
+        assert_eq!(
+            DailyCapacityResponse {
+                day: day_of(&env.block.time),
+                daily_cap: Some(Uint128::new(1000)),
+                volume: Uint128::new(400),
+                remaining: Some(Uint128::new(600)),
+            },
+            response,
+            "the remaining capacity should deduct the day's volume from the cap",
+        );
+        env.block.time = env.block.time.plus_seconds(SECONDS_PER_DAY);
+        let response = from_json::<DailyCapacityResponse>(
+            &query_daily_capacity(deps.as_ref(), env.to_owned()).expect("the query should succeed"),
+        )
+        .expect("the response binary should properly deserialize");
+        assert_eq!(
+            DailyCapacityResponse {
+                day: day_of(&env.block.time),
+                daily_cap: Some(Uint128::new(1000)),
+                volume: Uint128::zero(),
+                remaining: Some(Uint128::new(1000)),
+            },
+            response,
+            "the full cap should be available once the day rolls over",
+        );
+    }
+}
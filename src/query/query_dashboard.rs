@@ -0,0 +1,133 @@
+use crate::query::query_contract_balances::get_contract_balances;
+use crate::store::contract_state::get_contract_state_v1;
+use crate::store::runtime_tunables::get_runtime_tunables;
+use crate::store::trade_stats::{day_of, get_daily_trade_totals, get_trade_stats};
+use crate::types::error::ContractError;
+use crate::types::responses::DashboardResponse;
+use cosmwasm_std::{to_json_binary, Binary, Deps, Env};
+use result_extensions::ResultExtensions;
+
+/// Fetches every section of the [DashboardResponse] in a single query, reusing the same lookups as
+/// the individual query routes.  Sections that cannot be loaded are omitted rather than causing the
+/// entire query to fail.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+pub fn query_dashboard(deps: Deps, env: Env) -> Result<Binary, ContractError> {
+    to_json_binary(&DashboardResponse {
+        contract_state: get_contract_state_v1(deps.storage).ok(),
+        tunables: get_runtime_tunables(deps.storage).ok(),
+        contract_balances: get_contract_balances(&deps, &env).ok(),
+        trade_stats: get_trade_stats(deps.storage).ok(),
+        daily_trade_totals: get_daily_trade_totals(deps.storage, day_of(&env.block.time)).ok(),
+    })?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_dashboard::query_dashboard;
+    use crate::store::contract_state::get_contract_state_v1;
+    use crate::store::runtime_tunables::RuntimeTunables;
+    use crate::store::trade_stats::{record_trade, TradeStats, TradeTotals};
+    use crate::test::test_constants::{DEFAULT_DEPOSIT_DENOM_NAME, DEFAULT_TRADING_DENOM_NAME};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::quote::TradeDirection;
+    use crate::types::responses::DashboardResponse;
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{coin, from_json, Addr, Uint128};
+    use provwasm_mocks::{
+        mock_provenance_dependencies, mock_provenance_dependencies_with_custom_querier,
+        MockProvenanceQuerier,
+    };
+    use provwasm_std::types::cosmos::bank::v1beta1::{
+        QueryBalanceRequest, QueryBalanceResponse, QuerySupplyOfRequest, QuerySupplyOfResponse,
+    };
+
+    #[test]
+    fn test_query_with_no_storage() {
+        let deps = mock_provenance_dependencies();
+        let dashboard = from_json::<DashboardResponse>(
+            &query_dashboard(deps.as_ref(), mock_env())
+                .expect("the dashboard should load even when no contract state exists"),
+        )
+        .expect("the dashboard binary should properly deserialize");
+        assert!(
+            dashboard.contract_state.is_none(),
+            "the contract state section should be omitted when it cannot be loaded",
+        );
+        assert!(
+            dashboard.contract_balances.is_none(),
+            "the contract balances section should be omitted when it cannot be loaded",
+        );
+    }
+
+    #[test]
+    fn test_query_with_stored_state() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(&mut querier, QueryBalanceResponse { balance: None });
+        QuerySupplyOfRequest::mock_response(&mut querier, QuerySupplyOfResponse { amount: None });
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        test_instantiate(&mut deps);
+        record_trade(
+            &mut deps.storage,
+            &mock_env().block.time,
+            TradeDirection::Fund,
+            &Addr::unchecked("trader"),
+            Uint128::new(100),
+        )
+        .expect("the trade should be recorded");
+        let dashboard = from_json::<DashboardResponse>(
+            &query_dashboard(deps.as_ref(), mock_env()).expect("the dashboard should load"),
+        )
+        .expect("the dashboard binary should properly deserialize");
+        assert_eq!(
+            Some(
+                get_contract_state_v1(&deps.storage)
+                    .expect("contract state should load after instantiation")
+            ),
+            dashboard.contract_state,
+            "the contract state section should equate to the value in storage",
+        );
+        assert_eq!(
+            Some(RuntimeTunables::default()),
+            dashboard.tunables,
+            "the tunables section should include the stored tunables",
+        );
+        let balances = dashboard
+            .contract_balances
+            .expect("the contract balances section should be populated");
+        assert_eq!(
+            coin(0, DEFAULT_DEPOSIT_DENOM_NAME),
+            balances.deposit_balance,
+            "the deposit balance should be included",
+        );
+        assert_eq!(
+            coin(0, DEFAULT_TRADING_DENOM_NAME),
+            balances.trading_balance,
+            "the trading balance should be included",
+        );
+        let expected_totals = TradeTotals {
+            fund_count: 1,
+            fund_volume: Uint128::new(100),
+            ..TradeTotals::default()
+        };
+        assert_eq!(
+            Some(TradeStats {
+                totals: expected_totals.to_owned(),
+                account_count: 1,
+            }),
+            dashboard.trade_stats,
+            "the trade stats section should include the recorded trade",
+        );
+        assert_eq!(
+            Some(expected_totals),
+            dashboard.daily_trade_totals,
+            "the daily trade totals section should include the trade recorded today",
+        );
+    }
+}
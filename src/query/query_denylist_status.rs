@@ -0,0 +1,80 @@
+use crate::store::denylist::get_denylist_entry;
+use crate::types::error::ContractError;
+use crate::types::responses::DenylistStatusResponse;
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+/// Reports whether or not an account is on the [denylist](crate::store::denylist), along with the
+/// reason that it was listed.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `account` The bech32 address of the account to inspect.
+pub fn query_denylist_status(deps: Deps, account: String) -> Result<Binary, ContractError> {
+    let account = deps.api.addr_validate(&account)?;
+    let entry = get_denylist_entry(deps.storage, &account)?;
+    to_json_binary(&DenylistStatusResponse {
+        account,
+        denylisted: entry.is_some(),
+        reason: entry.and_then(|entry| entry.reason),
+    })?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_denylist_status::query_denylist_status;
+    use crate::store::denylist::add_denylisted_account;
+    use crate::types::responses::DenylistStatusResponse;
+    use cosmwasm_std::from_json;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_query_invalid_account() {
+        let deps = mock_provenance_dependencies();
+        query_denylist_status(deps.as_ref(), "not-an-address".to_string())
+            .expect_err("an invalid account address should be rejected");
+    }
+
+    #[test]
+    fn test_query_denylist_status() {
+        let mut deps = mock_provenance_dependencies();
+        let account = deps.api.addr_make("account");
+        let status = from_json::<DenylistStatusResponse>(
+            &query_denylist_status(deps.as_ref(), account.to_string())
+                .expect("the query should succeed"),
+        )
+        .expect("the response binary should properly deserialize");
+        assert_eq!(
+            DenylistStatusResponse {
+                account: account.to_owned(),
+                denylisted: false,
+                reason: None,
+            },
+            status,
+            "an unlisted account should not be reported as denylisted",
+        );
+        add_denylisted_account(
+            &mut deps.storage,
+            &account,
+            Some("flagged by compliance".to_string()),
+        )
+        .expect("denylisting an account should succeed");
+        let status = from_json::<DenylistStatusResponse>(
+            &query_denylist_status(deps.as_ref(), account.to_string())
+                .expect("the query should succeed"),
+        )
+        .expect("the response binary should properly deserialize");
+        assert_eq!(
+            DenylistStatusResponse {
+                account,
+                denylisted: true,
+                reason: Some("flagged by compliance".to_string()),
+            },
+            status,
+            "a listed account should be reported with its reason",
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use crate::store::fee_exemptions::get_fee_exemptions;
+use crate::types::error::ContractError;
+use crate::types::responses::FeeExemptionsResponse;
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+/// The amount of accounts returned when the query does not specify a limit.
+pub const DEFAULT_FEE_EXEMPTIONS_LIMIT: u32 = 10;
+/// The largest amount of accounts returned by a single query.
+pub const MAX_FEE_EXEMPTIONS_LIMIT: u32 = 30;
+
+/// Fetches a page of the accounts on the [fee exemption](crate::store::fee_exemptions) list,
+/// ordered by address.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `start_after` If provided, the bech32 address after which accounts are returned.
+/// * `limit` The maximum amount of accounts to return.  Defaults to
+/// [DEFAULT_FEE_EXEMPTIONS_LIMIT] and is capped at [MAX_FEE_EXEMPTIONS_LIMIT].
+pub fn query_fee_exemptions(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> Result<Binary, ContractError> {
+    let start_after = start_after
+        .map(|start_after| deps.api.addr_validate(&start_after))
+        .transpose()?;
+    let limit = limit
+        .unwrap_or(DEFAULT_FEE_EXEMPTIONS_LIMIT)
+        .min(MAX_FEE_EXEMPTIONS_LIMIT);
+    let accounts = get_fee_exemptions(deps.storage, start_after.as_ref(), limit as usize)?;
+    to_json_binary(&FeeExemptionsResponse { accounts })?.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_fee_exemptions::{query_fee_exemptions, MAX_FEE_EXEMPTIONS_LIMIT};
+    use crate::store::fee_exemptions::add_fee_exemption;
+    use crate::types::responses::FeeExemptionsResponse;
+    use cosmwasm_std::from_json;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_query_pages_accounts_in_order() {
+        let mut deps = mock_provenance_dependencies();
+        let mut accounts = ["first", "second", "third"]
+            .into_iter()
+            .map(|name| deps.api.addr_make(name))
+            .collect::<Vec<_>>();
+        accounts.sort();
+        for account in &accounts {
+            add_fee_exemption(&mut deps.storage, account)
+                .expect("adding an exemption should succeed");
+        }
+        let first_page = from_json::<FeeExemptionsResponse>(
+            &query_fee_exemptions(deps.as_ref(), None, Some(2)).expect("the query should succeed"),
+        )
+        .expect("the response binary should properly deserialize");
+        assert_eq!(
+            accounts[..2].to_vec(),
+            first_page.accounts,
+            "the first page should contain the first two accounts by address",
+        );
+        let second_page = from_json::<FeeExemptionsResponse>(
+            &query_fee_exemptions(
+                deps.as_ref(),
+                Some(accounts[1].to_string()),
+                Some(MAX_FEE_EXEMPTIONS_LIMIT + 1),
+            )
+            .expect("the query should succeed"),
+        )
+        .expect("the response binary should properly deserialize");
+        assert_eq!(
+            accounts[2..].to_vec(),
+            second_page.accounts,
+            "the next page should start after the given address",
+        );
+    }
+
+    #[test]
+    fn test_query_invalid_start_after() {
+        let deps = mock_provenance_dependencies();
+        query_fee_exemptions(deps.as_ref(), Some("not-an-address".to_string()), None)
+            .expect_err("an invalid start_after address should be rejected");
+    }
+}
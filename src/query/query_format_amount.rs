@@ -0,0 +1,82 @@
+use crate::store::contract_state::get_contract_state_v1;
+use crate::types::denom::DenomSide;
+use crate::types::error::ContractError;
+use crate::types::responses::FormatAmountResponse;
+use crate::util::conversion_utils::format_amount;
+use cosmwasm_std::{to_json_binary, Binary, Deps, Uint128};
+use result_extensions::ResultExtensions;
+
+/// Renders an amount of the denom on the given side of the bridge in its decimal display form,
+/// using the precision stored in the [contract state](crate::store::contract_state::ContractStateV1).
+/// This allows clients to display amounts without re-implementing the precision arithmetic.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `denom_side` The side of the bridge whose denom defines the amount.
+/// * `amount` The amount to format, in base units.
+pub fn query_format_amount(
+    deps: Deps,
+    denom_side: DenomSide,
+    amount: Uint128,
+) -> Result<Binary, ContractError> {
+    let contract_state = get_contract_state_v1(deps.storage)?;
+    let denom = contract_state.marker_for_side(denom_side);
+    to_json_binary(&FormatAmountResponse {
+        denom_side,
+        denom: denom.to_owned(),
+        amount,
+        formatted: format_amount(amount, denom.precision.u64())?,
+    })?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_format_amount::query_format_amount;
+    use crate::test::test_constants::{DEFAULT_DEPOSIT_DENOM_NAME, DEFAULT_TRADING_DENOM_NAME};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::denom::DenomSide;
+    use crate::types::responses::FormatAmountResponse;
+    use cosmwasm_std::{from_json, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_query_with_no_storage() {
+        let deps = mock_provenance_dependencies();
+        query_format_amount(deps.as_ref(), DenomSide::Deposit, Uint128::new(100))
+            .expect_err("an error should occur when no contract state has been initialized");
+    }
+
+    #[test]
+    fn test_query_formats_amounts_with_each_precision() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let deposit = from_json::<FormatAmountResponse>(
+            &query_format_amount(deps.as_ref(), DenomSide::Deposit, Uint128::new(123456))
+                .expect("the amount should be formatted"),
+        )
+        .expect("the format binary should properly deserialize");
+        assert_eq!(
+            DEFAULT_DEPOSIT_DENOM_NAME, deposit.denom.name,
+            "the deposit denom should be used",
+        );
+        assert_eq!("1234", deposit.formatted.integer_part);
+        assert_eq!("56", deposit.formatted.fractional_part);
+        assert_eq!("1234.56", deposit.formatted.display);
+        let trading = from_json::<FormatAmountResponse>(
+            &query_format_amount(deps.as_ref(), DenomSide::Trading, Uint128::new(1234560))
+                .expect("the amount should be formatted"),
+        )
+        .expect("the format binary should properly deserialize");
+        assert_eq!(
+            DEFAULT_TRADING_DENOM_NAME, trading.denom.name,
+            "the trading denom should be used",
+        );
+        assert_eq!(
+            "1.234560", trading.formatted.display,
+            "the fractional part should be padded to the trading precision",
+        );
+    }
+}
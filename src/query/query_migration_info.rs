@@ -0,0 +1,77 @@
+use crate::store::contract_state::{get_contract_state_v1, CONTRACT_STATE_SCHEMA_VERSION};
+use crate::types::error::ContractError;
+use crate::types::responses::MigrationInfoResponse;
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+/// Fetches the values that the [migration](crate::migrate::migrate_contract::migrate_contract)
+/// route checks new code against, along with the rules it enforces.  Deployment tooling can
+/// compare these against the constants embedded in a candidate wasm before submitting a migration.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+pub fn query_migration_info(deps: Deps) -> Result<Binary, ContractError> {
+    let contract_state = get_contract_state_v1(deps.storage)?;
+    to_json_binary(&MigrationInfoResponse {
+        rules: vec![
+            format!(
+                "the new code's contract type must equal [{}]",
+                contract_state.contract_type,
+            ),
+            format!(
+                "the new code's contract version must be a semver version greater than [{}]",
+                contract_state.contract_version,
+            ),
+        ],
+        contract_type: contract_state.contract_type,
+        contract_version: contract_state.contract_version,
+        storage_schema_version: CONTRACT_STATE_SCHEMA_VERSION,
+    })?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_migration_info::query_migration_info;
+    use crate::store::contract_state::{
+        CONTRACT_STATE_SCHEMA_VERSION, CONTRACT_TYPE, CONTRACT_VERSION,
+    };
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::responses::MigrationInfoResponse;
+    use cosmwasm_std::from_json;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_query_with_no_storage() {
+        let deps = mock_provenance_dependencies();
+        query_migration_info(deps.as_ref())
+            .expect_err("an error should occur when no contract state has been initialized");
+    }
+
+    #[test]
+    fn test_query_reports_the_stored_values() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let response = from_json::<MigrationInfoResponse>(
+            &query_migration_info(deps.as_ref()).expect("the migration info query should succeed"),
+        )
+        .expect("the migration info response should deserialize");
+        assert_eq!(
+            MigrationInfoResponse {
+                contract_type: CONTRACT_TYPE.to_string(),
+                contract_version: CONTRACT_VERSION.to_string(),
+                storage_schema_version: CONTRACT_STATE_SCHEMA_VERSION,
+                rules: vec![
+                    format!("the new code's contract type must equal [{CONTRACT_TYPE}]"),
+                    format!(
+                        "the new code's contract version must be a semver version greater than [{CONTRACT_VERSION}]"
+                    ),
+                ],
+            },
+            response,
+            "the stored contract type and version should be reported with their rules",
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use crate::store::contract_state::get_contract_state_v1;
+use crate::types::denom::DenomSide;
+use crate::types::error::ContractError;
+use crate::types::responses::ParseAmountResponse;
+use crate::util::conversion_utils::parse_amount;
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+/// Converts an amount of the denom on the given side of the bridge from its decimal display form
+/// into base units, using the precision stored in the [contract state](crate::store::contract_state::ContractStateV1).
+/// This is the inverse of the [query_format_amount](crate::query::query_format_amount::query_format_amount)
+/// query.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `denom_side` The side of the bridge whose denom defines the amount.
+/// * `display` The amount to parse, in decimal form, like `1234.56`.
+pub fn query_parse_amount(
+    deps: Deps,
+    denom_side: DenomSide,
+    display: String,
+) -> Result<Binary, ContractError> {
+    let contract_state = get_contract_state_v1(deps.storage)?;
+    let denom = contract_state.marker_for_side(denom_side);
+    let amount = parse_amount(&display, denom.precision.u64())?;
+    to_json_binary(&ParseAmountResponse {
+        denom_side,
+        denom: denom.to_owned(),
+        display,
+        amount,
+    })?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_parse_amount::query_parse_amount;
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::denom::DenomSide;
+    use crate::types::responses::ParseAmountResponse;
+    use cosmwasm_std::{from_json, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_query_with_no_storage() {
+        let deps = mock_provenance_dependencies();
+        query_parse_amount(deps.as_ref(), DenomSide::Deposit, "1.00".to_string())
+            .expect_err("an error should occur when no contract state has been initialized");
+    }
+
+    #[test]
+    fn test_query_parses_amounts_with_each_precision() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        for (denom_side, display, amount) in [
+            (DenomSide::Deposit, "1234.5", 123450),
+            (DenomSide::Trading, "1234.5", 1234500000),
+        ] {
+            let parsed = from_json::<ParseAmountResponse>(
+                &query_parse_amount(deps.as_ref(), denom_side, display.to_string())
+                    .expect("the amount should be parsed"),
+            )
+            .expect("the parse binary should properly deserialize");
+            assert_eq!(
+                Uint128::new(amount),
+                parsed.amount,
+                "the amount should be scaled by the {denom_side:?} precision",
+            );
+        }
+    }
+
+    #[test]
+    fn test_query_rejects_excess_decimal_places() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let error = query_parse_amount(deps.as_ref(), DenomSide::Deposit, "1.005".to_string())
+            .expect_err("an amount finer than the deposit precision should be rejected");
+        assert_eq!(
+            "validation failed: amount [1.005] cannot have more than [2] decimal places",
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use crate::store::pending_admin::get_pending_admin;
+use crate::types::error::ContractError;
+use crate::types::responses::PendingAdminResponse;
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+/// Fetches the address proposed to become the contract admin by the [admin_propose_new_admin](crate::execute::admin_propose_new_admin::admin_propose_new_admin)
+/// route, if a proposal is awaiting acceptance.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+pub fn query_pending_admin(deps: Deps) -> Result<Binary, ContractError> {
+    to_json_binary(&PendingAdminResponse {
+        pending_admin: get_pending_admin(deps.storage)?,
+    })?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_pending_admin::query_pending_admin;
+    use crate::store::pending_admin::set_pending_admin;
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::responses::PendingAdminResponse;
+    use cosmwasm_std::{from_json, Addr};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_query_reports_the_pending_admin() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let response = from_json::<PendingAdminResponse>(
+            &query_pending_admin(deps.as_ref()).expect("the pending admin query should succeed"),
+        )
+        .expect("the pending admin response should deserialize");
+        assert!(
+            response.pending_admin.is_none(),
+            "no pending admin should be reported before one is proposed",
+        );
+        set_pending_admin(&mut deps.storage, Some(&Addr::unchecked("pending-admin")))
+            .expect("the pending admin should be stored");
+        let response = from_json::<PendingAdminResponse>(
+            &query_pending_admin(deps.as_ref()).expect("the pending admin query should succeed"),
+        )
+        .expect("the pending admin response should deserialize");
+        assert_eq!(
+            Some(Addr::unchecked("pending-admin")),
+            response.pending_admin,
+            "the proposed address should be reported",
+        );
+    }
+}
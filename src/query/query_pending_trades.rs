@@ -0,0 +1,148 @@
+use crate::store::pending_trades::get_pending_trades;
+use crate::types::error::ContractError;
+use crate::types::responses::{PendingTradeResponse, PendingTradesResponse};
+use cosmwasm_std::{to_json_binary, Binary, Deps, Env};
+use result_extensions::ResultExtensions;
+
+/// The amount of pending trades returned when the query does not specify a limit.
+pub const DEFAULT_PENDING_TRADES_LIMIT: u32 = 10;
+/// The largest amount of pending trades returned by a single query.
+pub const MAX_PENDING_TRADES_LIMIT: u32 = 30;
+
+/// Fetches a page of the [pending trades](crate::store::pending_trades::PendingTrade) awaiting admin
+/// approval, ordered from oldest to newest, and flags any that have expired.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `start_after` If provided, only pending trades with an identifier greater than this value are
+/// returned.
+/// * `limit` The maximum amount of pending trades to return.  Defaults to
+/// [DEFAULT_PENDING_TRADES_LIMIT] and is capped at [MAX_PENDING_TRADES_LIMIT].
+pub fn query_pending_trades(
+    deps: Deps,
+    env: Env,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Result<Binary, ContractError> {
+    let limit = limit
+        .unwrap_or(DEFAULT_PENDING_TRADES_LIMIT)
+        .min(MAX_PENDING_TRADES_LIMIT);
+    let pending_trades = get_pending_trades(deps.storage, start_after, limit as usize)?
+        .into_iter()
+        .map(|pending_trade| PendingTradeResponse {
+            expired: pending_trade.is_expired(env.block.height),
+            pending_trade,
+        })
+        .collect();
+    to_json_binary(&PendingTradesResponse { pending_trades })?.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_pending_trades::{query_pending_trades, MAX_PENDING_TRADES_LIMIT};
+    use crate::store::pending_trades::{insert_pending_trade, PendingTrade};
+    use crate::types::quote::TradeDirection;
+    use crate::types::responses::PendingTradesResponse;
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_json, Addr, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_query_with_no_pending_trades() {
+        let deps = mock_provenance_dependencies();
+        let response = from_json::<PendingTradesResponse>(
+            &query_pending_trades(deps.as_ref(), mock_env(), None, None)
+                .expect("the query should succeed without any pending trades"),
+        )
+        .expect("the response binary should properly deserialize");
+        assert!(
+            response.pending_trades.is_empty(),
+            "no pending trades should be returned",
+        );
+    }
+
+    #[test]
+    fn test_query_pages_and_flags_expired_trades() {
+        let mut deps = mock_provenance_dependencies();
+        let env = mock_env();
+        for expires_at_height in [env.block.height - 1, env.block.height, env.block.height + 1] {
+            insert_pending_trade(
+                &mut deps.storage,
+                PendingTrade {
+                    id: 0,
+                    direction: TradeDirection::Fund,
+                    sender: Addr::unchecked("sender"),
+                    trade_amount: Uint128::new(100),
+                    submitted_at_height: 1,
+                    expires_at_height,
+                    referrer: None,
+                    allow_remainder: false,
+                },
+            )
+            .expect("the pending trade should be stored");
+        }
+        let response = from_json::<PendingTradesResponse>(
+            &query_pending_trades(deps.as_ref(), env.clone(), None, None)
+                .expect("the query should succeed"),
+        )
+        .expect("the response binary should properly deserialize");
+        assert_eq!(
+            vec![(1, true), (2, false), (3, false)],
+            response
+                .pending_trades
+                .iter()
+                .map(|trade| (trade.pending_trade.id, trade.expired))
+                .collect::<Vec<_>>(),
+            "every pending trade should be returned, with only the first flagged as expired",
+        );
+        let response = from_json::<PendingTradesResponse>(
+            &query_pending_trades(deps.as_ref(), env, Some(1), Some(1))
+                .expect("the paged query should succeed"),
+        )
+        .expect("the response binary should properly deserialize");
+        assert_eq!(
+            vec![2],
+            response
+                .pending_trades
+                .iter()
+                .map(|trade| trade.pending_trade.id)
+                .collect::<Vec<_>>(),
+            "only the requested page should be returned",
+        );
+    }
+
+    #[test]
+    fn test_query_caps_the_limit() {
+        let mut deps = mock_provenance_dependencies();
+        for _ in 0..MAX_PENDING_TRADES_LIMIT + 5 {
+            insert_pending_trade(
+                &mut deps.storage,
+                PendingTrade {
+                    id: 0,
+                    direction: TradeDirection::Withdraw,
+                    sender: Addr::unchecked("sender"),
+                    trade_amount: Uint128::new(100),
+                    submitted_at_height: 1,
+                    expires_at_height: 100,
+                    referrer: None,
+                    allow_remainder: false,
+                },
+            )
+            .expect("the pending trade should be stored");
+        }
+        let response = from_json::<PendingTradesResponse>(
+            &query_pending_trades(deps.as_ref(), mock_env(), None, Some(u32::MAX))
+                .expect("the query should succeed"),
+        )
+        .expect("the response binary should properly deserialize");
+        assert_eq!(
+            MAX_PENDING_TRADES_LIMIT as usize,
+            response.pending_trades.len(),
+            "the limit should be capped",
+        );
+    }
+}
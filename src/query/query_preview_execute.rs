@@ -0,0 +1,615 @@
+use crate::execute::fund_trading::plan_fund_trading;
+use crate::execute::withdraw_trading::plan_withdraw_trading;
+use crate::store::contract_state::{get_contract_state_v1, ContractStateV1};
+use crate::types::error::ContractError;
+use crate::types::msg::ExecuteMsg;
+use crate::types::quote::TradeDirection;
+use crate::types::responses::PreviewExecuteResponse;
+use crate::types::trade_options::TradeOptions;
+use crate::types::trade_plan::TradePlan;
+use crate::util::response_utils::trade_execution_messages;
+use cosmwasm_std::{to_json_binary, Addr, Binary, Deps, Env, Uint128};
+use result_extensions::ResultExtensions;
+
+/// Runs the same checks and calculations as the execution route targeted by the given message,
+/// and returns the messages and amounts that the execution would produce for the sender.  A trade
+/// that would be held for admin approval lists only the messages emitted when it is submitted.  If the
+/// execution would fail, the error it would produce is included in the response instead.  No state
+/// is modified by this query.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `msg` The trade execution message to preview.
+/// * `sender` The bech32 address of the account that would send the execution message.
+pub fn query_preview_execute(
+    deps: Deps,
+    env: Env,
+    msg: ExecuteMsg,
+    sender: String,
+) -> Result<Binary, ContractError> {
+    let sender = deps.api.addr_validate(&sender)?;
+    let (route_name, requested_amount, plan_result) = match msg {
+        ExecuteMsg::FundTrading {
+            trade_amount,
+            quote,
+            referrer,
+            allow_partial,
+            allow_remainder,
+            ..
+        } => (
+            "fund_trading",
+            trade_amount,
+            plan_trade(
+                &deps,
+                &env,
+                &sender,
+                TradeDirection::Fund,
+                trade_amount,
+                &TradeOptions {
+                    quote,
+                    referrer,
+                    allow_partial,
+                    allow_remainder,
+                    ..TradeOptions::default()
+                },
+                plan_fund_trading,
+            ),
+        ),
+        ExecuteMsg::WithdrawTrading {
+            trade_amount,
+            quote,
+            referrer,
+            allow_partial,
+            allow_remainder,
+            ..
+        } => (
+            "withdraw_trading",
+            trade_amount,
+            plan_trade(
+                &deps,
+                &env,
+                &sender,
+                TradeDirection::Withdraw,
+                trade_amount,
+                &TradeOptions {
+                    quote,
+                    referrer,
+                    allow_partial,
+                    allow_remainder,
+                    ..TradeOptions::default()
+                },
+                plan_withdraw_trading,
+            ),
+        ),
+        _ => {
+            return ContractError::ValidationError {
+                message: "only trade execution messages can be previewed".to_string(),
+            }
+            .to_err()
+        }
+    };
+    let response = match plan_result {
+        Ok(plan) => PreviewExecuteResponse {
+            would_succeed: true,
+            messages: trade_execution_messages(&env, &plan, route_name)
+                .into_iter()
+                .map(|sub_msg| sub_msg.msg)
+                .collect(),
+            requested_amount,
+            collected_amount: Some(plan.collected_amount),
+            output_amount: Some(plan.output_amount()),
+            remainder: Some(plan.conversion.remainder),
+            remainder_requires_acknowledgment: Some(plan.requires_remainder_acknowledgment()),
+            requires_approval: Some(plan.requires_approval),
+            msg_fee: plan.msg_fee.map(|msg_fee| msg_fee.amount),
+            error: None,
+        },
+        Err(e) => PreviewExecuteResponse {
+            would_succeed: false,
+            messages: vec![],
+            requested_amount,
+            collected_amount: None,
+            output_amount: None,
+            remainder: None,
+            remainder_requires_acknowledgment: matches!(
+                e,
+                ContractError::RemainderNotAcknowledgedError { .. }
+            )
+            .then_some(true),
+            requires_approval: None,
+            msg_fee: None,
+            error: Some(e.to_string()),
+        },
+    };
+    to_json_binary(&response)?.to_ok()
+}
+
+fn plan_trade<F>(
+    deps: &Deps,
+    env: &Env,
+    sender: &Addr,
+    direction: TradeDirection,
+    trade_amount: Uint128,
+    options: &TradeOptions,
+    planner: F,
+) -> Result<TradePlan, ContractError>
+where
+    F: FnOnce(
+        &Deps,
+        &Env,
+        &ContractStateV1,
+        &Addr,
+        Uint128,
+        &TradeOptions,
+    ) -> Result<TradePlan, ContractError>,
+{
+    let contract_state = get_contract_state_v1(deps.storage)?;
+    let plan = planner(deps, env, &contract_state, sender, trade_amount, options)?;
+    let input_marker = match direction {
+        TradeDirection::Fund => &contract_state.deposit_marker,
+        TradeDirection::Withdraw => &contract_state.trading_marker,
+    };
+    plan.check_remainder_acknowledged(&input_marker.name, options.allow_remainder)?;
+    plan.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::contract::execute;
+    use crate::query::query_preview_execute::query_preview_execute;
+    use crate::test::test_constants::{
+        DEFAULT_DEPOSIT_DENOM_NAME, DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE, DEFAULT_TRADING_DENOM_NAME,
+    };
+    use crate::test::test_instantiate::{test_instantiate, test_instantiate_with_msg};
+    use crate::test::test_markers::{
+        mock_marker_params_response, mock_marker_response, test_marker_account,
+    };
+    use crate::types::denom::Denom;
+    use crate::types::error::ContractError;
+    use crate::types::msg::{ExecuteMsg, InstantiateMsg};
+    use crate::types::msg_fee::MsgFee;
+    use crate::types::responses::PreviewExecuteResponse;
+    use cosmwasm_std::testing::{message_info, mock_env, MockApi, MockStorage};
+    use cosmwasm_std::{coin, from_json, Addr, OwnedDeps, Uint128};
+    use provwasm_mocks::{mock_provenance_dependencies_with_custom_querier, MockProvenanceQuerier};
+    use provwasm_std::types::cosmos::bank::v1beta1::{QueryBalanceRequest, QueryBalanceResponse};
+    use provwasm_std::types::cosmos::base::v1beta1::Coin;
+    use provwasm_std::types::provenance::attribute::v1::{
+        Attribute, AttributeType, QueryAttributesRequest, QueryAttributesResponse,
+    };
+
+    #[test]
+    fn test_preview_of_successful_fund_trading() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(
+            &mut querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    amount: "100".to_string(),
+                    denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+                }),
+            },
+        );
+        QueryAttributesRequest::mock_response(
+            &mut querier,
+            QueryAttributesResponse {
+                account: "sender".to_string(),
+                attributes: vec![Attribute {
+                    name: DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE.to_string(),
+                    value: vec![],
+                    attribute_type: AttributeType::String as i32,
+                    address: "addr".to_string(),
+                    expiration_date: None,
+                }],
+                pagination: None,
+            },
+        );
+        mock_marker_response(
+            &mut querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        mock_marker_params_response(&mut querier, "");
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        test_instantiate(&mut deps);
+        let sender = deps.api.addr_make("sender");
+        let preview = query_preview_execute(
+            deps.as_ref(),
+            mock_env(),
+            ExecuteMsg::FundTrading {
+                trade_amount: Uint128::new(100),
+                dry_run: false,
+                quote: None,
+                referrer: None,
+                allow_partial: false,
+                allow_remainder: false,
+            },
+            sender.to_string(),
+        )
+        .expect("the preview query should succeed");
+        let preview = from_json::<PreviewExecuteResponse>(&preview)
+            .expect("the preview response should deserialize");
+        assert!(
+            preview.would_succeed,
+            "the preview should indicate success: {:?}",
+            preview.error,
+        );
+        assert_eq!(
+            3,
+            preview.messages.len(),
+            "the preview should include all three fund trading messages",
+        );
+        assert_eq!(
+            Uint128::new(100),
+            preview.requested_amount,
+            "the requested amount should be reflected in the preview",
+        );
+        assert_eq!(
+            Some(Uint128::new(100)),
+            preview.collected_amount,
+            "the full amount should be collected because there is no remainder",
+        );
+        assert_eq!(
+            Some(Uint128::new(1000000)),
+            preview.output_amount,
+            "the output amount should be converted to the trading precision",
+        );
+        assert_eq!(
+            Some(Uint128::zero()),
+            preview.remainder,
+            "there should be no remainder",
+        );
+        assert_eq!(
+            Some(false),
+            preview.remainder_requires_acknowledgment,
+            "a trade without a remainder should not require acknowledgment",
+        );
+        assert!(preview.error.is_none(), "no error should be populated");
+    }
+
+    #[test]
+    fn test_preview_of_fund_trading_with_a_remainder() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(
+            &mut querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    amount: "103".to_string(),
+                    denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+                }),
+            },
+        );
+        QueryAttributesRequest::mock_response(
+            &mut querier,
+            QueryAttributesResponse {
+                account: "sender".to_string(),
+                attributes: vec![Attribute {
+                    name: DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE.to_string(),
+                    value: vec![],
+                    attribute_type: AttributeType::String as i32,
+                    address: "addr".to_string(),
+                    expiration_date: None,
+                }],
+                pagination: None,
+            },
+        );
+        mock_marker_response(
+            &mut querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        mock_marker_params_response(&mut querier, "");
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                deposit_marker: Denom::new(DEFAULT_DEPOSIT_DENOM_NAME, 2),
+                trading_marker: Denom::new(DEFAULT_TRADING_DENOM_NAME, 1),
+                ..InstantiateMsg::default()
+            },
+        );
+        let sender = deps.api.addr_make("sender");
+        for allow_remainder in [false, true] {
+            let preview = from_json::<PreviewExecuteResponse>(
+                &query_preview_execute(
+                    deps.as_ref(),
+                    mock_env(),
+                    ExecuteMsg::FundTrading {
+                        trade_amount: Uint128::new(103),
+                        dry_run: false,
+                        quote: None,
+                        referrer: None,
+                        allow_partial: false,
+                        allow_remainder,
+                    },
+                    sender.to_string(),
+                )
+                .expect("the preview query should succeed"),
+            )
+            .expect("the preview response should deserialize");
+            assert_eq!(
+                allow_remainder, preview.would_succeed,
+                "the preview should only succeed when the remainder is acknowledged",
+            );
+            assert_eq!(
+                Some(true),
+                preview.remainder_requires_acknowledgment,
+                "the remainder should be reported as requiring acknowledgment",
+            );
+        }
+    }
+
+    #[test]
+    fn test_preview_of_failing_withdraw_trading() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(
+            &mut querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    amount: "10000".to_string(),
+                    denom: DEFAULT_TRADING_DENOM_NAME.to_string(),
+                }),
+            },
+        );
+        QueryAttributesRequest::mock_response(
+            &mut querier,
+            QueryAttributesResponse {
+                account: "sender".to_string(),
+                attributes: vec![],
+                pagination: None,
+            },
+        );
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        test_instantiate(&mut deps);
+        let sender = deps.api.addr_make("sender");
+        let preview = query_preview_execute(
+            deps.as_ref(),
+            mock_env(),
+            ExecuteMsg::WithdrawTrading {
+                trade_amount: Uint128::new(10000),
+                dry_run: false,
+                quote: None,
+                referrer: None,
+                allow_partial: false,
+                allow_remainder: false,
+            },
+            sender.to_string(),
+        )
+        .expect("the preview query should succeed even when the execution would fail");
+        let preview = from_json::<PreviewExecuteResponse>(&preview)
+            .expect("the preview response should deserialize");
+        assert!(
+            !preview.would_succeed,
+            "the preview should indicate failure when the sender is missing attributes",
+        );
+        assert!(
+            preview.messages.is_empty(),
+            "no messages should be included when the execution would fail",
+        );
+        assert_eq!(
+            "preconditions failed: invalid account: account does not have all required attributes",
+            preview
+                .error
+                .expect("the error should be populated when the execution would fail"),
+            "the error that the execution would produce should be included",
+        );
+        assert!(
+            preview.collected_amount.is_none()
+                && preview.output_amount.is_none()
+                && preview.remainder.is_none(),
+            "no amounts should be populated when the execution would fail",
+        );
+    }
+
+    fn get_fund_querier() -> MockProvenanceQuerier {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(
+            &mut querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    amount: "100".to_string(),
+                    denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+                }),
+            },
+        );
+        QueryAttributesRequest::mock_response(
+            &mut querier,
+            QueryAttributesResponse {
+                account: "sender".to_string(),
+                attributes: vec![Attribute {
+                    name: DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE.to_string(),
+                    value: vec![],
+                    attribute_type: AttributeType::String as i32,
+                    address: "addr".to_string(),
+                    expiration_date: None,
+                }],
+                pagination: None,
+            },
+        );
+        mock_marker_response(
+            &mut querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        mock_marker_params_response(&mut querier, "");
+        querier
+    }
+
+    fn fund_msg(referrer: Option<String>) -> ExecuteMsg {
+        ExecuteMsg::FundTrading {
+            trade_amount: Uint128::new(100),
+            dry_run: false,
+            quote: None,
+            referrer,
+            allow_partial: false,
+            allow_remainder: false,
+        }
+    }
+
+    /// Previews the message, then executes it, and verifies that the preview described the
+    /// execution's outcome exactly.
+    fn assert_preview_matches_execution(
+        deps: &mut OwnedDeps<MockStorage, MockApi, MockProvenanceQuerier>,
+        sender: &Addr,
+        msg: ExecuteMsg,
+    ) -> PreviewExecuteResponse {
+        let preview = from_json::<PreviewExecuteResponse>(
+            &query_preview_execute(
+                deps.as_ref(),
+                mock_env(),
+                msg.to_owned(),
+                sender.to_string(),
+            )
+            .expect("the preview query should succeed"),
+        )
+        .expect("the preview response should deserialize");
+        match execute(deps.as_mut(), mock_env(), message_info(sender, &[]), msg) {
+            Ok(response) => {
+                assert!(
+                    preview.would_succeed,
+                    "the preview should succeed like the execution: {:?}",
+                    preview.error,
+                );
+                assert_eq!(
+                    response
+                        .messages
+                        .iter()
+                        .map(|sub_msg| sub_msg.msg.to_owned())
+                        .collect::<Vec<_>>(),
+                    preview.messages,
+                    "the preview should list exactly the messages that the execution emits",
+                );
+            }
+            Err(e) => {
+                assert!(
+                    !preview.would_succeed,
+                    "the preview should fail like the execution",
+                );
+                assert_eq!(
+                    Some(e.to_string()),
+                    preview.error,
+                    "the preview should report the execution's error",
+                );
+            }
+        }
+        preview
+    }
+
+    #[test]
+    fn test_preview_rejects_referrers_like_the_execution() {
+        let mut deps = mock_provenance_dependencies_with_custom_querier(get_fund_querier());
+        test_instantiate(&mut deps);
+        let sender = deps.api.addr_make("sender");
+        for (referrer, expected_error) in [
+            (
+                sender.to_string(),
+                "validation failed: an account cannot refer its own trades".to_string(),
+            ),
+            (
+                "not-bech32".to_string(),
+                "validation failed: referrer [not-bech32] is not a valid address".to_string(),
+            ),
+        ] {
+            let preview =
+                assert_preview_matches_execution(&mut deps, &sender, fund_msg(Some(referrer)));
+            assert!(
+                preview
+                    .error
+                    .expect("the referrer should cause an error")
+                    .starts_with(&expected_error),
+                "the preview should report the referrer's rejection",
+            );
+        }
+        let referrer = deps.api.addr_make("referrer");
+        let preview = assert_preview_matches_execution(
+            &mut deps,
+            &sender,
+            fund_msg(Some(referrer.to_string())),
+        );
+        assert!(
+            preview.would_succeed,
+            "a valid referrer should be accepted: {:?}",
+            preview.error,
+        );
+    }
+
+    #[test]
+    fn test_preview_includes_the_msg_fee_message_like_the_execution() {
+        let mut deps = mock_provenance_dependencies_with_custom_querier(get_fund_querier());
+        let fee_recipient = deps.api.addr_make("fee-recipient");
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                msg_fee: Some(MsgFee {
+                    amount: coin(250, "nhash"),
+                    recipient: fee_recipient.to_string(),
+                }),
+                ..InstantiateMsg::default()
+            },
+        );
+        let sender = deps.api.addr_make("sender");
+        let preview = assert_preview_matches_execution(&mut deps, &sender, fund_msg(None));
+        assert_eq!(
+            4,
+            preview.messages.len(),
+            "the three trade messages and the msg fee assessment should be previewed",
+        );
+        assert_eq!(
+            Some(false),
+            preview.requires_approval,
+            "a trade below the threshold should not require approval",
+        );
+    }
+
+    #[test]
+    fn test_preview_of_a_trade_held_for_approval_matches_the_execution() {
+        let mut deps = mock_provenance_dependencies_with_custom_querier(get_fund_querier());
+        let fee_recipient = deps.api.addr_make("fee-recipient");
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                large_trade_threshold: Some(Uint128::new(100)),
+                msg_fee: Some(MsgFee {
+                    amount: coin(250, "nhash"),
+                    recipient: fee_recipient.to_string(),
+                }),
+                ..InstantiateMsg::default()
+            },
+        );
+        let sender = deps.api.addr_make("sender");
+        let preview = assert_preview_matches_execution(&mut deps, &sender, fund_msg(None));
+        assert_eq!(
+            Some(true),
+            preview.requires_approval,
+            "a trade at the threshold should require approval",
+        );
+        assert_eq!(
+            1,
+            preview.messages.len(),
+            "only the msg fee assessment should be previewed for a trade held for approval",
+        );
+    }
+
+    #[test]
+    fn test_preview_of_non_trade_message_is_rejected() {
+        let mut deps =
+            mock_provenance_dependencies_with_custom_querier(MockProvenanceQuerier::new(&[]));
+        test_instantiate(&mut deps);
+        let sender = deps.api.addr_make("sender");
+        let error = query_preview_execute(
+            deps.as_ref(),
+            mock_env(),
+            ExecuteMsg::AdminUpdateAdmin {
+                new_admin_address: "new-admin".to_string(),
+                admin_is_group: false,
+            },
+            sender.to_string(),
+        )
+        .expect_err("previewing a non-trade message should be rejected");
+        assert!(
+            matches!(error, ContractError::ValidationError { .. }),
+            "unexpected error type encountered: {error:?}",
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use crate::store::contract_state::get_contract_state_v1;
+use crate::store::runtime_tunables::get_runtime_tunables;
+use crate::types::error::ContractError;
+use crate::types::quote::TradeDirection;
+use crate::util::quote_utils::derive_trade_quote;
+use cosmwasm_std::{to_json_binary, Binary, Deps, Env, Uint128};
+use result_extensions::ResultExtensions;
+
+/// Produces a [trade quote](crate::types::quote::TradeQuote) describing the conversion that a trade
+/// in the given direction would currently produce.  The quote is not stored; supplying its hash and
+/// height to a trade execution route causes the route to re-derive it and reject the trade if it
+/// differs or has expired.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `direction` The direction of the trade to quote.
+/// * `amount` The amount of input denom to quote.
+pub fn query_quote(
+    deps: Deps,
+    env: Env,
+    direction: TradeDirection,
+    amount: Uint128,
+) -> Result<Binary, ContractError> {
+    let quote = derive_trade_quote(
+        &env,
+        &get_contract_state_v1(deps.storage)?,
+        &get_runtime_tunables(deps.storage)?,
+        direction,
+        amount,
+        env.block.height,
+    )?;
+    to_json_binary(&quote)?.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_quote::query_quote;
+    use crate::test::test_constants::{DEFAULT_DEPOSIT_DENOM_NAME, DEFAULT_TRADING_DENOM_NAME};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::quote::{TradeDirection, TradeQuote};
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{from_json, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_query_with_no_storage() {
+        let deps = mock_provenance_dependencies();
+        query_quote(
+            deps.as_ref(),
+            mock_env(),
+            TradeDirection::Fund,
+            Uint128::new(100),
+        )
+        .expect_err("an error should occur when no contract state has been initialized");
+    }
+
+    #[test]
+    fn test_query_quotes_at_the_current_height() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let env = mock_env();
+        let quote = from_json::<TradeQuote>(
+            &query_quote(
+                deps.as_ref(),
+                env.clone(),
+                TradeDirection::Fund,
+                Uint128::new(100),
+            )
+            .expect("the quote should be produced"),
+        )
+        .expect("the quote binary should properly deserialize");
+        assert_eq!(
+            TradeDirection::Fund,
+            quote.direction,
+            "the quoted direction should be reflected",
+        );
+        assert_eq!(
+            DEFAULT_DEPOSIT_DENOM_NAME, quote.input_denom,
+            "a fund quote should take the deposit denom",
+        );
+        assert_eq!(
+            DEFAULT_TRADING_DENOM_NAME, quote.output_denom,
+            "a fund quote should produce the trading denom",
+        );
+        assert_eq!(
+            Uint128::new(1000000),
+            quote.output_amount,
+            "the output amount should be converted to the trading precision",
+        );
+        assert_eq!(
+            env.block.height, quote.quoted_at_height,
+            "the quote should be produced at the current height",
+        );
+    }
+}
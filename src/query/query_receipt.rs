@@ -0,0 +1,64 @@
+use crate::store::trade_receipts::get_trade_receipt;
+use crate::types::error::ContractError;
+use crate::types::responses::TradeReceiptResponse;
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+/// Fetches the record of the executed trade that was issued the given [receipt](crate::store::trade_receipts::TradeReceipt).
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `receipt` The receipt number returned by the trade's execution.
+pub fn query_receipt(deps: Deps, receipt: u64) -> Result<Binary, ContractError> {
+    let trade_receipt = get_trade_receipt(deps.storage, receipt)?;
+    to_json_binary(&TradeReceiptResponse { trade_receipt })?.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_receipt::query_receipt;
+    use crate::store::trade_receipts::issue_trade_receipt;
+    use crate::types::error::ContractError;
+    use crate::types::quote::TradeDirection;
+    use crate::types::responses::TradeReceiptResponse;
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{coin, from_json, Addr, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_query_missing_receipt() {
+        let deps = mock_provenance_dependencies();
+        let error = query_receipt(deps.as_ref(), 1)
+            .expect_err("an error should occur when no trade holds the receipt");
+        assert!(
+            matches!(&error, ContractError::NotFoundError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn test_query_receipt() {
+        let mut deps = mock_provenance_dependencies();
+        let trade_receipt = issue_trade_receipt(
+            &mut deps.storage,
+            &mock_env().block,
+            TradeDirection::Withdraw,
+            &Addr::unchecked("account"),
+            coin(1000000, "trading"),
+            coin(100, "deposit"),
+            Uint128::zero(),
+        )
+        .expect("issuing a receipt should succeed");
+        let response = from_json::<TradeReceiptResponse>(
+            &query_receipt(deps.as_ref(), 1).expect("the query should succeed"),
+        )
+        .expect("the response binary should properly deserialize");
+        assert_eq!(
+            TradeReceiptResponse { trade_receipt },
+            response,
+            "the stored receipt should be returned",
+        );
+    }
+}
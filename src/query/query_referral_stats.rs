@@ -0,0 +1,69 @@
+use crate::store::referral_stats::get_referral_stats;
+use crate::types::error::ContractError;
+use crate::types::responses::ReferralStatsResponse;
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+/// Fetches the [referral stats](crate::store::referral_stats::ReferralStats) credited to an
+/// account.  Accounts that have never referred a trade receive empty stats.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `referrer` The bech32 address of the referrer to inspect.
+pub fn query_referral_stats(deps: Deps, referrer: String) -> Result<Binary, ContractError> {
+    let referrer = deps.api.addr_validate(&referrer)?;
+    let stats = get_referral_stats(deps.storage, &referrer)?;
+    to_json_binary(&ReferralStatsResponse { referrer, stats })?.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_referral_stats::query_referral_stats;
+    use crate::store::referral_stats::{record_referral, ReferralStats};
+    use crate::types::responses::ReferralStatsResponse;
+    use cosmwasm_std::{from_json, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_query_invalid_referrer() {
+        let deps = mock_provenance_dependencies();
+        query_referral_stats(deps.as_ref(), "not-an-address".to_string())
+            .expect_err("an invalid referrer address should be rejected");
+    }
+
+    #[test]
+    fn test_query_referral_stats() {
+        let mut deps = mock_provenance_dependencies();
+        let referrer = deps.api.addr_make("referrer");
+        let response = from_json::<ReferralStatsResponse>(
+            &query_referral_stats(deps.as_ref(), referrer.to_string())
+                .expect("the query should succeed for a referrer without stats"),
+        )
+        .expect("the response binary should properly deserialize");
+        assert_eq!(
+            ReferralStats::default(),
+            response.stats,
+            "a referrer without any referrals should have empty stats",
+        );
+        record_referral(&mut deps.storage, &referrer, Uint128::new(250))
+            .expect("recording a referral should succeed");
+        let response = from_json::<ReferralStatsResponse>(
+            &query_referral_stats(deps.as_ref(), referrer.to_string())
+                .expect("the query should succeed"),
+        )
+        .expect("the response binary should properly deserialize");
+        assert_eq!(
+            ReferralStatsResponse {
+                referrer,
+                stats: ReferralStats {
+                    trade_count: 1,
+                    volume: Uint128::new(250),
+                },
+            },
+            response,
+            "the recorded stats should be returned",
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use crate::store::contract_state::get_contract_state_v1;
+use crate::types::error::ContractError;
+use crate::types::responses::RequiredGrantsResponse;
+use crate::util::diagnostics_utils::find_required_grants;
+use cosmwasm_std::{to_json_binary, Binary, Deps, Env};
+use result_extensions::ResultExtensions;
+
+/// Inspects both configured markers and returns the access grants that the contract lacks for the
+/// trade routes to function, each with a ready-to-encode message that adds the missing
+/// permissions.  The permissions are diffed with the same logic as the marker grant checks of the
+/// [admin_run_diagnostics](crate::execute::admin_run_diagnostics::admin_run_diagnostics) route.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+pub fn query_required_grants(deps: Deps, env: Env) -> Result<Binary, ContractError> {
+    let contract_state = get_contract_state_v1(deps.storage)?;
+    to_json_binary(&RequiredGrantsResponse {
+        grants: find_required_grants(&deps, &env, &contract_state)?,
+    })?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_required_grants::query_required_grants;
+    use crate::test::test_constants::{DEFAULT_DEPOSIT_DENOM_NAME, DEFAULT_TRADING_DENOM_NAME};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::diagnostics::{AccessGrantMsg, AddAccessMsg, RequiredGrant};
+    use crate::types::responses::RequiredGrantsResponse;
+    use cosmwasm_std::from_json;
+    use cosmwasm_std::testing::{mock_env, MockApi, MOCK_CONTRACT_ADDR};
+    use prost::Message;
+    use provwasm_mocks::{mock_provenance_dependencies_with_custom_querier, MockProvenanceQuerier};
+    use provwasm_std::shim::Any;
+    use provwasm_std::types::cosmos::auth::v1beta1::BaseAccount;
+    use provwasm_std::types::provenance::marker::v1::{
+        Access, AccessGrant, MarkerAccount, MarkerStatus, MarkerType, QueryMarkerRequest,
+        QueryMarkerResponse,
+    };
+
+    fn mock_marker(querier: &mut MockProvenanceQuerier, grants: Vec<Access>) {
+        QueryMarkerRequest::mock_response(
+            querier,
+            QueryMarkerResponse {
+                marker: Some(Any {
+                    type_url: "/provenance.marker.v1.MarkerAccount".to_string(),
+                    value: MarkerAccount {
+                        base_account: Some(BaseAccount {
+                            address: MockApi::default().addr_make("marker").to_string(),
+                            pub_key: None,
+                            account_number: 312,
+                            sequence: 68,
+                        }),
+                        manager: "some-manager".to_string(),
+                        access_control: vec![AccessGrant {
+                            address: MOCK_CONTRACT_ADDR.to_string(),
+                            permissions: grants.into_iter().map(|grant| grant as i32).collect(),
+                        }],
+                        status: MarkerStatus::Active as i32,
+                        denom: "marker".to_string(),
+                        supply: "100".to_string(),
+                        marker_type: MarkerType::Restricted as i32,
+                        supply_fixed: false,
+                        allow_governance_control: false,
+                        allow_forced_transfer: false,
+                        required_attributes: vec![],
+                    }
+                    .encode_to_vec(),
+                }),
+            },
+        );
+    }
+
+    #[test]
+    fn test_query_with_no_storage() {
+        let deps =
+            mock_provenance_dependencies_with_custom_querier(MockProvenanceQuerier::new(&[]));
+        query_required_grants(deps.as_ref(), mock_env())
+            .expect_err("an error should occur when no contract state has been initialized");
+    }
+
+    #[test]
+    fn test_query_reports_only_missing_permissions() {
+        let mut deps =
+            mock_provenance_dependencies_with_custom_querier(MockProvenanceQuerier::new(&[]));
+        test_instantiate(&mut deps);
+        // Instantiation requires every grant, so the grants are revoked after the contract is created
+        mock_marker(&mut deps.querier, vec![Access::Transfer, Access::Mint]);
+        let response = from_json::<RequiredGrantsResponse>(
+            &query_required_grants(deps.as_ref(), mock_env())
+                .expect("the required grants should be produced"),
+        )
+        .expect("the required grants binary should properly deserialize");
+        assert_eq!(
+            vec![RequiredGrant {
+                marker_denom: DEFAULT_TRADING_DENOM_NAME.to_string(),
+                missing_permissions: vec!["ACCESS_BURN".to_string(), "ACCESS_WITHDRAW".to_string()],
+                type_url: "/provenance.marker.v1.MsgAddAccessRequest".to_string(),
+                msg: AddAccessMsg {
+                    denom: DEFAULT_TRADING_DENOM_NAME.to_string(),
+                    administrator: "some-manager".to_string(),
+                    access: vec![AccessGrantMsg {
+                        address: MOCK_CONTRACT_ADDR.to_string(),
+                        permissions: vec!["ACCESS_BURN".to_string(), "ACCESS_WITHDRAW".to_string(),],
+                    }],
+                },
+            }],
+            response.grants,
+            "only the trading marker should need a grant, for only its missing permissions",
+        );
+        assert!(
+            response
+                .grants
+                .iter()
+                .all(|grant| grant.marker_denom != DEFAULT_DEPOSIT_DENOM_NAME),
+            "the deposit marker should not need a grant when transfer is held",
+        );
+    }
+
+    #[test]
+    fn test_query_reports_no_grants_when_all_permissions_are_held() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        mock_marker(
+            &mut querier,
+            vec![
+                Access::Mint,
+                Access::Burn,
+                Access::Withdraw,
+                Access::Transfer,
+            ],
+        );
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        test_instantiate(&mut deps);
+        let response = from_json::<RequiredGrantsResponse>(
+            &query_required_grants(deps.as_ref(), mock_env())
+                .expect("the required grants should be produced"),
+        )
+        .expect("the required grants binary should properly deserialize");
+        assert!(
+            response.grants.is_empty(),
+            "no grants should be needed when every permission is held",
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use crate::store::runtime_tunables::get_runtime_tunables;
+use crate::types::error::ContractError;
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+/// Fetches the current [runtime tunables](crate::store::runtime_tunables::RuntimeTunables), which
+/// the contract admin adjusts via the [admin_set_tunables](crate::execute::admin_set_tunables::admin_set_tunables)
+/// route.  Contracts that have never stored tunables report the default values.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+pub fn query_runtime_tunables(deps: Deps) -> Result<Binary, ContractError> {
+    to_json_binary(&get_runtime_tunables(deps.storage)?)?.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_runtime_tunables::query_runtime_tunables;
+    use crate::store::runtime_tunables::{set_runtime_tunables, RuntimeTunables};
+    use crate::test::test_instantiate::test_instantiate;
+    use cosmwasm_std::from_json;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_query_with_no_storage() {
+        let deps = mock_provenance_dependencies();
+        assert_eq!(
+            RuntimeTunables::default(),
+            from_json::<RuntimeTunables>(
+                &query_runtime_tunables(deps.as_ref())
+                    .expect("the default tunables should be returned when none are stored"),
+            )
+            .expect("the tunables binary should properly deserialize"),
+            "the default tunables should be reported when none are stored",
+        );
+    }
+
+    #[test]
+    fn test_query_with_stored_tunables() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let tunables = RuntimeTunables {
+            attribute_page_size: 25,
+            attribute_max_pages: 4,
+            ..RuntimeTunables::default()
+        };
+        set_runtime_tunables(&mut deps.storage, &tunables).expect("the tunables should be stored");
+        assert_eq!(
+            tunables,
+            from_json::<RuntimeTunables>(
+                &query_runtime_tunables(deps.as_ref())
+                    .expect("the tunables binary should load from query"),
+            )
+            .expect("the tunables binary should properly deserialize"),
+            "the stored tunables should be reported",
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use crate::store::contract_state::get_contract_state_v1;
+use crate::types::error::ContractError;
+use crate::types::responses::FundTradingSimulationResponse;
+use crate::util::conversion_utils::convert_denom;
+use cosmwasm_std::{to_json_binary, Binary, Deps, Uint128};
+use result_extensions::ResultExtensions;
+
+/// Converts the given amount of deposit denom to trading denom exactly as the [fund_trading](crate::execute::fund_trading::fund_trading)
+/// route would, allowing a client to display the trade's outcome before it is signed.  Unlike the
+/// [simulate_trade](crate::query::query_simulate_trade::query_simulate_trade) query, no account is
+/// involved, so balances, attributes and every other account check are ignored.  An amount too
+/// small to convert to any trading denom is reported through the response's
+/// [would_fail](FundTradingSimulationResponse#would_fail) flag rather than as an error.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `trade_amount` The amount of deposit denom to convert.
+pub fn query_simulate_fund_trading(
+    deps: Deps,
+    trade_amount: Uint128,
+) -> Result<Binary, ContractError> {
+    let contract_state = get_contract_state_v1(deps.storage)?;
+    let conversion = convert_denom(
+        trade_amount,
+        &contract_state.deposit_marker,
+        &contract_state.trading_marker,
+    )?;
+    let failure = if conversion.target_amount.is_zero() {
+        Some(
+            ContractError::InvalidFundsError {
+                message: format!(
+                    "sent [{}{}], but that is not enough to convert to at least one [{}]",
+                    trade_amount,
+                    &contract_state.deposit_marker.name,
+                    &contract_state.trading_marker.name,
+                ),
+            }
+            .to_string(),
+        )
+    } else {
+        None
+    };
+    to_json_binary(&FundTradingSimulationResponse {
+        input_amount: trade_amount,
+        output_amount: conversion.target_amount,
+        remainder: conversion.remainder,
+        input_denom: contract_state.deposit_marker.name,
+        output_denom: contract_state.trading_marker.name,
+        would_fail: failure.is_some(),
+        failure,
+    })?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_simulate_fund_trading::query_simulate_fund_trading;
+    use crate::test::test_constants::{DEFAULT_DEPOSIT_DENOM_NAME, DEFAULT_TRADING_DENOM_NAME};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::responses::FundTradingSimulationResponse;
+    use cosmwasm_std::{from_json, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_query_with_no_storage() {
+        let deps = mock_provenance_dependencies();
+        query_simulate_fund_trading(deps.as_ref(), Uint128::new(100))
+            .expect_err("an error should occur when no contract state has been initialized");
+    }
+
+    #[test]
+    fn test_query_simulates_the_conversion() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let simulation = from_json::<FundTradingSimulationResponse>(
+            &query_simulate_fund_trading(deps.as_ref(), Uint128::new(150))
+                .expect("the simulation should be produced"),
+        )
+        .expect("the simulation binary should properly deserialize");
+        assert_eq!(
+            FundTradingSimulationResponse {
+                input_amount: Uint128::new(150),
+                output_amount: Uint128::new(1_500_000),
+                remainder: Uint128::zero(),
+                input_denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+                output_denom: DEFAULT_TRADING_DENOM_NAME.to_string(),
+                would_fail: false,
+                failure: None,
+            },
+            simulation,
+            "the conversion should be simulated from the deposit denom to the trading denom",
+        );
+    }
+
+    #[test]
+    fn test_query_reports_a_conversion_without_output() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let simulation = from_json::<FundTradingSimulationResponse>(
+            &query_simulate_fund_trading(deps.as_ref(), Uint128::zero())
+                .expect("the simulation should be produced"),
+        )
+        .expect("the simulation binary should properly deserialize");
+        assert!(
+            simulation.would_fail,
+            "a conversion without output should be reported as failing",
+        );
+        assert_eq!(
+            Uint128::zero(),
+            simulation.output_amount,
+            "no trading denom should be produced",
+        );
+        assert_eq!(
+            Some(format!(
+                "invalid funds: sent [0{DEFAULT_DEPOSIT_DENOM_NAME}], but that is not enough to convert to at least one [{DEFAULT_TRADING_DENOM_NAME}]"
+            )),
+            simulation.failure,
+            "the failure should match the error produced by fund_trading",
+        );
+    }
+}
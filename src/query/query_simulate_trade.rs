@@ -0,0 +1,402 @@
+use crate::execute::fund_trading::plan_fund_trading;
+use crate::execute::withdraw_trading::plan_withdraw_trading;
+use crate::store::contract_state::get_contract_state_v1;
+use crate::types::error::ContractError;
+use crate::types::quote::TradeDirection;
+use crate::types::responses::TradeSimulationResponse;
+use crate::types::trade_options::TradeOptions;
+use crate::types::trade_plan::TradePlan;
+use cosmwasm_std::{to_json_binary, Addr, Binary, Deps, Env, Uint128};
+use result_extensions::ResultExtensions;
+
+/// Walks the full pipeline of the trade execution route for the given direction on behalf of the
+/// given sender, using the same plan functions as the execution routes.  If the trade would
+/// succeed, the response contains its expected amounts, whether or not its remainder must be
+/// acknowledged, whether or not it would be held for admin approval, and the msg fee after any
+/// discount earned by the sender's attributes.  Otherwise, the response contains every failure
+/// that the execution would report.  No state is modified by this query.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `sender` The bech32 address of the account that would request the trade.
+/// * `direction` The direction of the trade to simulate.
+/// * `amount` The amount of input denom that the trade would request.
+pub fn query_simulate_trade(
+    deps: Deps,
+    env: Env,
+    sender: String,
+    direction: TradeDirection,
+    amount: Uint128,
+) -> Result<Binary, ContractError> {
+    let sender = deps.api.addr_validate(&sender)?;
+    let response = match simulate_trade(&deps, &env, &sender, direction, amount) {
+        Ok(plan) => TradeSimulationResponse {
+            sender,
+            direction,
+            requested_amount: amount,
+            would_succeed: true,
+            collected_amount: Some(plan.collected_amount),
+            output_amount: Some(plan.output_amount()),
+            remainder: Some(plan.conversion.remainder),
+            remainder_requires_acknowledgment: Some(plan.requires_remainder_acknowledgment()),
+            requires_approval: Some(plan.requires_approval),
+            msg_fee: plan.msg_fee.map(|msg_fee| msg_fee.amount),
+            fee_discount: plan.fee_discount,
+            failures: vec![],
+        },
+        Err(e) => TradeSimulationResponse {
+            sender,
+            direction,
+            requested_amount: amount,
+            would_succeed: false,
+            collected_amount: None,
+            output_amount: None,
+            remainder: None,
+            remainder_requires_acknowledgment: None,
+            requires_approval: None,
+            msg_fee: None,
+            fee_discount: None,
+            failures: match e {
+                ContractError::PreconditionsFailed { failures } => failures,
+                e => vec![e.to_string()],
+            },
+        },
+    };
+    to_json_binary(&response)?.to_ok()
+}
+
+fn simulate_trade(
+    deps: &Deps,
+    env: &Env,
+    sender: &Addr,
+    direction: TradeDirection,
+    amount: Uint128,
+) -> Result<TradePlan, ContractError> {
+    let contract_state = get_contract_state_v1(deps.storage)?;
+    match direction {
+        TradeDirection::Fund => plan_fund_trading(
+            deps,
+            env,
+            &contract_state,
+            sender,
+            amount,
+            &TradeOptions::default(),
+        ),
+        TradeDirection::Withdraw => plan_withdraw_trading(
+            deps,
+            env,
+            &contract_state,
+            sender,
+            amount,
+            &TradeOptions::default(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_simulate_trade::query_simulate_trade;
+    use crate::store::contract_state::{get_contract_state_v1, set_contract_state_v1};
+    use crate::test::test_constants::{
+        DEFAULT_DEPOSIT_DENOM_NAME, DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE, DEFAULT_TRADING_DENOM_NAME,
+    };
+    use crate::test::test_instantiate::{test_instantiate, test_instantiate_with_msg};
+    use crate::test::test_markers::{
+        mock_marker_params_response, mock_marker_response, test_marker_account,
+    };
+    use crate::types::denom::Denom;
+    use crate::types::msg::InstantiateMsg;
+    use crate::types::msg_fee::{FeeDiscount, MsgFee};
+    use crate::types::quote::TradeDirection;
+    use crate::types::responses::TradeSimulationResponse;
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{coin, from_json, Uint128};
+    use provwasm_mocks::{mock_provenance_dependencies_with_custom_querier, MockProvenanceQuerier};
+    use provwasm_std::types::cosmos::bank::v1beta1::{QueryBalanceRequest, QueryBalanceResponse};
+    use provwasm_std::types::cosmos::base::v1beta1::Coin;
+    use provwasm_std::types::provenance::attribute::v1::{
+        Attribute, AttributeType, QueryAttributesRequest, QueryAttributesResponse,
+    };
+
+    fn get_fund_querier(balance: &str, attributes: &[&str]) -> MockProvenanceQuerier {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(
+            &mut querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    amount: balance.to_string(),
+                    denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+                }),
+            },
+        );
+        QueryAttributesRequest::mock_response(
+            &mut querier,
+            QueryAttributesResponse {
+                account: "sender".to_string(),
+                attributes: attributes
+                    .iter()
+                    .map(|name| Attribute {
+                        name: name.to_string(),
+                        value: vec![],
+                        attribute_type: AttributeType::String as i32,
+                        address: "addr".to_string(),
+                        expiration_date: None,
+                    })
+                    .collect(),
+                pagination: None,
+            },
+        );
+        mock_marker_response(
+            &mut querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        mock_marker_params_response(&mut querier, "");
+        querier
+    }
+
+    #[test]
+    fn test_simulation_of_successful_fund_trading() {
+        let mut deps = mock_provenance_dependencies_with_custom_querier(get_fund_querier(
+            "150",
+            &[DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE],
+        ));
+        let fee_recipient = deps.api.addr_make("fee-recipient");
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                large_trade_threshold: Some(Uint128::new(100)),
+                msg_fee: Some(MsgFee {
+                    amount: coin(250, "nhash"),
+                    recipient: fee_recipient.to_string(),
+                }),
+                ..InstantiateMsg::default()
+            },
+        );
+        let sender = deps.api.addr_make("sender");
+        let simulation = from_json::<TradeSimulationResponse>(
+            &query_simulate_trade(
+                deps.as_ref(),
+                mock_env(),
+                sender.to_string(),
+                TradeDirection::Fund,
+                Uint128::new(150),
+            )
+            .expect("the simulation query should succeed"),
+        )
+        .expect("the simulation response should deserialize");
+        assert!(
+            simulation.would_succeed,
+            "the simulation should indicate success: {:?}",
+            simulation.failures,
+        );
+        assert_eq!(sender, simulation.sender, "the sender should be reflected");
+        assert_eq!(
+            TradeDirection::Fund,
+            simulation.direction,
+            "the direction should be reflected",
+        );
+        assert_eq!(
+            Some(Uint128::new(150)),
+            simulation.collected_amount,
+            "the full amount should be collected because there is no remainder",
+        );
+        assert_eq!(
+            Some(Uint128::new(1500000)),
+            simulation.output_amount,
+            "the output amount should be converted to the trading precision",
+        );
+        assert_eq!(
+            Some(false),
+            simulation.remainder_requires_acknowledgment,
+            "a trade without a remainder should not require acknowledgment",
+        );
+        assert_eq!(
+            Some(true),
+            simulation.requires_approval,
+            "a trade above the large trade threshold should require approval",
+        );
+        assert_eq!(
+            Some(coin(250, "nhash")),
+            simulation.msg_fee,
+            "the configured msg fee should be reported",
+        );
+        assert!(
+            simulation.failures.is_empty(),
+            "no failures should be populated",
+        );
+    }
+
+    #[test]
+    fn test_simulation_reflects_the_sender_fee_discount() {
+        let mut deps = mock_provenance_dependencies_with_custom_querier(get_fund_querier(
+            "150",
+            &[DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE, "loyalty.gold.pb"],
+        ));
+        let fee_recipient = deps.api.addr_make("fee-recipient");
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                msg_fee: Some(MsgFee {
+                    amount: coin(250, "nhash"),
+                    recipient: fee_recipient.to_string(),
+                }),
+                ..InstantiateMsg::default()
+            },
+        );
+        let gold_discount = FeeDiscount {
+            attribute: "loyalty.gold.pb".to_string(),
+            discount_bps_off: 5000,
+        };
+        let mut contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        contract_state.fee_discounts = vec![gold_discount.to_owned()];
+        set_contract_state_v1(&mut deps.storage, &contract_state)
+            .expect("contract state should be updated");
+        let simulation = from_json::<TradeSimulationResponse>(
+            &query_simulate_trade(
+                deps.as_ref(),
+                mock_env(),
+                deps.api.addr_make("sender").to_string(),
+                TradeDirection::Fund,
+                Uint128::new(150),
+            )
+            .expect("the simulation query should succeed"),
+        )
+        .expect("the simulation response should deserialize");
+        assert!(
+            simulation.would_succeed,
+            "the simulation should indicate success: {:?}",
+            simulation.failures,
+        );
+        assert_eq!(
+            Some(coin(125, "nhash")),
+            simulation.msg_fee,
+            "the discounted msg fee should be reported",
+        );
+        assert_eq!(
+            Some(gold_discount),
+            simulation.fee_discount,
+            "the applied discount should be reported",
+        );
+    }
+
+    #[test]
+    fn test_simulation_reports_every_failed_precondition() {
+        let mut deps = mock_provenance_dependencies_with_custom_querier(get_fund_querier("9", &[]));
+        test_instantiate(&mut deps);
+        let sender = deps.api.addr_make("sender");
+        let simulation = from_json::<TradeSimulationResponse>(
+            &query_simulate_trade(
+                deps.as_ref(),
+                mock_env(),
+                sender.to_string(),
+                TradeDirection::Fund,
+                Uint128::new(10),
+            )
+            .expect("the simulation query should succeed even when the trade would fail"),
+        )
+        .expect("the simulation response should deserialize");
+        assert!(
+            !simulation.would_succeed,
+            "the simulation should indicate failure",
+        );
+        assert_eq!(
+            vec![
+                "invalid account: account does not have all required attributes".to_string(),
+                "invalid account: required [10], but account only holds [9]".to_string(),
+            ],
+            simulation.failures,
+            "every failed precondition should be listed separately",
+        );
+        assert!(
+            simulation.collected_amount.is_none()
+                && simulation.output_amount.is_none()
+                && simulation.remainder.is_none()
+                && simulation.requires_approval.is_none()
+                && simulation.msg_fee.is_none(),
+            "no outcome should be populated when the trade would fail",
+        );
+    }
+
+    #[test]
+    fn test_simulation_reports_a_remainder_requiring_acknowledgment() {
+        let mut deps = mock_provenance_dependencies_with_custom_querier(get_fund_querier(
+            "103",
+            &[DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE],
+        ));
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                deposit_marker: Denom::new(DEFAULT_DEPOSIT_DENOM_NAME, 2),
+                trading_marker: Denom::new(DEFAULT_TRADING_DENOM_NAME, 1),
+                ..InstantiateMsg::default()
+            },
+        );
+        let sender = deps.api.addr_make("sender");
+        let simulation = from_json::<TradeSimulationResponse>(
+            &query_simulate_trade(
+                deps.as_ref(),
+                mock_env(),
+                sender.to_string(),
+                TradeDirection::Fund,
+                Uint128::new(103),
+            )
+            .expect("the simulation query should succeed"),
+        )
+        .expect("the simulation response should deserialize");
+        assert!(
+            simulation.would_succeed,
+            "the simulation should indicate success: {:?}",
+            simulation.failures,
+        );
+        assert_eq!(
+            Some(Uint128::new(3)),
+            simulation.remainder,
+            "the unconvertible remainder should be reported",
+        );
+        assert_eq!(
+            Some(true),
+            simulation.remainder_requires_acknowledgment,
+            "the remainder should be reported as requiring acknowledgment",
+        );
+    }
+
+    #[test]
+    fn test_simulation_reports_conversion_failures() {
+        let mut deps = mock_provenance_dependencies_with_custom_querier(get_fund_querier(
+            "10000",
+            &[DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE],
+        ));
+        test_instantiate(&mut deps);
+        let sender = deps.api.addr_make("sender");
+        let simulation = from_json::<TradeSimulationResponse>(
+            &query_simulate_trade(
+                deps.as_ref(),
+                mock_env(),
+                sender.to_string(),
+                TradeDirection::Withdraw,
+                Uint128::new(9999),
+            )
+            .expect("the simulation query should succeed even when the trade would fail"),
+        )
+        .expect("the simulation response should deserialize");
+        assert!(
+            !simulation.would_succeed,
+            "the simulation should indicate failure",
+        );
+        assert_eq!(
+            1,
+            simulation.failures.len(),
+            "the conversion failure should be the only failure listed",
+        );
+        assert!(
+            simulation.failures[0].starts_with("invalid funds"),
+            "unexpected failure listed: {:?}",
+            simulation.failures,
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use crate::store::contract_state::get_contract_state_v1;
+use crate::types::error::ContractError;
+use crate::types::responses::WithdrawTradingSimulationResponse;
+use crate::util::conversion_utils::convert_denom;
+use cosmwasm_std::{to_json_binary, Binary, Deps, Uint128};
+use result_extensions::ResultExtensions;
+
+/// Converts the given amount of trading denom to deposit denom exactly as the [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading)
+/// route would, allowing an integrator to verify that a withdrawal produces deposit denom before
+/// it is executed.  No account is involved, so balances, attributes and every other account check
+/// are ignored.  An amount too small to convert to any deposit denom is reported through the
+/// response's [would_fail](WithdrawTradingSimulationResponse#would_fail) flag rather than as an
+/// error.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `trade_amount` The amount of trading denom to convert.
+pub fn query_simulate_withdraw_trading(
+    deps: Deps,
+    trade_amount: Uint128,
+) -> Result<Binary, ContractError> {
+    let contract_state = get_contract_state_v1(deps.storage)?;
+    let conversion = convert_denom(
+        trade_amount,
+        &contract_state.trading_marker,
+        &contract_state.deposit_marker,
+    )?;
+    let failure = if conversion.target_amount.is_zero() {
+        Some(
+            ContractError::InvalidFundsError {
+                message: format!(
+                    "sent [{}{}], but that is not enough to convert to at least one [{}]",
+                    trade_amount,
+                    &contract_state.trading_marker.name,
+                    &contract_state.deposit_marker.name,
+                ),
+            }
+            .to_string(),
+        )
+    } else {
+        None
+    };
+    to_json_binary(&WithdrawTradingSimulationResponse {
+        input_amount: trade_amount,
+        collected_amount: trade_amount.checked_sub(conversion.remainder)?,
+        output_amount: conversion.target_amount,
+        remainder: conversion.remainder,
+        input_denom: contract_state.trading_marker.name,
+        output_denom: contract_state.deposit_marker.name,
+        would_fail: failure.is_some(),
+        failure,
+    })?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_simulate_withdraw_trading::query_simulate_withdraw_trading;
+    use crate::test::test_constants::{DEFAULT_DEPOSIT_DENOM_NAME, DEFAULT_TRADING_DENOM_NAME};
+    use crate::test::test_instantiate::{test_instantiate, test_instantiate_with_msg};
+    use crate::types::denom::Denom;
+    use crate::types::msg::InstantiateMsg;
+    use crate::types::responses::WithdrawTradingSimulationResponse;
+    use cosmwasm_std::{from_json, Deps, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    fn simulate(deps: Deps, trade_amount: u128) -> WithdrawTradingSimulationResponse {
+        from_json::<WithdrawTradingSimulationResponse>(
+            &query_simulate_withdraw_trading(deps, Uint128::new(trade_amount))
+                .expect("the simulation should be produced"),
+        )
+        .expect("the simulation binary should properly deserialize")
+    }
+
+    #[test]
+    fn test_query_with_no_storage() {
+        let deps = mock_provenance_dependencies();
+        query_simulate_withdraw_trading(deps.as_ref(), Uint128::new(100))
+            .expect_err("an error should occur when no contract state has been initialized");
+    }
+
+    #[test]
+    fn test_query_simulates_the_conversion() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        assert_eq!(
+            WithdrawTradingSimulationResponse {
+                input_amount: Uint128::new(10_000_000),
+                collected_amount: Uint128::new(10_000_000),
+                output_amount: Uint128::new(1000),
+                remainder: Uint128::zero(),
+                input_denom: DEFAULT_TRADING_DENOM_NAME.to_string(),
+                output_denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+                would_fail: false,
+                failure: None,
+            },
+            simulate(deps.as_ref(), 10_000_000),
+            "the conversion should be simulated from the trading denom to the deposit denom",
+        );
+    }
+
+    #[test]
+    fn test_query_reports_the_remainder_left_behind() {
+        let mut deps = mock_provenance_dependencies();
+        // 4321 trading denom equates to 4.321, but the deposit marker's precision of 2 only holds
+        // 4.32, leaving 1 trading denom behind
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                deposit_marker: Denom::new(DEFAULT_DEPOSIT_DENOM_NAME, 2),
+                trading_marker: Denom::new(DEFAULT_TRADING_DENOM_NAME, 3),
+                ..InstantiateMsg::default()
+            },
+        );
+        let simulation = simulate(deps.as_ref(), 4321);
+        assert_eq!(
+            Uint128::new(432),
+            simulation.output_amount,
+            "the convertible amount should be produced",
+        );
+        assert_eq!(
+            Uint128::new(1),
+            simulation.remainder,
+            "the unconvertible amount should be reported as the remainder",
+        );
+        assert_eq!(
+            Uint128::new(4320),
+            simulation.collected_amount,
+            "only the convertible amount should be collected",
+        );
+        assert!(
+            !simulation.would_fail,
+            "a conversion with output should not fail",
+        );
+    }
+
+    #[test]
+    fn test_query_reports_a_conversion_without_output() {
+        let mut deps = mock_provenance_dependencies();
+        // Input 7 == 0.07, but the deposit marker can only hold values with one decimal place
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                deposit_marker: Denom::new("denom1", 1),
+                trading_marker: Denom::new("denom2", 2),
+                ..InstantiateMsg::default()
+            },
+        );
+        let simulation = simulate(deps.as_ref(), 7);
+        assert!(
+            simulation.would_fail,
+            "a conversion without output should be reported as failing",
+        );
+        assert_eq!(
+            Uint128::zero(),
+            simulation.output_amount,
+            "no deposit denom should be produced",
+        );
+        assert_eq!(
+            Uint128::new(7),
+            simulation.remainder,
+            "the entire amount should be reported as the remainder",
+        );
+        assert_eq!(
+            Uint128::zero(),
+            simulation.collected_amount,
+            "nothing should be collected",
+        );
+        assert_eq!(
+            Some(
+                "invalid funds: sent [7denom2], but that is not enough to convert to at least one [denom1]"
+                    .to_string()
+            ),
+            simulation.failure,
+            "the failure should match the error produced by withdraw_trading",
+        );
+    }
+
+    #[test]
+    fn test_query_with_equal_precisions() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                deposit_marker: Denom::new(DEFAULT_DEPOSIT_DENOM_NAME, 4),
+                trading_marker: Denom::new(DEFAULT_TRADING_DENOM_NAME, 4),
+                ..InstantiateMsg::default()
+            },
+        );
+        let simulation = simulate(deps.as_ref(), 7);
+        assert_eq!(
+            Uint128::new(7),
+            simulation.output_amount,
+            "equal precisions should convert one to one",
+        );
+        assert_eq!(
+            Uint128::zero(),
+            simulation.remainder,
+            "equal precisions should never leave a remainder",
+        );
+    }
+}
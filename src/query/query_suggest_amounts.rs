@@ -0,0 +1,226 @@
+use crate::store::contract_state::get_contract_state_v1;
+use crate::types::error::ContractError;
+use crate::types::quote::TradeDirection;
+use crate::types::responses::SuggestAmountsResponse;
+use crate::util::conversion_utils::suggest_amounts;
+use cosmwasm_std::{to_json_binary, Binary, Deps, Uint128};
+use result_extensions::ResultExtensions;
+
+/// Finds the trade amounts nearest to the given amount that would convert without leaving a
+/// remainder in the sender's account and fall within the minimum and maximum trade amounts of the
+/// direction, allowing a client to suggest corrections before a trade is submitted.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `direction` The direction of the trade for which amounts are suggested.
+/// * `amount` The amount of input denom entered for the trade.
+pub fn query_suggest_amounts(
+    deps: Deps,
+    direction: TradeDirection,
+    amount: Uint128,
+) -> Result<Binary, ContractError> {
+    let contract_state = get_contract_state_v1(deps.storage)?;
+    let suggestion = match direction {
+        TradeDirection::Fund => suggest_amounts(
+            amount,
+            &contract_state.deposit_marker,
+            &contract_state.trading_marker,
+            contract_state.min_fund_amount,
+            contract_state.max_fund_amount,
+        ),
+        TradeDirection::Withdraw => suggest_amounts(
+            amount,
+            &contract_state.trading_marker,
+            &contract_state.deposit_marker,
+            contract_state.min_withdraw_amount,
+            contract_state.max_withdraw_amount,
+        ),
+    }?;
+    to_json_binary(&SuggestAmountsResponse {
+        direction,
+        amount,
+        suggestion,
+    })?
+    .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_suggest_amounts::query_suggest_amounts;
+    use crate::store::contract_state::{get_contract_state_v1, set_contract_state_v1};
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::types::quote::TradeDirection;
+    use crate::types::responses::SuggestAmountsResponse;
+    use cosmwasm_std::{from_json, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_query_with_no_storage() {
+        let deps = mock_provenance_dependencies();
+        query_suggest_amounts(deps.as_ref(), TradeDirection::Fund, Uint128::new(100))
+            .expect_err("an error should occur when no contract state has been initialized");
+    }
+
+    #[test]
+    fn test_query_suggests_amounts_for_each_direction() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let withdraw = from_json::<SuggestAmountsResponse>(
+            &query_suggest_amounts(
+                deps.as_ref(),
+                TradeDirection::Withdraw,
+                Uint128::new(1234567),
+            )
+            .expect("the suggestions should be produced"),
+        )
+        .expect("the suggestion binary should properly deserialize");
+        assert_eq!(
+            TradeDirection::Withdraw,
+            withdraw.direction,
+            "the direction should be reflected",
+        );
+        assert_eq!(
+            Some(Uint128::new(1230000)),
+            withdraw.suggestion.lower_amount,
+            "the withdraw amount should be trimmed to the deposit precision",
+        );
+        assert_eq!(
+            Some(Uint128::new(4567)),
+            withdraw.suggestion.lower_delta,
+            "the lower delta should be the unconvertible remainder",
+        );
+        assert_eq!(
+            Some(Uint128::new(1240000)),
+            withdraw.suggestion.upper_amount,
+            "the next convertible withdraw amount should be suggested",
+        );
+        assert_eq!(
+            Some(Uint128::new(5433)),
+            withdraw.suggestion.upper_delta,
+            "the upper delta should be the distance to the next convertible amount",
+        );
+        let fund = from_json::<SuggestAmountsResponse>(
+            &query_suggest_amounts(deps.as_ref(), TradeDirection::Fund, Uint128::new(150))
+                .expect("the suggestions should be produced"),
+        )
+        .expect("the suggestion binary should properly deserialize");
+        assert_eq!(
+            Some(Uint128::new(150)),
+            fund.suggestion.lower_amount,
+            "every deposit amount converts to trading denom without a remainder",
+        );
+        assert_eq!(
+            Some(Uint128::new(151)),
+            fund.suggestion.upper_amount,
+            "the next deposit amount should be suggested",
+        );
+    }
+
+    #[test]
+    fn test_query_omits_a_lower_amount_below_the_minimum() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let mut contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        contract_state.min_withdraw_amount = Some(Uint128::new(1235000));
+        contract_state.min_fund_amount = Some(Uint128::new(200));
+        set_contract_state_v1(&mut deps.storage, &contract_state)
+            .expect("contract state should be stored");
+        let withdraw = from_json::<SuggestAmountsResponse>(
+            &query_suggest_amounts(
+                deps.as_ref(),
+                TradeDirection::Withdraw,
+                Uint128::new(1234567),
+            )
+            .expect("the suggestions should be produced"),
+        )
+        .expect("the suggestion binary should properly deserialize");
+        assert_eq!(
+            None, withdraw.suggestion.lower_amount,
+            "the clean lower amount of 1230000 is below the minimum and should not be suggested",
+        );
+        assert_eq!(
+            None, withdraw.suggestion.lower_delta,
+            "no lower delta should be produced without a lower amount",
+        );
+        assert_eq!(
+            Some(Uint128::new(1240000)),
+            withdraw.suggestion.upper_amount,
+            "the next convertible amount above the minimum should be suggested",
+        );
+        let fund = from_json::<SuggestAmountsResponse>(
+            &query_suggest_amounts(deps.as_ref(), TradeDirection::Fund, Uint128::new(150))
+                .expect("the suggestions should be produced"),
+        )
+        .expect("the suggestion binary should properly deserialize");
+        assert_eq!(
+            None, fund.suggestion.lower_amount,
+            "no deposit amount at or below the request reaches the minimum",
+        );
+        assert_eq!(
+            Some(Uint128::new(200)),
+            fund.suggestion.upper_amount,
+            "the minimum itself should be suggested when the request falls below it",
+        );
+        assert_eq!(
+            Some(Uint128::new(50)),
+            fund.suggestion.upper_delta,
+            "the upper delta should be the distance to the minimum",
+        );
+    }
+
+    #[test]
+    fn test_query_clamps_suggestions_to_the_maximum() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let mut contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        contract_state.max_withdraw_amount = Some(Uint128::new(1239999));
+        contract_state.max_fund_amount = Some(Uint128::new(120));
+        set_contract_state_v1(&mut deps.storage, &contract_state)
+            .expect("contract state should be stored");
+        let withdraw = from_json::<SuggestAmountsResponse>(
+            &query_suggest_amounts(
+                deps.as_ref(),
+                TradeDirection::Withdraw,
+                Uint128::new(1234567),
+            )
+            .expect("the suggestions should be produced"),
+        )
+        .expect("the suggestion binary should properly deserialize");
+        assert_eq!(
+            Some(Uint128::new(1230000)),
+            withdraw.suggestion.lower_amount,
+            "the lower amount is within the maximum and should still be suggested",
+        );
+        assert_eq!(
+            None, withdraw.suggestion.upper_amount,
+            "the clean upper amount of 1240000 exceeds the maximum and should not be suggested",
+        );
+        assert_eq!(
+            None, withdraw.suggestion.upper_delta,
+            "no upper delta should be produced without an upper amount",
+        );
+        let fund = from_json::<SuggestAmountsResponse>(
+            &query_suggest_amounts(deps.as_ref(), TradeDirection::Fund, Uint128::new(150))
+                .expect("the suggestions should be produced"),
+        )
+        .expect("the suggestion binary should properly deserialize");
+        assert_eq!(
+            Some(Uint128::new(120)),
+            fund.suggestion.lower_amount,
+            "the maximum itself should be suggested when the request exceeds it",
+        );
+        assert_eq!(
+            Some(Uint128::new(30)),
+            fund.suggestion.lower_delta,
+            "the lower delta should be the distance to the maximum",
+        );
+        assert_eq!(
+            None, fund.suggestion.upper_amount,
+            "no deposit amount above the request is within the maximum",
+        );
+    }
+}
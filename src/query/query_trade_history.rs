@@ -0,0 +1,123 @@
+use crate::store::trade_receipts::get_trade_receipts;
+use crate::types::error::ContractError;
+use crate::types::responses::TradeHistoryResponse;
+use cosmwasm_std::{to_json_binary, Binary, Deps};
+use result_extensions::ResultExtensions;
+
+/// The amount of trade receipts returned when the query does not specify a limit.
+pub const DEFAULT_TRADE_HISTORY_LIMIT: u32 = 30;
+/// The largest amount of trade receipts returned by a single query.
+pub const MAX_TRADE_HISTORY_LIMIT: u32 = 100;
+
+/// Fetches a page of the [receipts](crate::store::trade_receipts::TradeReceipt) issued to executed
+/// trades, ordered from oldest to newest unless the newest trades are requested first.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `start_after` If provided, only receipts ordered after the receipt with this number are
+/// returned.
+/// * `limit` The maximum amount of receipts to return.  Defaults to [DEFAULT_TRADE_HISTORY_LIMIT]
+/// and is capped at [MAX_TRADE_HISTORY_LIMIT].
+/// * `descending` If true, receipts are ordered from newest to oldest.
+pub fn query_trade_history(
+    deps: Deps,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+    descending: Option<bool>,
+) -> Result<Binary, ContractError> {
+    let limit = limit
+        .unwrap_or(DEFAULT_TRADE_HISTORY_LIMIT)
+        .min(MAX_TRADE_HISTORY_LIMIT);
+    let trades = get_trade_receipts(
+        deps.storage,
+        start_after,
+        limit as usize,
+        descending.unwrap_or(false),
+    )?;
+    to_json_binary(&TradeHistoryResponse { trades })?.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::query::query_trade_history::{
+        query_trade_history, DEFAULT_TRADE_HISTORY_LIMIT, MAX_TRADE_HISTORY_LIMIT,
+    };
+    use crate::store::trade_receipts::issue_trade_receipt;
+    use crate::types::quote::TradeDirection;
+    use crate::types::responses::TradeHistoryResponse;
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{coin, from_json, Addr, Deps, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    fn query_ids(
+        deps: Deps,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+        descending: Option<bool>,
+    ) -> Vec<u64> {
+        from_json::<TradeHistoryResponse>(
+            &query_trade_history(deps, start_after, limit, descending)
+                .expect("the query should succeed"),
+        )
+        .expect("the response binary should properly deserialize")
+        .trades
+        .into_iter()
+        .map(|trade_receipt| trade_receipt.receipt)
+        .collect()
+    }
+
+    #[test]
+    fn test_query_with_no_trades() {
+        let deps = mock_provenance_dependencies();
+        assert!(
+            query_ids(deps.as_ref(), None, None, None).is_empty(),
+            "no trades should be returned before any trade executes",
+        );
+    }
+
+    #[test]
+    fn test_query_pages_in_either_order_and_caps_the_limit() {
+        let mut deps = mock_provenance_dependencies();
+        let block = mock_env().block;
+        let trade_count = u64::from(MAX_TRADE_HISTORY_LIMIT) + 5;
+        for _ in 0..trade_count {
+            issue_trade_receipt(
+                &mut deps.storage,
+                &block,
+                TradeDirection::Fund,
+                &Addr::unchecked("sender"),
+                coin(100, "input"),
+                coin(10, "output"),
+                Uint128::zero(),
+            )
+            .expect("the receipt should be issued");
+        }
+        assert_eq!(
+            (1..=u64::from(DEFAULT_TRADE_HISTORY_LIMIT)).collect::<Vec<u64>>(),
+            query_ids(deps.as_ref(), None, None, None),
+            "the default page should hold the oldest trades",
+        );
+        assert_eq!(
+            vec![trade_count, trade_count - 1],
+            query_ids(deps.as_ref(), None, Some(2), Some(true)),
+            "a descending page should hold the newest trades",
+        );
+        assert_eq!(
+            vec![3, 2, 1],
+            query_ids(deps.as_ref(), Some(4), Some(10), Some(true)),
+            "a descending page should continue before the given receipt",
+        );
+        assert_eq!(
+            vec![5, 6],
+            query_ids(deps.as_ref(), Some(4), Some(2), Some(false)),
+            "an ascending page should continue after the given receipt",
+        );
+        assert_eq!(
+            MAX_TRADE_HISTORY_LIMIT as usize,
+            query_ids(deps.as_ref(), None, Some(u32::MAX), None).len(),
+            "the limit should be capped",
+        );
+    }
+}
@@ -0,0 +1,5 @@
+//! Contains the functionality used in the [contract file](crate::contract) to handle the replies
+//! requested by submessages that the contract emits.
+
+/// Translates the failure of a trade's submessage into an error that names the failed step.
+pub mod trade_step_reply;
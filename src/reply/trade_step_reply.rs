@@ -0,0 +1,94 @@
+use crate::types::error::ContractError;
+use crate::types::trade_step::TradeStep;
+use cosmwasm_std::{Reply, Response, SubMsgResult};
+use result_extensions::ResultExtensions;
+
+/// Handles the reply requested by one of a trade's submessages.  Trade submessages only reply when
+/// they fail, so a failure is translated into a [TradeStepError](ContractError::TradeStepError)
+/// that names the [step](TradeStep) that failed, the most likely causes of the failure, and the
+/// original error.  Returning the error reverts the entire trade.  A
+/// [NotFoundError](ContractError::NotFoundError) is returned for a reply that no trade step
+/// requests.
+///
+/// # Parameters
+/// * `msg` The reply produced by the submessage.
+pub fn handle_trade_step_reply(msg: Reply) -> Result<Response, ContractError> {
+    let Some(step) = TradeStep::from_reply_id(msg.id) else {
+        return ContractError::NotFoundError {
+            message: format!("no trade step requests replies with id [{}]", msg.id),
+        }
+        .to_err();
+    };
+    match msg.result {
+        SubMsgResult::Err(e) => ContractError::TradeStepError {
+            step: step.name().to_string(),
+            message: format!("{}: {e}", step.failure_hint()),
+        }
+        .to_err(),
+        // A successful step requires no follow-up, and leaves the trade's response unchanged
+        SubMsgResult::Ok(_) => Response::new().to_ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::reply::trade_step_reply::handle_trade_step_reply;
+    use crate::types::error::ContractError;
+    use crate::types::trade_step::TradeStep;
+    use cosmwasm_std::{Binary, Reply, SubMsgResult};
+
+    fn failed_reply(id: u64, error: &str) -> Reply {
+        Reply {
+            id,
+            payload: Binary::default(),
+            gas_used: 0,
+            result: SubMsgResult::Err(error.to_string()),
+        }
+    }
+
+    #[test]
+    fn failed_replies_should_name_each_step() {
+        for (step, name) in [
+            (TradeStep::Collect, "collect"),
+            (TradeStep::Mint, "mint"),
+            (TradeStep::Withdraw, "withdraw"),
+            (TradeStep::Burn, "burn"),
+            (TradeStep::Release, "release"),
+        ] {
+            let error =
+                handle_trade_step_reply(failed_reply(step.reply_id(), "insufficient funds"))
+                    .expect_err("a failed reply should produce an error");
+            match &error {
+                ContractError::TradeStepError {
+                    step: error_step,
+                    message,
+                } => {
+                    assert_eq!(name, error_step, "the error should name the failed step");
+                    assert_eq!(
+                        format!("{}: insufficient funds", step.failure_hint()),
+                        *message,
+                        "the error should describe the step's likely causes and include the original error",
+                    );
+                }
+                _ => panic!("unexpected error encountered: {error:?}"),
+            }
+            assert!(
+                error
+                    .to_string()
+                    .starts_with(&format!("trade step [{name}] failed: ")),
+                "unexpected error message encountered: {error}",
+            );
+        }
+    }
+
+    #[test]
+    fn unknown_reply_id_should_cause_an_error() {
+        let error = handle_trade_step_reply(failed_reply(99, "insufficient funds"))
+            .expect_err("a reply that no step requests should produce an error");
+        assert_eq!(
+            "not found: no trade step requests replies with id [99]",
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+    }
+}
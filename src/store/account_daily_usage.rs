@@ -0,0 +1,261 @@
+use crate::store::trade_stats::{day_of, SECONDS_PER_DAY};
+use crate::types::error::ContractError;
+use cosmwasm_std::{Addr, Storage, Timestamp, Uint128};
+use cw_storage_plus::Map;
+use result_extensions::ResultExtensions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const NAMESPACE_ACCOUNT_DAILY_USAGE: &str = "account_daily_usage";
+const ACCOUNT_DAILY_USAGE: Map<&Addr, DailyUsage> = Map::new(NAMESPACE_ACCOUNT_DAILY_USAGE);
+
+/// The value that an account has traded during a single UTC day, which is compared against the
+/// contract's [per-account daily limit](crate::store::contract_state::ContractStateV1#per_account_daily_limit).
+/// Only each account's current day is stored, and its amount resets automatically once a block
+/// falls on a later day.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+pub struct DailyUsage {
+    /// The index of the UTC day that the usage covers, as derived by [day_of].
+    pub day: u64,
+    /// The combined value of the account's trades in both directions during the day, expressed as
+    /// an amount of deposit denom.
+    pub amount: Uint128,
+}
+impl DailyUsage {
+    /// The time at which the usage's day ends, and a new allowance becomes available.
+    pub fn resets_at(&self) -> Timestamp {
+        Timestamp::from_seconds(self.day.saturating_add(1).saturating_mul(SECONDS_PER_DAY))
+    }
+}
+
+/// Fetches the [DailyUsage] of an account for the day containing the given block time.  Empty
+/// usage is returned when the account has not traded during that day.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+/// fetches.
+/// * `account` The account for which to fetch usage.
+/// * `time` The block time for which to fetch usage.
+pub fn get_account_daily_usage(
+    storage: &dyn Storage,
+    account: &Addr,
+    time: &Timestamp,
+) -> Result<DailyUsage, ContractError> {
+    let day = day_of(time);
+    ACCOUNT_DAILY_USAGE
+        .may_load(storage, account)
+        .map(|usage| {
+            usage
+                .filter(|usage| usage.day == day)
+                .unwrap_or(DailyUsage {
+                    day,
+                    amount: Uint128::zero(),
+                })
+        })
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })
+}
+
+/// Verifies that adding a trade to an account's usage for the day containing the given block time
+/// would not exceed the per-account daily limit.  An [AccountDailyLimitExceededError](ContractError::AccountDailyLimitExceededError)
+/// naming the remaining allowance and its reset time is returned otherwise.  Returns the usage
+/// that the account would have after the trade.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+/// fetches.
+/// * `account` The account whose funds are traded.
+/// * `time` The block time at which the trade executes.
+/// * `daily_limit` The largest value that a single account may trade during a day, if any.
+/// * `deposit_amount` The value of the trade, expressed as an amount of deposit denom.
+/// * `denom` The deposit denom, used to describe the amounts in the error.
+pub fn check_account_daily_limit(
+    storage: &dyn Storage,
+    account: &Addr,
+    time: &Timestamp,
+    daily_limit: Option<Uint128>,
+    deposit_amount: Uint128,
+    denom: &str,
+) -> Result<DailyUsage, ContractError> {
+    let current = get_account_daily_usage(storage, account, time)?;
+    let amount = current.amount.checked_add(deposit_amount)?;
+    if let Some(daily_limit) = daily_limit {
+        if amount > daily_limit {
+            return ContractError::AccountDailyLimitExceededError {
+                message: format!(
+                    "trade valued at [{deposit_amount}{denom}], but account [{account}] only has [{}{denom}] of its daily limit of [{daily_limit}{denom}] remaining until [{}]",
+                    daily_limit.saturating_sub(current.amount),
+                    current.resets_at().seconds(),
+                ),
+            }
+            .to_err();
+        }
+    }
+    DailyUsage {
+        day: current.day,
+        amount,
+    }
+    .to_ok()
+}
+
+/// Adds an executed trade to an account's usage for the day containing the given block time,
+/// returning an [AccountDailyLimitExceededError](ContractError::AccountDailyLimitExceededError)
+/// instead if the per-account daily limit would be exceeded.  Nothing is stored when no limit is
+/// configured.  Returns the account's updated usage.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+/// manipulation.
+/// * `account` The account whose funds were traded.
+/// * `time` The block time at which the trade executed.
+/// * `daily_limit` The largest value that a single account may trade during a day, if any.
+/// * `deposit_amount` The value of the trade, expressed as an amount of deposit denom.
+/// * `denom` The deposit denom, used to describe the amounts in the error.
+pub fn record_account_daily_usage(
+    storage: &mut dyn Storage,
+    account: &Addr,
+    time: &Timestamp,
+    daily_limit: Option<Uint128>,
+    deposit_amount: Uint128,
+    denom: &str,
+) -> Result<DailyUsage, ContractError> {
+    let usage =
+        check_account_daily_limit(storage, account, time, daily_limit, deposit_amount, denom)?;
+    if daily_limit.is_some() {
+        ACCOUNT_DAILY_USAGE
+            .save(storage, account, &usage)
+            .map_err(|e| ContractError::StorageError {
+                message: format!("{e:?}"),
+            })?;
+    }
+    usage.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::account_daily_usage::{
+        check_account_daily_limit, get_account_daily_usage, record_account_daily_usage, DailyUsage,
+    };
+    use crate::store::trade_stats::SECONDS_PER_DAY;
+    use crate::types::error::ContractError;
+    use cosmwasm_std::{Addr, Timestamp, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_record_account_daily_usage_up_to_the_limit() {
+        let mut deps = mock_provenance_dependencies();
+        let account = Addr::unchecked("account");
+        let other = Addr::unchecked("other");
+        let time = Timestamp::from_seconds(SECONDS_PER_DAY * 20 + 100);
+        let limit = Some(Uint128::new(500));
+        record_account_daily_usage(
+            &mut deps.storage,
+            &account,
+            &time,
+            limit,
+            Uint128::new(300),
+            "deposit",
+        )
+        .expect("a trade below the limit should be recorded");
+        let usage = record_account_daily_usage(
+            &mut deps.storage,
+            &account,
+            &time,
+            limit,
+            Uint128::new(200),
+            "deposit",
+        )
+        .expect("a trade reaching the exact limit should be recorded");
+        assert_eq!(
+            DailyUsage {
+                day: 20,
+                amount: Uint128::new(500),
+            },
+            usage,
+            "the usage should equal the limit",
+        );
+        assert_eq!(
+            Timestamp::from_seconds(SECONDS_PER_DAY * 21),
+            usage.resets_at(),
+            "the usage should reset at the start of the next day",
+        );
+        let error = check_account_daily_limit(
+            &deps.storage,
+            &account,
+            &time,
+            limit,
+            Uint128::one(),
+            "deposit",
+        )
+        .expect_err("a trade beyond the limit should be rejected");
+        assert_eq!(
+            format!(
+                "account daily limit exceeded: trade valued at [1deposit], but account [account] only has [0deposit] of its daily limit of [500deposit] remaining until [{}]",
+                SECONDS_PER_DAY * 21,
+            ),
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+        record_account_daily_usage(
+            &mut deps.storage,
+            &other,
+            &time,
+            limit,
+            Uint128::new(500),
+            "deposit",
+        )
+        .expect("the limit should apply to each account separately");
+        let error = record_account_daily_usage(
+            &mut deps.storage,
+            &account,
+            &time.plus_seconds(SECONDS_PER_DAY - 101),
+            limit,
+            Uint128::one(),
+            "deposit",
+        )
+        .expect_err("the final second of the day should still be limited");
+        assert!(
+            matches!(error, ContractError::AccountDailyLimitExceededError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+        assert_eq!(
+            DailyUsage {
+                day: 21,
+                amount: Uint128::zero(),
+            },
+            get_account_daily_usage(
+                &deps.storage,
+                &account,
+                &Timestamp::from_seconds(SECONDS_PER_DAY * 21),
+            )
+            .expect("fetching usage should succeed"),
+            "the usage should reset at the first second of the next day",
+        );
+    }
+
+    #[test]
+    fn test_record_account_daily_usage_without_a_limit() {
+        let mut deps = mock_provenance_dependencies();
+        let account = Addr::unchecked("account");
+        let time = Timestamp::from_seconds(0);
+        record_account_daily_usage(
+            &mut deps.storage,
+            &account,
+            &time,
+            None,
+            Uint128::MAX,
+            "deposit",
+        )
+        .expect("any amount should be accepted when no limit is set");
+        assert_eq!(
+            DailyUsage::default(),
+            get_account_daily_usage(&deps.storage, &account, &time)
+                .expect("fetching usage should succeed"),
+            "no usage should be stored when no limit is set",
+        );
+    }
+}
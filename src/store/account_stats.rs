@@ -0,0 +1,154 @@
+use crate::types::error::ContractError;
+use crate::types::quote::TradeDirection;
+use cosmwasm_std::{Addr, Storage, Uint128};
+use cw_storage_plus::Map;
+use result_extensions::ResultExtensions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const NAMESPACE_ACCOUNT_STATS: &str = "account_stats";
+const ACCOUNT_STATS: Map<&Addr, AccountStats> = Map::new(NAMESPACE_ACCOUNT_STATS);
+
+/// The volume of trades that an account has executed against the contract.  Amounts are expressed
+/// as an amount of deposit denom: the amount collected by each fund, and the amount released by
+/// each withdraw.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+pub struct AccountStats {
+    /// The amount of fund trades executed by the account.
+    pub fund_count: u64,
+    /// The combined value of the account's fund trades.
+    pub total_funded: Uint128,
+    /// The amount of withdraw trades executed by the account.
+    pub withdraw_count: u64,
+    /// The combined value of the account's withdraw trades.
+    pub total_withdrawn: Uint128,
+    /// The block height at which the account's most recent trade executed.  Zero if the account
+    /// has never traded.
+    pub last_trade_height: u64,
+}
+
+/// Credits an executed trade to the trading account's stats, incrementing the count and adding
+/// the trade's value to the total of the trade's direction.  Returns the account's updated stats.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+/// manipulation.
+/// * `account` The account whose funds were traded.
+/// * `direction` The direction of the trade.
+/// * `deposit_amount` The value of the trade, expressed as an amount of deposit denom.
+/// * `block_height` The block height at which the trade executed.
+pub fn record_account_trade(
+    storage: &mut dyn Storage,
+    account: &Addr,
+    direction: TradeDirection,
+    deposit_amount: Uint128,
+    block_height: u64,
+) -> Result<AccountStats, ContractError> {
+    let stats = get_account_stats(storage, account)?;
+    let stats = match direction {
+        TradeDirection::Fund => AccountStats {
+            fund_count: stats.fund_count.saturating_add(1),
+            total_funded: stats.total_funded.checked_add(deposit_amount)?,
+            last_trade_height: block_height,
+            ..stats
+        },
+        TradeDirection::Withdraw => AccountStats {
+            withdraw_count: stats.withdraw_count.saturating_add(1),
+            total_withdrawn: stats.total_withdrawn.checked_add(deposit_amount)?,
+            last_trade_height: block_height,
+            ..stats
+        },
+    };
+    ACCOUNT_STATS
+        .save(storage, account, &stats)
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })?;
+    stats.to_ok()
+}
+
+/// Fetches the trade stats for an account.  Accounts that have never traded receive empty stats.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+/// fetches.
+/// * `account` The account for which to fetch stats.
+pub fn get_account_stats(
+    storage: &dyn Storage,
+    account: &Addr,
+) -> Result<AccountStats, ContractError> {
+    ACCOUNT_STATS
+        .may_load(storage, account)
+        .map(|stats| stats.unwrap_or_default())
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::account_stats::{get_account_stats, record_account_trade, AccountStats};
+    use crate::types::quote::TradeDirection;
+    use cosmwasm_std::{Addr, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_record_and_get_account_stats() {
+        let mut deps = mock_provenance_dependencies();
+        let account = Addr::unchecked("account");
+        assert_eq!(
+            AccountStats::default(),
+            get_account_stats(&deps.storage, &account)
+                .expect("fetching stats for an unknown account should succeed"),
+            "an account that never traded should have empty stats",
+        );
+        record_account_trade(
+            &mut deps.storage,
+            &account,
+            TradeDirection::Fund,
+            Uint128::new(100),
+            10,
+        )
+        .expect("recording the first fund should succeed");
+        record_account_trade(
+            &mut deps.storage,
+            &account,
+            TradeDirection::Fund,
+            Uint128::new(50),
+            11,
+        )
+        .expect("recording the second fund should succeed");
+        let stats = record_account_trade(
+            &mut deps.storage,
+            &account,
+            TradeDirection::Withdraw,
+            Uint128::new(30),
+            12,
+        )
+        .expect("recording the withdraw should succeed");
+        assert_eq!(
+            AccountStats {
+                fund_count: 2,
+                total_funded: Uint128::new(150),
+                withdraw_count: 1,
+                total_withdrawn: Uint128::new(30),
+                last_trade_height: 12,
+            },
+            stats,
+            "each trade should accumulate into the totals of its direction",
+        );
+        assert_eq!(
+            stats,
+            get_account_stats(&deps.storage, &account).expect("fetching stats should succeed"),
+            "the recorded stats should be persisted",
+        );
+        assert_eq!(
+            AccountStats::default(),
+            get_account_stats(&deps.storage, &Addr::unchecked("other"))
+                .expect("fetching stats for another account should succeed"),
+            "trades should only be credited to the trading account",
+        );
+    }
+}
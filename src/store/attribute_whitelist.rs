@@ -0,0 +1,177 @@
+use crate::types::error::ContractError;
+use cosmwasm_std::{Addr, Order, Storage};
+use cw_storage_plus::{Bound, Map};
+use result_extensions::ResultExtensions;
+
+const NAMESPACE_ATTRIBUTE_WHITELIST: &str = "attribute_whitelist";
+const ATTRIBUTE_WHITELIST: Map<&Addr, ()> = Map::new(NAMESPACE_ATTRIBUTE_WHITELIST);
+
+/// Adds an account to the attribute whitelist, allowing it to trade without holding the
+/// [required_deposit_attributes](crate::store::contract_state::ContractStateV1#required_deposit_attributes)
+/// or the [required_withdraw_attributes](crate::store::contract_state::ContractStateV1#required_withdraw_attributes).
+/// A [ValidationError](ContractError::ValidationError) is returned if the account is already
+/// whitelisted.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+/// manipulation.
+/// * `account` The account to whitelist.
+pub fn add_whitelisted_account(
+    storage: &mut dyn Storage,
+    account: &Addr,
+) -> Result<(), ContractError> {
+    if is_whitelisted_account(storage, account)? {
+        return ContractError::ValidationError {
+            message: format!("account [{account}] is already whitelisted"),
+        }
+        .to_err();
+    }
+    ATTRIBUTE_WHITELIST
+        .save(storage, account, &())
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })
+}
+
+/// Removes an account from the attribute whitelist, causing the required attributes to be checked
+/// on its subsequent trades.  A [NotFoundError](ContractError::NotFoundError) is returned if the
+/// account is not whitelisted.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+/// manipulation.
+/// * `account` The account to remove from the whitelist.
+pub fn remove_whitelisted_account(
+    storage: &mut dyn Storage,
+    account: &Addr,
+) -> Result<(), ContractError> {
+    if !is_whitelisted_account(storage, account)? {
+        return ContractError::NotFoundError {
+            message: format!("account [{account}] is not whitelisted"),
+        }
+        .to_err();
+    }
+    ATTRIBUTE_WHITELIST.remove(storage, account);
+    ().to_ok()
+}
+
+/// Determines whether or not an account is on the attribute whitelist.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+/// fetches.
+/// * `account` The account to check.
+pub fn is_whitelisted_account(
+    storage: &dyn Storage,
+    account: &Addr,
+) -> Result<bool, ContractError> {
+    ATTRIBUTE_WHITELIST
+        .may_load(storage, account)
+        .map(|entry| entry.is_some())
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })
+}
+
+/// Fetches every whitelisted account, ordered by address.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+/// fetches.
+/// * `start_after` If provided, only accounts with an address ordered after this value are
+/// returned.
+/// * `limit` The maximum amount of accounts to return.
+pub fn get_whitelisted_accounts(
+    storage: &dyn Storage,
+    start_after: Option<&Addr>,
+    limit: usize,
+) -> Result<Vec<Addr>, ContractError> {
+    ATTRIBUTE_WHITELIST
+        .keys(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|result| {
+            result.map_err(|e| ContractError::StorageError {
+                message: format!("{e:?}"),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::attribute_whitelist::{
+        add_whitelisted_account, get_whitelisted_accounts, is_whitelisted_account,
+        remove_whitelisted_account,
+    };
+    use crate::types::error::ContractError;
+    use cosmwasm_std::Addr;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_add_and_remove_whitelisted_accounts() {
+        let mut deps = mock_provenance_dependencies();
+        let account = Addr::unchecked("account");
+        assert!(
+            !is_whitelisted_account(&deps.storage, &account)
+                .expect("checking the whitelist should succeed"),
+            "an unknown account should not be whitelisted",
+        );
+        add_whitelisted_account(&mut deps.storage, &account)
+            .expect("whitelisting an account should succeed");
+        assert!(
+            is_whitelisted_account(&deps.storage, &account)
+                .expect("checking the whitelist should succeed"),
+            "the added account should be whitelisted",
+        );
+        let error = add_whitelisted_account(&mut deps.storage, &account)
+            .expect_err("whitelisting an account twice should fail");
+        assert_eq!(
+            "validation failed: account [account] is already whitelisted",
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+        remove_whitelisted_account(&mut deps.storage, &account)
+            .expect("removing a whitelisted account should succeed");
+        assert!(
+            !is_whitelisted_account(&deps.storage, &account)
+                .expect("checking the whitelist should succeed"),
+            "the removed account should no longer be whitelisted",
+        );
+        let error = remove_whitelisted_account(&mut deps.storage, &account)
+            .expect_err("removing a missing account should fail");
+        assert!(
+            matches!(error, ContractError::NotFoundError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn test_get_whitelisted_accounts_pages_in_order() {
+        let mut deps = mock_provenance_dependencies();
+        for name in ["third", "first", "second"] {
+            add_whitelisted_account(&mut deps.storage, &Addr::unchecked(name))
+                .expect("whitelisting an account should succeed");
+        }
+        assert_eq!(
+            vec![Addr::unchecked("first"), Addr::unchecked("second")],
+            get_whitelisted_accounts(&deps.storage, None, 2)
+                .expect("fetching the whitelist should succeed"),
+            "the first page should be ordered by address",
+        );
+        assert_eq!(
+            vec![Addr::unchecked("third")],
+            get_whitelisted_accounts(&deps.storage, Some(&Addr::unchecked("second")), 2)
+                .expect("fetching the whitelist should succeed"),
+            "the next page should start after the given address",
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use crate::types::error::ContractError;
+use cosmwasm_std::{Addr, Order, Storage, Timestamp};
+use cw_storage_plus::{Item, Map};
+use result_extensions::ResultExtensions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const NAMESPACE_AUDIT_LOG_SEQUENCE: &str = "audit_log_sequence";
+const NAMESPACE_AUDIT_LOG: &str = "audit_log";
+const AUDIT_LOG_SEQUENCE: Item<u64> = Item::new(NAMESPACE_AUDIT_LOG_SEQUENCE);
+const AUDIT_LOG: Map<u64, AuditEntry> = Map::new(NAMESPACE_AUDIT_LOG);
+
+/// A single record of an administrative action taken against the contract.  Entries are only ever
+/// appended, never modified or removed.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct AuditEntry {
+    /// The sequential identifier assigned to this entry when it was appended.
+    pub id: u64,
+    /// The name of the action that produced this entry.
+    pub action: String,
+    /// The bech32 address of the account that performed the action.
+    pub actor: Addr,
+    /// True when the contract admin was a [group](crate::store::contract_state::ContractStateV1#admin_is_group)
+    /// at the time of the action, meaning that the [actor](AuditEntry#actor) is the group's policy
+    /// address and the action was executed from a passed group proposal.  The member that executed
+    /// the proposal is not visible to this contract, so it must be looked up in the group's own
+    /// proposal history.
+    #[serde(default)]
+    pub via_group_policy: bool,
+    /// True when the [actor](AuditEntry#actor) is the chain's [gov_authority](crate::store::contract_state::ContractStateV1#gov_authority),
+    /// acting in place of the contract admin.
+    #[serde(default)]
+    pub via_gov_authority: bool,
+    /// The block time at which the action was performed.
+    pub timestamp: Timestamp,
+    /// The block height at which the action was performed.
+    pub block_height: u64,
+    /// Free-form lines describing the findings or changes produced by the action.
+    pub details: Vec<String>,
+}
+
+/// Appends a new entry to the audit log, assigning it the next sequential identifier.  The
+/// identifier of the entry's input value is ignored.  Returns the stored entry.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+/// manipulation.
+/// * `entry` The entry to append.
+pub fn append_audit_entry(
+    storage: &mut dyn Storage,
+    entry: AuditEntry,
+) -> Result<AuditEntry, ContractError> {
+    let id = AUDIT_LOG_SEQUENCE
+        .may_load(storage)
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })?
+        .unwrap_or_default()
+        + 1;
+    let entry = AuditEntry { id, ..entry };
+    AUDIT_LOG_SEQUENCE
+        .save(storage, &id)
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })?;
+    AUDIT_LOG
+        .save(storage, id, &entry)
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })?;
+    entry.to_ok()
+}
+
+/// Fetches all entries in the audit log, ordered from oldest to newest.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+/// fetches.
+pub fn get_audit_entries(storage: &dyn Storage) -> Result<Vec<AuditEntry>, ContractError> {
+    AUDIT_LOG
+        .range(storage, None, None, Order::Ascending)
+        .map(|result| {
+            result
+                .map(|(_, entry)| entry)
+                .map_err(|e| ContractError::StorageError {
+                    message: format!("{e:?}"),
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::audit_log::{append_audit_entry, get_audit_entries, AuditEntry};
+    use cosmwasm_std::{Addr, Timestamp};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    fn entry(action: &str) -> AuditEntry {
+        AuditEntry {
+            id: 0,
+            action: action.to_string(),
+            actor: Addr::unchecked("admin"),
+            via_group_policy: false,
+            via_gov_authority: false,
+            timestamp: Timestamp::from_seconds(100),
+            block_height: 12,
+            details: vec!["some detail".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_append_and_get_audit_entries() {
+        let mut deps = mock_provenance_dependencies();
+        assert!(
+            get_audit_entries(&deps.storage)
+                .expect("fetching an empty audit log should succeed")
+                .is_empty(),
+            "no entries should exist before any are appended",
+        );
+        let first = append_audit_entry(&mut deps.storage, entry("first"))
+            .expect("appending the first entry should succeed");
+        let second = append_audit_entry(&mut deps.storage, entry("second"))
+            .expect("appending the second entry should succeed");
+        assert_eq!(1, first.id, "the first entry should receive the first id");
+        assert_eq!(2, second.id, "the second entry should receive the next id");
+        assert_eq!(
+            vec![first, second],
+            get_audit_entries(&deps.storage).expect("fetching the audit log should succeed"),
+            "all appended entries should be returned in order",
+        );
+    }
+}
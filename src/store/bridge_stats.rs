@@ -0,0 +1,141 @@
+use crate::types::error::ContractError;
+use crate::types::quote::TradeDirection;
+use cosmwasm_std::{Storage, Uint128};
+use cw_storage_plus::Item;
+use result_extensions::ResultExtensions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const NAMESPACE_BRIDGE_STATS: &str = "bridge_stats";
+const BRIDGE_STATS: Item<BridgeStats> = Item::new(NAMESPACE_BRIDGE_STATS);
+
+/// Lifetime counters of the funds moved across the bridge.  Because every fund mints the trading
+/// denom it delivers and every withdraw burns the trading denom it collects, the difference
+/// between [total_trading_minted](BridgeStats#total_trading_minted) and [total_trading_burned](BridgeStats#total_trading_burned)
+/// can be reconciled against the trading marker's supply without replaying any trade history.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+pub struct BridgeStats {
+    /// The amount of deposit denom collected by every executed fund trade.
+    pub total_deposit_accepted: Uint128,
+    /// The amount of trading denom minted by every executed fund trade.
+    pub total_trading_minted: Uint128,
+    /// The amount of trading denom burned by every executed withdraw trade.
+    pub total_trading_burned: Uint128,
+    /// The amount of deposit denom released by every executed withdraw trade.
+    pub total_deposit_released: Uint128,
+    /// The amount of executed fund trades.
+    pub fund_count: u64,
+    /// The amount of executed withdraw trades.
+    pub withdraw_count: u64,
+}
+
+/// Adds an executed trade to the [BridgeStats].  Returns the updated stats.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+/// manipulation.
+/// * `direction` The direction of the trade.
+/// * `input_amount` The amount of input denom collected from the trading account: deposit denom
+/// when funding, and trading denom when withdrawing.
+/// * `output_amount` The amount of output denom delivered to the trading account: trading denom
+/// when funding, and deposit denom when withdrawing.
+pub fn record_bridge_trade(
+    storage: &mut dyn Storage,
+    direction: TradeDirection,
+    input_amount: Uint128,
+    output_amount: Uint128,
+) -> Result<BridgeStats, ContractError> {
+    let stats = get_bridge_stats(storage)?;
+    let stats = match direction {
+        TradeDirection::Fund => BridgeStats {
+            total_deposit_accepted: stats.total_deposit_accepted.checked_add(input_amount)?,
+            total_trading_minted: stats.total_trading_minted.checked_add(output_amount)?,
+            fund_count: stats.fund_count.saturating_add(1),
+            ..stats
+        },
+        TradeDirection::Withdraw => BridgeStats {
+            total_trading_burned: stats.total_trading_burned.checked_add(input_amount)?,
+            total_deposit_released: stats.total_deposit_released.checked_add(output_amount)?,
+            withdraw_count: stats.withdraw_count.saturating_add(1),
+            ..stats
+        },
+    };
+    BRIDGE_STATS
+        .save(storage, &stats)
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })?;
+    stats.to_ok()
+}
+
+/// Fetches the [BridgeStats].  Empty stats are returned before any trade has been recorded.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+/// fetches.
+pub fn get_bridge_stats(storage: &dyn Storage) -> Result<BridgeStats, ContractError> {
+    BRIDGE_STATS
+        .may_load(storage)
+        .map(|stats| stats.unwrap_or_default())
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::bridge_stats::{get_bridge_stats, record_bridge_trade, BridgeStats};
+    use crate::types::quote::TradeDirection;
+    use cosmwasm_std::Uint128;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_record_and_get_bridge_stats() {
+        let mut deps = mock_provenance_dependencies();
+        assert_eq!(
+            BridgeStats::default(),
+            get_bridge_stats(&deps.storage).expect("fetching empty stats should succeed"),
+            "empty stats should be returned before any trade is recorded",
+        );
+        record_bridge_trade(
+            &mut deps.storage,
+            TradeDirection::Fund,
+            Uint128::new(100),
+            Uint128::new(1_000_000),
+        )
+        .expect("recording a fund should succeed");
+        record_bridge_trade(
+            &mut deps.storage,
+            TradeDirection::Withdraw,
+            Uint128::new(300_000),
+            Uint128::new(30),
+        )
+        .expect("recording a withdraw should succeed");
+        let stats = record_bridge_trade(
+            &mut deps.storage,
+            TradeDirection::Fund,
+            Uint128::new(5),
+            Uint128::new(50_000),
+        )
+        .expect("recording a second fund should succeed");
+        assert_eq!(
+            BridgeStats {
+                total_deposit_accepted: Uint128::new(105),
+                total_trading_minted: Uint128::new(1_050_000),
+                total_trading_burned: Uint128::new(300_000),
+                total_deposit_released: Uint128::new(30),
+                fund_count: 2,
+                withdraw_count: 1,
+            },
+            stats,
+            "each trade should add to the counters of its direction",
+        );
+        assert_eq!(
+            stats,
+            get_bridge_stats(&deps.storage).expect("fetching stats should succeed"),
+            "the recorded stats should be persisted",
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use crate::types::error::ContractError;
+use cosmwasm_std::Storage;
+use cw_storage_plus::Item;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const NAMESPACE_BULK_ATTRIBUTE_UPDATE: &str = "bulk_attribute_update";
+const BULK_ATTRIBUTE_UPDATE: Item<StagedAttributeUpdate> =
+    Item::new(NAMESPACE_BULK_ATTRIBUTE_UPDATE);
+
+/// The progress of a chunked [AdminBulkUpdateAttributes](crate::types::msg::ExecuteMsg::AdminBulkUpdateAttributes)
+/// operation.  Staged lists are held here, and are only copied into the [contract state](crate::store::contract_state::ContractStateV1)
+/// once every chunk has been submitted, so trades never observe a partially applied update.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct StagedAttributeUpdate {
+    /// The token that follow-up chunks must supply to add to this operation.
+    pub chunk_token: String,
+    /// The block height at which the operation's first chunk was submitted.
+    pub started_at_height: u64,
+    /// The amount of chunks that have been submitted to the operation.
+    pub chunk_count: u32,
+    /// The new required attributes of the [fund_trading](crate::execute::fund_trading::fund_trading)
+    /// route, or `None` if no chunk has updated them.
+    pub deposit_attributes: Option<Vec<String>>,
+    /// The new required attributes of the [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading)
+    /// route, or `None` if no chunk has updated them.
+    pub withdraw_attributes: Option<Vec<String>>,
+}
+
+/// Overwrites the existing singleton contract storage instance of [StagedAttributeUpdate] with
+/// the input reference.  An error is returned if the store write is unsuccessful.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+/// manipulation.
+/// * `staged_update` The new value for which an internal storage write will be done.
+pub fn set_staged_attribute_update(
+    storage: &mut dyn Storage,
+    staged_update: &StagedAttributeUpdate,
+) -> Result<(), ContractError> {
+    BULK_ATTRIBUTE_UPDATE
+        .save(storage, staged_update)
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })
+}
+
+/// Fetches the [StagedAttributeUpdate] that is in progress, if any.  An error will be returned if
+/// store communication fails.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+/// fetches.
+pub fn get_staged_attribute_update(
+    storage: &dyn Storage,
+) -> Result<Option<StagedAttributeUpdate>, ContractError> {
+    BULK_ATTRIBUTE_UPDATE
+        .may_load(storage)
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })
+}
+
+/// Removes the [StagedAttributeUpdate] that is in progress, if any.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+/// manipulation.
+pub fn remove_staged_attribute_update(storage: &mut dyn Storage) {
+    BULK_ATTRIBUTE_UPDATE.remove(storage);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::bulk_attribute_update::{
+        get_staged_attribute_update, remove_staged_attribute_update, set_staged_attribute_update,
+        StagedAttributeUpdate,
+    };
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_get_set_remove_staged_attribute_update() {
+        let mut deps = mock_provenance_dependencies();
+        assert!(
+            get_staged_attribute_update(&deps.storage)
+                .expect("fetching before anything is staged should succeed")
+                .is_none(),
+            "nothing should be returned before an update is staged",
+        );
+        let staged_update = StagedAttributeUpdate {
+            chunk_token: "12345-0".to_string(),
+            started_at_height: 12345,
+            chunk_count: 2,
+            deposit_attributes: Some(vec!["attr.pb".to_string()]),
+            withdraw_attributes: None,
+        };
+        set_staged_attribute_update(&mut deps.storage, &staged_update)
+            .expect("staging an update should succeed");
+        assert_eq!(
+            Some(staged_update),
+            get_staged_attribute_update(&deps.storage).expect("fetching should succeed"),
+            "expected the staged update from storage to equate to the value stored",
+        );
+        remove_staged_attribute_update(&mut deps.storage);
+        assert!(
+            get_staged_attribute_update(&deps.storage)
+                .expect("fetching after removal should succeed")
+                .is_none(),
+            "nothing should be returned after the staged update is removed",
+        );
+    }
+}
@@ -1,6 +1,10 @@
-use crate::types::denom::Denom;
+use crate::types::denom::{Denom, DenomSide, IbcDenomTrace};
 use crate::types::error::ContractError;
-use cosmwasm_std::{Addr, Storage};
+use crate::types::msg_fee::{FeeDiscount, MsgFee, FULL_FEE_BPS};
+use crate::types::quote::TradeDirection;
+use crate::types::schedule::ScheduledPoint;
+use crate::types::trade_plan::TradeFee;
+use cosmwasm_std::{Addr, BlockInfo, Storage, Timestamp, Uint128};
 use cw_storage_plus::Item;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -8,6 +12,11 @@ use serde::{Deserialize, Serialize};
 pub const CONTRACT_TYPE: &str = env!("CARGO_CRATE_NAME");
 pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
+/// The version of the storage layout used for [ContractStateV1], matching the suffix of its storage
+/// namespace.  Fields added with serde defaults do not change the layout, so this only increases
+/// when a migration must rewrite the stored state.
+pub const CONTRACT_STATE_SCHEMA_VERSION: u64 = 1;
+
 const NAMESPACE_CONTRACT_STATE_V1: &str = "contract_state_v1";
 const CONTRACT_STATE_V1: Item<ContractStateV1> = Item::new(NAMESPACE_CONTRACT_STATE_V1);
 
@@ -16,6 +25,18 @@ const CONTRACT_STATE_V1: Item<ContractStateV1> = Item::new(NAMESPACE_CONTRACT_ST
 pub struct ContractStateV1 {
     /// The bech32 address of the account that has admin rights within this contract.
     pub admin: Addr,
+    /// When true, the [admin](ContractStateV1#admin) is the policy address of a cw3/cw4 group or
+    /// DAO, and admin routes are reached by proposals that the group executes through its policy.
+    /// This does not change who may execute admin routes, but rejected senders are directed to the
+    /// group policy and audit log entries record that the change arrived through it.
+    #[serde(default)]
+    pub admin_is_group: bool,
+    /// When set, the bech32 address of the chain governance authority, normally the x/gov module
+    /// account.  The authority may execute every admin route in place of the [admin](ContractStateV1#admin),
+    /// allowing governance to recover a contract whose admin key is lost.  Only the authority itself
+    /// may change this value after instantiation.
+    #[serde(default)]
+    pub gov_authority: Option<Addr>,
     /// A free-form name defining this particular contract instance.  Used for identification on
     /// query purposes only.
     pub contract_name: String,
@@ -36,6 +57,149 @@ pub struct ContractStateV1 {
     /// Defines any blockchain attributes required on accounts in order to execute the
     /// [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading) execution route.
     pub required_withdraw_attributes: Vec<String>,
+    /// Other denom names that accounts may hold as equivalents of the [deposit_marker](ContractStateV1#deposit_marker)
+    /// denom, like an IBC voucher and its chain-registered alias.  These balances are included when
+    /// describing a failed balance check, but the contract can only transfer the deposit marker's
+    /// own denom, so they never count towards the amount that a trade can collect.
+    #[serde(default)]
+    pub deposit_denom_aliases: Vec<String>,
+    /// When true, the [fund_trading](crate::execute::fund_trading::fund_trading) and
+    /// [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading) routes will not query
+    /// the sender's bank balance before emitting their marker transfers.  This saves a bank query
+    /// per trade, but an account without enough funds will then receive the marker module's
+    /// transfer failure instead of this contract's more descriptive error.
+    #[serde(default)]
+    pub skip_balance_precheck: bool,
+    /// When set, any trade whose value in deposit denom is at or above this amount is not executed
+    /// immediately.  It is instead stored as a [pending trade](crate::store::pending_trades::PendingTrade)
+    /// that the contract admin must approve before its messages are emitted.  Trades below the
+    /// threshold, and all trades when this is `None`, execute immediately.
+    #[serde(default)]
+    pub large_trade_threshold: Option<Uint128>,
+    /// When set, the [fund_trading](crate::execute::fund_trading::fund_trading) route rejects any
+    /// request for less than this amount of deposit denom.  The requested amount is compared before
+    /// any partial fill or remainder is deducted.
+    #[serde(default)]
+    pub min_fund_amount: Option<Uint128>,
+    /// When set, the [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading) route
+    /// rejects any request for less than this amount of trading denom.  The requested amount is
+    /// compared before any partial fill or remainder is deducted.
+    #[serde(default)]
+    pub min_withdraw_amount: Option<Uint128>,
+    /// When set, the [fund_trading](crate::execute::fund_trading::fund_trading) route rejects any
+    /// request for more than this amount of deposit denom, limiting how much a single trade can
+    /// move.
+    #[serde(default)]
+    pub max_fund_amount: Option<Uint128>,
+    /// When set, the [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading) route
+    /// rejects any request for more than this amount of trading denom, limiting how much a single
+    /// trade can move.
+    #[serde(default)]
+    pub max_withdraw_amount: Option<Uint128>,
+    /// When set, the [fund_trading](crate::execute::fund_trading::fund_trading) route stops
+    /// accepting trades once the deposit denom collected during the current UTC day would exceed
+    /// this amount.  The accepted volume is read from the [daily trade totals](crate::store::trade_stats::TradeTotals)
+    /// and resets automatically when the day rolls over.
+    #[serde(default)]
+    pub daily_cap: Option<Uint128>,
+    /// When set, both trade routes reject any trade that would push the value traded by its
+    /// account during the current UTC day, expressed in deposit denom, beyond this amount.  Each
+    /// account's usage is tracked in the [account daily usage](crate::store::account_daily_usage::DailyUsage)
+    /// store and resets automatically when the day rolls over.
+    #[serde(default)]
+    pub per_account_daily_limit: Option<Uint128>,
+    /// When set, the [deposit_marker](ContractStateV1#deposit_marker) denom is an IBC voucher that
+    /// must trace back to this base denom and path in the IBC transfer module.  The trace is
+    /// verified at instantiation and by the [admin_run_diagnostics](crate::execute::admin_run_diagnostics::admin_run_diagnostics)
+    /// route, guarding against a spoofed voucher backing the trading denom.
+    #[serde(default)]
+    pub expected_ibc_trace: Option<IbcDenomTrace>,
+    /// When set, every trade accepted by the [fund_trading](crate::execute::fund_trading::fund_trading)
+    /// and [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading) routes assesses
+    /// this Provenance msg fee on the sender's transaction, including trades held for approval.
+    /// Trades are assessed no fee when this is `None`.
+    #[serde(default)]
+    pub msg_fee: Option<MsgFee>,
+    /// Reductions of the [msg_fee](ContractStateV1#msg_fee) granted to senders that hold a
+    /// designated attribute.  Only the single largest discount held by a sender is applied.  No
+    /// discounts are granted when this is empty.
+    #[serde(default)]
+    pub fee_discounts: Vec<FeeDiscount>,
+    /// When set above zero, the [fund_trading](crate::execute::fund_trading::fund_trading) route
+    /// withholds this portion of the trading denom minted by each trade, in basis points, and
+    /// withdraws it to the [fee_collector](ContractStateV1#fee_collector) instead of the sender.
+    /// The withheld amount is rounded down.  Withdrawals are unaffected.
+    #[serde(default)]
+    pub fund_fee_bps: Option<u16>,
+    /// When set above zero, the [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading)
+    /// route withholds this portion of the deposit denom released by each trade, in basis points,
+    /// and transfers it to the [fee_collector](ContractStateV1#fee_collector) instead of the
+    /// sender.  The withheld amount is rounded down, and the amount of trading denom burned is
+    /// unaffected.
+    #[serde(default)]
+    pub withdraw_fee_bps: Option<u16>,
+    /// The bech32 address of the account that receives the denom withheld according to the
+    /// [fund_fee_bps](ContractStateV1#fund_fee_bps) and the [withdraw_fee_bps](ContractStateV1#withdraw_fee_bps).
+    /// Always set when either fee is configured.
+    #[serde(default)]
+    pub fee_collector: Option<Addr>,
+    /// When set, the [fund_trading](crate::execute::fund_trading::fund_trading) route rejects
+    /// senders that do not own a scope in the metadata module created from this scope
+    /// specification, referenced by its bech32 address.  Withdrawals are unaffected.
+    #[serde(default)]
+    pub required_scope_spec: Option<String>,
+    /// When set, the bech32 address of a compliance contract that must approve every trade.  The
+    /// trade routes send it a [CheckTrade](crate::types::msg::ComplianceQuery::CheckTrade) query
+    /// and reject the trade unless it responds with approval.  A failed or malformed response also
+    /// rejects the trade.
+    #[serde(default)]
+    pub compliance_contract: Option<Addr>,
+    /// When set, the bech32 address of the custody account that holds the contract's reserves when
+    /// they are moved out of the contract.  The address can never be the contract itself or the
+    /// account of either marker.
+    #[serde(default)]
+    pub custody_address: Option<Addr>,
+    /// When set, the [fund_trading](crate::execute::fund_trading::fund_trading) and
+    /// [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading) routes reject trades
+    /// until this point is reached.  Once it is reached, trading has begun and the value can no
+    /// longer be changed.
+    #[serde(default)]
+    pub trading_start: Option<ScheduledPoint>,
+    /// When set, the [fund_trading](crate::execute::fund_trading::fund_trading) and
+    /// [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading) routes reject trades
+    /// once this point is reached, winding down the bridge without a further transaction.
+    #[serde(default)]
+    pub sunset_at: Option<ScheduledPoint>,
+    /// When true, the [fund_trading](crate::execute::fund_trading::fund_trading) and
+    /// [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading) routes reject every
+    /// trade.  The contract admin sets and clears the value with the [admin_pause](crate::execute::admin_pause::admin_pause)
+    /// and [admin_resume](crate::execute::admin_resume::admin_resume) routes.
+    #[serde(default)]
+    pub paused: bool,
+    /// When false, the [fund_trading](crate::execute::fund_trading::fund_trading) route rejects
+    /// every trade, while the [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading)
+    /// route is unaffected.  Contract states stored before this value existed are enabled.
+    #[serde(default = "default_route_enabled")]
+    pub fund_enabled: bool,
+    /// When false, the [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading) route
+    /// rejects every trade, while the [fund_trading](crate::execute::fund_trading::fund_trading)
+    /// route is unaffected.  Contract states stored before this value existed are enabled.
+    #[serde(default = "default_route_enabled")]
+    pub withdraw_enabled: bool,
+    /// When true, the [fund_trading](crate::execute::fund_trading::fund_trading) route, and every
+    /// other route that would issue trading denom, rejects trades while withdrawals continue to be
+    /// accepted.  Once set by the [admin_disable_minting_permanently](crate::execute::admin_disable_minting_permanently::admin_disable_minting_permanently)
+    /// route, no route or migration can clear it.
+    #[serde(default)]
+    pub minting_disabled: bool,
+    /// The block height at which the contract was instantiated.  This is `None` for contracts
+    /// instantiated before the value was recorded, unless it was backfilled during a migration.
+    #[serde(default)]
+    pub instantiated_at_height: Option<u64>,
+    /// The block time at which the contract was instantiated.  This is `None` for contracts
+    /// instantiated before the value was recorded, unless it was backfilled during a migration.
+    #[serde(default)]
+    pub instantiated_at_time: Option<Timestamp>,
 }
 impl ContractStateV1 {
     /// Constructs a new instance of this struct.
@@ -54,6 +218,34 @@ impl ContractStateV1 {
     /// * `required_withdraw_attributes` Defines any blockchain attributes required on accounts in
     /// order to execute the [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading)
     /// execution route.
+    ///
+    /// The [deposit_denom_aliases](ContractStateV1#deposit_denom_aliases) and
+    /// [fee_discounts](ContractStateV1#fee_discounts) values default to empty,
+    /// the [admin_is_group](ContractStateV1#admin_is_group),
+    /// [skip_balance_precheck](ContractStateV1#skip_balance_precheck),
+    /// [paused](ContractStateV1#paused) and
+    /// [minting_disabled](ContractStateV1#minting_disabled) values default to false,
+    /// the [fund_enabled](ContractStateV1#fund_enabled) and
+    /// [withdraw_enabled](ContractStateV1#withdraw_enabled) values default to true,
+    /// the [large_trade_threshold](ContractStateV1#large_trade_threshold),
+    /// [min_fund_amount](ContractStateV1#min_fund_amount),
+    /// [min_withdraw_amount](ContractStateV1#min_withdraw_amount),
+    /// [max_fund_amount](ContractStateV1#max_fund_amount),
+    /// [max_withdraw_amount](ContractStateV1#max_withdraw_amount),
+    /// [daily_cap](ContractStateV1#daily_cap),
+    /// [per_account_daily_limit](ContractStateV1#per_account_daily_limit),
+    /// [expected_ibc_trace](ContractStateV1#expected_ibc_trace), [msg_fee](ContractStateV1#msg_fee),
+    /// [fund_fee_bps](ContractStateV1#fund_fee_bps),
+    /// [withdraw_fee_bps](ContractStateV1#withdraw_fee_bps),
+    /// [fee_collector](ContractStateV1#fee_collector),
+    /// [required_scope_spec](ContractStateV1#required_scope_spec),
+    /// [compliance_contract](ContractStateV1#compliance_contract),
+    /// [custody_address](ContractStateV1#custody_address),
+    /// [trading_start](ContractStateV1#trading_start), [sunset_at](ContractStateV1#sunset_at) and
+    /// [gov_authority](ContractStateV1#gov_authority) values default to `None`,
+    /// and the [instantiated_at_height](ContractStateV1#instantiated_at_height) and
+    /// [instantiated_at_time](ContractStateV1#instantiated_at_time) values default to `None`.  They
+    /// must be modified after construction if different values are desired.
     pub fn new<S: Into<String>>(
         admin: Addr,
         contract_name: S,
@@ -64,6 +256,8 @@ impl ContractStateV1 {
     ) -> Self {
         Self {
             admin,
+            admin_is_group: false,
+            gov_authority: None,
             contract_name: contract_name.into(),
             contract_type: CONTRACT_TYPE.to_string(),
             contract_version: CONTRACT_VERSION.to_string(),
@@ -71,10 +265,265 @@ impl ContractStateV1 {
             trading_marker: Denom::new(&trading_marker.name, trading_marker.precision.u64()),
             required_deposit_attributes: required_deposit_attributes.to_vec(),
             required_withdraw_attributes: required_withdraw_attributes.to_vec(),
+            deposit_denom_aliases: vec![],
+            skip_balance_precheck: false,
+            large_trade_threshold: None,
+            min_fund_amount: None,
+            min_withdraw_amount: None,
+            max_fund_amount: None,
+            max_withdraw_amount: None,
+            daily_cap: None,
+            per_account_daily_limit: None,
+            expected_ibc_trace: None,
+            msg_fee: None,
+            fee_discounts: vec![],
+            fund_fee_bps: None,
+            withdraw_fee_bps: None,
+            fee_collector: None,
+            required_scope_spec: None,
+            compliance_contract: None,
+            custody_address: None,
+            trading_start: None,
+            sunset_at: None,
+            paused: false,
+            fund_enabled: true,
+            withdraw_enabled: true,
+            minting_disabled: false,
+            instantiated_at_height: None,
+            instantiated_at_time: None,
+        }
+    }
+
+    /// Fetches the marker denom on the given side of the bridge.
+    ///
+    /// # Parameters
+    /// * `side` Selects the [deposit_marker](ContractStateV1#deposit_marker) or the
+    /// [trading_marker](ContractStateV1#trading_marker).
+    pub fn marker_for_side(&self, side: DenomSide) -> &Denom {
+        match side {
+            DenomSide::Deposit => &self.deposit_marker,
+            DenomSide::Trading => &self.trading_marker,
+        }
+    }
+
+    /// Determines whether or not a trade of the given value must be approved by the contract admin
+    /// before it executes, based on the [large_trade_threshold](ContractStateV1#large_trade_threshold).
+    ///
+    /// # Parameters
+    /// * `deposit_amount` The value of the trade, expressed as an amount of deposit denom.
+    pub fn requires_trade_approval(&self, deposit_amount: Uint128) -> bool {
+        self.large_trade_threshold
+            .is_some_and(|threshold| deposit_amount >= threshold)
+    }
+
+    /// Determines the [fund fee](TradeFee) withheld from the given amount of minted trading denom,
+    /// according to the [fund_fee_bps](ContractStateV1#fund_fee_bps).  The fee is rounded down, so
+    /// it never exceeds the configured rate.  Returns `None` when no fee is configured.
+    ///
+    /// # Parameters
+    /// * `minted_amount` The amount of trading denom minted by the trade.
+    pub fn fund_fee(&self, minted_amount: Uint128) -> Option<TradeFee> {
+        self.trade_fee(self.fund_fee_bps, minted_amount)
+    }
+
+    /// Determines the [withdraw fee](TradeFee) withheld from the given amount of released deposit
+    /// denom, according to the [withdraw_fee_bps](ContractStateV1#withdraw_fee_bps).  The fee is
+    /// rounded down, so it never exceeds the configured rate.  Returns `None` when no fee is
+    /// configured.
+    ///
+    /// # Parameters
+    /// * `released_amount` The amount of deposit denom released by the trade.
+    pub fn withdraw_fee(&self, released_amount: Uint128) -> Option<TradeFee> {
+        self.trade_fee(self.withdraw_fee_bps, released_amount)
+    }
+
+    fn trade_fee(&self, fee_bps: Option<u16>, output_amount: Uint128) -> Option<TradeFee> {
+        let fee_bps = fee_bps.filter(|fee_bps| *fee_bps > 0)?;
+        let collector = self.fee_collector.as_ref()?;
+        Some(TradeFee {
+            amount: output_amount.multiply_ratio(fee_bps, FULL_FEE_BPS),
+            collector: collector.to_owned(),
+        })
+    }
+
+    /// Verifies that the given block falls within the contract's trading schedule, after the
+    /// [trading_start](ContractStateV1#trading_start) and before the [sunset_at](ContractStateV1#sunset_at)
+    /// points.  A [TradingClosedError](ContractError::TradingClosedError) is returned otherwise.
+    ///
+    /// # Parameters
+    /// * `block` The block in which the trade would execute.
+    pub fn check_trading_schedule(&self, block: &BlockInfo) -> Result<(), ContractError> {
+        if let Some(trading_start) = &self.trading_start {
+            if !trading_start.has_passed(block) {
+                return Err(ContractError::TradingClosedError {
+                    message: format!("trading opens at [{trading_start}]"),
+                });
+            }
+        }
+        if let Some(sunset_at) = &self.sunset_at {
+            if sunset_at.has_passed(block) {
+                return Err(ContractError::TradingClosedError {
+                    message: format!("trading closed at [{sunset_at}]"),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Verifies that the contract admin has not [paused](ContractStateV1#paused) trading, returning
+    /// a [ContractPausedError](ContractError::ContractPausedError) otherwise.
+    pub fn check_not_paused(&self) -> Result<(), ContractError> {
+        if self.paused {
+            return Err(ContractError::ContractPausedError {
+                message: "trading is paused by the contract admin".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Verifies that the contract admin has not disabled the [fund_trading](crate::execute::fund_trading::fund_trading)
+    /// route, returning a [RouteDisabledError](ContractError::RouteDisabledError) otherwise.
+    pub fn check_fund_enabled(&self) -> Result<(), ContractError> {
+        if !self.fund_enabled {
+            return Err(ContractError::RouteDisabledError {
+                message: "fund_trading is disabled by the contract admin".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Verifies that the contract admin has not disabled the [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading)
+    /// route, returning a [RouteDisabledError](ContractError::RouteDisabledError) otherwise.
+    pub fn check_withdraw_enabled(&self) -> Result<(), ContractError> {
+        if !self.withdraw_enabled {
+            return Err(ContractError::RouteDisabledError {
+                message: "withdraw_trading is disabled by the contract admin".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Verifies that a requested trade amount is not below the [min_fund_amount](ContractStateV1#min_fund_amount)
+    /// or [min_withdraw_amount](ContractStateV1#min_withdraw_amount) of its direction, returning a
+    /// [ValidationError](ContractError::ValidationError) naming the minimum otherwise.
+    ///
+    /// # Parameters
+    /// * `direction` The direction of the requested trade.
+    /// * `trade_amount` The amount of input denom requested by the sender.
+    pub fn check_min_trade_amount(
+        &self,
+        direction: TradeDirection,
+        trade_amount: Uint128,
+    ) -> Result<(), ContractError> {
+        let (minimum, denom) = match direction {
+            TradeDirection::Fund => (self.min_fund_amount, &self.deposit_marker.name),
+            TradeDirection::Withdraw => (self.min_withdraw_amount, &self.trading_marker.name),
+        };
+        if let Some(minimum) = minimum {
+            if trade_amount < minimum {
+                return Err(ContractError::ValidationError {
+                    message: format!(
+                        "requested [{trade_amount}{denom}], but the minimum {} amount is [{minimum}{denom}]",
+                        direction.name(),
+                    ),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Verifies that a requested trade amount does not exceed the [max_fund_amount](ContractStateV1#max_fund_amount)
+    /// or [max_withdraw_amount](ContractStateV1#max_withdraw_amount) of its direction, returning a
+    /// [ValidationError](ContractError::ValidationError) naming the cap and the requested amount
+    /// otherwise.
+    ///
+    /// # Parameters
+    /// * `direction` The direction of the requested trade.
+    /// * `trade_amount` The amount of input denom requested by the sender.
+    pub fn check_max_trade_amount(
+        &self,
+        direction: TradeDirection,
+        trade_amount: Uint128,
+    ) -> Result<(), ContractError> {
+        let (maximum, denom) = match direction {
+            TradeDirection::Fund => (self.max_fund_amount, &self.deposit_marker.name),
+            TradeDirection::Withdraw => (self.max_withdraw_amount, &self.trading_marker.name),
+        };
+        if let Some(maximum) = maximum {
+            if trade_amount > maximum {
+                return Err(ContractError::ValidationError {
+                    message: format!(
+                        "requested [{trade_amount}{denom}], but the maximum {} amount is [{maximum}{denom}]",
+                        direction.name(),
+                    ),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Verifies that the contract may still issue trading denom, returning a
+    /// [MintingDisabledError](ContractError::MintingDisabledError) once [minting_disabled](ContractStateV1#minting_disabled)
+    /// is set.
+    pub fn check_minting_enabled(&self) -> Result<(), ContractError> {
+        if self.minting_disabled {
+            return Err(ContractError::MintingDisabledError {
+                message: "the trading denom can no longer be issued, but existing holdings can still be withdrawn".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Verifies that the given sender is the contract [admin](ContractStateV1#admin) or the
+    /// [gov_authority](ContractStateV1#gov_authority), returning the [AdminSender] that it acts as.
+    /// A [NotAuthorizedError](ContractError::NotAuthorizedError) is returned otherwise.  When the
+    /// admin is a [group](ContractStateV1#admin_is_group), the error explains that the action must be
+    /// proposed to the group and executed through its policy address, because group members cannot
+    /// execute admin routes directly.
+    ///
+    /// # Parameters
+    /// * `sender` The address of the account that sent the admin request.
+    /// * `action` A short description of the admin action, used to complete the sentence "only the
+    /// contract admin may ...".
+    pub fn check_admin(&self, sender: &Addr, action: &str) -> Result<AdminSender, ContractError> {
+        if sender == self.admin {
+            return if self.admin_is_group {
+                Ok(AdminSender::GroupPolicy)
+            } else {
+                Ok(AdminSender::Admin)
+            };
+        }
+        if self.gov_authority.as_ref() == Some(sender) {
+            return Ok(AdminSender::GovAuthority);
         }
+        let message = if self.admin_is_group {
+            format!(
+                "only the contract admin may {action}: the admin is the group policy [{}], so submit a proposal to the group and execute it through the policy once it passes",
+                self.admin,
+            )
+        } else {
+            format!("only the contract admin may {action}")
+        };
+        Err(ContractError::NotAuthorizedError { message })
     }
 }
 
+/// Describes the capacity in which a sender passed the [admin check](ContractStateV1::check_admin).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AdminSender {
+    /// The sender is the contract [admin](ContractStateV1#admin).
+    Admin,
+    /// The sender is the contract [admin](ContractStateV1#admin), which is the policy address of a
+    /// [group](ContractStateV1#admin_is_group).
+    GroupPolicy,
+    /// The sender is the [gov_authority](ContractStateV1#gov_authority), overriding the admin.
+    GovAuthority,
+}
+
+fn default_route_enabled() -> bool {
+    true
+}
+
 /// Overwrites the existing singleton contract storage instance of [ContractStateV1] with the input
 /// reference.  An error is returned if the store write is unsuccessful.
 ///
@@ -113,11 +562,15 @@ pub fn get_contract_state_v1(storage: &dyn Storage) -> Result<ContractStateV1, C
 #[cfg(test)]
 mod tests {
     use crate::store::contract_state::{
-        get_contract_state_v1, set_contract_state_v1, ContractStateV1, CONTRACT_TYPE,
+        get_contract_state_v1, set_contract_state_v1, AdminSender, ContractStateV1, CONTRACT_TYPE,
         CONTRACT_VERSION,
     };
     use crate::types::denom::Denom;
-    use cosmwasm_std::{Addr, Uint64};
+    use crate::types::error::ContractError;
+    use crate::types::schedule::ScheduledPoint;
+    use crate::types::trade_plan::TradeFee;
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{Addr, Uint128, Uint64};
     use provwasm_mocks::mock_provenance_dependencies;
 
     #[test]
@@ -182,6 +635,431 @@ mod tests {
             state.required_withdraw_attributes,
             "the required withdraw attributes should have the proper value",
         );
+        assert!(
+            state.deposit_denom_aliases.is_empty(),
+            "there should be no deposit denom aliases by default",
+        );
+        assert!(
+            !state.admin_is_group,
+            "the admin should not be a group by default",
+        );
+        assert!(
+            state.gov_authority.is_none(),
+            "there should be no gov authority by default",
+        );
+        assert!(
+            state.compliance_contract.is_none(),
+            "there should be no compliance contract by default",
+        );
+        assert!(
+            !state.skip_balance_precheck,
+            "the balance precheck should not be skipped by default",
+        );
+        assert!(
+            state.large_trade_threshold.is_none(),
+            "there should be no large trade threshold by default",
+        );
+        assert!(
+            state.instantiated_at_height.is_none() && state.instantiated_at_time.is_none(),
+            "the instantiation details should not be set by default",
+        );
+        assert!(
+            !state.paused,
+            "the contract should not be paused by default"
+        );
+        assert!(
+            state.fund_enabled,
+            "the fund route should be enabled by default"
+        );
+        assert!(
+            state.withdraw_enabled,
+            "the withdraw route should be enabled by default"
+        );
+        assert!(
+            !state.minting_disabled,
+            "minting should not be disabled by default",
+        );
+    }
+
+    #[test]
+    fn test_requires_trade_approval() {
+        let mut state = ContractStateV1::new(
+            Addr::unchecked("admin"),
+            "contract-name",
+            &Denom::new("deposit", 10),
+            &Denom::new("trading", 4),
+            &[],
+            &[],
+        );
+        assert!(
+            !state.requires_trade_approval(Uint128::MAX),
+            "no trade should require approval without a threshold",
+        );
+        state.large_trade_threshold = Some(Uint128::new(100));
+        assert!(
+            !state.requires_trade_approval(Uint128::new(99)),
+            "a trade below the threshold should not require approval",
+        );
+        assert!(
+            state.requires_trade_approval(Uint128::new(100)),
+            "a trade at the threshold should require approval",
+        );
+        assert!(
+            state.requires_trade_approval(Uint128::new(101)),
+            "a trade above the threshold should require approval",
+        );
+    }
+
+    #[test]
+    fn test_fund_fee() {
+        let mut state = ContractStateV1::new(
+            Addr::unchecked("admin"),
+            "contract-name",
+            &Denom::new("deposit", 10),
+            &Denom::new("trading", 4),
+            &[],
+            &[],
+        );
+        state.fee_collector = Some(Addr::unchecked("collector"));
+        assert_eq!(
+            None,
+            state.fund_fee(Uint128::new(10000)),
+            "no fee should be withheld without a rate",
+        );
+        state.fund_fee_bps = Some(0);
+        assert_eq!(
+            None,
+            state.fund_fee(Uint128::new(10000)),
+            "no fee should be withheld at a zero rate",
+        );
+        state.fund_fee_bps = Some(25);
+        assert_eq!(
+            Some(TradeFee {
+                amount: Uint128::new(25),
+                collector: Addr::unchecked("collector"),
+            }),
+            state.fund_fee(Uint128::new(10000)),
+            "the fee should be withheld at the configured rate",
+        );
+        assert_eq!(
+            Some(Uint128::new(2)),
+            state
+                .fund_fee(Uint128::new(1199))
+                .map(|fund_fee| fund_fee.amount),
+            "the fee should be rounded down in favor of the sender",
+        );
+        assert_eq!(
+            Some(Uint128::zero()),
+            state
+                .fund_fee(Uint128::new(399))
+                .map(|fund_fee| fund_fee.amount),
+            "a fee below one unit should round down to zero",
+        );
+    }
+
+    #[test]
+    fn test_withdraw_fee() {
+        let mut state = ContractStateV1::new(
+            Addr::unchecked("admin"),
+            "contract-name",
+            &Denom::new("deposit", 10),
+            &Denom::new("trading", 4),
+            &[],
+            &[],
+        );
+        state.withdraw_fee_bps = Some(30);
+        assert_eq!(
+            None,
+            state.withdraw_fee(Uint128::new(10000)),
+            "no fee should be withheld without a collector",
+        );
+        state.fee_collector = Some(Addr::unchecked("collector"));
+        state.fund_fee_bps = Some(25);
+        assert_eq!(
+            Some(TradeFee {
+                amount: Uint128::new(30),
+                collector: Addr::unchecked("collector"),
+            }),
+            state.withdraw_fee(Uint128::new(10000)),
+            "the fee should be withheld at the withdraw rate rather than the fund rate",
+        );
+        state.withdraw_fee_bps = Some(0);
+        assert_eq!(
+            None,
+            state.withdraw_fee(Uint128::new(10000)),
+            "no fee should be withheld at a zero rate",
+        );
+    }
+
+    #[test]
+    fn test_check_trading_schedule() {
+        let block = mock_env().block;
+        let mut state = ContractStateV1::new(
+            Addr::unchecked("admin"),
+            "contract-name",
+            &Denom::new("deposit", 10),
+            &Denom::new("trading", 4),
+            &[],
+            &[],
+        );
+        state
+            .check_trading_schedule(&block)
+            .expect("trading should be open without a schedule");
+        state.trading_start = Some(ScheduledPoint::Height(block.height + 1));
+        let error = state
+            .check_trading_schedule(&block)
+            .expect_err("trading should be closed before the start");
+        assert_eq!(
+            format!(
+                "trading is closed: trading opens at [height {}]",
+                block.height + 1
+            ),
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+        state.trading_start = Some(ScheduledPoint::Height(block.height));
+        state.sunset_at = Some(ScheduledPoint::Time(block.time.plus_seconds(1)));
+        state
+            .check_trading_schedule(&block)
+            .expect("trading should be open between the start and the sunset");
+        state.sunset_at = Some(ScheduledPoint::Time(block.time));
+        let error = state
+            .check_trading_schedule(&block)
+            .expect_err("trading should be closed once the sunset passes");
+        assert!(
+            matches!(error, ContractError::TradingClosedError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn test_check_not_paused() {
+        let mut state = ContractStateV1::new(
+            Addr::unchecked("admin"),
+            "contract-name",
+            &Denom::new("deposit", 10),
+            &Denom::new("trading", 4),
+            &[],
+            &[],
+        );
+        state
+            .check_not_paused()
+            .expect("the contract should not be paused by default");
+        state.paused = true;
+        let error = state
+            .check_not_paused()
+            .expect_err("a paused contract should be rejected");
+        assert_eq!(
+            "contract is paused: trading is paused by the contract admin",
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+    }
+
+    #[test]
+    fn test_check_route_enabled() {
+        let mut state = ContractStateV1::new(
+            Addr::unchecked("admin"),
+            "contract-name",
+            &Denom::new("deposit", 10),
+            &Denom::new("trading", 4),
+            &[],
+            &[],
+        );
+        state
+            .check_fund_enabled()
+            .expect("the fund route should be enabled by default");
+        state
+            .check_withdraw_enabled()
+            .expect("the withdraw route should be enabled by default");
+        state.fund_enabled = false;
+        let error = state
+            .check_fund_enabled()
+            .expect_err("a disabled fund route should be rejected");
+        assert_eq!(
+            "route disabled: fund_trading is disabled by the contract admin",
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+        state
+            .check_withdraw_enabled()
+            .expect("disabling the fund route should not affect the withdraw route");
+        state.withdraw_enabled = false;
+        let error = state
+            .check_withdraw_enabled()
+            .expect_err("a disabled withdraw route should be rejected");
+        assert!(
+            matches!(error, ContractError::RouteDisabledError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn test_check_min_trade_amount() {
+        let mut state = ContractStateV1::new(
+            Addr::unchecked("admin"),
+            "contract-name",
+            &Denom::new("deposit", 2),
+            &Denom::new("trading", 6),
+            &[],
+            &[],
+        );
+        for direction in [TradeDirection::Fund, TradeDirection::Withdraw] {
+            state
+                .check_min_trade_amount(direction, Uint128::new(1))
+                .expect("any amount should be accepted when no minimum is set");
+        }
+        state.min_fund_amount = Some(Uint128::new(100));
+        state.min_withdraw_amount = Some(Uint128::new(1_000_000));
+        state
+            .check_min_trade_amount(TradeDirection::Fund, Uint128::new(100))
+            .expect("an amount equal to the minimum should be accepted");
+        let error = state
+            .check_min_trade_amount(TradeDirection::Fund, Uint128::new(99))
+            .expect_err("an amount below the fund minimum should be rejected");
+        assert_eq!(
+            "validation failed: requested [99deposit], but the minimum fund amount is [100deposit]",
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+        state
+            .check_min_trade_amount(TradeDirection::Withdraw, Uint128::new(1_000_000))
+            .expect("an amount equal to the minimum should be accepted");
+        let error = state
+            .check_min_trade_amount(TradeDirection::Withdraw, Uint128::new(999_999))
+            .expect_err("an amount below the withdraw minimum should be rejected");
+        assert_eq!(
+            "validation failed: requested [999999trading], but the minimum withdraw amount is [1000000trading]",
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+    }
+
+    #[test]
+    fn test_check_max_trade_amount() {
+        let mut state = ContractStateV1::new(
+            Addr::unchecked("admin"),
+            "contract-name",
+            &Denom::new("deposit", 2),
+            &Denom::new("trading", 6),
+            &[],
+            &[],
+        );
+        for direction in [TradeDirection::Fund, TradeDirection::Withdraw] {
+            state
+                .check_max_trade_amount(direction, Uint128::MAX)
+                .expect("any amount should be accepted when no maximum is set");
+        }
+        state.max_fund_amount = Some(Uint128::new(100));
+        state.max_withdraw_amount = Some(Uint128::new(1_000_000));
+        state
+            .check_max_trade_amount(TradeDirection::Fund, Uint128::new(100))
+            .expect("an amount equal to the maximum should be accepted");
+        let error = state
+            .check_max_trade_amount(TradeDirection::Fund, Uint128::new(101))
+            .expect_err("an amount above the fund maximum should be rejected");
+        assert_eq!(
+            "validation failed: requested [101deposit], but the maximum fund amount is [100deposit]",
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+        state
+            .check_max_trade_amount(TradeDirection::Withdraw, Uint128::new(1_000_000))
+            .expect("an amount equal to the maximum should be accepted");
+        let error = state
+            .check_max_trade_amount(TradeDirection::Withdraw, Uint128::new(1_000_001))
+            .expect_err("an amount above the withdraw maximum should be rejected");
+        assert_eq!(
+            "validation failed: requested [1000001trading], but the maximum withdraw amount is [1000000trading]",
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+    }
+
+    #[test]
+    fn test_check_minting_enabled() {
+        let mut state = ContractStateV1::new(
+            Addr::unchecked("admin"),
+            "contract-name",
+            &Denom::new("deposit", 10),
+            &Denom::new("trading", 4),
+            &[],
+            &[],
+        );
+        state
+            .check_minting_enabled()
+            .expect("minting should be enabled by default");
+        state.minting_disabled = true;
+        let error = state
+            .check_minting_enabled()
+            .expect_err("minting should be rejected once disabled");
+        assert!(
+            matches!(error, ContractError::MintingDisabledError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn test_check_admin() {
+        let mut state = ContractStateV1::new(
+            Addr::unchecked("admin"),
+            "contract-name",
+            &Denom::new("deposit", 10),
+            &Denom::new("trading", 4),
+            &[],
+            &[],
+        );
+        assert_eq!(
+            AdminSender::Admin,
+            state
+                .check_admin(&Addr::unchecked("admin"), "do things")
+                .expect("the admin should pass the admin check"),
+            "the admin should be recognized as the admin",
+        );
+        match state
+            .check_admin(&Addr::unchecked("member"), "do things")
+            .expect_err("a non-admin should fail the admin check")
+        {
+            ContractError::NotAuthorizedError { message } => assert_eq!(
+                "only the contract admin may do things", message,
+                "unexpected error message for a non-group admin",
+            ),
+            e => panic!("unexpected error encountered: {e:?}"),
+        };
+        state.admin_is_group = true;
+        assert_eq!(
+            AdminSender::GroupPolicy,
+            state
+                .check_admin(&Addr::unchecked("admin"), "do things")
+                .expect("the group policy should pass the admin check"),
+            "the admin should be recognized as a group policy",
+        );
+        match state
+            .check_admin(&Addr::unchecked("member"), "do things")
+            .expect_err("a group member should fail the admin check")
+        {
+            ContractError::NotAuthorizedError { message } => assert!(
+                message.starts_with("only the contract admin may do things")
+                    && message.contains("group policy [admin]")
+                    && message.contains("submit a proposal"),
+                "the error should direct the sender to the group policy, but got: {message}",
+            ),
+            e => panic!("unexpected error encountered: {e:?}"),
+        };
+        state
+            .check_admin(&Addr::unchecked("gov"), "do things")
+            .expect_err("the gov module should not pass the admin check without being configured");
+        state.gov_authority = Some(Addr::unchecked("gov"));
+        assert_eq!(
+            AdminSender::GovAuthority,
+            state
+                .check_admin(&Addr::unchecked("gov"), "do things")
+                .expect("the gov authority should pass the admin check"),
+            "the gov authority should be recognized as overriding the admin",
+        );
+        state
+            .check_admin(&Addr::unchecked("member"), "do things")
+            .expect_err("other accounts should still fail the admin check");
     }
 
     #[test]
@@ -0,0 +1,148 @@
+use crate::store::trade_stats::{day_of, get_daily_trade_totals};
+use crate::types::error::ContractError;
+use cosmwasm_std::{Storage, Timestamp, Uint128};
+use result_extensions::ResultExtensions;
+
+/// Verifies that adding a fund trade to the [fund volume](crate::store::trade_stats::TradeTotals#fund_volume)
+/// of the day containing the given block time would not exceed the daily cap.  The volume is read
+/// from the [daily trade totals](get_daily_trade_totals), so it resets automatically once a block
+/// falls on a later day.  A [DailyCapExceededError](ContractError::DailyCapExceededError) naming
+/// the remaining capacity is returned otherwise.  Returns the volume that the day would have after
+/// the trade.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+/// fetches.
+/// * `time` The block time at which the trade executes.
+/// * `daily_cap` The largest volume accepted during a single day, if any.
+/// * `deposit_amount` The amount of deposit denom collected by the trade.
+/// * `denom` The deposit denom, used to describe the amounts in the error.
+pub fn check_daily_capacity(
+    storage: &dyn Storage,
+    time: &Timestamp,
+    daily_cap: Option<Uint128>,
+    deposit_amount: Uint128,
+    denom: &str,
+) -> Result<Uint128, ContractError> {
+    let current_volume = get_daily_trade_totals(storage, day_of(time))?.fund_volume;
+    let volume = current_volume.checked_add(deposit_amount)?;
+    if let Some(daily_cap) = daily_cap {
+        if volume > daily_cap {
+            return ContractError::DailyCapExceededError {
+                message: format!(
+                    "requested [{deposit_amount}{denom}], but only [{}{denom}] of the daily cap of [{daily_cap}{denom}] remains",
+                    daily_cap.saturating_sub(current_volume),
+                ),
+            }
+            .to_err();
+        }
+    }
+    volume.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::daily_volume::check_daily_capacity;
+    use crate::store::trade_stats::{record_trade, SECONDS_PER_DAY};
+    use crate::types::error::ContractError;
+    use crate::types::quote::TradeDirection;
+    use cosmwasm_std::{Addr, Storage, Timestamp, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    fn record_fund(storage: &mut dyn Storage, time: &Timestamp, amount: u128) {
+        record_trade(
+            storage,
+            time,
+            TradeDirection::Fund,
+            &Addr::unchecked("account"),
+            Uint128::new(amount),
+        )
+        .expect("the fund trade should be recorded");
+    }
+
+    #[test]
+    fn test_check_daily_capacity_up_to_the_exact_cap() {
+        let mut deps = mock_provenance_dependencies();
+        let time = Timestamp::from_seconds(SECONDS_PER_DAY * 10 + 5);
+        let cap = Some(Uint128::new(1000));
+        record_fund(&mut deps.storage, &time, 600);
+        assert_eq!(
+            Uint128::new(1000),
+            check_daily_capacity(&deps.storage, &time, cap, Uint128::new(400), "deposit")
+                .expect("a trade reaching the exact cap should be accepted"),
+            "the volume after the trade should equal the cap",
+        );
+        record_fund(&mut deps.storage, &time, 400);
+        let error = check_daily_capacity(&deps.storage, &time, cap, Uint128::one(), "deposit")
+            .expect_err("a trade beyond the exact cap should be rejected");
+        assert_eq!(
+            "daily cap exceeded: requested [1deposit], but only [0deposit] of the daily cap of [1000deposit] remains",
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+    }
+
+    #[test]
+    fn test_check_daily_capacity_ignores_withdraw_volume() {
+        let mut deps = mock_provenance_dependencies();
+        let time = Timestamp::from_seconds(SECONDS_PER_DAY * 10);
+        record_trade(
+            &mut deps.storage,
+            &time,
+            TradeDirection::Withdraw,
+            &Addr::unchecked("account"),
+            Uint128::new(1000),
+        )
+        .expect("the withdraw trade should be recorded");
+        check_daily_capacity(
+            &deps.storage,
+            &time,
+            Some(Uint128::new(1000)),
+            Uint128::new(1000),
+            "deposit",
+        )
+        .expect("withdraw volume should not consume the daily cap");
+    }
+
+    #[test]
+    fn test_daily_capacity_resets_when_the_day_rolls_over() {
+        let mut deps = mock_provenance_dependencies();
+        let last_second = Timestamp::from_seconds(SECONDS_PER_DAY * 11 - 1);
+        let next_day = Timestamp::from_seconds(SECONDS_PER_DAY * 11);
+        let cap = Some(Uint128::new(1000));
+        record_fund(&mut deps.storage, &last_second, 1000);
+        let error =
+            check_daily_capacity(&deps.storage, &last_second, cap, Uint128::one(), "deposit")
+                .expect_err("the final second of the day should still be capped");
+        assert!(
+            matches!(error, ContractError::DailyCapExceededError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+        assert_eq!(
+            Uint128::new(1000),
+            check_daily_capacity(&deps.storage, &next_day, cap, Uint128::new(1000), "deposit")
+                .expect("the full cap should be available on the next day"),
+            "the next day's volume should only include its own trades",
+        );
+    }
+
+    #[test]
+    fn test_check_daily_capacity_without_a_cap() {
+        let mut deps = mock_provenance_dependencies();
+        let time = Timestamp::from_seconds(0);
+        record_fund(&mut deps.storage, &time, 1000);
+        assert_eq!(
+            Uint128::MAX,
+            check_daily_capacity(
+                &deps.storage,
+                &time,
+                None,
+                Uint128::MAX - Uint128::new(1000),
+                "deposit",
+            )
+            .expect("any volume should be accepted when no cap is set"),
+            "the volume after the trade should include the day's existing volume",
+        );
+    }
+}
@@ -0,0 +1,267 @@
+use crate::types::error::ContractError;
+use cosmwasm_std::{Addr, Order, Storage};
+use cw_storage_plus::{Bound, Map};
+use result_extensions::ResultExtensions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const NAMESPACE_DENYLIST: &str = "denylist";
+/// Maps each denylisted account to the reason it was listed.  An empty reason indicates that none
+/// was provided.
+const DENYLIST: Map<&Addr, String> = Map::new(NAMESPACE_DENYLIST);
+
+/// An account that is blocked from trading, along with the reason it was listed.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct DenylistEntry {
+    /// The blocked account.
+    pub account: Addr,
+    /// The reason that the account was blocked, if one was provided.
+    pub reason: Option<String>,
+}
+
+/// Blocks an account from trading, regardless of the attributes that it holds.  A
+/// [ValidationError](ContractError::ValidationError) is returned if the account is already
+/// denylisted.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+/// manipulation.
+/// * `account` The account to block.
+/// * `reason` The reason that the account is blocked, if any.
+pub fn add_denylisted_account(
+    storage: &mut dyn Storage,
+    account: &Addr,
+    reason: Option<String>,
+) -> Result<(), ContractError> {
+    if get_denylist_entry(storage, account)?.is_some() {
+        return ContractError::ValidationError {
+            message: format!("account [{account}] is already denylisted"),
+        }
+        .to_err();
+    }
+    DENYLIST
+        .save(storage, account, &reason.unwrap_or_default())
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })
+}
+
+/// Removes an account from the denylist, allowing it to trade again.  A
+/// [NotFoundError](ContractError::NotFoundError) is returned if the account is not denylisted.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+/// manipulation.
+/// * `account` The account to unblock.
+pub fn remove_denylisted_account(
+    storage: &mut dyn Storage,
+    account: &Addr,
+) -> Result<(), ContractError> {
+    if get_denylist_entry(storage, account)?.is_none() {
+        return ContractError::NotFoundError {
+            message: format!("account [{account}] is not denylisted"),
+        }
+        .to_err();
+    }
+    DENYLIST.remove(storage, account);
+    ().to_ok()
+}
+
+/// Fetches the denylist entry for an account, or `None` if the account is not denylisted.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+/// fetches.
+/// * `account` The account to check.
+pub fn get_denylist_entry(
+    storage: &dyn Storage,
+    account: &Addr,
+) -> Result<Option<DenylistEntry>, ContractError> {
+    DENYLIST
+        .may_load(storage, account)
+        .map(|reason| reason.map(|reason| to_entry(account.to_owned(), reason)))
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })
+}
+
+/// Fetches every denylisted account, ordered by address.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+/// fetches.
+/// * `start_after` If provided, only accounts with an address ordered after this value are
+/// returned.
+/// * `limit` The maximum amount of accounts to return.
+pub fn get_denylist_entries(
+    storage: &dyn Storage,
+    start_after: Option<&Addr>,
+    limit: usize,
+) -> Result<Vec<DenylistEntry>, ContractError> {
+    DENYLIST
+        .range(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|result| {
+            result
+                .map(|(account, reason)| to_entry(account, reason))
+                .map_err(|e| ContractError::StorageError {
+                    message: format!("{e:?}"),
+                })
+        })
+        .collect()
+}
+
+/// Verifies that an account is not denylisted.  A [NotAuthorizedError](ContractError::NotAuthorizedError)
+/// that includes the stored reason is returned otherwise.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+/// fetches.
+/// * `account` The account that requests a trade.
+pub fn check_account_not_denylisted(
+    storage: &dyn Storage,
+    account: &Addr,
+) -> Result<(), ContractError> {
+    match get_denylist_entry(storage, account)? {
+        Some(DenylistEntry {
+            reason: Some(reason),
+            ..
+        }) => ContractError::NotAuthorizedError {
+            message: format!("account [{account}] is denylisted: {reason}"),
+        }
+        .to_err(),
+        Some(_) => ContractError::NotAuthorizedError {
+            message: format!("account [{account}] is denylisted"),
+        }
+        .to_err(),
+        None => ().to_ok(),
+    }
+}
+
+fn to_entry(account: Addr, reason: String) -> DenylistEntry {
+    DenylistEntry {
+        account,
+        reason: Some(reason).filter(|reason| !reason.is_empty()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::denylist::{
+        add_denylisted_account, check_account_not_denylisted, get_denylist_entries,
+        get_denylist_entry, remove_denylisted_account, DenylistEntry,
+    };
+    use crate::types::error::ContractError;
+    use cosmwasm_std::Addr;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_add_and_remove_denylisted_accounts() {
+        let mut deps = mock_provenance_dependencies();
+        let account = Addr::unchecked("account");
+        check_account_not_denylisted(&deps.storage, &account)
+            .expect("an unknown account should not be denylisted");
+        add_denylisted_account(
+            &mut deps.storage,
+            &account,
+            Some("flagged by compliance".to_string()),
+        )
+        .expect("denylisting an account should succeed");
+        assert_eq!(
+            Some(DenylistEntry {
+                account: account.to_owned(),
+                reason: Some("flagged by compliance".to_string()),
+            }),
+            get_denylist_entry(&deps.storage, &account).expect("fetching an entry should succeed"),
+            "the added account should be denylisted with its reason",
+        );
+        let error = check_account_not_denylisted(&deps.storage, &account)
+            .expect_err("a denylisted account should fail the check");
+        assert_eq!(
+            "not authorized: account [account] is denylisted: flagged by compliance",
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+        let error = add_denylisted_account(&mut deps.storage, &account, None)
+            .expect_err("denylisting a duplicate account should fail");
+        assert_eq!(
+            "validation failed: account [account] is already denylisted",
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+        remove_denylisted_account(&mut deps.storage, &account)
+            .expect("removing a denylisted account should succeed");
+        check_account_not_denylisted(&deps.storage, &account)
+            .expect("the removed account should no longer be denylisted");
+        let error = remove_denylisted_account(&mut deps.storage, &account)
+            .expect_err("removing an account that is not denylisted should fail");
+        assert!(
+            matches!(error, ContractError::NotFoundError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn test_missing_reason_should_be_omitted() {
+        let mut deps = mock_provenance_dependencies();
+        let account = Addr::unchecked("account");
+        add_denylisted_account(&mut deps.storage, &account, None)
+            .expect("denylisting an account should succeed");
+        assert_eq!(
+            None,
+            get_denylist_entry(&deps.storage, &account)
+                .expect("fetching an entry should succeed")
+                .expect("the account should be denylisted")
+                .reason,
+            "no reason should be reported when none was provided",
+        );
+        let error = check_account_not_denylisted(&deps.storage, &account)
+            .expect_err("a denylisted account should fail the check");
+        assert_eq!(
+            "not authorized: account [account] is denylisted",
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+    }
+
+    #[test]
+    fn test_get_denylist_entries_pages_in_order() {
+        let mut deps = mock_provenance_dependencies();
+        for name in ["third", "first", "second"] {
+            add_denylisted_account(&mut deps.storage, &Addr::unchecked(name), None)
+                .expect("denylisting an account should succeed");
+        }
+        let accounts = |entries: Vec<DenylistEntry>| {
+            entries
+                .into_iter()
+                .map(|entry| entry.account)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            vec![Addr::unchecked("first"), Addr::unchecked("second")],
+            accounts(
+                get_denylist_entries(&deps.storage, None, 2)
+                    .expect("fetching entries should succeed")
+            ),
+            "the first page should be ordered by address",
+        );
+        assert_eq!(
+            vec![Addr::unchecked("third")],
+            accounts(
+                get_denylist_entries(&deps.storage, Some(&Addr::unchecked("second")), 2)
+                    .expect("fetching entries should succeed")
+            ),
+            "the next page should start after the given address",
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use crate::types::error::ContractError;
+use cosmwasm_std::Storage;
+use result_extensions::ResultExtensions;
+
+/// The raw storage keys of singleton items written by a tagged release of the contract that are
+/// no longer read or written.  Only keys in this list may be purged during a migration, ensuring
+/// that live contract state can never be removed by a mistyped key.  No released item has been
+/// retired yet, so the list is empty.
+///
+/// To retire an item, remove its store module, and add its raw key here in the same change along
+/// with a comment naming the last release that wrote it.  A key must never be added while any
+/// item still uses it, and a retired key must never be reused by a new item.
+pub const DEPRECATED_STORAGE_KEYS: &[&str] = &[];
+
+/// A key that is only treated as deprecated by tests, allowing the purge to be exercised without
+/// retiring a real storage item.
+#[cfg(test)]
+pub const TEST_DEPRECATED_STORAGE_KEY: &str = "test_deprecated_item";
+
+/// Determines if the given raw storage key belongs to a deprecated item that may be purged.
+///
+/// # Parameters
+///
+/// * `key` The raw storage key to check.
+pub fn is_deprecated_storage_key<S: AsRef<str>>(key: S) -> bool {
+    let key = key.as_ref();
+    #[cfg(test)]
+    if key == TEST_DEPRECATED_STORAGE_KEY {
+        return true;
+    }
+    DEPRECATED_STORAGE_KEYS.contains(&key)
+}
+
+/// Removes the value stored at a deprecated raw storage key.  Returns true if a value existed and
+/// was removed, or false if nothing was stored at the key.  Keys that are not included in
+/// [DEPRECATED_STORAGE_KEYS] are rejected.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+/// manipulation.
+/// * `key` The raw storage key to purge.
+pub fn purge_deprecated_storage_key<S: AsRef<str>>(
+    storage: &mut dyn Storage,
+    key: S,
+) -> Result<bool, ContractError> {
+    let key = key.as_ref();
+    if !is_deprecated_storage_key(key) {
+        return ContractError::MigrationError {
+            message: format!(
+                "storage key [{key}] is not a known deprecated key and cannot be purged"
+            ),
+        }
+        .to_err();
+    }
+    if storage.get(key.as_bytes()).is_none() {
+        return false.to_ok();
+    }
+    storage.remove(key.as_bytes());
+    true.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::deprecated_storage::{
+        is_deprecated_storage_key, purge_deprecated_storage_key, TEST_DEPRECATED_STORAGE_KEY,
+    };
+    use crate::types::error::ContractError;
+    use cosmwasm_std::Storage;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_purge_deprecated_storage_key() {
+        let mut deps = mock_provenance_dependencies();
+        deps.storage
+            .set(TEST_DEPRECATED_STORAGE_KEY.as_bytes(), b"stale");
+        assert!(
+            purge_deprecated_storage_key(&mut deps.storage, TEST_DEPRECATED_STORAGE_KEY)
+                .expect("purging a stored deprecated key should succeed"),
+            "a stored deprecated key should be reported as removed",
+        );
+        assert!(
+            deps.storage
+                .get(TEST_DEPRECATED_STORAGE_KEY.as_bytes())
+                .is_none(),
+            "the deprecated key should be removed from storage",
+        );
+        assert!(
+            !purge_deprecated_storage_key(&mut deps.storage, TEST_DEPRECATED_STORAGE_KEY)
+                .expect("purging an absent deprecated key should succeed"),
+            "an absent deprecated key should be reported as not removed",
+        );
+    }
+
+    #[test]
+    fn test_purge_rejects_live_storage_keys() {
+        let mut deps = mock_provenance_dependencies();
+        deps.storage.set(b"contract_state_v1", b"live");
+        assert!(
+            !is_deprecated_storage_key("contract_state_v1"),
+            "live keys should not be deprecated",
+        );
+        let error = purge_deprecated_storage_key(&mut deps.storage, "contract_state_v1")
+            .expect_err("purging a live key should fail");
+        assert!(
+            matches!(error, ContractError::MigrationError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+        assert_eq!(
+            Some(b"live".to_vec()),
+            deps.storage.get(b"contract_state_v1"),
+            "the live key should remain in storage",
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use crate::types::error::ContractError;
+use cosmwasm_std::{Addr, Order, Storage};
+use cw_storage_plus::{Bound, Map};
+use result_extensions::ResultExtensions;
+
+const NAMESPACE_FEE_EXEMPTIONS: &str = "fee_exemptions";
+const FEE_EXEMPTIONS: Map<&Addr, ()> = Map::new(NAMESPACE_FEE_EXEMPTIONS);
+
+/// Exempts an account from every fee assessed on its trades: the [msg fee](crate::store::contract_state::ContractStateV1#msg_fee),
+/// the [fund fee](crate::store::contract_state::ContractStateV1#fund_fee_bps) and the
+/// [withdraw fee](crate::store::contract_state::ContractStateV1#withdraw_fee_bps).  A
+/// [ValidationError](ContractError::ValidationError) is returned if the account is already exempt.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+/// manipulation.
+/// * `account` The account to exempt.
+pub fn add_fee_exemption(storage: &mut dyn Storage, account: &Addr) -> Result<(), ContractError> {
+    if is_fee_exempt(storage, account)? {
+        return ContractError::ValidationError {
+            message: format!("account [{account}] is already fee exempt"),
+        }
+        .to_err();
+    }
+    FEE_EXEMPTIONS
+        .save(storage, account, &())
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })
+}
+
+/// Removes an account's fee exemption, causing fees to be assessed on its subsequent trades.  A
+/// [NotFoundError](ContractError::NotFoundError) is returned if the account is not exempt.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+/// manipulation.
+/// * `account` The account whose exemption is removed.
+pub fn remove_fee_exemption(
+    storage: &mut dyn Storage,
+    account: &Addr,
+) -> Result<(), ContractError> {
+    if !is_fee_exempt(storage, account)? {
+        return ContractError::NotFoundError {
+            message: format!("account [{account}] is not fee exempt"),
+        }
+        .to_err();
+    }
+    FEE_EXEMPTIONS.remove(storage, account);
+    ().to_ok()
+}
+
+/// Determines whether or not an account is exempt from the fees assessed on its trades.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+/// fetches.
+/// * `account` The account to check.
+pub fn is_fee_exempt(storage: &dyn Storage, account: &Addr) -> Result<bool, ContractError> {
+    FEE_EXEMPTIONS
+        .may_load(storage, account)
+        .map(|exemption| exemption.is_some())
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })
+}
+
+/// Fetches every fee-exempt account, ordered by address.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+/// fetches.
+/// * `start_after` If provided, only accounts with an address ordered after this value are
+/// returned.
+/// * `limit` The maximum amount of accounts to return.
+pub fn get_fee_exemptions(
+    storage: &dyn Storage,
+    start_after: Option<&Addr>,
+    limit: usize,
+) -> Result<Vec<Addr>, ContractError> {
+    FEE_EXEMPTIONS
+        .keys(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|result| {
+            result.map_err(|e| ContractError::StorageError {
+                message: format!("{e:?}"),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::fee_exemptions::{
+        add_fee_exemption, get_fee_exemptions, is_fee_exempt, remove_fee_exemption,
+    };
+    use crate::types::error::ContractError;
+    use cosmwasm_std::Addr;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_add_and_remove_fee_exemptions() {
+        let mut deps = mock_provenance_dependencies();
+        let account = Addr::unchecked("account");
+        assert!(
+            !is_fee_exempt(&deps.storage, &account).expect("checking an exemption should succeed"),
+            "an unknown account should not be exempt",
+        );
+        add_fee_exemption(&mut deps.storage, &account).expect("adding an exemption should succeed");
+        assert!(
+            is_fee_exempt(&deps.storage, &account).expect("checking an exemption should succeed"),
+            "the added account should be exempt",
+        );
+        let error = add_fee_exemption(&mut deps.storage, &account)
+            .expect_err("adding a duplicate exemption should fail");
+        assert_eq!(
+            "validation failed: account [account] is already fee exempt",
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+        remove_fee_exemption(&mut deps.storage, &account)
+            .expect("removing an exemption should succeed");
+        assert!(
+            !is_fee_exempt(&deps.storage, &account).expect("checking an exemption should succeed"),
+            "the removed account should no longer be exempt",
+        );
+        let error = remove_fee_exemption(&mut deps.storage, &account)
+            .expect_err("removing a missing exemption should fail");
+        assert!(
+            matches!(error, ContractError::NotFoundError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+
+    #[test]
+    fn test_get_fee_exemptions_pages_in_order() {
+        let mut deps = mock_provenance_dependencies();
+        for name in ["third", "first", "second"] {
+            add_fee_exemption(&mut deps.storage, &Addr::unchecked(name))
+                .expect("adding an exemption should succeed");
+        }
+        assert_eq!(
+            vec![Addr::unchecked("first"), Addr::unchecked("second")],
+            get_fee_exemptions(&deps.storage, None, 2).expect("fetching exemptions should succeed"),
+            "the first page should be ordered by address",
+        );
+        assert_eq!(
+            vec![Addr::unchecked("third")],
+            get_fee_exemptions(&deps.storage, Some(&Addr::unchecked("second")), 2)
+                .expect("fetching exemptions should succeed"),
+            "the next page should start after the given address",
+        );
+    }
+}
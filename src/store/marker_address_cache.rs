@@ -0,0 +1,88 @@
+use crate::types::error::ContractError;
+use cosmwasm_std::{Addr, Storage};
+use cw_storage_plus::Item;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const NAMESPACE_MARKER_ADDRESS_CACHE: &str = "marker_address_cache";
+const MARKER_ADDRESS_CACHE: Item<MarkerAddressCache> = Item::new(NAMESPACE_MARKER_ADDRESS_CACHE);
+
+/// The trading marker's account address, stored so that withdraws can direct collected trading
+/// denom to the marker without querying the marker module on every trade.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct MarkerAddressCache {
+    /// The bech32 address of the trading marker's account as of its last lookup.
+    pub trading_marker_address: Addr,
+    /// The amount of withdraws that have used the cached address since it was last compared against
+    /// the marker module.  Only counted while the [verify_marker_cache_every_n_trades](crate::store::runtime_tunables::RuntimeTunables#verify_marker_cache_every_n_trades)
+    /// tunable is set.
+    pub trades_since_verification: u64,
+}
+
+/// Overwrites the existing singleton contract storage instance of [MarkerAddressCache] with the
+/// input reference.  An error is returned if the store write is unsuccessful.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+/// manipulation.
+/// * `cache` The new value for which an internal storage write will be done.
+pub fn set_marker_address_cache(
+    storage: &mut dyn Storage,
+    cache: &MarkerAddressCache,
+) -> Result<(), ContractError> {
+    MARKER_ADDRESS_CACHE
+        .save(storage, cache)
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })
+}
+
+/// Fetches the current contract instance of [MarkerAddressCache].  The cache is populated at
+/// instantiation, but contracts instantiated before it was introduced only populate it on their
+/// first withdraw, so `None` is returned before then.  An error will be returned if store
+/// communication fails.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+/// fetches.
+pub fn get_marker_address_cache(
+    storage: &dyn Storage,
+) -> Result<Option<MarkerAddressCache>, ContractError> {
+    MARKER_ADDRESS_CACHE
+        .may_load(storage)
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::marker_address_cache::{
+        get_marker_address_cache, set_marker_address_cache, MarkerAddressCache,
+    };
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_get_set_marker_address_cache() {
+        let mut deps = mock_provenance_dependencies();
+        assert!(
+            get_marker_address_cache(&deps.storage)
+                .expect("getting the cache before it has been set should succeed")
+                .is_none(),
+            "no cache should be returned before one has been stored",
+        );
+        let cache = MarkerAddressCache {
+            trading_marker_address: deps.api.addr_make("trading-marker"),
+            trades_since_verification: 4,
+        };
+        set_marker_address_cache(&mut deps.storage, &cache)
+            .expect("setting the cache should succeed");
+        assert_eq!(
+            Some(cache),
+            get_marker_address_cache(&deps.storage).expect("getting the cache should succeed"),
+            "expected the cache from storage to equate to the value stored",
+        );
+    }
+}
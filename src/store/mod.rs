@@ -1,4 +1,38 @@
 //! Contains all type definitions and functionality for interacting with contract internal storage.
 
+/// Contains the functionality for interacting with the value that each account has traded today.
+pub mod account_daily_usage;
+/// Contains the functionality for interacting with the trade statistics of each account.
+pub mod account_stats;
+/// Contains the functionality for interacting with the accounts that bypass the required attributes.
+pub mod attribute_whitelist;
+/// Contains the functionality for interacting with the append-only log of administrative actions.
+pub mod audit_log;
+/// Contains the functionality for interacting with the lifetime counters of every executed trade.
+pub mod bridge_stats;
+/// Contains the functionality for interacting with the chunked attribute update that is in progress.
+pub mod bulk_attribute_update;
 /// Contains the functionality for interacting with the singleton contract state value.
 pub mod contract_state;
+/// Contains the functionality for checking fund trades against the contract's daily cap.
+pub mod daily_volume;
+/// Contains the functionality for interacting with the accounts blocked from trading.
+pub mod denylist;
+/// Contains the functionality for removing storage keys retired by previous releases.
+pub mod deprecated_storage;
+/// Contains the functionality for interacting with the accounts exempt from trade fees.
+pub mod fee_exemptions;
+/// Contains the functionality for interacting with the cached address of the trading marker.
+pub mod marker_address_cache;
+/// Contains the functionality for interacting with the address proposed to become the contract admin.
+pub mod pending_admin;
+/// Contains the functionality for interacting with trades that are awaiting admin approval.
+pub mod pending_trades;
+/// Contains the functionality for interacting with the trade statistics credited to referrers.
+pub mod referral_stats;
+/// Contains the functionality for interacting with the singleton runtime tunables value.
+pub mod runtime_tunables;
+/// Contains the functionality for interacting with the numbered records of executed trades.
+pub mod trade_receipts;
+/// Contains the functionality for interacting with the aggregate statistics of executed trades.
+pub mod trade_stats;
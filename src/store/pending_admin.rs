@@ -0,0 +1,75 @@
+use crate::types::error::ContractError;
+use cosmwasm_std::{Addr, Storage};
+use cw_storage_plus::Item;
+
+const NAMESPACE_PENDING_ADMIN: &str = "pending_admin";
+const PENDING_ADMIN: Item<Option<Addr>> = Item::new(NAMESPACE_PENDING_ADMIN);
+
+/// Overwrites the address proposed to become the contract admin.  Storing `None` discards any
+/// proposal.  An error is returned if the store write is unsuccessful.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+/// manipulation.
+/// * `pending_admin` The bech32 address proposed to become the contract admin, or `None` to
+/// discard the proposal.
+pub fn set_pending_admin(
+    storage: &mut dyn Storage,
+    pending_admin: Option<&Addr>,
+) -> Result<(), ContractError> {
+    PENDING_ADMIN
+        .save(storage, &pending_admin.cloned())
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })
+}
+
+/// Fetches the address proposed to become the contract admin.  `None` is returned when no proposal
+/// is awaiting acceptance, including for contracts that have never stored one.  An error will be
+/// returned if store communication fails.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+/// fetches.
+pub fn get_pending_admin(storage: &dyn Storage) -> Result<Option<Addr>, ContractError> {
+    PENDING_ADMIN
+        .may_load(storage)
+        .map(Option::flatten)
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::pending_admin::{get_pending_admin, set_pending_admin};
+    use cosmwasm_std::Addr;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_get_set_pending_admin() {
+        let mut deps = mock_provenance_dependencies();
+        assert!(
+            get_pending_admin(&deps.storage)
+                .expect("the pending admin should load before it is stored")
+                .is_none(),
+            "no pending admin should exist before one is stored",
+        );
+        set_pending_admin(&mut deps.storage, Some(&Addr::unchecked("proposed")))
+            .expect("the pending admin should be stored");
+        assert_eq!(
+            Some(Addr::unchecked("proposed")),
+            get_pending_admin(&deps.storage).expect("the pending admin should load"),
+            "the stored pending admin should be returned",
+        );
+        set_pending_admin(&mut deps.storage, None).expect("the pending admin should be cleared");
+        assert!(
+            get_pending_admin(&deps.storage)
+                .expect("the pending admin should load after it is cleared")
+                .is_none(),
+            "no pending admin should exist after it is cleared",
+        );
+    }
+}
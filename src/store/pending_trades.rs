@@ -0,0 +1,237 @@
+use crate::types::error::ContractError;
+use crate::types::quote::TradeDirection;
+use cosmwasm_std::{Addr, Order, Storage, Uint128};
+use cw_storage_plus::{Bound, Item, Map};
+use result_extensions::ResultExtensions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const NAMESPACE_PENDING_TRADE_SEQUENCE: &str = "pending_trade_sequence";
+const NAMESPACE_PENDING_TRADES: &str = "pending_trades";
+const PENDING_TRADE_SEQUENCE: Item<u64> = Item::new(NAMESPACE_PENDING_TRADE_SEQUENCE);
+const PENDING_TRADES: Map<u64, PendingTrade> = Map::new(NAMESPACE_PENDING_TRADES);
+
+/// A trade at or above the contract's [large trade threshold](crate::store::contract_state::ContractStateV1#large_trade_threshold)
+/// that has passed every check, but that will not execute until the contract admin approves it.
+/// Entries are removed when they are approved or rejected.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct PendingTrade {
+    /// The sequential identifier assigned to this trade when it was stored.
+    pub id: u64,
+    /// The direction of the requested trade.
+    pub direction: TradeDirection,
+    /// The bech32 address of the account that requested the trade, and that will be traded against
+    /// when it is approved.
+    pub sender: Addr,
+    /// The amount of input denom requested by the trade.
+    pub trade_amount: Uint128,
+    /// The block height at which the trade was requested.
+    pub submitted_at_height: u64,
+    /// The last block height at which the trade can be approved.
+    pub expires_at_height: u64,
+    /// The account named as the trade's referrer, which is credited when the trade is approved.
+    #[serde(default)]
+    pub referrer: Option<Addr>,
+    /// Whether or not the sender acknowledged that the trade may leave an unconvertible remainder
+    /// behind, which the approval requires again if the conversion still produces one.
+    #[serde(default)]
+    pub allow_remainder: bool,
+}
+impl PendingTrade {
+    /// Determines whether or not the trade can no longer be approved at the given block height.
+    ///
+    /// # Parameters
+    /// * `height` The block height to check against the trade's expiration.
+    pub fn is_expired(&self, height: u64) -> bool {
+        height > self.expires_at_height
+    }
+}
+
+/// Stores a new pending trade, assigning it the next sequential identifier.  The identifier of the
+/// trade's input value is ignored.  Returns the stored trade.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+/// manipulation.
+/// * `trade` The trade to store.
+pub fn insert_pending_trade(
+    storage: &mut dyn Storage,
+    trade: PendingTrade,
+) -> Result<PendingTrade, ContractError> {
+    let id = PENDING_TRADE_SEQUENCE
+        .may_load(storage)
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })?
+        .unwrap_or_default()
+        + 1;
+    let trade = PendingTrade { id, ..trade };
+    PENDING_TRADE_SEQUENCE
+        .save(storage, &id)
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })?;
+    PENDING_TRADES
+        .save(storage, id, &trade)
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })?;
+    trade.to_ok()
+}
+
+/// Fetches the pending trade with the given identifier.  A [NotFoundError](ContractError::NotFoundError)
+/// is returned if no such trade is stored.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+/// fetches.
+/// * `id` The identifier of the trade to fetch.
+pub fn get_pending_trade(storage: &dyn Storage, id: u64) -> Result<PendingTrade, ContractError> {
+    PENDING_TRADES
+        .may_load(storage, id)
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })?
+        .ok_or_else(|| ContractError::NotFoundError {
+            message: format!("no pending trade exists with id [{id}]"),
+        })
+}
+
+/// Removes the pending trade with the given identifier.  Removing a trade that does not exist has
+/// no effect.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+/// manipulation.
+/// * `id` The identifier of the trade to remove.
+pub fn remove_pending_trade(storage: &mut dyn Storage, id: u64) {
+    PENDING_TRADES.remove(storage, id);
+}
+
+/// Fetches stored pending trades ordered from oldest to newest.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+/// fetches.
+/// * `start_after` If provided, only trades with an identifier greater than this value are returned.
+/// * `limit` The maximum amount of trades to return.
+pub fn get_pending_trades(
+    storage: &dyn Storage,
+    start_after: Option<u64>,
+    limit: usize,
+) -> Result<Vec<PendingTrade>, ContractError> {
+    PENDING_TRADES
+        .range(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|result| {
+            result
+                .map(|(_, trade)| trade)
+                .map_err(|e| ContractError::StorageError {
+                    message: format!("{e:?}"),
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::pending_trades::{
+        get_pending_trade, get_pending_trades, insert_pending_trade, remove_pending_trade,
+        PendingTrade,
+    };
+    use crate::types::error::ContractError;
+    use crate::types::quote::TradeDirection;
+    use cosmwasm_std::{Addr, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    fn trade(amount: u128) -> PendingTrade {
+        PendingTrade {
+            id: 0,
+            direction: TradeDirection::Fund,
+            sender: Addr::unchecked("sender"),
+            trade_amount: Uint128::new(amount),
+            submitted_at_height: 10,
+            expires_at_height: 20,
+            referrer: None,
+            allow_remainder: false,
+        }
+    }
+
+    #[test]
+    fn test_insert_get_and_remove_pending_trades() {
+        let mut deps = mock_provenance_dependencies();
+        let first = insert_pending_trade(&mut deps.storage, trade(100))
+            .expect("inserting the first trade should succeed");
+        let second = insert_pending_trade(&mut deps.storage, trade(200))
+            .expect("inserting the second trade should succeed");
+        assert_eq!(1, first.id, "the first trade should receive the first id");
+        assert_eq!(2, second.id, "the second trade should receive the next id");
+        assert_eq!(
+            second,
+            get_pending_trade(&deps.storage, 2).expect("the second trade should be fetched"),
+            "the stored trade should be returned",
+        );
+        remove_pending_trade(&mut deps.storage, 1);
+        let error = get_pending_trade(&deps.storage, 1)
+            .expect_err("a removed trade should no longer be found");
+        assert!(
+            matches!(error, ContractError::NotFoundError { .. }),
+            "unexpected error emitted for a removed trade: {error:?}",
+        );
+        let third = insert_pending_trade(&mut deps.storage, trade(300))
+            .expect("inserting the third trade should succeed");
+        assert_eq!(
+            3, third.id,
+            "ids should not be reused after a trade is removed",
+        );
+    }
+
+    #[test]
+    fn test_get_pending_trades_pages_in_order() {
+        let mut deps = mock_provenance_dependencies();
+        for amount in [100, 200, 300] {
+            insert_pending_trade(&mut deps.storage, trade(amount))
+                .expect("inserting a trade should succeed");
+        }
+        let ids = |start_after, limit| {
+            get_pending_trades(&deps.storage, start_after, limit)
+                .expect("fetching pending trades should succeed")
+                .into_iter()
+                .map(|trade| trade.id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            vec![1, 2, 3],
+            ids(None, 10),
+            "all trades should be returned"
+        );
+        assert_eq!(vec![1, 2], ids(None, 2), "the limit should be respected");
+        assert_eq!(
+            vec![3],
+            ids(Some(2), 10),
+            "only trades after the start should be returned",
+        );
+    }
+
+    #[test]
+    fn test_pending_trade_expiration() {
+        let trade = trade(100);
+        assert!(
+            !trade.is_expired(20),
+            "a trade should be approvable at its expiration height",
+        );
+        assert!(
+            trade.is_expired(21),
+            "a trade should be expired after its expiration height",
+        );
+    }
+}
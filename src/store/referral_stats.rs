@@ -0,0 +1,161 @@
+use crate::types::error::ContractError;
+use cosmwasm_std::{Addr, Order, Storage, Uint128};
+use cw_storage_plus::{Bound, Map};
+use result_extensions::ResultExtensions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const NAMESPACE_REFERRAL_STATS: &str = "referral_stats";
+const REFERRAL_STATS: Map<&Addr, ReferralStats> = Map::new(NAMESPACE_REFERRAL_STATS);
+
+/// The volume of trades that an account has referred to the contract.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+pub struct ReferralStats {
+    /// The amount of successful trades that named the account as their referrer.
+    pub trade_count: u64,
+    /// The combined value of those trades, expressed as an amount of deposit denom: the amount
+    /// collected by each fund, and the amount released by each withdraw.
+    pub volume: Uint128,
+}
+
+/// Credits a successful trade to its referrer, incrementing the referrer's trade count and adding
+/// the trade's value to its volume.  Returns the referrer's updated stats.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+/// manipulation.
+/// * `referrer` The account named as the trade's referrer.
+/// * `deposit_amount` The value of the trade, expressed as an amount of deposit denom.
+pub fn record_referral(
+    storage: &mut dyn Storage,
+    referrer: &Addr,
+    deposit_amount: Uint128,
+) -> Result<ReferralStats, ContractError> {
+    let stats = get_referral_stats(storage, referrer)?;
+    let stats = ReferralStats {
+        trade_count: stats.trade_count.saturating_add(1),
+        volume: stats.volume.checked_add(deposit_amount)?,
+    };
+    REFERRAL_STATS
+        .save(storage, referrer, &stats)
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })?;
+    stats.to_ok()
+}
+
+/// Fetches the referral stats for an account.  Accounts that have never been credited with a
+/// referral receive empty stats.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+/// fetches.
+/// * `referrer` The account for which to fetch stats.
+pub fn get_referral_stats(
+    storage: &dyn Storage,
+    referrer: &Addr,
+) -> Result<ReferralStats, ContractError> {
+    REFERRAL_STATS
+        .may_load(storage, referrer)
+        .map(|stats| stats.unwrap_or_default())
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })
+}
+
+/// Fetches the referral stats of every credited referrer, ordered by address.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+/// fetches.
+/// * `start_after` If provided, only referrers with an address ordered after this value are
+/// returned.
+/// * `limit` The maximum amount of referrers to return.
+pub fn get_all_referral_stats(
+    storage: &dyn Storage,
+    start_after: Option<&Addr>,
+    limit: usize,
+) -> Result<Vec<(Addr, ReferralStats)>, ContractError> {
+    REFERRAL_STATS
+        .range(
+            storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|result| {
+            result.map_err(|e| ContractError::StorageError {
+                message: format!("{e:?}"),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::referral_stats::{
+        get_all_referral_stats, get_referral_stats, record_referral, ReferralStats,
+    };
+    use cosmwasm_std::{Addr, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_record_and_get_referral_stats() {
+        let mut deps = mock_provenance_dependencies();
+        let referrer = Addr::unchecked("referrer");
+        assert_eq!(
+            ReferralStats::default(),
+            get_referral_stats(&deps.storage, &referrer)
+                .expect("fetching stats for an unknown referrer should succeed"),
+            "an unknown referrer should have empty stats",
+        );
+        record_referral(&mut deps.storage, &referrer, Uint128::new(100))
+            .expect("recording the first referral should succeed");
+        let stats = record_referral(&mut deps.storage, &referrer, Uint128::new(50))
+            .expect("recording the second referral should succeed");
+        assert_eq!(
+            ReferralStats {
+                trade_count: 2,
+                volume: Uint128::new(150),
+            },
+            stats,
+            "each referral should increment the count and add to the volume",
+        );
+        assert_eq!(
+            stats,
+            get_referral_stats(&deps.storage, &referrer).expect("fetching stats should succeed"),
+            "the recorded stats should be persisted",
+        );
+    }
+
+    #[test]
+    fn test_get_all_referral_stats_pages_in_order() {
+        let mut deps = mock_provenance_dependencies();
+        for name in ["c", "a", "b"] {
+            record_referral(&mut deps.storage, &Addr::unchecked(name), Uint128::new(1))
+                .expect("recording a referral should succeed");
+        }
+        let names = |start_after: Option<&Addr>, limit| {
+            get_all_referral_stats(&deps.storage, start_after, limit)
+                .expect("fetching all referral stats should succeed")
+                .into_iter()
+                .map(|(referrer, _)| referrer.to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            vec!["a", "b", "c"],
+            names(None, 10),
+            "every referrer should be returned in order",
+        );
+        assert_eq!(
+            vec!["c"],
+            names(Some(&Addr::unchecked("b")), 10),
+            "only referrers after the start should be returned",
+        );
+        assert_eq!(vec!["a"], names(None, 1), "the limit should be respected");
+    }
+}
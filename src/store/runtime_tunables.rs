@@ -0,0 +1,228 @@
+use crate::types::error::ContractError;
+use cosmwasm_std::Storage;
+use cw_storage_plus::Item;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const NAMESPACE_RUNTIME_TUNABLES: &str = "runtime_tunables";
+const RUNTIME_TUNABLES: Item<RuntimeTunables> = Item::new(NAMESPACE_RUNTIME_TUNABLES);
+
+/// The default amount of attributes requested per page when verifying account attributes.
+pub const DEFAULT_ATTRIBUTE_PAGE_SIZE: u32 = 25;
+/// The default maximum amount of attribute pages fetched when verifying account attributes.
+pub const DEFAULT_ATTRIBUTE_MAX_PAGES: u32 = 10;
+/// The smallest allowed value for [attribute_page_size](RuntimeTunables#attribute_page_size).
+pub const MIN_ATTRIBUTE_PAGE_SIZE: u32 = 1;
+/// The largest allowed value for [attribute_page_size](RuntimeTunables#attribute_page_size).
+pub const MAX_ATTRIBUTE_PAGE_SIZE: u32 = 100;
+/// The smallest allowed value for [attribute_max_pages](RuntimeTunables#attribute_max_pages).
+pub const MIN_ATTRIBUTE_MAX_PAGES: u32 = 1;
+/// The largest allowed value for [attribute_max_pages](RuntimeTunables#attribute_max_pages).
+pub const MAX_ATTRIBUTE_MAX_PAGES: u32 = 50;
+/// The default amount of blocks after its quoted height for which a trade quote remains valid.
+pub const DEFAULT_QUOTE_VALIDITY_BLOCKS: u64 = 100;
+/// The smallest allowed value for [quote_validity_blocks](RuntimeTunables#quote_validity_blocks).
+pub const MIN_QUOTE_VALIDITY_BLOCKS: u64 = 1;
+/// The largest allowed value for [quote_validity_blocks](RuntimeTunables#quote_validity_blocks).
+pub const MAX_QUOTE_VALIDITY_BLOCKS: u64 = 10000;
+/// The default amount of blocks after its submission for which a pending trade can be approved.
+pub const DEFAULT_PENDING_TRADE_EXPIRATION_BLOCKS: u64 = 14400;
+/// The smallest allowed value for [pending_trade_expiration_blocks](RuntimeTunables#pending_trade_expiration_blocks).
+pub const MIN_PENDING_TRADE_EXPIRATION_BLOCKS: u64 = 1;
+/// The largest allowed value for [pending_trade_expiration_blocks](RuntimeTunables#pending_trade_expiration_blocks).
+pub const MAX_PENDING_TRADE_EXPIRATION_BLOCKS: u64 = 100800;
+/// The smallest allowed value for [verify_marker_cache_every_n_trades](RuntimeTunables#verify_marker_cache_every_n_trades).
+pub const MIN_VERIFY_MARKER_CACHE_EVERY_N_TRADES: u64 = 1;
+
+/// Controls how many attributes the [fund_trading](crate::execute::fund_trading::fund_trading)
+/// and [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading) routes emit for an
+/// executed trade, allowing the size of hot route responses to be trimmed.  Dry runs and trades
+/// held for approval emit their additional outcome attributes at every level.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EventVerbosity {
+    /// Emits only the `action`, `pair`, `sender`, `input`, and `output` attributes.  The input and
+    /// output are coins, like `100nhash`, and the pair names the deposit and trading denoms
+    /// separated by a slash.
+    Minimal,
+    /// Emits the contract identification attributes shared by every route, followed by the route's
+    /// input and received denoms and amounts, and the `partial_fill`, `filled_amount`, `referrer`,
+    /// `marker_cache_warning`, and `msg_fee` attributes when they apply.
+    #[default]
+    Standard,
+    /// Emits every [Standard](EventVerbosity::Standard) attribute, followed by the `sender`, `pair`,
+    /// `remainder`, `requires_approval`, and `block_height` attributes.
+    Verbose,
+}
+impl EventVerbosity {
+    /// The name of the verbosity level, matching its serialized form.
+    pub fn name(&self) -> &'static str {
+        match self {
+            EventVerbosity::Minimal => "minimal",
+            EventVerbosity::Standard => "standard",
+            EventVerbosity::Verbose => "verbose",
+        }
+    }
+}
+
+/// Stores helper behaviors that the contract admin can adjust without a code release.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RuntimeTunables {
+    /// The amount of attributes requested per page when verifying that an account holds the
+    /// attributes required by a trade route.
+    pub attribute_page_size: u32,
+    /// The maximum amount of attribute pages fetched when verifying that an account holds the
+    /// attributes required by a trade route.  Accounts whose required attributes are not found
+    /// within this many pages are treated as missing them.
+    pub attribute_max_pages: u32,
+    /// The amount of blocks after its quoted height for which a [trade quote](crate::types::quote::TradeQuote)
+    /// can still be executed against.  Tunables stored before this value existed use the default.
+    #[serde(default = "default_quote_validity_blocks")]
+    pub quote_validity_blocks: u64,
+    /// The amount of blocks after its submission for which a [pending trade](crate::store::pending_trades::PendingTrade)
+    /// can still be approved by the contract admin.  Tunables stored before this value existed use
+    /// the default.
+    #[serde(default = "default_pending_trade_expiration_blocks")]
+    pub pending_trade_expiration_blocks: u64,
+    /// If provided, every Nth withdraw re-queries the trading marker and compares its address to
+    /// the [cached address](crate::store::marker_address_cache::MarkerAddressCache), repairing the
+    /// cache when they differ.  When omitted, withdraws never query the marker once the cache is
+    /// populated.
+    #[serde(default)]
+    pub verify_marker_cache_every_n_trades: Option<u64>,
+    /// The amount of attributes emitted by the trade routes for each executed trade.  Tunables
+    /// stored before this value existed use [Standard](EventVerbosity::Standard).
+    #[serde(default)]
+    pub event_verbosity: EventVerbosity,
+    /// When enabled, every trade planned by the [fund_trading](crate::execute::fund_trading::fund_trading)
+    /// and [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading) routes, including
+    /// rebalances and pending trade approvals, queries both markers and is rejected if the contract
+    /// lacks a required marker permission.
+    /// The permissions are always verified at instantiation, so this is disabled by default to
+    /// avoid querying the markers on every trade.
+    #[serde(default)]
+    pub verify_marker_access_on_trade: bool,
+}
+impl Default for RuntimeTunables {
+    fn default() -> Self {
+        Self {
+            attribute_page_size: DEFAULT_ATTRIBUTE_PAGE_SIZE,
+            attribute_max_pages: DEFAULT_ATTRIBUTE_MAX_PAGES,
+            quote_validity_blocks: DEFAULT_QUOTE_VALIDITY_BLOCKS,
+            pending_trade_expiration_blocks: DEFAULT_PENDING_TRADE_EXPIRATION_BLOCKS,
+            verify_marker_cache_every_n_trades: None,
+            event_verbosity: EventVerbosity::Standard,
+            verify_marker_access_on_trade: false,
+        }
+    }
+}
+
+fn default_quote_validity_blocks() -> u64 {
+    DEFAULT_QUOTE_VALIDITY_BLOCKS
+}
+
+fn default_pending_trade_expiration_blocks() -> u64 {
+    DEFAULT_PENDING_TRADE_EXPIRATION_BLOCKS
+}
+
+/// Overwrites the existing singleton contract storage instance of [RuntimeTunables] with the input
+/// reference.  An error is returned if the store write is unsuccessful.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+/// manipulation.
+/// * `tunables` The new value for which an internal storage write will be done.
+pub fn set_runtime_tunables(
+    storage: &mut dyn Storage,
+    tunables: &RuntimeTunables,
+) -> Result<(), ContractError> {
+    RUNTIME_TUNABLES
+        .save(storage, tunables)
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })
+}
+
+/// Fetches the current contract instance of [RuntimeTunables].  Contracts instantiated before the
+/// tunables existed have no stored value, so the [default](RuntimeTunables::default) values are
+/// returned in that case.  An error will be returned if store communication fails.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+/// fetches.
+pub fn get_runtime_tunables(storage: &dyn Storage) -> Result<RuntimeTunables, ContractError> {
+    RUNTIME_TUNABLES
+        .may_load(storage)
+        .map(|tunables| tunables.unwrap_or_default())
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::runtime_tunables::{
+        get_runtime_tunables, set_runtime_tunables, EventVerbosity, RuntimeTunables,
+        DEFAULT_PENDING_TRADE_EXPIRATION_BLOCKS, DEFAULT_QUOTE_VALIDITY_BLOCKS,
+    };
+    use cosmwasm_std::from_json;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_get_set_runtime_tunables() {
+        let mut deps = mock_provenance_dependencies();
+        assert_eq!(
+            RuntimeTunables::default(),
+            get_runtime_tunables(&deps.storage)
+                .expect("getting tunables before they have been set should succeed"),
+            "the default tunables should be returned when none have been stored",
+        );
+        let tunables = RuntimeTunables {
+            attribute_page_size: 50,
+            attribute_max_pages: 3,
+            quote_validity_blocks: 20,
+            pending_trade_expiration_blocks: 500,
+            verify_marker_cache_every_n_trades: Some(10),
+            event_verbosity: EventVerbosity::Minimal,
+            verify_marker_access_on_trade: true,
+        };
+        set_runtime_tunables(&mut deps.storage, &tunables)
+            .expect("setting tunables should succeed");
+        assert_eq!(
+            tunables,
+            get_runtime_tunables(&deps.storage).expect("getting tunables should succeed"),
+            "expected the tunables from storage to equate to the value stored",
+        );
+    }
+
+    #[test]
+    fn test_tunables_without_newer_values_use_the_defaults() {
+        let tunables =
+            from_json::<RuntimeTunables>(r#"{"attribute_page_size":50,"attribute_max_pages":3}"#)
+                .expect("tunables stored before the newer values existed should deserialize");
+        assert_eq!(
+            DEFAULT_QUOTE_VALIDITY_BLOCKS, tunables.quote_validity_blocks,
+            "the default quote validity blocks should be used when the value is missing",
+        );
+        assert_eq!(
+            DEFAULT_PENDING_TRADE_EXPIRATION_BLOCKS, tunables.pending_trade_expiration_blocks,
+            "the default pending trade expiration blocks should be used when the value is missing",
+        );
+        assert!(
+            tunables.verify_marker_cache_every_n_trades.is_none(),
+            "marker cache verification should be disabled when the value is missing",
+        );
+        assert_eq!(
+            EventVerbosity::Standard,
+            tunables.event_verbosity,
+            "the standard event verbosity should be used when the value is missing",
+        );
+        assert!(
+            !tunables.verify_marker_access_on_trade,
+            "marker access verification should be disabled when the value is missing",
+        );
+    }
+}
@@ -0,0 +1,262 @@
+use crate::types::error::ContractError;
+use crate::types::quote::TradeDirection;
+use cosmwasm_std::{Addr, BlockInfo, Coin, Order, Storage, Timestamp, Uint128};
+use cw_storage_plus::{Bound, Item, Map};
+use result_extensions::ResultExtensions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const NAMESPACE_TRADE_RECEIPT_SEQUENCE: &str = "trade_receipt_sequence";
+const NAMESPACE_TRADE_RECEIPTS: &str = "trade_receipts";
+const TRADE_RECEIPT_SEQUENCE: Item<u64> = Item::new(NAMESPACE_TRADE_RECEIPT_SEQUENCE);
+const TRADE_RECEIPTS: Map<u64, TradeReceipt> = Map::new(NAMESPACE_TRADE_RECEIPTS);
+
+/// The record of a single executed trade, identified by a receipt number that can be shared with
+/// support staff in place of a transaction hash.  Receipts are assigned from a single sequence
+/// shared by both trade directions, so they increase by exactly one with each executed trade,
+/// allowing the receipts to be paged in either direction as the contract's trade history.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct TradeReceipt {
+    /// The sequential receipt number assigned when the trade executed.
+    pub receipt: u64,
+    /// The bech32 address of the account whose funds were traded.
+    pub account: Addr,
+    /// The direction of the trade.
+    pub direction: TradeDirection,
+    /// The funds collected from the account.
+    pub input: Coin,
+    /// The funds delivered to the account.
+    pub output: Coin,
+    /// The amount of input denom requested by the account that could not be converted, and was
+    /// therefore left in the account.  Receipts issued before this value was recorded report zero.
+    #[serde(default)]
+    pub remainder: Uint128,
+    /// The block height at which the trade executed.
+    pub block_height: u64,
+    /// The block time at which the trade executed.
+    pub block_time: Timestamp,
+}
+
+/// Stores a record of an executed trade, assigning it the next receipt number.  Returns the
+/// stored receipt.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+/// manipulation.
+/// * `block` The block in which the trade executed.
+/// * `direction` The direction of the trade.
+/// * `account` The account whose funds were traded.
+/// * `input` The funds collected from the account.
+/// * `output` The funds delivered to the account.
+/// * `remainder` The amount of input denom that could not be converted by the trade.
+pub fn issue_trade_receipt(
+    storage: &mut dyn Storage,
+    block: &BlockInfo,
+    direction: TradeDirection,
+    account: &Addr,
+    input: Coin,
+    output: Coin,
+    remainder: Uint128,
+) -> Result<TradeReceipt, ContractError> {
+    let receipt = TRADE_RECEIPT_SEQUENCE
+        .may_load(storage)
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })?
+        .unwrap_or_default()
+        + 1;
+    let trade_receipt = TradeReceipt {
+        receipt,
+        account: account.to_owned(),
+        direction,
+        input,
+        output,
+        remainder,
+        block_height: block.height,
+        block_time: block.time,
+    };
+    TRADE_RECEIPT_SEQUENCE
+        .save(storage, &receipt)
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })?;
+    TRADE_RECEIPTS
+        .save(storage, receipt, &trade_receipt)
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })?;
+    trade_receipt.to_ok()
+}
+
+/// Fetches the record of the trade that was issued the given receipt number.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+/// fetches.
+/// * `receipt` The receipt number of the trade to fetch.
+pub fn get_trade_receipt(
+    storage: &dyn Storage,
+    receipt: u64,
+) -> Result<TradeReceipt, ContractError> {
+    TRADE_RECEIPTS
+        .may_load(storage, receipt)
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })?
+        .ok_or_else(|| ContractError::NotFoundError {
+            message: format!("no trade exists with receipt [{receipt}]"),
+        })
+}
+
+/// Fetches a page of the receipts issued to executed trades.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+/// fetches.
+/// * `start_after` If provided, only receipts ordered after the receipt with this number are
+/// returned.
+/// * `limit` The maximum amount of receipts to return.
+/// * `descending` True to order the receipts from newest to oldest, false to order them from
+/// oldest to newest.
+pub fn get_trade_receipts(
+    storage: &dyn Storage,
+    start_after: Option<u64>,
+    limit: usize,
+    descending: bool,
+) -> Result<Vec<TradeReceipt>, ContractError> {
+    let bound = start_after.map(Bound::exclusive);
+    let (min, max, order) = if descending {
+        (None, bound, Order::Descending)
+    } else {
+        (bound, None, Order::Ascending)
+    };
+    TRADE_RECEIPTS
+        .range(storage, min, max, order)
+        .take(limit)
+        .map(|result| {
+            result.map(|(_, trade_receipt)| trade_receipt).map_err(|e| {
+                ContractError::StorageError {
+                    message: format!("{e:?}"),
+                }
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::trade_receipts::{
+        get_trade_receipt, get_trade_receipts, issue_trade_receipt,
+    };
+    use crate::types::error::ContractError;
+    use crate::types::quote::TradeDirection;
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{coin, Addr, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_issue_and_get_trade_receipts() {
+        let mut deps = mock_provenance_dependencies();
+        let block = mock_env().block;
+        let account = Addr::unchecked("account");
+        let error = get_trade_receipt(&deps.storage, 1)
+            .expect_err("an error should occur before any receipt is issued");
+        assert!(
+            matches!(&error, ContractError::NotFoundError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+        let first = issue_trade_receipt(
+            &mut deps.storage,
+            &block,
+            TradeDirection::Fund,
+            &account,
+            coin(100, "deposit"),
+            coin(1000000, "trading"),
+            Uint128::zero(),
+        )
+        .expect("issuing a receipt should succeed");
+        let second = issue_trade_receipt(
+            &mut deps.storage,
+            &block,
+            TradeDirection::Withdraw,
+            &account,
+            coin(1000000, "trading"),
+            coin(100, "deposit"),
+            Uint128::new(3),
+        )
+        .expect("issuing a receipt should succeed");
+        assert_eq!(1, first.receipt, "the first receipt should be number one");
+        assert_eq!(2, second.receipt, "receipts should increase sequentially");
+        assert_eq!(
+            first,
+            get_trade_receipt(&deps.storage, 1).expect("the first receipt should load"),
+            "the first receipt should be stored",
+        );
+        assert_eq!(
+            second,
+            get_trade_receipt(&deps.storage, 2).expect("the second receipt should load"),
+            "the second receipt should be stored",
+        );
+        assert_eq!(block.height, second.block_height);
+        assert_eq!(block.time, second.block_time);
+        assert_eq!(Uint128::new(3), second.remainder);
+    }
+
+    #[test]
+    fn test_page_trade_receipts() {
+        let mut deps = mock_provenance_dependencies();
+        let block = mock_env().block;
+        assert!(
+            get_trade_receipts(&deps.storage, None, 10, false)
+                .expect("the receipts should load before any trade executes")
+                .is_empty(),
+            "no receipts should exist before any trade executes",
+        );
+        for direction in [
+            TradeDirection::Fund,
+            TradeDirection::Withdraw,
+            TradeDirection::Fund,
+        ] {
+            issue_trade_receipt(
+                &mut deps.storage,
+                &block,
+                direction,
+                &Addr::unchecked("account"),
+                coin(100, "input"),
+                coin(10, "output"),
+                Uint128::zero(),
+            )
+            .expect("the receipt should be issued");
+        }
+        let receipts = |start_after: Option<u64>, limit: usize, descending: bool| {
+            get_trade_receipts(&deps.storage, start_after, limit, descending)
+                .expect("the receipts should load")
+                .into_iter()
+                .map(|trade_receipt| trade_receipt.receipt)
+                .collect::<Vec<u64>>()
+        };
+        assert_eq!(
+            vec![1, 2, 3],
+            receipts(None, 10, false),
+            "receipts should be ordered from oldest to newest",
+        );
+        assert_eq!(
+            vec![3, 2, 1],
+            receipts(None, 10, true),
+            "receipts should be ordered from newest to oldest",
+        );
+        assert_eq!(
+            vec![2],
+            receipts(Some(1), 1, false),
+            "ascending pages should start after the given receipt",
+        );
+        assert_eq!(
+            vec![2, 1],
+            receipts(Some(3), 10, true),
+            "descending pages should start before the given receipt",
+        );
+    }
+}
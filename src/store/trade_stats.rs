@@ -0,0 +1,262 @@
+use crate::types::error::ContractError;
+use crate::types::quote::TradeDirection;
+use cosmwasm_std::{Addr, Empty, Storage, Timestamp, Uint128};
+use cw_storage_plus::{Item, Map};
+use result_extensions::ResultExtensions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const NAMESPACE_TRADE_STATS: &str = "trade_stats";
+const NAMESPACE_DAILY_TRADE_TOTALS: &str = "daily_trade_totals";
+const NAMESPACE_TRADED_ACCOUNTS: &str = "traded_accounts";
+const TRADE_STATS: Item<TradeStats> = Item::new(NAMESPACE_TRADE_STATS);
+const DAILY_TRADE_TOTALS: Map<u64, TradeTotals> = Map::new(NAMESPACE_DAILY_TRADE_TOTALS);
+const TRADED_ACCOUNTS: Map<&Addr, Empty> = Map::new(NAMESPACE_TRADED_ACCOUNTS);
+
+/// The amount of seconds covered by each day of [daily trade totals](get_daily_trade_totals).
+pub const SECONDS_PER_DAY: u64 = 86400;
+
+/// The amount and value of the trades executed in each direction.  Values are expressed as an
+/// amount of deposit denom: the amount collected by each fund, and the amount released by each
+/// withdraw.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+pub struct TradeTotals {
+    /// The amount of executed fund trades.
+    pub fund_count: u64,
+    /// The combined value of the executed fund trades.
+    pub fund_volume: Uint128,
+    /// The amount of executed withdraw trades.
+    pub withdraw_count: u64,
+    /// The combined value of the executed withdraw trades.
+    pub withdraw_volume: Uint128,
+}
+impl TradeTotals {
+    fn add_trade(
+        &self,
+        direction: TradeDirection,
+        deposit_amount: Uint128,
+    ) -> Result<Self, ContractError> {
+        match direction {
+            TradeDirection::Fund => Self {
+                fund_count: self.fund_count.saturating_add(1),
+                fund_volume: self.fund_volume.checked_add(deposit_amount)?,
+                ..self.to_owned()
+            },
+            TradeDirection::Withdraw => Self {
+                withdraw_count: self.withdraw_count.saturating_add(1),
+                withdraw_volume: self.withdraw_volume.checked_add(deposit_amount)?,
+                ..self.to_owned()
+            },
+        }
+        .to_ok()
+    }
+}
+
+/// Aggregates describing every trade executed by the contract.  These are maintained as each
+/// trade executes, allowing queries to report contract-wide activity without iterating over
+/// per-account or per-day storage.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, JsonSchema)]
+pub struct TradeStats {
+    /// The totals of every executed trade since the stats were introduced.
+    pub totals: TradeTotals,
+    /// The amount of distinct accounts that have executed at least one trade.
+    pub account_count: u64,
+}
+
+/// Converts a block time to the index of the day that contains it, as used by [daily trade totals](get_daily_trade_totals).
+///
+/// # Parameters
+///
+/// * `time` The block time to convert.
+pub fn day_of(time: &Timestamp) -> u64 {
+    time.seconds() / SECONDS_PER_DAY
+}
+
+/// Credits an executed trade to the contract-wide [TradeStats] and to the [TradeTotals] of the
+/// day containing the given block time.  The trading account is counted toward the stats' account
+/// count the first time it trades.  Returns the updated contract-wide stats.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+/// manipulation.
+/// * `time` The block time at which the trade executed.
+/// * `direction` The direction of the trade.
+/// * `account` The account whose funds were traded.
+/// * `deposit_amount` The value of the trade, expressed as an amount of deposit denom.
+pub fn record_trade(
+    storage: &mut dyn Storage,
+    time: &Timestamp,
+    direction: TradeDirection,
+    account: &Addr,
+    deposit_amount: Uint128,
+) -> Result<TradeStats, ContractError> {
+    let stats = get_trade_stats(storage)?;
+    let first_trade = !TRADED_ACCOUNTS.has(storage, account);
+    if first_trade {
+        TRADED_ACCOUNTS
+            .save(storage, account, &Empty {})
+            .map_err(|e| ContractError::StorageError {
+                message: format!("{e:?}"),
+            })?;
+    }
+    let stats = TradeStats {
+        totals: stats.totals.add_trade(direction, deposit_amount)?,
+        account_count: if first_trade {
+            stats.account_count.saturating_add(1)
+        } else {
+            stats.account_count
+        },
+    };
+    TRADE_STATS
+        .save(storage, &stats)
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })?;
+    let day = day_of(time);
+    let daily_totals =
+        get_daily_trade_totals(storage, day)?.add_trade(direction, deposit_amount)?;
+    DAILY_TRADE_TOTALS
+        .save(storage, day, &daily_totals)
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })?;
+    stats.to_ok()
+}
+
+/// Fetches the contract-wide [TradeStats].  Empty stats are returned before any trade has been
+/// recorded.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+/// fetches.
+pub fn get_trade_stats(storage: &dyn Storage) -> Result<TradeStats, ContractError> {
+    TRADE_STATS
+        .may_load(storage)
+        .map(|stats| stats.unwrap_or_default())
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })
+}
+
+/// Fetches the [TradeTotals] of a single day.  Days without any recorded trades receive empty
+/// totals.
+///
+/// # Parameters
+///
+/// * `storage` An immutable instance of the contract storage value, allowing internal store data
+/// fetches.
+/// * `day` The index of the day, as produced by [day_of].
+pub fn get_daily_trade_totals(
+    storage: &dyn Storage,
+    day: u64,
+) -> Result<TradeTotals, ContractError> {
+    DAILY_TRADE_TOTALS
+        .may_load(storage, day)
+        .map(|totals| totals.unwrap_or_default())
+        .map_err(|e| ContractError::StorageError {
+            message: format!("{e:?}"),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::trade_stats::{
+        day_of, get_daily_trade_totals, get_trade_stats, record_trade, TradeStats, TradeTotals,
+        SECONDS_PER_DAY,
+    };
+    use crate::types::quote::TradeDirection;
+    use cosmwasm_std::{Addr, Timestamp, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn test_day_of_groups_times_by_day() {
+        assert_eq!(0, day_of(&Timestamp::from_seconds(SECONDS_PER_DAY - 1)));
+        assert_eq!(1, day_of(&Timestamp::from_seconds(SECONDS_PER_DAY)));
+        assert_eq!(2, day_of(&Timestamp::from_seconds(SECONDS_PER_DAY * 3 - 1)));
+    }
+
+    #[test]
+    fn test_record_and_get_trade_stats() {
+        let mut deps = mock_provenance_dependencies();
+        assert_eq!(
+            TradeStats::default(),
+            get_trade_stats(&deps.storage).expect("fetching stats before any trade should succeed"),
+            "empty stats should be returned before any trade is recorded",
+        );
+        let first = Addr::unchecked("first");
+        let second = Addr::unchecked("second");
+        let first_day = Timestamp::from_seconds(SECONDS_PER_DAY * 10);
+        let second_day = first_day.plus_seconds(SECONDS_PER_DAY);
+        record_trade(
+            &mut deps.storage,
+            &first_day,
+            TradeDirection::Fund,
+            &first,
+            Uint128::new(100),
+        )
+        .expect("recording the first trade should succeed");
+        record_trade(
+            &mut deps.storage,
+            &first_day,
+            TradeDirection::Withdraw,
+            &first,
+            Uint128::new(40),
+        )
+        .expect("recording a repeat trader's trade should succeed");
+        let stats = record_trade(
+            &mut deps.storage,
+            &second_day,
+            TradeDirection::Fund,
+            &second,
+            Uint128::new(25),
+        )
+        .expect("recording a trade on a new day should succeed");
+        assert_eq!(
+            TradeStats {
+                totals: TradeTotals {
+                    fund_count: 2,
+                    fund_volume: Uint128::new(125),
+                    withdraw_count: 1,
+                    withdraw_volume: Uint128::new(40),
+                },
+                account_count: 2,
+            },
+            stats,
+            "each trade should be added to the totals, and each account counted once",
+        );
+        assert_eq!(
+            stats,
+            get_trade_stats(&deps.storage).expect("fetching stats should succeed"),
+            "the recorded stats should be persisted",
+        );
+        assert_eq!(
+            TradeTotals {
+                fund_count: 1,
+                fund_volume: Uint128::new(100),
+                withdraw_count: 1,
+                withdraw_volume: Uint128::new(40),
+            },
+            get_daily_trade_totals(&deps.storage, day_of(&first_day))
+                .expect("fetching the first day's totals should succeed"),
+            "the first day should only include its own trades",
+        );
+        assert_eq!(
+            TradeTotals {
+                fund_count: 1,
+                fund_volume: Uint128::new(25),
+                ..TradeTotals::default()
+            },
+            get_daily_trade_totals(&deps.storage, day_of(&second_day))
+                .expect("fetching the second day's totals should succeed"),
+            "the second day should only include its own trades",
+        );
+        assert_eq!(
+            TradeTotals::default(),
+            get_daily_trade_totals(&deps.storage, day_of(&second_day) + 1)
+                .expect("fetching a day without trades should succeed"),
+            "a day without trades should have empty totals",
+        );
+    }
+}
@@ -0,0 +1,888 @@
+use crate::store::account_stats::AccountStats;
+use crate::store::bridge_stats::BridgeStats;
+use crate::store::contract_state::{ContractStateV1, CONTRACT_VERSION};
+use crate::store::deprecated_storage::TEST_DEPRECATED_STORAGE_KEY;
+use crate::store::runtime_tunables::RuntimeTunables;
+use crate::store::trade_receipts::TradeReceipt;
+use crate::store::trade_stats::SECONDS_PER_DAY;
+use crate::test::integration::{IntegrationHarness, TRADER_STARTING_DEPOSIT};
+use crate::test::test_constants::{
+    DEFAULT_DEPOSIT_DENOM_NAME, DEFAULT_TRADING_DENOM_NAME, DEFAULT_TRADING_DENOM_PRECISION,
+};
+use crate::test::test_versions::set_stored_contract_version;
+use crate::types::denom::Denom;
+use crate::types::error::ContractError;
+use crate::types::msg::{ExecuteMsg, MigrateMsg, QueryMsg, DISABLE_MINTING_CONFIRMATION};
+use crate::types::quote::TradeDirection;
+use crate::types::responses::{
+    AccountDailyRemainingResponse, AccountStatsResponse, AdminResponse, BridgeStatsResponse,
+    ContractBalancesResponse, DailyCapacityResponse, PendingAdminResponse, TradeReceiptData,
+    TradeReceiptResponse,
+};
+use cosmwasm_std::{coin, from_json, StdResult, Timestamp, Uint128};
+use cw_multi_test::{next_block, AppResponse, Executor};
+
+#[test]
+fn fund_and_withdraw_round_trip_should_conserve_value() {
+    let mut harness = IntegrationHarness::setup();
+    harness.fund(1000).expect("the fund should succeed");
+    let trader = harness.trader.to_owned();
+    assert_eq!(
+        TRADER_STARTING_DEPOSIT - 1000,
+        harness.balance(&trader, DEFAULT_DEPOSIT_DENOM_NAME),
+        "the traded deposit denom should leave the trader's account",
+    );
+    assert_eq!(
+        10_000_000,
+        harness.balance(&trader, DEFAULT_TRADING_DENOM_NAME),
+        "the trader should receive the equivalent trading denom",
+    );
+    assert_eq!(
+        10_000_000,
+        harness.supply(DEFAULT_TRADING_DENOM_NAME),
+        "only the withdrawn trading denom should be in circulation",
+    );
+    let balances = harness.query::<ContractBalancesResponse>(&QueryMsg::QueryContractBalances {});
+    assert_eq!(
+        Uint128::new(1000),
+        balances.deposit_balance.amount,
+        "the contract should hold the deposit denom backing the trading denom",
+    );
+    assert_eq!(
+        Uint128::new(10_000_000),
+        balances.trading_supply.amount,
+        "the trading supply should match the trading denom in circulation",
+    );
+    harness
+        .withdraw(10_000_000)
+        .expect("the withdraw should succeed");
+    assert_eq!(
+        TRADER_STARTING_DEPOSIT,
+        harness.balance(&trader, DEFAULT_DEPOSIT_DENOM_NAME),
+        "the round trip should return every deposit denom to the trader",
+    );
+    assert_eq!(
+        0,
+        harness.balance(&trader, DEFAULT_TRADING_DENOM_NAME),
+        "the trader's trading denom should be spent",
+    );
+    assert_eq!(
+        0,
+        harness.balance(&harness.trading_marker, DEFAULT_TRADING_DENOM_NAME),
+        "the returned trading denom should not remain in the trading marker",
+    );
+    assert_eq!(
+        0,
+        harness.supply(DEFAULT_TRADING_DENOM_NAME),
+        "the returned trading denom should be burned",
+    );
+    assert_eq!(
+        0,
+        harness.balance(&harness.contract, DEFAULT_DEPOSIT_DENOM_NAME),
+        "the contract should not retain any deposit denom",
+    );
+}
+
+fn receipt_of(response: AppResponse) -> u64 {
+    from_json::<TradeReceiptData>(response.data.expect("an executed trade should set data"))
+        .expect("the response data should deserialize")
+        .receipt
+}
+
+#[test]
+fn interleaved_trades_should_be_issued_sequential_receipts() {
+    let mut harness = IntegrationHarness::setup();
+    let mut receipts = vec![receipt_of(
+        harness.fund(1000).expect("the first fund should succeed"),
+    )];
+    receipts.push(receipt_of(
+        harness
+            .withdraw(5_000_000)
+            .expect("the first withdraw should succeed"),
+    ));
+    harness
+        .withdraw(50_000_000)
+        .expect_err("a withdraw exceeding the trader's balance should fail");
+    receipts.push(receipt_of(
+        harness.fund(500).expect("the second fund should succeed"),
+    ));
+    receipts.push(receipt_of(
+        harness
+            .withdraw(1_000_000)
+            .expect("the second withdraw should succeed"),
+    ));
+    assert_eq!(
+        vec![1, 2, 3, 4],
+        receipts,
+        "receipts should increase by one with each executed trade, skipping the failed trade",
+    );
+    let expected_trades = [
+        (TradeDirection::Fund, 1000, 10_000_000),
+        (TradeDirection::Withdraw, 5_000_000, 500),
+        (TradeDirection::Fund, 500, 5_000_000),
+        (TradeDirection::Withdraw, 1_000_000, 100),
+    ];
+    for (receipt, (direction, input_amount, output_amount)) in
+        receipts.into_iter().zip(expected_trades)
+    {
+        let (input_denom, output_denom) = match direction {
+            TradeDirection::Fund => (DEFAULT_DEPOSIT_DENOM_NAME, DEFAULT_TRADING_DENOM_NAME),
+            TradeDirection::Withdraw => (DEFAULT_TRADING_DENOM_NAME, DEFAULT_DEPOSIT_DENOM_NAME),
+        };
+        let trade_receipt = harness
+            .query::<TradeReceiptResponse>(&QueryMsg::QueryReceipt { receipt })
+            .trade_receipt;
+        assert_eq!(
+            TradeReceipt {
+                receipt,
+                account: harness.trader.to_owned(),
+                direction,
+                input: coin(input_amount, input_denom),
+                output: coin(output_amount, output_denom),
+                remainder: Uint128::zero(),
+                block_height: trade_receipt.block_height,
+                block_time: trade_receipt.block_time,
+            },
+            trade_receipt,
+            "receipt [{receipt}] should record its trade",
+        );
+    }
+    let missing: StdResult<TradeReceiptResponse> = harness
+        .app
+        .wrap()
+        .query_wasm_smart(&harness.contract, &QueryMsg::QueryReceipt { receipt: 5 });
+    missing.expect_err("no receipt should be issued for the failed trade");
+}
+
+#[test]
+fn account_stats_should_accumulate_across_both_directions() {
+    let mut harness = IntegrationHarness::setup();
+    let account_stats = |harness: &IntegrationHarness| {
+        harness
+            .query::<AccountStatsResponse>(&QueryMsg::QueryAccountStats {
+                account: harness.trader.to_string(),
+            })
+            .stats
+    };
+    assert_eq!(
+        AccountStats::default(),
+        account_stats(&harness),
+        "an account that never traded should report zeroes",
+    );
+    harness.fund(1000).expect("the first fund should succeed");
+    harness.app.update_block(next_block);
+    harness
+        .withdraw(5_000_000)
+        .expect("the first withdraw should succeed");
+    harness.app.update_block(next_block);
+    harness
+        .withdraw(50_000_000)
+        .expect_err("a withdraw exceeding the trader's balance should fail");
+    harness.fund(500).expect("the second fund should succeed");
+    harness.app.update_block(next_block);
+    harness
+        .withdraw(1_000_000)
+        .expect("the second withdraw should succeed");
+    assert_eq!(
+        AccountStats {
+            fund_count: 2,
+            total_funded: Uint128::new(1500),
+            withdraw_count: 2,
+            total_withdrawn: Uint128::new(600),
+            last_trade_height: harness.app.block_info().height,
+        },
+        account_stats(&harness),
+        "every executed trade should accumulate into the trader's stats, skipping the failed trade",
+    );
+    let admin = harness.admin.to_string();
+    assert_eq!(
+        AccountStats::default(),
+        harness
+            .query::<AccountStatsResponse>(&QueryMsg::QueryAccountStats { account: admin })
+            .stats,
+        "the trader's trades should not be credited to other accounts",
+    );
+}
+
+#[test]
+fn bridge_stats_should_reconcile_against_the_trading_supply() {
+    let mut harness = IntegrationHarness::setup();
+    harness.fund(1000).expect("the first fund should succeed");
+    harness
+        .withdraw(5_000_000)
+        .expect("the withdraw should succeed");
+    harness
+        .withdraw(50_000_000)
+        .expect_err("a withdraw exceeding the trader's balance should fail");
+    harness.fund(500).expect("the second fund should succeed");
+    let bridge_stats = harness
+        .query::<BridgeStatsResponse>(&QueryMsg::QueryBridgeStats {})
+        .bridge_stats;
+    assert_eq!(
+        BridgeStats {
+            total_deposit_accepted: Uint128::new(1500),
+            total_trading_minted: Uint128::new(15_000_000),
+            total_trading_burned: Uint128::new(5_000_000),
+            total_deposit_released: Uint128::new(500),
+            fund_count: 2,
+            withdraw_count: 1,
+        },
+        bridge_stats,
+        "every executed trade should be counted, skipping the failed trade",
+    );
+    assert_eq!(
+        harness.supply(DEFAULT_TRADING_DENOM_NAME),
+        (bridge_stats.total_trading_minted - bridge_stats.total_trading_burned).u128(),
+        "the trading denom in circulation should equal the minted amount less the burned amount",
+    );
+    assert_eq!(
+        harness.balance(&harness.contract, DEFAULT_DEPOSIT_DENOM_NAME),
+        (bridge_stats.total_deposit_accepted - bridge_stats.total_deposit_released).u128(),
+        "the contract's deposit denom should equal the accepted amount less the released amount",
+    );
+}
+
+#[test]
+fn admin_rotation_should_take_effect_between_trades() {
+    let mut harness = IntegrationHarness::setup();
+    harness.fund(500).expect("the fund should succeed");
+    let previous_admin = harness.admin.to_owned();
+    let new_admin = harness.app.api().addr_make("new-admin");
+    harness
+        .execute(
+            &previous_admin,
+            &ExecuteMsg::AdminUpdateAdmin {
+                new_admin_address: new_admin.to_string(),
+                admin_is_group: false,
+            },
+        )
+        .expect("the admin should be able to rotate the admin");
+    assert_eq!(
+        new_admin,
+        harness
+            .query::<AdminResponse>(&QueryMsg::QueryAdmin {})
+            .admin,
+        "the new admin should be persisted",
+    );
+    let set_tunables = ExecuteMsg::AdminSetTunables {
+        tunables: RuntimeTunables {
+            quote_validity_blocks: 50,
+            ..RuntimeTunables::default()
+        },
+    };
+    let error = harness
+        .execute(&previous_admin, &set_tunables)
+        .expect_err("the previous admin should no longer be authorized");
+    assert!(
+        matches!(
+            error.root_cause().downcast_ref::<ContractError>(),
+            Some(ContractError::NotAuthorizedError { .. }),
+        ),
+        "unexpected error encountered: {error:?}",
+    );
+    harness
+        .execute(&new_admin, &set_tunables)
+        .expect("the new admin should be authorized");
+    harness
+        .withdraw(5_000_000)
+        .expect("trades should continue after the admin is rotated");
+    assert_eq!(
+        TRADER_STARTING_DEPOSIT,
+        harness.balance(&harness.trader, DEFAULT_DEPOSIT_DENOM_NAME),
+        "the trade should complete after the admin is rotated",
+    );
+}
+
+#[test]
+fn two_step_admin_transfer_should_only_complete_when_accepted() {
+    let mut harness = IntegrationHarness::setup();
+    let previous_admin = harness.admin.to_owned();
+    let mistyped_admin = harness.app.api().addr_make("mistyped-admin");
+    let new_admin = harness.app.api().addr_make("new-admin");
+    harness
+        .execute(
+            &previous_admin,
+            &ExecuteMsg::AdminProposeNewAdmin {
+                new_admin_address: mistyped_admin.to_string(),
+            },
+        )
+        .expect("the admin should be able to propose a new admin");
+    assert_eq!(
+        Some(mistyped_admin.to_owned()),
+        harness
+            .query::<PendingAdminResponse>(&QueryMsg::QueryPendingAdmin {})
+            .pending_admin,
+        "the proposal should be visible through the pending admin query",
+    );
+    harness
+        .execute(&previous_admin, &ExecuteMsg::AdminCancelPendingAdmin {})
+        .expect("the admin should be able to cancel the proposal");
+    harness
+        .execute(&mistyped_admin, &ExecuteMsg::AcceptAdminRole {})
+        .expect_err("a cancelled proposal should not be accepted");
+    harness
+        .execute(
+            &previous_admin,
+            &ExecuteMsg::AdminProposeNewAdmin {
+                new_admin_address: new_admin.to_string(),
+            },
+        )
+        .expect("the admin should be able to propose a corrected admin");
+    assert_eq!(
+        previous_admin,
+        harness
+            .query::<AdminResponse>(&QueryMsg::QueryAdmin {})
+            .admin,
+        "the admin should not change before the proposal is accepted",
+    );
+    harness
+        .execute(&new_admin, &ExecuteMsg::AcceptAdminRole {})
+        .expect("the proposed admin should be able to accept the role");
+    let contract_state = harness.query::<ContractStateV1>(&QueryMsg::QueryContractState {});
+    assert_eq!(
+        new_admin, contract_state.admin,
+        "the accepted admin should be persisted",
+    );
+    assert!(
+        harness
+            .query::<PendingAdminResponse>(&QueryMsg::QueryPendingAdmin {})
+            .pending_admin
+            .is_none(),
+        "the proposal should be discarded once accepted",
+    );
+    let error = harness
+        .execute(
+            &previous_admin,
+            &ExecuteMsg::AdminProposeNewAdmin {
+                new_admin_address: previous_admin.to_string(),
+            },
+        )
+        .expect_err("the previous admin should no longer be authorized");
+    assert!(
+        matches!(
+            error.root_cause().downcast_ref::<ContractError>(),
+            Some(ContractError::NotAuthorizedError { .. }),
+        ),
+        "unexpected error encountered: {error:?}",
+    );
+}
+
+#[test]
+fn migration_should_preserve_state() {
+    let mut harness = IntegrationHarness::setup();
+    harness.fund(1000).expect("the fund should succeed");
+    // Migrations require a newer version, so the stored state is made to look like a previous
+    // release of the contract
+    set_stored_contract_version(
+        harness.app.contract_storage_mut(&harness.contract).as_mut(),
+        "0.0.1",
+    );
+    let previous_state = harness.query::<ContractStateV1>(&QueryMsg::QueryContractState {});
+    let previous_tunables = harness.query::<RuntimeTunables>(&QueryMsg::QueryRuntimeTunables {});
+    let previous_stats = harness.query::<BridgeStatsResponse>(&QueryMsg::QueryBridgeStats {});
+    let admin = harness.admin.to_owned();
+    let contract = harness.contract.to_owned();
+    harness
+        .app
+        .migrate_contract(
+            admin,
+            contract,
+            &MigrateMsg::ContractUpgrade {
+                instantiated_at_height: None,
+                instantiated_at_time: None,
+            },
+            harness.code_id,
+        )
+        .expect("the migration should succeed");
+    let migrated_state = harness.query::<ContractStateV1>(&QueryMsg::QueryContractState {});
+    assert_eq!(
+        CONTRACT_VERSION, migrated_state.contract_version,
+        "the migration should record the new version",
+    );
+    let mut expected_state = previous_state;
+    expected_state.contract_version = CONTRACT_VERSION.to_string();
+    assert_eq!(
+        expected_state, migrated_state,
+        "the migration should not change any other configuration",
+    );
+    assert_eq!(
+        previous_tunables,
+        harness.query::<RuntimeTunables>(&QueryMsg::QueryRuntimeTunables {}),
+        "the migration should not change the runtime tunables",
+    );
+    assert_eq!(
+        previous_stats,
+        harness.query::<BridgeStatsResponse>(&QueryMsg::QueryBridgeStats {}),
+        "the migration should not change the bridge stats",
+    );
+    harness
+        .withdraw(10_000_000)
+        .expect("balances funded before the migration should be withdrawable");
+    assert_eq!(
+        TRADER_STARTING_DEPOSIT,
+        harness.balance(&harness.trader, DEFAULT_DEPOSIT_DENOM_NAME),
+        "the migration should preserve the deposit denom backing the trading denom",
+    );
+}
+
+#[test]
+fn disabled_minting_should_survive_every_route_and_migration() {
+    let mut harness = IntegrationHarness::setup();
+    harness.fund(1000).expect("the fund should succeed");
+    let admin = harness.admin.to_owned();
+    harness
+        .execute(
+            &admin,
+            &ExecuteMsg::AdminDisableMintingPermanently {
+                confirm: DISABLE_MINTING_CONFIRMATION.to_string(),
+            },
+        )
+        .expect("the admin should be able to disable minting");
+    // Every route that rewrites the contract state is executed, and none of them may clear the flag
+    for msg in [
+        ExecuteMsg::AdminUpdateAdmin {
+            new_admin_address: admin.to_string(),
+            admin_is_group: false,
+        },
+        ExecuteMsg::AdminSetTunables {
+            tunables: RuntimeTunables::default(),
+        },
+        ExecuteMsg::AdminUpdateSkipBalancePrecheck {
+            skip_balance_precheck: false,
+        },
+        ExecuteMsg::AdminUpdateLargeTradeThreshold {
+            large_trade_threshold: None,
+        },
+        ExecuteMsg::AdminUpdateDailyCap { daily_cap: None },
+        ExecuteMsg::AdminUpdatePerAccountDailyLimit {
+            per_account_daily_limit: None,
+        },
+        ExecuteMsg::AdminUpdateMinTradeAmounts {
+            min_fund_amount: None,
+            min_withdraw_amount: None,
+        },
+        ExecuteMsg::AdminUpdateMaxTradeAmounts {
+            max_fund_amount: None,
+            max_withdraw_amount: None,
+        },
+        ExecuteMsg::AdminUpdateMsgFee { msg_fee: None },
+        ExecuteMsg::AdminUpdateFeeDiscounts {
+            fee_discounts: vec![],
+        },
+        ExecuteMsg::AdminUpdateFundFee {
+            fund_fee_bps: None,
+            fee_collector: None,
+        },
+        ExecuteMsg::AdminUpdateWithdrawFee {
+            withdraw_fee_bps: None,
+        },
+        ExecuteMsg::AdminUpdateRequiredScopeSpec {
+            required_scope_spec: None,
+        },
+        ExecuteMsg::AdminUpdateComplianceContract {
+            compliance_contract: None,
+        },
+        ExecuteMsg::AdminUpdateSchedule {
+            trading_start: None,
+            sunset_at: None,
+        },
+        ExecuteMsg::AdminUpdateContractName {
+            name: "renamed".to_string(),
+        },
+        ExecuteMsg::AdminUpdateTradingMarker {
+            denom: Denom::new(DEFAULT_TRADING_DENOM_NAME, DEFAULT_TRADING_DENOM_PRECISION),
+            force: true,
+        },
+        ExecuteMsg::AdminUpdateAllRequiredAttributes {
+            deposit_attributes: vec![],
+            withdraw_attributes: vec![],
+            confirm_clear: true,
+        },
+    ] {
+        harness
+            .execute(&admin, &msg)
+            .unwrap_or_else(|e| panic!("the admin route {msg:?} should succeed: {e:?}"));
+        assert!(
+            harness
+                .query::<ContractStateV1>(&QueryMsg::QueryContractState {})
+                .minting_disabled,
+            "minting should remain disabled after {msg:?}",
+        );
+    }
+    let error = harness
+        .execute(
+            &admin,
+            &ExecuteMsg::AdminDisableMintingPermanently {
+                confirm: DISABLE_MINTING_CONFIRMATION.to_string(),
+            },
+        )
+        .expect_err("minting cannot be disabled twice");
+    assert!(
+        matches!(
+            error.root_cause().downcast_ref::<ContractError>(),
+            Some(ContractError::ValidationError { .. }),
+        ),
+        "unexpected error encountered: {error:?}",
+    );
+    for migrate_msg in [
+        MigrateMsg::ContractUpgrade {
+            instantiated_at_height: None,
+            instantiated_at_time: None,
+        },
+        MigrateMsg::ContractUpgradeWithCleanup {
+            purge_keys: vec![TEST_DEPRECATED_STORAGE_KEY.to_string()],
+        },
+    ] {
+        set_stored_contract_version(
+            harness.app.contract_storage_mut(&harness.contract).as_mut(),
+            "0.0.1",
+        );
+        let contract = harness.contract.to_owned();
+        harness
+            .app
+            .migrate_contract(admin.to_owned(), contract, &migrate_msg, harness.code_id)
+            .unwrap_or_else(|e| panic!("the migration {migrate_msg:?} should succeed: {e:?}"));
+        assert!(
+            harness
+                .query::<ContractStateV1>(&QueryMsg::QueryContractState {})
+                .minting_disabled,
+            "minting should remain disabled after {migrate_msg:?}",
+        );
+    }
+    let error = harness
+        .fund(1000)
+        .expect_err("funding should fail once minting is disabled");
+    assert!(
+        matches!(
+            error.root_cause().downcast_ref::<ContractError>(),
+            Some(ContractError::PreconditionsFailed { failures })
+                if failures.iter().any(|failure| failure.starts_with("minting is permanently disabled: ")),
+        ),
+        "unexpected error encountered: {error:?}",
+    );
+    harness
+        .withdraw(10_000_000)
+        .expect("holders should still be able to withdraw once minting is disabled");
+    assert_eq!(
+        TRADER_STARTING_DEPOSIT,
+        harness.balance(&harness.trader, DEFAULT_DEPOSIT_DENOM_NAME),
+        "the withdrawal should return the deposit denom",
+    );
+}
+
+#[test]
+fn minimum_trade_amounts_should_apply_to_the_requested_amount() {
+    let mut harness = IntegrationHarness::setup();
+    let admin = harness.admin.to_owned();
+    let trader = harness.trader.to_owned();
+    harness.fund(1000).expect("the fund should succeed");
+    harness
+        .execute(
+            &admin,
+            &ExecuteMsg::AdminUpdateMinTradeAmounts {
+                min_fund_amount: Some(Uint128::new(100)),
+                min_withdraw_amount: Some(Uint128::new(5_000_001)),
+            },
+        )
+        .expect("the admin should be able to set the minimums");
+    for error in [
+        harness
+            .fund(99)
+            .expect_err("a fund below the minimum should fail"),
+        harness
+            .withdraw(5_000_000)
+            .expect_err("a withdraw below the minimum should fail"),
+    ] {
+        assert!(
+            matches!(
+                error.root_cause().downcast_ref::<ContractError>(),
+                Some(ContractError::PreconditionsFailed { failures })
+                    if failures.iter().any(|failure| failure.starts_with("validation failed: requested ")),
+            ),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+    // The request meets the minimum even though only 5_000_000 converts and is collected
+    harness
+        .execute(
+            &trader,
+            &ExecuteMsg::WithdrawTrading {
+                trade_amount: Uint128::new(5_000_001),
+                dry_run: false,
+                quote: None,
+                referrer: None,
+                allow_partial: false,
+                allow_remainder: true,
+            },
+        )
+        .expect("a request at the minimum should succeed despite its remainder");
+    assert_eq!(
+        5_000_000,
+        harness.balance(&trader, DEFAULT_TRADING_DENOM_NAME),
+        "only the convertible amount should be collected",
+    );
+    harness
+        .execute(
+            &admin,
+            &ExecuteMsg::AdminUpdateMinTradeAmounts {
+                min_fund_amount: None,
+                min_withdraw_amount: None,
+            },
+        )
+        .expect("the admin should be able to remove the minimums");
+    harness
+        .fund(1)
+        .expect("any amount should be accepted once the minimums are removed");
+}
+
+#[test]
+fn maximum_trade_amounts_should_cap_each_request() {
+    let mut harness = IntegrationHarness::setup();
+    let admin = harness.admin.to_owned();
+    harness.fund(1000).expect("the fund should succeed");
+    harness
+        .execute(
+            &admin,
+            &ExecuteMsg::AdminUpdateMaxTradeAmounts {
+                max_fund_amount: Some(Uint128::new(500)),
+                max_withdraw_amount: Some(Uint128::new(2_000_000)),
+            },
+        )
+        .expect("the admin should be able to set the maximums");
+    for error in [
+        harness
+            .fund(501)
+            .expect_err("a fund above the maximum should fail"),
+        harness
+            .withdraw(2_000_001)
+            .expect_err("a withdraw above the maximum should fail"),
+    ] {
+        assert!(
+            matches!(
+                error.root_cause().downcast_ref::<ContractError>(),
+                Some(ContractError::PreconditionsFailed { failures })
+                    if failures.iter().any(|failure| failure.starts_with("validation failed: requested ")),
+            ),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+    harness
+        .fund(500)
+        .expect("a fund at the maximum should succeed");
+    harness
+        .withdraw(2_000_000)
+        .expect("a withdraw at the maximum should succeed");
+    let error = harness
+        .execute(
+            &admin,
+            &ExecuteMsg::AdminUpdateMinTradeAmounts {
+                min_fund_amount: Some(Uint128::new(501)),
+                min_withdraw_amount: None,
+            },
+        )
+        .expect_err("a minimum above the configured maximum should be rejected");
+    assert!(
+        matches!(
+            error.root_cause().downcast_ref::<ContractError>(),
+            Some(ContractError::ValidationError { .. }),
+        ),
+        "unexpected error encountered: {error:?}",
+    );
+    harness
+        .execute(
+            &admin,
+            &ExecuteMsg::AdminUpdateMaxTradeAmounts {
+                max_fund_amount: None,
+                max_withdraw_amount: None,
+            },
+        )
+        .expect("the admin should be able to remove the maximums");
+    harness
+        .fund(501)
+        .expect("any amount should be accepted once the maximums are removed");
+}
+
+#[test]
+fn daily_cap_should_fill_exactly_and_reset_when_the_day_rolls_over() {
+    let mut harness = IntegrationHarness::setup();
+    let admin = harness.admin.to_owned();
+    harness
+        .execute(
+            &admin,
+            &ExecuteMsg::AdminUpdateDailyCap {
+                daily_cap: Some(Uint128::new(1000)),
+            },
+        )
+        .expect("the admin should be able to set the daily cap");
+    // Trade during the final second of a day, so that the next block falls on the following day
+    let next_day = harness.app.block_info().time.seconds() / SECONDS_PER_DAY + 1;
+    harness.app.update_block(|block| {
+        block.time = Timestamp::from_seconds(next_day * SECONDS_PER_DAY - 1);
+    });
+    harness
+        .fund(600)
+        .expect("a fund within the cap should succeed");
+    harness
+        .fund(400)
+        .expect("a fund reaching the exact cap should succeed");
+    assert_eq!(
+        DailyCapacityResponse {
+            day: next_day - 1,
+            daily_cap: Some(Uint128::new(1000)),
+            volume: Uint128::new(1000),
+            remaining: Some(Uint128::zero()),
+        },
+        harness.query::<DailyCapacityResponse>(&QueryMsg::QueryDailyCapacity {}),
+        "the full cap should be consumed",
+    );
+    let error = harness
+        .fund(1)
+        .expect_err("a fund beyond the exact cap should fail");
+    assert!(
+        matches!(
+            error.root_cause().downcast_ref::<ContractError>(),
+            Some(ContractError::PreconditionsFailed { failures })
+                if failures.iter().any(|failure| failure.starts_with("daily cap exceeded: ")),
+        ),
+        "unexpected error encountered: {error:?}",
+    );
+    harness
+        .withdraw(1_000_000)
+        .expect("withdrawals should not be limited by the daily cap");
+    harness
+        .app
+        .update_block(|block| block.time = block.time.plus_seconds(1));
+    assert_eq!(
+        DailyCapacityResponse {
+            day: next_day,
+            daily_cap: Some(Uint128::new(1000)),
+            volume: Uint128::zero(),
+            remaining: Some(Uint128::new(1000)),
+        },
+        harness.query::<DailyCapacityResponse>(&QueryMsg::QueryDailyCapacity {}),
+        "the capacity should reset at the first second of the next day",
+    );
+    harness
+        .fund(1000)
+        .expect("the full cap should be available on the next day");
+}
+
+#[test]
+fn per_account_daily_limit_should_span_both_directions_until_it_resets() {
+    let mut harness = IntegrationHarness::setup();
+    let admin = harness.admin.to_owned();
+    let trader = harness.trader.to_owned();
+    harness
+        .execute(
+            &admin,
+            &ExecuteMsg::AdminUpdatePerAccountDailyLimit {
+                per_account_daily_limit: Some(Uint128::new(1500)),
+            },
+        )
+        .expect("the admin should be able to set the daily limit");
+    harness
+        .fund(1000)
+        .expect("a fund within the limit should succeed");
+    // 5_000_000 trading denom releases 500 deposit denom, filling the limit exactly
+    harness
+        .withdraw(5_000_000)
+        .expect("a withdraw reaching the exact limit should succeed");
+    let remaining =
+        harness.query::<AccountDailyRemainingResponse>(&QueryMsg::QueryAccountDailyRemaining {
+            account: trader.to_string(),
+        });
+    assert_eq!(
+        (Uint128::new(1500), Some(Uint128::zero())),
+        (remaining.used, remaining.remaining),
+        "trades in both directions should count toward the limit",
+    );
+    for error in [
+        harness
+            .fund(1)
+            .expect_err("a fund beyond the limit should fail"),
+        harness
+            .withdraw(10_000)
+            .expect_err("a withdraw beyond the limit should fail"),
+    ] {
+        assert!(
+            matches!(
+                error.root_cause().downcast_ref::<ContractError>(),
+                Some(ContractError::PreconditionsFailed { failures })
+                if failures.iter().any(|failure| failure.starts_with("account daily limit exceeded: ")),
+            ),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+    harness
+        .app
+        .update_block(|block| block.time = remaining.resets_at);
+    assert_eq!(
+        Some(Uint128::new(1500)),
+        harness
+            .query::<AccountDailyRemainingResponse>(&QueryMsg::QueryAccountDailyRemaining {
+                account: trader.to_string(),
+            })
+            .remaining,
+        "the full allowance should be available once the day rolls over",
+    );
+    harness
+        .fund(1)
+        .expect("trading should resume once the allowance resets");
+}
+
+#[test]
+fn disabled_fund_route_should_allow_a_withdraw_only_wind_down() {
+    let mut harness = IntegrationHarness::setup();
+    harness.fund(1000).expect("the fund should succeed");
+    let admin = harness.admin.to_owned();
+    harness
+        .execute(
+            &admin,
+            &ExecuteMsg::AdminUpdateFundEnabled {
+                fund_enabled: false,
+            },
+        )
+        .expect("the admin should be able to disable the fund route");
+    let contract_state = harness.query::<ContractStateV1>(&QueryMsg::QueryContractState {});
+    assert!(
+        !contract_state.fund_enabled,
+        "the disabled fund route should be visible in the contract state",
+    );
+    assert!(
+        contract_state.withdraw_enabled,
+        "the withdraw route should remain enabled",
+    );
+    let error = harness
+        .fund(1000)
+        .expect_err("a fund should fail while the route is disabled");
+    assert!(
+        matches!(
+            error.root_cause().downcast_ref::<ContractError>(),
+            Some(ContractError::PreconditionsFailed { failures })
+                if failures.iter().any(|failure| failure.starts_with("route disabled: ")),
+        ),
+        "unexpected error encountered: {error:?}",
+    );
+    harness
+        .withdraw(5_000_000)
+        .expect("a withdraw should succeed while only the fund route is disabled");
+    harness
+        .execute(
+            &admin,
+            &ExecuteMsg::AdminUpdateWithdrawEnabled {
+                withdraw_enabled: false,
+            },
+        )
+        .expect("the admin should be able to disable the withdraw route");
+    let error = harness
+        .withdraw(5_000_000)
+        .expect_err("a withdraw should fail while the route is disabled");
+    assert!(
+        matches!(
+            error.root_cause().downcast_ref::<ContractError>(),
+            Some(ContractError::PreconditionsFailed { failures })
+                if failures.iter().any(|failure| failure.starts_with("route disabled: ")),
+        ),
+        "unexpected error encountered: {error:?}",
+    );
+}
@@ -0,0 +1,207 @@
+//! An end-to-end harness that runs the contract's entry points within a cw-multi-test app, so
+//! that message dispatch, serde and state persistence are exercised across calls.
+
+mod contract_flows;
+pub mod provenance_stub;
+
+use crate::contract::{execute, instantiate, migrate, query, reply};
+use crate::test::integration::provenance_stub::ProvenanceStub;
+use crate::test::test_constants::{
+    DEFAULT_DEPOSIT_DENOM_NAME, DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE,
+    DEFAULT_REQUIRED_WITHDRAW_ATTRIBUTE, DEFAULT_TRADING_DENOM_NAME,
+};
+use crate::types::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use anyhow::Result as AnyResult;
+use cosmwasm_std::testing::{MockApi, MockStorage};
+use cosmwasm_std::{coins, Addr, Empty, Uint128};
+use cw_multi_test::{
+    App, AppBuilder, AppResponse, BankKeeper, ContractWrapper, DistributionKeeper, Executor,
+    FailingModule, GovFailingModule, IbcFailingModule, StakeKeeper, WasmKeeper,
+};
+use serde::de::DeserializeOwned;
+
+/// The amount of deposit denom held by the trader when the harness is created.
+pub const TRADER_STARTING_DEPOSIT: u128 = 10_000;
+
+/// The cw-multi-test app in which the contract runs, with Provenance modules provided by the
+/// [ProvenanceStub].
+pub type IntegrationApp = App<
+    BankKeeper,
+    MockApi,
+    MockStorage,
+    FailingModule<Empty, Empty, Empty>,
+    WasmKeeper<Empty, Empty>,
+    StakeKeeper,
+    DistributionKeeper,
+    IbcFailingModule,
+    GovFailingModule,
+    ProvenanceStub,
+>;
+
+/// An instantiated contract within an [IntegrationApp], along with the accounts that interact
+/// with it.
+pub struct IntegrationHarness {
+    /// The app in which the contract runs.
+    pub app: IntegrationApp,
+    /// The id of the contract's stored code.
+    pub code_id: u64,
+    /// The address of the instantiated contract.
+    pub contract: Addr,
+    /// The account that instantiated the contract, which is its admin.
+    pub admin: Addr,
+    /// An account that holds both required attributes and [TRADER_STARTING_DEPOSIT] deposit denom.
+    pub trader: Addr,
+    /// The address of the trading marker, which receives trading denom before it is burned.
+    pub trading_marker: Addr,
+}
+impl IntegrationHarness {
+    /// Stores the contract's code and instantiates it with the default [InstantiateMsg].
+    pub fn setup() -> Self {
+        let api = MockApi::default().with_prefix("tp");
+        let admin = api.addr_make("admin");
+        let trader = api.addr_make("trader");
+        // The address that cw-multi-test assigns to the first contract it instantiates, which must
+        // hold its marker permissions before it is instantiated
+        let expected_contract = api.addr_make("contract0");
+        let stub = ProvenanceStub::new(
+            api,
+            &[DEFAULT_DEPOSIT_DENOM_NAME, DEFAULT_TRADING_DENOM_NAME],
+        )
+        .with_marker_access(&expected_contract)
+        .with_attributes(
+            &trader,
+            &[
+                DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE,
+                DEFAULT_REQUIRED_WITHDRAW_ATTRIBUTE,
+            ],
+        );
+        let trading_marker = stub.marker_address(DEFAULT_TRADING_DENOM_NAME);
+        let mut app =
+            AppBuilder::new()
+                .with_api(api)
+                .with_stargate(stub)
+                .build(|router, _, storage| {
+                    router
+                        .bank
+                        .init_balance(
+                            storage,
+                            &trader,
+                            coins(TRADER_STARTING_DEPOSIT, DEFAULT_DEPOSIT_DENOM_NAME),
+                        )
+                        .expect("the trader's starting balance should be set");
+                });
+        let code_id = app.store_code(Box::new(
+            ContractWrapper::new(execute, instantiate, query)
+                .with_migrate(migrate)
+                .with_reply(reply),
+        ));
+        let contract = app
+            .instantiate_contract(
+                code_id,
+                admin.to_owned(),
+                &InstantiateMsg::default(),
+                &[],
+                "funding-trading-bridge",
+                Some(admin.to_string()),
+            )
+            .expect("the contract should instantiate");
+        assert_eq!(
+            expected_contract, contract,
+            "the contract should be instantiated at the address granted marker access",
+        );
+        Self {
+            app,
+            code_id,
+            contract,
+            admin,
+            trader,
+            trading_marker,
+        }
+    }
+
+    /// Executes the contract from the given sender without any funds.
+    ///
+    /// # Parameters
+    /// * `sender` The account executing the contract.
+    /// * `msg` The execute msg to send.
+    pub fn execute(&mut self, sender: &Addr, msg: &ExecuteMsg) -> AnyResult<AppResponse> {
+        self.app
+            .execute_contract(sender.to_owned(), self.contract.to_owned(), msg, &[])
+    }
+
+    /// Funds the trading denom with the given amount of the trader's deposit denom.
+    ///
+    /// # Parameters
+    /// * `trade_amount` The amount of deposit denom to trade.
+    pub fn fund(&mut self, trade_amount: u128) -> AnyResult<AppResponse> {
+        let trader = self.trader.to_owned();
+        self.execute(
+            &trader,
+            &ExecuteMsg::FundTrading {
+                trade_amount: Uint128::new(trade_amount),
+                dry_run: false,
+                quote: None,
+                referrer: None,
+                allow_partial: false,
+                allow_remainder: false,
+            },
+        )
+    }
+
+    /// Withdraws deposit denom with the given amount of the trader's trading denom.
+    ///
+    /// # Parameters
+    /// * `trade_amount` The amount of trading denom to trade.
+    pub fn withdraw(&mut self, trade_amount: u128) -> AnyResult<AppResponse> {
+        let trader = self.trader.to_owned();
+        self.execute(
+            &trader,
+            &ExecuteMsg::WithdrawTrading {
+                trade_amount: Uint128::new(trade_amount),
+                dry_run: false,
+                quote: None,
+                referrer: None,
+                allow_partial: false,
+                allow_remainder: false,
+            },
+        )
+    }
+
+    /// Queries the contract, deserializing its response.
+    ///
+    /// # Parameters
+    /// * `msg` The query msg to send.
+    pub fn query<T: DeserializeOwned>(&self, msg: &QueryMsg) -> T {
+        self.app
+            .wrap()
+            .query_wasm_smart(self.contract.to_owned(), msg)
+            .expect("the contract query should succeed")
+    }
+
+    /// The bank balance of an account.
+    ///
+    /// # Parameters
+    /// * `account` The account holding the balance.
+    /// * `denom` The denom of the balance.
+    pub fn balance(&self, account: &Addr, denom: &str) -> u128 {
+        self.app
+            .wrap()
+            .query_balance(account, denom)
+            .expect("the balance query should succeed")
+            .amount
+            .u128()
+    }
+
+    /// The total supply of a denom across every account.
+    ///
+    /// # Parameters
+    /// * `denom` The denom to total.
+    pub fn supply(&self, denom: &str) -> u128 {
+        self.app
+            .wrap()
+            .query_supply(denom)
+            .expect("the supply query should succeed")
+            .amount
+            .u128()
+    }
+}
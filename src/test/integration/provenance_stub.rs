@@ -0,0 +1,350 @@
+use anyhow::{bail, Result as AnyResult};
+use cosmwasm_std::testing::MockApi;
+use cosmwasm_std::{
+    to_json_binary, Addr, AnyMsg, Api, BankMsg, Binary, BlockInfo, CosmosMsg, CustomMsg,
+    CustomQuery, Empty, GrpcQuery, Querier, QuerierWrapper, Storage,
+};
+use cw_multi_test::{AppResponse, BankSudo, CosmosRouter, Stargate, SudoMsg};
+use prost::Message;
+use provwasm_std::shim::Any;
+use provwasm_std::types::cosmos::auth::v1beta1::BaseAccount;
+use provwasm_std::types::cosmos::bank::v1beta1::{
+    QueryBalanceRequest, QueryBalanceResponse, QuerySupplyOfRequest, QuerySupplyOfResponse,
+};
+use provwasm_std::types::cosmos::base::v1beta1::Coin;
+use provwasm_std::types::provenance::attribute::v1::{
+    Attribute, AttributeType, QueryAttributesRequest, QueryAttributesResponse,
+};
+use provwasm_std::types::provenance::marker::v1::{
+    Access, AccessGrant, MarkerAccount, MarkerStatus, MarkerType, MsgBurnRequest, MsgMintRequest,
+    MsgTransferRequest, MsgWithdrawRequest, Params, QueryMarkerRequest, QueryMarkerResponse,
+    QueryParamsResponse,
+};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Stands in for the Provenance modules that the contract interacts with.  Marker transfers, mints,
+/// withdraws and burns are applied to the bank module, so balances of the two marker denoms are
+/// tracked across calls.  Name binds and msg fees are accepted without effect.
+pub struct ProvenanceStub {
+    api: MockApi,
+    attributes: HashMap<String, Vec<String>>,
+    marker_denoms: Vec<String>,
+    marker_access_holders: Vec<String>,
+}
+impl ProvenanceStub {
+    /// Creates a stub that resolves a marker for each of the given denoms.
+    ///
+    /// # Parameters
+    /// * `api` The api used by the app, which derives each marker's address.
+    /// * `marker_denoms` The denoms managed by markers.
+    pub fn new(api: MockApi, marker_denoms: &[&str]) -> Self {
+        Self {
+            api,
+            attributes: HashMap::new(),
+            marker_denoms: marker_denoms
+                .iter()
+                .map(|denom| denom.to_string())
+                .collect(),
+            marker_access_holders: vec![],
+        }
+    }
+
+    /// Grants an account every permission on every marker.
+    ///
+    /// # Parameters
+    /// * `account` The account receiving the permissions.
+    pub fn with_marker_access(mut self, account: &Addr) -> Self {
+        self.marker_access_holders.push(account.to_string());
+        self
+    }
+
+    /// Grants the given attribute names to an account.
+    ///
+    /// # Parameters
+    /// * `account` The account receiving the attributes.
+    /// * `attributes` The names of the attributes to grant.
+    pub fn with_attributes(mut self, account: &Addr, attributes: &[&str]) -> Self {
+        self.attributes
+            .entry(account.to_string())
+            .or_default()
+            .extend(attributes.iter().map(|attr| attr.to_string()));
+        self
+    }
+
+    /// The address of the marker account that manages the given denom.
+    ///
+    /// # Parameters
+    /// * `denom` The denom managed by the marker.
+    pub fn marker_address(&self, denom: &str) -> Addr {
+        self.api.addr_make(&format!("marker-{denom}"))
+    }
+
+    fn query(
+        &self,
+        querier: &dyn Querier,
+        path: &str,
+        data: &[u8],
+        grpc: bool,
+    ) -> AnyResult<Binary> {
+        let querier = QuerierWrapper::<Empty>::new(querier);
+        match path {
+            "/cosmos.bank.v1beta1.Query/Balance" => {
+                let request = QueryBalanceRequest::decode(data)?;
+                let balance = querier.query_balance(request.address, request.denom)?;
+                respond(
+                    QueryBalanceResponse {
+                        balance: Some(Coin {
+                            denom: balance.denom,
+                            amount: balance.amount.to_string(),
+                        }),
+                    },
+                    grpc,
+                )
+            }
+            "/cosmos.bank.v1beta1.Query/SupplyOf" => {
+                let request = QuerySupplyOfRequest::decode(data)?;
+                let supply = querier.query_supply(request.denom)?;
+                respond(
+                    QuerySupplyOfResponse {
+                        amount: Some(Coin {
+                            denom: supply.denom,
+                            amount: supply.amount.to_string(),
+                        }),
+                    },
+                    grpc,
+                )
+            }
+            "/provenance.attribute.v1.Query/Attributes" => {
+                let request = QueryAttributesRequest::decode(data)?;
+                let attributes = self
+                    .attributes
+                    .get(&request.account)
+                    .cloned()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|name| Attribute {
+                        name,
+                        value: vec![],
+                        attribute_type: AttributeType::String as i32,
+                        address: request.account.to_owned(),
+                        expiration_date: None,
+                    })
+                    .collect();
+                respond(
+                    QueryAttributesResponse {
+                        account: request.account,
+                        attributes,
+                        pagination: None,
+                    },
+                    grpc,
+                )
+            }
+            "/provenance.marker.v1.Query/Marker" => {
+                let request = QueryMarkerRequest::decode(data)?;
+                if !self.marker_denoms.contains(&request.id) {
+                    bail!("no marker exists for [{}]", request.id);
+                }
+                let marker = MarkerAccount {
+                    base_account: Some(BaseAccount {
+                        address: self.marker_address(&request.id).to_string(),
+                        pub_key: None,
+                        account_number: 0,
+                        sequence: 0,
+                    }),
+                    manager: String::new(),
+                    access_control: self
+                        .marker_access_holders
+                        .iter()
+                        .map(|address| AccessGrant {
+                            address: address.to_owned(),
+                            permissions: vec![
+                                Access::Mint as i32,
+                                Access::Burn as i32,
+                                Access::Withdraw as i32,
+                                Access::Transfer as i32,
+                            ],
+                        })
+                        .collect(),
+                    status: MarkerStatus::Active as i32,
+                    supply: querier.query_supply(&request.id)?.amount.to_string(),
+                    denom: request.id,
+                    marker_type: MarkerType::Restricted as i32,
+                    supply_fixed: false,
+                    allow_governance_control: false,
+                    allow_forced_transfer: false,
+                    required_attributes: vec![],
+                };
+                respond(
+                    QueryMarkerResponse {
+                        marker: Some(Any {
+                            type_url: "/provenance.marker.v1.MarkerAccount".to_string(),
+                            value: marker.to_proto_bytes(),
+                        }),
+                    },
+                    grpc,
+                )
+            }
+            "/provenance.marker.v1.Query/Params" => respond(
+                QueryParamsResponse {
+                    params: Some(Params::default()),
+                },
+                grpc,
+            ),
+            path => bail!("unsupported query path [{path}]"),
+        }
+    }
+
+    fn execute<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        type_url: &str,
+        value: &[u8],
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: CustomMsg + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        match type_url {
+            "/provenance.marker.v1.MsgTransferRequest" => {
+                let msg = MsgTransferRequest::decode(value)?;
+                router.execute(
+                    api,
+                    storage,
+                    block,
+                    Addr::unchecked(msg.from_address),
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: msg.to_address,
+                        amount: vec![to_coin(msg.amount)?],
+                    }),
+                )
+            }
+            "/provenance.marker.v1.MsgMintRequest" => {
+                let coin = to_coin(MsgMintRequest::decode(value)?.amount)?;
+                router.sudo(
+                    api,
+                    storage,
+                    block,
+                    SudoMsg::Bank(BankSudo::Mint {
+                        to_address: self.marker_address(&coin.denom).to_string(),
+                        amount: vec![coin],
+                    }),
+                )
+            }
+            "/provenance.marker.v1.MsgWithdrawRequest" => {
+                let msg = MsgWithdrawRequest::decode(value)?;
+                router.execute(
+                    api,
+                    storage,
+                    block,
+                    self.marker_address(&msg.denom),
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: msg.to_address,
+                        amount: msg
+                            .amount
+                            .into_iter()
+                            .map(|coin| to_coin(Some(coin)))
+                            .collect::<AnyResult<_>>()?,
+                    }),
+                )
+            }
+            "/provenance.marker.v1.MsgBurnRequest" => {
+                let coin = to_coin(MsgBurnRequest::decode(value)?.amount)?;
+                router.execute(
+                    api,
+                    storage,
+                    block,
+                    self.marker_address(&coin.denom),
+                    CosmosMsg::Bank(BankMsg::Burn { amount: vec![coin] }),
+                )
+            }
+            "/provenance.name.v1.MsgBindNameRequest"
+            | "/provenance.msgfees.v1.MsgAssessCustomMsgFeeRequest" => Ok(AppResponse::default()),
+            type_url => bail!("unsupported message type [{type_url}]"),
+        }
+    }
+}
+impl Stargate for ProvenanceStub {
+    fn execute_stargate<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        _sender: Addr,
+        type_url: String,
+        value: Binary,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: CustomMsg + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        self.execute(api, storage, router, block, &type_url, value.as_slice())
+    }
+
+    fn query_stargate(
+        &self,
+        _api: &dyn Api,
+        _storage: &dyn Storage,
+        querier: &dyn Querier,
+        _block: &BlockInfo,
+        path: String,
+        data: Binary,
+    ) -> AnyResult<Binary> {
+        self.query(querier, &path, data.as_slice(), false)
+    }
+
+    fn execute_any<ExecC, QueryC>(
+        &self,
+        api: &dyn Api,
+        storage: &mut dyn Storage,
+        router: &dyn CosmosRouter<ExecC = ExecC, QueryC = QueryC>,
+        block: &BlockInfo,
+        _sender: Addr,
+        msg: AnyMsg,
+    ) -> AnyResult<AppResponse>
+    where
+        ExecC: CustomMsg + DeserializeOwned + 'static,
+        QueryC: CustomQuery + DeserializeOwned + 'static,
+    {
+        self.execute(
+            api,
+            storage,
+            router,
+            block,
+            &msg.type_url,
+            msg.value.as_slice(),
+        )
+    }
+
+    fn query_grpc(
+        &self,
+        _api: &dyn Api,
+        _storage: &dyn Storage,
+        querier: &dyn Querier,
+        _block: &BlockInfo,
+        request: GrpcQuery,
+    ) -> AnyResult<Binary> {
+        self.query(querier, &request.path, request.data.as_slice(), true)
+    }
+}
+
+/// Stargate queries are answered in JSON, while gRPC queries are answered in protobuf.
+fn respond<T: Message + Serialize>(response: T, grpc: bool) -> AnyResult<Binary> {
+    if grpc {
+        Ok(Binary::from(response.encode_to_vec()))
+    } else {
+        Ok(to_json_binary(&response)?)
+    }
+}
+
+fn to_coin(coin: Option<Coin>) -> AnyResult<cosmwasm_std::Coin> {
+    let Some(coin) = coin else {
+        bail!("marker message did not include an amount");
+    };
+    Ok(cosmwasm_std::coin(coin.amount.parse::<u128>()?, coin.denom))
+}
@@ -1,4 +1,10 @@
 pub mod attribute_extractor;
+pub mod integration;
+pub mod test_compliance;
 pub mod test_constants;
 pub mod test_defaults;
+pub mod test_ibc;
 pub mod test_instantiate;
+pub mod test_markers;
+pub mod test_metadata;
+pub mod test_versions;
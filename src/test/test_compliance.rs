@@ -0,0 +1,33 @@
+use crate::types::msg::{ComplianceQuery, ComplianceResponse};
+use cosmwasm_std::{
+    from_json, to_json_binary, Addr, ContractResult, SystemError, SystemResult, WasmQuery,
+};
+use provwasm_mocks::MockProvenanceQuerier;
+
+/// Registers the given response as the answer to every [ComplianceQuery] sent to the given
+/// compliance contract.  Queries to any other contract fail.
+///
+/// # Parameters
+/// * `querier` The mock querier to which the response will be added.
+/// * `compliance_contract` The address of the mocked compliance contract.
+/// * `response` The response returned for every compliance check.
+pub fn mock_compliance_response(
+    querier: &mut MockProvenanceQuerier,
+    compliance_contract: &Addr,
+    response: ComplianceResponse,
+) {
+    let compliance_contract = compliance_contract.to_string();
+    querier.mock_querier.update_wasm(move |query| match query {
+        WasmQuery::Smart { contract_addr, msg } if contract_addr == &compliance_contract => {
+            match from_json::<ComplianceQuery>(msg) {
+                Ok(ComplianceQuery::CheckTrade { .. }) => SystemResult::Ok(ContractResult::Ok(
+                    to_json_binary(&response).expect("the compliance response should serialize"),
+                )),
+                Err(e) => panic!("unexpected query sent to the compliance contract: {e:?}"),
+            }
+        }
+        _ => SystemResult::Err(SystemError::UnsupportedRequest {
+            kind: "only the compliance contract can be queried".to_string(),
+        }),
+    });
+}
@@ -23,6 +23,25 @@ impl Default for InstantiateMsg {
             required_deposit_attributes: vec![DEFAULT_REQUIRED_DEPOSIT_ATTRIBUTE.to_string()],
             required_withdraw_attributes: vec![DEFAULT_REQUIRED_WITHDRAW_ATTRIBUTE.to_string()],
             name_to_bind: Some(DEFAULT_BOUND_NAME.to_string()),
+            deposit_denom_aliases: vec![],
+            skip_balance_precheck: false,
+            large_trade_threshold: None,
+            min_fund_amount: None,
+            min_withdraw_amount: None,
+            max_fund_amount: None,
+            max_withdraw_amount: None,
+            daily_cap: None,
+            per_account_daily_limit: None,
+            expected_ibc_trace: None,
+            msg_fee: None,
+            fund_fee_bps: None,
+            withdraw_fee_bps: None,
+            fee_collector: None,
+            required_scope_spec: None,
+            compliance_contract: None,
+            admin_is_group: false,
+            gov_authority: None,
+            publish_trading_denom_metadata: None,
         }
     }
 }
@@ -0,0 +1,31 @@
+use provwasm_mocks::MockProvenanceQuerier;
+use provwasm_std::types::ibc::applications::transfer::v1::{
+    DenomTrace, QueryDenomTraceRequest, QueryDenomTraceResponse,
+};
+
+/// The IBC voucher denom used by tests that verify denom traces.
+pub const TEST_IBC_DENOM: &str =
+    "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2";
+
+/// Registers the given trace as the response to every IBC denom trace query made against the
+/// querier.
+///
+/// # Parameters
+/// * `querier` The mock querier to which the response will be added.
+/// * `path` The chain of port and channel identifiers through which the asset arrived.
+/// * `base_denom` The denom of the asset on its native chain.
+pub fn mock_denom_trace_response<S1: Into<String>, S2: Into<String>>(
+    querier: &mut MockProvenanceQuerier,
+    path: S1,
+    base_denom: S2,
+) {
+    QueryDenomTraceRequest::mock_response(
+        querier,
+        QueryDenomTraceResponse {
+            denom_trace: Some(DenomTrace {
+                path: path.into(),
+                base_denom: base_denom.into(),
+            }),
+        },
+    );
+}
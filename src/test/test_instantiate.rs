@@ -1,16 +1,33 @@
 use crate::instantiate::instantiate_contract::instantiate_contract;
 use crate::test::test_constants::DEFAULT_ADMIN;
+use crate::test::test_markers::{mock_marker_response, test_marker_account};
 use crate::types::msg::InstantiateMsg;
-use cosmwasm_std::testing::{message_info, mock_env};
-use cosmwasm_std::{Addr, DepsMut};
+use cosmwasm_std::testing::{message_info, mock_env, MockApi, MockStorage};
+use cosmwasm_std::{Addr, OwnedDeps};
+use provwasm_mocks::MockProvenanceQuerier;
+use provwasm_std::types::provenance::marker::v1::MarkerQuerier;
 
-pub fn test_instantiate(deps: DepsMut) {
+pub fn test_instantiate(deps: &mut OwnedDeps<MockStorage, MockApi, MockProvenanceQuerier>) {
     test_instantiate_with_msg(deps, InstantiateMsg::default());
 }
 
-pub fn test_instantiate_with_msg(deps: DepsMut, msg: InstantiateMsg) {
+pub fn test_instantiate_with_msg(
+    deps: &mut OwnedDeps<MockStorage, MockApi, MockProvenanceQuerier>,
+    msg: InstantiateMsg,
+) {
+    // Instantiation requires both markers to exist, so a default marker is registered unless the
+    // test has already registered its own marker response
+    if MarkerQuerier::new(&deps.as_ref().querier)
+        .marker(msg.trading_marker.name.to_owned())
+        .is_err()
+    {
+        mock_marker_response(
+            &mut deps.querier,
+            test_marker_account(&msg.trading_marker.name, "0"),
+        );
+    }
     instantiate_contract(
-        deps,
+        deps.as_mut(),
         mock_env(),
         message_info(&Addr::unchecked(DEFAULT_ADMIN), &[]),
         msg,
@@ -0,0 +1,95 @@
+use cosmwasm_std::testing::{MockApi, MOCK_CONTRACT_ADDR};
+use provwasm_mocks::MockProvenanceQuerier;
+use provwasm_std::shim::Any;
+use provwasm_std::types::cosmos::auth::v1beta1::BaseAccount;
+use provwasm_std::types::provenance::marker::v1::{
+    Access, AccessGrant, MarkerAccount, MarkerStatus, MarkerType, Params, QueryMarkerRequest,
+    QueryMarkerResponse, QueryParamsRequest, QueryParamsResponse,
+};
+
+/// Builds an active, restricted marker account for the given denom that does not have a fixed
+/// supply.  The mock contract address is granted every permission that the contract requires on
+/// either of its markers.
+///
+/// # Parameters
+/// * `denom` The denom managed by the marker.
+/// * `supply` The marker's current supply.
+pub fn test_marker_account<S1: Into<String>, S2: Into<String>>(
+    denom: S1,
+    supply: S2,
+) -> MarkerAccount {
+    MarkerAccount {
+        base_account: Some(BaseAccount {
+            address: MockApi::default().addr_make("marker").to_string(),
+            pub_key: None,
+            account_number: 32,
+            sequence: 37,
+        }),
+        manager: "some-manager".to_string(),
+        access_control: vec![AccessGrant {
+            address: MOCK_CONTRACT_ADDR.to_string(),
+            permissions: vec![
+                Access::Mint as i32,
+                Access::Burn as i32,
+                Access::Withdraw as i32,
+                Access::Transfer as i32,
+            ],
+        }],
+        status: MarkerStatus::Active as i32,
+        denom: denom.into(),
+        supply: supply.into(),
+        marker_type: MarkerType::Restricted as i32,
+        supply_fixed: false,
+        allow_governance_control: false,
+        allow_forced_transfer: false,
+        required_attributes: vec![],
+    }
+}
+
+/// Registers the given marker account as the response to every marker query made against the
+/// querier.
+///
+/// # Parameters
+/// * `querier` The mock querier to which the response will be added.
+/// * `marker` The marker account to return.
+pub fn mock_marker_response(querier: &mut MockProvenanceQuerier, marker: MarkerAccount) {
+    QueryMarkerRequest::mock_response(
+        querier,
+        QueryMarkerResponse {
+            marker: Some(Any {
+                type_url: "/provenance.marker.v1.MarkerAccount".to_string(),
+                value: marker.to_proto_bytes(),
+            }),
+        },
+    );
+}
+
+/// Registers a response to every marker query made against the querier that does not include a
+/// marker, simulating a marker that no longer exists.
+///
+/// # Parameters
+/// * `querier` The mock querier to which the response will be added.
+pub fn mock_missing_marker_response(querier: &mut MockProvenanceQuerier) {
+    QueryMarkerRequest::mock_response(querier, QueryMarkerResponse { marker: None });
+}
+
+/// Registers a marker module params response with the given max supply.
+///
+/// # Parameters
+/// * `querier` The mock querier to which the response will be added.
+/// * `max_supply` The max supply enforced by the marker module.  An empty value indicates that no
+/// max supply is enforced.
+pub fn mock_marker_params_response<S: Into<String>>(
+    querier: &mut MockProvenanceQuerier,
+    max_supply: S,
+) {
+    QueryParamsRequest::mock_response(
+        querier,
+        QueryParamsResponse {
+            params: Some(Params {
+                max_supply: max_supply.into(),
+                ..Params::default()
+            }),
+        },
+    );
+}
@@ -0,0 +1,45 @@
+use provwasm_mocks::MockProvenanceQuerier;
+use provwasm_std::types::provenance::metadata::v1::{
+    OwnershipRequest, OwnershipResponse, ScopeRequest, ScopeResponse, ScopeSpecIdInfo, ScopeWrapper,
+};
+
+/// The scope specification address used by tests that require scope ownership.
+pub const TEST_SCOPE_SPEC: &str = "scopespec1qnwg86nsatx5pl56muw0v9ytlz3qu3jx6m";
+
+/// Registers the given scopes as the response to every scope ownership query made against the
+/// querier, and the given scope specification as the specification of every scope fetched.
+///
+/// # Parameters
+/// * `querier` The mock querier to which the responses will be added.
+/// * `scope_uuids` The uuids of the scopes owned by every queried account.
+/// * `scope_spec` The bech32 address of the scope specification of every fetched scope.
+pub fn mock_scope_ownership_response<S: Into<String>>(
+    querier: &mut MockProvenanceQuerier,
+    scope_uuids: &[&str],
+    scope_spec: S,
+) {
+    OwnershipRequest::mock_response(
+        querier,
+        OwnershipResponse {
+            scope_uuids: scope_uuids.iter().map(|uuid| uuid.to_string()).collect(),
+            request: None,
+            pagination: None,
+        },
+    );
+    ScopeRequest::mock_response(
+        querier,
+        ScopeResponse {
+            scope: Some(ScopeWrapper {
+                scope: None,
+                scope_id_info: None,
+                scope_spec_id_info: Some(ScopeSpecIdInfo {
+                    scope_spec_addr: scope_spec.into(),
+                    ..ScopeSpecIdInfo::default()
+                }),
+            }),
+            sessions: vec![],
+            records: vec![],
+            request: None,
+        },
+    );
+}
@@ -0,0 +1,19 @@
+use crate::store::contract_state::{get_contract_state_v1, set_contract_state_v1, CONTRACT_TYPE};
+use cosmwasm_std::Storage;
+use cw2::set_contract_version;
+
+/// Makes the stored contract look like the given release of the contract by setting the version in
+/// both the contract state and the cw2 contract version, allowing a migration to be exercised.
+///
+/// # Parameters
+/// * `storage` The storage of an instantiated contract.
+/// * `version` The semver version to store.
+pub fn set_stored_contract_version(storage: &mut dyn Storage, version: &str) {
+    let mut contract_state =
+        get_contract_state_v1(storage).expect("contract state should load after instantiation");
+    contract_state.contract_version = version.to_string();
+    set_contract_state_v1(storage, &contract_state)
+        .expect("contract state should save successfully");
+    set_contract_version(storage, CONTRACT_TYPE, version)
+        .expect("the cw2 contract version should save successfully");
+}
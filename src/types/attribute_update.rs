@@ -0,0 +1,97 @@
+use crate::types::error::ContractError;
+use crate::types::quote::TradeDirection;
+use crate::util::self_validating::SelfValidating;
+use crate::util::validation_utils::validate_attribute_name;
+use result_extensions::ResultExtensions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// A portion of a new required attribute list, submitted within an [AdminBulkUpdateAttributes](crate::types::msg::ExecuteMsg::AdminBulkUpdateAttributes)
+/// chunk.  Every update for the same route across all chunks of an operation is combined, in
+/// order, into that route's new list.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AttributeUpdate {
+    /// The trading pair whose attributes are updated.  The contract currently trades a single pair,
+    /// so this must be omitted.
+    #[serde(default)]
+    pub pair_id: Option<String>,
+    /// The trade route whose required attributes are updated: `fund` for [fund_trading](crate::execute::fund_trading::fund_trading),
+    /// and `withdraw` for [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading).
+    pub route: TradeDirection,
+    /// The attribute names to add to the route's new list.
+    pub attributes: Vec<String>,
+}
+impl SelfValidating for AttributeUpdate {
+    fn self_validate(&self) -> Result<(), ContractError> {
+        if self.pair_id.is_some() {
+            return ContractError::ValidationError {
+                message: "pair id must be omitted because the contract trades a single pair"
+                    .to_string(),
+            }
+            .to_err();
+        }
+        if self.attributes.is_empty() {
+            return ContractError::ValidationError {
+                message: "each attribute update must contain at least one attribute".to_string(),
+            }
+            .to_err();
+        }
+        if self
+            .attributes
+            .iter()
+            .any(|attr| validate_attribute_name(attr).is_err())
+        {
+            return ContractError::ValidationError {
+                message: "all specified attributes must be valid".to_string(),
+            }
+            .to_err();
+        }
+        ().to_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::attribute_update::AttributeUpdate;
+    use crate::types::error::ContractError;
+    use crate::types::quote::TradeDirection;
+    use crate::util::self_validating::SelfValidating;
+
+    fn update(pair_id: Option<&str>, attributes: &[&str]) -> AttributeUpdate {
+        AttributeUpdate {
+            pair_id: pair_id.map(|pair_id| pair_id.to_string()),
+            route: TradeDirection::Fund,
+            attributes: attributes.iter().map(|attr| attr.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn validation_should_function_properly() {
+        for (invalid_update, expected_message) in [
+            (
+                update(Some("pair"), &["attr.pb"]),
+                "pair id must be omitted because the contract trades a single pair",
+            ),
+            (
+                update(None, &[]),
+                "each attribute update must contain at least one attribute",
+            ),
+            (
+                update(None, &["attr.pb", ""]),
+                "all specified attributes must be valid",
+            ),
+        ] {
+            match invalid_update.self_validate() {
+                Err(ContractError::ValidationError { message }) => assert_eq!(
+                    expected_message, message,
+                    "unexpected validation message encountered",
+                ),
+                result => panic!("unexpected validation result encountered: {result:?}"),
+            }
+        }
+        update(None, &["attr.pb", "other.pb"])
+            .self_validate()
+            .expect("a valid update should pass validation");
+    }
+}
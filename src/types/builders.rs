@@ -0,0 +1,478 @@
+use crate::types::denom::{Denom, DenomMetadataInput, IbcDenomTrace};
+use crate::types::error::ContractError;
+use crate::types::msg::{ExecuteMsg, InstantiateMsg};
+use crate::types::msg_fee::MsgFee;
+use crate::types::quote::QuoteRef;
+use crate::types::trade_options::TradeOptions;
+use crate::util::self_validating::SelfValidating;
+use cosmwasm_std::Uint128;
+use result_extensions::ResultExtensions;
+
+/// Constructs an [InstantiateMsg], defaulting every optional field to its omitted value.  The
+/// message is validated when it is built.
+///
+/// # Example
+/// ```
+/// use funding_trading_bridge_smart_contract::types::builders::InstantiateMsgBuilder;
+/// use funding_trading_bridge_smart_contract::types::denom::Denom;
+///
+/// let msg = InstantiateMsgBuilder::new(
+///     "bridge",
+///     Denom::new("deposit", 2),
+///     Denom::new("trading", 6),
+/// )
+/// .required_deposit_attributes(vec!["deposit.pb".to_string()])
+/// .name_to_bind("bridge.pb")
+/// .build()
+/// .expect("the message should be valid");
+/// assert_eq!(Some("bridge.pb".to_string()), msg.name_to_bind);
+/// ```
+#[derive(Clone, Debug)]
+pub struct InstantiateMsgBuilder {
+    msg: InstantiateMsg,
+}
+impl InstantiateMsgBuilder {
+    /// Constructs a new builder with the fields that every instantiation requires.
+    ///
+    /// # Parameters
+    /// * `contract_name` A free-form name defining this particular contract instance.
+    /// * `deposit_marker` The marker denom that is deposited to the contract.
+    /// * `trading_marker` The marker denom that the contract sends in exchange for deposits.
+    pub fn new<S: Into<String>>(
+        contract_name: S,
+        deposit_marker: Denom,
+        trading_marker: Denom,
+    ) -> Self {
+        Self {
+            msg: InstantiateMsg {
+                contract_name: contract_name.into(),
+                deposit_marker,
+                trading_marker,
+                required_deposit_attributes: vec![],
+                required_withdraw_attributes: vec![],
+                name_to_bind: None,
+                deposit_denom_aliases: vec![],
+                skip_balance_precheck: false,
+                large_trade_threshold: None,
+                min_fund_amount: None,
+                min_withdraw_amount: None,
+                max_fund_amount: None,
+                max_withdraw_amount: None,
+                daily_cap: None,
+                per_account_daily_limit: None,
+                expected_ibc_trace: None,
+                msg_fee: None,
+                fund_fee_bps: None,
+                withdraw_fee_bps: None,
+                fee_collector: None,
+                required_scope_spec: None,
+                compliance_contract: None,
+                admin_is_group: false,
+                gov_authority: None,
+                publish_trading_denom_metadata: None,
+            },
+        }
+    }
+
+    /// Sets the [required_deposit_attributes](InstantiateMsg#required_deposit_attributes).
+    pub fn required_deposit_attributes(mut self, attributes: Vec<String>) -> Self {
+        self.msg.required_deposit_attributes = attributes;
+        self
+    }
+
+    /// Sets the [required_withdraw_attributes](InstantiateMsg#required_withdraw_attributes).
+    pub fn required_withdraw_attributes(mut self, attributes: Vec<String>) -> Self {
+        self.msg.required_withdraw_attributes = attributes;
+        self
+    }
+
+    /// Sets the [name_to_bind](InstantiateMsg#name_to_bind).
+    pub fn name_to_bind<S: Into<String>>(mut self, name: S) -> Self {
+        self.msg.name_to_bind = Some(name.into());
+        self
+    }
+
+    /// Sets the [deposit_denom_aliases](InstantiateMsg#deposit_denom_aliases).
+    pub fn deposit_denom_aliases(mut self, aliases: Vec<String>) -> Self {
+        self.msg.deposit_denom_aliases = aliases;
+        self
+    }
+
+    /// Sets the [skip_balance_precheck](InstantiateMsg#skip_balance_precheck) value.
+    pub fn skip_balance_precheck(mut self, skip_balance_precheck: bool) -> Self {
+        self.msg.skip_balance_precheck = skip_balance_precheck;
+        self
+    }
+
+    /// Sets the [large_trade_threshold](InstantiateMsg#large_trade_threshold).
+    pub fn large_trade_threshold(mut self, threshold: Uint128) -> Self {
+        self.msg.large_trade_threshold = Some(threshold);
+        self
+    }
+
+    /// Sets the [min_fund_amount](InstantiateMsg#min_fund_amount).
+    pub fn min_fund_amount(mut self, minimum: Uint128) -> Self {
+        self.msg.min_fund_amount = Some(minimum);
+        self
+    }
+
+    /// Sets the [min_withdraw_amount](InstantiateMsg#min_withdraw_amount).
+    pub fn min_withdraw_amount(mut self, minimum: Uint128) -> Self {
+        self.msg.min_withdraw_amount = Some(minimum);
+        self
+    }
+
+    /// Sets the [max_fund_amount](InstantiateMsg#max_fund_amount).
+    pub fn max_fund_amount(mut self, maximum: Uint128) -> Self {
+        self.msg.max_fund_amount = Some(maximum);
+        self
+    }
+
+    /// Sets the [max_withdraw_amount](InstantiateMsg#max_withdraw_amount).
+    pub fn max_withdraw_amount(mut self, maximum: Uint128) -> Self {
+        self.msg.max_withdraw_amount = Some(maximum);
+        self
+    }
+
+    /// Sets the [daily_cap](InstantiateMsg#daily_cap).
+    pub fn daily_cap(mut self, daily_cap: Uint128) -> Self {
+        self.msg.daily_cap = Some(daily_cap);
+        self
+    }
+
+    /// Sets the [per_account_daily_limit](InstantiateMsg#per_account_daily_limit).
+    pub fn per_account_daily_limit(mut self, daily_limit: Uint128) -> Self {
+        self.msg.per_account_daily_limit = Some(daily_limit);
+        self
+    }
+
+    /// Sets the [expected_ibc_trace](InstantiateMsg#expected_ibc_trace).
+    pub fn expected_ibc_trace(mut self, trace: IbcDenomTrace) -> Self {
+        self.msg.expected_ibc_trace = Some(trace);
+        self
+    }
+
+    /// Sets the [msg_fee](InstantiateMsg#msg_fee).
+    pub fn msg_fee(mut self, msg_fee: MsgFee) -> Self {
+        self.msg.msg_fee = Some(msg_fee);
+        self
+    }
+
+    /// Sets the [fund_fee_bps](InstantiateMsg#fund_fee_bps) and the [fee_collector](InstantiateMsg#fee_collector)
+    /// that receives the fee.
+    pub fn fund_fee<S: Into<String>>(mut self, fund_fee_bps: u16, fee_collector: S) -> Self {
+        self.msg.fund_fee_bps = Some(fund_fee_bps);
+        self.msg.fee_collector = Some(fee_collector.into());
+        self
+    }
+
+    /// Sets the [withdraw_fee_bps](InstantiateMsg#withdraw_fee_bps) and the [fee_collector](InstantiateMsg#fee_collector)
+    /// that receives the fee.  The collector is shared with the fund fee.
+    pub fn withdraw_fee<S: Into<String>>(
+        mut self,
+        withdraw_fee_bps: u16,
+        fee_collector: S,
+    ) -> Self {
+        self.msg.withdraw_fee_bps = Some(withdraw_fee_bps);
+        self.msg.fee_collector = Some(fee_collector.into());
+        self
+    }
+
+    /// Sets the [required_scope_spec](InstantiateMsg#required_scope_spec).
+    pub fn required_scope_spec<S: Into<String>>(mut self, scope_spec: S) -> Self {
+        self.msg.required_scope_spec = Some(scope_spec.into());
+        self
+    }
+
+    /// Sets the [compliance_contract](InstantiateMsg#compliance_contract).
+    pub fn compliance_contract<S: Into<String>>(mut self, compliance_contract: S) -> Self {
+        self.msg.compliance_contract = Some(compliance_contract.into());
+        self
+    }
+
+    /// Sets the [admin_is_group](InstantiateMsg#admin_is_group) value.
+    pub fn admin_is_group(mut self, admin_is_group: bool) -> Self {
+        self.msg.admin_is_group = admin_is_group;
+        self
+    }
+
+    /// Sets the [gov_authority](InstantiateMsg#gov_authority).
+    pub fn gov_authority<S: Into<String>>(mut self, gov_authority: S) -> Self {
+        self.msg.gov_authority = Some(gov_authority.into());
+        self
+    }
+
+    /// Sets the [publish_trading_denom_metadata](InstantiateMsg#publish_trading_denom_metadata).
+    pub fn publish_trading_denom_metadata(mut self, metadata: DenomMetadataInput) -> Self {
+        self.msg.publish_trading_denom_metadata = Some(metadata);
+        self
+    }
+
+    /// Validates and returns the constructed message.
+    pub fn build(self) -> Result<InstantiateMsg, ContractError> {
+        self.msg.self_validate()?;
+        self.msg.to_ok()
+    }
+}
+
+/// Constructs an [ExecuteMsg::FundTrading] message, defaulting every optional behavior to off.  The
+/// message is validated when it is built.
+///
+/// # Example
+/// ```
+/// use cosmwasm_std::Uint128;
+/// use funding_trading_bridge_smart_contract::types::builders::FundTradingBuilder;
+///
+/// let msg = FundTradingBuilder::new(Uint128::new(100))
+///     .allow_partial(true)
+///     .build()
+///     .expect("the message should be valid");
+/// ```
+#[derive(Clone, Debug)]
+pub struct FundTradingBuilder {
+    trade_amount: Uint128,
+    options: TradeOptions,
+}
+impl FundTradingBuilder {
+    /// Constructs a new builder for a trade of the given amount of deposit denom.
+    ///
+    /// # Parameters
+    /// * `trade_amount` The amount of deposit denom to trade.
+    pub fn new(trade_amount: Uint128) -> Self {
+        Self {
+            trade_amount,
+            options: TradeOptions::default(),
+        }
+    }
+
+    /// Sets whether or not the trade is only a dry run.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.options.dry_run = dry_run;
+        self
+    }
+
+    /// Locks the trade to the referenced quote.
+    pub fn quote(mut self, quote: QuoteRef) -> Self {
+        self.options.quote = Some(quote);
+        self
+    }
+
+    /// Credits the given account as the trade's referrer.
+    pub fn referrer<S: Into<String>>(mut self, referrer: S) -> Self {
+        self.options.referrer = Some(referrer.into());
+        self
+    }
+
+    /// Sets whether or not the trade may be reduced to the sender's balance.
+    pub fn allow_partial(mut self, allow_partial: bool) -> Self {
+        self.options.allow_partial = allow_partial;
+        self
+    }
+
+    /// Sets whether or not the trade may leave an unconvertible remainder with the sender.
+    pub fn allow_remainder(mut self, allow_remainder: bool) -> Self {
+        self.options.allow_remainder = allow_remainder;
+        self
+    }
+
+    /// Validates and returns the constructed message.
+    pub fn build(self) -> Result<ExecuteMsg, ContractError> {
+        let msg = ExecuteMsg::FundTrading {
+            trade_amount: self.trade_amount,
+            dry_run: self.options.dry_run,
+            quote: self.options.quote,
+            referrer: self.options.referrer,
+            allow_partial: self.options.allow_partial,
+            allow_remainder: self.options.allow_remainder,
+        };
+        msg.self_validate()?;
+        msg.to_ok()
+    }
+}
+
+/// Constructs an [ExecuteMsg::WithdrawTrading] message, defaulting every optional behavior to off.
+/// The message is validated when it is built.
+///
+/// # Example
+/// ```
+/// use cosmwasm_std::Uint128;
+/// use funding_trading_bridge_smart_contract::types::builders::WithdrawTradingBuilder;
+///
+/// let msg = WithdrawTradingBuilder::new(Uint128::new(1000000))
+///     .referrer("referrer-address")
+///     .build()
+///     .expect("the message should be valid");
+/// ```
+#[derive(Clone, Debug)]
+pub struct WithdrawTradingBuilder {
+    trade_amount: Uint128,
+    options: TradeOptions,
+}
+impl WithdrawTradingBuilder {
+    /// Constructs a new builder for a trade of the given amount of trading denom.
+    ///
+    /// # Parameters
+    /// * `trade_amount` The amount of trading denom to trade.
+    pub fn new(trade_amount: Uint128) -> Self {
+        Self {
+            trade_amount,
+            options: TradeOptions::default(),
+        }
+    }
+
+    /// Sets whether or not the trade is only a dry run.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.options.dry_run = dry_run;
+        self
+    }
+
+    /// Locks the trade to the referenced quote.
+    pub fn quote(mut self, quote: QuoteRef) -> Self {
+        self.options.quote = Some(quote);
+        self
+    }
+
+    /// Credits the given account as the trade's referrer.
+    pub fn referrer<S: Into<String>>(mut self, referrer: S) -> Self {
+        self.options.referrer = Some(referrer.into());
+        self
+    }
+
+    /// Sets whether or not the trade may be reduced to the sender's balance.
+    pub fn allow_partial(mut self, allow_partial: bool) -> Self {
+        self.options.allow_partial = allow_partial;
+        self
+    }
+
+    /// Sets whether or not the trade may leave an unconvertible remainder with the sender.
+    pub fn allow_remainder(mut self, allow_remainder: bool) -> Self {
+        self.options.allow_remainder = allow_remainder;
+        self
+    }
+
+    /// Validates and returns the constructed message.
+    pub fn build(self) -> Result<ExecuteMsg, ContractError> {
+        let msg = ExecuteMsg::WithdrawTrading {
+            trade_amount: self.trade_amount,
+            dry_run: self.options.dry_run,
+            quote: self.options.quote,
+            referrer: self.options.referrer,
+            allow_partial: self.options.allow_partial,
+            allow_remainder: self.options.allow_remainder,
+        };
+        msg.self_validate()?;
+        msg.to_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::builders::{
+        FundTradingBuilder, InstantiateMsgBuilder, WithdrawTradingBuilder,
+    };
+    use crate::types::denom::Denom;
+    use crate::types::error::ContractError;
+    use crate::types::msg::{ExecuteMsg, InstantiateMsg};
+    use crate::types::quote::QuoteRef;
+    use cosmwasm_std::Uint128;
+
+    #[test]
+    fn instantiate_msg_builder_should_produce_a_validated_message() {
+        let msg = InstantiateMsgBuilder::new(
+            "bridge",
+            Denom::new("deposit", 2),
+            Denom::new("trading", 6),
+        )
+        .required_withdraw_attributes(vec!["withdraw.pb".to_string()])
+        .large_trade_threshold(Uint128::new(500))
+        .min_withdraw_amount(Uint128::new(10_000))
+        .build()
+        .expect("a valid instantiate msg should be built");
+        assert_eq!(
+            InstantiateMsg {
+                contract_name: "bridge".to_string(),
+                deposit_marker: Denom::new("deposit", 2),
+                trading_marker: Denom::new("trading", 6),
+                required_deposit_attributes: vec![],
+                required_withdraw_attributes: vec!["withdraw.pb".to_string()],
+                name_to_bind: None,
+                deposit_denom_aliases: vec![],
+                skip_balance_precheck: false,
+                large_trade_threshold: Some(Uint128::new(500)),
+                min_fund_amount: None,
+                min_withdraw_amount: Some(Uint128::new(10_000)),
+                max_fund_amount: None,
+                max_withdraw_amount: None,
+                daily_cap: None,
+                per_account_daily_limit: None,
+                expected_ibc_trace: None,
+                msg_fee: None,
+                fund_fee_bps: None,
+                withdraw_fee_bps: None,
+                fee_collector: None,
+                required_scope_spec: None,
+                compliance_contract: None,
+                admin_is_group: false,
+                gov_authority: None,
+                publish_trading_denom_metadata: None,
+            },
+            msg,
+            "the builder should only set the requested fields",
+        );
+        let error =
+            InstantiateMsgBuilder::new("", Denom::new("deposit", 2), Denom::new("trading", 6))
+                .build()
+                .expect_err("an invalid instantiate msg should not be built");
+        assert!(
+            matches!(error, ContractError::ValidationError { .. }),
+            "unexpected error emitted by an invalid instantiate msg: {error:?}",
+        );
+    }
+
+    #[test]
+    fn trade_builders_should_produce_validated_messages() {
+        assert_eq!(
+            ExecuteMsg::FundTrading {
+                trade_amount: Uint128::new(100),
+                dry_run: true,
+                quote: None,
+                referrer: Some("referrer".to_string()),
+                allow_partial: false,
+                allow_remainder: false,
+            },
+            FundTradingBuilder::new(Uint128::new(100))
+                .dry_run(true)
+                .referrer("referrer")
+                .build()
+                .expect("a valid fund trading msg should be built"),
+            "the fund trading builder should set the requested fields",
+        );
+        assert_eq!(
+            ExecuteMsg::WithdrawTrading {
+                trade_amount: Uint128::new(100),
+                dry_run: false,
+                quote: None,
+                referrer: None,
+                allow_partial: true,
+                allow_remainder: true,
+            },
+            WithdrawTradingBuilder::new(Uint128::new(100))
+                .allow_partial(true)
+                .allow_remainder(true)
+                .build()
+                .expect("a valid withdraw trading msg should be built"),
+            "the withdraw trading builder should set the requested fields",
+        );
+        WithdrawTradingBuilder::new(Uint128::zero())
+            .build()
+            .expect_err("a zero trade amount should not be built");
+        FundTradingBuilder::new(Uint128::new(100))
+            .allow_partial(true)
+            .quote(QuoteRef {
+                hash: "hash".to_string(),
+                quoted_at_height: 1,
+            })
+            .build()
+            .expect_err("a partial fill should not be combined with a quote");
+    }
+}
@@ -1,12 +1,16 @@
 use crate::types::error::ContractError;
 use crate::util::self_validating::SelfValidating;
-use cosmwasm_std::Uint64;
+use cosmwasm_std::{Uint128, Uint64};
 use result_extensions::ResultExtensions;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// The prefix that distinguishes IBC voucher denoms from denoms native to the chain.
+pub const IBC_DENOM_PREFIX: &str = "ibc/";
+
 /// Defines a blockchain denom associated with a marker in reference to the contract's usages.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(deny_unknown_fields)]
 pub struct Denom {
     /// The name of the marker on-chain that manages this denom.
     pub name: String,
@@ -38,14 +42,156 @@ impl Denom {
     }
 }
 
+/// Describes the origin of an IBC voucher denom, as recorded by the IBC transfer module.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct IbcDenomTrace {
+    /// The denom of the asset on its native chain.
+    pub base_denom: String,
+    /// The chain of port and channel identifiers through which the asset arrived, like
+    /// `transfer/channel-0`.
+    pub path: String,
+}
+impl SelfValidating for IbcDenomTrace {
+    fn self_validate(&self) -> Result<(), ContractError> {
+        if self.base_denom.is_empty() {
+            return ContractError::ValidationError {
+                message: "base denom cannot be empty".to_string(),
+            }
+            .to_err();
+        }
+        if self.path.is_empty() {
+            return ContractError::ValidationError {
+                message: "path cannot be empty".to_string(),
+            }
+            .to_err();
+        }
+        ().to_ok()
+    }
+}
+
+/// The bank module metadata that describes how wallets should display the trading marker's denom.
+/// The trading marker's denom is always used as the base unit.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DenomMetadataInput {
+    /// The human-readable name of the denom, like `Funding Trading Dollar`.
+    pub name: String,
+    /// The denom unit that wallets display balances in, like `ftd`.
+    pub display: String,
+    /// The ticker symbol of the denom, like `FTD`.
+    pub symbol: String,
+    /// A free-form description of the denom.
+    #[serde(default)]
+    pub description: String,
+    /// The power of ten by which the [display](DenomMetadataInput#display) unit exceeds the base
+    /// unit.  Must equal the trading marker's [precision](Denom#precision).
+    pub exponent: u32,
+}
+impl SelfValidating for DenomMetadataInput {
+    fn self_validate(&self) -> Result<(), ContractError> {
+        if self.name.is_empty() {
+            return ContractError::ValidationError {
+                message: "name cannot be empty".to_string(),
+            }
+            .to_err();
+        }
+        if self.display.is_empty() {
+            return ContractError::ValidationError {
+                message: "display cannot be empty".to_string(),
+            }
+            .to_err();
+        }
+        if self.symbol.is_empty() {
+            return ContractError::ValidationError {
+                message: "symbol cannot be empty".to_string(),
+            }
+            .to_err();
+        }
+        ().to_ok()
+    }
+}
+impl DenomMetadataInput {
+    /// Verifies that this metadata can describe the given trading marker.  The
+    /// [exponent](DenomMetadataInput#exponent) must equal the marker's precision, and the
+    /// [display](DenomMetadataInput#display) unit must differ from the marker's denom unless the
+    /// precision is zero, in which case the base unit is displayed directly.
+    ///
+    /// # Parameters
+    /// * `trading_marker` The trading marker whose denom the metadata describes.
+    pub fn validate_for_marker(&self, trading_marker: &Denom) -> Result<(), ContractError> {
+        if u64::from(self.exponent) != trading_marker.precision.u64() {
+            return ContractError::ValidationError {
+                message: format!(
+                    "denom metadata exponent [{}] must equal the trading marker precision [{}]",
+                    self.exponent, trading_marker.precision,
+                ),
+            }
+            .to_err();
+        }
+        if (self.exponent == 0) != (self.display == trading_marker.name) {
+            return ContractError::ValidationError {
+                message: format!(
+                    "denom metadata display [{}] must match the trading marker denom [{}] exactly when the exponent is zero",
+                    self.display, trading_marker.name,
+                ),
+            }
+            .to_err();
+        }
+        ().to_ok()
+    }
+}
+
 /// Defines a conversion between one denom and another.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
 pub struct DenomConversion {
     /// The amount of the first denom used in the conversion.
-    pub source_amount: u128,
+    pub source_amount: Uint128,
     /// The amount of the second denom to which the first denom is equivalent after conversion.
-    pub target_amount: u128,
+    pub target_amount: Uint128,
     /// Any amount of the [source amount](DenomConversion#source_amount) that cannot be converted to
     /// the second denom due to values that do not fit into the second denom's precision.
-    pub remainder: u128,
+    pub remainder: Uint128,
+}
+
+/// Describes the valid trade amounts nearest to a requested amount of a source denom.  A valid
+/// amount converts to a nonzero amount of the target denom without leaving a remainder, and falls
+/// within the minimum and maximum trade amounts of its direction.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct AmountSuggestion {
+    /// The largest valid amount that does not exceed the requested amount.  Omitted when no valid
+    /// amount exists at or below the requested amount.
+    pub lower_amount: Option<Uint128>,
+    /// The amount by which the requested amount exceeds the [lower amount](AmountSuggestion#lower_amount).
+    pub lower_delta: Option<Uint128>,
+    /// The smallest valid amount that exceeds the requested amount.  Omitted when no valid amount
+    /// exists above the requested amount.
+    pub upper_amount: Option<Uint128>,
+    /// The amount by which the [upper amount](AmountSuggestion#upper_amount) exceeds the requested
+    /// amount.
+    pub upper_delta: Option<Uint128>,
+}
+
+/// Identifies one of the two denoms managed by the contract.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DenomSide {
+    /// The denom of the [deposit marker](crate::store::contract_state::ContractStateV1#deposit_marker).
+    Deposit,
+    /// The denom of the [trading marker](crate::store::contract_state::ContractStateV1#trading_marker).
+    Trading,
+}
+
+/// Describes an amount of coin in the decimal form in which it is displayed to users, derived from
+/// the precision of its denom.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct AmountDisplay {
+    /// The digits of the amount that precede the decimal point.  Always contains at least one digit.
+    pub integer_part: String,
+    /// The digits of the amount that follow the decimal point, padded with trailing zeroes to the
+    /// denom's precision.  Empty when the precision is zero.
+    pub fractional_part: String,
+    /// The amount as it should be rendered, like `1234.560`.  The decimal point is omitted when the
+    /// precision is zero.
+    pub display: String,
 }
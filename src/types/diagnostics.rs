@@ -0,0 +1,77 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The outcome of a single verification of one of the contract's external dependencies, like its
+/// marker grants or name binding.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct DiagnosticCheck {
+    /// A short, stable identifier for the check that was performed.
+    pub name: String,
+    /// Whether or not the dependency was found to be in the expected state.
+    pub passed: bool,
+    /// A free-form description of what was found.
+    pub details: String,
+}
+impl DiagnosticCheck {
+    /// Constructs a passing check.
+    ///
+    /// # Parameters
+    /// * `name` A short, stable identifier for the check that was performed.
+    /// * `details` A free-form description of what was found.
+    pub fn pass<S1: Into<String>, S2: Into<String>>(name: S1, details: S2) -> Self {
+        Self {
+            name: name.into(),
+            passed: true,
+            details: details.into(),
+        }
+    }
+
+    /// Constructs a failing check.
+    ///
+    /// # Parameters
+    /// * `name` A short, stable identifier for the check that was performed.
+    /// * `details` A free-form description of what was found.
+    pub fn fail<S1: Into<String>, S2: Into<String>>(name: S1, details: S2) -> Self {
+        Self {
+            name: name.into(),
+            passed: false,
+            details: details.into(),
+        }
+    }
+}
+
+/// A marker access grant that the contract lacks for the trade routes to function, returned by
+/// the [query_required_grants](crate::query::query_required_grants::query_required_grants) query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct RequiredGrant {
+    /// The denom of the marker on which the contract lacks permissions.
+    pub marker_denom: String,
+    /// The names of the missing permissions, like `ACCESS_MINT`.
+    pub missing_permissions: Vec<String>,
+    /// The type url with which the [msg](RequiredGrant#msg) must be encoded.
+    pub type_url: String,
+    /// The message that adds the missing permissions, in its JSON form.
+    pub msg: AddAccessMsg,
+}
+
+/// The JSON form of a Provenance marker module `MsgAddAccessRequest`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct AddAccessMsg {
+    /// The denom of the marker to which access is added.
+    pub denom: String,
+    /// The bech32 address of the account that must sign the message.  This is the marker's
+    /// manager, or an account holding admin access when the marker has no manager.  Empty when
+    /// neither exists, in which case the signer must be filled in by the operator.
+    pub administrator: String,
+    /// The access grants to add.
+    pub access: Vec<AccessGrantMsg>,
+}
+
+/// The JSON form of a Provenance marker module `AccessGrant`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct AccessGrantMsg {
+    /// The bech32 address of the account receiving the permissions.
+    pub address: String,
+    /// The names of the granted permissions, like `ACCESS_MINT`.
+    pub permissions: Vec<String>,
+}
@@ -1,10 +1,33 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{OverflowError, StdError, Uint128};
 use std::num::ParseIntError;
 use thiserror::Error;
 
 /// The base error enum that is used to wrap any errors that occur throughout contract execution.
 #[derive(Error, Debug)]
 pub enum ContractError {
+    /// An error that occurs when a trade would push the value that an account trades during the
+    /// current UTC day beyond the contract's per-account daily limit.
+    #[error("account daily limit exceeded: {message}")]
+    AccountDailyLimitExceededError {
+        /// A free-form message describing the nature of the error.
+        message: String,
+    },
+
+    /// An error that occurs when the configured compliance contract does not approve a trade, or
+    /// cannot be reached to do so.
+    #[error("compliance check failed: {message}")]
+    ComplianceError {
+        /// A free-form message describing the nature of the error.
+        message: String,
+    },
+
+    /// An error that occurs when a trade is requested while the contract admin has paused trading.
+    #[error("contract is paused: {message}")]
+    ContractPausedError {
+        /// A free-form message describing the nature of the error.
+        message: String,
+    },
+
     /// An error that occurs when a conversion between two denominations fails.
     #[error("conversion failure: {message}")]
     ConversionError {
@@ -12,6 +35,25 @@ pub enum ContractError {
         message: String,
     },
 
+    /// An error that occurs when a fund trade would push the deposit denom accepted during the
+    /// current UTC day beyond the contract's daily cap.
+    #[error("daily cap exceeded: {message}")]
+    DailyCapExceededError {
+        /// A free-form message describing the nature of the error.
+        message: String,
+    },
+
+    /// An error that occurs when the contract does not hold enough of a denom to complete a trade.
+    #[error("insufficient contract reserves: required [{required}{denom}], but the contract only holds [{available}{denom}]")]
+    InsolventError {
+        /// The amount of the denom that the trade requires the contract to release.
+        required: Uint128,
+        /// The amount of the denom currently held by the contract.
+        available: Uint128,
+        /// The denom that the contract does not hold enough of.
+        denom: String,
+    },
+
     /// An error that occurs when a blockchain account contains invalid information.
     #[error("invalid account: {message}")]
     InvalidAccountError {
@@ -33,6 +75,13 @@ pub enum ContractError {
         message: String,
     },
 
+    /// An error that occurs when a trade quote supplied to an execution route no longer holds.
+    #[error("invalid quote: {message}")]
+    InvalidQuoteError {
+        /// A free-form message describing the nature of the error.
+        message: String,
+    },
+
     /// An error that occurs when a migration fails.
     #[error("migration error occurred: {message}")]
     MigrationError {
@@ -40,6 +89,14 @@ pub enum ContractError {
         message: String,
     },
 
+    /// An error that occurs when a trade would issue trading denom after minting was permanently
+    /// disabled.
+    #[error("minting is permanently disabled: {message}")]
+    MintingDisabledError {
+        /// A free-form message describing the nature of the error.
+        message: String,
+    },
+
     /// An error that occurs when the executing sender is not authorized to take an action.
     #[error("not authorized: {message}")]
     NotAuthorizedError {
@@ -54,6 +111,38 @@ pub enum ContractError {
         message: String,
     },
 
+    /// An error that occurs when one or more preconditions of a route are not met.  Every failed
+    /// precondition is included, allowing the sender to resolve all of them at once.
+    #[error("preconditions failed: {}", .failures.join("; "))]
+    PreconditionsFailed {
+        /// A description of each precondition that was not met.
+        failures: Vec<String>,
+    },
+
+    /// An error that occurs when a trade would leave part of its requested amount unconverted, and
+    /// the sender did not acknowledge the remainder by setting `allow_remainder`.
+    #[error("unacknowledged remainder: the trade would leave [{remainder}{denom}] unconverted; trade [{clean_amount}{denom}] instead, or set allow_remainder to accept the remainder")]
+    RemainderNotAcknowledgedError {
+        /// The amount of the requested input denom that cannot be converted.
+        remainder: Uint128,
+        /// The largest amount not exceeding the requested amount that converts without a remainder.
+        clean_amount: Uint128,
+        /// The input denom of the trade.
+        denom: String,
+    },
+
+    /// An error that occurs when a trade is requested through a route that the contract admin has
+    /// disabled.
+    #[error("route disabled: {message}")]
+    RouteDisabledError {
+        /// A free-form message describing the nature of the error.
+        message: String,
+    },
+
+    /// A wrapper for a cosmwasm math overflow error.
+    #[error("{0}")]
+    OverflowError(#[from] OverflowError),
+
     /// A wrapper for a core library integer parsing error.
     #[error("{0}")]
     ParseIntError(#[from] ParseIntError),
@@ -73,6 +162,23 @@ pub enum ContractError {
         message: String,
     },
 
+    /// An error that occurs when a submessage emitted by a trade route fails downstream, naming the
+    /// [step](crate::types::trade_step::TradeStep) of the trade that was rejected.
+    #[error("trade step [{step}] failed: {message}")]
+    TradeStepError {
+        /// The name of the trade step that failed.
+        step: String,
+        /// A free-form message describing the nature of the error.
+        message: String,
+    },
+
+    /// An error that occurs when a trade is requested outside of the contract's trading schedule.
+    #[error("trading is closed: {message}")]
+    TradingClosedError {
+        /// A free-form message describing the nature of the error.
+        message: String,
+    },
+
     /// An error that occurs when type validation fails.
     #[error("validation failed: {message}")]
     ValidationError {
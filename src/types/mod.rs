@@ -1,8 +1,29 @@
 //! Contains all types and base functionality used to construct the logic of the contract.
 
+/// Defines the portions of required attribute lists submitted by chunked bulk attribute updates.
+pub mod attribute_update;
+/// Defines fluent builders that construct validated msg payloads for the contract.
+#[cfg(feature = "library")]
+pub mod builders;
 /// Defines a blockchain denom associated with a marker in reference to the contract's usages.
 pub mod denom;
+/// Defines the findings produced when verifying the contract's external dependencies.
+pub mod diagnostics;
 /// Defines all errors emitted by the contract.
 pub mod error;
 /// Defines all msg payloads sent to the contract.
 pub mod msg;
+/// Defines the Provenance msg fee that the contract can assess on trades.
+pub mod msg_fee;
+/// Defines the quotes that lock a trade execution to a previously-queried conversion.
+pub mod quote;
+/// Defines all response payloads returned by the contract's query routes.
+pub mod responses;
+/// Defines the points at which scheduled changes to trading take effect.
+pub mod schedule;
+/// Defines the optional behaviors that a sender can request from a trade route.
+pub mod trade_options;
+/// Defines the outcome of a trade route's checks and calculations before execution.
+pub mod trade_plan;
+/// Defines the steps performed by a trade's submessages, and the replies that report their failure.
+pub mod trade_step;
@@ -1,15 +1,43 @@
-use crate::types::denom::Denom;
+use crate::store::runtime_tunables::{
+    RuntimeTunables, MAX_ATTRIBUTE_MAX_PAGES, MAX_ATTRIBUTE_PAGE_SIZE,
+    MAX_PENDING_TRADE_EXPIRATION_BLOCKS, MAX_QUOTE_VALIDITY_BLOCKS, MIN_ATTRIBUTE_MAX_PAGES,
+    MIN_ATTRIBUTE_PAGE_SIZE, MIN_PENDING_TRADE_EXPIRATION_BLOCKS, MIN_QUOTE_VALIDITY_BLOCKS,
+    MIN_VERIFY_MARKER_CACHE_EVERY_N_TRADES,
+};
+use crate::types::attribute_update::AttributeUpdate;
+use crate::types::denom::{Denom, DenomMetadataInput, DenomSide, IbcDenomTrace, IBC_DENOM_PREFIX};
 use crate::types::error::ContractError;
+use crate::types::msg_fee::{FeeDiscount, MsgFee, FULL_FEE_BPS};
+use crate::types::quote::{QuoteRef, TradeDirection};
+use crate::types::schedule::ScheduledPoint;
 use crate::util::self_validating::SelfValidating;
-use crate::util::validation_utils::validate_attribute_name;
-use cosmwasm_std::Uint128;
+use crate::util::serde_utils::deserialize_trade_amount;
+use crate::util::validation_utils::{check_trade_amount_range, validate_attribute_name};
+use cosmwasm_std::{Timestamp, Uint128};
 use result_extensions::ResultExtensions;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+/// The largest amount of accounts that can be supplied to a single [AdminBatchForceWithdraw](ExecuteMsg::AdminBatchForceWithdraw)
+/// message.
+pub const MAX_BATCH_FORCE_WITHDRAW_ACCOUNTS: usize = 100;
+
+/// The largest amount of attribute updates that can be supplied to a single [AdminBulkUpdateAttributes](ExecuteMsg::AdminBulkUpdateAttributes)
+/// chunk.
+pub const MAX_BULK_ATTRIBUTE_UPDATES_PER_CHUNK: usize = 10;
+
+/// The literal value that the `confirm` field of an [AdminDisableMintingPermanently](ExecuteMsg::AdminDisableMintingPermanently)
+/// message must contain.
+pub const DISABLE_MINTING_CONFIRMATION: &str = "permanently disable minting";
+
+/// The bech32 prefix shared by every scope specification address in the metadata module, including
+/// its separator.
+const SCOPE_SPEC_ADDRESS_PREFIX: &str = "scopespec1";
+
 /// The msg that is sent to the chain in order to instantiate a new instance of this contract's
 /// stored code.  Used in the functionality described in [instantiate_contract](crate::instantiate::instantiate_contract::instantiate_contract).
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(deny_unknown_fields)]
 pub struct InstantiateMsg {
     /// A free-form name defining this particular contract instance.  Used for identification on
     /// query purposes only.
@@ -29,6 +57,106 @@ pub struct InstantiateMsg {
     /// If provided, this value must be a valid provenance name module name that can be bound to an
     /// unrestricted parent name.  This will cause the contract to bind the provided name to itself.
     pub name_to_bind: Option<String>,
+    /// Other denom names that accounts may hold as equivalents of the deposit marker's denom.  See
+    /// [deposit_denom_aliases](crate::store::contract_state::ContractStateV1#deposit_denom_aliases).
+    /// Defaults to empty when omitted.
+    #[serde(default)]
+    pub deposit_denom_aliases: Vec<String>,
+    /// If true, the trade routes will skip verifying the sender's balance before emitting marker
+    /// transfers, saving a bank query per trade at the cost of less descriptive errors.  See
+    /// [skip_balance_precheck](crate::store::contract_state::ContractStateV1#skip_balance_precheck).
+    /// Defaults to false when omitted.
+    #[serde(default)]
+    pub skip_balance_precheck: bool,
+    /// If provided, trades valued at or above this amount of deposit denom must be approved by the
+    /// contract admin before they execute.  See [large_trade_threshold](crate::store::contract_state::ContractStateV1#large_trade_threshold).
+    /// Defaults to no threshold when omitted.
+    #[serde(default)]
+    pub large_trade_threshold: Option<Uint128>,
+    /// If provided, fund trades requesting less than this amount of deposit denom are rejected.
+    /// See [min_fund_amount](crate::store::contract_state::ContractStateV1#min_fund_amount).
+    /// Defaults to no minimum when omitted.
+    #[serde(default)]
+    pub min_fund_amount: Option<Uint128>,
+    /// If provided, withdraw trades requesting less than this amount of trading denom are
+    /// rejected.  See [min_withdraw_amount](crate::store::contract_state::ContractStateV1#min_withdraw_amount).
+    /// Defaults to no minimum when omitted.
+    #[serde(default)]
+    pub min_withdraw_amount: Option<Uint128>,
+    /// If provided, fund trades requesting more than this amount of deposit denom are rejected.
+    /// See [max_fund_amount](crate::store::contract_state::ContractStateV1#max_fund_amount).
+    /// Defaults to no maximum when omitted.
+    #[serde(default)]
+    pub max_fund_amount: Option<Uint128>,
+    /// If provided, withdraw trades requesting more than this amount of trading denom are
+    /// rejected.  See [max_withdraw_amount](crate::store::contract_state::ContractStateV1#max_withdraw_amount).
+    /// Defaults to no maximum when omitted.
+    #[serde(default)]
+    pub max_withdraw_amount: Option<Uint128>,
+    /// If provided, fund trades are rejected once the deposit denom accepted during the current UTC
+    /// day would exceed this amount.  See [daily_cap](crate::store::contract_state::ContractStateV1#daily_cap).
+    /// Defaults to no cap when omitted.
+    #[serde(default)]
+    pub daily_cap: Option<Uint128>,
+    /// If provided, trades are rejected once the value traded by their account during the current
+    /// UTC day would exceed this amount of deposit denom.  See [per_account_daily_limit](crate::store::contract_state::ContractStateV1#per_account_daily_limit).
+    /// Defaults to no limit when omitted.
+    #[serde(default)]
+    pub per_account_daily_limit: Option<Uint128>,
+    /// If provided, the IBC origin that the deposit marker's `ibc/` denom must trace back to.  See
+    /// [expected_ibc_trace](crate::store::contract_state::ContractStateV1#expected_ibc_trace).
+    /// Defaults to no verification when omitted.
+    #[serde(default)]
+    pub expected_ibc_trace: Option<IbcDenomTrace>,
+    /// If provided, the Provenance msg fee assessed on every accepted trade.  See
+    /// [msg_fee](crate::store::contract_state::ContractStateV1#msg_fee).  Defaults to no fee when
+    /// omitted.
+    #[serde(default)]
+    pub msg_fee: Option<MsgFee>,
+    /// If provided, the portion of the trading denom minted by each fund trade, in basis points,
+    /// that is withheld and paid to the [fee_collector](InstantiateMsg#fee_collector).  See
+    /// [fund_fee_bps](crate::store::contract_state::ContractStateV1#fund_fee_bps).  Defaults to no
+    /// fee when omitted.
+    #[serde(default)]
+    pub fund_fee_bps: Option<u16>,
+    /// If provided, the portion of the deposit denom released by each withdraw trade, in basis
+    /// points, that is withheld and paid to the [fee_collector](InstantiateMsg#fee_collector).  See
+    /// [withdraw_fee_bps](crate::store::contract_state::ContractStateV1#withdraw_fee_bps).
+    /// Defaults to no fee when omitted.
+    #[serde(default)]
+    pub withdraw_fee_bps: Option<u16>,
+    /// If provided, the bech32 address of the account that receives the fund and withdraw fees.
+    /// Required when the [fund_fee_bps](InstantiateMsg#fund_fee_bps) or the [withdraw_fee_bps](InstantiateMsg#withdraw_fee_bps)
+    /// is above zero.
+    #[serde(default)]
+    pub fee_collector: Option<String>,
+    /// If provided, the bech32 address of the scope specification from which senders of
+    /// [fund_trading](crate::execute::fund_trading::fund_trading) must own a scope.  See
+    /// [required_scope_spec](crate::store::contract_state::ContractStateV1#required_scope_spec).
+    /// Defaults to no requirement when omitted.
+    #[serde(default)]
+    pub required_scope_spec: Option<String>,
+    /// If provided, the bech32 address of a compliance contract that must approve every trade.  See
+    /// [compliance_contract](crate::store::contract_state::ContractStateV1#compliance_contract).
+    /// Defaults to no compliance check when omitted.
+    #[serde(default)]
+    pub compliance_contract: Option<String>,
+    /// If true, the instantiating account is the policy address of a group or DAO.  See
+    /// [admin_is_group](crate::store::contract_state::ContractStateV1#admin_is_group).  Defaults to
+    /// false when omitted.
+    #[serde(default)]
+    pub admin_is_group: bool,
+    /// If provided, the bech32 address of the chain governance authority that may execute admin
+    /// routes in place of the admin.  See [gov_authority](crate::store::contract_state::ContractStateV1#gov_authority).
+    /// Defaults to no authority when omitted.
+    #[serde(default)]
+    pub gov_authority: Option<String>,
+    /// If provided, the bank module metadata published for the trading marker's denom during
+    /// instantiation, allowing wallets to display balances with the marker's precision.  Its
+    /// exponent must equal the trading marker's precision.  Defaults to publishing no metadata when
+    /// omitted.
+    #[serde(default)]
+    pub publish_trading_denom_metadata: Option<DenomMetadataInput>,
 }
 impl SelfValidating for InstantiateMsg {
     fn self_validate(&self) -> Result<(), ContractError> {
@@ -76,19 +204,221 @@ impl SelfValidating for InstantiateMsg {
                 .to_err();
             }
         }
+        if self
+            .deposit_denom_aliases
+            .iter()
+            .any(|alias| alias.is_empty() || alias == &self.deposit_marker.name)
+        {
+            return ContractError::ValidationError {
+                message: "deposit denom aliases cannot be empty or match the deposit marker denom"
+                    .to_string(),
+            }
+            .to_err();
+        }
+        validate_large_trade_threshold(&self.large_trade_threshold)?;
+        validate_min_trade_amount("min_fund_amount", &self.min_fund_amount)?;
+        validate_min_trade_amount("min_withdraw_amount", &self.min_withdraw_amount)?;
+        validate_max_trade_amount("max_fund_amount", &self.max_fund_amount)?;
+        validate_max_trade_amount("max_withdraw_amount", &self.max_withdraw_amount)?;
+        validate_daily_cap(&self.daily_cap)?;
+        validate_per_account_daily_limit(&self.per_account_daily_limit)?;
+        check_trade_amount_range(
+            TradeDirection::Fund,
+            self.min_fund_amount,
+            self.max_fund_amount,
+        )?;
+        check_trade_amount_range(
+            TradeDirection::Withdraw,
+            self.min_withdraw_amount,
+            self.max_withdraw_amount,
+        )?;
+        if let Some(trace) = &self.expected_ibc_trace {
+            if !self.deposit_marker.name.starts_with(IBC_DENOM_PREFIX) {
+                return ContractError::ValidationError {
+                    message: "an expected ibc trace requires an ibc deposit marker denom"
+                        .to_string(),
+                }
+                .to_err();
+            }
+            trace
+                .self_validate()
+                .map_err(|e| ContractError::ValidationError {
+                    message: format!("expected ibc trace: {e:?}"),
+                })?;
+        }
+        validate_msg_fee(&self.msg_fee)?;
+        validate_trade_fee("fund", self.fund_fee_bps, &self.fee_collector)?;
+        validate_trade_fee("withdraw", self.withdraw_fee_bps, &self.fee_collector)?;
+        validate_required_scope_spec(&self.required_scope_spec)?;
+        validate_compliance_contract(&self.compliance_contract)?;
+        validate_gov_authority(&self.gov_authority)?;
+        if let Some(metadata) = &self.publish_trading_denom_metadata {
+            validate_denom_metadata(metadata)?;
+            metadata.validate_for_marker(&self.trading_marker)?;
+        }
         ().to_ok()
     }
 }
 
 /// All defined paylods to be used when executing routes on this contract instance.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
-#[serde(rename_all = "snake_case")]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
 pub enum ExecuteMsg {
+    /// A route that adds an account to the [denylist](crate::store::denylist), blocking it from
+    /// trading regardless of the attributes that it holds.  Only the contract admin may invoke it.
+    AdminAddDenylistedAccount {
+        /// The bech32 address of the account to block.
+        account: String,
+        /// The reason that the account is blocked, which is reported when its trades are rejected.
+        #[serde(default)]
+        reason: Option<String>,
+    },
+    /// A route that adds an account to the [fee exemption](crate::store::fee_exemptions) list, so
+    /// that its trades are assessed no fees.  Only the contract admin may invoke it.
+    AdminAddFeeExemption {
+        /// The bech32 address of the account to exempt.
+        account: String,
+    },
+    /// A route that adds an account to the [attribute whitelist](crate::store::attribute_whitelist),
+    /// so that its trades skip the required attribute checks.  Only the contract admin may invoke
+    /// it.
+    AdminAddWhitelistedAccount {
+        /// The bech32 address of the account to whitelist.
+        account: String,
+    },
+    /// A route that withdraws the trading marker balance of each of the given accounts on their
+    /// behalf, returning deposit marker denom to them.  Only the contract admin may invoke it.
+    AdminBatchForceWithdraw {
+        /// The bech32 addresses of the accounts to withdraw, in processing order.  No more than
+        /// [MAX_BATCH_FORCE_WITHDRAW_ACCOUNTS] may be supplied, and none may be repeated.
+        accounts: Vec<String>,
+        /// If provided, the largest amount of trading marker denom withdrawn from any single
+        /// account.
+        #[serde(default)]
+        max_per_account: Option<Uint128>,
+    },
+    /// A route that replaces the required attributes of the trade routes across multiple chunks,
+    /// applying every chunk at once when the operation completes.  Only the contract admin may
+    /// invoke it.
+    AdminBulkUpdateAttributes {
+        /// The attribute updates contained in this chunk.  No more than [MAX_BULK_ATTRIBUTE_UPDATES_PER_CHUNK]
+        /// may be supplied.  Supplying none alongside a [chunk_token](ExecuteMsg::AdminBulkUpdateAttributes#chunk_token)
+        /// completes the operation.
+        updates: Vec<AttributeUpdate>,
+        /// The token returned by the operation's first chunk.  Omitting the value starts a new
+        /// operation, abandoning any operation that is in progress.
+        #[serde(default)]
+        chunk_token: Option<String>,
+    },
+    /// A route that discards the [pending admin](crate::store::pending_admin) before it accepts the
+    /// admin role.  Only the contract admin may invoke it.
+    AdminCancelPendingAdmin {},
+    /// A route that permanently stops the contract from issuing trading denom, while still allowing
+    /// holders to withdraw.  The change can never be reverted.  Only the contract admin may invoke
+    /// it.
+    AdminDisableMintingPermanently {
+        /// Must equal [DISABLE_MINTING_CONFIRMATION], confirming that the change is intentional.
+        confirm: String,
+    },
+    /// A route that transfers escrowed deposit denom out of the contract to a recipient, for use
+    /// when funds must be recovered outside of the normal trading routes.  Only the contract admin
+    /// may invoke it.
+    AdminEmergencyWithdraw {
+        /// The denom to transfer.  Must be the deposit marker's denom.
+        denom: String,
+        /// The amount of the denom to transfer.  The contract must hold at least this amount.
+        amount: Uint128,
+        /// The bech32 address that will receive the funds.
+        recipient: String,
+    },
+    /// A route that halts every trade until the contract is resumed.  Only the contract admin may
+    /// invoke it.
+    AdminPause {},
+    /// A route that proposes a new contract admin, which takes effect only once the proposed
+    /// address invokes [AcceptAdminRole](ExecuteMsg::AcceptAdminRole).  Only the contract admin may
+    /// invoke it.
+    AdminProposeNewAdmin {
+        /// A bech32 address that may accept the admin role.
+        new_admin_address: String,
+    },
+    /// A route that publishes bank module metadata for the trading marker's denom, replacing any
+    /// metadata that was published before.  Only the contract admin may invoke it.
+    AdminPublishTradingDenomMetadata {
+        /// The metadata to publish.  Its exponent must equal the trading marker's precision.
+        metadata: DenomMetadataInput,
+    },
+    /// A route that allows trades again after the contract was paused.  Only the contract admin
+    /// may invoke it.
+    AdminResume {},
+    /// A route that verifies the contract's external dependencies, like its marker grants and name
+    /// binding, emitting the findings as events and recording them in the [audit log](crate::store::audit_log).
+    AdminRunDiagnostics {},
+    /// A route that re-queries the trading marker and overwrites the [marker address cache](crate::store::marker_address_cache::MarkerAddressCache)
+    /// with its current address.
+    AdminRefreshMarkerCache {},
+    /// A route that removes an account from the [denylist](crate::store::denylist).  Only the
+    /// contract admin may invoke it.
+    AdminRemoveDenylistedAccount {
+        /// The bech32 address of the denylisted account.
+        account: String,
+    },
+    /// A route that removes an account from the [fee exemption](crate::store::fee_exemptions) list.
+    /// Only the contract admin may invoke it.
+    AdminRemoveFeeExemption {
+        /// The bech32 address of the exempt account.
+        account: String,
+    },
+    /// A route that removes an account from the [attribute whitelist](crate::store::attribute_whitelist).
+    /// Only the contract admin may invoke it.
+    AdminRemoveWhitelistedAccount {
+        /// The bech32 address of the whitelisted account.
+        account: String,
+    },
+    /// A route that sets the custody account that holds the contract's reserves.
+    AdminSetCustodyAddress {
+        /// The bech32 address that will be set in the contract state's [custody_address](crate::store::contract_state::ContractStateV1#custody_address)
+        /// property upon successful execution.
+        address: String,
+    },
     /// A route that swaps the current value in the [contract state](crate::store::contract_state::ContractStateV1)
     /// for the admin to the provided value.
     AdminUpdateAdmin {
         /// A bech32 address to use as the new administrator of the contract.
         new_admin_address: String,
+        /// Whether or not the new admin is the policy address of a group or DAO.  See
+        /// [admin_is_group](crate::store::contract_state::ContractStateV1#admin_is_group).
+        /// Defaults to false when omitted.
+        #[serde(default)]
+        admin_is_group: bool,
+    },
+    /// A route that renames the contract instance, changing the name emitted in the attributes of
+    /// every response.
+    AdminUpdateContractName {
+        /// The new value that will be set in the contract state's [contract_name](crate::store::contract_state::ContractStateV1#contract_name)
+        /// property upon successful execution.
+        name: String,
+    },
+    /// A route that sets the compliance contract that must approve every trade.
+    AdminUpdateComplianceContract {
+        /// The new value that will be set in the contract state's [compliance_contract](crate::store::contract_state::ContractStateV1#compliance_contract)
+        /// property upon successful execution.  Omitting the value removes the compliance check.
+        #[serde(default)]
+        compliance_contract: Option<String>,
+    },
+    /// A route that sets new collections of attribute names required by both the [fund_trading](crate::execute::fund_trading::fund_trading)
+    /// and [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading) execution routes
+    /// at once, allowing a coordinated rotation of attributes.
+    AdminUpdateAllRequiredAttributes {
+        /// The new attributes that will be set in the contract state's [required_deposit_attributes](crate::store::contract_state::ContractStateV1#required_deposit_attributes)
+        /// property upon successful execution.
+        deposit_attributes: Vec<String>,
+        /// The new attributes that will be set in the contract state's [required_withdraw_attributes](crate::store::contract_state::ContractStateV1#required_withdraw_attributes)
+        /// property upon successful execution.
+        withdraw_attributes: Vec<String>,
+        /// Must be set to true when either attribute list is empty, confirming that all of its
+        /// required attributes should intentionally be removed.  Defaults to false when omitted.
+        #[serde(default)]
+        confirm_clear: bool,
     },
     /// A route that sets a new collection of attribute names required when an account deposits their
     /// deposit denom into the contract via the [fund_trading](crate::execute::fund_trading::fund_trading)
@@ -97,6 +427,11 @@ pub enum ExecuteMsg {
         /// The new attributes that will be set in the contract state's [required_deposit_attributes](crate::store::contract_state::ContractStateV1#required_deposit_attributes)
         /// property upon successful execution.
         attributes: Vec<String>,
+        /// Must be set to true when [attributes](ExecuteMsg::AdminUpdateDepositRequiredAttributes#attributes)
+        /// is empty, confirming that all required attributes should intentionally be removed.
+        /// Defaults to false when omitted.
+        #[serde(default)]
+        confirm_clear: bool,
     },
     /// A route that sets a new collection of attribute names required when an account withdraws
     /// their deposit denom from the contract via the [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading)
@@ -105,6 +440,174 @@ pub enum ExecuteMsg {
         /// The new attributes that will be set in the contract state's [required_withdraw_attributes](crate::store::contract_state::ContractStateV1#required_withdraw_attributes)
         /// property upon successful execution.
         attributes: Vec<String>,
+        /// Must be set to true when [attributes](ExecuteMsg::AdminUpdateWithdrawRequiredAttributes#attributes)
+        /// is empty, confirming that all required attributes should intentionally be removed.
+        /// Defaults to false when omitted.
+        #[serde(default)]
+        confirm_clear: bool,
+    },
+    /// A route that replaces the contract's [runtime tunables](crate::store::runtime_tunables::RuntimeTunables).
+    AdminSetTunables {
+        /// The new values that will be stored upon successful execution.
+        tunables: RuntimeTunables,
+    },
+    /// A route that sets whether or not the trade routes skip verifying the sender's balance before
+    /// emitting marker transfers.
+    AdminUpdateSkipBalancePrecheck {
+        /// The new value that will be set in the contract state's [skip_balance_precheck](crate::store::contract_state::ContractStateV1#skip_balance_precheck)
+        /// property upon successful execution.
+        skip_balance_precheck: bool,
+    },
+    /// A route that sets whether or not the [fund_trading](crate::execute::fund_trading::fund_trading)
+    /// route accepts trades.
+    AdminUpdateFundEnabled {
+        /// The new value that will be set in the contract state's [fund_enabled](crate::store::contract_state::ContractStateV1#fund_enabled)
+        /// property upon successful execution.
+        fund_enabled: bool,
+    },
+    /// A route that sets whether or not the [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading)
+    /// route accepts trades.
+    AdminUpdateWithdrawEnabled {
+        /// The new value that will be set in the contract state's [withdraw_enabled](crate::store::contract_state::ContractStateV1#withdraw_enabled)
+        /// property upon successful execution.
+        withdraw_enabled: bool,
+    },
+    /// A route that sets the deposit denom value at or above which trades must be approved by the
+    /// contract admin before they execute.
+    AdminUpdateLargeTradeThreshold {
+        /// The new value that will be set in the contract state's [large_trade_threshold](crate::store::contract_state::ContractStateV1#large_trade_threshold)
+        /// property upon successful execution.  Omitting the value removes the threshold.
+        #[serde(default)]
+        large_trade_threshold: Option<Uint128>,
+    },
+    /// A route that sets the largest amount of deposit denom that fund trades may collect during a
+    /// single UTC day.
+    AdminUpdateDailyCap {
+        /// The new value that will be set in the contract state's [daily_cap](crate::store::contract_state::ContractStateV1#daily_cap)
+        /// property upon successful execution.  Omitting the value removes the cap.
+        #[serde(default)]
+        daily_cap: Option<Uint128>,
+    },
+    /// A route that sets the largest value that a single account may trade during a single UTC
+    /// day.
+    AdminUpdatePerAccountDailyLimit {
+        /// The new value that will be set in the contract state's [per_account_daily_limit](crate::store::contract_state::ContractStateV1#per_account_daily_limit)
+        /// property upon successful execution.  Omitting the value removes the limit.
+        #[serde(default)]
+        per_account_daily_limit: Option<Uint128>,
+    },
+    /// A route that sets the smallest amounts that the trade routes accept in each direction.
+    AdminUpdateMinTradeAmounts {
+        /// The new value that will be set in the contract state's [min_fund_amount](crate::store::contract_state::ContractStateV1#min_fund_amount)
+        /// property upon successful execution.  Omitting the value removes the minimum.
+        #[serde(default)]
+        min_fund_amount: Option<Uint128>,
+        /// The new value that will be set in the contract state's [min_withdraw_amount](crate::store::contract_state::ContractStateV1#min_withdraw_amount)
+        /// property upon successful execution.  Omitting the value removes the minimum.
+        #[serde(default)]
+        min_withdraw_amount: Option<Uint128>,
+    },
+    /// A route that sets the largest amounts that the trade routes accept in each direction.
+    AdminUpdateMaxTradeAmounts {
+        /// The new value that will be set in the contract state's [max_fund_amount](crate::store::contract_state::ContractStateV1#max_fund_amount)
+        /// property upon successful execution.  Omitting the value removes the maximum.
+        #[serde(default)]
+        max_fund_amount: Option<Uint128>,
+        /// The new value that will be set in the contract state's [max_withdraw_amount](crate::store::contract_state::ContractStateV1#max_withdraw_amount)
+        /// property upon successful execution.  Omitting the value removes the maximum.
+        #[serde(default)]
+        max_withdraw_amount: Option<Uint128>,
+    },
+    /// A route that sets the Provenance msg fee assessed on every accepted trade.
+    AdminUpdateMsgFee {
+        /// The new value that will be set in the contract state's [msg_fee](crate::store::contract_state::ContractStateV1#msg_fee)
+        /// property upon successful execution.  Omitting the value removes the fee.
+        #[serde(default)]
+        msg_fee: Option<MsgFee>,
+    },
+    /// A route that replaces the discounts of the msg fee granted to senders holding designated
+    /// attributes.
+    AdminUpdateFeeDiscounts {
+        /// The new value that will be set in the contract state's [fee_discounts](crate::store::contract_state::ContractStateV1#fee_discounts)
+        /// property upon successful execution.  An empty list removes every discount.
+        fee_discounts: Vec<FeeDiscount>,
+    },
+    /// A route that sets the fee withheld from the trading denom minted by the [fund_trading](crate::execute::fund_trading::fund_trading)
+    /// route, and the account that receives it.
+    AdminUpdateFundFee {
+        /// The new value that will be set in the contract state's [fund_fee_bps](crate::store::contract_state::ContractStateV1#fund_fee_bps)
+        /// property upon successful execution.  Omitting the value removes the fee.
+        #[serde(default)]
+        fund_fee_bps: Option<u16>,
+        /// The new value that will be set in the contract state's [fee_collector](crate::store::contract_state::ContractStateV1#fee_collector)
+        /// property upon successful execution.  Required when the fee is above zero.
+        #[serde(default)]
+        fee_collector: Option<String>,
+    },
+    /// A route that sets the fee withheld from the deposit denom released by the [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading)
+    /// route.  The fee is paid to the [fee_collector](crate::store::contract_state::ContractStateV1#fee_collector)
+    /// shared with the fund fee.
+    AdminUpdateWithdrawFee {
+        /// The new value that will be set in the contract state's [withdraw_fee_bps](crate::store::contract_state::ContractStateV1#withdraw_fee_bps)
+        /// property upon successful execution.  Omitting the value removes the fee.
+        #[serde(default)]
+        withdraw_fee_bps: Option<u16>,
+    },
+    /// A route that sets the scope specification from which senders of the fund trading route must
+    /// own a scope.
+    AdminUpdateRequiredScopeSpec {
+        /// The new value that will be set in the contract state's [required_scope_spec](crate::store::contract_state::ContractStateV1#required_scope_spec)
+        /// property upon successful execution.  Omitting the value removes the requirement.
+        #[serde(default)]
+        required_scope_spec: Option<String>,
+    },
+    /// A route that sets the points at which the trade routes open and close.
+    AdminUpdateSchedule {
+        /// The new value that will be set in the contract state's [trading_start](crate::store::contract_state::ContractStateV1#trading_start)
+        /// property upon successful execution.  Omitting the value removes the start.
+        #[serde(default)]
+        trading_start: Option<ScheduledPoint>,
+        /// The new value that will be set in the contract state's [sunset_at](crate::store::contract_state::ContractStateV1#sunset_at)
+        /// property upon successful execution.  Omitting the value removes the sunset.
+        #[serde(default)]
+        sunset_at: Option<ScheduledPoint>,
+    },
+    /// A route that replaces the marker whose denom is minted and burned by the trade routes,
+    /// allowing the trading denom to be rotated during a token migration.
+    AdminUpdateTradingMarker {
+        /// The new value that will be set in the contract state's [trading_marker](crate::store::contract_state::ContractStateV1#trading_marker)
+        /// property upon successful execution.  The marker must already exist on-chain.
+        denom: Denom,
+        /// Whether or not to replace the marker while its trading denom has a nonzero on-chain
+        /// supply.  That denom can no longer be withdrawn through the contract once the marker is
+        /// replaced, so the request is rejected in this state unless the flag is set.
+        #[serde(default)]
+        force: bool,
+    },
+    /// A route that makes the sender the contract admin.  Only the address proposed by
+    /// [AdminProposeNewAdmin](ExecuteMsg::AdminProposeNewAdmin) may invoke it.
+    AcceptAdminRole {},
+    /// A route that executes a [pending trade](crate::store::pending_trades::PendingTrade) after
+    /// re-running every check performed by its trade route against the sender's current balances.
+    ApprovePendingTrade {
+        /// The identifier of the pending trade to approve.
+        id: u64,
+    },
+    /// A route that discards a [pending trade](crate::store::pending_trades::PendingTrade) without
+    /// executing it.
+    RejectPendingTrade {
+        /// The identifier of the pending trade to reject.
+        id: u64,
+        /// A free-form explanation of the rejection, emitted for the sender's benefit.
+        reason: String,
+    },
+    /// A route that replaces the chain governance authority that may execute admin routes in place
+    /// of the admin.  Only the current authority may invoke it.
+    GovUpdateAuthority {
+        /// The new value that will be set in the contract state's [gov_authority](crate::store::contract_state::ContractStateV1#gov_authority)
+        /// property upon successful execution.  Omitting the value removes the authority.
+        #[serde(default)]
+        gov_authority: Option<String>,
     },
     /// A route that will attempt to pull the trade amount of the deposit marker's denom from the
     /// sender's account with a marker transfer, discern how much of the trading denom to which the
@@ -112,8 +615,30 @@ pub enum ExecuteMsg {
     /// sender's account.
     FundTrading {
         /// The amount of the deposit marker to pull from the sender's account in exchange for
-        /// trading denom.
+        /// trading denom.  Accepted as either a JSON string or a JSON number.
+        #[serde(deserialize_with = "deserialize_trade_amount")]
         trade_amount: Uint128,
+        /// If true, all checks and the conversion are performed, but no messages are emitted.  The
+        /// response will only contain attributes describing the result of the trade.
+        #[serde(default)]
+        dry_run: bool,
+        /// If provided, the trade is rejected unless it matches the referenced [quote](crate::types::quote::TradeQuote)
+        /// and is executed within the quote's validity window.
+        #[serde(default)]
+        quote: Option<QuoteRef>,
+        /// If provided, the bech32 address of the account that referred the trade, which is
+        /// credited with the trade's value once it executes.
+        #[serde(default)]
+        referrer: Option<String>,
+        /// If true and the sender does not hold enough input denom to cover the trade, the trade is
+        /// reduced to the largest amount that the sender's balance can cleanly convert.  Cannot be
+        /// combined with a quote.
+        #[serde(default)]
+        allow_partial: bool,
+        /// If true, the trade executes even when part of the requested amount cannot be converted
+        /// and is left in the sender's account.  Otherwise, such a trade is rejected.
+        #[serde(default)]
+        allow_remainder: bool,
     },
     /// A route that will attempt to pull the trade amount of the trading marker's denom from the
     /// sender's account with a marker transfer, discern how much of the deposit denom to which the
@@ -121,14 +646,82 @@ pub enum ExecuteMsg {
     /// exchanged trading marker denom.
     WithdrawTrading {
         /// The amount of the trading marker to pull from the sender's account in exchange for
-        /// deposit denom.
+        /// deposit denom.  Accepted as either a JSON string or a JSON number.
+        #[serde(deserialize_with = "deserialize_trade_amount")]
         trade_amount: Uint128,
+        /// If true, all checks and the conversion are performed, but no messages are emitted.  The
+        /// response will only contain attributes describing the result of the trade.
+        #[serde(default)]
+        dry_run: bool,
+        /// If provided, the trade is rejected unless it matches the referenced [quote](crate::types::quote::TradeQuote)
+        /// and is executed within the quote's validity window.
+        #[serde(default)]
+        quote: Option<QuoteRef>,
+        /// If provided, the bech32 address of the account that referred the trade, which is
+        /// credited with the trade's value once it executes.
+        #[serde(default)]
+        referrer: Option<String>,
+        /// If true and the sender does not hold enough input denom to cover the trade, the trade is
+        /// reduced to the largest amount that the sender's balance can cleanly convert.  Cannot be
+        /// combined with a quote.
+        #[serde(default)]
+        allow_partial: bool,
+        /// If true, the trade executes even when part of the requested amount cannot be converted
+        /// and is left in the sender's account.  Otherwise, such a trade is rejected.
+        #[serde(default)]
+        allow_remainder: bool,
+    },
+    /// A route that compares the sender's balance of the trading marker's denom to a target, and
+    /// then performs the single fund or withdraw trade that moves the balance as close to the
+    /// target as possible.  Succeeds without trading if the balance is already within one
+    /// convertible unit of the target.
+    Rebalance {
+        /// The amount of the trading marker's denom that the sender wishes to hold.  Accepted as
+        /// either a JSON string or a JSON number.
+        #[serde(deserialize_with = "deserialize_trade_amount")]
+        target_trading_balance: Uint128,
     },
 }
 impl SelfValidating for ExecuteMsg {
     fn self_validate(&self) -> Result<(), ContractError> {
         match self {
-            ExecuteMsg::AdminUpdateAdmin { new_admin_address } => {
+            ExecuteMsg::AdminAddDenylistedAccount { account, reason } => {
+                if account.is_empty() {
+                    return ContractError::ValidationError {
+                        message: "account param must be supplied".to_string(),
+                    }
+                    .to_err();
+                }
+                if reason.as_ref().is_some_and(|reason| reason.is_empty()) {
+                    return ContractError::ValidationError {
+                        message: "reason must not be empty when supplied".to_string(),
+                    }
+                    .to_err();
+                }
+            }
+            ExecuteMsg::AdminAddFeeExemption { account }
+            | ExecuteMsg::AdminAddWhitelistedAccount { account }
+            | ExecuteMsg::AdminRemoveDenylistedAccount { account }
+            | ExecuteMsg::AdminRemoveFeeExemption { account }
+            | ExecuteMsg::AdminRemoveWhitelistedAccount { account } => {
+                if account.is_empty() {
+                    return ContractError::ValidationError {
+                        message: "account param must be supplied".to_string(),
+                    }
+                    .to_err();
+                }
+            }
+            ExecuteMsg::AdminSetCustodyAddress { address } => {
+                if address.is_empty() {
+                    return ContractError::ValidationError {
+                        message: "address param must be supplied".to_string(),
+                    }
+                    .to_err();
+                }
+            }
+            ExecuteMsg::AdminUpdateAdmin {
+                new_admin_address, ..
+            } => {
                 if new_admin_address.is_empty() {
                     return ContractError::ValidationError {
                         message: "new_admin_address param must be supplied".to_string(),
@@ -136,7 +729,53 @@ impl SelfValidating for ExecuteMsg {
                     .to_err();
                 }
             }
-            ExecuteMsg::AdminUpdateDepositRequiredAttributes { attributes } => {
+            ExecuteMsg::AdminUpdateContractName { name } => {
+                if name.is_empty() {
+                    return ContractError::ValidationError {
+                        message: "contract name cannot be empty".to_string(),
+                    }
+                    .to_err();
+                }
+            }
+            ExecuteMsg::AdminUpdateComplianceContract {
+                compliance_contract,
+            } => validate_compliance_contract(compliance_contract)?,
+            ExecuteMsg::AdminUpdateAllRequiredAttributes {
+                deposit_attributes,
+                withdraw_attributes,
+                confirm_clear,
+            } => {
+                if (deposit_attributes.is_empty() || withdraw_attributes.is_empty())
+                    && !confirm_clear
+                {
+                    return ContractError::ValidationError {
+                        message: "confirm_clear must be set to remove all required attributes"
+                            .to_string(),
+                    }
+                    .to_err();
+                }
+                if deposit_attributes
+                    .iter()
+                    .chain(withdraw_attributes.iter())
+                    .any(|attr| validate_attribute_name(attr).is_err())
+                {
+                    return ContractError::ValidationError {
+                        message: "all specified attributes must be valid".to_string(),
+                    }
+                    .to_err();
+                }
+            }
+            ExecuteMsg::AdminUpdateDepositRequiredAttributes {
+                attributes,
+                confirm_clear,
+            } => {
+                if attributes.is_empty() && !confirm_clear {
+                    return ContractError::ValidationError {
+                        message: "confirm_clear must be set to remove all required attributes"
+                            .to_string(),
+                    }
+                    .to_err();
+                }
                 if attributes
                     .iter()
                     .any(|attr| validate_attribute_name(attr).is_err())
@@ -147,7 +786,17 @@ impl SelfValidating for ExecuteMsg {
                     .to_err();
                 }
             }
-            ExecuteMsg::AdminUpdateWithdrawRequiredAttributes { attributes } => {
+            ExecuteMsg::AdminUpdateWithdrawRequiredAttributes {
+                attributes,
+                confirm_clear,
+            } => {
+                if attributes.is_empty() && !confirm_clear {
+                    return ContractError::ValidationError {
+                        message: "confirm_clear must be set to remove all required attributes"
+                            .to_string(),
+                    }
+                    .to_err();
+                }
                 if attributes
                     .iter()
                     .any(|attr| validate_attribute_name(attr).is_err())
@@ -158,22 +807,292 @@ impl SelfValidating for ExecuteMsg {
                     .to_err();
                 }
             }
-            ExecuteMsg::FundTrading { trade_amount } => {
+            ExecuteMsg::AdminBatchForceWithdraw {
+                accounts,
+                max_per_account,
+            } => {
+                if accounts.is_empty() || accounts.len() > MAX_BATCH_FORCE_WITHDRAW_ACCOUNTS {
+                    return ContractError::ValidationError {
+                        message: format!(
+                            "between [1] and [{MAX_BATCH_FORCE_WITHDRAW_ACCOUNTS}] accounts must be supplied"
+                        ),
+                    }
+                    .to_err();
+                }
+                if accounts.iter().any(|account| account.is_empty()) {
+                    return ContractError::ValidationError {
+                        message: "all specified accounts must be non-empty".to_string(),
+                    }
+                    .to_err();
+                }
+                if accounts
+                    .iter()
+                    .enumerate()
+                    .any(|(index, account)| accounts[..index].contains(account))
+                {
+                    return ContractError::ValidationError {
+                        message: "each account may only be specified once".to_string(),
+                    }
+                    .to_err();
+                }
+                if max_per_account.is_some_and(|max| max.is_zero()) {
+                    return ContractError::ValidationError {
+                        message: "max per account must be greater than zero when provided"
+                            .to_string(),
+                    }
+                    .to_err();
+                }
+            }
+            ExecuteMsg::AdminBulkUpdateAttributes {
+                updates,
+                chunk_token,
+            } => {
+                if updates.len() > MAX_BULK_ATTRIBUTE_UPDATES_PER_CHUNK {
+                    return ContractError::ValidationError {
+                        message: format!(
+                            "no more than [{MAX_BULK_ATTRIBUTE_UPDATES_PER_CHUNK}] attribute updates may be supplied per chunk"
+                        ),
+                    }
+                    .to_err();
+                }
+                match chunk_token {
+                    Some(chunk_token) if chunk_token.is_empty() => {
+                        return ContractError::ValidationError {
+                            message: "chunk token must be non-empty when provided".to_string(),
+                        }
+                        .to_err();
+                    }
+                    None if updates.is_empty() => {
+                        return ContractError::ValidationError {
+                            message:
+                                "the first chunk of an operation must contain attribute updates"
+                                    .to_string(),
+                        }
+                        .to_err();
+                    }
+                    _ => {}
+                }
+                for update in updates {
+                    update.self_validate()?;
+                }
+            }
+            ExecuteMsg::AdminDisableMintingPermanently { confirm } => {
+                if confirm != DISABLE_MINTING_CONFIRMATION {
+                    return ContractError::ValidationError {
+                        message: format!(
+                            "confirm must be exactly [{DISABLE_MINTING_CONFIRMATION}] to permanently disable minting"
+                        ),
+                    }
+                    .to_err();
+                }
+            }
+            ExecuteMsg::AdminEmergencyWithdraw {
+                denom,
+                amount,
+                recipient,
+            } => {
+                if denom.is_empty() {
+                    return ContractError::ValidationError {
+                        message: "denom param must be supplied".to_string(),
+                    }
+                    .to_err();
+                }
+                if amount.is_zero() {
+                    return ContractError::ValidationError {
+                        message: "amount must be greater than zero".to_string(),
+                    }
+                    .to_err();
+                }
+                if recipient.is_empty() {
+                    return ContractError::ValidationError {
+                        message: "recipient param must be supplied".to_string(),
+                    }
+                    .to_err();
+                }
+            }
+            ExecuteMsg::AdminPublishTradingDenomMetadata { metadata } => {
+                validate_denom_metadata(metadata)?
+            }
+            ExecuteMsg::AdminPause {} => {}
+            ExecuteMsg::AdminProposeNewAdmin { new_admin_address } => {
+                if new_admin_address.is_empty() {
+                    return ContractError::ValidationError {
+                        message: "new_admin_address param must be supplied".to_string(),
+                    }
+                    .to_err();
+                }
+            }
+            ExecuteMsg::AdminResume {} => {}
+            ExecuteMsg::AdminRunDiagnostics {} => {}
+            ExecuteMsg::AdminRefreshMarkerCache {} => {}
+            ExecuteMsg::AdminSetTunables { tunables } => {
+                if !(MIN_ATTRIBUTE_PAGE_SIZE..=MAX_ATTRIBUTE_PAGE_SIZE)
+                    .contains(&tunables.attribute_page_size)
+                {
+                    return ContractError::ValidationError {
+                        message: format!(
+                            "attribute page size must be between [{MIN_ATTRIBUTE_PAGE_SIZE}] and [{MAX_ATTRIBUTE_PAGE_SIZE}]"
+                        ),
+                    }
+                    .to_err();
+                }
+                if !(MIN_ATTRIBUTE_MAX_PAGES..=MAX_ATTRIBUTE_MAX_PAGES)
+                    .contains(&tunables.attribute_max_pages)
+                {
+                    return ContractError::ValidationError {
+                        message: format!(
+                            "attribute max pages must be between [{MIN_ATTRIBUTE_MAX_PAGES}] and [{MAX_ATTRIBUTE_MAX_PAGES}]"
+                        ),
+                    }
+                    .to_err();
+                }
+                if !(MIN_QUOTE_VALIDITY_BLOCKS..=MAX_QUOTE_VALIDITY_BLOCKS)
+                    .contains(&tunables.quote_validity_blocks)
+                {
+                    return ContractError::ValidationError {
+                        message: format!(
+                            "quote validity blocks must be between [{MIN_QUOTE_VALIDITY_BLOCKS}] and [{MAX_QUOTE_VALIDITY_BLOCKS}]"
+                        ),
+                    }
+                    .to_err();
+                }
+                if !(MIN_PENDING_TRADE_EXPIRATION_BLOCKS..=MAX_PENDING_TRADE_EXPIRATION_BLOCKS)
+                    .contains(&tunables.pending_trade_expiration_blocks)
+                {
+                    return ContractError::ValidationError {
+                        message: format!(
+                            "pending trade expiration blocks must be between [{MIN_PENDING_TRADE_EXPIRATION_BLOCKS}] and [{MAX_PENDING_TRADE_EXPIRATION_BLOCKS}]"
+                        ),
+                    }
+                    .to_err();
+                }
+                if tunables
+                    .verify_marker_cache_every_n_trades
+                    .is_some_and(|interval| interval < MIN_VERIFY_MARKER_CACHE_EVERY_N_TRADES)
+                {
+                    return ContractError::ValidationError {
+                        message: format!(
+                            "marker cache verification interval must be at least [{MIN_VERIFY_MARKER_CACHE_EVERY_N_TRADES}] when provided"
+                        ),
+                    }
+                    .to_err();
+                }
+            }
+            ExecuteMsg::AdminUpdateSkipBalancePrecheck { .. } => {}
+            ExecuteMsg::AdminUpdateFundEnabled { .. } => {}
+            ExecuteMsg::AdminUpdateWithdrawEnabled { .. } => {}
+            ExecuteMsg::AdminUpdateLargeTradeThreshold {
+                large_trade_threshold,
+            } => validate_large_trade_threshold(large_trade_threshold)?,
+            ExecuteMsg::AdminUpdateDailyCap { daily_cap } => validate_daily_cap(daily_cap)?,
+            ExecuteMsg::AdminUpdatePerAccountDailyLimit {
+                per_account_daily_limit,
+            } => validate_per_account_daily_limit(per_account_daily_limit)?,
+            ExecuteMsg::AdminUpdateMinTradeAmounts {
+                min_fund_amount,
+                min_withdraw_amount,
+            } => {
+                validate_min_trade_amount("min_fund_amount", min_fund_amount)?;
+                validate_min_trade_amount("min_withdraw_amount", min_withdraw_amount)?;
+            }
+            ExecuteMsg::AdminUpdateMaxTradeAmounts {
+                max_fund_amount,
+                max_withdraw_amount,
+            } => {
+                validate_max_trade_amount("max_fund_amount", max_fund_amount)?;
+                validate_max_trade_amount("max_withdraw_amount", max_withdraw_amount)?;
+            }
+            ExecuteMsg::AdminUpdateMsgFee { msg_fee } => validate_msg_fee(msg_fee)?,
+            ExecuteMsg::AdminUpdateFeeDiscounts { fee_discounts } => {
+                validate_fee_discounts(fee_discounts)?
+            }
+            ExecuteMsg::AdminUpdateFundFee {
+                fund_fee_bps,
+                fee_collector,
+            } => validate_trade_fee("fund", *fund_fee_bps, fee_collector)?,
+            ExecuteMsg::AdminUpdateWithdrawFee { withdraw_fee_bps } => {
+                validate_fee_bps("withdraw", *withdraw_fee_bps)?
+            }
+            ExecuteMsg::AdminUpdateRequiredScopeSpec {
+                required_scope_spec,
+            } => validate_required_scope_spec(required_scope_spec)?,
+            ExecuteMsg::AdminUpdateSchedule {
+                trading_start: Some(trading_start),
+                sunset_at: Some(sunset_at),
+            } => match trading_start.precedes(sunset_at) {
+                Some(true) => {}
+                Some(false) => {
+                    return ContractError::ValidationError {
+                        message: "trading_start must precede sunset_at".to_string(),
+                    }
+                    .to_err();
+                }
+                None => {
+                    return ContractError::ValidationError {
+                        message:
+                            "trading_start and sunset_at must both be heights or both be times"
+                                .to_string(),
+                    }
+                    .to_err();
+                }
+            },
+            ExecuteMsg::AdminUpdateSchedule { .. } => {}
+            ExecuteMsg::AdminUpdateTradingMarker { denom, .. } => {
+                denom
+                    .self_validate()
+                    .map_err(|e| ContractError::ValidationError {
+                        message: format!("trading marker: {e:?}"),
+                    })?;
+            }
+            ExecuteMsg::AcceptAdminRole {} => {}
+            ExecuteMsg::ApprovePendingTrade { .. } => {}
+            ExecuteMsg::RejectPendingTrade { reason, .. } => {
+                if reason.is_empty() {
+                    return ContractError::ValidationError {
+                        message: "a reason must be supplied when rejecting a pending trade"
+                            .to_string(),
+                    }
+                    .to_err();
+                }
+            }
+            ExecuteMsg::GovUpdateAuthority { gov_authority } => {
+                validate_gov_authority(gov_authority)?
+            }
+            ExecuteMsg::FundTrading {
+                trade_amount,
+                quote,
+                referrer,
+                allow_partial,
+                ..
+            } => {
                 if trade_amount.u128() == 0 {
                     return ContractError::ValidationError {
                         message: "trade amount must be greater than zero".to_string(),
                     }
                     .to_err();
                 }
+                validate_quote_ref(quote)?;
+                validate_referrer_param(referrer)?;
+                validate_partial_fill(*allow_partial, quote)?;
             }
-            ExecuteMsg::WithdrawTrading { trade_amount } => {
+            ExecuteMsg::WithdrawTrading {
+                trade_amount,
+                quote,
+                referrer,
+                allow_partial,
+                ..
+            } => {
                 if trade_amount.u128() == 0 {
                     return ContractError::ValidationError {
                         message: "trade amount must be greater than zero".to_string(),
                     }
                     .to_err();
                 }
+                validate_quote_ref(quote)?;
+                validate_referrer_param(referrer)?;
+                validate_partial_fill(*allow_partial, quote)?;
             }
+            ExecuteMsg::Rebalance { .. } => {}
         }
         ().to_ok()
     }
@@ -181,45 +1100,666 @@ impl SelfValidating for ExecuteMsg {
 
 /// All defined payloads to be used when querying routes on this contract instance.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
-#[serde(rename_all = "snake_case")]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
 pub enum QueryMsg {
     /// A route that returns the current [contract state](crate::store::contract_state::ContractStateV1)
     /// value stored in state.  Invokes the functionality defined in [query_contract_state](crate::query::query_contract_state).
     QueryContractState {},
+    /// A route that returns the current [runtime tunables](crate::store::runtime_tunables::RuntimeTunables).
+    /// Invokes the functionality defined in [query_runtime_tunables](crate::query::query_runtime_tunables).
+    QueryRuntimeTunables {},
+    /// A route that returns the contract admin's address, and whether or not it is the policy
+    /// address of a group.  Invokes the functionality defined in [query_admin](crate::query::query_admin).
+    QueryAdmin {},
+    /// A route that returns the address proposed to become the contract admin, if a proposal is
+    /// awaiting acceptance.  Invokes the functionality defined in [query_pending_admin](crate::query::query_pending_admin).
+    QueryPendingAdmin {},
+    /// A route that returns the custody address that holds the contract's reserves, if one is
+    /// configured.  Invokes the functionality defined in [query_custody_address](crate::query::query_custody_address).
+    QueryCustodyAddress {},
+    /// A route that returns the stored contract type and version, and the rules that new code must
+    /// satisfy to migrate the contract.  Invokes the functionality defined in [query_migration_info](crate::query::query_migration_info).
+    QueryMigrationInfo {},
+    /// A route that inspects both configured markers and returns the access grants that the
+    /// contract lacks, each with a ready-to-encode message that adds them.  Invokes the
+    /// functionality defined in [query_required_grants](crate::query::query_required_grants).
+    QueryRequiredGrants {},
+    /// A route that summarizes whether or not an account is able to use the trade routes, and how
+    /// much it is able to trade.  Invokes the functionality defined in [query_account_readiness](crate::query::query_account_readiness).
+    QueryAccountReadiness {
+        /// The bech32 address of the account to inspect.
+        account: String,
+    },
+    /// A route that returns whether or not an account is on the [denylist](crate::store::denylist),
+    /// along with the reason that it was listed.  Invokes the functionality defined in
+    /// [query_denylist_status](crate::query::query_denylist_status).
+    QueryDenylistStatus {
+        /// The bech32 address of the account to inspect.
+        account: String,
+    },
+    /// A route that returns the contract's current balances of the deposit and trading denoms.
+    /// Invokes the functionality defined in [query_contract_balances](crate::query::query_contract_balances).
+    QueryContractBalances {},
+    /// A route that returns the lifetime [bridge stats](crate::store::bridge_stats::BridgeStats)
+    /// of every executed trade.  Invokes the functionality defined in [query_bridge_stats](crate::query::query_bridge_stats).
+    QueryBridgeStats {},
+    /// A route that returns the deposit denom accepted by fund trades during the current UTC day and
+    /// the capacity remaining under the daily cap.  Invokes the functionality defined in [query_daily_capacity](crate::query::query_daily_capacity).
+    QueryDailyCapacity {},
+    /// A route that returns a combined view of the contract's configuration and activity in a
+    /// single response.  Invokes the functionality defined in [query_dashboard](crate::query::query_dashboard).
+    QueryDashboard {},
+    /// A route that returns the contract's configuration, trading pairs, balances and operational
+    /// status in a single response.  Invokes the functionality defined in [query_bootstrap](crate::query::query_bootstrap).
+    QueryBootstrap {},
+    /// A route that returns a [quote](crate::types::quote::TradeQuote) describing the conversion
+    /// that a trade would currently produce, which can be referenced by a trade execution message to
+    /// lock the trade to it.  Invokes the functionality defined in [query_quote](crate::query::query_quote).
+    QueryQuote {
+        /// The direction of the trade to quote.
+        direction: TradeDirection,
+        /// The amount of input denom to quote.  Accepted as either a JSON string or a JSON number.
+        #[serde(deserialize_with = "deserialize_trade_amount")]
+        amount: Uint128,
+    },
+    /// A route that returns the [pending trades](crate::store::pending_trades::PendingTrade) awaiting
+    /// admin approval, ordered from oldest to newest.  Invokes the functionality defined in
+    /// [query_pending_trades](crate::query::query_pending_trades).
+    QueryPendingTrades {
+        /// If provided, only pending trades with an identifier greater than this value are returned.
+        #[serde(default)]
+        start_after: Option<u64>,
+        /// The maximum amount of pending trades to return.  Defaults to [DEFAULT_PENDING_TRADES_LIMIT](crate::query::query_pending_trades::DEFAULT_PENDING_TRADES_LIMIT)
+        /// and is capped at [MAX_PENDING_TRADES_LIMIT](crate::query::query_pending_trades::MAX_PENDING_TRADES_LIMIT).
+        #[serde(default)]
+        limit: Option<u32>,
+    },
+    /// A route that returns the [referral stats](crate::store::referral_stats::ReferralStats) credited
+    /// to an account.  Invokes the functionality defined in [query_referral_stats](crate::query::query_referral_stats).
+    QueryReferralStats {
+        /// The bech32 address of the referrer to inspect.
+        referrer: String,
+    },
+    /// A route that returns the [trade stats](crate::store::account_stats::AccountStats) of an
+    /// account.  Invokes the functionality defined in [query_account_stats](crate::query::query_account_stats).
+    QueryAccountStats {
+        /// The bech32 address of the account to inspect.
+        account: String,
+    },
+    /// A route that returns the value that an account may still trade during the current UTC day,
+    /// and the time at which its allowance resets.  Invokes the functionality defined in
+    /// [query_account_daily_remaining](crate::query::query_account_daily_remaining).
+    QueryAccountDailyRemaining {
+        /// The bech32 address of the account to inspect.
+        account: String,
+    },
+    /// A route that returns the [referral stats](crate::store::referral_stats::ReferralStats) of
+    /// every credited referrer, ordered by address.  Invokes the functionality defined in
+    /// [query_all_referral_stats](crate::query::query_all_referral_stats).
+    QueryAllReferralStats {
+        /// If provided, only referrers with an address ordered after this value are returned.
+        #[serde(default)]
+        start_after: Option<String>,
+        /// The maximum amount of referrers to return.  Defaults to [DEFAULT_REFERRAL_STATS_LIMIT](crate::query::query_all_referral_stats::DEFAULT_REFERRAL_STATS_LIMIT)
+        /// and is capped at [MAX_REFERRAL_STATS_LIMIT](crate::query::query_all_referral_stats::MAX_REFERRAL_STATS_LIMIT).
+        #[serde(default)]
+        limit: Option<u32>,
+    },
+    /// A route that returns the accounts on the [fee exemption](crate::store::fee_exemptions) list,
+    /// ordered by address.  Invokes the functionality defined in [query_fee_exemptions](crate::query::query_fee_exemptions).
+    QueryFeeExemptions {
+        /// If provided, only accounts with an address ordered after this value are returned.
+        #[serde(default)]
+        start_after: Option<String>,
+        /// The maximum amount of accounts to return.  Defaults to [DEFAULT_FEE_EXEMPTIONS_LIMIT](crate::query::query_fee_exemptions::DEFAULT_FEE_EXEMPTIONS_LIMIT)
+        /// and is capped at [MAX_FEE_EXEMPTIONS_LIMIT](crate::query::query_fee_exemptions::MAX_FEE_EXEMPTIONS_LIMIT).
+        #[serde(default)]
+        limit: Option<u32>,
+    },
+    /// A route that returns the accounts on the [denylist](crate::store::denylist) and the reasons
+    /// they were listed, ordered by address.  Invokes the functionality defined in [query_denylist](crate::query::query_denylist).
+    QueryDenylist {
+        /// If provided, only accounts with an address ordered after this value are returned.
+        #[serde(default)]
+        start_after: Option<String>,
+        /// The maximum amount of accounts to return.  Defaults to [DEFAULT_DENYLIST_LIMIT](crate::query::query_denylist::DEFAULT_DENYLIST_LIMIT)
+        /// and is capped at [MAX_DENYLIST_LIMIT](crate::query::query_denylist::MAX_DENYLIST_LIMIT).
+        #[serde(default)]
+        limit: Option<u32>,
+    },
+    /// A route that returns the accounts on the [attribute whitelist](crate::store::attribute_whitelist),
+    /// ordered by address.  Invokes the functionality defined in [query_whitelisted_accounts](crate::query::query_whitelisted_accounts).
+    QueryWhitelistedAccounts {
+        /// If provided, only accounts with an address ordered after this value are returned.
+        #[serde(default)]
+        start_after: Option<String>,
+        /// The maximum amount of accounts to return.  Defaults to [DEFAULT_WHITELISTED_ACCOUNTS_LIMIT](crate::query::query_whitelisted_accounts::DEFAULT_WHITELISTED_ACCOUNTS_LIMIT)
+        /// and is capped at [MAX_WHITELISTED_ACCOUNTS_LIMIT](crate::query::query_whitelisted_accounts::MAX_WHITELISTED_ACCOUNTS_LIMIT).
+        #[serde(default)]
+        limit: Option<u32>,
+    },
+    /// A route that returns the record of the executed trade that was issued the given
+    /// [receipt](crate::store::trade_receipts::TradeReceipt).  Invokes the functionality defined in
+    /// [query_receipt](crate::query::query_receipt).
+    QueryReceipt {
+        /// The receipt number returned by the trade's execution.
+        receipt: u64,
+    },
+    /// A route that returns a page of the [receipts](crate::store::trade_receipts::TradeReceipt)
+    /// issued to executed trades, ordered from oldest to newest unless the newest trades are
+    /// requested first.  Invokes the
+    /// functionality defined in [query_trade_history](crate::query::query_trade_history).
+    QueryTradeHistory {
+        /// If provided, only receipts ordered after the receipt with this number are returned.
+        #[serde(default)]
+        start_after: Option<u64>,
+        /// The maximum amount of receipts to return.  Defaults to [DEFAULT_TRADE_HISTORY_LIMIT](crate::query::query_trade_history::DEFAULT_TRADE_HISTORY_LIMIT)
+        /// and is capped at [MAX_TRADE_HISTORY_LIMIT](crate::query::query_trade_history::MAX_TRADE_HISTORY_LIMIT).
+        #[serde(default)]
+        limit: Option<u32>,
+        /// If true, receipts are ordered from newest to oldest.  Defaults to false.
+        #[serde(default)]
+        descending: Option<bool>,
+    },
+    /// A route that runs the same checks and calculations as a trade execution route for the given
+    /// sender, and returns the messages and amounts that the execution would produce, or the error
+    /// that would occur.  Only [FundTrading](ExecuteMsg::FundTrading) and [WithdrawTrading](ExecuteMsg::WithdrawTrading)
+    /// messages may be previewed.  Invokes the functionality defined in [query_preview_execute](crate::query::query_preview_execute).
+    PreviewExecute {
+        /// The execution message to preview.
+        msg: ExecuteMsg,
+        /// The bech32 address of the account that would send the execution message.
+        sender: String,
+    },
+    /// A route that returns the trade amounts nearest to the given amount that convert without
+    /// leaving a remainder and fall within the trade limits, along with their distances from it.
+    /// Invokes the functionality defined in [query_suggest_amounts](crate::query::query_suggest_amounts).
+    QuerySuggestAmounts {
+        /// The direction of the trade for which amounts are suggested.
+        direction: TradeDirection,
+        /// The amount of input denom entered for the trade.  Accepted as either a JSON string or a
+        /// JSON number.
+        #[serde(deserialize_with = "deserialize_trade_amount")]
+        amount: Uint128,
+    },
+    /// A route that renders an amount of the denom on the given side of the bridge in its decimal
+    /// display form, using the stored precision.  Invokes the functionality defined in
+    /// [query_format_amount](crate::query::query_format_amount).
+    QueryFormatAmount {
+        /// The side of the bridge whose denom defines the amount.
+        denom_side: DenomSide,
+        /// The amount to format, in base units.  Accepted as either a JSON string or a JSON number.
+        #[serde(deserialize_with = "deserialize_trade_amount")]
+        amount: Uint128,
+    },
+    /// A route that converts an amount of the denom on the given side of the bridge from its
+    /// decimal display form into base units, using the stored precision.  Invokes the
+    /// functionality defined in [query_parse_amount](crate::query::query_parse_amount).
+    QueryParseAmount {
+        /// The side of the bridge whose denom defines the amount.
+        denom_side: DenomSide,
+        /// The amount to parse in decimal form, like `1234.56`.
+        display: String,
+    },
+    /// A route that converts the given amount of deposit denom to trading denom as the
+    /// [fund_trading](crate::execute::fund_trading::fund_trading) route would, without involving an
+    /// account.  Invokes the functionality defined in [query_simulate_fund_trading](crate::query::query_simulate_fund_trading).
+    SimulateFundTrading {
+        /// The amount of deposit denom to convert.  Accepted as either a JSON string or a JSON
+        /// number.
+        #[serde(deserialize_with = "deserialize_trade_amount")]
+        trade_amount: Uint128,
+    },
+    /// A route that converts the given amount of trading denom to deposit denom as the
+    /// [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading) route would, without
+    /// involving an account.  Invokes the functionality defined in [query_simulate_withdraw_trading](crate::query::query_simulate_withdraw_trading).
+    SimulateWithdrawTrading {
+        /// The amount of trading denom to convert.  Accepted as either a JSON string or a JSON
+        /// number.
+        #[serde(deserialize_with = "deserialize_trade_amount")]
+        trade_amount: Uint128,
+    },
+    /// A route that walks every check and calculation of the trade execution route for the given
+    /// direction on behalf of the given sender, and returns either the trade's expected outcome or
+    /// every failure that the execution would report.  Invokes the functionality defined in
+    /// [query_simulate_trade](crate::query::query_simulate_trade).
+    SimulateTrade {
+        /// The bech32 address of the account that would request the trade.
+        sender: String,
+        /// The direction of the trade to simulate.
+        direction: TradeDirection,
+        /// The amount of input denom to simulate.  Accepted as either a JSON string or a JSON
+        /// number.
+        #[serde(deserialize_with = "deserialize_trade_amount")]
+        amount: Uint128,
+    },
 }
 impl SelfValidating for QueryMsg {
     fn self_validate(&self) -> Result<(), ContractError> {
         match self {
             QueryMsg::QueryContractState {} => ().to_ok(),
-        }
-    }
-}
-
-/// All defined payloads to be used when migrating to a new instance of this contract.
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
-#[serde(rename_all = "snake_case")]
-pub enum MigrateMsg {
-    /// The standard migration route that modifies the [contract state](crate::store::contract_state::ContractStateV1)
-    /// to include the new values defined in a target code instance.  Invokes the functionality
-    /// defined in [migrate_contract](crate::migrate::migrate_contract::migrate_contract).
-    ContractUpgrade {},
-}
-impl SelfValidating for MigrateMsg {
-    fn self_validate(&self) -> Result<(), ContractError> {
-        match self {
-            MigrateMsg::ContractUpgrade {} => ().to_ok(),
-        }
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::types::denom::Denom;
-    use crate::types::error::ContractError;
-    use crate::types::msg::{ExecuteMsg, InstantiateMsg};
-    use crate::util::self_validating::SelfValidating;
-    use cosmwasm_std::{Uint128, Uint64};
-
+            QueryMsg::QueryRuntimeTunables {} => ().to_ok(),
+            QueryMsg::QueryAdmin {} => ().to_ok(),
+            QueryMsg::QueryPendingAdmin {} => ().to_ok(),
+            QueryMsg::QueryCustodyAddress {} => ().to_ok(),
+            QueryMsg::QueryMigrationInfo {} => ().to_ok(),
+            QueryMsg::QueryRequiredGrants {} => ().to_ok(),
+            QueryMsg::QueryContractBalances {} => ().to_ok(),
+            QueryMsg::QueryBridgeStats {} => ().to_ok(),
+            QueryMsg::QueryDailyCapacity {} => ().to_ok(),
+            QueryMsg::QueryAccountReadiness { account } => {
+                if account.is_empty() {
+                    return ContractError::ValidationError {
+                        message: "account param must be supplied".to_string(),
+                    }
+                    .to_err();
+                }
+                ().to_ok()
+            }
+            QueryMsg::QueryDashboard {} => ().to_ok(),
+            QueryMsg::QueryBootstrap {} => ().to_ok(),
+            QueryMsg::QueryQuote { amount, .. } => {
+                if amount.is_zero() {
+                    return ContractError::ValidationError {
+                        message: "quote amount must be greater than zero".to_string(),
+                    }
+                    .to_err();
+                }
+                ().to_ok()
+            }
+            QueryMsg::QueryPendingTrades { .. } => ().to_ok(),
+            QueryMsg::QueryReferralStats { referrer } => {
+                if referrer.is_empty() {
+                    return ContractError::ValidationError {
+                        message: "referrer param must be supplied".to_string(),
+                    }
+                    .to_err();
+                }
+                ().to_ok()
+            }
+            QueryMsg::QueryAccountStats { account }
+            | QueryMsg::QueryAccountDailyRemaining { account }
+            | QueryMsg::QueryDenylistStatus { account } => {
+                if account.is_empty() {
+                    return ContractError::ValidationError {
+                        message: "account param must be supplied".to_string(),
+                    }
+                    .to_err();
+                }
+                ().to_ok()
+            }
+            QueryMsg::QueryAllReferralStats { .. } => ().to_ok(),
+            QueryMsg::QueryFeeExemptions { .. } => ().to_ok(),
+            QueryMsg::QueryDenylist { .. } => ().to_ok(),
+            QueryMsg::QueryWhitelistedAccounts { .. } => ().to_ok(),
+            QueryMsg::QueryReceipt { receipt } => {
+                if *receipt == 0 {
+                    return ContractError::ValidationError {
+                        message: "receipt must be greater than zero".to_string(),
+                    }
+                    .to_err();
+                }
+                ().to_ok()
+            }
+            QueryMsg::QueryTradeHistory { .. } => ().to_ok(),
+            QueryMsg::PreviewExecute { msg, sender } => {
+                if sender.is_empty() {
+                    return ContractError::ValidationError {
+                        message: "sender param must be supplied".to_string(),
+                    }
+                    .to_err();
+                }
+                if !matches!(
+                    msg,
+                    ExecuteMsg::FundTrading { .. } | ExecuteMsg::WithdrawTrading { .. }
+                ) {
+                    return ContractError::ValidationError {
+                        message: "only trade execution messages can be previewed".to_string(),
+                    }
+                    .to_err();
+                }
+                msg.self_validate()
+            }
+            QueryMsg::QuerySuggestAmounts { .. } => ().to_ok(),
+            QueryMsg::QueryFormatAmount { .. } => ().to_ok(),
+            QueryMsg::QueryParseAmount { display, .. } => {
+                if display.is_empty() {
+                    return ContractError::ValidationError {
+                        message: "display param must be supplied".to_string(),
+                    }
+                    .to_err();
+                }
+                ().to_ok()
+            }
+            QueryMsg::SimulateFundTrading { .. } => ().to_ok(),
+            QueryMsg::SimulateWithdrawTrading { .. } => ().to_ok(),
+            QueryMsg::SimulateTrade { sender, amount, .. } => {
+                if sender.is_empty() {
+                    return ContractError::ValidationError {
+                        message: "sender param must be supplied".to_string(),
+                    }
+                    .to_err();
+                }
+                if amount.is_zero() {
+                    return ContractError::ValidationError {
+                        message: "simulated amount must be greater than zero".to_string(),
+                    }
+                    .to_err();
+                }
+                ().to_ok()
+            }
+        }
+    }
+}
+
+fn validate_large_trade_threshold(threshold: &Option<Uint128>) -> Result<(), ContractError> {
+    if threshold.is_some_and(|threshold| threshold.is_zero()) {
+        return ContractError::ValidationError {
+            message: "large trade threshold must be greater than zero when provided".to_string(),
+        }
+        .to_err();
+    }
+    ().to_ok()
+}
+
+fn validate_daily_cap(daily_cap: &Option<Uint128>) -> Result<(), ContractError> {
+    if daily_cap.is_some_and(|daily_cap| daily_cap.is_zero()) {
+        return ContractError::ValidationError {
+            message: "daily cap must be greater than zero when provided".to_string(),
+        }
+        .to_err();
+    }
+    ().to_ok()
+}
+
+fn validate_per_account_daily_limit(daily_limit: &Option<Uint128>) -> Result<(), ContractError> {
+    if daily_limit.is_some_and(|daily_limit| daily_limit.is_zero()) {
+        return ContractError::ValidationError {
+            message: "per account daily limit must be greater than zero when provided".to_string(),
+        }
+        .to_err();
+    }
+    ().to_ok()
+}
+
+fn validate_min_trade_amount(name: &str, minimum: &Option<Uint128>) -> Result<(), ContractError> {
+    if minimum.is_some_and(|minimum| minimum.is_zero()) {
+        return ContractError::ValidationError {
+            message: format!("{name} must be greater than zero when provided"),
+        }
+        .to_err();
+    }
+    ().to_ok()
+}
+
+fn validate_max_trade_amount(name: &str, maximum: &Option<Uint128>) -> Result<(), ContractError> {
+    if maximum.is_some_and(|maximum| maximum.is_zero()) {
+        return ContractError::ValidationError {
+            message: format!("{name} must be greater than zero when provided"),
+        }
+        .to_err();
+    }
+    ().to_ok()
+}
+
+fn validate_msg_fee(msg_fee: &Option<MsgFee>) -> Result<(), ContractError> {
+    if let Some(msg_fee) = msg_fee {
+        msg_fee
+            .self_validate()
+            .map_err(|e| ContractError::ValidationError {
+                message: format!("msg fee: {e:?}"),
+            })?;
+    }
+    ().to_ok()
+}
+
+fn validate_fee_discounts(fee_discounts: &[FeeDiscount]) -> Result<(), ContractError> {
+    for (index, fee_discount) in fee_discounts.iter().enumerate() {
+        fee_discount.self_validate()?;
+        if fee_discounts[..index]
+            .iter()
+            .any(|other| other.attribute == fee_discount.attribute)
+        {
+            return ContractError::ValidationError {
+                message: format!(
+                    "fee discount attribute [{}] is specified more than once",
+                    fee_discount.attribute,
+                ),
+            }
+            .to_err();
+        }
+    }
+    ().to_ok()
+}
+
+fn validate_fee_bps(fee_name: &str, fee_bps: Option<u16>) -> Result<(), ContractError> {
+    if fee_bps.is_some_and(|fee_bps| fee_bps >= FULL_FEE_BPS) {
+        return ContractError::ValidationError {
+            message: format!("{fee_name} fee must be less than {FULL_FEE_BPS} bps"),
+        }
+        .to_err();
+    }
+    ().to_ok()
+}
+
+fn validate_trade_fee(
+    fee_name: &str,
+    fee_bps: Option<u16>,
+    fee_collector: &Option<String>,
+) -> Result<(), ContractError> {
+    validate_fee_bps(fee_name, fee_bps)?;
+    if fee_collector
+        .as_ref()
+        .is_some_and(|fee_collector| fee_collector.is_empty())
+    {
+        return ContractError::ValidationError {
+            message: "fee collector cannot be empty when provided".to_string(),
+        }
+        .to_err();
+    }
+    if fee_bps.is_some_and(|fee_bps| fee_bps > 0) && fee_collector.is_none() {
+        return ContractError::ValidationError {
+            message: format!("fee collector must be provided when a {fee_name} fee is set"),
+        }
+        .to_err();
+    }
+    ().to_ok()
+}
+
+fn validate_required_scope_spec(scope_spec: &Option<String>) -> Result<(), ContractError> {
+    if scope_spec
+        .as_ref()
+        .is_some_and(|scope_spec| !scope_spec.starts_with(SCOPE_SPEC_ADDRESS_PREFIX))
+    {
+        return ContractError::ValidationError {
+            message: format!(
+                "required scope spec must be a scope specification address beginning with [{SCOPE_SPEC_ADDRESS_PREFIX}]"
+            ),
+        }
+        .to_err();
+    }
+    ().to_ok()
+}
+
+fn validate_compliance_contract(compliance_contract: &Option<String>) -> Result<(), ContractError> {
+    if compliance_contract
+        .as_ref()
+        .is_some_and(|compliance_contract| compliance_contract.is_empty())
+    {
+        return ContractError::ValidationError {
+            message: "compliance contract cannot be empty when provided".to_string(),
+        }
+        .to_err();
+    }
+    ().to_ok()
+}
+
+fn validate_gov_authority(gov_authority: &Option<String>) -> Result<(), ContractError> {
+    if gov_authority
+        .as_ref()
+        .is_some_and(|gov_authority| gov_authority.is_empty())
+    {
+        return ContractError::ValidationError {
+            message: "gov authority cannot be empty when provided".to_string(),
+        }
+        .to_err();
+    }
+    ().to_ok()
+}
+
+fn validate_denom_metadata(metadata: &DenomMetadataInput) -> Result<(), ContractError> {
+    metadata
+        .self_validate()
+        .map_err(|e| ContractError::ValidationError {
+            message: format!("denom metadata: {e:?}"),
+        })
+}
+
+fn validate_quote_ref(quote: &Option<QuoteRef>) -> Result<(), ContractError> {
+    if let Some(quote) = quote {
+        if quote.hash.is_empty() {
+            return ContractError::ValidationError {
+                message: "quote hash must be supplied when a quote is referenced".to_string(),
+            }
+            .to_err();
+        }
+    }
+    ().to_ok()
+}
+
+fn validate_partial_fill(
+    allow_partial: bool,
+    quote: &Option<QuoteRef>,
+) -> Result<(), ContractError> {
+    if allow_partial && quote.is_some() {
+        return ContractError::ValidationError {
+            message: "a partial fill cannot be allowed for a quoted trade".to_string(),
+        }
+        .to_err();
+    }
+    ().to_ok()
+}
+
+fn validate_referrer_param(referrer: &Option<String>) -> Result<(), ContractError> {
+    if referrer
+        .as_ref()
+        .is_some_and(|referrer| referrer.is_empty())
+    {
+        return ContractError::ValidationError {
+            message: "referrer must not be empty when provided".to_string(),
+        }
+        .to_err();
+    }
+    ().to_ok()
+}
+
+/// All defined payloads to be used when migrating to a new instance of this contract.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum MigrateMsg {
+    /// The standard migration route that modifies the [contract state](crate::store::contract_state::ContractStateV1)
+    /// to include the new values defined in a target code instance.  Invokes the functionality
+    /// defined in [migrate_contract](crate::migrate::migrate_contract::migrate_contract).
+    ContractUpgrade {
+        /// If provided, backfills the contract state's [instantiated_at_height](crate::store::contract_state::ContractStateV1#instantiated_at_height)
+        /// for contracts instantiated before the value was recorded.
+        #[serde(default)]
+        instantiated_at_height: Option<u64>,
+        /// If provided, backfills the contract state's [instantiated_at_time](crate::store::contract_state::ContractStateV1#instantiated_at_time)
+        /// for contracts instantiated before the value was recorded.
+        #[serde(default)]
+        instantiated_at_time: Option<Timestamp>,
+    },
+    /// Performs the standard migration, and then removes the given raw storage keys left behind
+    /// by retired features.  Only keys on the contract's allowlist of [deprecated storage keys](crate::store::deprecated_storage::DEPRECATED_STORAGE_KEYS)
+    /// may be purged.  Invokes the functionality defined in [migrate_contract_with_cleanup](crate::migrate::migrate_contract_with_cleanup::migrate_contract_with_cleanup).
+    ContractUpgradeWithCleanup {
+        /// The raw storage keys to remove.  Each key may only be specified once.
+        purge_keys: Vec<String>,
+    },
+}
+impl SelfValidating for MigrateMsg {
+    fn self_validate(&self) -> Result<(), ContractError> {
+        match self {
+            MigrateMsg::ContractUpgrade { .. } => ().to_ok(),
+            MigrateMsg::ContractUpgradeWithCleanup { purge_keys } => {
+                if purge_keys.is_empty() {
+                    return ContractError::ValidationError {
+                        message: "at least one purge key must be supplied".to_string(),
+                    }
+                    .to_err();
+                }
+                if purge_keys.iter().any(|key| key.is_empty()) {
+                    return ContractError::ValidationError {
+                        message: "all specified purge keys must be non-empty".to_string(),
+                    }
+                    .to_err();
+                }
+                if purge_keys
+                    .iter()
+                    .enumerate()
+                    .any(|(index, key)| purge_keys[..index].contains(key))
+                {
+                    return ContractError::ValidationError {
+                        message: "each purge key may only be specified once".to_string(),
+                    }
+                    .to_err();
+                }
+                ().to_ok()
+            }
+        }
+    }
+}
+
+/// The query that the trade routes send to a configured [compliance contract](crate::store::contract_state::ContractStateV1#compliance_contract)
+/// before accepting a trade.  A compliance contract must accept this message on its query entry
+/// point and respond with a [ComplianceResponse].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ComplianceQuery {
+    /// Asks whether or not a trade may proceed.  Serialized as `{"check_trade":{...}}`.
+    CheckTrade {
+        /// The bech32 address of the account requesting the trade.
+        account: String,
+        /// The direction of the trade.
+        direction: TradeDirection,
+        /// The amount of [denom](ComplianceQuery::CheckTrade#denom) that the account would send to
+        /// this contract, excluding any remainder that cannot be converted.
+        amount: Uint128,
+        /// The denom that the account would send to this contract: the deposit marker denom when
+        /// funding, and the trading marker denom when withdrawing.
+        denom: String,
+    },
+}
+
+/// The response that a compliance contract must return for a [ComplianceQuery].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct ComplianceResponse {
+    /// Whether or not the trade may proceed.
+    pub approved: bool,
+    /// When the trade is not approved, an explanation that is relayed to the sender in the trade's
+    /// error.
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::runtime_tunables::{EventVerbosity, RuntimeTunables};
+    use crate::types::attribute_update::AttributeUpdate;
+    use crate::types::denom::{Denom, DenomMetadataInput, DenomSide, IbcDenomTrace};
+    use crate::types::error::ContractError;
+    use crate::types::msg::{
+        ComplianceQuery, ComplianceResponse, ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg,
+        DISABLE_MINTING_CONFIRMATION, MAX_BATCH_FORCE_WITHDRAW_ACCOUNTS,
+        MAX_BULK_ATTRIBUTE_UPDATES_PER_CHUNK,
+    };
+    use crate::types::msg_fee::{FeeDiscount, MsgFee};
+    use crate::types::quote::{QuoteRef, TradeDirection};
+    use crate::types::schedule::ScheduledPoint;
+    use crate::util::self_validating::SelfValidating;
+    use cosmwasm_std::{coin, from_json, to_json_string, Timestamp, Uint128, Uint64};
+
     #[test]
     fn instantiate_msg_self_validation_should_function_properly() {
         assert_validation_err(
@@ -282,106 +1822,1894 @@ mod tests {
             .expect_err("expected invalid name to bind to fail"),
             "contract name cannot be specified as empty string",
         );
-        InstantiateMsg::default()
-            .self_validate()
-            .expect("proper instantiate message values should pass validation");
-    }
-
-    #[test]
-    fn admin_update_admin_execute_message_validation_should_function_properly() {
         assert_validation_err(
-            &ExecuteMsg::AdminUpdateAdmin {
-                new_admin_address: "".to_string(),
+            &InstantiateMsg {
+                large_trade_threshold: Some(Uint128::zero()),
+                ..InstantiateMsg::default()
             }
             .self_validate()
-            .expect_err("expected invalid new_admin_address to fail"),
-            "new_admin_address param must be supplied",
+            .expect_err("expected a zero large trade threshold to fail"),
+            "large trade threshold must be greater than zero when provided",
         );
-        ExecuteMsg::AdminUpdateAdmin {
-            new_admin_address: "some-addr".to_string(),
-        }
-        .self_validate()
-        .expect("non-empty input for new admin address should succeed");
-    }
-
-    #[test]
-    fn admin_update_deposit_required_attributes_execute_message_validation_should_function_properly(
-    ) {
         assert_validation_err(
-            &ExecuteMsg::AdminUpdateDepositRequiredAttributes {
-                attributes: vec![
-                    "verylongstringintheattributeshouldberejected.thiswouldbeokthough".to_string(),
-                ],
+            &InstantiateMsg {
+                min_fund_amount: Some(Uint128::zero()),
+                ..InstantiateMsg::default()
             }
             .self_validate()
-            .expect_err("expected invalid attributes to fail"),
-            "all specified attributes must be valid",
+            .expect_err("expected a zero minimum fund amount to fail"),
+            "min_fund_amount must be greater than zero when provided",
         );
-        ExecuteMsg::AdminUpdateDepositRequiredAttributes { attributes: vec![] }
-            .self_validate()
-            .expect("empty attributes should succeed");
-        ExecuteMsg::AdminUpdateDepositRequiredAttributes {
-            attributes: vec!["some-attribute".to_string()],
-        }
-        .self_validate()
-        .expect("specified attributes should succeed");
-    }
-
-    #[test]
-    fn admin_update_withdraw_required_attributes_execute_message_validation_should_function_properly(
-    ) {
         assert_validation_err(
-            &ExecuteMsg::AdminUpdateWithdrawRequiredAttributes {
-                attributes: vec!["not a.validattribute".to_string()],
+            &InstantiateMsg {
+                min_withdraw_amount: Some(Uint128::zero()),
+                ..InstantiateMsg::default()
             }
             .self_validate()
-            .expect_err("expected invalid attributes to fail"),
-            "all specified attributes must be valid",
+            .expect_err("expected a zero minimum withdraw amount to fail"),
+            "min_withdraw_amount must be greater than zero when provided",
         );
-        ExecuteMsg::AdminUpdateWithdrawRequiredAttributes { attributes: vec![] }
+        assert_validation_err(
+            &InstantiateMsg {
+                max_fund_amount: Some(Uint128::zero()),
+                ..InstantiateMsg::default()
+            }
             .self_validate()
-            .expect("empty attributes should succeed");
-        ExecuteMsg::AdminUpdateWithdrawRequiredAttributes {
-            attributes: vec!["some-attribute".to_string()],
-        }
-        .self_validate()
-        .expect("specified attributes should succeed");
-    }
-
-    #[test]
-    fn funding_trading_execute_message_validation_should_function_properly() {
+            .expect_err("expected a zero maximum fund amount to fail"),
+            "max_fund_amount must be greater than zero when provided",
+        );
         assert_validation_err(
-            &ExecuteMsg::FundTrading {
-                trade_amount: Uint128::new(0),
+            &InstantiateMsg {
+                max_withdraw_amount: Some(Uint128::zero()),
+                ..InstantiateMsg::default()
             }
             .self_validate()
-            .expect_err("expected invalid trade amount to fail"),
-            "trade amount must be greater than zero",
+            .expect_err("expected a zero maximum withdraw amount to fail"),
+            "max_withdraw_amount must be greater than zero when provided",
         );
-        ExecuteMsg::FundTrading {
-            trade_amount: Uint128::new(1),
-        }
-        .self_validate()
-        .expect("a valid funding trading msg should pass validation");
-    }
-
-    #[test]
-    fn withdraw_trading_execute_message_validation_should_function_properly() {
         assert_validation_err(
-            &ExecuteMsg::WithdrawTrading {
-                trade_amount: Uint128::new(0),
+            &InstantiateMsg {
+                daily_cap: Some(Uint128::zero()),
+                ..InstantiateMsg::default()
             }
             .self_validate()
-            .expect_err("expected invalid trade amount to fail"),
-            "trade amount must be greater than zero",
+            .expect_err("expected a zero daily cap to fail"),
+            "daily cap must be greater than zero when provided",
         );
-        ExecuteMsg::WithdrawTrading {
-            trade_amount: Uint128::new(1),
+        assert_validation_err(
+            &InstantiateMsg {
+                per_account_daily_limit: Some(Uint128::zero()),
+                ..InstantiateMsg::default()
+            }
+            .self_validate()
+            .expect_err("expected a zero per account daily limit to fail"),
+            "per account daily limit must be greater than zero when provided",
+        );
+        assert_validation_err(
+            &InstantiateMsg {
+                min_fund_amount: Some(Uint128::new(101)),
+                max_fund_amount: Some(Uint128::new(100)),
+                ..InstantiateMsg::default()
+            }
+            .self_validate()
+            .expect_err("expected a fund maximum below the fund minimum to fail"),
+            "min_fund_amount [101] cannot exceed max_fund_amount [100]",
+        );
+        assert_validation_err(
+            &InstantiateMsg {
+                min_withdraw_amount: Some(Uint128::new(101)),
+                max_withdraw_amount: Some(Uint128::new(100)),
+                ..InstantiateMsg::default()
+            }
+            .self_validate()
+            .expect_err("expected a withdraw maximum below the withdraw minimum to fail"),
+            "min_withdraw_amount [101] cannot exceed max_withdraw_amount [100]",
+        );
+        assert_validation_err(
+            &InstantiateMsg {
+                deposit_denom_aliases: vec!["".to_string()],
+                ..InstantiateMsg::default()
+            }
+            .self_validate()
+            .expect_err("expected an empty deposit denom alias to fail"),
+            "deposit denom aliases cannot be empty or match the deposit marker denom",
+        );
+        assert_validation_err(
+            &InstantiateMsg {
+                deposit_denom_aliases: vec![InstantiateMsg::default().deposit_marker.name],
+                ..InstantiateMsg::default()
+            }
+            .self_validate()
+            .expect_err("expected a deposit denom alias matching the deposit marker to fail"),
+            "deposit denom aliases cannot be empty or match the deposit marker denom",
+        );
+        InstantiateMsg::default()
+            .self_validate()
+            .expect("proper instantiate message values should pass validation");
+        assert_validation_err(
+            &InstantiateMsg {
+                compliance_contract: Some("".to_string()),
+                ..InstantiateMsg::default()
+            }
+            .self_validate()
+            .expect_err("expected an empty compliance contract to fail"),
+            "compliance contract cannot be empty when provided",
+        );
+        assert_validation_err(
+            &InstantiateMsg {
+                gov_authority: Some("".to_string()),
+                ..InstantiateMsg::default()
+            }
+            .self_validate()
+            .expect_err("expected an empty gov authority to fail"),
+            "gov authority cannot be empty when provided",
+        );
+        assert_validation_err(
+            &InstantiateMsg {
+                expected_ibc_trace: Some(IbcDenomTrace {
+                    base_denom: "uusdc".to_string(),
+                    path: "transfer/channel-0".to_string(),
+                }),
+                ..InstantiateMsg::default()
+            }
+            .self_validate()
+            .expect_err("expected an ibc trace on a non-ibc deposit marker to fail"),
+            "an expected ibc trace requires an ibc deposit marker denom",
+        );
+        assert_validation_err(
+            &InstantiateMsg {
+                msg_fee: Some(MsgFee {
+                    amount: coin(0, "nhash"),
+                    recipient: "recipient".to_string(),
+                }),
+                ..InstantiateMsg::default()
+            }
+            .self_validate()
+            .expect_err("expected a zero msg fee to fail"),
+            "msg fee: fee amount must be greater than zero",
+        );
+        assert_validation_err(
+            &InstantiateMsg {
+                fund_fee_bps: Some(25),
+                ..InstantiateMsg::default()
+            }
+            .self_validate()
+            .expect_err("expected a fund fee without a collector to fail"),
+            "fee collector must be provided when a fund fee is set",
+        );
+        assert_validation_err(
+            &InstantiateMsg {
+                withdraw_fee_bps: Some(30),
+                ..InstantiateMsg::default()
+            }
+            .self_validate()
+            .expect_err("expected a withdraw fee without a collector to fail"),
+            "fee collector must be provided when a withdraw fee is set",
+        );
+        assert_validation_err(
+            &InstantiateMsg {
+                withdraw_fee_bps: Some(10000),
+                fee_collector: Some("collector".to_string()),
+                ..InstantiateMsg::default()
+            }
+            .self_validate()
+            .expect_err("expected a withdraw fee of the entire amount to fail"),
+            "withdraw fee must be less than 10000 bps",
+        );
+    }
+
+    #[test]
+    fn admin_disable_minting_permanently_execute_message_validation_should_function_properly() {
+        for confirm in ["", "yes", "Permanently Disable Minting"] {
+            assert_validation_err(
+                &ExecuteMsg::AdminDisableMintingPermanently {
+                    confirm: confirm.to_string(),
+                }
+                .self_validate()
+                .expect_err("expected an incorrect confirmation to fail"),
+                format!(
+                    "confirm must be exactly [{DISABLE_MINTING_CONFIRMATION}] to permanently disable minting"
+                ),
+            );
+        }
+        ExecuteMsg::AdminDisableMintingPermanently {
+            confirm: DISABLE_MINTING_CONFIRMATION.to_string(),
+        }
+        .self_validate()
+        .expect("the exact confirmation should succeed");
+    }
+
+    #[test]
+    fn admin_emergency_withdraw_execute_message_validation_should_function_properly() {
+        assert_validation_err(
+            &ExecuteMsg::AdminEmergencyWithdraw {
+                denom: String::new(),
+                amount: Uint128::new(100),
+                recipient: "recipient".to_string(),
+            }
+            .self_validate()
+            .expect_err("expected an empty denom to fail"),
+            "denom param must be supplied",
+        );
+        assert_validation_err(
+            &ExecuteMsg::AdminEmergencyWithdraw {
+                denom: "deposit".to_string(),
+                amount: Uint128::zero(),
+                recipient: "recipient".to_string(),
+            }
+            .self_validate()
+            .expect_err("expected a zero amount to fail"),
+            "amount must be greater than zero",
+        );
+        assert_validation_err(
+            &ExecuteMsg::AdminEmergencyWithdraw {
+                denom: "deposit".to_string(),
+                amount: Uint128::new(100),
+                recipient: String::new(),
+            }
+            .self_validate()
+            .expect_err("expected an empty recipient to fail"),
+            "recipient param must be supplied",
+        );
+        ExecuteMsg::AdminEmergencyWithdraw {
+            denom: "deposit".to_string(),
+            amount: Uint128::new(100),
+            recipient: "recipient".to_string(),
+        }
+        .self_validate()
+        .expect("a fully populated message should succeed");
+    }
+
+    #[test]
+    fn trading_denom_metadata_validation_should_function_properly() {
+        let metadata = DenomMetadataInput {
+            name: "Trading".to_string(),
+            display: "ttrading".to_string(),
+            symbol: "TRD".to_string(),
+            description: String::new(),
+            exponent: 6,
+        };
+        InstantiateMsg {
+            publish_trading_denom_metadata: Some(metadata.to_owned()),
+            ..InstantiateMsg::default()
+        }
+        .self_validate()
+        .expect("metadata matching the trading marker precision should pass validation");
+        assert_validation_err(
+            &InstantiateMsg {
+                publish_trading_denom_metadata: Some(DenomMetadataInput {
+                    exponent: 2,
+                    ..metadata.to_owned()
+                }),
+                ..InstantiateMsg::default()
+            }
+            .self_validate()
+            .expect_err("expected a mismatched exponent to fail"),
+            "denom metadata exponent [2] must equal the trading marker precision [6]",
+        );
+        assert_validation_err(
+            &InstantiateMsg {
+                publish_trading_denom_metadata: Some(DenomMetadataInput {
+                    display: "trading".to_string(),
+                    ..metadata.to_owned()
+                }),
+                ..InstantiateMsg::default()
+            }
+            .self_validate()
+            .expect_err("expected a display unit matching the base denom to fail"),
+            "denom metadata display [trading] must match the trading marker denom [trading] exactly when the exponent is zero",
+        );
+        assert_validation_err(
+            &ExecuteMsg::AdminPublishTradingDenomMetadata {
+                metadata: DenomMetadataInput {
+                    symbol: String::new(),
+                    ..metadata.to_owned()
+                },
+            }
+            .self_validate()
+            .expect_err("expected an empty symbol to fail"),
+            "denom metadata: ValidationError { message: \"symbol cannot be empty\" }",
+        );
+        ExecuteMsg::AdminPublishTradingDenomMetadata { metadata }
+            .self_validate()
+            .expect("complete metadata should pass validation");
+    }
+
+    #[test]
+    fn admin_propose_new_admin_execute_message_validation_should_function_properly() {
+        assert_validation_err(
+            &ExecuteMsg::AdminProposeNewAdmin {
+                new_admin_address: "".to_string(),
+            }
+            .self_validate()
+            .expect_err("expected an empty new_admin_address to fail"),
+            "new_admin_address param must be supplied",
+        );
+        ExecuteMsg::AdminProposeNewAdmin {
+            new_admin_address: "some-addr".to_string(),
+        }
+        .self_validate()
+        .expect("a non-empty new_admin_address should succeed");
+    }
+
+    #[test]
+    fn fee_exemption_execute_message_validation_should_function_properly() {
+        for msg in [
+            ExecuteMsg::AdminAddFeeExemption {
+                account: "".to_string(),
+            },
+            ExecuteMsg::AdminRemoveFeeExemption {
+                account: "".to_string(),
+            },
+        ] {
+            assert_validation_err(
+                &msg.self_validate()
+                    .expect_err("expected an empty account to fail"),
+                "account param must be supplied",
+            );
+        }
+        ExecuteMsg::AdminAddFeeExemption {
+            account: "some-addr".to_string(),
+        }
+        .self_validate()
+        .expect("a non-empty account should succeed");
+        ExecuteMsg::AdminRemoveFeeExemption {
+            account: "some-addr".to_string(),
+        }
+        .self_validate()
+        .expect("a non-empty account should succeed");
+    }
+
+    #[test]
+    fn denylisted_account_execute_message_validation_should_function_properly() {
+        for msg in [
+            ExecuteMsg::AdminAddDenylistedAccount {
+                account: "".to_string(),
+                reason: None,
+            },
+            ExecuteMsg::AdminRemoveDenylistedAccount {
+                account: "".to_string(),
+            },
+        ] {
+            assert_validation_err(
+                &msg.self_validate()
+                    .expect_err("expected an empty account to fail"),
+                "account param must be supplied",
+            );
+        }
+        assert_validation_err(
+            &ExecuteMsg::AdminAddDenylistedAccount {
+                account: "some-addr".to_string(),
+                reason: Some("".to_string()),
+            }
+            .self_validate()
+            .expect_err("expected an empty reason to fail"),
+            "reason must not be empty when supplied",
+        );
+        ExecuteMsg::AdminAddDenylistedAccount {
+            account: "some-addr".to_string(),
+            reason: Some("flagged by compliance".to_string()),
+        }
+        .self_validate()
+        .expect("a non-empty account and reason should succeed");
+        ExecuteMsg::AdminRemoveDenylistedAccount {
+            account: "some-addr".to_string(),
+        }
+        .self_validate()
+        .expect("a non-empty account should succeed");
+    }
+
+    #[test]
+    fn whitelisted_account_execute_message_validation_should_function_properly() {
+        for msg in [
+            ExecuteMsg::AdminAddWhitelistedAccount {
+                account: "".to_string(),
+            },
+            ExecuteMsg::AdminRemoveWhitelistedAccount {
+                account: "".to_string(),
+            },
+        ] {
+            assert_validation_err(
+                &msg.self_validate()
+                    .expect_err("expected an empty account to fail"),
+                "account param must be supplied",
+            );
+        }
+        ExecuteMsg::AdminAddWhitelistedAccount {
+            account: "some-addr".to_string(),
+        }
+        .self_validate()
+        .expect("a non-empty account should succeed");
+        ExecuteMsg::AdminRemoveWhitelistedAccount {
+            account: "some-addr".to_string(),
+        }
+        .self_validate()
+        .expect("a non-empty account should succeed");
+    }
+
+    #[test]
+    fn admin_set_custody_address_execute_message_validation_should_function_properly() {
+        assert_validation_err(
+            &ExecuteMsg::AdminSetCustodyAddress {
+                address: "".to_string(),
+            }
+            .self_validate()
+            .expect_err("expected an empty address to fail"),
+            "address param must be supplied",
+        );
+        ExecuteMsg::AdminSetCustodyAddress {
+            address: "some-addr".to_string(),
+        }
+        .self_validate()
+        .expect("a non-empty address should succeed");
+    }
+
+    #[test]
+    fn admin_update_admin_execute_message_validation_should_function_properly() {
+        assert_validation_err(
+            &ExecuteMsg::AdminUpdateAdmin {
+                new_admin_address: "".to_string(),
+                admin_is_group: false,
+            }
+            .self_validate()
+            .expect_err("expected invalid new_admin_address to fail"),
+            "new_admin_address param must be supplied",
+        );
+        ExecuteMsg::AdminUpdateAdmin {
+            new_admin_address: "some-addr".to_string(),
+            admin_is_group: false,
+        }
+        .self_validate()
+        .expect("non-empty input for new admin address should succeed");
+        ExecuteMsg::AdminUpdateAdmin {
+            new_admin_address: "some-group-policy".to_string(),
+            admin_is_group: true,
+        }
+        .self_validate()
+        .expect("a group admin address should succeed");
+    }
+
+    #[test]
+    fn admin_update_contract_name_execute_message_validation_should_function_properly() {
+        assert_validation_err(
+            &ExecuteMsg::AdminUpdateContractName {
+                name: "".to_string(),
+            }
+            .self_validate()
+            .expect_err("expected an empty contract name to fail"),
+            "contract name cannot be empty",
+        );
+        ExecuteMsg::AdminUpdateContractName {
+            name: "new-name".to_string(),
+        }
+        .self_validate()
+        .expect("a non-empty contract name should pass validation");
+    }
+
+    #[test]
+    fn admin_update_all_required_attributes_execute_message_validation_should_function_properly() {
+        let msg = |deposit_attributes: &[&str], withdraw_attributes: &[&str], confirm_clear| {
+            ExecuteMsg::AdminUpdateAllRequiredAttributes {
+                deposit_attributes: deposit_attributes.iter().map(|a| a.to_string()).collect(),
+                withdraw_attributes: withdraw_attributes.iter().map(|a| a.to_string()).collect(),
+                confirm_clear,
+            }
+        };
+        assert_validation_err(
+            &msg(&["deposit.pb"], &["not a.validattribute"], false)
+                .self_validate()
+                .expect_err("expected invalid withdraw attributes to fail"),
+            "all specified attributes must be valid",
+        );
+        assert_validation_err(
+            &msg(&[""], &["withdraw.pb"], false)
+                .self_validate()
+                .expect_err("expected invalid deposit attributes to fail"),
+            "all specified attributes must be valid",
+        );
+        assert_validation_err(
+            &msg(&["deposit.pb"], &[], false)
+                .self_validate()
+                .expect_err("expected an empty list without a confirmed clear to fail"),
+            "confirm_clear must be set to remove all required attributes",
+        );
+        msg(&[], &["withdraw.pb"], true)
+            .self_validate()
+            .expect("an empty list with a confirmed clear should succeed");
+        msg(&["deposit.pb"], &["withdraw.pb"], false)
+            .self_validate()
+            .expect("specified attributes should succeed");
+    }
+
+    #[test]
+    fn admin_update_deposit_required_attributes_execute_message_validation_should_function_properly(
+    ) {
+        assert_validation_err(
+            &ExecuteMsg::AdminUpdateDepositRequiredAttributes {
+                attributes: vec![
+                    "verylongstringintheattributeshouldberejected.thiswouldbeokthough".to_string(),
+                ],
+                confirm_clear: false,
+            }
+            .self_validate()
+            .expect_err("expected invalid attributes to fail"),
+            "all specified attributes must be valid",
+        );
+        assert_validation_err(
+            &ExecuteMsg::AdminUpdateDepositRequiredAttributes {
+                attributes: vec![],
+                confirm_clear: false,
+            }
+            .self_validate()
+            .expect_err("expected empty attributes without a confirmed clear to fail"),
+            "confirm_clear must be set to remove all required attributes",
+        );
+        ExecuteMsg::AdminUpdateDepositRequiredAttributes {
+            attributes: vec![],
+            confirm_clear: true,
+        }
+        .self_validate()
+        .expect("empty attributes with a confirmed clear should succeed");
+        ExecuteMsg::AdminUpdateDepositRequiredAttributes {
+            attributes: vec!["some-attribute".to_string()],
+            confirm_clear: false,
+        }
+        .self_validate()
+        .expect("specified attributes should succeed");
+    }
+
+    #[test]
+    fn admin_update_withdraw_required_attributes_execute_message_validation_should_function_properly(
+    ) {
+        assert_validation_err(
+            &ExecuteMsg::AdminUpdateWithdrawRequiredAttributes {
+                attributes: vec!["not a.validattribute".to_string()],
+                confirm_clear: false,
+            }
+            .self_validate()
+            .expect_err("expected invalid attributes to fail"),
+            "all specified attributes must be valid",
+        );
+        assert_validation_err(
+            &ExecuteMsg::AdminUpdateWithdrawRequiredAttributes {
+                attributes: vec![],
+                confirm_clear: false,
+            }
+            .self_validate()
+            .expect_err("expected empty attributes without a confirmed clear to fail"),
+            "confirm_clear must be set to remove all required attributes",
+        );
+        ExecuteMsg::AdminUpdateWithdrawRequiredAttributes {
+            attributes: vec![],
+            confirm_clear: true,
+        }
+        .self_validate()
+        .expect("empty attributes with a confirmed clear should succeed");
+        ExecuteMsg::AdminUpdateWithdrawRequiredAttributes {
+            attributes: vec!["some-attribute".to_string()],
+            confirm_clear: false,
+        }
+        .self_validate()
+        .expect("specified attributes should succeed");
+    }
+
+    #[test]
+    fn admin_set_tunables_execute_message_validation_should_function_properly() {
+        for (page_size, max_pages, validity_blocks, expiration_blocks, expected_error) in [
+            (
+                0,
+                10,
+                100,
+                14400,
+                "attribute page size must be between [1] and [100]",
+            ),
+            (
+                101,
+                10,
+                100,
+                14400,
+                "attribute page size must be between [1] and [100]",
+            ),
+            (
+                25,
+                0,
+                100,
+                14400,
+                "attribute max pages must be between [1] and [50]",
+            ),
+            (
+                25,
+                51,
+                100,
+                14400,
+                "attribute max pages must be between [1] and [50]",
+            ),
+            (
+                25,
+                10,
+                0,
+                14400,
+                "quote validity blocks must be between [1] and [10000]",
+            ),
+            (
+                25,
+                10,
+                10001,
+                14400,
+                "quote validity blocks must be between [1] and [10000]",
+            ),
+            (
+                25,
+                10,
+                100,
+                0,
+                "pending trade expiration blocks must be between [1] and [100800]",
+            ),
+            (
+                25,
+                10,
+                100,
+                100801,
+                "pending trade expiration blocks must be between [1] and [100800]",
+            ),
+        ] {
+            assert_validation_err(
+                &ExecuteMsg::AdminSetTunables {
+                    tunables: RuntimeTunables {
+                        attribute_page_size: page_size,
+                        attribute_max_pages: max_pages,
+                        quote_validity_blocks: validity_blocks,
+                        pending_trade_expiration_blocks: expiration_blocks,
+                        verify_marker_cache_every_n_trades: None,
+                        event_verbosity: EventVerbosity::Standard,
+                        verify_marker_access_on_trade: false,
+                    },
+                }
+                .self_validate()
+                .expect_err("expected out of range tunables to fail"),
+                expected_error,
+            );
+        }
+        assert_validation_err(
+            &ExecuteMsg::AdminSetTunables {
+                tunables: RuntimeTunables {
+                    verify_marker_cache_every_n_trades: Some(0),
+                    ..RuntimeTunables::default()
+                },
+            }
+            .self_validate()
+            .expect_err("expected a zero marker cache verification interval to fail"),
+            "marker cache verification interval must be at least [1] when provided",
+        );
+        for (page_size, max_pages, validity_blocks, expiration_blocks) in
+            [(1, 1, 1, 1), (100, 50, 10000, 100800)]
+        {
+            ExecuteMsg::AdminSetTunables {
+                tunables: RuntimeTunables {
+                    attribute_page_size: page_size,
+                    attribute_max_pages: max_pages,
+                    quote_validity_blocks: validity_blocks,
+                    pending_trade_expiration_blocks: expiration_blocks,
+                    verify_marker_cache_every_n_trades: Some(1),
+                    event_verbosity: EventVerbosity::Verbose,
+                    verify_marker_access_on_trade: true,
+                },
+            }
+            .self_validate()
+            .expect("tunables at the edges of their ranges should pass validation");
+        }
+    }
+
+    #[test]
+    fn admin_update_large_trade_threshold_execute_message_validation_should_function_properly() {
+        assert_validation_err(
+            &ExecuteMsg::AdminUpdateLargeTradeThreshold {
+                large_trade_threshold: Some(Uint128::zero()),
+            }
+            .self_validate()
+            .expect_err("expected a zero threshold to fail"),
+            "large trade threshold must be greater than zero when provided",
+        );
+        for large_trade_threshold in [None, Some(Uint128::new(1))] {
+            ExecuteMsg::AdminUpdateLargeTradeThreshold {
+                large_trade_threshold,
+            }
+            .self_validate()
+            .expect("an absent or positive threshold should pass validation");
+        }
+    }
+
+    #[test]
+    fn admin_update_daily_cap_execute_message_validation_should_function_properly() {
+        assert_validation_err(
+            &ExecuteMsg::AdminUpdateDailyCap {
+                daily_cap: Some(Uint128::zero()),
+            }
+            .self_validate()
+            .expect_err("expected a zero daily cap to fail"),
+            "daily cap must be greater than zero when provided",
+        );
+        for daily_cap in [None, Some(Uint128::new(1))] {
+            ExecuteMsg::AdminUpdateDailyCap { daily_cap }
+                .self_validate()
+                .expect("an absent or positive daily cap should pass validation");
+        }
+    }
+
+    #[test]
+    fn admin_update_per_account_daily_limit_execute_message_validation_should_function_properly() {
+        assert_validation_err(
+            &ExecuteMsg::AdminUpdatePerAccountDailyLimit {
+                per_account_daily_limit: Some(Uint128::zero()),
+            }
+            .self_validate()
+            .expect_err("expected a zero daily limit to fail"),
+            "per account daily limit must be greater than zero when provided",
+        );
+        for per_account_daily_limit in [None, Some(Uint128::new(1))] {
+            ExecuteMsg::AdminUpdatePerAccountDailyLimit {
+                per_account_daily_limit,
+            }
+            .self_validate()
+            .expect("an absent or positive daily limit should pass validation");
+        }
+    }
+
+    #[test]
+    fn admin_update_min_trade_amounts_execute_message_validation_should_function_properly() {
+        assert_validation_err(
+            &ExecuteMsg::AdminUpdateMinTradeAmounts {
+                min_fund_amount: Some(Uint128::zero()),
+                min_withdraw_amount: None,
+            }
+            .self_validate()
+            .expect_err("expected a zero minimum fund amount to fail"),
+            "min_fund_amount must be greater than zero when provided",
+        );
+        assert_validation_err(
+            &ExecuteMsg::AdminUpdateMinTradeAmounts {
+                min_fund_amount: None,
+                min_withdraw_amount: Some(Uint128::zero()),
+            }
+            .self_validate()
+            .expect_err("expected a zero minimum withdraw amount to fail"),
+            "min_withdraw_amount must be greater than zero when provided",
+        );
+        for minimum in [None, Some(Uint128::new(1))] {
+            ExecuteMsg::AdminUpdateMinTradeAmounts {
+                min_fund_amount: minimum,
+                min_withdraw_amount: minimum,
+            }
+            .self_validate()
+            .expect("absent or positive minimums should pass validation");
+        }
+    }
+
+    #[test]
+    fn admin_update_max_trade_amounts_execute_message_validation_should_function_properly() {
+        assert_validation_err(
+            &ExecuteMsg::AdminUpdateMaxTradeAmounts {
+                max_fund_amount: Some(Uint128::zero()),
+                max_withdraw_amount: None,
+            }
+            .self_validate()
+            .expect_err("expected a zero maximum fund amount to fail"),
+            "max_fund_amount must be greater than zero when provided",
+        );
+        assert_validation_err(
+            &ExecuteMsg::AdminUpdateMaxTradeAmounts {
+                max_fund_amount: None,
+                max_withdraw_amount: Some(Uint128::zero()),
+            }
+            .self_validate()
+            .expect_err("expected a zero maximum withdraw amount to fail"),
+            "max_withdraw_amount must be greater than zero when provided",
+        );
+        for maximum in [None, Some(Uint128::new(1))] {
+            ExecuteMsg::AdminUpdateMaxTradeAmounts {
+                max_fund_amount: maximum,
+                max_withdraw_amount: maximum,
+            }
+            .self_validate()
+            .expect("absent or positive maximums should pass validation");
+        }
+    }
+
+    #[test]
+    fn admin_update_msg_fee_execute_message_validation_should_function_properly() {
+        for (msg_fee, expected_error) in [
+            (
+                MsgFee {
+                    amount: coin(100, ""),
+                    recipient: "recipient".to_string(),
+                },
+                "msg fee: fee denom cannot be empty",
+            ),
+            (
+                MsgFee {
+                    amount: coin(0, "nhash"),
+                    recipient: "recipient".to_string(),
+                },
+                "msg fee: fee amount must be greater than zero",
+            ),
+            (
+                MsgFee {
+                    amount: coin(100, "nhash"),
+                    recipient: "".to_string(),
+                },
+                "msg fee: fee recipient cannot be empty",
+            ),
+        ] {
+            assert_validation_err(
+                &ExecuteMsg::AdminUpdateMsgFee {
+                    msg_fee: Some(msg_fee),
+                }
+                .self_validate()
+                .expect_err("expected an invalid msg fee to fail"),
+                expected_error,
+            );
+        }
+        for msg_fee in [
+            None,
+            Some(MsgFee {
+                amount: coin(100, "nhash"),
+                recipient: "recipient".to_string(),
+            }),
+        ] {
+            ExecuteMsg::AdminUpdateMsgFee { msg_fee }
+                .self_validate()
+                .expect("an absent or valid msg fee should pass validation");
+        }
+    }
+
+    #[test]
+    fn admin_update_fee_discounts_execute_message_validation_should_function_properly() {
+        let discount = |attribute: &str, discount_bps_off: u16| FeeDiscount {
+            attribute: attribute.to_string(),
+            discount_bps_off,
+        };
+        assert_validation_err(
+            &ExecuteMsg::AdminUpdateFeeDiscounts {
+                fee_discounts: vec![discount("loyalty.gold.pb", 0)],
+            }
+            .self_validate()
+            .expect_err("expected a zero discount to fail"),
+            "discount for attribute [loyalty.gold.pb] must be between 1 and 10000 bps",
+        );
+        assert_validation_err(
+            &ExecuteMsg::AdminUpdateFeeDiscounts {
+                fee_discounts: vec![
+                    discount("loyalty.gold.pb", 5000),
+                    discount("loyalty.gold.pb", 2500),
+                ],
+            }
+            .self_validate()
+            .expect_err("expected a duplicate attribute to fail"),
+            "fee discount attribute [loyalty.gold.pb] is specified more than once",
+        );
+        let error = ExecuteMsg::AdminUpdateFeeDiscounts {
+            fee_discounts: vec![discount("a.pb", 5000)],
+        }
+        .self_validate()
+        .expect_err("expected a malformed attribute name to fail");
+        assert!(
+            matches!(error, ContractError::InvalidFormatError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+        for fee_discounts in [
+            vec![],
+            vec![
+                discount("loyalty.gold.pb", 5000),
+                discount("loyalty.platinum.pb", 10000),
+            ],
+        ] {
+            ExecuteMsg::AdminUpdateFeeDiscounts { fee_discounts }
+                .self_validate()
+                .expect("empty or valid discounts should pass validation");
+        }
+    }
+
+    #[test]
+    fn admin_update_fund_fee_execute_message_validation_should_function_properly() {
+        assert_validation_err(
+            &ExecuteMsg::AdminUpdateFundFee {
+                fund_fee_bps: Some(10000),
+                fee_collector: Some("collector".to_string()),
+            }
+            .self_validate()
+            .expect_err("expected a fee of the entire amount to fail"),
+            "fund fee must be less than 10000 bps",
+        );
+        assert_validation_err(
+            &ExecuteMsg::AdminUpdateFundFee {
+                fund_fee_bps: Some(25),
+                fee_collector: Some("".to_string()),
+            }
+            .self_validate()
+            .expect_err("expected an empty fee collector to fail"),
+            "fee collector cannot be empty when provided",
+        );
+        assert_validation_err(
+            &ExecuteMsg::AdminUpdateFundFee {
+                fund_fee_bps: Some(25),
+                fee_collector: None,
+            }
+            .self_validate()
+            .expect_err("expected a fee without a collector to fail"),
+            "fee collector must be provided when a fund fee is set",
+        );
+        for (fund_fee_bps, fee_collector) in [
+            (None, None),
+            (Some(0), None),
+            (Some(9999), Some("collector".to_string())),
+        ] {
+            ExecuteMsg::AdminUpdateFundFee {
+                fund_fee_bps,
+                fee_collector,
+            }
+            .self_validate()
+            .expect("an absent, zero or valid fund fee should pass validation");
+        }
+    }
+
+    #[test]
+    fn admin_update_withdraw_fee_execute_message_validation_should_function_properly() {
+        assert_validation_err(
+            &ExecuteMsg::AdminUpdateWithdrawFee {
+                withdraw_fee_bps: Some(10000),
+            }
+            .self_validate()
+            .expect_err("expected a fee of the entire amount to fail"),
+            "withdraw fee must be less than 10000 bps",
+        );
+        for withdraw_fee_bps in [None, Some(0), Some(9999)] {
+            ExecuteMsg::AdminUpdateWithdrawFee { withdraw_fee_bps }
+                .self_validate()
+                .expect("an absent, zero or valid withdraw fee should pass validation");
+        }
+    }
+
+    #[test]
+    fn admin_update_compliance_contract_execute_message_validation_should_function_properly() {
+        assert_validation_err(
+            &ExecuteMsg::AdminUpdateComplianceContract {
+                compliance_contract: Some("".to_string()),
+            }
+            .self_validate()
+            .expect_err("expected an empty compliance contract to fail"),
+            "compliance contract cannot be empty when provided",
+        );
+        for compliance_contract in [None, Some("compliance".to_string())] {
+            ExecuteMsg::AdminUpdateComplianceContract {
+                compliance_contract,
+            }
+            .self_validate()
+            .expect("an absent or non-empty compliance contract should pass validation");
+        }
+    }
+
+    #[test]
+    fn compliance_query_should_serialize_to_the_documented_format() {
+        assert_eq!(
+            r#"{"check_trade":{"account":"account","direction":"fund","amount":"100","denom":"deposit"}}"#,
+            to_json_string(&ComplianceQuery::CheckTrade {
+                account: "account".to_string(),
+                direction: TradeDirection::Fund,
+                amount: Uint128::new(100),
+                denom: "deposit".to_string(),
+            })
+            .expect("the compliance query should serialize"),
+            "the compliance query should match the format documented for compliance contracts",
+        );
+        assert_eq!(
+            ComplianceResponse {
+                approved: true,
+                reason: None,
+            },
+            from_json::<ComplianceResponse>(r#"{"approved":true}"#)
+                .expect("a response without a reason should deserialize"),
+            "the reason should be optional",
+        );
+    }
+
+    #[test]
+    fn gov_update_authority_execute_message_validation_should_function_properly() {
+        assert_validation_err(
+            &ExecuteMsg::GovUpdateAuthority {
+                gov_authority: Some("".to_string()),
+            }
+            .self_validate()
+            .expect_err("expected an empty gov authority to fail"),
+            "gov authority cannot be empty when provided",
+        );
+        for gov_authority in [None, Some("gov-module".to_string())] {
+            ExecuteMsg::GovUpdateAuthority { gov_authority }
+                .self_validate()
+                .expect("an absent or non-empty gov authority should pass validation");
+        }
+    }
+
+    #[test]
+    fn admin_update_schedule_execute_message_validation_should_function_properly() {
+        let msg = |trading_start, sunset_at| ExecuteMsg::AdminUpdateSchedule {
+            trading_start,
+            sunset_at,
+        };
+        assert_validation_err(
+            &msg(
+                Some(ScheduledPoint::Height(10)),
+                Some(ScheduledPoint::Height(10)),
+            )
+            .self_validate()
+            .expect_err("expected a start that does not precede the sunset to fail"),
+            "trading_start must precede sunset_at",
+        );
+        assert_validation_err(
+            &msg(
+                Some(ScheduledPoint::Height(10)),
+                Some(ScheduledPoint::Time(Timestamp::from_seconds(100))),
+            )
+            .self_validate()
+            .expect_err("expected points of different kinds to fail"),
+            "trading_start and sunset_at must both be heights or both be times",
+        );
+        for (trading_start, sunset_at) in [
+            (None, None),
+            (Some(ScheduledPoint::Height(10)), None),
+            (None, Some(ScheduledPoint::Height(10))),
+            (
+                Some(ScheduledPoint::Time(Timestamp::from_seconds(10))),
+                Some(ScheduledPoint::Time(Timestamp::from_seconds(100))),
+            ),
+        ] {
+            msg(trading_start, sunset_at)
+                .self_validate()
+                .expect("a valid schedule should pass validation");
+        }
+    }
+
+    #[test]
+    fn admin_update_required_scope_spec_execute_message_validation_should_function_properly() {
+        for invalid_spec in ["", "scope1qzge0zaztu65tx5x5llv5xc9ztsqxlkwel"] {
+            assert_validation_err(
+                &ExecuteMsg::AdminUpdateRequiredScopeSpec {
+                    required_scope_spec: Some(invalid_spec.to_string()),
+                }
+                .self_validate()
+                .expect_err("expected a value that is not a scope spec address to fail"),
+                "required scope spec must be a scope specification address beginning with [scopespec1]",
+            );
+        }
+        for required_scope_spec in [
+            None,
+            Some("scopespec1qnwg86nsatx5pl56muw0v9ytlz3qu3jx6m".to_string()),
+        ] {
+            ExecuteMsg::AdminUpdateRequiredScopeSpec {
+                required_scope_spec,
+            }
+            .self_validate()
+            .expect("an absent or valid scope spec address should pass validation");
+        }
+    }
+
+    #[test]
+    fn admin_update_trading_marker_execute_message_validation_should_function_properly() {
+        assert_validation_err(
+            &ExecuteMsg::AdminUpdateTradingMarker {
+                denom: Denom::new("", 6),
+                force: false,
+            }
+            .self_validate()
+            .expect_err("expected an invalid trading marker to fail"),
+            "trading marker: name cannot be empty",
+        );
+        ExecuteMsg::AdminUpdateTradingMarker {
+            denom: Denom::new("newtrading", 6),
+            force: false,
+        }
+        .self_validate()
+        .expect("a valid trading marker should pass validation");
+    }
+
+    #[test]
+    fn reject_pending_trade_execute_message_validation_should_function_properly() {
+        assert_validation_err(
+            &ExecuteMsg::RejectPendingTrade {
+                id: 1,
+                reason: "".to_string(),
+            }
+            .self_validate()
+            .expect_err("expected a missing reason to fail"),
+            "a reason must be supplied when rejecting a pending trade",
+        );
+        ExecuteMsg::RejectPendingTrade {
+            id: 1,
+            reason: "exceeds the sender's approved limit".to_string(),
+        }
+        .self_validate()
+        .expect("a rejection with a reason should pass validation");
+    }
+
+    #[test]
+    fn funding_trading_execute_message_validation_should_function_properly() {
+        assert_validation_err(
+            &ExecuteMsg::FundTrading {
+                trade_amount: Uint128::new(0),
+                dry_run: false,
+                quote: None,
+                referrer: None,
+                allow_partial: false,
+                allow_remainder: false,
+            }
+            .self_validate()
+            .expect_err("expected invalid trade amount to fail"),
+            "trade amount must be greater than zero",
+        );
+        ExecuteMsg::FundTrading {
+            trade_amount: Uint128::new(1),
+            dry_run: false,
+            quote: None,
+            referrer: None,
+            allow_partial: false,
+            allow_remainder: false,
+        }
+        .self_validate()
+        .expect("a valid funding trading msg should pass validation");
+    }
+
+    #[test]
+    fn withdraw_trading_execute_message_validation_should_function_properly() {
+        assert_validation_err(
+            &ExecuteMsg::WithdrawTrading {
+                trade_amount: Uint128::new(0),
+                dry_run: false,
+                quote: None,
+                referrer: None,
+                allow_partial: false,
+                allow_remainder: false,
+            }
+            .self_validate()
+            .expect_err("expected invalid trade amount to fail"),
+            "trade amount must be greater than zero",
+        );
+        ExecuteMsg::WithdrawTrading {
+            trade_amount: Uint128::new(1),
+            dry_run: false,
+            quote: None,
+            referrer: None,
+            allow_partial: false,
+            allow_remainder: false,
         }
         .self_validate()
         .expect("a valid withdraw trading msg should pass validation");
     }
 
+    #[test]
+    fn quoted_trade_execute_message_validation_should_function_properly() {
+        assert_validation_err(
+            &ExecuteMsg::FundTrading {
+                trade_amount: Uint128::new(1),
+                dry_run: false,
+                quote: Some(QuoteRef {
+                    hash: "".to_string(),
+                    quoted_at_height: 1,
+                }),
+                referrer: None,
+                allow_partial: false,
+                allow_remainder: false,
+            }
+            .self_validate()
+            .expect_err("expected an empty quote hash to fail"),
+            "quote hash must be supplied when a quote is referenced",
+        );
+        ExecuteMsg::WithdrawTrading {
+            trade_amount: Uint128::new(1),
+            dry_run: false,
+            quote: Some(QuoteRef {
+                hash: "abc123".to_string(),
+                quoted_at_height: 1,
+            }),
+            referrer: None,
+            allow_partial: false,
+            allow_remainder: false,
+        }
+        .self_validate()
+        .expect("a trade msg with a quote hash should pass validation");
+    }
+
+    #[test]
+    fn partial_fill_execute_message_validation_should_function_properly() {
+        assert_validation_err(
+            &ExecuteMsg::FundTrading {
+                trade_amount: Uint128::new(1),
+                dry_run: false,
+                quote: Some(QuoteRef {
+                    hash: "abc123".to_string(),
+                    quoted_at_height: 1,
+                }),
+                referrer: None,
+                allow_partial: true,
+                allow_remainder: false,
+            }
+            .self_validate()
+            .expect_err("expected a quoted partial fill to fail"),
+            "a partial fill cannot be allowed for a quoted trade",
+        );
+        assert_eq!(
+            ExecuteMsg::WithdrawTrading {
+                trade_amount: Uint128::new(1),
+                dry_run: false,
+                quote: None,
+                referrer: None,
+                allow_partial: true,
+                allow_remainder: false,
+            },
+            from_json::<ExecuteMsg>(
+                r#"{"withdraw_trading":{"trade_amount":"1","allow_partial":true}}"#
+            )
+            .expect("a trade msg allowing a partial fill should deserialize"),
+            "the partial fill flag should be parsed correctly",
+        );
+    }
+
+    #[test]
+    fn remainder_acknowledgment_should_default_to_false() {
+        assert_eq!(
+            ExecuteMsg::FundTrading {
+                trade_amount: Uint128::new(103),
+                dry_run: false,
+                quote: None,
+                referrer: None,
+                allow_partial: false,
+                allow_remainder: false,
+            },
+            from_json::<ExecuteMsg>(r#"{"fund_trading":{"trade_amount":"103"}}"#)
+                .expect("a trade msg without the remainder flag should deserialize"),
+            "an omitted remainder flag should not acknowledge a remainder",
+        );
+        assert_eq!(
+            ExecuteMsg::FundTrading {
+                trade_amount: Uint128::new(103),
+                dry_run: false,
+                quote: None,
+                referrer: None,
+                allow_partial: false,
+                allow_remainder: true,
+            },
+            from_json::<ExecuteMsg>(
+                r#"{"fund_trading":{"trade_amount":"103","allow_remainder":true}}"#
+            )
+            .expect("a trade msg acknowledging a remainder should deserialize"),
+            "the remainder flag should be parsed correctly",
+        );
+    }
+
+    #[test]
+    fn referred_trade_execute_message_validation_should_function_properly() {
+        assert_validation_err(
+            &ExecuteMsg::WithdrawTrading {
+                trade_amount: Uint128::new(1),
+                dry_run: false,
+                quote: None,
+                referrer: Some("".to_string()),
+                allow_partial: false,
+                allow_remainder: false,
+            }
+            .self_validate()
+            .expect_err("expected an empty referrer to fail"),
+            "referrer must not be empty when provided",
+        );
+        assert_eq!(
+            ExecuteMsg::FundTrading {
+                trade_amount: Uint128::new(1),
+                dry_run: false,
+                quote: None,
+                referrer: Some("referrer".to_string()),
+                allow_partial: false,
+                allow_remainder: false,
+            },
+            from_json::<ExecuteMsg>(
+                r#"{"fund_trading":{"trade_amount":"1","referrer":"referrer"}}"#
+            )
+            .expect("a trade msg with a referrer should deserialize"),
+            "the referrer should be parsed correctly",
+        );
+        ExecuteMsg::FundTrading {
+            trade_amount: Uint128::new(1),
+            dry_run: false,
+            quote: None,
+            referrer: Some("referrer".to_string()),
+            allow_partial: false,
+            allow_remainder: false,
+        }
+        .self_validate()
+        .expect("a trade msg with a referrer should pass validation");
+    }
+
+    #[test]
+    fn referral_stats_query_message_validation_should_function_properly() {
+        assert_validation_err(
+            &QueryMsg::QueryReferralStats {
+                referrer: "".to_string(),
+            }
+            .self_validate()
+            .expect_err("expected a missing referrer to fail"),
+            "referrer param must be supplied",
+        );
+        QueryMsg::QueryReferralStats {
+            referrer: "referrer".to_string(),
+        }
+        .self_validate()
+        .expect("a valid referral stats msg should pass validation");
+        assert_eq!(
+            QueryMsg::QueryAllReferralStats {
+                start_after: None,
+                limit: None,
+            },
+            from_json::<QueryMsg>(r#"{"query_all_referral_stats":{}}"#)
+                .expect("an all referral stats query msg should deserialize"),
+            "omitted paging fields should use their defaults",
+        );
+        assert_eq!(
+            QueryMsg::QueryFeeExemptions {
+                start_after: None,
+                limit: None,
+            },
+            from_json::<QueryMsg>(r#"{"query_fee_exemptions":{}}"#)
+                .expect("a fee exemptions query msg should deserialize"),
+            "omitted paging fields should use their defaults",
+        );
+        assert_eq!(
+            QueryMsg::QueryDenylist {
+                start_after: None,
+                limit: None,
+            },
+            from_json::<QueryMsg>(r#"{"query_denylist":{}}"#)
+                .expect("a denylist query msg should deserialize"),
+            "omitted paging fields should use their defaults",
+        );
+        assert_eq!(
+            QueryMsg::QueryWhitelistedAccounts {
+                start_after: None,
+                limit: None,
+            },
+            from_json::<QueryMsg>(r#"{"query_whitelisted_accounts":{}}"#)
+                .expect("a whitelisted accounts query msg should deserialize"),
+            "omitted paging fields should use their defaults",
+        );
+    }
+
+    #[test]
+    fn account_stats_query_message_validation_should_function_properly() {
+        assert_validation_err(
+            &QueryMsg::QueryAccountStats {
+                account: "".to_string(),
+            }
+            .self_validate()
+            .expect_err("expected a missing account to fail"),
+            "account param must be supplied",
+        );
+        QueryMsg::QueryAccountStats {
+            account: "account".to_string(),
+        }
+        .self_validate()
+        .expect("a valid account stats msg should pass validation");
+    }
+
+    #[test]
+    fn account_daily_remaining_query_message_validation_should_function_properly() {
+        assert_validation_err(
+            &QueryMsg::QueryAccountDailyRemaining {
+                account: "".to_string(),
+            }
+            .self_validate()
+            .expect_err("expected a missing account to fail"),
+            "account param must be supplied",
+        );
+        QueryMsg::QueryAccountDailyRemaining {
+            account: "account".to_string(),
+        }
+        .self_validate()
+        .expect("a valid account daily remaining msg should pass validation");
+    }
+
+    #[test]
+    fn receipt_query_message_validation_should_function_properly() {
+        assert_validation_err(
+            &QueryMsg::QueryReceipt { receipt: 0 }
+                .self_validate()
+                .expect_err("expected a zero receipt to fail"),
+            "receipt must be greater than zero",
+        );
+        QueryMsg::QueryReceipt { receipt: 1 }
+            .self_validate()
+            .expect("a valid receipt msg should pass validation");
+    }
+
+    #[test]
+    fn trade_history_query_message_should_deserialize() {
+        assert_eq!(
+            QueryMsg::QueryTradeHistory {
+                start_after: None,
+                limit: None,
+                descending: None,
+            },
+            from_json::<QueryMsg>(r#"{"query_trade_history":{}}"#)
+                .expect("a trade history query msg should deserialize"),
+            "omitted paging fields should use their defaults",
+        );
+        assert_eq!(
+            QueryMsg::QueryTradeHistory {
+                start_after: Some(10),
+                limit: Some(5),
+                descending: Some(true),
+            },
+            from_json::<QueryMsg>(
+                r#"{"query_trade_history":{"start_after":10,"limit":5,"descending":true}}"#
+            )
+            .expect("a trade history query msg with paging fields should deserialize"),
+            "the paging fields should be parsed correctly",
+        );
+        QueryMsg::QueryTradeHistory {
+            start_after: None,
+            limit: None,
+            descending: None,
+        }
+        .self_validate()
+        .expect("a trade history msg should pass validation");
+    }
+
+    #[test]
+    fn simulate_trade_query_message_validation_should_function_properly() {
+        assert_validation_err(
+            &QueryMsg::SimulateTrade {
+                sender: "".to_string(),
+                direction: TradeDirection::Fund,
+                amount: Uint128::new(5),
+            }
+            .self_validate()
+            .expect_err("expected a missing sender to fail"),
+            "sender param must be supplied",
+        );
+        assert_validation_err(
+            &QueryMsg::SimulateTrade {
+                sender: "sender".to_string(),
+                direction: TradeDirection::Fund,
+                amount: Uint128::zero(),
+            }
+            .self_validate()
+            .expect_err("expected a zero amount to fail"),
+            "simulated amount must be greater than zero",
+        );
+        assert_eq!(
+            QueryMsg::SimulateTrade {
+                sender: "sender".to_string(),
+                direction: TradeDirection::Withdraw,
+                amount: Uint128::new(5),
+            },
+            from_json::<QueryMsg>(
+                r#"{"simulate_trade":{"sender":"sender","direction":"withdraw","amount":5}}"#
+            )
+            .expect("a simulate trade query msg should deserialize"),
+            "the simulate trade query should be parsed correctly",
+        );
+    }
+
+    #[test]
+    fn simulate_fund_trading_query_message_should_deserialize() {
+        assert_eq!(
+            QueryMsg::SimulateFundTrading {
+                trade_amount: Uint128::new(150),
+            },
+            from_json::<QueryMsg>(r#"{"simulate_fund_trading":{"trade_amount":"150"}}"#)
+                .expect("a simulate fund trading query msg should deserialize"),
+            "the simulate fund trading query should be parsed correctly",
+        );
+        QueryMsg::SimulateFundTrading {
+            trade_amount: Uint128::zero(),
+        }
+        .self_validate()
+        .expect("a zero amount should be simulated rather than rejected");
+    }
+
+    #[test]
+    fn simulate_withdraw_trading_query_message_should_deserialize() {
+        assert_eq!(
+            QueryMsg::SimulateWithdrawTrading {
+                trade_amount: Uint128::new(7),
+            },
+            from_json::<QueryMsg>(r#"{"simulate_withdraw_trading":{"trade_amount":7}}"#)
+                .expect("a simulate withdraw trading query msg should deserialize"),
+            "the simulate withdraw trading query should be parsed correctly",
+        );
+    }
+
+    #[test]
+    fn quote_query_message_validation_should_function_properly() {
+        assert_validation_err(
+            &QueryMsg::QueryQuote {
+                direction: TradeDirection::Fund,
+                amount: Uint128::zero(),
+            }
+            .self_validate()
+            .expect_err("expected a zero amount to fail"),
+            "quote amount must be greater than zero",
+        );
+        assert_eq!(
+            QueryMsg::QueryQuote {
+                direction: TradeDirection::Withdraw,
+                amount: Uint128::new(5),
+            },
+            from_json::<QueryMsg>(r#"{"query_quote":{"direction":"withdraw","amount":5}}"#)
+                .expect("a quote query msg should deserialize"),
+            "the quote query msg should be parsed correctly",
+        );
+    }
+
+    #[test]
+    fn suggest_amounts_query_message_should_deserialize() {
+        assert_eq!(
+            QueryMsg::QuerySuggestAmounts {
+                direction: TradeDirection::Fund,
+                amount: Uint128::new(12),
+            },
+            from_json::<QueryMsg>(
+                r#"{"query_suggest_amounts":{"direction":"fund","amount":"12"}}"#
+            )
+            .expect("a suggest amounts query msg should deserialize"),
+            "the suggest amounts query msg should be parsed correctly",
+        );
+    }
+
+    #[test]
+    fn amount_display_query_messages_should_deserialize_and_validate() {
+        assert_eq!(
+            QueryMsg::QueryFormatAmount {
+                denom_side: DenomSide::Trading,
+                amount: Uint128::new(1234560),
+            },
+            from_json::<QueryMsg>(
+                r#"{"query_format_amount":{"denom_side":"trading","amount":"1234560"}}"#
+            )
+            .expect("a format amount query msg should deserialize"),
+            "the format amount query msg should be parsed correctly",
+        );
+        assert_eq!(
+            QueryMsg::QueryParseAmount {
+                denom_side: DenomSide::Deposit,
+                display: "1234.56".to_string(),
+            },
+            from_json::<QueryMsg>(
+                r#"{"query_parse_amount":{"denom_side":"deposit","display":"1234.56"}}"#
+            )
+            .expect("a parse amount query msg should deserialize"),
+            "the parse amount query msg should be parsed correctly",
+        );
+        assert_validation_err(
+            &QueryMsg::QueryParseAmount {
+                denom_side: DenomSide::Deposit,
+                display: "".to_string(),
+            }
+            .self_validate()
+            .expect_err("expected a missing display to fail"),
+            "display param must be supplied",
+        );
+    }
+
+    #[test]
+    fn denylist_status_query_message_validation_should_function_properly() {
+        assert_validation_err(
+            &QueryMsg::QueryDenylistStatus {
+                account: "".to_string(),
+            }
+            .self_validate()
+            .expect_err("expected a missing account to fail"),
+            "account param must be supplied",
+        );
+        QueryMsg::QueryDenylistStatus {
+            account: "account".to_string(),
+        }
+        .self_validate()
+        .expect("a valid denylist status msg should pass validation");
+    }
+
+    #[test]
+    fn account_readiness_query_message_validation_should_function_properly() {
+        assert_validation_err(
+            &QueryMsg::QueryAccountReadiness {
+                account: "".to_string(),
+            }
+            .self_validate()
+            .expect_err("expected a missing account to fail"),
+            "account param must be supplied",
+        );
+        QueryMsg::QueryAccountReadiness {
+            account: "account".to_string(),
+        }
+        .self_validate()
+        .expect("a valid account readiness msg should pass validation");
+    }
+
+    #[test]
+    fn preview_execute_query_message_validation_should_function_properly() {
+        assert_validation_err(
+            &QueryMsg::PreviewExecute {
+                msg: ExecuteMsg::FundTrading {
+                    trade_amount: Uint128::new(1),
+                    dry_run: false,
+                    quote: None,
+                    referrer: None,
+                    allow_partial: false,
+                    allow_remainder: false,
+                },
+                sender: "".to_string(),
+            }
+            .self_validate()
+            .expect_err("expected a missing sender to fail"),
+            "sender param must be supplied",
+        );
+        assert_validation_err(
+            &QueryMsg::PreviewExecute {
+                msg: ExecuteMsg::AdminUpdateAdmin {
+                    new_admin_address: "some-addr".to_string(),
+                    admin_is_group: false,
+                },
+                sender: "sender".to_string(),
+            }
+            .self_validate()
+            .expect_err("expected a non-trade message to fail"),
+            "only trade execution messages can be previewed",
+        );
+        assert_validation_err(
+            &QueryMsg::PreviewExecute {
+                msg: ExecuteMsg::WithdrawTrading {
+                    trade_amount: Uint128::new(0),
+                    dry_run: false,
+                    quote: None,
+                    referrer: None,
+                    allow_partial: false,
+                    allow_remainder: false,
+                },
+                sender: "sender".to_string(),
+            }
+            .self_validate()
+            .expect_err("expected an invalid inner message to fail"),
+            "trade amount must be greater than zero",
+        );
+        QueryMsg::PreviewExecute {
+            msg: ExecuteMsg::FundTrading {
+                trade_amount: Uint128::new(1),
+                dry_run: false,
+                quote: None,
+                referrer: None,
+                allow_partial: false,
+                allow_remainder: false,
+            },
+            sender: "sender".to_string(),
+        }
+        .self_validate()
+        .expect("a valid preview execute msg should pass validation");
+    }
+
+    #[test]
+    fn instantiate_msg_should_reject_unknown_fields() {
+        from_json::<InstantiateMsg>(
+            r#"{"contract_name":"name","deposit_marker":{"name":"deposit","precision":"2"},"trading_marker":{"name":"trading","precision":"6"},"required_deposit_attributes":[],"required_withdraw_attributes":[],"name_to_bind":null}"#,
+        )
+        .expect("an instantiate msg without unknown fields should deserialize");
+        from_json::<InstantiateMsg>(
+            r#"{"contract_name":"name","deposit_marker":{"name":"deposit","precision":"2"},"trading_marker":{"name":"trading","precision":"6"},"required_deposit_attributes":[],"required_withdraw_attributes":[],"name_to_bind":null,"extra":true}"#,
+        )
+        .expect_err("an instantiate msg with an unknown field should be rejected");
+        from_json::<InstantiateMsg>(
+            r#"{"contract_name":"name","deposit_marker":{"name":"deposit","precision":"2","extra":true},"trading_marker":{"name":"trading","precision":"6"},"required_deposit_attributes":[],"required_withdraw_attributes":[],"name_to_bind":null}"#,
+        )
+        .expect_err("an instantiate msg with an unknown field in a denom should be rejected");
+    }
+
+    #[test]
+    fn execute_msg_should_reject_unknown_fields() {
+        assert_eq!(
+            ExecuteMsg::FundTrading {
+                trade_amount: Uint128::new(10),
+                dry_run: false,
+                quote: None,
+                referrer: None,
+                allow_partial: false,
+                allow_remainder: false,
+            },
+            from_json::<ExecuteMsg>(r#"{"fund_trading":{"trade_amount":"10"}}"#)
+                .expect("an execute msg without unknown fields should deserialize"),
+            "omitted optional fields should use their defaults",
+        );
+        from_json::<ExecuteMsg>(r#"{"fund_trading":{"trade_amount":"10","amount":"999"}}"#)
+            .expect_err("an execute msg with an unknown field should be rejected");
+        from_json::<ExecuteMsg>(
+            r#"{"admin_set_tunables":{"tunables":{"attribute_page_size":25,"attribute_max_pages":10,"extra":1}}}"#,
+        )
+        .expect_err("an execute msg with an unknown field in the tunables should be rejected");
+    }
+
+    #[test]
+    fn execute_msg_should_accept_numeric_trade_amounts() {
+        assert_eq!(
+            ExecuteMsg::WithdrawTrading {
+                trade_amount: Uint128::new(100),
+                dry_run: false,
+                quote: None,
+                referrer: None,
+                allow_partial: false,
+                allow_remainder: false,
+            },
+            from_json::<ExecuteMsg>(r#"{"withdraw_trading":{"trade_amount":100}}"#)
+                .expect("a numeric trade amount should deserialize"),
+            "the numeric trade amount should be parsed correctly",
+        );
+        from_json::<ExecuteMsg>(r#"{"withdraw_trading":{"trade_amount":-100}}"#)
+            .expect_err("a negative trade amount should be rejected");
+    }
+
+    #[test]
+    fn rebalance_execute_message_should_deserialize() {
+        assert_eq!(
+            ExecuteMsg::Rebalance {
+                target_trading_balance: Uint128::new(250),
+            },
+            from_json::<ExecuteMsg>(r#"{"rebalance":{"target_trading_balance":250}}"#)
+                .expect("a rebalance msg should deserialize"),
+            "the numeric target should be parsed correctly",
+        );
+        ExecuteMsg::Rebalance {
+            target_trading_balance: Uint128::zero(),
+        }
+        .self_validate()
+        .expect("a rebalance to an empty trading balance should pass validation");
+    }
+
+    #[test]
+    fn admin_batch_force_withdraw_should_validate_its_accounts() {
+        let msg = |accounts: Vec<&str>, max_per_account: Option<u128>| {
+            ExecuteMsg::AdminBatchForceWithdraw {
+                accounts: accounts.into_iter().map(|a| a.to_string()).collect(),
+                max_per_account: max_per_account.map(Uint128::new),
+            }
+        };
+        msg(vec!["first", "second"], None)
+            .self_validate()
+            .expect("distinct accounts should pass validation");
+        msg(vec!["first"], Some(10))
+            .self_validate()
+            .expect("a positive max per account should pass validation");
+        for (invalid, reason) in [
+            (msg(vec![], None), "no accounts"),
+            (msg(vec!["first", ""], None), "an empty account"),
+            (msg(vec!["first", "first"], None), "a duplicate account"),
+            (msg(vec!["first"], Some(0)), "a zero max per account"),
+            (
+                msg(vec!["account"; MAX_BATCH_FORCE_WITHDRAW_ACCOUNTS + 1], None),
+                "too many accounts",
+            ),
+        ] {
+            let error = invalid
+                .self_validate()
+                .expect_err(&format!("{reason} should fail validation"));
+            assert!(
+                matches!(error, ContractError::ValidationError { .. }),
+                "unexpected error type for {reason}: {error:?}",
+            );
+        }
+    }
+
+    #[test]
+    fn admin_bulk_update_attributes_should_validate_its_chunk() {
+        let msg = |update_count: usize, chunk_token: Option<&str>| {
+            ExecuteMsg::AdminBulkUpdateAttributes {
+                updates: vec![
+                    AttributeUpdate {
+                        pair_id: None,
+                        route: TradeDirection::Fund,
+                        attributes: vec!["attr.pb".to_string()],
+                    };
+                    update_count
+                ],
+                chunk_token: chunk_token.map(|token| token.to_string()),
+            }
+        };
+        msg(1, None)
+            .self_validate()
+            .expect("a first chunk with updates should pass validation");
+        msg(MAX_BULK_ATTRIBUTE_UPDATES_PER_CHUNK, Some("token"))
+            .self_validate()
+            .expect("a follow-up chunk with the maximum updates should pass validation");
+        msg(0, Some("token"))
+            .self_validate()
+            .expect("a completing chunk without updates should pass validation");
+        assert_validation_err(
+            &msg(0, None).self_validate().unwrap_err(),
+            "the first chunk of an operation must contain attribute updates",
+        );
+        assert_validation_err(
+            &msg(1, Some("")).self_validate().unwrap_err(),
+            "chunk token must be non-empty when provided",
+        );
+        assert_validation_err(
+            &msg(MAX_BULK_ATTRIBUTE_UPDATES_PER_CHUNK + 1, None)
+                .self_validate()
+                .unwrap_err(),
+            format!(
+                "no more than [{MAX_BULK_ATTRIBUTE_UPDATES_PER_CHUNK}] attribute updates may be supplied per chunk"
+            ),
+        );
+        assert_validation_err(
+            &ExecuteMsg::AdminBulkUpdateAttributes {
+                updates: vec![AttributeUpdate {
+                    pair_id: Some("pair".to_string()),
+                    route: TradeDirection::Withdraw,
+                    attributes: vec!["attr.pb".to_string()],
+                }],
+                chunk_token: None,
+            }
+            .self_validate()
+            .unwrap_err(),
+            "pair id must be omitted because the contract trades a single pair",
+        );
+    }
+
+    #[test]
+    fn query_msg_should_reject_unknown_fields() {
+        from_json::<QueryMsg>(r#"{"query_account_readiness":{"account":"account"}}"#)
+            .expect("a query msg without unknown fields should deserialize");
+        from_json::<QueryMsg>(r#"{"query_account_readiness":{"account":"account","extra":1}}"#)
+            .expect_err("a query msg with an unknown field should be rejected");
+        from_json::<QueryMsg>(
+            r#"{"preview_execute":{"msg":{"withdraw_trading":{"trade_amount":"10","amount":"1"}},"sender":"sender"}}"#,
+        )
+        .expect_err("a query msg with an unknown field in its nested execute msg should be rejected");
+    }
+
+    #[test]
+    fn migrate_msg_should_reject_unknown_fields() {
+        assert_eq!(
+            MigrateMsg::ContractUpgrade {
+                instantiated_at_height: None,
+                instantiated_at_time: None,
+            },
+            from_json::<MigrateMsg>(r#"{"contract_upgrade":{}}"#)
+                .expect("a migrate msg without unknown fields should deserialize"),
+            "omitted optional fields should use their defaults",
+        );
+        from_json::<MigrateMsg>(r#"{"contract_upgrade":{"extra":1}}"#)
+            .expect_err("a migrate msg with an unknown field should be rejected");
+    }
+
+    #[test]
+    fn contract_upgrade_with_cleanup_should_validate_its_purge_keys() {
+        let msg = |purge_keys: Vec<&str>| MigrateMsg::ContractUpgradeWithCleanup {
+            purge_keys: purge_keys.into_iter().map(|key| key.to_string()).collect(),
+        };
+        msg(vec!["contract_state", "reply_trade_context"])
+            .self_validate()
+            .expect("distinct purge keys should pass validation");
+        for (invalid, expected_message) in [
+            (msg(vec![]), "at least one purge key must be supplied"),
+            (
+                msg(vec!["contract_state", ""]),
+                "all specified purge keys must be non-empty",
+            ),
+            (
+                msg(vec!["contract_state", "contract_state"]),
+                "each purge key may only be specified once",
+            ),
+        ] {
+            assert_validation_err(
+                &invalid
+                    .self_validate()
+                    .expect_err("invalid purge keys should fail validation"),
+                expected_message,
+            );
+        }
+    }
+
     fn assert_validation_err<S: Into<String>>(error: &ContractError, expected_message: S) {
         let _message = expected_message.into();
         assert!(
@@ -0,0 +1,116 @@
+use crate::types::error::ContractError;
+use crate::util::self_validating::SelfValidating;
+use crate::util::validation_utils::validate_attribute_name;
+use cosmwasm_std::Coin;
+use result_extensions::ResultExtensions;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Describes an additional Provenance msg fee that is assessed on every trade accepted by the
+/// [fund_trading](crate::execute::fund_trading::fund_trading) and [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading)
+/// routes.  The fee is charged to the trade transaction's fee payer by the msgfees module and is
+/// routed to the recipient at the protocol level, so the contract never holds the fee's coin.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MsgFee {
+    /// The coin assessed on each trade.
+    pub amount: Coin,
+    /// The bech32 address of the account that receives the assessed fee.
+    pub recipient: String,
+}
+impl SelfValidating for MsgFee {
+    fn self_validate(&self) -> Result<(), ContractError> {
+        if self.amount.denom.is_empty() {
+            return ContractError::ValidationError {
+                message: "fee denom cannot be empty".to_string(),
+            }
+            .to_err();
+        }
+        if self.amount.amount.is_zero() {
+            return ContractError::ValidationError {
+                message: "fee amount must be greater than zero".to_string(),
+            }
+            .to_err();
+        }
+        if self.recipient.is_empty() {
+            return ContractError::ValidationError {
+                message: "fee recipient cannot be empty".to_string(),
+            }
+            .to_err();
+        }
+        ().to_ok()
+    }
+}
+
+/// The number of basis points that make up an entire msg fee.
+pub const FULL_FEE_BPS: u16 = 10000;
+
+/// Describes a reduction of the [msg fee](crate::store::contract_state::ContractStateV1#msg_fee)
+/// granted to trade senders that hold a designated attribute.  When a sender holds the attributes
+/// of multiple discounts, only the discount with the largest reduction is applied.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FeeDiscount {
+    /// The fully-qualified attribute name that a sender must hold to receive the discount.
+    pub attribute: String,
+    /// The portion of the msg fee that is waived, in basis points.  A value of [FULL_FEE_BPS]
+    /// waives the fee entirely.
+    pub discount_bps_off: u16,
+}
+impl SelfValidating for FeeDiscount {
+    fn self_validate(&self) -> Result<(), ContractError> {
+        validate_attribute_name(&self.attribute)?;
+        if self.discount_bps_off == 0 || self.discount_bps_off > FULL_FEE_BPS {
+            return ContractError::ValidationError {
+                message: format!(
+                    "discount for attribute [{}] must be between 1 and {FULL_FEE_BPS} bps",
+                    self.attribute,
+                ),
+            }
+            .to_err();
+        }
+        ().to_ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::error::ContractError;
+    use crate::types::msg_fee::FeeDiscount;
+    use crate::util::self_validating::SelfValidating;
+
+    #[test]
+    fn fee_discount_validation_should_check_the_attribute_and_bounds() {
+        for discount_bps_off in [1, 5000, 10000] {
+            FeeDiscount {
+                attribute: "loyalty.gold.pb".to_string(),
+                discount_bps_off,
+            }
+            .self_validate()
+            .expect("a discount within bounds should be valid");
+        }
+        for discount_bps_off in [0, 10001] {
+            let error = FeeDiscount {
+                attribute: "loyalty.gold.pb".to_string(),
+                discount_bps_off,
+            }
+            .self_validate()
+            .expect_err("a discount outside of bounds should be rejected");
+            assert_eq!(
+                "validation failed: discount for attribute [loyalty.gold.pb] must be between 1 and 10000 bps",
+                error.to_string(),
+                "unexpected error message encountered",
+            );
+        }
+        let error = FeeDiscount {
+            attribute: "a.pb".to_string(),
+            discount_bps_off: 5000,
+        }
+        .self_validate()
+        .expect_err("a malformed attribute name should be rejected");
+        assert!(
+            matches!(error, ContractError::InvalidFormatError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use cosmwasm_std::Uint128;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The direction in which a trade converts funds.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TradeDirection {
+    /// Converts deposit denom to trading denom via the [fund_trading](crate::execute::fund_trading::fund_trading)
+    /// execution route.
+    Fund,
+    /// Converts trading denom to deposit denom via the [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading)
+    /// execution route.
+    Withdraw,
+}
+impl TradeDirection {
+    /// The stable name of the direction, matching its serialized form.
+    pub fn name(&self) -> &'static str {
+        match self {
+            TradeDirection::Fund => "fund",
+            TradeDirection::Withdraw => "withdraw",
+        }
+    }
+}
+
+/// The conversion that a trade would currently produce, returned by the [query_quote](crate::query::query_quote::query_quote)
+/// query.  Its [hash](TradeQuote#hash) can be supplied to a trade execution route to lock the trade
+/// to these values.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct TradeQuote {
+    /// The direction of the quoted trade.
+    pub direction: TradeDirection,
+    /// The amount of input denom requested in the quote.
+    pub trade_amount: Uint128,
+    /// The denom that the trade would pull from the sender's account.
+    pub input_denom: String,
+    /// The denom that the sender would receive.
+    pub output_denom: String,
+    /// The amount of input denom that would actually be pulled from the sender's account, after any
+    /// unconvertible remainder is left behind.
+    pub collected_amount: Uint128,
+    /// The amount of output denom that the sender would receive.
+    pub output_amount: Uint128,
+    /// The amount of the requested input that cannot be converted and would remain in the sender's
+    /// account.
+    pub remainder: Uint128,
+    /// The block height at which the quote was produced.
+    pub quoted_at_height: u64,
+    /// The last block height at which a trade can be executed against the quote.
+    pub valid_through_height: u64,
+    /// A hex-encoded sha256 hash of the quote's inputs and results, which a trade execution route
+    /// re-derives from the current contract configuration to verify that the quote still holds.
+    pub hash: String,
+}
+
+/// A reference to a previously-produced [TradeQuote], supplied to a trade execution route to reject
+/// the trade if its results would differ from the quote.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct QuoteRef {
+    /// The [hash](TradeQuote#hash) of the quote.
+    pub hash: String,
+    /// The [block height](TradeQuote#quoted_at_height) at which the quote was produced.
+    pub quoted_at_height: u64,
+}
@@ -0,0 +1,503 @@
+use crate::store::account_stats::AccountStats;
+use crate::store::bridge_stats::BridgeStats;
+use crate::store::contract_state::ContractStateV1;
+use crate::store::denylist::DenylistEntry;
+use crate::store::pending_trades::PendingTrade;
+use crate::store::referral_stats::ReferralStats;
+use crate::store::runtime_tunables::RuntimeTunables;
+use crate::store::trade_receipts::TradeReceipt;
+use crate::store::trade_stats::{TradeStats, TradeTotals};
+use crate::types::denom::{AmountDisplay, AmountSuggestion, Denom, DenomSide};
+use crate::types::diagnostics::{DiagnosticCheck, RequiredGrant};
+use crate::types::msg_fee::FeeDiscount;
+use crate::types::quote::TradeDirection;
+use cosmwasm_std::{Addr, Coin, CosmosMsg, Timestamp, Uint128};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// The result of previewing an execution route via the [preview_execute](crate::query::query_preview_execute::query_preview_execute)
+/// query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PreviewExecuteResponse {
+    /// True if the execution would currently succeed for the given sender.
+    pub would_succeed: bool,
+    /// Every message that the execution would emit, including the assessment of any msg fee.  Empty
+    /// when the execution would fail.
+    pub messages: Vec<CosmosMsg>,
+    /// The amount of input denom requested in the previewed message.
+    pub requested_amount: Uint128,
+    /// The amount of input denom that would actually be pulled from the sender's account, after
+    /// any unconvertible remainder is left behind.  Only populated when the execution would succeed.
+    pub collected_amount: Option<Uint128>,
+    /// The amount of output denom that the sender would receive.  Only populated when the
+    /// execution would succeed.
+    pub output_amount: Option<Uint128>,
+    /// The amount of the requested input that cannot be converted and would remain in the sender's
+    /// account.  Only populated when the execution would succeed.
+    pub remainder: Option<Uint128>,
+    /// True if the execution leaves a remainder, and therefore only succeeds when the message sets
+    /// `allow_remainder`.  Populated when the execution would succeed, or when it would only fail
+    /// because the remainder is not acknowledged.
+    pub remainder_requires_acknowledgment: Option<bool>,
+    /// True if the trade reaches the contract's [large trade threshold](crate::store::contract_state::ContractStateV1#large_trade_threshold),
+    /// and would therefore be held for admin approval instead of emitting its trade messages.
+    /// Only populated when the execution would succeed.
+    pub requires_approval: Option<bool>,
+    /// The Provenance [msg fee](crate::store::contract_state::ContractStateV1#msg_fee) that the
+    /// execution would assess in addition to the transaction's gas fees.  Only populated when the
+    /// execution would succeed and a fee is configured.
+    pub msg_fee: Option<Coin>,
+    /// A description of the error that the execution would produce.  Only populated when the
+    /// execution would fail.
+    pub error: Option<String>,
+}
+
+/// An amount of coin in its display form, returned by the [query_format_amount](crate::query::query_format_amount::query_format_amount)
+/// query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FormatAmountResponse {
+    /// The side of the bridge whose denom was used to format the amount.
+    pub denom_side: DenomSide,
+    /// The marker denom, including the precision used to format the amount.
+    pub denom: Denom,
+    /// The formatted amount, in base units.
+    pub amount: Uint128,
+    /// The amount in its display form.
+    #[serde(flatten)]
+    pub formatted: AmountDisplay,
+}
+
+/// An amount of coin converted from its display form into base units, returned by the
+/// [query_parse_amount](crate::query::query_parse_amount::query_parse_amount) query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ParseAmountResponse {
+    /// The side of the bridge whose denom was used to parse the amount.
+    pub denom_side: DenomSide,
+    /// The marker denom, including the precision used to parse the amount.
+    pub denom: Denom,
+    /// The parsed amount in its display form.
+    pub display: String,
+    /// The parsed amount, in base units.
+    pub amount: Uint128,
+}
+
+/// The trade amounts nearest to a requested amount, returned by the [query_suggest_amounts](crate::query::query_suggest_amounts::query_suggest_amounts)
+/// query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct SuggestAmountsResponse {
+    /// The direction of the trade for which amounts were suggested.
+    pub direction: TradeDirection,
+    /// The amount of input denom entered for the trade.
+    pub amount: Uint128,
+    /// The valid amounts nearest to the entered amount.
+    #[serde(flatten)]
+    pub suggestion: AmountSuggestion,
+}
+
+/// The result of simulating a trade via the [simulate_trade](crate::query::query_simulate_trade::query_simulate_trade)
+/// query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TradeSimulationResponse {
+    /// The account for which the trade was simulated.
+    pub sender: Addr,
+    /// The direction of the simulated trade.
+    pub direction: TradeDirection,
+    /// The amount of input denom requested in the simulation.
+    pub requested_amount: Uint128,
+    /// True if the trade would currently succeed for the given sender.
+    pub would_succeed: bool,
+    /// The amount of input denom that would actually be pulled from the sender's account, after
+    /// any unconvertible remainder is left behind.  Only populated when the trade would succeed.
+    pub collected_amount: Option<Uint128>,
+    /// The amount of output denom that the sender would receive.  Only populated when the trade
+    /// would succeed.
+    pub output_amount: Option<Uint128>,
+    /// The amount of the requested input that cannot be converted and would remain in the sender's
+    /// account.  Only populated when the trade would succeed.
+    pub remainder: Option<Uint128>,
+    /// True if the trade leaves a remainder, and therefore only executes when the trade sets
+    /// `allow_remainder`.  Only populated when the trade would succeed.
+    pub remainder_requires_acknowledgment: Option<bool>,
+    /// True if the trade would be held for admin approval instead of executing immediately.  Only
+    /// populated when the trade would succeed.
+    pub requires_approval: Option<bool>,
+    /// The Provenance [msg fee](crate::store::contract_state::ContractStateV1#msg_fee) that the
+    /// trade would assess in addition to the transaction's gas fees, after any discount earned by
+    /// the sender's attributes.  Only populated when the trade would succeed and a fee is assessed.
+    pub msg_fee: Option<Coin>,
+    /// The [fee discount](crate::store::contract_state::ContractStateV1#fee_discounts) that the
+    /// trade would receive.  Only populated when the trade would succeed and the sender holds a
+    /// discount attribute.
+    pub fee_discount: Option<FeeDiscount>,
+    /// Every failure that the trade would report, in the order that the execution route reports
+    /// them.  Empty when the trade would succeed.
+    pub failures: Vec<String>,
+}
+
+/// The result of converting an amount of deposit denom to trading denom via the
+/// [simulate_fund_trading](crate::query::query_simulate_fund_trading::query_simulate_fund_trading)
+/// query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct FundTradingSimulationResponse {
+    /// The amount of deposit denom requested in the simulation.
+    pub input_amount: Uint128,
+    /// The amount of trading denom that the deposit denom converts to.
+    pub output_amount: Uint128,
+    /// The amount of the requested deposit denom that cannot be converted and would remain in the
+    /// sender's account.
+    pub remainder: Uint128,
+    /// The deposit denom that a fund trade pulls from the sender's account.
+    pub input_denom: String,
+    /// The trading denom that a fund trade sends to the sender's account.
+    pub output_denom: String,
+    /// True if the conversion produces no trading denom, in which case a fund trade of the amount
+    /// would be rejected.
+    pub would_fail: bool,
+    /// The error that a fund trade of the amount would report because of its conversion.  Only
+    /// populated when [would_fail](FundTradingSimulationResponse#would_fail) is true.
+    pub failure: Option<String>,
+}
+
+/// The result of converting an amount of trading denom to deposit denom via the
+/// [simulate_withdraw_trading](crate::query::query_simulate_withdraw_trading::query_simulate_withdraw_trading)
+/// query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct WithdrawTradingSimulationResponse {
+    /// The amount of trading denom requested in the simulation.
+    pub input_amount: Uint128,
+    /// The amount of trading denom that would actually be pulled from the sender's account, after
+    /// any unconvertible remainder is left behind.
+    pub collected_amount: Uint128,
+    /// The amount of deposit denom that the trading denom converts to.
+    pub output_amount: Uint128,
+    /// The amount of the requested trading denom that cannot be converted and would remain in the
+    /// sender's account.
+    pub remainder: Uint128,
+    /// The trading denom that a withdraw trade pulls from the sender's account.
+    pub input_denom: String,
+    /// The deposit denom that a withdraw trade sends to the sender's account.
+    pub output_denom: String,
+    /// True if the conversion produces no deposit denom, in which case a withdraw trade of the
+    /// amount would be rejected.
+    pub would_fail: bool,
+    /// The error that a withdraw trade of the amount would report because of its conversion.  Only
+    /// populated when [would_fail](WithdrawTradingSimulationResponse#would_fail) is true.
+    pub failure: Option<String>,
+}
+
+/// A summary of whether or not an account is able to use the trade routes, returned by the
+/// [query_account_readiness](crate::query::query_account_readiness::query_account_readiness)
+/// query.  Each section is optional, and is omitted when it cannot be determined.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AccountReadinessResponse {
+    /// The bech32 address of the inspected account.
+    pub account: String,
+    /// The account's current balance of the deposit denom.
+    pub deposit_balance: Option<Coin>,
+    /// The account's current balance of the trading denom.
+    pub trading_balance: Option<Coin>,
+    /// Whether or not the account holds every attribute required to use the
+    /// [fund_trading](crate::execute::fund_trading::fund_trading) route.
+    pub meets_deposit_attributes: Option<bool>,
+    /// Whether or not the account holds every attribute required to use the
+    /// [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading) route.
+    pub meets_withdraw_attributes: Option<bool>,
+    /// Whether or not the account owns a scope created from the [required scope specification](crate::store::contract_state::ContractStateV1#required_scope_spec)
+    /// of the [fund_trading](crate::execute::fund_trading::fund_trading) route.  Always true when
+    /// no scope specification is required, or when the account is whitelisted.
+    pub owns_required_scope: Option<bool>,
+    /// The largest amount of the account's deposit denom balance that can be traded without leaving
+    /// an unconvertible remainder.
+    pub max_fund_amount: Option<Uint128>,
+    /// The largest amount of the account's trading denom balance that can be withdrawn without
+    /// leaving an unconvertible remainder.
+    pub max_withdraw_amount: Option<Uint128>,
+}
+
+/// The lifetime counters of every executed trade, returned by the [query_bridge_stats](crate::query::query_bridge_stats::query_bridge_stats)
+/// query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct BridgeStatsResponse {
+    /// The stored [bridge stats](BridgeStats).  Its fields are flattened into the top level of the
+    /// response.
+    #[serde(flatten)]
+    pub bridge_stats: BridgeStats,
+}
+
+/// The deposit denom accepted by fund trades during the current UTC day, returned by the
+/// [query_daily_capacity](crate::query::query_daily_capacity::query_daily_capacity) query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct DailyCapacityResponse {
+    /// The index of the current UTC day: the block time in seconds divided by the seconds in a day.
+    pub day: u64,
+    /// The configured [daily cap](crate::store::contract_state::ContractStateV1#daily_cap), if any.
+    pub daily_cap: Option<Uint128>,
+    /// The amount of deposit denom collected by fund trades executed during the current day.
+    pub volume: Uint128,
+    /// The amount of deposit denom that fund trades may still collect during the current day.
+    /// `None` when no daily cap is configured.
+    pub remaining: Option<Uint128>,
+}
+
+/// The contract address's current balances of its configured denoms, along with the trading denom's
+/// total supply, returned by the
+/// [query_contract_balances](crate::query::query_contract_balances::query_contract_balances) query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ContractBalancesResponse {
+    /// The amount of the deposit denom held by the contract, which backs the trading denom in
+    /// circulation.
+    pub deposit_balance: Coin,
+    /// The amount of the trading denom held by the contract.  This is normally zero, because the
+    /// trading denom is burned when it is returned.
+    pub trading_balance: Coin,
+    /// The total supply of the trading denom, for comparison against the deposit balance that backs
+    /// it.
+    pub trading_supply: Coin,
+}
+
+/// The contract's current admin, returned by the [query_admin](crate::query::query_admin::query_admin)
+/// query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct AdminResponse {
+    /// The bech32 address of the account that has admin rights within this contract.
+    pub admin: Addr,
+    /// Whether or not the admin is the policy address of a group, in which case admin routes must
+    /// be executed through passed group proposals.  See [admin_is_group](crate::store::contract_state::ContractStateV1#admin_is_group).
+    pub admin_is_group: bool,
+    /// The bech32 address of the chain governance authority that may act in place of the admin, if
+    /// one is configured.  See [gov_authority](crate::store::contract_state::ContractStateV1#gov_authority).
+    pub gov_authority: Option<Addr>,
+}
+
+/// The address proposed to become the contract admin, returned by the [query_pending_admin](crate::query::query_pending_admin::query_pending_admin)
+/// query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PendingAdminResponse {
+    /// The bech32 address proposed by [admin_propose_new_admin](crate::execute::admin_propose_new_admin::admin_propose_new_admin),
+    /// which has not yet accepted the role.  This is `None` when no proposal is pending.
+    pub pending_admin: Option<Addr>,
+}
+
+/// The contract's custody address, returned by the [query_custody_address](crate::query::query_custody_address::query_custody_address)
+/// query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct CustodyAddressResponse {
+    /// The bech32 address of the custody account, if one is configured.  See [custody_address](crate::store::contract_state::ContractStateV1#custody_address).
+    pub custody_address: Option<Addr>,
+}
+
+/// The marker access grants that the contract lacks, returned by the [query_required_grants](crate::query::query_required_grants::query_required_grants)
+/// query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RequiredGrantsResponse {
+    /// One grant per marker on which the contract lacks permissions.  Empty when the contract holds
+    /// every permission that it requires.
+    pub grants: Vec<RequiredGrant>,
+}
+
+/// The constraints that new code must satisfy to migrate the contract, returned by the
+/// [query_migration_info](crate::query::query_migration_info::query_migration_info) query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct MigrationInfoResponse {
+    /// The stored contract type, which the crate name of the new code must match exactly.
+    pub contract_type: String,
+    /// The stored contract version, which the crate version of the new code must exceed.
+    pub contract_version: String,
+    /// The [storage layout version](crate::store::contract_state::CONTRACT_STATE_SCHEMA_VERSION)
+    /// of the stored contract state.
+    pub storage_schema_version: u64,
+    /// A description of each rule that the migration enforces against the new code.
+    pub rules: Vec<String>,
+}
+
+/// A combined view of the contract's current configuration and activity, returned by the
+/// [query_dashboard](crate::query::query_dashboard::query_dashboard) query.  Each section is
+/// optional, and is omitted when the subsystem backing it cannot be loaded, allowing the remaining
+/// sections to be returned.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DashboardResponse {
+    /// The contract's current configuration.
+    pub contract_state: Option<ContractStateV1>,
+    /// The contract's current [runtime tunables](crate::store::runtime_tunables::RuntimeTunables).
+    pub tunables: Option<RuntimeTunables>,
+    /// The contract's current balances of the deposit and trading denoms.
+    pub contract_balances: Option<ContractBalancesResponse>,
+    /// The aggregates of every trade executed by the contract.
+    pub trade_stats: Option<TradeStats>,
+    /// The totals of the trades executed during the current day.
+    pub daily_trade_totals: Option<TradeTotals>,
+}
+
+/// A pair of denoms between which the contract converts funds, as returned within a
+/// [BootstrapResponse].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct TradingPairResponse {
+    /// The denom that accounts deposit via the [fund_trading](crate::execute::fund_trading::fund_trading)
+    /// route.
+    pub deposit_marker: Denom,
+    /// The denom that accounts receive in exchange for their deposit.
+    pub trading_marker: Denom,
+}
+
+/// Everything a client needs to render the bridge without any other queries, returned by the
+/// [query_bootstrap](crate::query::query_bootstrap::query_bootstrap) query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BootstrapResponse {
+    /// The version of the contract code that produced the response, allowing clients to discard
+    /// cached values after an upgrade.
+    pub contract_version: String,
+    /// The contract's current configuration.
+    pub contract_state: ContractStateV1,
+    /// The contract's current [runtime tunables](crate::store::runtime_tunables::RuntimeTunables).
+    pub tunables: RuntimeTunables,
+    /// The pairs of denoms that the contract trades.  The contract currently trades a single pair.
+    pub trading_pairs: Vec<TradingPairResponse>,
+    /// The contract's current balances of the deposit and trading denoms, which back the trades
+    /// that it can perform.  Omitted when the balances cannot be loaded.
+    pub contract_balances: Option<ContractBalancesResponse>,
+    /// Whether or not every check in [status_checks](BootstrapResponse#status_checks) passed.
+    pub operational: bool,
+    /// Whether or not the current block falls within the contract's trading schedule, between its
+    /// [trading_start](crate::store::contract_state::ContractStateV1#trading_start) and
+    /// [sunset_at](crate::store::contract_state::ContractStateV1#sunset_at) points.
+    pub trading_open: bool,
+    /// Whether or not the issuance of trading denom was permanently stopped, as recorded in the
+    /// contract state's [minting_disabled](crate::store::contract_state::ContractStateV1#minting_disabled)
+    /// flag.  Withdrawals remain available when this is true.
+    pub minting_disabled: bool,
+    /// The verifications of the contract's external dependencies, as performed by the
+    /// [admin_run_diagnostics](crate::execute::admin_run_diagnostics::admin_run_diagnostics) route.
+    pub status_checks: Vec<DiagnosticCheck>,
+}
+
+/// A single trade awaiting admin approval, as returned within a [PendingTradesResponse].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PendingTradeResponse {
+    /// The stored [pending trade](crate::store::pending_trades::PendingTrade).  Its fields are
+    /// flattened into the top level of the response.
+    #[serde(flatten)]
+    pub pending_trade: PendingTrade,
+    /// True if the trade can no longer be approved at the current block height, and can only be
+    /// rejected.
+    pub expired: bool,
+}
+
+/// The trades awaiting admin approval, returned by the [query_pending_trades](crate::query::query_pending_trades::query_pending_trades)
+/// query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct PendingTradesResponse {
+    /// The requested page of pending trades, ordered from oldest to newest.
+    pub pending_trades: Vec<PendingTradeResponse>,
+}
+
+/// The data set on the response of a trade route that executed a trade, identifying the
+/// [receipt](crate::store::trade_receipts::TradeReceipt) issued to it.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct TradeReceiptData {
+    /// The receipt number issued to the executed trade.
+    pub receipt: u64,
+}
+
+/// The record of an executed trade, returned by the [query_receipt](crate::query::query_receipt::query_receipt)
+/// query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct TradeReceiptResponse {
+    /// The stored [trade receipt](TradeReceipt).  Its fields are flattened into the top level of
+    /// the response.
+    #[serde(flatten)]
+    pub trade_receipt: TradeReceipt,
+}
+
+/// A page of the history of executed trades, returned by the [query_trade_history](crate::query::query_trade_history::query_trade_history)
+/// query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct TradeHistoryResponse {
+    /// The requested page of [trade receipts](TradeReceipt), in the requested order.
+    pub trades: Vec<TradeReceipt>,
+}
+
+/// The trade statistics of a single account, returned by the [query_account_stats](crate::query::query_account_stats::query_account_stats)
+/// query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct AccountStatsResponse {
+    /// The bech32 address of the account.
+    pub account: Addr,
+    /// The account's [stats](AccountStats).  Its fields are flattened into the top level of the
+    /// response.
+    #[serde(flatten)]
+    pub stats: AccountStats,
+}
+
+/// The value that a single account may still trade during the current UTC day, returned by the
+/// [query_account_daily_remaining](crate::query::query_account_daily_remaining::query_account_daily_remaining)
+/// query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct AccountDailyRemainingResponse {
+    /// The bech32 address of the account.
+    pub account: Addr,
+    /// The configured [per-account daily limit](crate::store::contract_state::ContractStateV1#per_account_daily_limit),
+    /// if any.
+    pub per_account_daily_limit: Option<Uint128>,
+    /// The value that the account has traded during the current day, expressed in deposit denom.
+    pub used: Uint128,
+    /// The value that the account may still trade during the current day, expressed in deposit
+    /// denom.  `None` when no per-account daily limit is configured.
+    pub remaining: Option<Uint128>,
+    /// The time at which the current day ends and the account's allowance resets.
+    pub resets_at: Timestamp,
+}
+
+/// The trade statistics credited to a single referrer, returned by the [query_referral_stats](crate::query::query_referral_stats::query_referral_stats)
+/// query and within a [ReferralStatsListResponse].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct ReferralStatsResponse {
+    /// The bech32 address of the referrer.
+    pub referrer: Addr,
+    /// The referrer's [stats](ReferralStats).  Its fields are flattened into the top level of the
+    /// response.
+    #[serde(flatten)]
+    pub stats: ReferralStats,
+}
+
+/// The trade statistics of every credited referrer, returned by the [query_all_referral_stats](crate::query::query_all_referral_stats::query_all_referral_stats)
+/// query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct ReferralStatsListResponse {
+    /// The requested page of referrers, ordered by address.
+    pub referral_stats: Vec<ReferralStatsResponse>,
+}
+
+/// The result of the [query_fee_exemptions](crate::query::query_fee_exemptions::query_fee_exemptions)
+/// query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct FeeExemptionsResponse {
+    /// The requested page of fee-exempt accounts, ordered by address.
+    pub accounts: Vec<Addr>,
+}
+
+/// The result of the [query_whitelisted_accounts](crate::query::query_whitelisted_accounts::query_whitelisted_accounts)
+/// query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct WhitelistedAccountsResponse {
+    /// The requested page of whitelisted accounts, ordered by address.
+    pub accounts: Vec<Addr>,
+}
+
+/// The denylist status of a single account, returned by the [query_denylist_status](crate::query::query_denylist_status::query_denylist_status)
+/// query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct DenylistStatusResponse {
+    /// The bech32 address of the queried account.
+    pub account: Addr,
+    /// Whether or not the account is blocked from trading.
+    pub denylisted: bool,
+    /// The reason that the account was blocked, if it is denylisted and one was provided.
+    pub reason: Option<String>,
+}
+
+/// The result of the [query_denylist](crate::query::query_denylist::query_denylist) query.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct DenylistResponse {
+    /// The requested page of denylisted accounts and their reasons, ordered by address.
+    pub entries: Vec<DenylistEntry>,
+}
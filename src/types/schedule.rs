@@ -0,0 +1,102 @@
+use cosmwasm_std::{BlockInfo, Timestamp};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// A point in the chain's progression at which a scheduled change to trading takes effect,
+/// expressed as either a block height or a block time.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ScheduledPoint {
+    /// The point is reached once the chain produces a block at or above this height.
+    Height(u64),
+    /// The point is reached once the chain produces a block at or after this time.
+    Time(Timestamp),
+}
+impl ScheduledPoint {
+    /// Determines whether or not the given block has reached this point.
+    ///
+    /// # Parameters
+    /// * `block` The block to compare against, normally the current block.
+    pub fn has_passed(&self, block: &BlockInfo) -> bool {
+        match self {
+            ScheduledPoint::Height(height) => block.height >= *height,
+            ScheduledPoint::Time(time) => block.time >= *time,
+        }
+    }
+
+    /// Determines whether or not this point occurs strictly before the other point.  Heights and
+    /// times cannot be compared without knowing future block times, so `None` is returned when the
+    /// two points are of different kinds.
+    ///
+    /// # Parameters
+    /// * `other` The point to compare against.
+    pub fn precedes(&self, other: &ScheduledPoint) -> Option<bool> {
+        match (self, other) {
+            (ScheduledPoint::Height(height), ScheduledPoint::Height(other_height)) => {
+                Some(height < other_height)
+            }
+            (ScheduledPoint::Time(time), ScheduledPoint::Time(other_time)) => {
+                Some(time < other_time)
+            }
+            _ => None,
+        }
+    }
+}
+impl Display for ScheduledPoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScheduledPoint::Height(height) => write!(f, "height {height}"),
+            ScheduledPoint::Time(time) => write!(f, "time {time}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::schedule::ScheduledPoint;
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::Timestamp;
+
+    #[test]
+    fn has_passed_should_compare_against_the_block() {
+        let block = mock_env().block;
+        assert!(
+            ScheduledPoint::Height(block.height).has_passed(&block),
+            "a height should be passed at the same block",
+        );
+        assert!(
+            !ScheduledPoint::Height(block.height + 1).has_passed(&block),
+            "a later height should not be passed",
+        );
+        assert!(
+            ScheduledPoint::Time(block.time.minus_seconds(1)).has_passed(&block),
+            "an earlier time should be passed",
+        );
+        assert!(
+            !ScheduledPoint::Time(block.time.plus_seconds(1)).has_passed(&block),
+            "a later time should not be passed",
+        );
+    }
+
+    #[test]
+    fn precedes_should_only_compare_points_of_the_same_kind() {
+        assert_eq!(
+            Some(true),
+            ScheduledPoint::Height(1).precedes(&ScheduledPoint::Height(2)),
+        );
+        assert_eq!(
+            Some(false),
+            ScheduledPoint::Height(2).precedes(&ScheduledPoint::Height(2)),
+        );
+        assert_eq!(
+            Some(false),
+            ScheduledPoint::Time(Timestamp::from_seconds(5))
+                .precedes(&ScheduledPoint::Time(Timestamp::from_seconds(4))),
+        );
+        assert_eq!(
+            None,
+            ScheduledPoint::Height(1).precedes(&ScheduledPoint::Time(Timestamp::from_seconds(4))),
+        );
+    }
+}
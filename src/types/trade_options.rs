@@ -0,0 +1,27 @@
+use crate::types::quote::QuoteRef;
+
+/// The optional behaviors that a sender can request from the [fund_trading](crate::execute::fund_trading::fund_trading)
+/// and [withdraw_trading](crate::execute::withdraw_trading::withdraw_trading) routes.  The default
+/// value requests none of them.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TradeOptions {
+    /// If true, every check and the conversion are still performed, but the response will only
+    /// contain attributes describing the result, including whether or not the trade would require
+    /// admin approval.  No messages are emitted.
+    pub dry_run: bool,
+    /// If provided, the trade is rejected unless it matches the referenced quote and is executed
+    /// within the quote's validity window.
+    pub quote: Option<QuoteRef>,
+    /// If provided, the bech32 address of the account that referred the trade.  The referrer is
+    /// credited with the trade's value in its [referral stats](crate::store::referral_stats::ReferralStats)
+    /// once the trade executes.  An account cannot refer its own trades.
+    pub referrer: Option<String>,
+    /// If true and the sender does not hold enough input denom to cover the trade, the trade is
+    /// reduced to the largest amount not exceeding the sender's balance that converts without a
+    /// remainder.  The response then includes a `partial_fill` attribute and the `filled_amount`.
+    pub allow_partial: bool,
+    /// If true, the trade executes even when part of the requested amount cannot be converted and
+    /// is left in the sender's account.  Otherwise, such a trade is rejected with an error naming
+    /// the remainder and the largest amount that converts without one.
+    pub allow_remainder: bool,
+}
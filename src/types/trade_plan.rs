@@ -0,0 +1,158 @@
+use crate::types::denom::DenomConversion;
+use crate::types::error::ContractError;
+use crate::types::msg_fee::{FeeDiscount, MsgFee};
+use cosmwasm_std::{Addr, SubMsg, Uint128};
+use result_extensions::ResultExtensions;
+
+/// Describes the full outcome of a trade route's checks and calculations before anything is
+/// executed.  Produced without modifying contract state, which allows the same plan to drive both
+/// an actual execution and a read-only preview of that execution.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TradePlan {
+    /// The conversion between the input denom and the output denom for the requested amount.
+    pub conversion: DenomConversion,
+    /// The amount of input denom that will actually be pulled from the sender's account.  This is
+    /// the requested amount minus any [remainder](DenomConversion#remainder) that cannot be
+    /// converted.
+    pub collected_amount: Uint128,
+    /// The value of the trade, expressed as an amount of deposit denom.  This is the collected
+    /// amount when funding, and the released amount when withdrawing.
+    pub deposit_amount: Uint128,
+    /// All messages that the trade will emit, in the order that they should be executed.  Each is
+    /// wrapped in a submessage that replies on error with the [step](crate::types::trade_step::TradeStep)
+    /// that it performs.
+    pub messages: Vec<SubMsg>,
+    /// The msg fee that the trade will assess, after any [fee_discount](TradePlan#fee_discount).
+    /// `None` when no fee is configured or the discount waives the fee entirely.
+    pub msg_fee: Option<MsgFee>,
+    /// The discount applied to the msg fee because the sender holds its attribute.
+    pub fee_discount: Option<FeeDiscount>,
+    /// The fee withheld from the trade's output denom: the minted trading denom when funding, and
+    /// the released deposit denom when withdrawing.  `None` when no fee is configured for the
+    /// trade's direction.
+    pub trade_fee: Option<TradeFee>,
+    /// Whether or not the sender is on the [attribute whitelist](crate::store::attribute_whitelist),
+    /// and was therefore not required to hold the trade route's required attributes.
+    pub whitelisted: bool,
+    /// The validated account that referred the trade, if the sender named one.
+    pub referrer: Option<Addr>,
+    /// Whether or not the trade's [deposit_amount](TradePlan#deposit_amount) reaches the contract's
+    /// [large trade threshold](crate::store::contract_state::ContractStateV1#large_trade_threshold),
+    /// in which case it is held for admin approval instead of emitting its trade messages.
+    pub requires_approval: bool,
+}
+impl TradePlan {
+    /// The amount of output denom delivered to the sender: the [converted amount](DenomConversion#target_amount)
+    /// minus any [trade fee](TradePlan#trade_fee) withheld from it.
+    pub fn output_amount(&self) -> Uint128 {
+        self.conversion.target_amount.saturating_sub(
+            self.trade_fee
+                .as_ref()
+                .map(|trade_fee| trade_fee.amount)
+                .unwrap_or_default(),
+        )
+    }
+
+    /// Determines whether or not the trade would leave a [remainder](DenomConversion#remainder)
+    /// that the sender must acknowledge before the trade can execute.
+    pub fn requires_remainder_acknowledgment(&self) -> bool {
+        !self.conversion.remainder.is_zero()
+    }
+
+    /// Verifies that the trade leaves no remainder, or that the sender acknowledged it.  The
+    /// resulting error names the remainder and the largest amount that would convert without one,
+    /// allowing the sender to resubmit that amount instead.
+    ///
+    /// # Parameters
+    /// * `input_denom` The name of the denom that the trade collects from the sender.
+    /// * `allow_remainder` True if the sender acknowledged that a remainder may be left behind.
+    pub fn check_remainder_acknowledged<S: Into<String>>(
+        &self,
+        input_denom: S,
+        allow_remainder: bool,
+    ) -> Result<(), ContractError> {
+        if allow_remainder || !self.requires_remainder_acknowledgment() {
+            return ().to_ok();
+        }
+        ContractError::RemainderNotAcknowledgedError {
+            remainder: self.conversion.remainder,
+            clean_amount: self.collected_amount,
+            denom: input_denom.into(),
+        }
+        .to_err()
+    }
+}
+
+/// The portion of a trade's output denom that is withheld from the sender and paid to the
+/// contract's [fee collector](crate::store::contract_state::ContractStateV1#fee_collector).
+#[derive(Clone, Debug, PartialEq)]
+pub struct TradeFee {
+    /// The amount of output denom withheld.  This is zero when the fee rounds down to nothing, in
+    /// which case no payment to the collector is emitted.
+    pub amount: Uint128,
+    /// The account that receives the withheld output denom.
+    pub collector: Addr,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::denom::DenomConversion;
+    use crate::types::trade_plan::{TradeFee, TradePlan};
+    use cosmwasm_std::{Addr, Uint128};
+
+    fn plan(requested_amount: u128, remainder: u128) -> TradePlan {
+        TradePlan {
+            conversion: DenomConversion {
+                source_amount: Uint128::new(requested_amount),
+                target_amount: Uint128::new(requested_amount / 10),
+                remainder: Uint128::new(remainder),
+            },
+            collected_amount: Uint128::new(requested_amount - remainder),
+            deposit_amount: Uint128::new(requested_amount - remainder),
+            messages: vec![],
+            msg_fee: None,
+            fee_discount: None,
+            trade_fee: None,
+            whitelisted: false,
+            referrer: None,
+            requires_approval: false,
+        }
+    }
+
+    #[test]
+    fn check_remainder_acknowledged_should_require_the_flag_for_a_remainder() {
+        plan(100, 0)
+            .check_remainder_acknowledged("deposit", false)
+            .expect("a trade without a remainder should not require acknowledgment");
+        plan(103, 3)
+            .check_remainder_acknowledged("deposit", true)
+            .expect("an acknowledged remainder should be accepted");
+        let error = plan(103, 3)
+            .check_remainder_acknowledged("deposit", false)
+            .expect_err("an unacknowledged remainder should be rejected");
+        assert_eq!(
+            "unacknowledged remainder: the trade would leave [3deposit] unconverted; trade [100deposit] instead, or set allow_remainder to accept the remainder",
+            error.to_string(),
+            "unexpected error message encountered",
+        );
+    }
+
+    #[test]
+    fn output_amount_should_deduct_the_trade_fee() {
+        let mut plan = plan(1000, 0);
+        assert_eq!(
+            Uint128::new(100),
+            plan.output_amount(),
+            "the full converted amount should be delivered without a trade fee",
+        );
+        plan.trade_fee = Some(TradeFee {
+            amount: Uint128::new(3),
+            collector: Addr::unchecked("collector"),
+        });
+        assert_eq!(
+            Uint128::new(97),
+            plan.output_amount(),
+            "the trade fee should be withheld from the delivered amount",
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use cosmwasm_std::{CosmosMsg, SubMsg};
+
+/// The stages of a trade's execution, each of which is performed by its own submessage.  Each
+/// submessage requests a reply when it fails, allowing the contract's reply entry point to report
+/// which stage was rejected downstream instead of a raw marker module error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TradeStep {
+    /// Moving the trade's input denom out of the sender's account.
+    Collect,
+    /// Minting the trading denom to which a fund trade converts.
+    Mint,
+    /// Withdrawing minted trading denom from the trading marker to an account.
+    Withdraw,
+    /// Burning the trading denom collected by a withdraw trade.
+    Burn,
+    /// Moving deposit denom held by the contract to an account.
+    Release,
+}
+impl TradeStep {
+    /// Every trade step, in the order in which a trade can perform them.
+    pub const ALL: [TradeStep; 5] = [
+        TradeStep::Collect,
+        TradeStep::Mint,
+        TradeStep::Withdraw,
+        TradeStep::Burn,
+        TradeStep::Release,
+    ];
+
+    /// The identifier of the reply requested by the step's submessage.  Each step uses a distinct
+    /// identifier.
+    pub fn reply_id(&self) -> u64 {
+        match self {
+            TradeStep::Collect => 1,
+            TradeStep::Mint => 2,
+            TradeStep::Withdraw => 3,
+            TradeStep::Burn => 4,
+            TradeStep::Release => 5,
+        }
+    }
+
+    /// Finds the step whose submessage requested the reply with the given identifier, if any.
+    ///
+    /// # Parameters
+    /// * `reply_id` The identifier of the received reply.
+    pub fn from_reply_id(reply_id: u64) -> Option<TradeStep> {
+        Self::ALL
+            .into_iter()
+            .find(|step| step.reply_id() == reply_id)
+    }
+
+    /// The name of the step, as reported in errors.
+    pub fn name(&self) -> &'static str {
+        match self {
+            TradeStep::Collect => "collect",
+            TradeStep::Mint => "mint",
+            TradeStep::Withdraw => "withdraw",
+            TradeStep::Burn => "burn",
+            TradeStep::Release => "release",
+        }
+    }
+
+    /// Describes the most common causes of the step failing, allowing the failure to be resolved
+    /// without interpreting the marker module's error.
+    pub fn failure_hint(&self) -> &'static str {
+        match self {
+            TradeStep::Collect => "the sender may not hold the traded amount, or the contract may lack transfer access on the input marker",
+            TradeStep::Mint => "the contract may lack mint access on the trading marker, or the mint may exceed the marker's supply limits",
+            TradeStep::Withdraw => "the contract may lack withdraw access on the trading marker, or the recipient may not hold the marker's required attributes",
+            TradeStep::Burn => "the contract may lack burn access on the trading marker",
+            TradeStep::Release => "the contract may lack transfer access on the deposit marker, or may not hold enough deposit denom",
+        }
+    }
+
+    /// Wraps a message that performs this step in a submessage that replies when it fails.
+    ///
+    /// # Parameters
+    /// * `msg` The message that performs the step.
+    pub fn sub_msg<M: Into<CosmosMsg>>(&self, msg: M) -> SubMsg {
+        SubMsg::reply_on_error(msg, self.reply_id())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::trade_step::TradeStep;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_reply_ids_should_be_distinct_and_reversible() {
+        assert_eq!(
+            TradeStep::ALL.len(),
+            TradeStep::ALL
+                .iter()
+                .map(|step| step.reply_id())
+                .collect::<HashSet<_>>()
+                .len(),
+            "every step should use a distinct reply id",
+        );
+        for step in TradeStep::ALL {
+            assert_eq!(
+                Some(step),
+                TradeStep::from_reply_id(step.reply_id()),
+                "the step should be found by its own reply id",
+            );
+        }
+        assert_eq!(
+            None,
+            TradeStep::from_reply_id(0),
+            "an unused reply id should not match a step",
+        );
+    }
+}
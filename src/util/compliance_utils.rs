@@ -0,0 +1,139 @@
+use crate::types::error::ContractError;
+use crate::types::msg::{ComplianceQuery, ComplianceResponse};
+use crate::types::quote::TradeDirection;
+use cosmwasm_std::{Addr, Deps, Uint128};
+use result_extensions::ResultExtensions;
+
+/// Asks the given compliance contract whether or not a trade may proceed by sending it a
+/// [CheckTrade](ComplianceQuery::CheckTrade) query.  An error is returned unless the compliance
+/// contract responds with approval, including the contract's reason when one is provided.  The check
+/// fails closed: a compliance contract that errors or returns a malformed response rejects the
+/// trade.
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `compliance_contract` The address of the compliance contract to query.
+/// * `account` The account requesting the trade.
+/// * `direction` The direction of the trade.
+/// * `amount` The amount of the given denom that the account would send to this contract.
+/// * `denom` The denom that the account would send to this contract.
+pub fn check_trade_compliance<S: Into<String>>(
+    deps: &Deps,
+    compliance_contract: &Addr,
+    account: &Addr,
+    direction: TradeDirection,
+    amount: Uint128,
+    denom: S,
+) -> Result<(), ContractError> {
+    let response = deps
+        .querier
+        .query_wasm_smart::<ComplianceResponse>(
+            compliance_contract,
+            &ComplianceQuery::CheckTrade {
+                account: account.to_string(),
+                direction,
+                amount,
+                denom: denom.into(),
+            },
+        )
+        .map_err(|e| ContractError::ComplianceError {
+            message: format!(
+                "compliance contract [{compliance_contract}] could not be consulted: {e}"
+            ),
+        })?;
+    if response.approved {
+        ().to_ok()
+    } else {
+        ContractError::ComplianceError {
+            message: format!(
+                "compliance contract [{compliance_contract}] rejected the trade: {}",
+                response
+                    .reason
+                    .unwrap_or_else(|| "no reason provided".to_string()),
+            ),
+        }
+        .to_err()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::test_compliance::mock_compliance_response;
+    use crate::types::error::ContractError;
+    use crate::types::msg::ComplianceResponse;
+    use crate::types::quote::TradeDirection;
+    use crate::util::compliance_utils::check_trade_compliance;
+    use cosmwasm_std::{Addr, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn approved_trade_should_pass() {
+        let mut deps = mock_provenance_dependencies();
+        mock_compliance_response(
+            &mut deps.querier,
+            &Addr::unchecked("compliance"),
+            ComplianceResponse {
+                approved: true,
+                reason: None,
+            },
+        );
+        check_trade_compliance(
+            &deps.as_ref(),
+            &Addr::unchecked("compliance"),
+            &Addr::unchecked("account"),
+            TradeDirection::Fund,
+            Uint128::new(100),
+            "deposit",
+        )
+        .expect("an approved trade should pass the compliance check");
+    }
+
+    #[test]
+    fn rejected_trade_should_include_the_reason() {
+        let mut deps = mock_provenance_dependencies();
+        mock_compliance_response(
+            &mut deps.querier,
+            &Addr::unchecked("compliance"),
+            ComplianceResponse {
+                approved: false,
+                reason: Some("account is sanctioned".to_string()),
+            },
+        );
+        let error = check_trade_compliance(
+            &deps.as_ref(),
+            &Addr::unchecked("compliance"),
+            &Addr::unchecked("account"),
+            TradeDirection::Withdraw,
+            Uint128::new(100),
+            "trading",
+        )
+        .expect_err("a rejected trade should fail the compliance check");
+        match error {
+            ContractError::ComplianceError { message } => assert_eq!(
+                "compliance contract [compliance] rejected the trade: account is sanctioned",
+                message,
+                "the compliance contract's reason should be included",
+            ),
+            e => panic!("unexpected error encountered: {e:?}"),
+        };
+    }
+
+    #[test]
+    fn unreachable_compliance_contract_should_fail_closed() {
+        let deps = mock_provenance_dependencies();
+        let error = check_trade_compliance(
+            &deps.as_ref(),
+            &Addr::unchecked("compliance"),
+            &Addr::unchecked("account"),
+            TradeDirection::Fund,
+            Uint128::new(100),
+            "deposit",
+        )
+        .expect_err("a failed compliance query should fail the compliance check");
+        assert!(
+            matches!(error, ContractError::ComplianceError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
+}
@@ -1,9 +1,12 @@
-use crate::types::denom::{Denom, DenomConversion};
+use crate::types::denom::{AmountDisplay, AmountSuggestion, Denom, DenomConversion};
 use crate::types::error::ContractError;
+use cosmwasm_std::Uint128;
 use result_extensions::ResultExtensions;
 
 /// Converts the source denom amount to the target denom's amount, accounting for any remaining
-/// funds.
+/// funds.  All arithmetic is checked, so an amount or precision difference too large to represent
+/// produces a [ConversionError](ContractError::ConversionError) naming the offending values instead
+/// of a panic.
 ///
 /// # Parameters
 /// * `source_amount` The amount of source denom to convert to target denom.
@@ -11,33 +14,45 @@ use result_extensions::ResultExtensions;
 /// * `target_denom` The denom defining the target amount, allowing the relation between source and
 /// target to dictate the results.
 pub fn convert_denom(
-    source_amount: u128,
+    source_amount: Uint128,
     source_denom: &Denom,
     target_denom: &Denom,
 ) -> Result<DenomConversion, ContractError> {
     let source_precision = source_denom.precision.u64();
     let target_precision = target_denom.precision.u64();
-    let precision_diff = u32::try_from((source_precision as i64 - target_precision as i64).abs())
-        .map_err(|e| ContractError::ConversionError {
-            message: format!("source precision [{source_precision}] and target precision [{target_precision}] have too large a difference to convert: {e:?}")
-        })?;
-    let precision_modifier = 10u128.pow(precision_diff);
+    let precision_modifier = get_precision_modifier(source_precision, target_precision)?;
     let (target_amount, remainder) = match source_precision {
         // If source precision is greater, the value needs some of its values trimmed off for target
         // conversion amount.
         s if s > target_precision => {
-            let target_amount = source_amount / precision_modifier;
-            let remainder = source_amount % precision_modifier;
+            let target_amount = source_amount.checked_div(precision_modifier).map_err(|e| {
+                ContractError::ConversionError {
+                    message: format!(
+                        "failed to reduce [{source_amount}] to target precision by dividing it by [{precision_modifier}]: {e:?}"
+                    ),
+                }
+            })?;
+            let remainder = source_amount.checked_rem(precision_modifier).map_err(|e| {
+                ContractError::ConversionError {
+                    message: format!("failed to derive remainder of [{source_amount}]: {e:?}"),
+                }
+            })?;
             (target_amount, remainder)
         }
         // If source precision is lesser, the value should get zeroes added to become the target.
         // The value increases, so there is never a remainder.
         s if s < target_precision => {
-            let target_amount = source_amount * precision_modifier;
-            (target_amount, 0u128)
+            let target_amount = source_amount.checked_mul(precision_modifier).map_err(|e| {
+                ContractError::ConversionError {
+                    message: format!(
+                        "[{source_amount}] is too large to convert to target precision by multiplying it by [{precision_modifier}]: {e:?}"
+                    ),
+                }
+            })?;
+            (target_amount, Uint128::zero())
         }
         // If the precisions are equal, then it is a 1 to 1 conversion and the result is the input
-        _ => (source_amount, 0u128),
+        _ => (source_amount, Uint128::zero()),
     };
     DenomConversion {
         source_amount,
@@ -47,114 +62,290 @@ pub fn convert_denom(
     .to_ok()
 }
 
+/// Finds the valid source denom amounts nearest to the requested amount.  When the source denom has
+/// a greater precision than the target denom, only multiples of the precision difference convert
+/// without a remainder.  Otherwise, every nonzero amount converts.  A valid amount must also lie
+/// within the given minimum and maximum, so that every suggestion is accepted by the trade route.
+/// A suggestion is omitted when no valid amount exists on its side of the requested amount.
+///
+/// # Parameters
+/// * `source_amount` The requested amount of source denom.
+/// * `source_denom` The denom defining the source amount.
+/// * `target_denom` The denom to which the source amount would be converted.
+/// * `minimum` The smallest amount of source denom that the trade route accepts, if any.
+/// * `maximum` The largest amount of source denom that the trade route accepts, if any.
+pub fn suggest_amounts(
+    source_amount: Uint128,
+    source_denom: &Denom,
+    target_denom: &Denom,
+    minimum: Option<Uint128>,
+    maximum: Option<Uint128>,
+) -> Result<AmountSuggestion, ContractError> {
+    let source_precision = source_denom.precision.u64();
+    let target_precision = target_denom.precision.u64();
+    // Amounts only need to be trimmed when the source denom has extra decimal places
+    let step = if source_precision > target_precision {
+        get_precision_modifier(source_precision, target_precision)?
+    } else {
+        Uint128::one()
+    };
+    // Zero never converts to any target denom, so the smallest valid amount is at least one step
+    let smallest_amount = round_up_to_step(minimum.unwrap_or_default().max(Uint128::one()), step)?;
+    let ceiling = maximum.map_or(source_amount, |maximum| source_amount.min(maximum));
+    let lower_amount = Some(round_down_to_step(ceiling, step)?)
+        .filter(|lower| smallest_amount.is_some_and(|smallest| *lower >= smallest));
+    let upper_amount = match source_amount.checked_add(Uint128::one()) {
+        Ok(next_amount) => round_up_to_step(next_amount.max(minimum.unwrap_or_default()), step)?,
+        Err(_) => None,
+    }
+    .filter(|upper| !maximum.is_some_and(|maximum| *upper > maximum));
+    AmountSuggestion {
+        lower_amount,
+        lower_delta: lower_amount
+            .map(|amount| source_amount.checked_sub(amount))
+            .transpose()?,
+        upper_amount,
+        upper_delta: upper_amount
+            .map(|amount| amount.checked_sub(source_amount))
+            .transpose()?,
+    }
+    .to_ok()
+}
+
+fn round_down_to_step(amount: Uint128, step: Uint128) -> Result<Uint128, ContractError> {
+    let remainder = amount
+        .checked_rem(step)
+        .map_err(|e| ContractError::ConversionError {
+            message: format!("failed to derive remainder of [{amount}]: {e:?}"),
+        })?;
+    amount.checked_sub(remainder)?.to_ok()
+}
+
+/// Returns `None` when the rounded amount cannot be represented.
+fn round_up_to_step(amount: Uint128, step: Uint128) -> Result<Option<Uint128>, ContractError> {
+    let lower_amount = round_down_to_step(amount, step)?;
+    if lower_amount == amount {
+        return Some(amount).to_ok();
+    }
+    lower_amount.checked_add(step).ok().to_ok()
+}
+
+/// Renders an amount of coin in its decimal display form, placing the decimal point according to
+/// the given precision.  The fractional part is always padded to the full precision, so the amount
+/// `1234560` with a precision of `3` is rendered as `1234.560`.
+///
+/// # Parameters
+/// * `amount` The amount of coin, in base units.
+/// * `precision` The amount of decimal places represented in coin by the amount's denom.
+pub fn format_amount(amount: Uint128, precision: u64) -> Result<AmountDisplay, ContractError> {
+    let precision = usize::try_from(precision).map_err(|e| ContractError::ConversionError {
+        message: format!("precision [{precision}] is too large to format: {e:?}"),
+    })?;
+    let digits = amount.to_string();
+    // Left-padding guarantees at least one digit before the decimal point
+    let digits = format!("{digits:0>width$}", width = precision.saturating_add(1));
+    let (integer_part, fractional_part) = digits.split_at(digits.len() - precision);
+    AmountDisplay {
+        integer_part: integer_part.to_string(),
+        fractional_part: fractional_part.to_string(),
+        display: if fractional_part.is_empty() {
+            integer_part.to_string()
+        } else {
+            format!("{integer_part}.{fractional_part}")
+        },
+    }
+    .to_ok()
+}
+
+/// Converts an amount of coin in its decimal display form into base units, rejecting any input
+/// that [format_amount] would not produce for some amount, aside from omitted trailing zeroes.  The
+/// input must contain only digits and at most one decimal point, must have digits on both sides of
+/// any decimal point, cannot have leading zeroes, and cannot have more fractional digits than the
+/// precision.
+///
+/// # Parameters
+/// * `display` The amount of coin in decimal form, like `1234.56`.
+/// * `precision` The amount of decimal places represented in coin by the amount's denom.
+pub fn parse_amount(display: &str, precision: u64) -> Result<Uint128, ContractError> {
+    let invalid = |reason: &str| ContractError::ValidationError {
+        message: format!("amount [{display}] {reason}"),
+    };
+    let (integer_part, fractional_part) = match display.split_once('.') {
+        Some((integer_part, fractional_part)) => {
+            if fractional_part.is_empty() {
+                return invalid("must have digits after its decimal point").to_err();
+            }
+            (integer_part, fractional_part)
+        }
+        None => (display, ""),
+    };
+    if integer_part.is_empty() {
+        return invalid("must have digits before its decimal point").to_err();
+    }
+    if !integer_part
+        .chars()
+        .chain(fractional_part.chars())
+        .all(|c| c.is_ascii_digit())
+    {
+        return invalid("must only contain digits and a single decimal point").to_err();
+    }
+    if integer_part.len() > 1 && integer_part.starts_with('0') {
+        return invalid("cannot have leading zeroes").to_err();
+    }
+    if u64::try_from(fractional_part.len()).unwrap_or(u64::MAX) > precision {
+        return invalid(&format!(
+            "cannot have more than [{precision}] decimal places"
+        ))
+        .to_err();
+    }
+    let precision = usize::try_from(precision).map_err(|e| ContractError::ConversionError {
+        message: format!("precision [{precision}] is too large to parse: {e:?}"),
+    })?;
+    let digits = format!("{integer_part}{fractional_part:0<precision$}");
+    digits
+        .parse::<u128>()
+        .map(Uint128::new)
+        .map_err(|e| ContractError::ConversionError {
+            message: format!("amount [{display}] is too large to represent: {e:?}"),
+        })
+}
+
+/// Derives the factor that separates the source and target precisions: ten raised to the
+/// difference between them.  The difference is computed without casting either precision to a
+/// signed value, so precisions of any size produce an error rather than a wrapped result.
+fn get_precision_modifier(
+    source_precision: u64,
+    target_precision: u64,
+) -> Result<Uint128, ContractError> {
+    let precision_diff = u32::try_from(source_precision.abs_diff(target_precision)).map_err(|e| {
+        ContractError::ConversionError {
+            message: format!("source precision [{source_precision}] and target precision [{target_precision}] have too large a difference to convert: {e:?}"),
+        }
+    })?;
+    Uint128::new(10)
+        .checked_pow(precision_diff)
+        .map_err(|e| ContractError::ConversionError {
+            message: format!("source precision [{source_precision}] and target precision [{target_precision}] differ by [{precision_diff}] decimal places, which is too large a difference to convert: {e:?}"),
+        })
+}
+
 #[cfg(test)]
 pub mod tests {
     use crate::types::denom::Denom;
-    use crate::util::conversion_utils::convert_denom;
+    use crate::types::error::ContractError;
+    use crate::util::conversion_utils::{
+        convert_denom, format_amount, parse_amount, suggest_amounts,
+    };
+    use cosmwasm_std::Uint128;
 
     #[test]
     fn test_source_precision_greater_than_target_precision() {
-        let amount = 123456789;
+        let amount = Uint128::new(123456789);
         let source_denom = Denom::new("source", 4);
         let target_denom = Denom::new("target", 1);
         let very_large_result = convert_denom(amount, &source_denom, &target_denom)
             .expect("The conversion should succeed with valid inputs");
         assert_eq!(
-            123456, very_large_result.target_amount,
+            Uint128::new(123456), very_large_result.target_amount,
             "Value {amount}: The resulting amount should be all values that fit into the target destination type",
         );
         assert_eq!(
-            789, very_large_result.remainder,
+            Uint128::new(789), very_large_result.remainder,
             "Value {amount}: The remainder amount should equate to all precision that could not be converted",
         );
-        let amount = 1000;
+        let amount = Uint128::new(1000);
         let just_large_enough_result = convert_denom(amount, &source_denom, &target_denom)
             .expect("The conversion should succeed with valid inputs");
         assert_eq!(
-            1, just_large_enough_result.target_amount,
+            Uint128::new(1), just_large_enough_result.target_amount,
             "Value {amount}: The resulting amount should be just the value before the decimal place",
         );
         assert_eq!(
-            0, just_large_enough_result.remainder,
+            Uint128::new(0), just_large_enough_result.remainder,
             "Value {amount}: There should be no remainder because all values after the decimal place were zeroes",
         );
-        let amount = 1101;
+        let amount = Uint128::new(1101);
         let small_overflow_result = convert_denom(amount, &source_denom, &target_denom)
             .expect("The conversion should succeed with valid inputs");
         assert_eq!(
-            1, small_overflow_result.target_amount,
+            Uint128::new(1),
+            small_overflow_result.target_amount,
             "Value {amount}: The resulting amount should be the value before the decimal place",
         );
         assert_eq!(
-            101, small_overflow_result.remainder,
+            Uint128::new(101),
+            small_overflow_result.remainder,
             "Value {amount}: The remainder should properly contain the overflow",
         );
-        let amount = 123;
+        let amount = Uint128::new(123);
         let full_overflow_result = convert_denom(amount, &source_denom, &target_denom)
             .expect("The conversion should succeed with valid inputs");
         assert_eq!(
-            0, full_overflow_result.target_amount,
+            Uint128::new(0), full_overflow_result.target_amount,
             "Value {amount}: The resulting amount should be zero because all converted amounts were remainders",
         );
         assert_eq!(
-            123, full_overflow_result.remainder,
+            Uint128::new(123), full_overflow_result.remainder,
             "Value {amount}: The remainder should be the whole value due to overflow past precision conversion",
         );
-        let amount = 0;
+        let amount = Uint128::new(0);
         let zero_result = convert_denom(amount, &source_denom, &target_denom)
             .expect("The conversion should succeed with valid inputs");
         assert_eq!(
-            0, zero_result.target_amount,
+            Uint128::new(0),
+            zero_result.target_amount,
             "Value {amount}: The target amount should be zero because the initial value was zero",
         );
         assert_eq!(
-            0, zero_result.remainder,
+            Uint128::new(0),
+            zero_result.remainder,
             "Value {amount}: The remainder should be zero because the initial value was zero",
         );
     }
 
     #[test]
     fn test_source_precision_lower_than_target_precision() {
-        let amount = 123456789;
+        let amount = Uint128::new(123456789);
         let source_denom = Denom::new("source", 1);
         let target_denom = Denom::new("target", 4);
         let very_large_result = convert_denom(amount, &source_denom, &target_denom)
             .expect("The conversion should succeed with valid inputs");
         assert_eq!(
-            123456789000, very_large_result.target_amount,
+            Uint128::new(123456789000), very_large_result.target_amount,
             "Value {amount}: The target amount should have extra zeroes for the increased precision",
         );
         assert_eq!(
-            0, very_large_result.remainder,
+            Uint128::new(0), very_large_result.remainder,
             "Value {amount}: A conversion with lower source precision than target should never have a remainder",
         );
-        let amount = 2;
+        let amount = Uint128::new(2);
         let simple_result = convert_denom(amount, &source_denom, &target_denom)
             .expect("The conversion should succeed with valid inputs");
         assert_eq!(
-            2000, simple_result.target_amount,
+            Uint128::new(2000), simple_result.target_amount,
             "Value {amount}: The target amount should have extra zeroes for the increased precision",
         );
         assert_eq!(
-            0, simple_result.remainder,
+            Uint128::new(0), simple_result.remainder,
             "Value {amount}: A conversion with lower source precision than target should never have a remainder",
         );
-        let amount = 0;
+        let amount = Uint128::new(0);
         let zero_result = convert_denom(amount, &source_denom, &target_denom)
             .expect("The conversion should succeed with valid inputs");
         assert_eq!(
-            0, zero_result.target_amount,
+            Uint128::new(0),
+            zero_result.target_amount,
             "Value {amount}: The target amount should be zero because the input was zero",
         );
         assert_eq!(
-            0, zero_result.remainder,
+            Uint128::new(0), zero_result.remainder,
             "Value {amount}: A conversion with lower source precision than target should never have a remainder",
         );
     }
 
     #[test]
     fn test_source_precision_equal_to_target_precision() {
-        let amount = 123456789;
+        let amount = Uint128::new(123456789);
         let source_denom = Denom::new("source", 3);
         let target_denom = Denom::new("target", 3);
         let large_result = convert_denom(amount, &source_denom, &target_denom)
@@ -164,10 +355,11 @@ pub mod tests {
             "Value {amount}: The target amount should equate to the input because there is no precision diff",
         );
         assert_eq!(
-            0, large_result.remainder,
+            Uint128::new(0),
+            large_result.remainder,
             "Value {amount}: The remainder should be zero because no conversion was necessary",
         );
-        let amount = 6;
+        let amount = Uint128::new(6);
         let simple_result = convert_denom(amount, &source_denom, &target_denom)
             .expect("The conversion should succeed with valid inputs");
         assert_eq!(
@@ -175,36 +367,409 @@ pub mod tests {
             "Value {amount}: The target amount should equate to the input because there is no precision diff",
         );
         assert_eq!(
-            0, simple_result.remainder,
+            Uint128::new(0),
+            simple_result.remainder,
             "Value {amount}: The remainder should be zero because no conversion was necessary",
         );
-        let amount = 0;
+        let amount = Uint128::new(0);
         let zero_result = convert_denom(amount, &source_denom, &target_denom)
             .expect("The conversion should succeed with valid inputs");
         assert_eq!(
-            0, zero_result.target_amount,
+            Uint128::new(0),
+            zero_result.target_amount,
             "Value {amount}: The target amount should be zero because the input was zero",
         );
         assert_eq!(
-            0, zero_result.remainder,
+            Uint128::new(0),
+            zero_result.remainder,
             "Value {amount}: The remainder should be zero because the input was zero",
         );
     }
 
     #[test]
     fn test_example_use_case() {
-        let amount = 987123456;
+        let amount = Uint128::new(987123456);
         let source_denom = Denom::new("trading", 6);
         let target_denom = Denom::new("deposit", 2);
         let result = convert_denom(amount, &source_denom, &target_denom)
             .expect("The conversion should succeed with valid inputs");
         assert_eq!(
-            98712, result.target_amount,
+            Uint128::new(98712),
+            result.target_amount,
             "Input {amount}: Expected the proper target amount output from input",
         );
         assert_eq!(
-            3456, result.remainder,
+            Uint128::new(3456),
+            result.remainder,
             "Input {amount}: Expected the proper remainder amount from input",
         );
     }
+
+    #[test]
+    fn test_conversion_overflow_produces_an_error() {
+        let error = convert_denom(
+            Uint128::MAX,
+            &Denom::new("source", 1),
+            &Denom::new("target", 2),
+        )
+        .expect_err("a conversion that overflows should produce an error");
+        assert!(
+            matches!(error, ContractError::ConversionError { .. }),
+            "unexpected error emitted when a conversion overflows: {error:?}",
+        );
+    }
+
+    #[test]
+    fn test_largest_amounts_convert_without_overflowing() {
+        let conversion = convert_denom(
+            Uint128::MAX,
+            &Denom::new("source", 2),
+            &Denom::new("target", 1),
+        )
+        .expect("reducing the largest amount should never overflow");
+        assert_eq!(
+            (Uint128::MAX / Uint128::new(10), Uint128::new(5)),
+            (conversion.target_amount, conversion.remainder),
+            "the largest amount should be reduced by the precision difference",
+        );
+        let conversion = convert_denom(
+            Uint128::new(3),
+            &Denom::new("source", 0),
+            &Denom::new("target", 38),
+        )
+        .expect(
+            "an amount that fits after the largest representable precision increase should convert",
+        );
+        assert_eq!(
+            Uint128::new(3 * 10u128.pow(38)),
+            conversion.target_amount,
+            "the amount should be increased by the full precision difference",
+        );
+    }
+
+    #[test]
+    fn test_amount_adjacent_to_the_maximum_produces_an_error_when_increased() {
+        let error = convert_denom(
+            Uint128::MAX - Uint128::one(),
+            &Denom::new("source", 6),
+            &Denom::new("target", 7),
+        )
+        .expect_err("increasing an amount near the maximum should overflow");
+        assert!(
+            matches!(
+                &error,
+                ContractError::ConversionError { message }
+                    if message.contains(&format!("[{}]", u128::MAX - 1)) && message.contains("[10]")
+            ),
+            "the error should name the amount and the precision modifier: {error:?}",
+        );
+        let error = convert_denom(
+            Uint128::new(4),
+            &Denom::new("source", 0),
+            &Denom::new("target", 38),
+        )
+        .expect_err(
+            "an amount that exceeds the maximum after the precision increase should overflow",
+        );
+        assert!(
+            matches!(error, ContractError::ConversionError { .. }),
+            "unexpected error emitted when a conversion overflows: {error:?}",
+        );
+    }
+
+    #[test]
+    fn test_precision_difference_beyond_u128_produces_an_error() {
+        for (source_precision, target_precision) in [(0, 39), (39, 0), (2, 60), (60, 2)] {
+            let error = convert_denom(
+                Uint128::one(),
+                &Denom::new("source", source_precision),
+                &Denom::new("target", target_precision),
+            )
+            .expect_err(
+                "a precision difference that cannot be represented should produce an error",
+            );
+            assert!(
+                matches!(
+                    &error,
+                    ContractError::ConversionError { message }
+                        if message.contains(&format!("[{source_precision}]"))
+                            && message.contains(&format!("[{target_precision}]"))
+                ),
+                "the error should name both precisions: {error:?}",
+            );
+        }
+        for (source_precision, target_precision) in [(0, u64::MAX), (u64::MAX, 0), (u64::MAX, 1)] {
+            let error = convert_denom(
+                Uint128::one(),
+                &Denom::new("source", source_precision),
+                &Denom::new("target", target_precision),
+            )
+            .expect_err("an extreme precision difference should produce an error");
+            assert!(
+                matches!(error, ContractError::ConversionError { .. }),
+                "unexpected error emitted for an extreme precision difference: {error:?}",
+            );
+        }
+    }
+
+    #[test]
+    fn test_suggested_amounts_always_convert_without_a_remainder() {
+        let precision_pairs = [(6, 2), (2, 6), (3, 3), (4, 1), (1, 0)];
+        let amounts = (0..2500)
+            .chain([9999, 10000, 10001, 987123456, 1000000000000])
+            .map(Uint128::new);
+        for (source_precision, target_precision) in precision_pairs {
+            let source_denom = Denom::new("source", source_precision);
+            let target_denom = Denom::new("target", target_precision);
+            for amount in amounts.clone() {
+                let suggestion = suggest_amounts(amount, &source_denom, &target_denom, None, None)
+                    .expect("suggestions should be produced for valid inputs");
+                let mut suggested = vec![];
+                if let Some(lower) = suggestion.lower_amount {
+                    assert!(
+                        lower <= amount,
+                        "Value {amount}: the lower amount [{lower}] should not exceed the input",
+                    );
+                    assert_eq!(
+                        Some(amount - lower),
+                        suggestion.lower_delta,
+                        "Value {amount}: the lower delta should be the distance to the input",
+                    );
+                    suggested.push(lower);
+                }
+                let upper = suggestion
+                    .upper_amount
+                    .expect("an upper amount should exist for small inputs");
+                assert!(
+                    upper > amount,
+                    "Value {amount}: the upper amount [{upper}] should exceed the input",
+                );
+                assert_eq!(
+                    Some(upper - amount),
+                    suggestion.upper_delta,
+                    "Value {amount}: the upper delta should be the distance to the input",
+                );
+                suggested.push(upper);
+                for suggested_amount in suggested {
+                    let conversion = convert_denom(suggested_amount, &source_denom, &target_denom)
+                        .expect("suggested amounts should convert");
+                    assert!(
+                        conversion.remainder.is_zero() && !conversion.target_amount.is_zero(),
+                        "Value {amount}: the suggested amount [{suggested_amount}] should convert cleanly: {conversion:?}",
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_suggested_amounts_always_satisfy_the_limits() {
+        let precision_pairs = [(4, 2), (2, 4), (3, 3), (3, 1)];
+        let limits = [
+            (None, None),
+            (Some(1), None),
+            (Some(150), None),
+            (Some(201), None),
+            (None, Some(1)),
+            (None, Some(99)),
+            (None, Some(450)),
+            (Some(150), Some(450)),
+            (Some(201), Some(299)),
+            (Some(250), Some(250)),
+            (Some(201), Some(202)),
+        ];
+        for (source_precision, target_precision) in precision_pairs {
+            let source_denom = Denom::new("source", source_precision);
+            let target_denom = Denom::new("target", target_precision);
+            let is_valid = |amount: Uint128, minimum: Option<u128>, maximum: Option<u128>| {
+                let conversion = convert_denom(amount, &source_denom, &target_denom)
+                    .expect("amounts within the limits should convert");
+                conversion.remainder.is_zero()
+                    && !conversion.target_amount.is_zero()
+                    && !minimum.is_some_and(|minimum| amount.u128() < minimum)
+                    && !maximum.is_some_and(|maximum| amount.u128() > maximum)
+            };
+            for (minimum, maximum) in limits {
+                for amount in (0..700).chain([9999, 10000, 10001]) {
+                    let amount = Uint128::new(amount);
+                    let suggestion = suggest_amounts(
+                        amount,
+                        &source_denom,
+                        &target_denom,
+                        minimum.map(Uint128::new),
+                        maximum.map(Uint128::new),
+                    )
+                    .expect("suggestions should be produced for valid inputs");
+                    // The nearest valid amounts are found by scanning every nearby candidate, and
+                    // the suggestions must match them exactly
+                    let expected_lower = (1..=amount.u128())
+                        .rev()
+                        .map(Uint128::new)
+                        .find(|candidate| is_valid(*candidate, minimum, maximum));
+                    let expected_upper = (amount.u128() + 1..=amount.u128() + 1000)
+                        .map(Uint128::new)
+                        .find(|candidate| is_valid(*candidate, minimum, maximum));
+                    assert_eq!(
+                        expected_lower, suggestion.lower_amount,
+                        "Value {amount} with limits {minimum:?}-{maximum:?}: unexpected lower amount",
+                    );
+                    assert_eq!(
+                        expected_upper, suggestion.upper_amount,
+                        "Value {amount} with limits {minimum:?}-{maximum:?}: unexpected upper amount",
+                    );
+                    for suggested_amount in suggestion
+                        .lower_amount
+                        .into_iter()
+                        .chain(suggestion.upper_amount)
+                    {
+                        assert!(
+                            is_valid(suggested_amount, minimum, maximum),
+                            "Value {amount} with limits {minimum:?}-{maximum:?}: the suggested amount [{suggested_amount}] should convert cleanly within the limits",
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_suggested_amounts_are_the_nearest_valid_amounts() {
+        let source_denom = Denom::new("trading", 6);
+        let target_denom = Denom::new("deposit", 2);
+        let suggestion = suggest_amounts(
+            Uint128::new(1234567),
+            &source_denom,
+            &target_denom,
+            None,
+            None,
+        )
+        .expect("suggestions should be produced for valid inputs");
+        assert_eq!(
+            Some(Uint128::new(1230000)),
+            suggestion.lower_amount,
+            "the remainder should be trimmed from the lower amount",
+        );
+        assert_eq!(
+            Some(Uint128::new(1240000)),
+            suggestion.upper_amount,
+            "the upper amount should be the next convertible amount",
+        );
+        let suggestion =
+            suggest_amounts(Uint128::new(9999), &source_denom, &target_denom, None, None)
+                .expect("suggestions should be produced for valid inputs");
+        assert_eq!(
+            None, suggestion.lower_amount,
+            "no lower amount should be suggested when the input cannot convert",
+        );
+        assert_eq!(
+            Some(Uint128::new(10000)),
+            suggestion.upper_amount,
+            "the smallest convertible amount should be suggested above the input",
+        );
+        let suggestion = suggest_amounts(Uint128::MAX, &target_denom, &source_denom, None, None)
+            .expect("suggestions should be produced for valid inputs");
+        assert_eq!(
+            None, suggestion.upper_amount,
+            "no upper amount should be suggested when it cannot be represented",
+        );
+    }
+
+    #[test]
+    fn test_format_amount_pads_to_the_precision() {
+        for (amount, precision, integer_part, fractional_part, display) in [
+            (1234560, 3, "1234", "560", "1234.560"),
+            (5, 3, "0", "005", "0.005"),
+            (0, 2, "0", "00", "0.00"),
+            (1234, 0, "1234", "", "1234"),
+        ] {
+            let formatted = format_amount(Uint128::new(amount), precision)
+                .expect("formatting should succeed for valid inputs");
+            assert_eq!(
+                integer_part, formatted.integer_part,
+                "unexpected integer part"
+            );
+            assert_eq!(
+                fractional_part, formatted.fractional_part,
+                "unexpected fractional part",
+            );
+            assert_eq!(display, formatted.display, "unexpected display");
+        }
+    }
+
+    #[test]
+    fn test_parse_amount_inverts_format_amount() {
+        for (display, precision, amount) in [
+            ("1234.560", 3, 1234560),
+            ("1234.56", 3, 1234560),
+            ("0.005", 3, 5),
+            ("0", 2, 0),
+            ("1234", 0, 1234),
+        ] {
+            assert_eq!(
+                Uint128::new(amount),
+                parse_amount(display, precision).expect("parsing should succeed for valid inputs"),
+                "unexpected amount parsed from [{display}]",
+            );
+        }
+        for amount in [Uint128::zero(), Uint128::new(1000001), Uint128::MAX] {
+            assert_eq!(
+                amount,
+                parse_amount(
+                    &format_amount(amount, 6)
+                        .expect("formatting should succeed")
+                        .display,
+                    6,
+                )
+                .expect("a formatted amount should parse"),
+                "a formatted amount should parse to the original amount",
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_amount_rejects_malformed_input() {
+        for (display, expected_message) in [
+            ("", "amount [] must have digits before its decimal point"),
+            (
+                ".5",
+                "amount [.5] must have digits before its decimal point",
+            ),
+            ("1.", "amount [1.] must have digits after its decimal point"),
+            (
+                "1.2.3",
+                "amount [1.2.3] must only contain digits and a single decimal point",
+            ),
+            (
+                "-1",
+                "amount [-1] must only contain digits and a single decimal point",
+            ),
+            (
+                " 1",
+                "amount [ 1] must only contain digits and a single decimal point",
+            ),
+            (
+                "1e3",
+                "amount [1e3] must only contain digits and a single decimal point",
+            ),
+            ("01.5", "amount [01.5] cannot have leading zeroes"),
+            (
+                "1.2345",
+                "amount [1.2345] cannot have more than [3] decimal places",
+            ),
+        ] {
+            let error =
+                parse_amount(display, 3).expect_err("malformed input should cause an error");
+            assert_eq!(
+                format!("validation failed: {expected_message}"),
+                error.to_string(),
+                "unexpected error message encountered",
+            );
+        }
+        let error = parse_amount(&format!("{}0", u128::MAX), 0)
+            .expect_err("an amount that cannot be represented should cause an error");
+        assert!(
+            matches!(error, ContractError::ConversionError { .. }),
+            "unexpected error encountered: {error:?}",
+        );
+    }
 }
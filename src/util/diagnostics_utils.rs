@@ -0,0 +1,417 @@
+use crate::store::contract_state::ContractStateV1;
+use crate::types::denom::{Denom, IbcDenomTrace};
+use crate::types::diagnostics::{AccessGrantMsg, AddAccessMsg, DiagnosticCheck, RequiredGrant};
+use crate::types::error::ContractError;
+use crate::util::provenance_utils::{
+    check_ibc_denom_trace, find_missing_marker_permissions, get_marker_account,
+    DEPOSIT_MARKER_REQUIRED_ACCESS, TRADING_MARKER_REQUIRED_ACCESS,
+};
+use cosmwasm_std::{Deps, Env};
+use provwasm_std::types::provenance::marker::v1::{Access, MarkerAccount, MarkerStatus};
+use provwasm_std::types::provenance::name::v1::NameQuerier;
+use result_extensions::ResultExtensions;
+
+/// Verifies that each external dependency of the contract is in the state required for the trade
+/// routes to function, returning one [DiagnosticCheck] per verification.  The deposit marker's IBC
+/// trace is only verified when an [expected trace](ContractStateV1#expected_ibc_trace) is configured.  Failures to query a
+/// dependency are reported as failed checks rather than errors, so this function always produces
+/// a full set of findings.
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `contract_state` The current contract state, which defines the markers to verify.
+pub fn run_diagnostics(
+    deps: &Deps,
+    env: &Env,
+    contract_state: &ContractStateV1,
+) -> Vec<DiagnosticCheck> {
+    let mut checks = vec![];
+    for (check_prefix, denom, required_permissions) in required_marker_access(contract_state) {
+        checks.append(&mut check_marker(
+            deps,
+            env,
+            check_prefix,
+            denom,
+            required_permissions,
+        ));
+    }
+    if let Some(expected_trace) = &contract_state.expected_ibc_trace {
+        checks.push(check_deposit_ibc_trace(
+            deps,
+            &contract_state.deposit_marker,
+            expected_trace,
+        ));
+    }
+    checks.push(check_name_binding(deps, env));
+    checks
+}
+
+/// Determines the marker access grants that the contract lacks for the trade routes to function,
+/// using the same permission diff as the marker grant checks of [run_diagnostics], so that the two
+/// never disagree.  Each returned grant carries a ready-to-encode `MsgAddAccessRequest` that adds
+/// only the missing permissions.  An empty result means that no grants are needed.
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `contract_state` The current contract state, which defines the markers to inspect.
+pub fn find_required_grants(
+    deps: &Deps,
+    env: &Env,
+    contract_state: &ContractStateV1,
+) -> Result<Vec<RequiredGrant>, ContractError> {
+    let mut grants = vec![];
+    for (_, denom, required_permissions) in required_marker_access(contract_state) {
+        let marker = get_marker_account(deps, &denom.name)?;
+        let missing_permissions = find_missing_marker_permissions(
+            &marker,
+            env.contract.address.as_str(),
+            required_permissions,
+        )
+        .into_iter()
+        .map(|permission| permission.as_str_name().to_string())
+        .collect::<Vec<String>>();
+        if missing_permissions.is_empty() {
+            continue;
+        }
+        grants.push(RequiredGrant {
+            marker_denom: denom.name.to_owned(),
+            missing_permissions: missing_permissions.to_owned(),
+            type_url: "/provenance.marker.v1.MsgAddAccessRequest".to_string(),
+            msg: AddAccessMsg {
+                denom: denom.name.to_owned(),
+                administrator: find_marker_administrator(&marker),
+                access: vec![AccessGrantMsg {
+                    address: env.contract.address.to_string(),
+                    permissions: missing_permissions,
+                }],
+            },
+        });
+    }
+    grants.to_ok()
+}
+
+/// Pairs each of the contract's markers with the permissions that the contract requires on it,
+/// along with the prefix of the diagnostic checks that verify it.
+fn required_marker_access(
+    contract_state: &ContractStateV1,
+) -> [(&'static str, &Denom, &'static [Access]); 2] {
+    [
+        (
+            "deposit_marker",
+            &contract_state.deposit_marker,
+            &DEPOSIT_MARKER_REQUIRED_ACCESS,
+        ),
+        (
+            "trading_marker",
+            &contract_state.trading_marker,
+            &TRADING_MARKER_REQUIRED_ACCESS,
+        ),
+    ]
+}
+
+fn find_marker_administrator(marker: &MarkerAccount) -> String {
+    // Only the marker's manager or an account with admin access can add access to a marker
+    if !marker.manager.is_empty() {
+        return marker.manager.to_owned();
+    }
+    marker
+        .access_control
+        .iter()
+        .find(|grant| grant.permissions.contains(&(Access::Admin as i32)))
+        .map(|grant| grant.address.to_owned())
+        .unwrap_or_default()
+}
+
+fn check_marker(
+    deps: &Deps,
+    env: &Env,
+    check_prefix: &str,
+    denom: &Denom,
+    required_permissions: &[Access],
+) -> Vec<DiagnosticCheck> {
+    let status_check = format!("{check_prefix}_status");
+    let grants_check = format!("{check_prefix}_grants");
+    let marker = match get_marker_account(deps, &denom.name) {
+        Ok(marker) => marker,
+        Err(e) => {
+            let details = format!("unable to load marker [{}]: {e}", denom.name);
+            return vec![
+                DiagnosticCheck::fail(status_check, &details),
+                DiagnosticCheck::fail(grants_check, &details),
+            ];
+        }
+    };
+    let status = if marker.status == MarkerStatus::Active as i32 {
+        DiagnosticCheck::pass(status_check, format!("marker [{}] is active", denom.name))
+    } else {
+        DiagnosticCheck::fail(
+            status_check,
+            format!(
+                "marker [{}] has status [{}]",
+                denom.name,
+                MarkerStatus::try_from(marker.status)
+                    .map(|status| status.as_str_name().to_string())
+                    .unwrap_or_else(|_| marker.status.to_string()),
+            ),
+        )
+    };
+    let missing_permissions = find_missing_marker_permissions(
+        &marker,
+        env.contract.address.as_str(),
+        required_permissions,
+    )
+    .into_iter()
+    .map(|permission| permission.as_str_name())
+    .collect::<Vec<&str>>();
+    let grants = if missing_permissions.is_empty() {
+        DiagnosticCheck::pass(
+            grants_check,
+            format!(
+                "contract holds all required permissions on marker [{}]",
+                denom.name
+            ),
+        )
+    } else {
+        DiagnosticCheck::fail(
+            grants_check,
+            format!(
+                "contract is missing permissions [{}] on marker [{}]",
+                missing_permissions.join(", "),
+                denom.name,
+            ),
+        )
+    };
+    vec![status, grants]
+}
+
+fn check_deposit_ibc_trace(
+    deps: &Deps,
+    deposit_marker: &Denom,
+    expected_trace: &IbcDenomTrace,
+) -> DiagnosticCheck {
+    let check = "deposit_ibc_trace";
+    match check_ibc_denom_trace(deps, &deposit_marker.name, expected_trace) {
+        Ok(()) => DiagnosticCheck::pass(
+            check,
+            format!(
+                "denom [{}] traces to [{}/{}]",
+                deposit_marker.name, expected_trace.path, expected_trace.base_denom,
+            ),
+        ),
+        Err(e) => DiagnosticCheck::fail(check, e.to_string()),
+    }
+}
+
+fn check_name_binding(deps: &Deps, env: &Env) -> DiagnosticCheck {
+    let check = "name_binding";
+    match NameQuerier::new(&deps.querier).reverse_lookup(env.contract.address.to_string(), None) {
+        Ok(response) if !response.name.is_empty() => DiagnosticCheck::pass(
+            check,
+            format!("contract is bound to names [{}]", response.name.join(", ")),
+        ),
+        Ok(_) => DiagnosticCheck::fail(check, "no names are bound to the contract address"),
+        Err(e) => DiagnosticCheck::fail(check, format!("unable to look up bound names: {e}")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::contract_state::get_contract_state_v1;
+    use crate::test::test_ibc::{mock_denom_trace_response, TEST_IBC_DENOM};
+    use crate::test::test_instantiate::{test_instantiate, test_instantiate_with_msg};
+    use crate::test::test_markers::mock_missing_marker_response;
+    use crate::types::denom::{Denom, IbcDenomTrace};
+    use crate::types::msg::InstantiateMsg;
+    use crate::util::diagnostics_utils::run_diagnostics;
+    use cosmwasm_std::testing::{mock_env, MockApi, MOCK_CONTRACT_ADDR};
+    use prost::Message;
+    use provwasm_mocks::{mock_provenance_dependencies_with_custom_querier, MockProvenanceQuerier};
+    use provwasm_std::shim::Any;
+    use provwasm_std::types::cosmos::auth::v1beta1::BaseAccount;
+    use provwasm_std::types::provenance::marker::v1::{
+        Access, AccessGrant, MarkerAccount, MarkerStatus, MarkerType, QueryMarkerRequest,
+        QueryMarkerResponse,
+    };
+    use provwasm_std::types::provenance::name::v1::{
+        QueryReverseLookupRequest, QueryReverseLookupResponse,
+    };
+
+    fn mock_marker(querier: &mut MockProvenanceQuerier, status: MarkerStatus, grants: Vec<Access>) {
+        QueryMarkerRequest::mock_response(
+            querier,
+            QueryMarkerResponse {
+                marker: Some(Any {
+                    type_url: "/provenance.marker.v1.MarkerAccount".to_string(),
+                    value: MarkerAccount {
+                        base_account: Some(BaseAccount {
+                            address: MockApi::default().addr_make("marker").to_string(),
+                            pub_key: None,
+                            account_number: 312,
+                            sequence: 68,
+                        }),
+                        manager: "some-manager".to_string(),
+                        access_control: vec![AccessGrant {
+                            address: MOCK_CONTRACT_ADDR.to_string(),
+                            permissions: grants.into_iter().map(|grant| grant as i32).collect(),
+                        }],
+                        status: status as i32,
+                        denom: "marker".to_string(),
+                        supply: "100".to_string(),
+                        marker_type: MarkerType::Restricted as i32,
+                        supply_fixed: false,
+                        allow_governance_control: false,
+                        allow_forced_transfer: false,
+                        required_attributes: vec![],
+                    }
+                    .encode_to_vec(),
+                }),
+            },
+        );
+    }
+
+    #[test]
+    fn healthy_dependencies_should_pass_all_checks() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        mock_marker(
+            &mut querier,
+            MarkerStatus::Active,
+            vec![
+                Access::Mint,
+                Access::Burn,
+                Access::Withdraw,
+                Access::Transfer,
+            ],
+        );
+        QueryReverseLookupRequest::mock_response(
+            &mut querier,
+            QueryReverseLookupResponse {
+                name: vec!["contract.name".to_string()],
+                pagination: None,
+            },
+        );
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        test_instantiate(&mut deps);
+        let contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        let checks = run_diagnostics(&deps.as_ref(), &mock_env(), &contract_state);
+        assert_eq!(5, checks.len(), "expected all five checks to be performed");
+        assert!(
+            checks.iter().all(|check| check.passed),
+            "all checks should pass when dependencies are healthy: {checks:?}",
+        );
+    }
+
+    #[test]
+    fn unhealthy_dependencies_should_fail_checks() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryReverseLookupRequest::mock_response(
+            &mut querier,
+            QueryReverseLookupResponse {
+                name: vec![],
+                pagination: None,
+            },
+        );
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        test_instantiate(&mut deps);
+        // Instantiation requires every grant, so the markers degrade after the contract is created
+        mock_marker(
+            &mut deps.querier,
+            MarkerStatus::Finalized,
+            vec![Access::Transfer],
+        );
+        let contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        let checks = run_diagnostics(&deps.as_ref(), &mock_env(), &contract_state);
+        let find = |name: &str| {
+            checks
+                .iter()
+                .find(|check| check.name == name)
+                .unwrap_or_else(|| panic!("expected the [{name}] check to be performed"))
+        };
+        assert!(
+            !find("deposit_marker_status").passed,
+            "a non-active deposit marker should fail its status check",
+        );
+        assert!(
+            find("deposit_marker_grants").passed,
+            "the deposit marker grants should pass because only transfer is required",
+        );
+        let trading_grants = find("trading_marker_grants");
+        assert!(
+            !trading_grants.passed,
+            "the trading marker grants should fail when permissions are missing",
+        );
+        assert_eq!(
+            "contract is missing permissions [ACCESS_MINT, ACCESS_BURN, ACCESS_WITHDRAW] on marker [trading]",
+            trading_grants.details,
+            "the missing permissions should be described",
+        );
+        assert!(
+            !find("name_binding").passed,
+            "the name binding check should fail when no names are bound",
+        );
+    }
+
+    #[test]
+    fn missing_dependencies_should_fail_checks_without_erroring() {
+        let mut deps =
+            mock_provenance_dependencies_with_custom_querier(MockProvenanceQuerier::new(&[]));
+        test_instantiate(&mut deps);
+        mock_missing_marker_response(&mut deps.querier);
+        let contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        let checks = run_diagnostics(&deps.as_ref(), &mock_env(), &contract_state);
+        assert_eq!(5, checks.len(), "expected all five checks to be reported");
+        assert!(
+            checks.iter().all(|check| !check.passed),
+            "all checks should fail when no dependencies can be queried: {checks:?}",
+        );
+    }
+
+    #[test]
+    fn configured_ibc_trace_should_be_verified() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        mock_denom_trace_response(&mut querier, "transfer/channel-0", "uusdc");
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                deposit_marker: Denom::new(TEST_IBC_DENOM, 6),
+                expected_ibc_trace: Some(IbcDenomTrace {
+                    base_denom: "uusdc".to_string(),
+                    path: "transfer/channel-0".to_string(),
+                }),
+                ..InstantiateMsg::default()
+            },
+        );
+        let mut contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        let checks = run_diagnostics(&deps.as_ref(), &mock_env(), &contract_state);
+        assert_eq!(6, checks.len(), "expected the ibc trace check to be added");
+        let trace_check = checks
+            .iter()
+            .find(|check| check.name == "deposit_ibc_trace")
+            .expect("expected the ibc trace check to be performed");
+        assert!(
+            trace_check.passed,
+            "the ibc trace check should pass when the trace matches: {trace_check:?}",
+        );
+        contract_state.expected_ibc_trace = Some(IbcDenomTrace {
+            base_denom: "uatom".to_string(),
+            path: "transfer/channel-0".to_string(),
+        });
+        let checks = run_diagnostics(&deps.as_ref(), &mock_env(), &contract_state);
+        assert!(
+            checks
+                .iter()
+                .any(|check| check.name == "deposit_ibc_trace" && !check.passed),
+            "the ibc trace check should fail when the trace differs: {checks:?}",
+        );
+    }
+}
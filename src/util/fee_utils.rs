@@ -0,0 +1,202 @@
+use crate::store::contract_state::ContractStateV1;
+use crate::store::runtime_tunables::RuntimeTunables;
+use crate::types::error::ContractError;
+use crate::types::msg_fee::{FeeDiscount, MsgFee, FULL_FEE_BPS};
+use crate::util::provenance_utils::search_account_attributes;
+use cosmwasm_std::{Addr, Coin, Deps};
+
+/// The outcome of checking a trade sender's attributes via [check_sender_attributes].
+#[derive(Debug)]
+pub struct SenderAttributeCheck {
+    /// The result of verifying that the sender holds every attribute required by the trade route.
+    pub requirement: Result<(), ContractError>,
+    /// The msg fee to assess on the trade after any discount.  `None` when no fee is configured
+    /// or the discount waives the fee entirely.
+    pub msg_fee: Option<MsgFee>,
+    /// The discount applied to the trade's msg fee, if the sender holds any discount attribute.
+    pub fee_discount: Option<FeeDiscount>,
+}
+
+/// Verifies that a trade's sender holds every attribute required by the trade route, and
+/// determines the msg fee to assess on the trade.  The sender's attributes are paged through once
+/// for both purposes, and the [fee discount](ContractStateV1#fee_discounts) attributes are only
+/// searched for when a [msg fee](ContractStateV1#msg_fee) is configured.
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `contract_state` The current contract state, which dictates the fee and its discounts.
+/// * `sender` The account requesting the trade.
+/// * `required_attributes` The attribute names required by the trade route.
+/// * `tunables` The current [runtime tunables](RuntimeTunables), which dictate the size and
+/// amount of attribute pages fetched.
+pub fn check_sender_attributes(
+    deps: &Deps,
+    contract_state: &ContractStateV1,
+    sender: &Addr,
+    required_attributes: &[String],
+    tunables: &RuntimeTunables,
+) -> SenderAttributeCheck {
+    let mut searched_attributes = required_attributes.to_vec();
+    if contract_state.msg_fee.is_some() {
+        for discount in contract_state.fee_discounts.iter() {
+            if !searched_attributes.contains(&discount.attribute) {
+                searched_attributes.push(discount.attribute.to_owned());
+            }
+        }
+    }
+    match search_account_attributes(deps, sender.as_str(), &searched_attributes, tunables) {
+        Ok(search) => {
+            let fee_discount = contract_state
+                .msg_fee
+                .as_ref()
+                .and_then(|_| best_fee_discount(&contract_state.fee_discounts, &search.found));
+            SenderAttributeCheck {
+                requirement: search.check_has_all(required_attributes, tunables),
+                msg_fee: contract_state
+                    .msg_fee
+                    .as_ref()
+                    .and_then(|msg_fee| apply_fee_discount(msg_fee, fee_discount)),
+                fee_discount: fee_discount.cloned(),
+            }
+        }
+        Err(e) => SenderAttributeCheck {
+            requirement: Err(e),
+            msg_fee: contract_state.msg_fee.to_owned(),
+            fee_discount: None,
+        },
+    }
+}
+
+/// Selects the discount with the largest reduction among those whose attribute is held.  When
+/// multiple held discounts share the largest reduction, the first configured discount is selected.
+///
+/// # Parameters
+/// * `fee_discounts` The configured discounts.
+/// * `held_attributes` The attribute names held by the sender.
+pub fn best_fee_discount<'a>(
+    fee_discounts: &'a [FeeDiscount],
+    held_attributes: &[String],
+) -> Option<&'a FeeDiscount> {
+    fee_discounts
+        .iter()
+        .filter(|discount| held_attributes.contains(&discount.attribute))
+        .fold(None, |best: Option<&FeeDiscount>, discount| match best {
+            Some(best) if best.discount_bps_off >= discount.discount_bps_off => Some(best),
+            _ => Some(discount),
+        })
+}
+
+/// Reduces the msg fee by the given discount, rounding the discounted amount down.  Returns
+/// `None` when nothing remains to be assessed.
+///
+/// # Parameters
+/// * `msg_fee` The configured msg fee.
+/// * `fee_discount` The discount to apply, if any.
+pub fn apply_fee_discount(msg_fee: &MsgFee, fee_discount: Option<&FeeDiscount>) -> Option<MsgFee> {
+    let Some(fee_discount) = fee_discount else {
+        return Some(msg_fee.to_owned());
+    };
+    let amount = msg_fee.amount.amount.multiply_ratio(
+        FULL_FEE_BPS.saturating_sub(fee_discount.discount_bps_off),
+        FULL_FEE_BPS,
+    );
+    if amount.is_zero() {
+        return None;
+    }
+    Some(MsgFee {
+        amount: Coin::new(amount, &msg_fee.amount.denom),
+        recipient: msg_fee.recipient.to_owned(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::msg_fee::{FeeDiscount, MsgFee};
+    use crate::util::fee_utils::{apply_fee_discount, best_fee_discount};
+    use cosmwasm_std::coin;
+
+    fn discount(attribute: &str, discount_bps_off: u16) -> FeeDiscount {
+        FeeDiscount {
+            attribute: attribute.to_string(),
+            discount_bps_off,
+        }
+    }
+
+    #[test]
+    fn best_fee_discount_should_select_the_largest_held_discount() {
+        let discounts = vec![
+            discount("loyalty.gold.pb", 5000),
+            discount("loyalty.platinum.pb", 10000),
+            discount("loyalty.silver.pb", 2500),
+        ];
+        assert_eq!(
+            None,
+            best_fee_discount(&discounts, &["unrelated.pb".to_string()]),
+            "no discount should be selected when no discount attribute is held",
+        );
+        assert_eq!(
+            Some(&discounts[0]),
+            best_fee_discount(
+                &discounts,
+                &[
+                    "loyalty.silver.pb".to_string(),
+                    "loyalty.gold.pb".to_string()
+                ],
+            ),
+            "the largest held discount should be selected",
+        );
+        assert_eq!(
+            Some(&discounts[1]),
+            best_fee_discount(
+                &discounts,
+                &[
+                    "loyalty.gold.pb".to_string(),
+                    "loyalty.platinum.pb".to_string(),
+                ],
+            ),
+            "the largest held discount should be selected regardless of order",
+        );
+        let tied = vec![discount("first.pb", 5000), discount("second.pb", 5000)];
+        assert_eq!(
+            Some(&tied[0]),
+            best_fee_discount(&tied, &["second.pb".to_string(), "first.pb".to_string()]),
+            "the first configured discount should win a tie",
+        );
+    }
+
+    #[test]
+    fn apply_fee_discount_should_reduce_the_fee() {
+        let msg_fee = MsgFee {
+            amount: coin(101, "nhash"),
+            recipient: "recipient".to_string(),
+        };
+        assert_eq!(
+            Some(msg_fee.to_owned()),
+            apply_fee_discount(&msg_fee, None),
+            "the fee should be unchanged without a discount",
+        );
+        assert_eq!(
+            Some(coin(50, "nhash")),
+            apply_fee_discount(&msg_fee, Some(&discount("loyalty.gold.pb", 5000)))
+                .map(|msg_fee| msg_fee.amount),
+            "the discounted fee should be rounded down",
+        );
+        assert_eq!(
+            None,
+            apply_fee_discount(&msg_fee, Some(&discount("loyalty.platinum.pb", 10000))),
+            "a full discount should waive the fee",
+        );
+        assert_eq!(
+            None,
+            apply_fee_discount(
+                &MsgFee {
+                    amount: coin(1, "nhash"),
+                    recipient: "recipient".to_string(),
+                },
+                Some(&discount("loyalty.silver.pb", 2500)),
+            ),
+            "a fee discounted below one unit should be waived",
+        );
+    }
+}
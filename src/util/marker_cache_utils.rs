@@ -0,0 +1,290 @@
+use crate::store::contract_state::ContractStateV1;
+use crate::store::marker_address_cache::{
+    get_marker_address_cache, set_marker_address_cache, MarkerAddressCache,
+};
+use crate::store::runtime_tunables::RuntimeTunables;
+use crate::types::error::ContractError;
+use crate::util::provenance_utils::get_marker_address_for_denom;
+use cosmwasm_std::{Addr, Deps, DepsMut};
+use result_extensions::ResultExtensions;
+
+/// Resolves the address of the trading marker's account, preferring the [cached address](MarkerAddressCache)
+/// and only querying the marker module when no cache has been populated yet.
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `contract_state` The current contract state, which names the trading marker.
+pub fn get_trading_marker_address(
+    deps: &Deps,
+    contract_state: &ContractStateV1,
+) -> Result<String, ContractError> {
+    match get_marker_address_cache(deps.storage)? {
+        Some(cache) => cache.trading_marker_address.to_string().to_ok(),
+        None => get_marker_address_for_denom(deps, &contract_state.trading_marker.name),
+    }
+}
+
+/// Prepares the [marker address cache](MarkerAddressCache) for a withdraw.  An empty cache is
+/// populated from the marker module.  Otherwise, when the [verify_marker_cache_every_n_trades](RuntimeTunables#verify_marker_cache_every_n_trades)
+/// tunable is set, the withdraw is counted and every Nth withdraw re-queries the marker to compare
+/// its address against the cache, repairing the cache on a mismatch.  Returns a warning describing
+/// the repair when one occurs.
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `contract_state` The current contract state, which names the trading marker.
+/// * `tunables` The current runtime tunables, which dictate how often the cache is verified.
+pub fn maintain_marker_address_cache(
+    deps: DepsMut,
+    contract_state: &ContractStateV1,
+    tunables: &RuntimeTunables,
+) -> Result<Option<String>, ContractError> {
+    let Some(cache) = get_marker_address_cache(deps.storage)? else {
+        refresh_marker_address_cache(deps, contract_state)?;
+        return None.to_ok();
+    };
+    let Some(interval) = tunables.verify_marker_cache_every_n_trades else {
+        return None.to_ok();
+    };
+    let trades_since_verification = cache.trades_since_verification.saturating_add(1);
+    if trades_since_verification < interval {
+        set_marker_address_cache(
+            deps.storage,
+            &MarkerAddressCache {
+                trades_since_verification,
+                ..cache
+            },
+        )?;
+        return None.to_ok();
+    }
+    let (previous_address, current_address) = refresh_marker_address_cache(deps, contract_state)?;
+    match previous_address {
+        Some(previous_address) if previous_address != current_address => Some(format!(
+            "cached trading marker address [{previous_address}] did not match the marker's address [{current_address}] and was repaired",
+        )),
+        _ => None,
+    }
+    .to_ok()
+}
+
+/// Queries the trading marker's current address and stores it as the [marker address cache](MarkerAddressCache),
+/// resetting the count of withdraws since the cache was verified.  Returns the previously cached
+/// address, if any, alongside the newly cached address.
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `contract_state` The current contract state, which names the trading marker.
+pub fn refresh_marker_address_cache(
+    deps: DepsMut,
+    contract_state: &ContractStateV1,
+) -> Result<(Option<Addr>, Addr), ContractError> {
+    let previous_address =
+        get_marker_address_cache(deps.storage)?.map(|cache| cache.trading_marker_address);
+    // The queried address has already been validated, so it can be stored without re-validation
+    let current_address = Addr::unchecked(get_marker_address_for_denom(
+        &deps.as_ref(),
+        &contract_state.trading_marker.name,
+    )?);
+    set_marker_address_cache(
+        deps.storage,
+        &MarkerAddressCache {
+            trading_marker_address: current_address.to_owned(),
+            trades_since_verification: 0,
+        },
+    )?;
+    (previous_address, current_address).to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::contract_state::get_contract_state_v1;
+    use crate::store::marker_address_cache::{
+        get_marker_address_cache, set_marker_address_cache, MarkerAddressCache,
+    };
+    use crate::store::runtime_tunables::RuntimeTunables;
+    use crate::test::test_constants::DEFAULT_TRADING_DENOM_NAME;
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::test::test_markers::{mock_marker_response, test_marker_account};
+    use crate::util::marker_cache_utils::{
+        get_trading_marker_address, maintain_marker_address_cache, refresh_marker_address_cache,
+    };
+    use cosmwasm_std::testing::MockApi;
+    use cosmwasm_std::Storage;
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    fn verify_every(interval: u64) -> RuntimeTunables {
+        RuntimeTunables {
+            verify_marker_cache_every_n_trades: Some(interval),
+            ..RuntimeTunables::default()
+        }
+    }
+
+    #[test]
+    fn cached_address_should_be_used_without_querying_the_marker() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        let cached_address = deps.api.addr_make("cached-marker");
+        set_marker_address_cache(
+            &mut deps.storage,
+            &MarkerAddressCache {
+                trading_marker_address: cached_address.to_owned(),
+                trades_since_verification: 0,
+            },
+        )
+        .expect("setting the cache should succeed");
+        // The marker responds with a different address, so any marker query would be detected
+        assert_eq!(
+            cached_address.as_str(),
+            get_trading_marker_address(&deps.as_ref(), &contract_state)
+                .expect("the cached address should be returned"),
+            "the cached address should be used",
+        );
+        assert_eq!(
+            None,
+            maintain_marker_address_cache(
+                deps.as_mut(),
+                &contract_state,
+                &RuntimeTunables::default()
+            )
+            .expect("an unverified cache should not require a marker query"),
+            "no warning should be produced when the cache is not verified",
+        );
+    }
+
+    #[test]
+    fn missing_cache_should_be_populated_from_the_marker() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        // Contracts instantiated before the cache was introduced have no cache
+        deps.storage.remove(b"marker_address_cache");
+        mock_marker_response(
+            &mut deps.querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        let contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        assert_eq!(
+            MockApi::default().addr_make("marker").as_str(),
+            get_trading_marker_address(&deps.as_ref(), &contract_state)
+                .expect("the marker should be queried when no cache exists"),
+            "the queried address should be returned",
+        );
+        assert_eq!(
+            None,
+            maintain_marker_address_cache(
+                deps.as_mut(),
+                &contract_state,
+                &RuntimeTunables::default()
+            )
+            .expect("populating the cache should succeed"),
+            "populating the cache should not produce a warning",
+        );
+        assert_eq!(
+            Some(MarkerAddressCache {
+                trading_marker_address: MockApi::default().addr_make("marker"),
+                trades_since_verification: 0,
+            }),
+            get_marker_address_cache(&deps.storage).expect("the cache should load"),
+            "the queried address should be cached",
+        );
+    }
+
+    #[test]
+    fn cache_should_be_verified_every_n_trades() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        mock_marker_response(
+            &mut deps.querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        let contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        let stale_address = deps.api.addr_make("stale-marker");
+        set_marker_address_cache(
+            &mut deps.storage,
+            &MarkerAddressCache {
+                trading_marker_address: stale_address.to_owned(),
+                trades_since_verification: 0,
+            },
+        )
+        .expect("setting the cache should succeed");
+        for expected_count in 1..3 {
+            assert_eq!(
+                None,
+                maintain_marker_address_cache(deps.as_mut(), &contract_state, &verify_every(3))
+                    .expect("counting a trade should succeed"),
+                "no warning should be produced before verification is due",
+            );
+            let cache = get_marker_address_cache(&deps.storage)
+                .expect("the cache should load")
+                .expect("the cache should exist");
+            assert_eq!(
+                stale_address, cache.trading_marker_address,
+                "the cache should be used unchanged before verification is due",
+            );
+            assert_eq!(
+                expected_count, cache.trades_since_verification,
+                "each trade should be counted",
+            );
+        }
+        let warning =
+            maintain_marker_address_cache(deps.as_mut(), &contract_state, &verify_every(3))
+                .expect("verifying the cache should succeed")
+                .expect("a warning should be produced when the cache is repaired");
+        assert_eq!(
+            format!(
+                "cached trading marker address [{stale_address}] did not match the marker's address [{}] and was repaired",
+                MockApi::default().addr_make("marker"),
+            ),
+            warning,
+            "unexpected warning produced",
+        );
+        assert_eq!(
+            Some(MarkerAddressCache {
+                trading_marker_address: MockApi::default().addr_make("marker"),
+                trades_since_verification: 0,
+            }),
+            get_marker_address_cache(&deps.storage).expect("the cache should load"),
+            "the cache should be repaired and its count reset",
+        );
+        assert_eq!(
+            None,
+            maintain_marker_address_cache(deps.as_mut(), &contract_state, &verify_every(1))
+                .expect("verifying a correct cache should succeed"),
+            "no warning should be produced when the cache matches the marker",
+        );
+    }
+
+    #[test]
+    fn refresh_should_report_the_previous_and_current_addresses() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        deps.storage.remove(b"marker_address_cache");
+        mock_marker_response(
+            &mut deps.querier,
+            test_marker_account(DEFAULT_TRADING_DENOM_NAME, "0"),
+        );
+        let contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        assert_eq!(
+            (None, MockApi::default().addr_make("marker")),
+            refresh_marker_address_cache(deps.as_mut(), &contract_state)
+                .expect("refreshing an empty cache should succeed"),
+            "no previous address should be reported for an empty cache",
+        );
+        assert_eq!(
+            (
+                Some(MockApi::default().addr_make("marker")),
+                MockApi::default().addr_make("marker"),
+            ),
+            refresh_marker_address_cache(deps.as_mut(), &contract_state)
+                .expect("refreshing a populated cache should succeed"),
+            "the previously cached address should be reported",
+        );
+    }
+}
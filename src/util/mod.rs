@@ -1,10 +1,40 @@
 //! Additional functionality that does not strictly belong to a category.
 
+/// Utility functions for consulting the configured compliance contract about trades.
+#[cfg(feature = "contract")]
+pub mod compliance_utils;
 /// Utility functions for converting denominations to other types.
 pub mod conversion_utils;
+/// Utility functions for verifying the contract's external dependencies.
+#[cfg(feature = "contract")]
+pub mod diagnostics_utils;
+/// Utility functions for determining the msg fee assessed on a trade's sender.
+#[cfg(feature = "contract")]
+pub mod fee_utils;
+/// Utility functions for resolving and maintaining the cached trading marker address.
+#[cfg(feature = "contract")]
+pub mod marker_cache_utils;
+/// Utility functions for reducing trades to the amount that a sender can cover.
+#[cfg(feature = "contract")]
+pub mod partial_fill_utils;
+/// Utility functions for holding trades for admin approval.
+#[cfg(feature = "contract")]
+pub mod pending_trade_utils;
 /// Utility functions for interacting with Provenance Blockchain resources.
+#[cfg(feature = "contract")]
 pub mod provenance_utils;
+/// Utility functions for producing and verifying trade quotes.
+#[cfg(feature = "contract")]
+pub mod quote_utils;
+/// Utility functions for building responses.
+#[cfg(feature = "contract")]
+pub mod response_utils;
 /// A trait for describing functions on various structs to validate their contents.
 pub mod self_validating;
+/// Utility functions for customizing message deserialization.
+pub mod serde_utils;
+/// Utility functions for recording executed trades in the contract's trading activity stores.
+#[cfg(feature = "contract")]
+pub mod trade_record_utils;
 /// Utility functions for validating requests.
 pub mod validation_utils;
@@ -0,0 +1,126 @@
+use crate::store::contract_state::ContractStateV1;
+use crate::types::error::ContractError;
+use crate::types::quote::TradeDirection;
+use crate::util::conversion_utils::convert_denom;
+use crate::util::provenance_utils::get_account_balance;
+use cosmwasm_std::{Addr, Deps, Uint128};
+use result_extensions::ResultExtensions;
+
+/// Determines the amount of input denom that a trade allowing partial fills should execute.  If the
+/// sender holds enough input denom to cover the amount that the full trade would collect, the
+/// requested amount is returned unchanged.  Otherwise, the largest amount not exceeding the sender's
+/// balance that converts without a remainder is returned.  When no portion of the balance can be
+/// converted, the balance itself is returned, allowing the trade route to reject it with its usual
+/// conversion error.
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `contract_state` The current contract state, which dictates the denoms used in the trade.
+/// * `direction` The direction of the requested trade.
+/// * `sender` The account that requested the trade.
+/// * `trade_amount` The amount of input denom requested by the trade.
+pub fn resolve_fill_amount(
+    deps: &Deps,
+    contract_state: &ContractStateV1,
+    direction: TradeDirection,
+    sender: &Addr,
+    trade_amount: Uint128,
+) -> Result<Uint128, ContractError> {
+    let (input_marker, output_marker) = match direction {
+        TradeDirection::Fund => (
+            &contract_state.deposit_marker,
+            &contract_state.trading_marker,
+        ),
+        TradeDirection::Withdraw => (
+            &contract_state.trading_marker,
+            &contract_state.deposit_marker,
+        ),
+    };
+    let required_amount = trade_amount
+        .checked_sub(convert_denom(trade_amount, input_marker, output_marker)?.remainder)?;
+    let balance = get_account_balance(deps, sender, &input_marker.name)?;
+    if balance >= required_amount {
+        return trade_amount.to_ok();
+    }
+    let balance_conversion = convert_denom(balance, input_marker, output_marker)?;
+    if balance_conversion.target_amount.is_zero() {
+        return balance.to_ok();
+    }
+    balance.checked_sub(balance_conversion.remainder)?.to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::contract_state::get_contract_state_v1;
+    use crate::test::test_constants::{DEFAULT_DEPOSIT_DENOM_NAME, DEFAULT_TRADING_DENOM_NAME};
+    use crate::test::test_instantiate::test_instantiate_with_msg;
+    use crate::types::denom::Denom;
+    use crate::types::msg::InstantiateMsg;
+    use crate::types::quote::TradeDirection;
+    use crate::util::partial_fill_utils::resolve_fill_amount;
+    use cosmwasm_std::{Addr, Uint128};
+    use provwasm_mocks::{mock_provenance_dependencies_with_custom_querier, MockProvenanceQuerier};
+    use provwasm_std::types::cosmos::bank::v1beta1::{QueryBalanceRequest, QueryBalanceResponse};
+    use provwasm_std::types::cosmos::base::v1beta1::Coin;
+
+    fn resolve_with_balance(balance: &str, trade_amount: u128) -> Uint128 {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(
+            &mut querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    amount: balance.to_string(),
+                    denom: DEFAULT_DEPOSIT_DENOM_NAME.to_string(),
+                }),
+            },
+        );
+        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        // Each unit of trading denom requires ten units of deposit denom
+        test_instantiate_with_msg(
+            &mut deps,
+            InstantiateMsg {
+                deposit_marker: Denom::new(DEFAULT_DEPOSIT_DENOM_NAME, 2),
+                trading_marker: Denom::new(DEFAULT_TRADING_DENOM_NAME, 1),
+                ..InstantiateMsg::default()
+            },
+        );
+        let contract_state =
+            get_contract_state_v1(&deps.storage).expect("the contract state should load");
+        resolve_fill_amount(
+            &deps.as_ref(),
+            &contract_state,
+            TradeDirection::Fund,
+            &Addr::unchecked("sender"),
+            Uint128::new(trade_amount),
+        )
+        .expect("the fill amount should be resolved")
+    }
+
+    #[test]
+    fn test_sufficient_balance_fills_the_requested_amount() {
+        assert_eq!(
+            Uint128::new(105),
+            resolve_with_balance("100", 105),
+            "a balance covering the collected amount should fill the full request",
+        );
+    }
+
+    #[test]
+    fn test_insufficient_balance_fills_the_largest_convertible_amount() {
+        assert_eq!(
+            Uint128::new(70),
+            resolve_with_balance("79", 200),
+            "the fill should be reduced to the largest cleanly-convertible portion of the balance",
+        );
+    }
+
+    #[test]
+    fn test_unconvertible_balance_is_returned_unchanged() {
+        assert_eq!(
+            Uint128::new(9),
+            resolve_with_balance("9", 200),
+            "a balance that cannot convert should be returned for the route to reject",
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use crate::store::pending_trades::{insert_pending_trade, PendingTrade};
+use crate::store::runtime_tunables::get_runtime_tunables;
+use crate::types::error::ContractError;
+use crate::types::quote::TradeDirection;
+use cosmwasm_std::{Addr, Env, Event, Response, Storage, Uint128};
+use result_extensions::ResultExtensions;
+
+/// Stores a trade that has passed every check as a [PendingTrade] awaiting admin approval, instead
+/// of emitting its messages.  The trade expires after the amount of blocks defined by the
+/// [runtime tunables](crate::store::runtime_tunables::RuntimeTunables#pending_trade_expiration_blocks).
+/// The input response is returned with the pending trade's identifier and expiration height added
+/// as attributes, as well as a `trade_pending_approval` event describing the trade.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+/// manipulation.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `direction` The direction of the requested trade.
+/// * `sender` The account that requested the trade.
+/// * `trade_amount` The amount of input denom requested by the trade.
+/// * `referrer` The validated referrer of the trade, if any.
+/// * `allow_remainder` True if the sender acknowledged that the trade may leave a remainder.
+/// * `response` The trade route's response, containing its attributes but none of its messages.
+pub fn submit_pending_trade(
+    storage: &mut dyn Storage,
+    env: &Env,
+    direction: TradeDirection,
+    sender: Addr,
+    trade_amount: Uint128,
+    referrer: Option<Addr>,
+    allow_remainder: bool,
+    response: Response,
+) -> Result<Response, ContractError> {
+    let tunables = get_runtime_tunables(storage)?;
+    let pending_trade = insert_pending_trade(
+        storage,
+        PendingTrade {
+            id: 0,
+            direction,
+            sender,
+            trade_amount,
+            submitted_at_height: env.block.height,
+            expires_at_height: env
+                .block
+                .height
+                .saturating_add(tunables.pending_trade_expiration_blocks),
+            referrer,
+            allow_remainder,
+        },
+    )?;
+    response
+        .add_attribute("pending_trade_id", pending_trade.id.to_string())
+        .add_attribute(
+            "pending_trade_expires_at_height",
+            pending_trade.expires_at_height.to_string(),
+        )
+        .add_event(
+            Event::new("trade_pending_approval")
+                .add_attribute("pending_trade_id", pending_trade.id.to_string())
+                .add_attribute("direction", pending_trade.direction.name())
+                .add_attribute("sender", pending_trade.sender.as_str())
+                .add_attribute("trade_amount", pending_trade.trade_amount.to_string())
+                .add_attribute(
+                    "expires_at_height",
+                    pending_trade.expires_at_height.to_string(),
+                ),
+        )
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::pending_trades::get_pending_trade;
+    use crate::store::runtime_tunables::DEFAULT_PENDING_TRADE_EXPIRATION_BLOCKS;
+    use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::types::quote::TradeDirection;
+    use crate::util::pending_trade_utils::submit_pending_trade;
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{Addr, Response, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn submit_pending_trade_should_store_the_trade_and_describe_it() {
+        let mut deps = mock_provenance_dependencies();
+        let env = mock_env();
+        let expires_at_height = env.block.height + DEFAULT_PENDING_TRADE_EXPIRATION_BLOCKS;
+        let response = submit_pending_trade(
+            &mut deps.storage,
+            &env,
+            TradeDirection::Withdraw,
+            Addr::unchecked("sender"),
+            Uint128::new(500),
+            None,
+            true,
+            Response::new(),
+        )
+        .expect("the pending trade should be submitted");
+        assert!(
+            response.messages.is_empty(),
+            "no messages should be emitted for a pending trade",
+        );
+        response.assert_attribute("pending_trade_id", "1");
+        response.assert_attribute(
+            "pending_trade_expires_at_height",
+            expires_at_height.to_string(),
+        );
+        assert_eq!(1, response.events.len(), "a single event should be emitted");
+        let event = response.events.first().unwrap();
+        assert_eq!(
+            "trade_pending_approval", event.ty,
+            "the pending approval event should be emitted",
+        );
+        assert_eq!(
+            vec![
+                ("pending_trade_id", "1".to_string()),
+                ("direction", "withdraw".to_string()),
+                ("sender", "sender".to_string()),
+                ("trade_amount", "500".to_string()),
+                ("expires_at_height", expires_at_height.to_string()),
+            ],
+            event
+                .attributes
+                .iter()
+                .map(|attr| (attr.key.as_str(), attr.value.to_owned()))
+                .collect::<Vec<_>>(),
+            "the event should describe the pending trade",
+        );
+        let pending_trade =
+            get_pending_trade(&deps.storage, 1).expect("the pending trade should be stored");
+        assert_eq!(
+            env.block.height, pending_trade.submitted_at_height,
+            "the submission height should be recorded",
+        );
+        assert_eq!(
+            expires_at_height, pending_trade.expires_at_height,
+            "the expiration height should be derived from the tunables",
+        );
+        assert!(
+            pending_trade.allow_remainder,
+            "the remainder acknowledgement should be stored",
+        );
+    }
+}
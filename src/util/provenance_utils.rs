@@ -1,12 +1,68 @@
+use crate::store::runtime_tunables::RuntimeTunables;
+use crate::types::denom::{Denom, DenomMetadataInput, IbcDenomTrace, IBC_DENOM_PREFIX};
 use crate::types::error::ContractError;
-use cosmwasm_std::{Deps, DepsMut};
-use provwasm_std::types::cosmos::bank::v1beta1::BankQuerier;
+use crate::types::msg_fee::MsgFee;
+use cosmwasm_std::{Addr, Deps, Uint128};
+use provwasm_std::types::cosmos::bank::v1beta1::{BankQuerier, DenomUnit, Metadata};
 use provwasm_std::types::cosmos::base::query::v1beta1::PageRequest;
+use provwasm_std::types::cosmos::base::v1beta1::Coin;
+use provwasm_std::types::ibc::applications::transfer::v1::TransferQuerier;
 use provwasm_std::types::provenance::attribute::v1::AttributeQuerier;
-use provwasm_std::types::provenance::marker::v1::{MarkerAccount, MarkerQuerier};
+use provwasm_std::types::provenance::marker::v1::{
+    Access, MarkerAccount, MarkerQuerier, MarkerStatus, MarkerType, MsgSetDenomMetadataRequest,
+};
+use provwasm_std::types::provenance::metadata::v1::MetadataQuerier;
+use provwasm_std::types::provenance::msgfees::v1::MsgAssessCustomMsgFeeRequest;
 use provwasm_std::types::provenance::name::v1::{MsgBindNameRequest, NameRecord};
 use result_extensions::ResultExtensions;
 
+/// The amount of scope ownership entries fetched per page when searching for an account's scopes.
+const SCOPE_OWNERSHIP_PAGE_SIZE: u64 = 25;
+/// The most pages of scope ownership entries inspected before an account is considered not to own
+/// a scope with the required specification.  Bounds the gas spent on accounts owning many scopes.
+const SCOPE_OWNERSHIP_MAX_PAGES: u32 = 4;
+
+/// The share of an assessed msg fee, in basis points, that is routed to the fee's recipient.  The
+/// contract never keeps a portion of the fee for the chain's fee module.
+const MSG_FEE_RECIPIENT_BASIS_POINTS: &str = "10000";
+
+/// The permissions that the contract requires on the deposit marker, which it only ever transfers
+/// between accounts.
+pub const DEPOSIT_MARKER_REQUIRED_ACCESS: [Access; 1] = [Access::Transfer];
+/// The permissions that the contract requires on the trading marker, which it mints, withdraws,
+/// transfers and burns.
+pub const TRADING_MARKER_REQUIRED_ACCESS: [Access; 4] = [
+    Access::Mint,
+    Access::Burn,
+    Access::Withdraw,
+    Access::Transfer,
+];
+
+/// Generates an [assess custom msg fee msg](MsgAssessCustomMsgFeeRequest) that charges the given
+/// fee to the fee payer of the transaction in which it is emitted, routing the full amount to the
+/// fee's recipient.
+///
+/// # Parameters
+/// * `name` A short description of the fee, recorded by the msgfees module alongside the charge.
+/// * `msg_fee` The fee to assess.
+/// * `from` The bech32 address of the contract emitting the msg.
+pub fn msg_assess_custom_fee<S1: Into<String>, S2: Into<String>>(
+    name: S1,
+    msg_fee: &MsgFee,
+    from: S2,
+) -> MsgAssessCustomMsgFeeRequest {
+    MsgAssessCustomMsgFeeRequest {
+        name: name.into(),
+        amount: Some(Coin {
+            denom: msg_fee.amount.denom.to_owned(),
+            amount: msg_fee.amount.amount.to_string(),
+        }),
+        recipient: msg_fee.recipient.to_owned(),
+        from: from.into(),
+        recipient_basis_points: MSG_FEE_RECIPIENT_BASIS_POINTS.to_string(),
+    }
+}
+
 /// Generates a [name bind msg](MsgBindNameRequest) that will properly assign the given name value
 /// to a target address.  Assumes the parent name is unrestricted or that the contract has access to
 /// bind a name to the parent name.
@@ -71,6 +127,87 @@ pub fn msg_bind_name<S1: Into<String>, S2: Into<String>>(
     .to_ok()
 }
 
+/// Generates a [set denom metadata msg](MsgSetDenomMetadataRequest) that publishes bank module
+/// metadata for the trading marker's denom.  The marker's denom is used as the base unit with an
+/// exponent of zero, and the input's display unit is added with the input's exponent when it is
+/// greater than zero.  Assumes the input was validated against the marker with
+/// [validate_for_marker](DenomMetadataInput::validate_for_marker).
+///
+/// # Parameters
+/// * `trading_marker` The trading marker whose denom the metadata describes.
+/// * `input` The display values to publish.
+/// * `administrator` The bech32 address of the account with admin access to the marker, normally
+/// the contract itself.
+pub fn msg_set_denom_metadata<S: Into<String>>(
+    trading_marker: &Denom,
+    input: &DenomMetadataInput,
+    administrator: S,
+) -> MsgSetDenomMetadataRequest {
+    let mut denom_units = vec![DenomUnit {
+        denom: trading_marker.name.to_owned(),
+        exponent: 0,
+        aliases: vec![],
+    }];
+    if input.exponent > 0 {
+        denom_units.push(DenomUnit {
+            denom: input.display.to_owned(),
+            exponent: input.exponent,
+            aliases: vec![],
+        });
+    }
+    MsgSetDenomMetadataRequest {
+        metadata: Some(Metadata {
+            description: input.description.to_owned(),
+            denom_units,
+            base: trading_marker.name.to_owned(),
+            display: input.display.to_owned(),
+            name: input.name.to_owned(),
+            symbol: input.symbol.to_owned(),
+            uri: String::new(),
+            uri_hash: String::new(),
+        }),
+        administrator: administrator.into(),
+    }
+}
+
+/// The outcome of searching an account's attributes via [search_account_attributes].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AttributeSearch {
+    /// Every searched attribute name that was found on the account.
+    pub found: Vec<String>,
+    /// True if the search ended at the [attribute_max_pages](crate::store::runtime_tunables::RuntimeTunables#attribute_max_pages)
+    /// limit before every searched name was found.
+    pub page_limit_reached: bool,
+}
+impl AttributeSearch {
+    /// Ensures that the search found all the specified attributes.
+    ///
+    /// # Parameters
+    /// * `attributes` All attribute names to verify.  Each should have been included in the search.
+    /// * `tunables` The [runtime tunables](crate::store::runtime_tunables::RuntimeTunables) used
+    /// for the search.
+    pub fn check_has_all(
+        &self,
+        attributes: &[String],
+        tunables: &RuntimeTunables,
+    ) -> Result<(), ContractError> {
+        if attributes.iter().all(|name| self.found.contains(name)) {
+            return ().to_ok();
+        }
+        ContractError::InvalidAccountError {
+            message: if self.page_limit_reached {
+                format!(
+                    "account does not have all required attributes within the first [{}] attribute pages",
+                    tunables.attribute_max_pages,
+                )
+            } else {
+                "account does not have all required attributes".to_string()
+            },
+        }
+        .to_err()
+    }
+}
+
 /// Ensures that the target account has all the specified attributes.  Does not check for valid
 /// attribute body contents.
 ///
@@ -79,126 +216,311 @@ pub fn msg_bind_name<S1: Into<String>, S2: Into<String>>(
 /// resources like contract internal storage and a querier to retrieve blockchain objects.
 /// * `account` The bech32 address for which to pull and verify attributes.
 /// * `attributes` All attribute names to verify.
+/// * `tunables` The current [runtime tunables](crate::store::runtime_tunables::RuntimeTunables),
+/// which dictate the size and amount of attribute pages fetched.
 pub fn check_account_has_all_attributes<S: Into<String>>(
-    deps: &DepsMut,
+    deps: &Deps,
     account: S,
     attributes: &[String],
+    tunables: &RuntimeTunables,
 ) -> Result<(), ContractError> {
+    search_account_attributes(deps, account, attributes, tunables)?
+        .check_has_all(attributes, tunables)
+}
+
+/// Pages through the target account's attributes, recording which of the specified attribute names
+/// it holds.  The search ends once every name is found, once the final page is fetched, or once
+/// the page limit is reached.  No query is made when no names are specified.
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `account` The bech32 address for which to pull attributes.
+/// * `attributes` All attribute names to search for.
+/// * `tunables` The current [runtime tunables](crate::store::runtime_tunables::RuntimeTunables),
+/// which dictate the size and amount of attribute pages fetched.
+pub fn search_account_attributes<S: Into<String>>(
+    deps: &Deps,
+    account: S,
+    attributes: &[String],
+    tunables: &RuntimeTunables,
+) -> Result<AttributeSearch, ContractError> {
+    let mut search = AttributeSearch::default();
     if attributes.is_empty() {
-        return ().to_ok();
+        return search.to_ok();
     }
     let querier = AttributeQuerier::new(&deps.querier);
     let account_addr = account.into();
-    let mut latest_response = querier.attributes(account_addr.to_owned(), None)?;
     let mut remaining_attributes = attributes.to_vec();
-    while !remaining_attributes.is_empty() {
-        for attr in latest_response.attributes.iter() {
-            remaining_attributes.retain(|name| name != &attr.name);
-        }
-        if !remaining_attributes.is_empty() {
-            if latest_response.pagination.is_some()
-                && !latest_response
-                    .pagination
-                    .clone()
-                    .unwrap()
-                    .next_key
-                    .clone()
-                    .unwrap()
-                    .is_empty()
-            {
-                latest_response = querier.attributes(
-                    account_addr.to_owned(),
-                    Some(PageRequest {
-                        key: latest_response
-                            .pagination
-                            .unwrap()
-                            .next_key
-                            .clone()
-                            .unwrap()
-                            .to_owned(),
-                        offset: 0,
-                        limit: 25,
-                        count_total: false,
-                        reverse: false,
-                    }),
-                )?;
-            } else {
-                return ContractError::InvalidAccountError {
-                    message: "account does not have all required attributes".to_string(),
-                }
-                .to_err();
+    let mut next_key: Option<Vec<u8>> = None;
+    for _ in 0..tunables.attribute_max_pages {
+        let response = querier.attributes(
+            account_addr.to_owned(),
+            Some(PageRequest {
+                key: next_key.unwrap_or_default(),
+                offset: 0,
+                limit: u64::from(tunables.attribute_page_size),
+                count_total: false,
+                reverse: false,
+            }),
+        )?;
+        for attr in response.attributes.iter() {
+            if remaining_attributes.contains(&attr.name) {
+                remaining_attributes.retain(|name| name != &attr.name);
+                search.found.push(attr.name.to_owned());
             }
         }
+        if remaining_attributes.is_empty() {
+            return search.to_ok();
+        }
+        next_key = response
+            .pagination
+            .and_then(|pagination| pagination.next_key)
+            .filter(|key| !key.is_empty());
+        if next_key.is_none() {
+            return search.to_ok();
+        }
     }
-    ().to_ok()
+    search.page_limit_reached = true;
+    search.to_ok()
 }
 
 /// Ensures that the target account holds enough of the target denom name by verifying their
 /// balances in the bank module.
 ///
+/// Only the target denom itself can be moved by the trade's transfer message, so it must cover the
+/// required amount on its own.  The balances of any denom aliases are only queried when the target
+/// denom falls short, allowing the error to explain that an account which appears funded through
+/// its aliases still cannot be traded from.
+///
 /// # Parameters
 /// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
 /// resources like contract internal storage and a querier to retrieve blockchain objects.
 /// * `account` The bech32 address of the account for which to verify balances.
 /// * `denom` The coin denomination for which balances are to be checked.
+/// * `denom_aliases` Other denominations that are considered equivalent to the target denom, but
+/// cannot be transferred in its place.
 /// * `required_amount` The minimum amount of coin that the target account must hold for the given
 /// denom to be considered valid.
 pub fn check_account_has_enough_denom<S1: Into<String>, S2: Into<String>>(
     deps: &Deps,
     account: S1,
     denom: S2,
-    required_amount: u128,
+    denom_aliases: &[String],
+    required_amount: Uint128,
 ) -> Result<(), ContractError> {
     let querier = BankQuerier::new(&deps.querier);
     let account_address = account.into();
     let target_denom = denom.into();
     let balance_response = querier.balance(account_address.to_owned(), target_denom.to_owned())?;
-    if let Some(coin) = balance_response.balance {
-        let numeric_balance = coin.amount.parse::<u128>()?;
-        if numeric_balance < required_amount {
-            ContractError::InvalidAccountError {
-                message: format!(
-                    "required [{required_amount}], but account only holds [{numeric_balance}]"
-                ),
+    let numeric_balance = match balance_response.balance {
+        Some(coin) => coin.amount.parse::<Uint128>()?,
+        None if denom_aliases.is_empty() => {
+            return ContractError::InvalidFundsError {
+                message: format!("account [{account_address}] has no [{target_denom}] balance"),
             }
-            .to_err()
-        } else {
-            ().to_ok()
+            .to_err();
         }
+        None => Uint128::zero(),
+    };
+    if numeric_balance >= required_amount {
+        return ().to_ok();
+    }
+    if denom_aliases.is_empty() {
+        return ContractError::InvalidAccountError {
+            message: format!(
+                "required [{required_amount}], but account only holds [{numeric_balance}]"
+            ),
+        }
+        .to_err();
+    }
+    let mut total_balance = numeric_balance;
+    for alias in denom_aliases {
+        total_balance =
+            total_balance.checked_add(get_account_balance(deps, &account_address, alias)?)?;
+    }
+    let aliases = denom_aliases.join(", ");
+    if total_balance >= required_amount {
+        ContractError::InvalidAccountError {
+            message: format!(
+                "required [{required_amount}], but account only holds [{numeric_balance}] of [{target_denom}], the only denom that can be transferred; aliases [{aliases}] bring its total to [{total_balance}]"
+            ),
+        }
+        .to_err()
     } else {
-        ContractError::InvalidFundsError {
-            message: format!("account [{account_address}] has no [{target_denom}] balance"),
+        ContractError::InvalidAccountError {
+            message: format!(
+                "required [{required_amount}], but account only holds [{total_balance}] including aliases [{aliases}]"
+            ),
         }
         .to_err()
     }
 }
 
-/// Fetches the bech32 address associated with the marker account for the given denomination.
+/// Ensures that the target account owns at least one scope in the metadata module that was created
+/// from the given scope specification.  Each owned scope is fetched individually to discern its
+/// specification, and only the first [SCOPE_OWNERSHIP_MAX_PAGES] pages of owned scopes are inspected.
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `account` The bech32 address of the account for which to verify scope ownership.
+/// * `scope_spec` The bech32 address of the scope specification that an owned scope must use, in
+/// the form `scopespec1...`.
+pub fn check_account_owns_scope_with_spec<S: Into<String>>(
+    deps: &Deps,
+    account: S,
+    scope_spec: &str,
+) -> Result<(), ContractError> {
+    let querier = MetadataQuerier::new(&deps.querier);
+    let account_addr = account.into();
+    let mut next_key: Option<Vec<u8>> = None;
+    for _ in 0..SCOPE_OWNERSHIP_MAX_PAGES {
+        let response = querier.ownership(
+            account_addr.to_owned(),
+            false,
+            Some(PageRequest {
+                key: next_key.unwrap_or_default(),
+                offset: 0,
+                limit: SCOPE_OWNERSHIP_PAGE_SIZE,
+                count_total: false,
+                reverse: false,
+            }),
+        )?;
+        for scope_uuid in response.scope_uuids {
+            let owned_spec = querier
+                .scope(
+                    scope_uuid,
+                    String::new(),
+                    String::new(),
+                    false,
+                    false,
+                    false,
+                    false,
+                )?
+                .scope
+                .and_then(|wrapper| wrapper.scope_spec_id_info)
+                .map(|info| info.scope_spec_addr);
+            if owned_spec.as_deref() == Some(scope_spec) {
+                return ().to_ok();
+            }
+        }
+        next_key = response
+            .pagination
+            .and_then(|pagination| pagination.next_key)
+            .filter(|key| !key.is_empty());
+        if next_key.is_none() {
+            break;
+        }
+    }
+    ContractError::InvalidAccountError {
+        message: format!("account does not own a scope with specification [{scope_spec}]"),
+    }
+    .to_err()
+}
+
+/// Fetches the amount of the target denom held by the target account in the bank module.  An
+/// account that holds none of the denom is reported as having a zero balance.
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `account` The bech32 address of the account for which to fetch the balance.
+/// * `denom` The coin denomination for which the balance is to be fetched.
+pub fn get_account_balance<S1: Into<String>, S2: Into<String>>(
+    deps: &Deps,
+    account: S1,
+    denom: S2,
+) -> Result<Uint128, ContractError> {
+    let querier = BankQuerier::new(&deps.querier);
+    if let Some(coin) = querier.balance(account.into(), denom.into())?.balance {
+        coin.amount.parse::<Uint128>()?.to_ok()
+    } else {
+        Uint128::zero().to_ok()
+    }
+}
+
+/// Fetches the total supply of the target denom from the bank module.  A denom with no supply is
+/// reported as having a zero supply.
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `denom` The coin denomination for which the supply is to be fetched.
+pub fn get_denom_supply<S: Into<String>>(deps: &Deps, denom: S) -> Result<Uint128, ContractError> {
+    let querier = BankQuerier::new(&deps.querier);
+    if let Some(coin) = querier.supply_of(denom.into())?.amount {
+        coin.amount.parse::<Uint128>()?.to_ok()
+    } else {
+        Uint128::zero().to_ok()
+    }
+}
+
+/// Verifies that the given IBC voucher denom traces back to the expected base denom and path in the
+/// IBC transfer module.  An error is returned if the denom is not an IBC voucher, if its trace
+/// cannot be found, or if the trace differs from the expected value.
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `denom` The IBC voucher denom to verify, in the form `ibc/{hash}`.
+/// * `expected_trace` The origin that the voucher denom must trace back to.
+pub fn check_ibc_denom_trace(
+    deps: &Deps,
+    denom: &str,
+    expected_trace: &IbcDenomTrace,
+) -> Result<(), ContractError> {
+    let Some(hash) = denom.strip_prefix(IBC_DENOM_PREFIX) else {
+        return ContractError::InvalidFormatError {
+            message: format!("denom [{denom}] is not an ibc voucher denom"),
+        }
+        .to_err();
+    };
+    let Some(trace) = TransferQuerier::new(&deps.querier)
+        .denom_trace(hash.to_string())?
+        .denom_trace
+    else {
+        return ContractError::NotFoundError {
+            message: format!("no ibc denom trace exists for denom [{denom}]"),
+        }
+        .to_err();
+    };
+    if trace.base_denom != expected_trace.base_denom || trace.path != expected_trace.path {
+        return ContractError::ValidationError {
+            message: format!(
+                "denom [{denom}] traces to [{}/{}], but [{}/{}] was expected",
+                trace.path, trace.base_denom, expected_trace.path, expected_trace.base_denom,
+            ),
+        }
+        .to_err();
+    }
+    ().to_ok()
+}
+
+/// Fetches the marker account for the given denomination.  A [NotFoundError](ContractError::NotFoundError)
+/// is returned if no marker exists for the denom.
 ///
 /// # Parameters
 /// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
 /// resources like contract internal storage and a querier to retrieve blockchain objects.
 /// * `denom` The on-chain name for the marker denom.
-pub fn get_marker_address_for_denom<S: Into<String>>(
+pub fn get_marker_account<S: Into<String>>(
     deps: &Deps,
     denom: S,
-) -> Result<String, ContractError> {
+) -> Result<MarkerAccount, ContractError> {
     let marker_denom = denom.into();
     let querier = MarkerQuerier::new(&deps.querier);
-    let marker_response = querier.marker(marker_denom.to_owned())?;
+    // The marker module rejects queries for markers that do not exist, so a failed query is
+    // reported as a missing marker
+    let marker_response =
+        querier
+            .marker(marker_denom.to_owned())
+            .map_err(|e| ContractError::NotFoundError {
+                message: format!("unable to query marker by name [{marker_denom}]: {e}"),
+            })?;
     if let Some(marker_account_any) = marker_response.marker {
         if let Ok(marker_account) = MarkerAccount::try_from(marker_account_any) {
-            if let Some(base_account) = marker_account.base_account {
-                base_account.address.to_ok()
-            } else {
-                ContractError::NotFoundError {
-                    message: format!(
-                        "unable to resolve base account from marker account [{}]",
-                        &marker_denom
-                    ),
-                }
-                .to_err()
-            }
+            marker_account.to_ok()
         } else {
             ContractError::NotFoundError {
                 message: format!("unable to resolve marker account for denom [{marker_denom}]"),
@@ -213,13 +535,247 @@ pub fn get_marker_address_for_denom<S: Into<String>>(
     }
 }
 
+/// Ensures that the given marker can back the contract's trades.  The trade routes move denom with
+/// marker transfers, which the marker module only permits for restricted markers, and only active
+/// markers allow their denom to move at all.  A [ValidationError](ContractError::ValidationError)
+/// naming the denom and its actual type or status is returned otherwise.
+///
+/// # Parameters
+/// * `marker` The marker account to verify.
+pub fn check_marker_is_restricted_and_active(marker: &MarkerAccount) -> Result<(), ContractError> {
+    if marker.marker_type != MarkerType::Restricted as i32 {
+        return ContractError::ValidationError {
+            message: format!(
+                "marker [{}] has type [{}], but must be [{}]",
+                marker.denom,
+                MarkerType::try_from(marker.marker_type)
+                    .map(|marker_type| marker_type.as_str_name().to_string())
+                    .unwrap_or_else(|_| marker.marker_type.to_string()),
+                MarkerType::Restricted.as_str_name(),
+            ),
+        }
+        .to_err();
+    }
+    if marker.status != MarkerStatus::Active as i32 {
+        return ContractError::ValidationError {
+            message: format!(
+                "marker [{}] has status [{}], but must be [{}]",
+                marker.denom,
+                MarkerStatus::try_from(marker.status)
+                    .map(|status| status.as_str_name().to_string())
+                    .unwrap_or_else(|_| marker.status.to_string()),
+                MarkerStatus::Active.as_str_name(),
+            ),
+        }
+        .to_err();
+    }
+    ().to_ok()
+}
+
+/// Ensures that the given marker is able to mint the target amount of its denom without violating
+/// its own configuration or the marker module's max supply parameter.  Performing this check up
+/// front produces an error that describes the violated constraint, rather than a failure within the
+/// marker module after messages have been emitted.
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `marker` The marker account that will mint the denom.
+/// * `mint_amount` The amount of the marker's denom that will be minted.
+pub fn check_marker_can_mint(
+    deps: &Deps,
+    marker: &MarkerAccount,
+    mint_amount: Uint128,
+) -> Result<(), ContractError> {
+    if marker.status != MarkerStatus::Active as i32 {
+        return ContractError::ValidationError {
+            message: format!(
+                "marker [{}] has status [{}], but must be active to mint [{mint_amount}]",
+                marker.denom,
+                MarkerStatus::try_from(marker.status)
+                    .map(|status| status.as_str_name().to_string())
+                    .unwrap_or_else(|_| marker.status.to_string()),
+            ),
+        }
+        .to_err();
+    }
+    if marker.supply_fixed {
+        return ContractError::ValidationError {
+            message: format!(
+                "marker [{}] has a fixed supply of [{}], so [{mint_amount}] cannot be minted",
+                marker.denom, marker.supply,
+            ),
+        }
+        .to_err();
+    }
+    let max_supply = MarkerQuerier::new(&deps.querier)
+        .params()?
+        .params
+        .map(|params| params.max_supply)
+        .unwrap_or_default();
+    // An empty value indicates that the marker module does not enforce a max supply
+    if max_supply.is_empty() {
+        return ().to_ok();
+    }
+    let max_supply = max_supply.parse::<Uint128>()?;
+    let new_supply = marker
+        .supply
+        .parse::<Uint128>()?
+        .saturating_add(mint_amount);
+    if new_supply > max_supply {
+        ContractError::ValidationError {
+            message: format!(
+                "minting [{mint_amount}] would raise the supply of marker [{}] to [{new_supply}], exceeding the max supply of [{max_supply}] by [{}]",
+                marker.denom,
+                new_supply - max_supply,
+            ),
+        }
+        .to_err()
+    } else {
+        ().to_ok()
+    }
+}
+
+/// Determines which of the required permissions the given address has not been granted on a
+/// marker, preserving the order in which they were required.
+///
+/// # Parameters
+/// * `marker` The marker account whose access control is inspected.
+/// * `address` The bech32 address that requires the permissions.
+/// * `required_permissions` The permissions that the address must hold.
+pub fn find_missing_marker_permissions(
+    marker: &MarkerAccount,
+    address: &str,
+    required_permissions: &[Access],
+) -> Vec<Access> {
+    let granted_permissions = marker
+        .access_control
+        .iter()
+        .find(|grant| grant.address == address)
+        .map(|grant| grant.permissions.to_owned())
+        .unwrap_or_default();
+    required_permissions
+        .iter()
+        .filter(|permission| !granted_permissions.contains(&(**permission as i32)))
+        .copied()
+        .collect()
+}
+
+/// Verifies that the contract has been granted every required permission on a marker.  Without
+/// them, the marker module rejects the contract's trade messages with an error that does not
+/// identify the missing grant, so a [NotAuthorizedError](ContractError::NotAuthorizedError) that
+/// names the missing permissions and the marker's denom is returned instead.
+///
+/// # Parameters
+/// * `marker` The marker account whose access control is inspected.
+/// * `contract_address` The address of this contract.
+/// * `required_permissions` The permissions that the contract must hold on the marker.
+pub fn check_contract_marker_access(
+    marker: &MarkerAccount,
+    contract_address: &Addr,
+    required_permissions: &[Access],
+) -> Result<(), ContractError> {
+    let missing_permissions =
+        find_missing_marker_permissions(marker, contract_address.as_str(), required_permissions);
+    if missing_permissions.is_empty() {
+        return ().to_ok();
+    }
+    ContractError::NotAuthorizedError {
+        message: format!(
+            "contract [{contract_address}] is missing permissions [{}] on marker [{}]",
+            missing_permissions
+                .iter()
+                .map(|permission| permission.as_str_name())
+                .collect::<Vec<&str>>()
+                .join(", "),
+            marker.denom,
+        ),
+    }
+    .to_err()
+}
+
+/// Fetches both of the contract's markers and verifies that the contract holds the
+/// [deposit marker permissions](DEPOSIT_MARKER_REQUIRED_ACCESS) and the
+/// [trading marker permissions](TRADING_MARKER_REQUIRED_ACCESS) via [check_contract_marker_access].
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `contract_address` The address of this contract.
+/// * `deposit_marker` The marker whose denom is deposited in exchange for trading denom.
+/// * `trading_marker` The marker whose denom is minted and burned by trades.
+pub fn check_contract_access_to_markers(
+    deps: &Deps,
+    contract_address: &Addr,
+    deposit_marker: &Denom,
+    trading_marker: &Denom,
+) -> Result<(), ContractError> {
+    check_contract_marker_access(
+        &get_marker_account(deps, &deposit_marker.name)?,
+        contract_address,
+        &DEPOSIT_MARKER_REQUIRED_ACCESS,
+    )?;
+    check_contract_marker_access(
+        &get_marker_account(deps, &trading_marker.name)?,
+        contract_address,
+        &TRADING_MARKER_REQUIRED_ACCESS,
+    )
+}
+
+/// Fetches the bech32 address associated with the marker account for the given denomination.  The
+/// address is validated before it is returned.
+///
+/// # Parameters
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `denom` The on-chain name for the marker denom.
+pub fn get_marker_address_for_denom<S: Into<String>>(
+    deps: &Deps,
+    denom: S,
+) -> Result<String, ContractError> {
+    let marker_denom = denom.into();
+    if let Some(base_account) = get_marker_account(deps, &marker_denom)?.base_account {
+        // The address is used as a message destination, so it is verified here rather than allowing
+        // a malformed value to fail after messages are emitted
+        if deps.api.addr_validate(&base_account.address).is_err() {
+            return ContractError::InvalidFormatError {
+                message: format!(
+                    "marker [{marker_denom}] has an invalid address [{}]",
+                    base_account.address,
+                ),
+            }
+            .to_err();
+        }
+        base_account.address.to_ok()
+    } else {
+        ContractError::NotFoundError {
+            message: format!(
+                "unable to resolve base account from marker account [{}]",
+                &marker_denom
+            ),
+        }
+        .to_err()
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::store::runtime_tunables::RuntimeTunables;
+    use crate::test::test_ibc::{mock_denom_trace_response, TEST_IBC_DENOM};
+    use crate::test::test_markers::{
+        mock_marker_params_response, mock_marker_response, test_marker_account,
+    };
+    use crate::types::denom::{Denom, DenomMetadataInput, IbcDenomTrace};
     use crate::types::error::ContractError;
     use crate::util::provenance_utils::{
         check_account_has_all_attributes, check_account_has_enough_denom,
-        get_marker_address_for_denom, msg_bind_name,
+        check_contract_marker_access, check_ibc_denom_trace, check_marker_can_mint,
+        check_marker_is_restricted_and_active, get_account_balance, get_marker_address_for_denom,
+        msg_bind_name, msg_set_denom_metadata, search_account_attributes,
+        DEPOSIT_MARKER_REQUIRED_ACCESS, TRADING_MARKER_REQUIRED_ACCESS,
     };
+    use cosmwasm_std::testing::{MockApi, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{Addr, Uint128};
     use prost::Message;
     use provwasm_mocks::{mock_provenance_dependencies_with_custom_querier, MockProvenanceQuerier};
     use provwasm_std::shim::Any;
@@ -231,7 +787,8 @@ mod tests {
         Attribute, AttributeType, QueryAttributesRequest, QueryAttributesResponse,
     };
     use provwasm_std::types::provenance::marker::v1::{
-        MarkerAccount, MarkerStatus, MarkerType, QueryMarkerRequest, QueryMarkerResponse,
+        Access, AccessGrant, MarkerAccount, MarkerStatus, MarkerType, QueryMarkerRequest,
+        QueryMarkerResponse,
     };
 
     #[test]
@@ -319,6 +876,63 @@ mod tests {
         );
     }
 
+    #[test]
+    fn msg_set_denom_metadata_uses_the_trading_marker_as_the_base_unit() {
+        let input = DenomMetadataInput {
+            name: "Trading Dollar".to_string(),
+            display: "tdollar".to_string(),
+            symbol: "TD".to_string(),
+            description: "The trading denom".to_string(),
+            exponent: 2,
+        };
+        let msg = msg_set_denom_metadata(&Denom::new("trading", 2), &input, "contract");
+        assert_eq!(
+            "contract", msg.administrator,
+            "the administrator should be set"
+        );
+        let metadata = msg.metadata.expect("the metadata should be set");
+        assert_eq!(
+            "trading", metadata.base,
+            "the marker denom should be the base"
+        );
+        assert_eq!(
+            "tdollar", metadata.display,
+            "the display unit should be set"
+        );
+        assert_eq!("Trading Dollar", metadata.name, "the name should be set");
+        assert_eq!("TD", metadata.symbol, "the symbol should be set");
+        assert_eq!(
+            "The trading denom", metadata.description,
+            "the description should be set",
+        );
+        assert_eq!(
+            vec![("trading".to_string(), 0), ("tdollar".to_string(), 2)],
+            metadata
+                .denom_units
+                .iter()
+                .map(|unit| (unit.denom.to_owned(), unit.exponent))
+                .collect::<Vec<(String, u32)>>(),
+            "the base and display units should be included with their exponents",
+        );
+        let msg = msg_set_denom_metadata(
+            &Denom::new("trading", 0),
+            &DenomMetadataInput {
+                display: "trading".to_string(),
+                exponent: 0,
+                ..input
+            },
+            "contract",
+        );
+        assert_eq!(
+            1,
+            msg.metadata
+                .expect("the metadata should be set")
+                .denom_units
+                .len(),
+            "only the base unit should be included when the exponent is zero",
+        );
+    }
+
     #[test]
     fn check_account_has_all_attributes_should_succeed_when_attributes_present() {
         let mut querier = MockProvenanceQuerier::new(&[]);
@@ -349,11 +963,12 @@ mod tests {
                 }),
             },
         );
-        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        let deps = mock_provenance_dependencies_with_custom_querier(querier);
         check_account_has_all_attributes(
-            &deps.as_mut(),
+            &deps.as_ref(),
             account,
             &["first".to_string(), "second".to_string()],
+            &RuntimeTunables::default(),
         )
         .expect("when all required attributes are in results, a success should occur");
     }
@@ -379,11 +994,12 @@ mod tests {
                 }),
             },
         );
-        let mut deps = mock_provenance_dependencies_with_custom_querier(querier);
+        let deps = mock_provenance_dependencies_with_custom_querier(querier);
         let error = check_account_has_all_attributes(
-            &deps.as_mut(),
+            &deps.as_ref(),
             account,
             &["right_attribute".to_string()],
+            &RuntimeTunables::default(),
         )
         .expect_err("when one or more attributes is missing, an error should occur");
         let _expected_error_message = "account does not have all required attributes".to_string();
@@ -398,6 +1014,103 @@ mod tests {
         );
     }
 
+    #[test]
+    fn check_account_has_all_attributes_should_stop_after_max_pages() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        let account = "account".to_string();
+        // The mock always indicates that another page exists, so only the page limit can end the
+        // search
+        QueryAttributesRequest::mock_response(
+            &mut querier,
+            QueryAttributesResponse {
+                account: account.to_owned(),
+                attributes: vec![Attribute {
+                    name: "wrong_attribute".to_string(),
+                    value: vec![],
+                    attribute_type: AttributeType::String as i32,
+                    address: "some-addr".to_string(),
+                    expiration_date: None,
+                }],
+                pagination: Some(PageResponse {
+                    next_key: Some(vec![1]),
+                    total: 100,
+                }),
+            },
+        );
+        let deps = mock_provenance_dependencies_with_custom_querier(querier);
+        let error = check_account_has_all_attributes(
+            &deps.as_ref(),
+            account,
+            &["right_attribute".to_string()],
+            &RuntimeTunables {
+                attribute_page_size: 1,
+                attribute_max_pages: 3,
+                ..RuntimeTunables::default()
+            },
+        )
+        .expect_err("an error should occur when the attribute is not found within the page limit");
+        assert_eq!(
+            "invalid account: account does not have all required attributes within the first [3] attribute pages",
+            error.to_string(),
+            "unexpected error occurred when the page limit was reached",
+        );
+    }
+
+    #[test]
+    fn search_account_attributes_should_report_found_names() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        let account = "account".to_string();
+        QueryAttributesRequest::mock_response(
+            &mut querier,
+            QueryAttributesResponse {
+                account: account.to_owned(),
+                attributes: vec![
+                    Attribute {
+                        name: "first".to_string(),
+                        value: vec![],
+                        attribute_type: AttributeType::String as i32,
+                        address: "some-addr".to_string(),
+                        expiration_date: None,
+                    },
+                    Attribute {
+                        name: "unsearched".to_string(),
+                        value: vec![],
+                        attribute_type: AttributeType::String as i32,
+                        address: "some-addr".to_string(),
+                        expiration_date: None,
+                    },
+                ],
+                pagination: Some(PageResponse {
+                    next_key: Some(vec![]),
+                    total: 2,
+                }),
+            },
+        );
+        let deps = mock_provenance_dependencies_with_custom_querier(querier);
+        let search = search_account_attributes(
+            &deps.as_ref(),
+            account,
+            &["first".to_string(), "second".to_string()],
+            &RuntimeTunables::default(),
+        )
+        .expect("the search should succeed");
+        assert_eq!(
+            vec!["first".to_string()],
+            search.found,
+            "only the searched names held by the account should be found",
+        );
+        assert!(
+            !search.page_limit_reached,
+            "the page limit should not be reached when the final page is fetched",
+        );
+        search
+            .check_has_all(&["first".to_string()], &RuntimeTunables::default())
+            .expect("a found name should pass the check");
+        search
+            .check_has_all(&["second".to_string()], &RuntimeTunables::default())
+            .expect_err("a missing name should fail the check");
+    }
+
     #[test]
     fn check_account_has_enough_denom_thresholds_work_correctly() {
         let mut querier = MockProvenanceQuerier::new(&[]);
@@ -411,12 +1124,18 @@ mod tests {
             },
         );
         let deps = mock_provenance_dependencies_with_custom_querier(querier);
-        check_account_has_enough_denom(&deps.as_ref(), "account", "denom", 300)
+        check_account_has_enough_denom(&deps.as_ref(), "account", "denom", &[], Uint128::new(300))
             .expect("the exact amount required should cause a pass");
-        check_account_has_enough_denom(&deps.as_ref(), "account", "denom", 299)
+        check_account_has_enough_denom(&deps.as_ref(), "account", "denom", &[], Uint128::new(299))
             .expect("having more than the amount required should cause a pass");
-        let error = check_account_has_enough_denom(&deps.as_ref(), "account", "denom", 301)
-            .expect_err("having less than the amount required should cause an error");
+        let error = check_account_has_enough_denom(
+            &deps.as_ref(),
+            "account",
+            "denom",
+            &[],
+            Uint128::new(301),
+        )
+        .expect_err("having less than the amount required should cause an error");
         let _expected_error_message = "required [301], but account only holds [300]".to_string();
         assert!(
             matches!(
@@ -434,8 +1153,14 @@ mod tests {
         let mut querier = MockProvenanceQuerier::new(&[]);
         QueryBalanceRequest::mock_response(&mut querier, QueryBalanceResponse { balance: None });
         let deps = mock_provenance_dependencies_with_custom_querier(querier);
-        let error = check_account_has_enough_denom(&deps.as_ref(), "account", "denom", 1)
-            .expect_err("an error should occur if the response includes no balance");
+        let error = check_account_has_enough_denom(
+            &deps.as_ref(),
+            "account",
+            "denom",
+            &[],
+            Uint128::new(1),
+        )
+        .expect_err("an error should occur if the response includes no balance");
         let _expected_error_message = "account [account] has no [denom] balance".to_string();
         assert!(
             matches!(
@@ -448,6 +1173,110 @@ mod tests {
         );
     }
 
+    #[test]
+    fn check_account_has_enough_denom_requires_the_target_denom_to_cover_aliased_amounts() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(
+            &mut querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    amount: "100".to_string(),
+                    denom: "denom".to_string(),
+                }),
+            },
+        );
+        let deps = mock_provenance_dependencies_with_custom_querier(querier);
+        let aliases = ["ibc/ABCDEF".to_string()];
+        check_account_has_enough_denom(
+            &deps.as_ref(),
+            "account",
+            "denom",
+            &aliases,
+            Uint128::new(100),
+        )
+        .expect("the target denom covering the amount alone should cause a pass");
+        let error = check_account_has_enough_denom(
+            &deps.as_ref(),
+            "account",
+            "denom",
+            &aliases,
+            Uint128::new(150),
+        )
+        .expect_err("an error should occur when only the aliases cover the required amount");
+        assert_eq!(
+            "invalid account: required [150], but account only holds [100] of [denom], the only denom that can be transferred; aliases [ibc/ABCDEF] bring its total to [200]",
+            error.to_string(),
+            "unexpected error message emitted when only the aliases cover the required amount",
+        );
+        let error = check_account_has_enough_denom(
+            &deps.as_ref(),
+            "account",
+            "denom",
+            &aliases,
+            Uint128::new(201),
+        )
+        .expect_err("an error should occur when the target denom and aliases are insufficient");
+        assert_eq!(
+            "invalid account: required [201], but account only holds [200] including aliases [ibc/ABCDEF]",
+            error.to_string(),
+            "unexpected error message emitted when the aliases do not cover the required amount",
+        );
+    }
+
+    #[test]
+    fn check_account_has_enough_denom_with_aliases_treats_no_balance_as_zero() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(&mut querier, QueryBalanceResponse { balance: None });
+        let deps = mock_provenance_dependencies_with_custom_querier(querier);
+        let error = check_account_has_enough_denom(
+            &deps.as_ref(),
+            "account",
+            "denom",
+            &["alias".to_string()],
+            Uint128::new(1),
+        )
+        .expect_err("an error should occur when no balances are held");
+        assert_eq!(
+            "invalid account: required [1], but account only holds [0] including aliases [alias]",
+            error.to_string(),
+            "unexpected error message emitted when no balances are held",
+        );
+    }
+
+    #[test]
+    fn get_account_balance_reports_held_amount() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(
+            &mut querier,
+            QueryBalanceResponse {
+                balance: Some(Coin {
+                    amount: "150".to_string(),
+                    denom: "nhash".to_string(),
+                }),
+            },
+        );
+        let deps = mock_provenance_dependencies_with_custom_querier(querier);
+        assert_eq!(
+            Uint128::new(150),
+            get_account_balance(&deps.as_ref(), "account", "nhash")
+                .expect("the balance should be fetched"),
+            "the held amount should be reported",
+        );
+    }
+
+    #[test]
+    fn get_account_balance_reports_zero_for_no_balance() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        QueryBalanceRequest::mock_response(&mut querier, QueryBalanceResponse { balance: None });
+        let deps = mock_provenance_dependencies_with_custom_querier(querier);
+        assert_eq!(
+            Uint128::zero(),
+            get_account_balance(&deps.as_ref(), "account", "nhash")
+                .expect("a missing balance should not cause an error"),
+            "a missing balance should be reported as zero",
+        );
+    }
+
     #[test]
     fn get_marker_address_for_denom_guards_against_missing_marker() {
         let mut querier = MockProvenanceQuerier::new(&[]);
@@ -467,6 +1296,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn get_marker_address_for_denom_reports_a_failed_query_as_a_missing_marker() {
+        let deps =
+            mock_provenance_dependencies_with_custom_querier(MockProvenanceQuerier::new(&[]));
+        let error = get_marker_address_for_denom(&deps.as_ref(), "marker")
+            .expect_err("an error should occur when the marker query fails");
+        assert!(
+            matches!(
+                &error,
+                ContractError::NotFoundError { message }
+                    if message.starts_with("unable to query marker by name [marker]: ")
+            ),
+            "unexpected error emitted when the marker query fails: {error:?}",
+        );
+    }
+
     #[test]
     fn get_marker_address_for_denom_guards_against_incorrect_marker_account_type() {
         // TODO: Test circumstance where marker account is malformed.  Provwasm 2.0.0 does not
@@ -514,6 +1359,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn get_marker_address_for_denom_guards_against_invalid_address() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        let mut marker = test_marker_account("marker", "100");
+        marker.base_account = Some(BaseAccount {
+            address: "not-an-address".to_string(),
+            pub_key: None,
+            account_number: 312,
+            sequence: 68,
+        });
+        mock_marker_response(&mut querier, marker);
+        let deps = mock_provenance_dependencies_with_custom_querier(querier);
+        let error = get_marker_address_for_denom(&deps.as_ref(), "marker")
+            .expect_err("an error should occur when the marker has an invalid address");
+        assert_eq!(
+            "invalid format: marker [marker] has an invalid address [not-an-address]",
+            error.to_string(),
+            "unexpected error message emitted when the marker address is invalid",
+        );
+    }
+
     #[test]
     fn get_marker_address_for_denom_should_succeed_with_a_proper_response() {
         let mut querier = MockProvenanceQuerier::new(&[]);
@@ -524,7 +1390,7 @@ mod tests {
                     type_url: "/provenance.marker.v1.MarkerAccount".to_string(),
                     value: MarkerAccount {
                         base_account: Some(BaseAccount {
-                            address: "marker-address".to_string(),
+                            address: MockApi::default().addr_make("marker").to_string(),
                             pub_key: None,
                             account_number: 312,
                             sequence: 68,
@@ -548,8 +1414,180 @@ mod tests {
         let marker_address = get_marker_address_for_denom(&deps.as_ref(), "marker")
             .expect("a response should be emitted when marker output is properly formed");
         assert_eq!(
-            "marker-address", marker_address,
+            MockApi::default().addr_make("marker").as_str(),
+            marker_address,
             "the correct marker address should be extracted",
         );
     }
+
+    #[test]
+    fn check_contract_marker_access_should_accept_a_contract_holding_every_permission() {
+        check_contract_marker_access(
+            &test_marker_account("marker", "100"),
+            &Addr::unchecked(MOCK_CONTRACT_ADDR),
+            &TRADING_MARKER_REQUIRED_ACCESS,
+        )
+        .expect("a contract holding every required permission should pass the check");
+    }
+
+    #[test]
+    fn check_contract_marker_access_should_name_the_missing_permissions_and_denom() {
+        let mut marker = test_marker_account("marker", "100");
+        marker.access_control = vec![AccessGrant {
+            address: MOCK_CONTRACT_ADDR.to_string(),
+            permissions: vec![Access::Transfer as i32, Access::Mint as i32],
+        }];
+        let error = check_contract_marker_access(
+            &marker,
+            &Addr::unchecked(MOCK_CONTRACT_ADDR),
+            &TRADING_MARKER_REQUIRED_ACCESS,
+        )
+        .expect_err("an error should occur when the contract lacks permissions");
+        assert_eq!(
+            format!("not authorized: contract [{MOCK_CONTRACT_ADDR}] is missing permissions [ACCESS_BURN, ACCESS_WITHDRAW] on marker [marker]"),
+            error.to_string(),
+            "unexpected error message emitted when permissions are missing",
+        );
+        let error = check_contract_marker_access(
+            &marker,
+            &Addr::unchecked("another-contract"),
+            &DEPOSIT_MARKER_REQUIRED_ACCESS,
+        )
+        .expect_err("an error should occur when the contract holds no grant on the marker");
+        assert_eq!(
+            "not authorized: contract [another-contract] is missing permissions [ACCESS_TRANSFER] on marker [marker]",
+            error.to_string(),
+            "permissions granted to another address should not satisfy the check",
+        );
+    }
+
+    #[test]
+    fn check_marker_is_restricted_and_active_should_accept_an_active_restricted_marker() {
+        check_marker_is_restricted_and_active(&test_marker_account("marker", "100"))
+            .expect("an active restricted marker should pass the check");
+    }
+
+    #[test]
+    fn check_marker_is_restricted_and_active_should_reject_coin_markers() {
+        let mut marker = test_marker_account("marker", "100");
+        marker.marker_type = MarkerType::Coin as i32;
+        let error = check_marker_is_restricted_and_active(&marker)
+            .expect_err("an error should occur when the marker is a coin marker");
+        assert_eq!(
+            "validation failed: marker [marker] has type [MARKER_TYPE_COIN], but must be [MARKER_TYPE_RESTRICTED]",
+            error.to_string(),
+            "unexpected error message emitted when the marker is a coin marker",
+        );
+    }
+
+    #[test]
+    fn check_marker_is_restricted_and_active_should_reject_proposed_markers() {
+        let mut marker = test_marker_account("marker", "100");
+        marker.status = MarkerStatus::Proposed as i32;
+        let error = check_marker_is_restricted_and_active(&marker)
+            .expect_err("an error should occur when the marker is not active");
+        assert_eq!(
+            "validation failed: marker [marker] has status [MARKER_STATUS_PROPOSED], but must be [MARKER_STATUS_ACTIVE]",
+            error.to_string(),
+            "unexpected error message emitted when the marker is not active",
+        );
+    }
+
+    #[test]
+    fn check_marker_can_mint_should_reject_inactive_markers() {
+        let deps =
+            mock_provenance_dependencies_with_custom_querier(MockProvenanceQuerier::new(&[]));
+        let mut marker = test_marker_account("marker", "100");
+        marker.status = MarkerStatus::Finalized as i32;
+        let error = check_marker_can_mint(&deps.as_ref(), &marker, Uint128::new(10))
+            .expect_err("an error should occur when the marker is not active");
+        assert_eq!(
+            "validation failed: marker [marker] has status [MARKER_STATUS_FINALIZED], but must be active to mint [10]",
+            error.to_string(),
+            "unexpected error message emitted when the marker is not active",
+        );
+    }
+
+    #[test]
+    fn check_marker_can_mint_should_reject_fixed_supply_markers() {
+        let deps =
+            mock_provenance_dependencies_with_custom_querier(MockProvenanceQuerier::new(&[]));
+        let mut marker = test_marker_account("marker", "100");
+        marker.supply_fixed = true;
+        let error = check_marker_can_mint(&deps.as_ref(), &marker, Uint128::new(10))
+            .expect_err("an error should occur when the marker has a fixed supply");
+        assert_eq!(
+            "validation failed: marker [marker] has a fixed supply of [100], so [10] cannot be minted",
+            error.to_string(),
+            "unexpected error message emitted when the marker has a fixed supply",
+        );
+    }
+
+    #[test]
+    fn check_marker_can_mint_thresholds_work_correctly() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        mock_marker_params_response(&mut querier, "110");
+        let deps = mock_provenance_dependencies_with_custom_querier(querier);
+        let marker = test_marker_account("marker", "100");
+        check_marker_can_mint(&deps.as_ref(), &marker, Uint128::new(10))
+            .expect("minting up to exactly the max supply should be allowed");
+        let error = check_marker_can_mint(&deps.as_ref(), &marker, Uint128::new(11))
+            .expect_err("an error should occur when minting would exceed the max supply");
+        assert_eq!(
+            "validation failed: minting [11] would raise the supply of marker [marker] to [111], exceeding the max supply of [110] by [1]",
+            error.to_string(),
+            "unexpected error message emitted when minting would exceed the max supply",
+        );
+    }
+
+    #[test]
+    fn check_marker_can_mint_should_allow_any_amount_without_a_max_supply() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        mock_marker_params_response(&mut querier, "");
+        let deps = mock_provenance_dependencies_with_custom_querier(querier);
+        check_marker_can_mint(
+            &deps.as_ref(),
+            &test_marker_account("marker", "100"),
+            Uint128::MAX,
+        )
+        .expect("any amount should be mintable when the marker module enforces no max supply");
+    }
+
+    #[test]
+    fn check_ibc_denom_trace_should_compare_the_trace_to_the_expected_value() {
+        let mut querier = MockProvenanceQuerier::new(&[]);
+        mock_denom_trace_response(&mut querier, "transfer/channel-0", "uusdc");
+        let deps = mock_provenance_dependencies_with_custom_querier(querier);
+        let expected_trace = |path: &str, base_denom: &str| IbcDenomTrace {
+            base_denom: base_denom.to_string(),
+            path: path.to_string(),
+        };
+        check_ibc_denom_trace(
+            &deps.as_ref(),
+            TEST_IBC_DENOM,
+            &expected_trace("transfer/channel-0", "uusdc"),
+        )
+        .expect("a matching trace should pass verification");
+        let error = check_ibc_denom_trace(
+            &deps.as_ref(),
+            TEST_IBC_DENOM,
+            &expected_trace("transfer/channel-9", "uusdc"),
+        )
+        .expect_err("an error should occur when the trace path differs");
+        assert_eq!(
+            format!("validation failed: denom [{TEST_IBC_DENOM}] traces to [transfer/channel-0/uusdc], but [transfer/channel-9/uusdc] was expected"),
+            error.to_string(),
+            "unexpected error message emitted when the trace differs",
+        );
+        let error = check_ibc_denom_trace(
+            &deps.as_ref(),
+            "nhash",
+            &expected_trace("transfer/channel-0", "uusdc"),
+        )
+        .expect_err("an error should occur when the denom is not an ibc voucher");
+        assert!(
+            matches!(error, ContractError::InvalidFormatError { .. }),
+            "unexpected error emitted when the denom is not an ibc voucher: {error:?}",
+        );
+    }
 }
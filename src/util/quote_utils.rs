@@ -0,0 +1,386 @@
+use crate::store::contract_state::ContractStateV1;
+use crate::store::runtime_tunables::RuntimeTunables;
+use crate::types::error::ContractError;
+use crate::types::quote::{QuoteRef, TradeDirection, TradeQuote};
+use crate::util::conversion_utils::convert_denom;
+use cosmwasm_std::{Env, HexBinary, Uint128};
+use result_extensions::ResultExtensions;
+use sha2::{Digest, Sha256};
+
+/// Derives the quote for a trade from the current contract configuration.  The quote's hash covers
+/// the contract address and version, the direction, the denoms and their precisions, the requested
+/// and converted amounts, and the quoted height, so any change to those values between quoting and
+/// executing produces a different hash.
+///
+/// # Parameters
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `contract_state` The current contract configuration.
+/// * `tunables` The current runtime tunables, which define how long the quote remains valid.
+/// * `direction` The direction of the trade to quote.
+/// * `trade_amount` The amount of input denom to quote.
+/// * `quoted_at_height` The block height at which the quote is considered to be produced.
+pub fn derive_trade_quote(
+    env: &Env,
+    contract_state: &ContractStateV1,
+    tunables: &RuntimeTunables,
+    direction: TradeDirection,
+    trade_amount: Uint128,
+    quoted_at_height: u64,
+) -> Result<TradeQuote, ContractError> {
+    let (input_marker, output_marker) = match direction {
+        TradeDirection::Fund => (
+            &contract_state.deposit_marker,
+            &contract_state.trading_marker,
+        ),
+        TradeDirection::Withdraw => (
+            &contract_state.trading_marker,
+            &contract_state.deposit_marker,
+        ),
+    };
+    let conversion = convert_denom(trade_amount, input_marker, output_marker)?;
+    if conversion.target_amount.is_zero() {
+        return ContractError::InvalidFundsError {
+            message: format!(
+                "sent [{}{}], but that is not enough to convert to at least one [{}]",
+                trade_amount, &input_marker.name, &output_marker.name,
+            ),
+        }
+        .to_err();
+    }
+    let collected_amount = trade_amount.checked_sub(conversion.remainder)?;
+    // Denom names cannot contain a pipe, so joining on it keeps every field boundary unambiguous
+    let preimage = [
+        env.contract.address.to_string(),
+        contract_state.contract_version.to_owned(),
+        direction.name().to_string(),
+        trade_amount.to_string(),
+        input_marker.name.to_owned(),
+        input_marker.precision.to_string(),
+        output_marker.name.to_owned(),
+        output_marker.precision.to_string(),
+        conversion.target_amount.to_string(),
+        conversion.remainder.to_string(),
+        quoted_at_height.to_string(),
+    ]
+    .join("|");
+    TradeQuote {
+        direction,
+        trade_amount,
+        input_denom: input_marker.name.to_owned(),
+        output_denom: output_marker.name.to_owned(),
+        collected_amount,
+        output_amount: conversion.target_amount,
+        remainder: conversion.remainder,
+        quoted_at_height,
+        valid_through_height: quoted_at_height.saturating_add(tunables.quote_validity_blocks),
+        hash: HexBinary::from(Sha256::digest(preimage.as_bytes()).as_slice()).to_hex(),
+    }
+    .to_ok()
+}
+
+/// Verifies that a quote supplied to a trade execution route is still valid at the current block
+/// height and that re-deriving it from the current contract configuration produces the same hash.
+///
+/// # Parameters
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `contract_state` The current contract configuration.
+/// * `tunables` The current runtime tunables, which define how long the quote remains valid.
+/// * `direction` The direction of the trade being executed.
+/// * `trade_amount` The amount of input denom requested by the trade being executed.
+/// * `quote` The reference to the quote against which the trade is being executed.
+pub fn check_quote(
+    env: &Env,
+    contract_state: &ContractStateV1,
+    tunables: &RuntimeTunables,
+    direction: TradeDirection,
+    trade_amount: Uint128,
+    quote: &QuoteRef,
+) -> Result<(), ContractError> {
+    let current_height = env.block.height;
+    if quote.quoted_at_height > current_height {
+        return ContractError::InvalidQuoteError {
+            message: format!(
+                "quote height [{}] is after the current height [{current_height}]",
+                quote.quoted_at_height,
+            ),
+        }
+        .to_err();
+    }
+    let current_quote = derive_trade_quote(
+        env,
+        contract_state,
+        tunables,
+        direction,
+        trade_amount,
+        quote.quoted_at_height,
+    )?;
+    if current_height > current_quote.valid_through_height {
+        return ContractError::InvalidQuoteError {
+            message: format!(
+                "quote from height [{}] was only valid through height [{}], but the current height is [{current_height}]",
+                quote.quoted_at_height, current_quote.valid_through_height,
+            ),
+        }
+        .to_err();
+    }
+    if current_quote.hash != quote.hash {
+        return ContractError::InvalidQuoteError {
+            message: format!(
+                "quote hash [{}] does not match the trade's current conversion",
+                quote.hash,
+            ),
+        }
+        .to_err();
+    }
+    ().to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::contract_state::ContractStateV1;
+    use crate::store::runtime_tunables::RuntimeTunables;
+    use crate::types::denom::Denom;
+    use crate::types::error::ContractError;
+    use crate::types::quote::{QuoteRef, TradeDirection};
+    use crate::util::quote_utils::{check_quote, derive_trade_quote};
+    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{Addr, Env, Uint128};
+
+    fn test_state() -> ContractStateV1 {
+        ContractStateV1::new(
+            Addr::unchecked("admin"),
+            "contract-name",
+            &Denom::new("deposit", 2),
+            &Denom::new("trading", 6),
+            &[],
+            &[],
+        )
+    }
+
+    fn env_at_height(height: u64) -> Env {
+        let mut env = mock_env();
+        env.block.height = height;
+        env
+    }
+
+    fn quote_ref(state: &ContractStateV1, quoted_at_height: u64) -> QuoteRef {
+        let quote = derive_trade_quote(
+            &env_at_height(quoted_at_height),
+            state,
+            &RuntimeTunables::default(),
+            TradeDirection::Withdraw,
+            Uint128::new(1234567),
+            quoted_at_height,
+        )
+        .expect("the quote should be derived");
+        QuoteRef {
+            hash: quote.hash,
+            quoted_at_height: quote.quoted_at_height,
+        }
+    }
+
+    #[test]
+    fn derive_trade_quote_produces_the_conversion_and_a_deterministic_hash() {
+        let state = test_state();
+        let tunables = RuntimeTunables::default();
+        let quote = derive_trade_quote(
+            &env_at_height(100),
+            &state,
+            &tunables,
+            TradeDirection::Withdraw,
+            Uint128::new(1234567),
+            100,
+        )
+        .expect("the quote should be derived");
+        assert_eq!(
+            "trading", quote.input_denom,
+            "the input denom should be set"
+        );
+        assert_eq!(
+            "deposit", quote.output_denom,
+            "the output denom should be set"
+        );
+        assert_eq!(
+            Uint128::new(1230000),
+            quote.collected_amount,
+            "the collected amount should exclude the remainder",
+        );
+        assert_eq!(
+            Uint128::new(123),
+            quote.output_amount,
+            "the output amount should be converted",
+        );
+        assert_eq!(
+            Uint128::new(4567),
+            quote.remainder,
+            "the remainder should be reported",
+        );
+        assert_eq!(
+            100 + tunables.quote_validity_blocks,
+            quote.valid_through_height,
+            "the quote should be valid for the configured amount of blocks",
+        );
+        assert_eq!(
+            64,
+            quote.hash.len(),
+            "the hash should be a hex-encoded sha256 digest",
+        );
+        assert_eq!(
+            quote,
+            derive_trade_quote(
+                &env_at_height(100),
+                &state,
+                &tunables,
+                TradeDirection::Withdraw,
+                Uint128::new(1234567),
+                100,
+            )
+            .expect("the quote should be derived again"),
+            "the same inputs should always produce the same quote",
+        );
+    }
+
+    #[test]
+    fn derive_trade_quote_hash_changes_with_its_inputs() {
+        let state = test_state();
+        let tunables = RuntimeTunables::default();
+        let hash = |state: &ContractStateV1, direction, amount: u128, height| {
+            derive_trade_quote(
+                &env_at_height(height),
+                state,
+                &tunables,
+                direction,
+                Uint128::new(amount),
+                height,
+            )
+            .expect("the quote should be derived")
+            .hash
+        };
+        let base_hash = hash(&state, TradeDirection::Fund, 100, 10);
+        assert_ne!(
+            base_hash,
+            hash(&state, TradeDirection::Fund, 101, 10),
+            "a different amount should produce a different hash",
+        );
+        assert_ne!(
+            base_hash,
+            hash(&state, TradeDirection::Withdraw, 100000000, 10),
+            "a different direction should produce a different hash",
+        );
+        assert_ne!(
+            base_hash,
+            hash(&state, TradeDirection::Fund, 100, 11),
+            "a different quoted height should produce a different hash",
+        );
+        let mut changed_state = test_state();
+        changed_state.trading_marker = Denom::new("trading", 5);
+        assert_ne!(
+            base_hash,
+            hash(&changed_state, TradeDirection::Fund, 100, 10),
+            "a different denom precision should produce a different hash",
+        );
+    }
+
+    #[test]
+    fn derive_trade_quote_rejects_amounts_that_convert_to_nothing() {
+        let error = derive_trade_quote(
+            &mock_env(),
+            &test_state(),
+            &RuntimeTunables::default(),
+            TradeDirection::Withdraw,
+            Uint128::new(9999),
+            1,
+        )
+        .expect_err("an amount that converts to nothing should not be quoted");
+        assert!(
+            matches!(error, ContractError::InvalidFundsError { .. }),
+            "unexpected error emitted for an unconvertible amount: {error:?}",
+        );
+    }
+
+    #[test]
+    fn check_quote_accepts_quotes_within_the_validity_window() {
+        let state = test_state();
+        let tunables = RuntimeTunables::default();
+        let quote = quote_ref(&state, 100);
+        for height in [100, 100 + tunables.quote_validity_blocks] {
+            check_quote(
+                &env_at_height(height),
+                &state,
+                &tunables,
+                TradeDirection::Withdraw,
+                Uint128::new(1234567),
+                &quote,
+            )
+            .expect("a quote within its validity window should be accepted");
+        }
+    }
+
+    #[test]
+    fn check_quote_rejects_expired_quotes() {
+        let state = test_state();
+        let tunables = RuntimeTunables::default();
+        let error = check_quote(
+            &env_at_height(101 + tunables.quote_validity_blocks),
+            &state,
+            &tunables,
+            TradeDirection::Withdraw,
+            Uint128::new(1234567),
+            &quote_ref(&state, 100),
+        )
+        .expect_err("a quote past its validity window should be rejected");
+        assert_eq!(
+            "invalid quote: quote from height [100] was only valid through height [200], but the current height is [201]",
+            error.to_string(),
+            "unexpected error emitted for an expired quote",
+        );
+    }
+
+    #[test]
+    fn check_quote_rejects_quotes_from_future_heights() {
+        let state = test_state();
+        let error = check_quote(
+            &env_at_height(99),
+            &state,
+            &RuntimeTunables::default(),
+            TradeDirection::Withdraw,
+            Uint128::new(1234567),
+            &quote_ref(&state, 100),
+        )
+        .expect_err("a quote from a future height should be rejected");
+        assert_eq!(
+            "invalid quote: quote height [100] is after the current height [99]",
+            error.to_string(),
+            "unexpected error emitted for a quote from a future height",
+        );
+    }
+
+    #[test]
+    fn check_quote_rejects_quotes_that_no_longer_match() {
+        let state = test_state();
+        let quote = quote_ref(&state, 100);
+        let mut changed_state = test_state();
+        changed_state.deposit_marker = Denom::new("deposit", 3);
+        for (state, amount) in [(&state, 1234568), (&changed_state, 1234567)] {
+            let error = check_quote(
+                &env_at_height(150),
+                state,
+                &RuntimeTunables::default(),
+                TradeDirection::Withdraw,
+                Uint128::new(amount),
+                &quote,
+            )
+            .expect_err("a quote that no longer matches should be rejected");
+            assert_eq!(
+                format!(
+                    "invalid quote: quote hash [{}] does not match the trade's current conversion",
+                    quote.hash,
+                ),
+                error.to_string(),
+                "unexpected error emitted for a quote that no longer matches",
+            );
+        }
+    }
+}
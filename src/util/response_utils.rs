@@ -0,0 +1,346 @@
+use crate::store::contract_state::{AdminSender, ContractStateV1};
+use crate::store::runtime_tunables::EventVerbosity;
+use crate::store::trade_receipts::TradeReceipt;
+use crate::types::error::ContractError;
+use crate::types::msg_fee::{FeeDiscount, MsgFee};
+use crate::types::responses::TradeReceiptData;
+use crate::types::trade_plan::TradePlan;
+use crate::util::provenance_utils::msg_assess_custom_fee;
+use cosmwasm_std::{to_json_binary, Addr, Attribute, Coin, Env, Response, SubMsg, Uint128};
+use result_extensions::ResultExtensions;
+
+/// The values of a planned trade from which a trade route's attributes are derived.  See
+/// [add_trade_attributes].
+pub struct TradeEvent {
+    /// The account whose funds are traded.
+    pub sender: Addr,
+    /// The coin pulled from the sender's account.
+    pub input: Coin,
+    /// The coin sent to the sender's account.
+    pub output: Coin,
+    /// The amount of the requested input that could not be converted.
+    pub remainder: Uint128,
+    /// Whether or not the trade must be approved before it executes.
+    pub requires_approval: bool,
+    /// The attributes that the route emits at the [Standard](EventVerbosity::Standard) level.
+    pub route_attributes: Vec<Attribute>,
+}
+
+/// Creates a new response containing the attributes that every route emits, allowing indexers to
+/// identify the contract instance and release that produced it.  Route-specific attributes and
+/// messages should be added to the result.
+///
+/// # Parameters
+///
+/// * `action` The name of the route producing the response.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `contract_state` The contract state as it stands at the end of the route's execution.
+pub fn base_response<S: Into<String>>(
+    action: S,
+    env: &Env,
+    contract_state: &ContractStateV1,
+) -> Response {
+    Response::new()
+        .add_attribute("action", action)
+        .add_attribute("contract_address", env.contract.address.as_str())
+        .add_attribute("contract_type", &contract_state.contract_type)
+        .add_attribute("contract_version", &contract_state.contract_version)
+        .add_attribute("contract_name", &contract_state.contract_name)
+}
+
+/// Creates a new [base_response] for an admin route.  When the route was executed by the
+/// [gov_authority](ContractStateV1#gov_authority) rather than the admin, a `gov_authority_override`
+/// attribute is included so that indexers can distinguish governance interventions.
+///
+/// # Parameters
+///
+/// * `action` The name of the route producing the response.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `contract_state` The contract state as it stands at the end of the route's execution.
+/// * `admin_sender` The capacity in which the sender passed the [admin check](ContractStateV1::check_admin).
+pub fn admin_response<S: Into<String>>(
+    action: S,
+    env: &Env,
+    contract_state: &ContractStateV1,
+    admin_sender: AdminSender,
+) -> Response {
+    let response = base_response(action, env, contract_state);
+    match admin_sender {
+        AdminSender::GovAuthority => response.add_attribute("gov_authority_override", "true"),
+        AdminSender::Admin | AdminSender::GroupPolicy => response,
+    }
+}
+
+/// Creates a new response for a trade route.  At the [Minimal](EventVerbosity::Minimal) level the
+/// response only contains the `action` attribute, and at every other level it is a [base_response].
+///
+/// # Parameters
+///
+/// * `action` The name of the route producing the response.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `contract_state` The contract state as it stands at the end of the route's execution.
+/// * `verbosity` The configured [event verbosity](crate::store::runtime_tunables::RuntimeTunables#event_verbosity).
+pub fn trade_response<S: Into<String>>(
+    action: S,
+    env: &Env,
+    contract_state: &ContractStateV1,
+    verbosity: EventVerbosity,
+) -> Response {
+    match verbosity {
+        EventVerbosity::Minimal => Response::new().add_attribute("action", action),
+        EventVerbosity::Standard | EventVerbosity::Verbose => {
+            base_response(action, env, contract_state)
+        }
+    }
+}
+
+/// Appends the attributes describing a planned trade to a trade route's response, choosing them
+/// according to the configured [EventVerbosity].
+///
+/// # Parameters
+///
+/// * `response` The trade route's response.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `contract_state` The current contract state, which dictates the traded pair.
+/// * `verbosity` The configured [event verbosity](crate::store::runtime_tunables::RuntimeTunables#event_verbosity).
+/// * `event` The values of the planned trade.
+pub fn add_trade_attributes(
+    response: Response,
+    env: &Env,
+    contract_state: &ContractStateV1,
+    verbosity: EventVerbosity,
+    event: TradeEvent,
+) -> Response {
+    let pair = format!(
+        "{}/{}",
+        contract_state.deposit_marker.name, contract_state.trading_marker.name,
+    );
+    match verbosity {
+        EventVerbosity::Minimal => response
+            .add_attribute("pair", pair)
+            .add_attribute("sender", event.sender.as_str())
+            .add_attribute("input", event.input.to_string())
+            .add_attribute("output", event.output.to_string()),
+        EventVerbosity::Standard => response.add_attributes(event.route_attributes),
+        EventVerbosity::Verbose => response
+            .add_attributes(event.route_attributes)
+            .add_attribute("sender", event.sender.as_str())
+            .add_attribute("pair", pair)
+            .add_attribute("remainder", event.remainder.to_string())
+            .add_attribute("requires_approval", event.requires_approval.to_string())
+            .add_attribute("block_height", env.block.height.to_string()),
+    }
+}
+
+/// Appends the attributes describing a dry run to a trade route's response.  The `remainder` and
+/// `requires_approval` attributes are only added when the [Verbose](EventVerbosity::Verbose) level
+/// has not already included them.
+///
+/// # Parameters
+///
+/// * `response` The trade route's response.
+/// * `verbosity` The configured [event verbosity](crate::store::runtime_tunables::RuntimeTunables#event_verbosity).
+/// * `remainder` The amount of the requested input that could not be converted.
+/// * `requires_approval` Whether or not the trade would need to be approved before it executes.
+pub fn add_dry_run_attributes(
+    response: Response,
+    verbosity: EventVerbosity,
+    remainder: Uint128,
+    requires_approval: bool,
+) -> Response {
+    let response = response.add_attribute("dry_run", "true");
+    match verbosity {
+        EventVerbosity::Verbose => response,
+        EventVerbosity::Minimal | EventVerbosity::Standard => response
+            .add_attribute("remainder", remainder.to_string())
+            .add_attribute("requires_approval", requires_approval.to_string()),
+    }
+}
+
+/// Lists every message that executing a planned trade emits, in order: the trade's own messages,
+/// unless the trade is [held for approval](TradePlan#requires_approval), followed by the
+/// assessment of its [msg fee](TradePlan#msg_fee), if any.  The trade routes and the
+/// [preview_execute](crate::query::query_preview_execute::query_preview_execute) query both use
+/// this, so that a preview lists exactly the messages that the execution emits.
+///
+/// # Parameters
+///
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `plan` The planned trade.
+/// * `name` The name recorded alongside the assessed fee, describing the trade that incurred it.
+pub fn trade_execution_messages<S: Into<String>>(
+    env: &Env,
+    plan: &TradePlan,
+    name: S,
+) -> Vec<SubMsg> {
+    let mut messages = if plan.requires_approval {
+        vec![]
+    } else {
+        plan.messages.to_owned()
+    };
+    if let Some(msg_fee) = &plan.msg_fee {
+        messages.push(SubMsg::new(msg_assess_custom_fee(
+            name,
+            msg_fee,
+            env.contract.address.as_str(),
+        )));
+    }
+    messages
+}
+
+/// Appends the attributes describing a trade's [msg fee](ContractStateV1#msg_fee) to a trade
+/// route's response: a `msg_fee` attribute describing the assessed coin and, when the sender
+/// earned a [fee discount](ContractStateV1#fee_discounts), `fee_discount_applied` and
+/// `fee_discount_bps_off` attributes describing it, even if the discount waives the fee entirely.
+/// The attributes are omitted at the [Minimal](EventVerbosity::Minimal) level.  The fee's message
+/// is produced by [trade_execution_messages].
+///
+/// # Parameters
+///
+/// * `response` The trade route's response.
+/// * `msg_fee` The fee assessed after any discount, if any.
+/// * `fee_discount` The discount that was applied to the fee, if any.
+/// * `verbosity` The configured [event verbosity](crate::store::runtime_tunables::RuntimeTunables#event_verbosity).
+pub fn add_msg_fee_attributes(
+    response: Response,
+    msg_fee: Option<&MsgFee>,
+    fee_discount: Option<&FeeDiscount>,
+    verbosity: EventVerbosity,
+) -> Response {
+    let mut response = response;
+    if verbosity == EventVerbosity::Minimal {
+        return response;
+    }
+    if let Some(msg_fee) = msg_fee {
+        response = response.add_attribute("msg_fee", msg_fee.amount.to_string());
+    }
+    if let Some(fee_discount) = fee_discount {
+        response = response
+            .add_attribute("fee_discount_applied", &fee_discount.attribute)
+            .add_attribute(
+                "fee_discount_bps_off",
+                fee_discount.discount_bps_off.to_string(),
+            );
+    }
+    response
+}
+
+/// Appends the [receipt](TradeReceipt#receipt) issued to an executed trade to a trade route's
+/// response.  The receipt is emitted as an attribute at every [EventVerbosity] level, and is set
+/// as the response's data so that the calling client or contract can read it without parsing
+/// events.
+///
+/// # Parameters
+///
+/// * `response` The trade route's response.
+/// * `trade_receipt` The receipt issued to the executed trade.
+pub fn add_trade_receipt(
+    response: Response,
+    trade_receipt: &TradeReceipt,
+) -> Result<Response, ContractError> {
+    response
+        .add_attribute("receipt", trade_receipt.receipt.to_string())
+        .set_data(to_json_binary(&TradeReceiptData {
+            receipt: trade_receipt.receipt,
+        })?)
+        .to_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::store::contract_state::{
+        get_contract_state_v1, AdminSender, CONTRACT_TYPE, CONTRACT_VERSION,
+    };
+    use crate::store::runtime_tunables::EventVerbosity;
+    use crate::test::attribute_extractor::AttributeExtractor;
+    use crate::test::test_constants::DEFAULT_CONTRACT_NAME;
+    use crate::test::test_instantiate::test_instantiate;
+    use crate::util::response_utils::{add_dry_run_attributes, admin_response, base_response};
+    use cosmwasm_std::testing::{mock_env, MOCK_CONTRACT_ADDR};
+    use cosmwasm_std::{Response, Uint128};
+    use provwasm_mocks::mock_provenance_dependencies;
+
+    #[test]
+    fn base_response_should_include_all_common_attributes() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        let response = base_response("some_action", &mock_env(), &contract_state);
+        assert!(
+            response.messages.is_empty(),
+            "no messages should be included in the base response",
+        );
+        assert_eq!(
+            5,
+            response.attributes.len(),
+            "five attributes should be included in the base response",
+        );
+        response.assert_attribute("action", "some_action");
+        response.assert_attribute("contract_address", MOCK_CONTRACT_ADDR);
+        response.assert_attribute("contract_type", CONTRACT_TYPE);
+        response.assert_attribute("contract_version", CONTRACT_VERSION);
+        response.assert_attribute("contract_name", DEFAULT_CONTRACT_NAME);
+    }
+
+    #[test]
+    fn admin_response_should_flag_the_gov_authority() {
+        let mut deps = mock_provenance_dependencies();
+        test_instantiate(&mut deps);
+        let contract_state =
+            get_contract_state_v1(&deps.storage).expect("contract state should load");
+        for admin_sender in [AdminSender::Admin, AdminSender::GroupPolicy] {
+            assert_eq!(
+                base_response("some_action", &mock_env(), &contract_state),
+                admin_response("some_action", &mock_env(), &contract_state, admin_sender),
+                "the admin response should match the base response for {admin_sender:?}",
+            );
+        }
+        let response = admin_response(
+            "some_action",
+            &mock_env(),
+            &contract_state,
+            AdminSender::GovAuthority,
+        );
+        assert_eq!(
+            6,
+            response.attributes.len(),
+            "six attributes should be included when the gov authority acts",
+        );
+        response.assert_attribute("action", "some_action");
+        response.assert_attribute("gov_authority_override", "true");
+    }
+
+    #[test]
+    fn dry_run_attributes_should_not_repeat_verbose_attributes() {
+        for verbosity in [EventVerbosity::Minimal, EventVerbosity::Standard] {
+            let response =
+                add_dry_run_attributes(Response::new(), verbosity, Uint128::new(3), true);
+            assert_eq!(
+                3,
+                response.attributes.len(),
+                "three attributes should be included for {verbosity:?}",
+            );
+            response.assert_attribute("dry_run", "true");
+            response.assert_attribute("remainder", "3");
+            response.assert_attribute("requires_approval", "true");
+        }
+        let response = add_dry_run_attributes(
+            Response::new(),
+            EventVerbosity::Verbose,
+            Uint128::new(3),
+            true,
+        );
+        assert_eq!(
+            1,
+            response.attributes.len(),
+            "only the dry run flag should be added at the verbose level",
+        );
+        response.assert_attribute("dry_run", "true");
+    }
+}
@@ -0,0 +1,176 @@
+use cosmwasm_std::Uint128;
+use result_extensions::ResultExtensions;
+use serde::de::{Error, Visitor};
+use serde::Deserializer;
+use std::fmt::Formatter;
+use std::num::IntErrorKind;
+
+/// Deserializes a trade amount from either a JSON number or a JSON string.  [Uint128] only accepts
+/// strings on its own, but many clients send trade amounts as numbers.  Negative and fractional
+/// values are rejected, as are values that do not fit within 128 bits.  Strings may only contain
+/// digits, so a leading sign like `+5` is rejected.
+///
+/// # Parameters
+///
+/// * `deserializer` The deserializer from which to read the amount.
+pub fn deserialize_trade_amount<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Uint128, D::Error> {
+    deserializer.deserialize_any(TradeAmountVisitor)
+}
+
+struct TradeAmountVisitor;
+impl<'de> Visitor<'de> for TradeAmountVisitor {
+    type Value = Uint128;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("a non-negative whole number trade amount as a JSON number or string")
+    }
+
+    fn visit_i64<E: Error>(self, v: i64) -> Result<Self::Value, E> {
+        if v < 0 {
+            E::custom(format!("trade amount [{v}] cannot be negative")).to_err()
+        } else {
+            Uint128::new(v as u128).to_ok()
+        }
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+        Uint128::new(v as u128).to_ok()
+    }
+
+    fn visit_u128<E: Error>(self, v: u128) -> Result<Self::Value, E> {
+        Uint128::new(v).to_ok()
+    }
+
+    fn visit_f64<E: Error>(self, v: f64) -> Result<Self::Value, E> {
+        // JSON numbers too large for any integer type are parsed as floats, so a whole float at
+        // or beyond 2^128 is an amount that overflows rather than a fractional amount
+        if v.is_sign_negative() && v != 0.0 {
+            E::custom(format!("trade amount [{v}] cannot be negative")).to_err()
+        } else if v.fract() == 0.0 && v >= u128::MAX as f64 {
+            E::custom(format!(
+                "trade amount [{v}] exceeds the maximum of [{}]",
+                u128::MAX,
+            ))
+            .to_err()
+        } else {
+            E::custom(format!("trade amount [{v}] must be a whole number")).to_err()
+        }
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        // u128 parsing tolerates a leading plus sign, which is not a valid trade amount
+        if v.is_empty() || !v.bytes().all(|byte| byte.is_ascii_digit()) {
+            return E::custom(format!(
+                "trade amount [{v}] must be a non-negative whole number"
+            ))
+            .to_err();
+        }
+        v.parse::<u128>()
+            .map(Uint128::new)
+            .map_err(|e| match e.kind() {
+                IntErrorKind::PosOverflow => E::custom(format!(
+                    "trade amount [{v}] exceeds the maximum of [{}]",
+                    u128::MAX,
+                )),
+                _ => E::custom(format!(
+                    "trade amount [{v}] must be a non-negative whole number"
+                )),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::util::serde_utils::deserialize_trade_amount;
+    use cosmwasm_std::{from_json, StdError, Uint128};
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+    struct TestAmount {
+        #[serde(deserialize_with = "deserialize_trade_amount")]
+        amount: Uint128,
+    }
+
+    #[test]
+    fn test_numeric_and_string_amounts_are_accepted() {
+        assert_eq!(
+            Uint128::new(100),
+            from_json::<TestAmount>(r#"{"amount":100}"#)
+                .expect("a numeric amount should deserialize")
+                .amount,
+            "the numeric amount should be parsed correctly",
+        );
+        assert_eq!(
+            Uint128::new(100),
+            from_json::<TestAmount>(r#"{"amount":"100"}"#)
+                .expect("a string amount should deserialize")
+                .amount,
+            "the string amount should be parsed correctly",
+        );
+        assert_eq!(
+            Uint128::MAX,
+            from_json::<TestAmount>(format!(r#"{{"amount":"{}"}}"#, u128::MAX))
+                .expect("the maximum string amount should deserialize")
+                .amount,
+            "the maximum string amount should be parsed correctly",
+        );
+    }
+
+    #[test]
+    fn test_invalid_amounts_are_rejected() {
+        let max = u128::MAX;
+        let cases = [
+            (r#"{"amount":-5}"#, "trade amount [-5] cannot be negative".to_string()),
+            (
+                r#"{"amount":"-5"}"#,
+                "trade amount [-5] must be a non-negative whole number".to_string(),
+            ),
+            (
+                r#"{"amount":"+5"}"#,
+                "trade amount [+5] must be a non-negative whole number".to_string(),
+            ),
+            (
+                r#"{"amount":""}"#,
+                "trade amount [] must be a non-negative whole number".to_string(),
+            ),
+            (r#"{"amount":1.5}"#, "trade amount [1.5] must be a whole number".to_string()),
+            (
+                r#"{"amount":"1.5"}"#,
+                "trade amount [1.5] must be a non-negative whole number".to_string(),
+            ),
+            (
+                r#"{"amount":"340282366920938463463374607431768211456"}"#,
+                format!(
+                    "trade amount [340282366920938463463374607431768211456] exceeds the maximum of [{max}]"
+                ),
+            ),
+            (
+                r#"{"amount":340282366920938463463374607431768211456}"#,
+                format!(
+                    "trade amount [340282366920938500000000000000000000000] exceeds the maximum of [{max}]"
+                ),
+            ),
+            (
+                r#"{"amount":-340282366920938463463374607431768211456}"#,
+                "trade amount [-340282366920938500000000000000000000000] cannot be negative"
+                    .to_string(),
+            ),
+        ];
+        for (json, expected_message) in cases {
+            let error = from_json::<TestAmount>(json)
+                .err()
+                .unwrap_or_else(|| panic!("the amount in {json} should be rejected"));
+            match error {
+                StdError::ParseErr { msg, .. } => {
+                    assert_eq!(
+                        expected_message, msg,
+                        "unexpected error message for the amount in {json}",
+                    );
+                }
+                _ => panic!("unexpected error for the amount in {json}: {error:?}"),
+            }
+        }
+    }
+}
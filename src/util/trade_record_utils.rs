@@ -0,0 +1,136 @@
+use crate::store::account_daily_usage::record_account_daily_usage;
+use crate::store::account_stats::record_account_trade;
+use crate::store::bridge_stats::record_bridge_trade;
+use crate::store::contract_state::ContractStateV1;
+use crate::store::daily_volume::check_daily_capacity;
+use crate::store::referral_stats::record_referral;
+use crate::store::trade_receipts::{issue_trade_receipt, TradeReceipt};
+use crate::store::trade_stats::record_trade;
+use crate::types::error::ContractError;
+use crate::types::quote::TradeDirection;
+use crate::types::trade_plan::TradePlan;
+use cosmwasm_std::{coin, Addr, Env, Storage};
+
+/// Records a planned trade that is about to execute.  The trade is checked against the contract's
+/// [daily cap](ContractStateV1#daily_cap) when funding, and counted toward its sender's
+/// [per-account daily limit](ContractStateV1#per_account_daily_limit) in either direction, failing
+/// if it no longer fits within them.  The daily cap is measured by the daily trade totals, which
+/// the trade joins once it is recorded as executed.  It is then recorded as [executed](record_executed_trade).
+/// Returns the receipt issued to the trade.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+/// manipulation.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `contract_state` The current contract state, which dictates the denoms and limits of the trade.
+/// * `direction` The direction of the trade.
+/// * `sender` The account whose funds are traded.
+/// * `referrer` The validated referrer of the trade, if any.
+/// * `plan` The plan produced by the trade's route.
+pub fn record_planned_trade(
+    storage: &mut dyn Storage,
+    env: &Env,
+    contract_state: &ContractStateV1,
+    direction: TradeDirection,
+    sender: &Addr,
+    referrer: Option<&Addr>,
+    plan: &TradePlan,
+) -> Result<TradeReceipt, ContractError> {
+    if direction == TradeDirection::Fund {
+        check_daily_capacity(
+            storage,
+            &env.block.time,
+            contract_state.daily_cap,
+            plan.deposit_amount,
+            &contract_state.deposit_marker.name,
+        )?;
+    }
+    record_account_daily_usage(
+        storage,
+        sender,
+        &env.block.time,
+        contract_state.per_account_daily_limit,
+        plan.deposit_amount,
+        &contract_state.deposit_marker.name,
+    )?;
+    record_executed_trade(
+        storage,
+        env,
+        contract_state,
+        direction,
+        sender,
+        referrer,
+        plan,
+    )
+}
+
+/// Records an executing trade in every store that tracks trading activity: the referrer's
+/// credit, the daily and per-account trade statistics, and the bridge's conversion totals.  The
+/// trade is issued a [receipt](TradeReceipt), which is returned.  Unlike
+/// [record_planned_trade], the trade is not counted toward any daily limit.
+///
+/// # Parameters
+///
+/// * `storage` A mutable instance of the contract storage value, allowing internal store
+/// manipulation.
+/// * `env` An environment object provided by the cosmwasm framework.  Describes the contract's
+/// details, as well as blockchain information at the time of the transaction.
+/// * `contract_state` The current contract state, which dictates the denoms of the trade.
+/// * `direction` The direction of the trade.
+/// * `sender` The account whose funds are traded.
+/// * `referrer` The validated referrer of the trade, if any.
+/// * `plan` The plan describing the trade's amounts.
+pub fn record_executed_trade(
+    storage: &mut dyn Storage,
+    env: &Env,
+    contract_state: &ContractStateV1,
+    direction: TradeDirection,
+    sender: &Addr,
+    referrer: Option<&Addr>,
+    plan: &TradePlan,
+) -> Result<TradeReceipt, ContractError> {
+    if let Some(referrer) = referrer {
+        record_referral(storage, referrer, plan.deposit_amount)?;
+    }
+    record_trade(
+        storage,
+        &env.block.time,
+        direction,
+        sender,
+        plan.deposit_amount,
+    )?;
+    record_account_trade(
+        storage,
+        sender,
+        direction,
+        plan.deposit_amount,
+        env.block.height,
+    )?;
+    record_bridge_trade(
+        storage,
+        direction,
+        plan.collected_amount,
+        plan.conversion.target_amount,
+    )?;
+    let (input_marker, output_marker) = match direction {
+        TradeDirection::Fund => (
+            &contract_state.deposit_marker,
+            &contract_state.trading_marker,
+        ),
+        TradeDirection::Withdraw => (
+            &contract_state.trading_marker,
+            &contract_state.deposit_marker,
+        ),
+    };
+    issue_trade_receipt(
+        storage,
+        &env.block,
+        direction,
+        sender,
+        coin(plan.collected_amount.u128(), &input_marker.name),
+        coin(plan.output_amount().u128(), &output_marker.name),
+        plan.conversion.remainder,
+    )
+}
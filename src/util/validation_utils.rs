@@ -1,5 +1,6 @@
 use crate::types::error::ContractError;
-use cosmwasm_std::MessageInfo;
+use crate::types::quote::TradeDirection;
+use cosmwasm_std::{Addr, Deps, MessageInfo, Uint128};
 use result_extensions::ResultExtensions;
 use uuid::Uuid;
 
@@ -21,6 +22,25 @@ pub fn check_funds_are_empty(info: &MessageInfo) -> Result<(), ContractError> {
     }
 }
 
+/// Combines the results of a set of independent precondition checks, producing a single
+/// [PreconditionsFailed](ContractError::PreconditionsFailed) error that describes every failure,
+/// rather than only the first one encountered.
+///
+/// # Parameters
+///
+/// * `results` The result of each precondition check.
+pub fn check_preconditions(results: Vec<Result<(), ContractError>>) -> Result<(), ContractError> {
+    let failures = results
+        .into_iter()
+        .filter_map(|result| result.err().map(|e| e.to_string()))
+        .collect::<Vec<String>>();
+    if failures.is_empty() {
+        ().to_ok()
+    } else {
+        ContractError::PreconditionsFailed { failures }.to_err()
+    }
+}
+
 /// Verifies that the provided string is a valid attribute name for the Provenance Blockchain,
 /// following their rules:
 /// - The attribute must not be empty.
@@ -76,11 +96,76 @@ pub fn validate_attribute_name<S: Into<String>>(name: S) -> Result<(), ContractE
     ().to_ok()
 }
 
+/// Verifies that a trade's optional referrer is a valid bech32 address that differs from the
+/// trade's sender, returning the validated address.  An absent referrer is always accepted.
+///
+/// # Parameters
+///
+/// * `deps` A dependencies object provided by the cosmwasm framework.  Allows access to useful
+/// resources like contract internal storage and a querier to retrieve blockchain objects.
+/// * `sender` The account sending the trade.
+/// * `referrer` The referrer named by the trade, if any.
+pub fn validate_referrer(
+    deps: &Deps,
+    sender: &Addr,
+    referrer: Option<String>,
+) -> Result<Option<Addr>, ContractError> {
+    let Some(referrer) = referrer else {
+        return None.to_ok();
+    };
+    let referrer =
+        deps.api
+            .addr_validate(&referrer)
+            .map_err(|e| ContractError::ValidationError {
+                message: format!("referrer [{referrer}] is not a valid address: {e}"),
+            })?;
+    if &referrer == sender {
+        return ContractError::ValidationError {
+            message: "an account cannot refer its own trades".to_string(),
+        }
+        .to_err();
+    }
+    Some(referrer).to_ok()
+}
+
+/// Verifies that the minimum and maximum amounts accepted for a trade direction do not conflict.
+/// Either bound may be absent, in which case any value of the other is accepted.
+///
+/// # Parameters
+///
+/// * `direction` The trade direction that the bounds apply to.
+/// * `minimum` The smallest amount accepted for the direction, if any.
+/// * `maximum` The largest amount accepted for the direction, if any.
+pub fn check_trade_amount_range(
+    direction: TradeDirection,
+    minimum: Option<Uint128>,
+    maximum: Option<Uint128>,
+) -> Result<(), ContractError> {
+    if let (Some(minimum), Some(maximum)) = (minimum, maximum) {
+        if minimum > maximum {
+            let name = direction.name();
+            return ContractError::ValidationError {
+                message: format!(
+                    "min_{name}_amount [{minimum}] cannot exceed max_{name}_amount [{maximum}]"
+                ),
+            }
+            .to_err();
+        }
+    }
+    ().to_ok()
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::util::validation_utils::{check_funds_are_empty, validate_attribute_name};
-    use cosmwasm_std::testing::message_info;
-    use cosmwasm_std::{coin, coins, Addr};
+    use crate::types::error::ContractError;
+    use crate::types::quote::TradeDirection;
+    use crate::util::validation_utils::{
+        check_funds_are_empty, check_preconditions, check_trade_amount_range,
+        validate_attribute_name, validate_referrer,
+    };
+    use cosmwasm_std::testing::{message_info, mock_dependencies};
+    use cosmwasm_std::{coin, coins, Addr, Uint128};
+    use result_extensions::ResultExtensions;
 
     #[test]
     fn test_check_funds_are_empty_cases() {
@@ -98,6 +183,41 @@ mod tests {
         .expect_err("multiple coins should produce an error");
     }
 
+    #[test]
+    fn test_check_preconditions_cases() {
+        check_preconditions(vec![]).expect("no preconditions should pass without an error");
+        check_preconditions(vec![Ok(()), Ok(())])
+            .expect("passing preconditions should pass without an error");
+        let error = check_preconditions(vec![
+            ContractError::InvalidAccountError {
+                message: "first".to_string(),
+            }
+            .to_err(),
+            Ok(()),
+            ContractError::InvalidFundsError {
+                message: "second".to_string(),
+            }
+            .to_err(),
+        ])
+        .expect_err("failing preconditions should produce an error");
+        match &error {
+            ContractError::PreconditionsFailed { failures } => assert_eq!(
+                &vec![
+                    "invalid account: first".to_string(),
+                    "invalid funds: second".to_string(),
+                ],
+                failures,
+                "every failure should be included in order",
+            ),
+            e => panic!("unexpected error emitted: {e:?}"),
+        };
+        assert_eq!(
+            "preconditions failed: invalid account: first; invalid funds: second",
+            error.to_string(),
+            "the error message should describe every failure",
+        );
+    }
+
     #[test]
     fn test_valid_attribute_name_use_cases() {
         // Invalid Cases:
@@ -139,6 +259,67 @@ mod tests {
         assert_attribute_valid("this-is.a-valid.name");
     }
 
+    #[test]
+    fn test_validate_referrer_cases() {
+        let deps = mock_dependencies();
+        let sender = deps.api.addr_make("sender");
+        let referrer = deps.api.addr_make("referrer");
+        assert_eq!(
+            None,
+            validate_referrer(&deps.as_ref(), &sender, None)
+                .expect("an absent referrer should be accepted"),
+            "an absent referrer should produce no address",
+        );
+        assert_eq!(
+            Some(referrer.clone()),
+            validate_referrer(&deps.as_ref(), &sender, Some(referrer.to_string()))
+                .expect("a valid referrer should be accepted"),
+            "a valid referrer should produce its address",
+        );
+        let error = validate_referrer(&deps.as_ref(), &sender, Some("not-bech32".to_string()))
+            .expect_err("an invalid referrer address should be rejected");
+        assert!(
+            matches!(error, ContractError::ValidationError { .. }),
+            "unexpected error emitted for an invalid referrer: {error:?}",
+        );
+        assert_eq!(
+            "validation failed: an account cannot refer its own trades",
+            validate_referrer(&deps.as_ref(), &sender, Some(sender.to_string()))
+                .expect_err("a self-referral should be rejected")
+                .to_string(),
+            "unexpected error emitted for a self-referral",
+        );
+    }
+
+    #[test]
+    fn test_check_trade_amount_range_cases() {
+        for (minimum, maximum) in [
+            (None, None),
+            (Some(100), None),
+            (None, Some(100)),
+            (Some(100), Some(100)),
+            (Some(100), Some(101)),
+        ] {
+            check_trade_amount_range(
+                TradeDirection::Fund,
+                minimum.map(Uint128::new),
+                maximum.map(Uint128::new),
+            )
+            .expect("a range without conflicting bounds should be accepted");
+        }
+        assert_eq!(
+            "validation failed: min_withdraw_amount [101] cannot exceed max_withdraw_amount [100]",
+            check_trade_amount_range(
+                TradeDirection::Withdraw,
+                Some(Uint128::new(101)),
+                Some(Uint128::new(100)),
+            )
+            .expect_err("a minimum above the maximum should be rejected")
+            .to_string(),
+            "unexpected error emitted for a conflicting range",
+        );
+    }
+
     fn assert_attribute_valid<S: Into<String>>(attribute_name: S) {
         let attribute_name = attribute_name.into();
         match validate_attribute_name(&attribute_name) {